@@ -0,0 +1,84 @@
+//! Compares the slice conversions from `f32` that skip the handling of
+//! special values to the checked conversions, and the scalar software
+//! conversions to the generic [`narrow`] they are tested against.
+//!
+//! Run with `cargo bench --bench convert`. With F16C or NEON, both slice
+//! conversions use the same vectorized kernels, so add
+//! `--no-default-features --features alloc` to disable the detection of CPU
+//! features and compare the software paths.
+
+use std::time::{Duration, Instant};
+
+use float16::minifloat::narrow;
+use float16::{bf16, f16, HalfFloatSliceExt};
+
+const ITERATIONS: u32 = 20;
+
+/// Returns the average time of `ITERATIONS` runs of `f`.
+fn time<F: FnMut()>(mut f: F) -> Duration {
+    f();
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    start.elapsed() / ITERATIONS
+}
+
+/// Returns the average time of converting each index of `dst` with `f`.
+fn scalar<F: Fn(usize) -> u16>(dst: &mut [u16], f: F) -> Duration {
+    time(|| {
+        for (i, d) in dst.iter_mut().enumerate() {
+            *d = f(i);
+        }
+    })
+}
+
+fn main() {
+    for &len in &[1 << 10, 1 << 16, 1 << 22] {
+        // Normal values of either sign, which are valid for both types.
+        let src: Vec<f32> = (0..len).map(|i| ((i % 2000) as f32 - 999.5) * 0.37).collect();
+        let mut halves = vec![f16::ZERO; len];
+        let mut bhalves = vec![bf16::ZERO; len];
+
+        let checked = time(|| halves.convert_from_f32_slice(&src));
+        let unchecked = time(|| unsafe { halves.convert_from_f32_slice_unchecked_normal(&src) });
+        let bchecked = time(|| bhalves.convert_from_f32_slice(&src));
+        let bunchecked = time(|| unsafe { bhalves.convert_from_f32_slice_unchecked_normal(&src) });
+
+        assert_eq!(halves[1], f16::from_f32(src[1]));
+        assert_eq!(bhalves[1], bf16::from_f32(src[1]));
+        println!(
+            "{:>8} f16 checked {:>10.3?}  unchecked_normal {:>10.3?}  bf16 checked {:>10.3?}  \
+             unchecked_normal {:>10.3?}",
+            len, checked, unchecked, bchecked, bunchecked
+        );
+    }
+
+    // Values of every magnitude, including subnormals and special values.
+    let src: Vec<f32> =
+        (0..1u32 << 20).map(|i| f32::from_bits(i.wrapping_mul(0x9E37_79B9))).collect();
+    let src64: Vec<f64> = src.iter().map(|&x| x as f64).collect();
+    let mut bits = vec![0u16; src.len()];
+    let mut generic = vec![0u16; src.len()];
+
+    let to_f16 = scalar(&mut bits, |i| f16::from_f32_const(src[i]).to_bits());
+    let f16_narrow = scalar(&mut generic, |i| narrow(src[i].to_bits() as u64, 8, 23, 5, 10) as u16);
+    assert_eq!(bits, generic);
+    let to_bf16 = scalar(&mut bits, |i| bf16::from_f32_const(src[i]).to_bits());
+    let bf16_narrow = scalar(&mut generic, |i| narrow(src[i].to_bits() as u64, 8, 23, 8, 7) as u16);
+    assert_eq!(bits, generic);
+    let from_f64 = scalar(&mut bits, |i| f16::from_f64_const(src64[i]).to_bits());
+    let f64_narrow = scalar(&mut generic, |i| narrow(src64[i].to_bits(), 11, 52, 5, 10) as u16);
+    assert_eq!(bits, generic);
+    println!(
+        "{:>8} f32 to f16 {:>10.3?}  narrow {:>10.3?}  f32 to bf16 {:>10.3?}  narrow {:>10.3?}  \
+         f64 to f16 {:>10.3?}  narrow {:>10.3?}",
+        src.len(),
+        to_f16,
+        f16_narrow,
+        to_bf16,
+        bf16_narrow,
+        from_f64,
+        f64_narrow
+    );
+}
@@ -0,0 +1,44 @@
+//! Compares the blocked mixed-precision matrix multiplication to the naive
+//! triple loop, converting each element to `f32`.
+//!
+//! Run with `cargo bench --bench gemm`.
+
+use std::time::{Duration, Instant};
+
+use float16::{f16, linalg::gemm_f16_f32};
+
+const ITERATIONS: u32 = 5;
+
+/// Returns the average time of `ITERATIONS` runs of `f`.
+fn time<F: FnMut()>(mut f: F) -> Duration {
+    f();
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    start.elapsed() / ITERATIONS
+}
+
+fn main() {
+    for &(m, n, k) in &[(64, 64, 64), (128, 256, 64), (256, 256, 256), (512, 512, 512)] {
+        let a: Vec<f16> = (0..m * k).map(|i| f16::from_f32((i % 17) as f32 / 16.)).collect();
+        let b: Vec<f16> = (0..k * n).map(|i| f16::from_f32((i % 13) as f32 / 8. - 0.75)).collect();
+        let mut c = vec![0f32; m * n];
+
+        let naive = time(|| {
+            for i in 0..m {
+                for j in 0..n {
+                    for p in 0..k {
+                        c[i * n + j] += a[i * k + p].to_f32() * b[p * n + j].to_f32();
+                    }
+                }
+            }
+        });
+        let blocked = time(|| gemm_f16_f32(m, n, k, &a, k, &b, n, &mut c, n));
+
+        println!(
+            "{:>4}x{:<4}x{:<4} naive {:>10.3?}  gemm_f16_f32 {:>10.3?}",
+            m, n, k, naive, blocked
+        );
+    }
+}
@@ -0,0 +1,47 @@
+//! Compares the tiled transpose of large matrices to copying elements one at
+//! a time.
+//!
+//! Run with `cargo bench --bench transpose`.
+
+use std::time::{Duration, Instant};
+
+use float16::{f16, HalfFloatSliceExt};
+
+const ITERATIONS: u32 = 20;
+
+/// Returns the average time of `ITERATIONS` runs of `f`.
+fn time<F: FnMut()>(mut f: F) -> Duration {
+    f();
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    start.elapsed() / ITERATIONS
+}
+
+fn main() {
+    for &(rows, cols) in &[(256, 256), (1024, 1024), (4096, 1024), (1000, 3001)] {
+        let src: Vec<f16> = (0..rows * cols).map(|i| f16::from_bits(i as u16)).collect();
+        let mut dst = vec![f16::ZERO; rows * cols];
+
+        let naive = time(|| {
+            for row in 0..rows {
+                for col in 0..cols {
+                    dst[col * rows + row] = src[row * cols + col];
+                }
+            }
+        });
+        let naive_check = dst[1].to_bits();
+        let tiled = time(|| src.transpose_to(rows, cols, &mut dst));
+        assert_eq!(dst[1].to_bits(), naive_check);
+
+        let n = rows.min(cols);
+        let mut square = src[..n * n].to_vec();
+        let inplace = time(|| square.transpose_square_inplace(n));
+
+        println!(
+            "{:>5}x{:<5} naive {:>10.3?}  transpose_to {:>10.3?}  transpose_square_inplace ({}x{}) {:>10.3?}",
+            rows, cols, naive, tiled, n, n, inplace
+        );
+    }
+}
@@ -124,6 +124,24 @@ fn bench_slice_f32_to_f16(c: &mut Criterion) {
     });
 }
 
+fn bench_slice_f32_to_f16_unchecked_normal(c: &mut Criterion) {
+    let large: Vec<_> = iter::repeat(0)
+        .enumerate()
+        .map(|(i, _)| i as f32 + 1.)
+        .take(SIMD_LARGE_BENCH_SLICE_LEN)
+        .collect();
+    let mut large_buffer = [f16::ZERO; SIMD_LARGE_BENCH_SLICE_LEN];
+    c.bench_function(
+        "HalfFloatSliceExt::convert_from_f32_slice_unchecked_normal/large",
+        |b: &mut Bencher<'_>| {
+            b.iter(|| unsafe {
+                black_box(&mut large_buffer)
+                    .convert_from_f32_slice_unchecked_normal(black_box(&large))
+            })
+        },
+    );
+}
+
 fn bench_slice_f64_to_f16(c: &mut Criterion) {
     let mut constant_buffer = [f16::ZERO; 11];
     let constants = [
@@ -220,6 +238,7 @@ fn bench_slice_f16_to_f64(c: &mut Criterion) {
 criterion_group!(
     f16_simd,
     bench_slice_f32_to_f16,
+    bench_slice_f32_to_f16_unchecked_normal,
     bench_slice_f64_to_f16,
     bench_slice_f16_to_f32,
     bench_slice_f16_to_f64
@@ -309,12 +328,37 @@ fn bench_bf16_to_f64(c: &mut Criterion) {
     }
 }
 
+fn bench_slice_f32_to_bf16(c: &mut Criterion) {
+    let large: Vec<_> = iter::repeat(0)
+        .enumerate()
+        .map(|(i, _)| i as f32 * 1.1)
+        .take(SIMD_LARGE_BENCH_SLICE_LEN)
+        .collect();
+    let mut large_buffer = [bf16::ZERO; SIMD_LARGE_BENCH_SLICE_LEN];
+    c.bench_function(
+        "HalfFloatSliceExt::convert_from_f32_slice/bf16/large",
+        |b: &mut Bencher<'_>| {
+            b.iter(|| black_box(&mut large_buffer).convert_from_f32_slice(black_box(&large)))
+        },
+    );
+    c.bench_function(
+        "HalfFloatSliceExt::convert_from_f32_slice_unchecked_normal/bf16/large",
+        |b: &mut Bencher<'_>| {
+            b.iter(|| unsafe {
+                black_box(&mut large_buffer)
+                    .convert_from_f32_slice_unchecked_normal(black_box(&large))
+            })
+        },
+    );
+}
+
 criterion_group!(
     bf16_sisd,
     bench_f32_to_bf16,
     bench_f64_to_bf16,
     bench_bf16_to_f32,
-    bench_bf16_to_f64
+    bench_bf16_to_f64,
+    bench_slice_f32_to_bf16
 );
 
 criterion_main!(f16_sisd, bf16_sisd, f16_simd);
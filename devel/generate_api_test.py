@@ -0,0 +1,131 @@
+#!/usr/bin/env python3
+"""
+Generates `tests/api.rs` from the API snapshot in `tests/api.txt`.
+
+The generated test assigns every listed item to a binding of the listed type,
+so it fails to compile if an item is removed or its signature changes, and
+checks that `tests/api.txt` has not changed since it was generated. Run it
+from the root of the repository after changing the snapshot:
+
+    python3 devel/generate_api_test.py
+
+Items that are removed or change type, compared to the snapshot in `HEAD`,
+are breaking changes, and must be mentioned by name in the unreleased section
+of `CHANGELOG.md`. Pass `--force` to skip this check.
+"""
+
+import re
+import subprocess
+import sys
+
+SNAPSHOT = 'tests/api.txt'
+OUTPUT = 'tests/api.rs'
+CHANGELOG = 'CHANGELOG.md'
+
+HEADER = '''\
+//! Checks that every item in `tests/api.txt` exists with the listed type.
+//!
+//! This file is generated by `devel/generate_api_test.py`. Do not edit it:
+//! change `tests/api.txt` and run the script instead.
+
+#![allow(unused_imports, unexpected_cfgs, clippy::type_complexity)]
+
+use float16::*;
+
+/// The FNV-1a hash of `tests/api.txt` when this file was generated, ignoring
+/// carriage returns.
+const SNAPSHOT_HASH: u64 = {hash:#018x};
+
+#[test]
+fn snapshot_is_current() {{
+    let mut hash = 0xcbf2_9ce4_8422_2325u64;
+    for &byte in include_bytes!("api.txt").iter().filter(|&&b| b != b'\\r') {{
+        hash = (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3);
+    }}
+    assert_eq!(
+        hash, SNAPSHOT_HASH,
+        "`tests/api.txt` changed, run `python3 devel/generate_api_test.py`"
+    );
+}}
+
+#[test]
+#[rustfmt::skip]
+fn items_exist() {{
+{items}}}
+'''
+
+
+def fnv1a(data):
+    '''Hashes the bytes the same way as `snapshot_is_current`.'''
+    value = 0xcbf29ce484222325
+    for byte in data:
+        if byte != ord('\r'):
+            value = ((value ^ byte) * 0x100000001b3) % (1 << 64)
+    return value
+
+
+def parse(text):
+    '''Returns the `(cfg, path, type)` of each item, keyed by the path.'''
+    items = {}
+    for number, line in enumerate(text.splitlines(), 1):
+        line = line.strip()
+        if not line or line.startswith('//'):
+            continue
+        match = re.fullmatch(r'(#\[cfg\(.*\)\] )?(.+?): (.+)', line)
+        if match is None:
+            sys.exit(f'{SNAPSHOT}:{number}: expected `path: type`, got `{line}`')
+        cfg, path, ty = match.groups()
+        if path in items:
+            sys.exit(f'{SNAPSHOT}:{number}: `{path}` is listed twice')
+        items[path] = ((cfg or '').strip(), path, ty)
+    return items
+
+
+def check_changelog(items):
+    '''Exits if an item removed or changed since `HEAD` is not in the changelog.'''
+    try:
+        previous = subprocess.run(
+            ['git', 'show', f'HEAD:{SNAPSHOT}'],
+            capture_output=True,
+            check=True,
+            text=True,
+        ).stdout
+    except (OSError, subprocess.CalledProcessError):
+        return
+    changelog = open(CHANGELOG, encoding='utf-8').read()
+    start = changelog.index('## [Unreleased]')
+    end = changelog.find('\n## [', start + 1)
+    unreleased = changelog[start:end if end != -1 else len(changelog)]
+
+    missing = []
+    for path, item in parse(previous).items():
+        if items.get(path) == item:
+            continue
+        name = re.sub(r'<\[?(\w+)\]? as \w+>', r'\1', path).split('::<')[0]
+        if name not in unreleased and name.split('::')[-1] not in unreleased:
+            missing.append(f'{item[1]}: {item[2]}')
+    if missing:
+        print(f'removed or changed items not mentioned in {CHANGELOG}:', file=sys.stderr)
+        for item in missing:
+            print(f'    {item}', file=sys.stderr)
+        sys.exit(1)
+
+
+def main():
+    data = open(SNAPSHOT, 'rb').read()
+    items = parse(data.decode('utf-8'))
+    if '--force' not in sys.argv[1:]:
+        check_changelog(items)
+
+    lines = []
+    for cfg, path, ty in items.values():
+        if cfg:
+            lines.append(f'    {cfg}\n')
+        lines.append(f'    let _: {ty} = {path};\n')
+    output = HEADER.format(hash=fnv1a(data), items=''.join(lines))
+    with open(OUTPUT, 'w', encoding='utf-8', newline='\n') as file:
+        file.write(output)
+
+
+if __name__ == '__main__':
+    main()
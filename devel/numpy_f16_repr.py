@@ -0,0 +1,165 @@
+#!/usr/bin/env python3
+"""
+Generates the corpus of NumPy's `float16` formatting, which the
+`numpy_repr_corpus` test compares against `f16::to_numpy_repr_string`.
+
+Each line is the bits of a value in hexadecimal, followed by a space and
+`str(numpy.float16(value))`, for all 65536 bit patterns. Run it from the
+repository root:
+
+    python3 devel/numpy_f16_repr.py > tests/data/numpy_f16_repr.txt
+
+NumPy is used when it is installed. Otherwise, the values are formatted by a
+port of the unique mode of Dragon4 from NumPy's `dragon4.c`, along with the
+choice between positional and scientific notation and the trimming of
+`str` for floating-point scalars from `scalartypes.c`.
+"""
+
+import math
+
+
+def dragon4(mantissa, exponent, mantissa_bit, unequal_margins):
+    """
+    Returns the shortest digits that uniquely identify `mantissa * 2^exponent`,
+    with exclusive margins, and the exponent of the first digit.
+    """
+
+    if unequal_margins:
+        if exponent > 0:
+            value = 4 * mantissa << exponent
+            scale = 4
+            margin_low = 1 << exponent
+            margin_high = 1 << (exponent + 1)
+        else:
+            value = 4 * mantissa
+            scale = 1 << (-exponent + 2)
+            margin_low = 1
+            margin_high = 2
+    else:
+        if exponent > 0:
+            value = 2 * mantissa << exponent
+            scale = 2
+            margin_low = 1 << exponent
+        else:
+            value = 2 * mantissa
+            scale = 1 << (-exponent + 1)
+            margin_low = 1
+        margin_high = margin_low
+
+    log10_2 = 0.30102999566398119521373889472449302676818988
+    digit_exponent = math.ceil((mantissa_bit + exponent) * log10_2 - 0.69)
+    if digit_exponent > 0:
+        scale *= 10**digit_exponent
+    elif digit_exponent < 0:
+        power = 10**-digit_exponent
+        value *= power
+        margin_low *= power
+        margin_high *= power
+
+    if value >= scale:
+        digit_exponent += 1
+    else:
+        value *= 10
+        margin_low *= 10
+        margin_high *= 10
+    out_exponent = digit_exponent - 1
+
+    digits = []
+    while True:
+        digit, value = divmod(value, scale)
+        low = value < margin_low
+        high = value + margin_high > scale
+        if low or high:
+            break
+        digits.append(digit)
+        value *= 10
+        margin_low *= 10
+        margin_high *= 10
+
+    round_down = low
+    if low == high:
+        compare = 2 * value - scale
+        round_down = compare < 0
+        if compare == 0:
+            round_down = digit & 1 == 0
+
+    if round_down:
+        digits.append(digit)
+    elif digit != 9:
+        digits.append(digit + 1)
+    else:
+        while digits and digits[-1] == 9:
+            digits.pop()
+        if digits:
+            digits[-1] += 1
+        else:
+            digits.append(1)
+            out_exponent += 1
+    return ''.join(map(str, digits)), out_exponent
+
+
+def format_positional(digits, exponent):
+    """Formats the digits positionally, leaving one zero after the point."""
+
+    if exponent >= 0:
+        whole = digits[:exponent + 1].ljust(exponent + 1, '0')
+        fraction = digits[exponent + 1:]
+    else:
+        whole = '0'
+        fraction = '0' * (-exponent - 1) + digits
+    return f'{whole}.{fraction.rstrip("0") or "0"}'
+
+
+def format_scientific(digits, exponent):
+    """Formats the digits in scientific notation, trimming the point."""
+
+    fraction = digits[1:].rstrip('0')
+    mantissa = f'{digits[0]}.{fraction}' if fraction else digits[0]
+    sign = '-' if exponent < 0 else '+'
+    return f'{mantissa}e{sign}{abs(exponent):02}'
+
+
+def f16_str(bits):
+    """Returns `str(numpy.float16(value))` for the bits of a value."""
+
+    sign = '-' if bits & 0x8000 else ''
+    biased = (bits >> 10) & 0x1F
+    fraction = bits & 0x3FF
+    if biased == 0x1F:
+        return 'nan' if fraction else f'{sign}inf'
+    if biased == 0 and fraction == 0:
+        return f'{sign}0.0'
+
+    if biased != 0:
+        mantissa = (1 << 10) | fraction
+        exponent = biased - 15 - 10
+        mantissa_bit = 10
+        unequal_margins = biased != 1 and fraction == 0
+    else:
+        mantissa = fraction
+        exponent = 1 - 15 - 10
+        mantissa_bit = fraction.bit_length() - 1
+        unequal_margins = False
+
+    digits, digit_exponent = dragon4(mantissa, exponent, mantissa_bit, unequal_margins)
+    value = mantissa * 2.0**exponent
+    if 1e-4 <= value < 1e16:
+        return sign + format_positional(digits, digit_exponent)
+    return sign + format_scientific(digits, digit_exponent)
+
+
+def main():
+    try:
+        import numpy as np
+    except ImportError:
+        for bits in range(1 << 16):
+            print(f'{bits:04x} {f16_str(bits)}')
+        return
+
+    bits = np.arange(1 << 16, dtype=np.uint16)
+    for b, value in zip(bits, bits.view(np.float16)):
+        print(f'{int(b):04x} {value}')
+
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,176 @@
+//! Implementations of the [`approx`](https://docs.rs/approx/) traits for
+//! [`struct@f16`] and [`struct@bf16`].
+//!
+//! The default epsilon is the type's `EPSILON`, and differences are computed
+//! in [`f32`] precision, so they are exact. Unlike the implementations for the
+//! primitive floats, the ulps comparison uses
+//! [`ulp_distance`][crate::f16::ulp_distance], so values on opposite sides of
+//! zero are compared by the number of values between them instead of never
+//! being equal.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use float16::*;
+//! use approx::{assert_abs_diff_eq, assert_relative_eq, assert_ulps_eq};
+//!
+//! let x = f16::from_f32(0.1) + f16::from_f32(0.2);
+//! assert_relative_eq!(x, f16::from_f32(0.3));
+//! assert_abs_diff_eq!(bf16::from_f32(1.0), bf16::from_f32(1.01), epsilon = bf16::from_f32(0.02));
+//! assert_ulps_eq!(f16::ONE, f16::ONE + f16::EPSILON, max_ulps = 1);
+//! ```
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::{bf16, f16};
+
+macro_rules! impl_approx {
+    ($half:ident) => {
+        impl AbsDiffEq for $half {
+            type Epsilon = $half;
+
+            #[inline]
+            fn default_epsilon() -> Self::Epsilon {
+                Self::EPSILON
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                let (a, b) = (self.to_f32(), other.to_f32());
+                let diff = a - b;
+                let epsilon = epsilon.to_f32();
+                a == b || (-epsilon <= diff && diff <= epsilon)
+            }
+        }
+
+        impl RelativeEq for $half {
+            #[inline]
+            fn default_max_relative() -> Self::Epsilon {
+                Self::EPSILON
+            }
+
+            #[inline]
+            fn relative_eq(
+                &self,
+                other: &Self,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> bool {
+                if self.to_f32() == other.to_f32() {
+                    return true;
+                }
+                if self.is_infinite() || other.is_infinite() {
+                    return false;
+                }
+                if self.abs_diff_eq(other, epsilon) {
+                    return true;
+                }
+                let diff = self.to_f32() - other.to_f32();
+                let diff = if diff < 0.0 {
+                    -diff
+                } else {
+                    diff
+                };
+                let largest = self.abs().max(other.abs()).to_f32();
+                diff <= largest * max_relative.to_f32()
+            }
+        }
+
+        impl UlpsEq for $half {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                4
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                let max_ulps = if max_ulps > u16::MAX as u32 {
+                    u16::MAX
+                } else {
+                    max_ulps as u16
+                };
+                self.abs_diff_eq(other, epsilon) || $half::ulps_eq(*self, *other, max_ulps)
+            }
+        }
+    };
+}
+
+impl_approx!(f16);
+impl_approx!(bf16);
+
+#[cfg(test)]
+mod test {
+    use approx::{assert_relative_ne, assert_ulps_ne};
+
+    use super::*;
+
+    #[test]
+    fn test_abs_diff_eq() {
+        let x = f16::from_f32(1.0);
+        assert!(x.abs_diff_eq(&(x + f16::EPSILON), f16::EPSILON));
+        assert!(!x.abs_diff_eq(&(x + f16::EPSILON), f16::ZERO));
+        assert!(f16::INFINITY.abs_diff_eq(&f16::INFINITY, f16::ZERO));
+        assert!(!f16::NAN.abs_diff_eq(&f16::NAN, f16::MAX));
+        assert!(!f16::MAX.abs_diff_eq(&f16::MIN, f16::MAX));
+        assert!(f16::MAX.abs_diff_eq(&f16::MIN, f16::INFINITY));
+        assert!(bf16::from_f32(-2.).abs_diff_eq(&bf16::from_f32(-2.5), bf16::from_f32(0.5)));
+        assert!(!bf16::from_f32(-2.).abs_diff_eq(&bf16::from_f32(-2.5), bf16::from_f32(0.25)));
+    }
+
+    #[test]
+    fn test_relative_eq() {
+        let x = f16::from_f32(1000.);
+        let y = f16::from_f32(1001.);
+        assert!(x.relative_eq(&y, f16::ZERO, f16::from_f32(0.001)));
+        assert!(!x.relative_eq(&y, f16::ZERO, f16::from_f32(0.0005)));
+        assert_relative_ne!(x, y);
+        assert!(!f16::INFINITY.relative_eq(&f16::MAX, f16::MAX, f16::MAX));
+        assert!(f16::NEG_INFINITY.relative_eq(&f16::NEG_INFINITY, f16::ZERO, f16::ZERO));
+        assert!(!bf16::NAN.relative_eq(&bf16::NAN, bf16::MAX, bf16::MAX));
+        assert!(bf16::from_f32(100.).relative_eq(
+            &bf16::from_f32(101.),
+            bf16::ZERO,
+            bf16::from_f32(0.01)
+        ));
+    }
+
+    #[test]
+    fn test_ulps_eq_crossing_zero() {
+        // The smallest subnormals of opposite signs are 2 ulps apart, since
+        // `-0` and `+0` are the same value.
+        let tiny = f16::from_bits(1);
+        assert!(UlpsEq::ulps_eq(&tiny, &-tiny, f16::ZERO, 2));
+        assert!(!UlpsEq::ulps_eq(&tiny, &-tiny, f16::ZERO, 1));
+        assert!(UlpsEq::ulps_eq(&f16::ZERO, &f16::NEG_ZERO, f16::ZERO, 0));
+        assert!(UlpsEq::ulps_eq(&tiny, &f16::NEG_ZERO, f16::ZERO, 1));
+
+        let tiny = bf16::from_bits(1);
+        assert!(UlpsEq::ulps_eq(&-tiny, &tiny, bf16::ZERO, 2));
+        assert!(!UlpsEq::ulps_eq(&-tiny, &tiny, bf16::ZERO, 1));
+        assert!(UlpsEq::ulps_eq(&bf16::NEG_ZERO, &bf16::ZERO, bf16::ZERO, 0));
+
+        // Within epsilon, even when many ulps apart.
+        let x = f16::MIN_POSITIVE;
+        assert!(UlpsEq::ulps_eq(&x, &-x, f16::EPSILON, 0));
+        assert_ulps_ne!(x, -x, epsilon = f16::ZERO);
+    }
+
+    #[test]
+    fn test_ulps_eq() {
+        let x = f16::ONE;
+        let y = f16::from_bits(x.to_bits() + 4);
+        assert!(UlpsEq::ulps_eq(&x, &y, f16::ZERO, f16::default_max_ulps()));
+        assert!(!UlpsEq::ulps_eq(&x, &y, f16::ZERO, 3));
+        assert!(!UlpsEq::ulps_eq(&f16::MAX, &f16::INFINITY, f16::ZERO, u32::MAX));
+        assert!(!UlpsEq::ulps_eq(&f16::NAN, &f16::NAN, f16::ZERO, u32::MAX));
+        assert!(!UlpsEq::ulps_eq(&bf16::MIN, &bf16::MAX, bf16::ZERO, 0x7F00));
+        assert!(UlpsEq::ulps_eq(&bf16::MIN, &bf16::MAX, bf16::ZERO, u32::MAX));
+    }
+
+    #[test]
+    fn test_defaults() {
+        assert_eq!(f16::default_epsilon(), f16::EPSILON);
+        assert_eq!(bf16::default_max_relative(), bf16::EPSILON);
+        assert_eq!(bf16::default_max_ulps(), 4);
+    }
+}
@@ -0,0 +1,361 @@
+//! Atomic storage for [`struct@f16`] and [`struct@bf16`] values.
+//!
+//! These wrap an [`AtomicU16`] holding the bits of the value, and are only
+//! available on targets that support 16-bit atomics. Loads, stores, swaps and
+//! compare-and-exchange operate on the bits directly, while arithmetic
+//! read-modify-write operations are compare-and-exchange loops that compute
+//! the new value in [`f32`] precision, the same as the arithmetic operators.
+
+use core::fmt::{self, Debug, Formatter};
+use core::sync::atomic::{AtomicU16, Ordering};
+
+use crate::{bf16, f16};
+
+macro_rules! atomic_half {
+    ($atomic:ident, $half:ident, $name:literal) => {
+        #[doc = concat!("A [`struct@", $name, "`] which can be safely shared between threads.")]
+        ///
+        #[doc = concat!(
+            "This has the same in-memory representation as a [`struct@", $name,
+            "`] and an [`AtomicU16`]. Every operation takes [`Ordering`] arguments with the same \
+            meaning as the equivalent operation on [`AtomicU16`]."
+        )]
+        #[repr(transparent)]
+        pub struct $atomic {
+            bits: AtomicU16,
+        }
+
+        impl $atomic {
+            /// Creates a new atomic value.
+            ///
+            /// # Examples
+            ///
+            /// ```rust
+            /// # use float16::*;
+            #[doc = concat!("let atomic = ", stringify!($atomic), "::new(", $name, "::ONE);")]
+            /// ```
+            #[inline]
+            #[must_use]
+            pub const fn new(value: $half) -> Self {
+                Self {
+                    bits: AtomicU16::new(value.to_bits()),
+                }
+            }
+
+            /// Returns a mutable reference to the underlying value.
+            ///
+            /// This is safe because the mutable reference guarantees that no
+            /// other threads are concurrently accessing the atomic value.
+            #[inline]
+            pub fn get_mut(&mut self) -> &mut $half {
+                // SAFETY: `AtomicU16` has the same in-memory representation as
+                // `u16`, which has the same representation as the half type.
+                unsafe { &mut *(self.bits.get_mut() as *mut u16).cast::<$half>() }
+            }
+
+            /// Consumes the atomic and returns the contained value.
+            #[inline]
+            #[must_use]
+            pub fn into_inner(self) -> $half {
+                $half::from_bits(self.bits.into_inner())
+            }
+
+            /// Loads the value.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `order` is [`Release`][Ordering::Release] or
+            /// [`AcqRel`][Ordering::AcqRel].
+            ///
+            /// # Examples
+            ///
+            /// ```rust
+            /// # use float16::*;
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($atomic), "::new(", $name, "::ONE);")]
+            #[doc = concat!("assert_eq!(atomic.load(Ordering::Relaxed), ", $name, "::ONE);")]
+            /// ```
+            #[inline]
+            pub fn load(&self, order: Ordering) -> $half {
+                $half::from_bits(self.bits.load(order))
+            }
+
+            /// Stores a value.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `order` is [`Acquire`][Ordering::Acquire] or
+            /// [`AcqRel`][Ordering::AcqRel].
+            #[inline]
+            pub fn store(&self, value: $half, order: Ordering) {
+                self.bits.store(value.to_bits(), order)
+            }
+
+            /// Stores a value, returning the previous value.
+            #[inline]
+            pub fn swap(&self, value: $half, order: Ordering) -> $half {
+                $half::from_bits(self.bits.swap(value.to_bits(), order))
+            }
+
+            /// Stores `new` if the current value has the same bits as
+            /// `current`.
+            ///
+            /// The comparison is on the bits of the values rather than
+            /// floating point equality, so a NaN matches the same NaN and `0`
+            /// does not match `-0`. Returns the previous value, wrapped in
+            /// `Ok` if it was replaced and `Err` otherwise.
+            ///
+            /// # Examples
+            ///
+            /// ```rust
+            /// # use float16::*;
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($atomic), "::new(", $name, "::NAN);")]
+            #[doc = concat!(
+                "assert!(atomic.compare_exchange(", $name, "::NAN, ", $name,
+                "::ONE, Ordering::AcqRel, Ordering::Acquire).is_ok());"
+            )]
+            #[doc = concat!(
+                "assert!(atomic.compare_exchange(", $name, "::ZERO, ", $name,
+                "::ONE, Ordering::AcqRel, Ordering::Acquire).is_err());"
+            )]
+            /// ```
+            #[inline]
+            pub fn compare_exchange(
+                &self,
+                current: $half,
+                new: $half,
+                success: Ordering,
+                failure: Ordering,
+            ) -> Result<$half, $half> {
+                self.bits
+                    .compare_exchange(current.to_bits(), new.to_bits(), success, failure)
+                    .map($half::from_bits)
+                    .map_err($half::from_bits)
+            }
+
+            /// Stores `new` if the current value has the same bits as
+            /// `current`, but may spuriously fail even when the comparison
+            /// succeeds.
+            ///
+            #[doc = concat!(
+                "See [`compare_exchange`][", stringify!($atomic), "::compare_exchange] \
+                for details."
+            )]
+            #[inline]
+            pub fn compare_exchange_weak(
+                &self,
+                current: $half,
+                new: $half,
+                success: Ordering,
+                failure: Ordering,
+            ) -> Result<$half, $half> {
+                self.bits
+                    .compare_exchange_weak(current.to_bits(), new.to_bits(), success, failure)
+                    .map($half::from_bits)
+                    .map_err($half::from_bits)
+            }
+
+            /// Fetches the value and applies a function to it that returns an
+            /// optional new value, retrying until the update succeeds or the
+            /// function returns `None`.
+            ///
+            /// `set_order` is the ordering when the update succeeds, and
+            /// `fetch_order` is the ordering of loads. Returns the previous
+            /// value, wrapped in `Ok` if it was updated and `Err` otherwise.
+            #[inline]
+            pub fn fetch_update<F>(
+                &self,
+                set_order: Ordering,
+                fetch_order: Ordering,
+                mut f: F,
+            ) -> Result<$half, $half>
+            where
+                F: FnMut($half) -> Option<$half>,
+            {
+                self.bits
+                    .fetch_update(set_order, fetch_order, |bits| {
+                        f($half::from_bits(bits)).map($half::to_bits)
+                    })
+                    .map($half::from_bits)
+                    .map_err($half::from_bits)
+            }
+
+            /// Adds to the current value, returning the previous value.
+            ///
+            /// The sum is computed in [`f32`] precision and rounded once, the
+            /// same as the `+` operator. This is a compare-and-exchange loop,
+            /// where `order` is the ordering of the successful update and
+            /// loads are [`Relaxed`][Ordering::Relaxed], or
+            /// [`Acquire`][Ordering::Acquire] if `order` is
+            /// [`AcqRel`][Ordering::AcqRel] or [`SeqCst`][Ordering::SeqCst].
+            ///
+            /// # Examples
+            ///
+            /// ```rust
+            /// # use float16::*;
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($atomic), "::new(", $name, "::ONE);")]
+            #[doc = concat!("assert_eq!(atomic.fetch_add(", $name, "::ONE, Ordering::SeqCst), ", $name, "::ONE);")]
+            #[doc = concat!("assert_eq!(atomic.load(Ordering::SeqCst), ", $name, "::from_f32(2.0));")]
+            /// ```
+            #[inline]
+            pub fn fetch_add(&self, value: $half, order: Ordering) -> $half {
+                self.fetch_update_infallible(order, |current| current + value)
+            }
+
+            /// Sets the current value to the maximum of it and `value`,
+            /// returning the previous value.
+            ///
+            #[doc = concat!(
+                "The maximum is the same as [`", $name, "::max`], so a NaN `value` \
+                leaves the current value unchanged, while a NaN current value is always \
+                replaced. This is a compare-and-exchange loop with the same orderings as \
+                [`fetch_add`][", stringify!($atomic), "::fetch_add]."
+            )]
+            ///
+            /// # Examples
+            ///
+            /// ```rust
+            /// # use float16::*;
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($atomic), "::new(", $name, "::NAN);")]
+            #[doc = concat!("atomic.fetch_max(", $name, "::ONE, Ordering::SeqCst);")]
+            #[doc = concat!("atomic.fetch_max(", $name, "::NAN, Ordering::SeqCst);")]
+            #[doc = concat!("assert_eq!(atomic.load(Ordering::SeqCst), ", $name, "::ONE);")]
+            /// ```
+            #[inline]
+            pub fn fetch_max(&self, value: $half, order: Ordering) -> $half {
+                self.fetch_update_infallible(order, |current| current.max(value))
+            }
+
+            #[inline]
+            fn fetch_update_infallible<F>(&self, order: Ordering, mut f: F) -> $half
+            where
+                F: FnMut($half) -> $half,
+            {
+                let fetch_order = match order {
+                    Ordering::AcqRel | Ordering::SeqCst => Ordering::Acquire,
+                    _ => Ordering::Relaxed,
+                };
+                match self.fetch_update(order, fetch_order, |current| Some(f(current))) {
+                    Ok(previous) | Err(previous) => previous,
+                }
+            }
+        }
+
+        impl Default for $atomic {
+            #[inline]
+            fn default() -> Self {
+                Self::new($half::default())
+            }
+        }
+
+        impl From<$half> for $atomic {
+            #[inline]
+            fn from(value: $half) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl Debug for $atomic {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                Debug::fmt(&self.load(Ordering::Relaxed), f)
+            }
+        }
+    };
+}
+
+atomic_half!(AtomicF16, f16, "f16");
+atomic_half!(AtomicBf16, bf16, "bf16");
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_atomic_f16() {
+        let atomic = AtomicF16::new(f16::ONE);
+        assert_eq!(atomic.swap(f16::NEG_ZERO, Ordering::SeqCst), f16::ONE);
+        // Bit equality distinguishes zeros and matches NaNs.
+        assert!(atomic
+            .compare_exchange(f16::ZERO, f16::NAN, Ordering::SeqCst, Ordering::SeqCst)
+            .is_err());
+        assert!(atomic
+            .compare_exchange(f16::NEG_ZERO, f16::NAN, Ordering::SeqCst, Ordering::SeqCst)
+            .is_ok());
+        assert!(atomic
+            .compare_exchange(f16::NAN, f16::ONE, Ordering::SeqCst, Ordering::SeqCst)
+            .is_ok());
+
+        assert_eq!(atomic.fetch_add(f16::from_f32(0.5), Ordering::SeqCst), f16::ONE);
+        assert_eq!(atomic.load(Ordering::SeqCst), f16::from_f32(1.5));
+        atomic.fetch_add(f16::MAX, Ordering::SeqCst);
+        atomic.fetch_add(f16::MAX, Ordering::SeqCst);
+        assert_eq!(atomic.load(Ordering::SeqCst), f16::INFINITY);
+
+        // `fetch_max` ignores NaN values, but replaces a NaN current value.
+        atomic.store(f16::ONE, Ordering::SeqCst);
+        assert_eq!(atomic.fetch_max(f16::NAN, Ordering::SeqCst), f16::ONE);
+        assert_eq!(atomic.load(Ordering::SeqCst), f16::ONE);
+        atomic.fetch_max(f16::from_f32(-2.0), Ordering::SeqCst);
+        assert_eq!(atomic.load(Ordering::SeqCst), f16::ONE);
+        atomic.store(f16::NAN, Ordering::SeqCst);
+        assert!(atomic.fetch_max(f16::from_f32(-2.0), Ordering::SeqCst).is_nan());
+        assert_eq!(atomic.load(Ordering::SeqCst), f16::from_f32(-2.0));
+
+        let mut atomic = AtomicF16::default();
+        *atomic.get_mut() = f16::E;
+        assert_eq!(atomic.into_inner(), f16::E);
+    }
+
+    #[test]
+    fn test_atomic_bf16() {
+        let atomic = AtomicBf16::from(bf16::ONE);
+        assert_eq!(atomic.fetch_add(bf16::ONE, Ordering::Relaxed), bf16::ONE);
+        assert_eq!(atomic.fetch_max(bf16::NAN, Ordering::AcqRel), bf16::from_f32(2.0));
+        assert_eq!(atomic.fetch_max(bf16::from_f32(3.0), Ordering::AcqRel), bf16::from_f32(2.0));
+        assert!(atomic
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |x| (x > bf16::ONE).then(|| -x))
+            .is_ok());
+        assert_eq!(atomic.into_inner(), bf16::from_f32(-3.0));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_atomic_threads() {
+        use std::sync::Arc;
+        use std::thread;
+
+        const THREADS: usize = 8;
+        const ITERATIONS: usize = 200;
+
+        let sum = Arc::new(AtomicF16::new(f16::ZERO));
+        let max = Arc::new(AtomicBf16::new(bf16::NEG_INFINITY));
+        let handles: Vec<_> = (0..THREADS)
+            .map(|t| {
+                let sum = Arc::clone(&sum);
+                let max = Arc::clone(&max);
+                thread::spawn(move || {
+                    for i in 0..ITERATIONS {
+                        sum.fetch_add(f16::from_f32(0.25), Ordering::Relaxed);
+                        max.fetch_max(bf16::from((t * ITERATIONS + i) as u8), Ordering::Relaxed);
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        // Every partial sum is a multiple of 0.25 below 512, so all of the
+        // additions are exact and none can be lost.
+        let expected = (THREADS * ITERATIONS) as f32 * 0.25;
+        assert_eq!(sum.load(Ordering::SeqCst).to_f32(), expected);
+        assert_eq!(max.load(Ordering::SeqCst), bf16::from(u8::MAX));
+    }
+}
@@ -1,8 +1,25 @@
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, vec::Vec};
+#[cfg(feature = "total-order-impls")]
+use core::hash::{Hash, Hasher};
 use core::{
     cmp::Ordering,
     iter::{Product, Sum},
     num::FpCategory,
-    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+    ops::{
+        Add,
+        AddAssign,
+        Div,
+        DivAssign,
+        Mul,
+        MulAssign,
+        Neg,
+        RangeInclusive,
+        Rem,
+        RemAssign,
+        Sub,
+        SubAssign,
+    },
 };
 #[cfg(not(target_arch = "spirv"))]
 use core::{
@@ -22,7 +39,23 @@ use core::{
     str::FromStr,
 };
 
-use crate::error::TryFromFloatError;
+use crate::binary16::arch;
+#[cfg(not(target_arch = "spirv"))]
+use crate::decimal;
+#[cfg(feature = "alloc")]
+use crate::error::OddLength;
+use crate::error::{ParseHalfError, TryFromFloatError, TryFromHalfError, TryFromHalfErrorKind};
+use crate::exact;
+use crate::f16;
+use crate::fixed;
+use crate::hex;
+#[cfg(not(target_arch = "spirv"))]
+use crate::hex::HexFloat;
+use crate::leading_zeros::leading_zeros_u16;
+use crate::minifloat;
+use crate::rounding::RoundingMode;
+#[cfg(not(target_arch = "spirv"))]
+use crate::shortest;
 use crate::try_from::try_from_lossless;
 
 pub(crate) mod convert;
@@ -35,6 +68,13 @@ pub(crate) mod convert;
 /// than [`f16`][crate::f16]. While [`f16`][crate::f16] has a precision of
 /// 11 bits, [`struct@bf16`] has a precision of only 8 bits.
 ///
+/// # Math functions
+///
+/// The math functions that need the `std` feature, such as `exp`, `ln` and
+/// `sin`, are computed in [`f32`] and then rounded to [`struct@bf16`]. The
+/// [`f32`] result is already rounded, so in rare cases the result differs
+/// from the correctly rounded value by double rounding.
+///
 /// [`bfloat16`]: https://en.wikipedia.org/wiki/Bfloat16_floating-point_format
 #[repr(C)]
 #[allow(non_camel_case_types)]
@@ -53,8 +93,9 @@ impl bf16 {
     /// Constructs a [`struct@bf16`] value from a 32-bit floating point value.
     ///
     /// This operation is lossy. If the 32-bit value is too large to fit, ±∞
-    /// will result. NaN values are preserved. Subnormal values that are too
-    /// tiny to be represented will result in ±0. All other values are
+    /// will result. NaN values are preserved, although signaling NaN values
+    /// are quieted, as with [`quiet`][Self::quiet]. Subnormal values that are
+    /// too tiny to be represented will result in ±0. All other values are
     /// truncated and rounded to the nearest representable value.
     #[inline]
     #[must_use]
@@ -76,7 +117,129 @@ impl bf16 {
     #[inline]
     #[must_use]
     pub const fn from_f32_const(value: f32) -> bf16 {
-        bf16(convert::f32_to_bf16(value))
+        bf16(convert::canonical_nan(convert::f32_to_bf16(value)))
+    }
+
+    /// Constructs a [`struct@bf16`] value from a 32-bit floating point value,
+    /// rounding with the given [`RoundingMode`].
+    ///
+    /// This is identical to [`from_f32_const`][Self::from_f32_const] with
+    /// [`RoundingMode::NearestEven`], and [`RoundingMode::TowardZero`]
+    /// truncates the low 16 bits of the [`f32`]. The directed modes also apply
+    /// to overflow: values too large to fit only result in ±∞ if the mode
+    /// rounds away from zero, and would otherwise be the largest finite value
+    /// of the same sign. NaN values are preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let third = 1.0 / 3.0;
+    /// assert_eq!(bf16::from_f32_round(third, RoundingMode::TowardZero).to_bits(), 0x3EAA);
+    /// assert_eq!(bf16::from_f32_round(third, RoundingMode::TowardPositive).to_bits(), 0x3EAB);
+    ///
+    /// assert_eq!(bf16::from_f32_round(f32::MAX, RoundingMode::NearestEven), bf16::INFINITY);
+    /// assert_eq!(bf16::from_f32_round(f32::MAX, RoundingMode::TowardZero), bf16::MAX);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_f32_round(value: f32, mode: RoundingMode) -> bf16 {
+        bf16(convert::canonical_nan(convert::f32_to_bf16_round(value, mode)))
+    }
+
+    /// Constructs a [`struct@bf16`] value from a 32-bit floating point value
+    /// with stochastic rounding, using the caller's `random` bits.
+    ///
+    /// The value rounds away from zero with a probability proportional to the
+    /// distance from the value below it, so the expected result is the
+    /// original value. This adds `random` to the 16 low mantissa bits that
+    /// are dropped, then truncates, so `random` should be uniformly
+    /// distributed. The result is exactly reproducible for the same `value`
+    /// and `random`: `0` always rounds toward zero, like
+    /// [`from_f32_truncate`][Self::from_f32_truncate].
+    ///
+    /// A carry out of the mantissa increments the exponent, so values above
+    /// [`MAX`][Self::MAX] round stochastically between it and ±∞, and
+    /// subnormals round stochastically to the smallest normal values. NaN
+    /// values are quieted the same as in [`from_f32`][Self::from_f32].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// // The dropped bits are a quarter of the way to the next value.
+    /// let x = f32::from_bits(0x3F80_4000);
+    /// assert_eq!(bf16::from_f32_stochastic(x, 0), bf16::ONE);
+    /// assert_eq!(bf16::from_f32_stochastic(x, 0xBFFF), bf16::ONE);
+    /// assert_eq!(bf16::from_f32_stochastic(x, 0xC000), bf16::ONE + bf16::EPSILON);
+    ///
+    /// // A quarter of all random values round up.
+    /// let up = (0..=u16::MAX).filter(|&r| bf16::from_f32_stochastic(x, r) > bf16::ONE).count();
+    /// assert_eq!(up, 0x4000);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_f32_stochastic(value: f32, random: u16) -> bf16 {
+        bf16(convert::canonical_nan(convert::f32_to_bf16_stochastic(value, random)))
+    }
+
+    /// Constructs a [`struct@bf16`] value from a 32-bit floating point value
+    /// by dropping the low 16 bits, without rounding.
+    ///
+    /// This is the conversion used by many machine learning frameworks, and
+    /// is identical to [`from_f32_round`][Self::from_f32_round] with
+    /// [`RoundingMode::TowardZero`]. The result is up to 1 ulp closer to zero
+    /// than [`from_f32`][Self::from_f32], and finite values never overflow to
+    /// ±∞. NaN values are quieted by setting the most significant mantissa bit,
+    /// so a NaN whose payload is only in the dropped bits never becomes an
+    /// infinity or a signaling NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// // The dropped bits are more than half an ulp, so `from_f32` rounds up.
+    /// let x = f32::from_bits(0x3F80_C000);
+    /// assert_eq!(bf16::from_f32_truncate(x).to_bits(), 0x3F80);
+    /// assert_eq!(bf16::from_f32(x).to_bits(), 0x3F81);
+    ///
+    /// assert_eq!(bf16::from_f32_truncate(f32::MAX), bf16::MAX);
+    /// assert!(bf16::from_f32_truncate(f32::from_bits(0x7F80_0001)).is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_f32_truncate(value: f32) -> bf16 {
+        bf16(convert::canonical_nan(convert::f32_to_bf16_round(value, RoundingMode::TowardZero)))
+    }
+
+    /// Constructs a [`struct@bf16`] value from a 32-bit floating point value
+    /// that is not NaN, skipping the handling of NaN values.
+    ///
+    /// This is rounded to the nearest representable value, identically to
+    /// [`from_f32`][Self::from_f32], but avoids the branch needed to preserve
+    /// NaN values, which makes it faster in hot loops where the inputs are
+    /// known to not be NaN. Unlike
+    /// [`f16::from_f32_unchecked_normal`][crate::f16::from_f32_unchecked_normal],
+    /// every other value is valid, since [`struct@bf16`] has the same
+    /// exponent range as [`f32`].
+    ///
+    /// # Safety
+    ///
+    /// `value` must not be NaN. The result is unspecified otherwise. This is
+    /// checked with a debug assertion.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let value = unsafe { bf16::from_f32_unchecked_normal(3.14159) };
+    /// assert_eq!(value, bf16::from_f32(3.14159));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub unsafe fn from_f32_unchecked_normal(value: f32) -> bf16 {
+        debug_assert!(!value.is_nan(), "value is NaN");
+        bf16(convert::f32_to_bf16_non_nan(value))
     }
 
     /// Create a [`struct@bf16`] loslessly from an [`f32`].
@@ -134,6 +297,132 @@ impl bf16 {
         }
     }
 
+    /// Constructs a [`struct@bf16`] value from a 32-bit floating point value,
+    /// saturating to the finite range instead of overflowing to ±∞.
+    ///
+    /// This is identical to [`from_f32`][Self::from_f32], except finite
+    /// values that would round to ±∞, which are those at or above half-way
+    /// between [`MAX`][Self::MAX] and [`f32::MAX`], result in
+    /// [`MAX`][Self::MAX] or [`MIN`][Self::MIN] instead. Infinite values still
+    /// result in ±∞, and NaN values are preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(bf16::from_f32(f32::MAX), bf16::INFINITY);
+    /// assert_eq!(bf16::from_f32_saturating(f32::MAX), bf16::MAX);
+    /// assert_eq!(bf16::from_f32_saturating(f32::MIN), bf16::MIN);
+    /// assert_eq!(bf16::from_f32_saturating(f32::INFINITY), bf16::INFINITY);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_f32_saturating(value: f32) -> bf16 {
+        let result = Self::from_f32(value);
+        if result.is_infinite() && value.is_finite() {
+            if value.is_sign_negative() {
+                bf16::MIN
+            } else {
+                bf16::MAX
+            }
+        } else {
+            result
+        }
+    }
+
+    /// Constructs a 16-bit floating point value from a 32-bit floating point
+    /// value, clamping to the finite range.
+    ///
+    /// Values at or above [`MAX`][Self::MAX] result in [`MAX`][Self::MAX],
+    /// and values at or below [`MIN`][Self::MIN] result in
+    /// [`MIN`][Self::MIN], including ±∞, so the result is always finite
+    /// unless the value is NaN, which is preserved. Other values are the same
+    /// as [`from_f32`][Self::from_f32]. Unlike
+    /// [`from_f32_saturating`][Self::from_f32_saturating], this also clamps
+    /// infinite values, which suits pipelines such as HDR tone mapping, where
+    /// every later calculation should stay finite.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(bf16::from_f32(3.4e38), bf16::INFINITY);
+    /// assert_eq!(bf16::saturating_from_f32(3.4e38), bf16::MAX);
+    /// assert_eq!(bf16::saturating_from_f32(f32::INFINITY), bf16::MAX);
+    /// assert_eq!(bf16::saturating_from_f32(f32::NEG_INFINITY), bf16::MIN);
+    /// assert_eq!(bf16::saturating_from_f32(1.5), bf16::from_f32(1.5));
+    /// assert!(bf16::saturating_from_f32(f32::NAN).is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn saturating_from_f32(value: f32) -> bf16 {
+        Self::from_f32(value).clamp_infinite()
+    }
+
+    /// Constructs a 16-bit floating point value from a 64-bit floating point
+    /// value, clamping to the finite range.
+    ///
+    /// This is the same as [`saturating_from_f32`][Self::saturating_from_f32],
+    /// but rounds from the [`f64`] value once, like
+    /// [`from_f64`][Self::from_f64].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(bf16::saturating_from_f64(1e300), bf16::MAX);
+    /// assert_eq!(bf16::saturating_from_f64(f64::NEG_INFINITY), bf16::MIN);
+    /// assert_eq!(bf16::saturating_from_f64(-0.25), bf16::from_f64(-0.25));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn saturating_from_f64(value: f64) -> bf16 {
+        Self::from_f64(value).clamp_infinite()
+    }
+
+    /// Converts a number read from JSON, which is an [`f64`], and returns if
+    /// the conversion was exact.
+    ///
+    /// The value is rounded once, as with [`from_f64`][Self::from_f64], and
+    /// the flag is `true` only if the number is exactly the returned value,
+    /// so numbers that are rounded, overflow to ±∞ or underflow to ±0 are
+    /// flagged. This is cheaper than converting the number twice, to round it
+    /// and to check it with [`from_f64_lossless`][Self::from_f64_lossless].
+    /// JSON has no NaN or infinite numbers, but they are converted exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let (value, exact) = bf16::from_json_number(0.5);
+    /// assert_eq!((value, exact), (bf16::from_f32(0.5), true));
+    ///
+    /// // 0.1 is rounded, and 1e39 overflows to infinity.
+    /// assert_eq!(bf16::from_json_number(0.1), (bf16::from_f64(0.1), false));
+    /// assert_eq!(bf16::from_json_number(1e39), (bf16::INFINITY, false));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_json_number(n: f64) -> (bf16, bool) {
+        let value = Self::from_f64(n);
+        // Widening is exact, so this compares the number with the value.
+        (value, n.is_nan() || value.to_f64() == n)
+    }
+
+    /// Replaces ±∞ with [`MAX`][Self::MAX] or [`MIN`][Self::MIN].
+    #[inline]
+    const fn clamp_infinite(self) -> bf16 {
+        if self.is_infinite() {
+            if self.is_sign_negative() {
+                Self::MIN
+            } else {
+                Self::MAX
+            }
+        } else {
+            self
+        }
+    }
+
     /// Constructs a [`struct@bf16`] value from a 64-bit floating point value.
     ///
     /// This operation is lossy. If the 64-bit value is to large to fit, ±∞ will
@@ -141,12 +430,49 @@ impl bf16 {
     /// tiny to be represented and result in ±0. Exponents that underflow
     /// the minimum exponent will result in subnormals or ±0. All other
     /// values are truncated and rounded to the nearest representable value.
+    ///
+    /// The value is rounded exactly once from the full [`f64`]. For every
+    /// [`f64`] that is exactly an [`f32`] value, the result is identical to
+    /// [`from_f32`][Self::from_f32] of that [`f32`], which is tested for every
+    /// exponent and every pattern of the rounded mantissa bits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let x = 1.00390625f32;
+    /// assert_eq!(bf16::from_f64(x as f64), bf16::from_f32(x));
+    /// ```
     #[inline]
     #[must_use]
     pub fn from_f64(value: f64) -> bf16 {
         Self::from_f64_const(value)
     }
 
+    /// Returns if slice conversions of [`struct@bf16`] values use hardware
+    /// instructions on the current CPU.
+    ///
+    /// This is the `bf16` feature on `aarch64`, which is used by
+    /// [`HalfFloatSliceExt::convert_from_f32_slice`] and
+    /// [`HalfFloatSliceExt::convert_to_f32_slice`]. With the `std` feature,
+    /// this is detected at runtime, the same as the conversions themselves.
+    /// Otherwise, it is only `true` if the feature is enabled at compile time.
+    ///
+    /// [`HalfFloatSliceExt::convert_from_f32_slice`]: crate::HalfFloatSliceExt::convert_from_f32_slice
+    /// [`HalfFloatSliceExt::convert_to_f32_slice`]: crate::HalfFloatSliceExt::convert_to_f32_slice
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// println!("running with hardware bf16 support: {}", bf16::hardware_bf16_available());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn hardware_bf16_available() -> bool {
+        arch::hardware_bf16_available()
+    }
+
     /// Constructs a [`struct@bf16`] value from a 64-bit floating point value.
     ///
     /// This function is identical to [`from_f64`][Self::from_f64] except it
@@ -162,7 +488,7 @@ impl bf16 {
     #[inline]
     #[must_use]
     pub const fn from_f64_const(value: f64) -> bf16 {
-        bf16(convert::f64_to_bf16(value))
+        bf16(convert::canonical_nan(convert::f64_to_bf16(value)))
     }
 
     /// Create a [`struct@bf16`] loslessly from an [`f64`].
@@ -200,1788 +526,6205 @@ impl bf16 {
         )
     }
 
-    /// Converts a [`struct@bf16`] into the underlying bit representation.
-    #[inline]
-    #[must_use]
-    pub const fn to_bits(self) -> u16 {
-        self.0
-    }
-
-    /// Returns the memory representation of the underlying bit representation
-    /// as a byte array in little-endian byte order.
+    /// Constructs a [`struct@bf16`] value from a [`struct@f16`] value, rounding
+    /// to the nearest representable value.
+    ///
+    /// Every [`struct@f16`] value is within the range of [`struct@bf16`],
+    /// including the subnormals, which are normal [`struct@bf16`] values, so
+    /// only the 3 lowest mantissa bits are lost, rounding to the nearest
+    /// value with ties to even. This never overflows or underflows. NaN values
+    /// are preserved and quiet.
+    ///
+    /// This is identical to converting through [`f32`], but does not use an
+    /// intermediate [`f32`] value, which also allows it to be `const`.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use float16::*;
-    /// let bytes = bf16::from_f32(12.5).to_le_bytes();
-    /// assert_eq!(bytes, [0x48, 0x41]);
+    /// let x = f16::from_f32(1.0 + 3.0 / 1024.0);
+    /// assert_eq!(bf16::from_f16_lossy(x), bf16::from_f32(x.to_f32()));
+    /// assert_eq!(bf16::from_f16_lossy(x), bf16::ONE);
+    ///
+    /// // f16 subnormals are normal bf16 values.
+    /// let tiny = bf16::from_f16_lossy(f16::MIN_POSITIVE_SUBNORMAL);
+    /// assert_eq!(tiny.to_f32(), f16::MIN_POSITIVE_SUBNORMAL.to_f32());
     /// ```
     #[inline]
     #[must_use]
-    pub const fn to_le_bytes(self) -> [u8; 2] {
-        self.0.to_le_bytes()
+    pub const fn from_f16_lossy(value: f16) -> bf16 {
+        bf16(convert::canonical_nan(minifloat::narrow(value.to_bits() as u64, 5, 10, 8, 7) as u16))
     }
 
-    /// Returns the memory representation of the underlying bit representation
-    /// as a byte array in big-endian (network) byte order.
+    /// Create a [`struct@bf16`] losslessly from a [`struct@f16`].
+    ///
+    /// This is only true if the [`struct@f16`] is non-finite (infinite or
+    /// NaN), or no non-zero bits of its 10-bit mantissa would be truncated to
+    /// fit in 7 bits. The exponent always fits, and the subnormals are normal
+    /// [`struct@bf16`] values, so they fit if they have at most 8 significant
+    /// bits.
+    ///
+    /// This does not respect signalling NaNs: if the value is NaN, then it
+    /// will return a quiet NaN.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use float16::*;
-    /// let bytes = bf16::from_f32(12.5).to_be_bytes();
-    /// assert_eq!(bytes, [0x41, 0x48]);
+    /// let x = f16::from_f32(1.5);
+    /// assert_eq!(bf16::from_f16_lossless(x), Some(bf16::from_f32(1.5)));
+    ///
+    /// assert_eq!(bf16::from_f16_lossless(f16::MAX), None);
+    /// assert_eq!(bf16::from_f16_lossless(f16::MIN_POSITIVE_SUBNORMAL).map(bf16::to_f32),
+    ///     Some(f16::MIN_POSITIVE_SUBNORMAL.to_f32()));
     /// ```
     #[inline]
-    #[must_use]
-    pub const fn to_be_bytes(self) -> [u8; 2] {
-        self.0.to_be_bytes()
+    pub const fn from_f16_lossless(value: f16) -> Option<bf16> {
+        // Every result is exactly an `f16` value, so it only converts back to
+        // the same value if nothing was rounded.
+        let result = Self::from_f16_lossy(value);
+        if value.is_nan() || result.to_f16().to_bits() == value.to_bits() {
+            Some(result)
+        } else {
+            None
+        }
     }
 
-    /// Returns the memory representation of the underlying bit representation
-    /// as a byte array in native byte order.
+    /// Constructs a [`struct@bf16`] value from a binary fixed-point integer.
     ///
-    /// As the target platform's native endianness is used, portable code should
-    /// use [`to_be_bytes`][bf16::to_be_bytes] or
-    /// [`to_le_bytes`][bf16::to_le_bytes], as appropriate, instead.
+    /// The result is `value / 2^fractional_bits`, so a Q12.4 value would use
+    /// `fractional_bits = 4`. This only uses integer arithmetic, so the value
+    /// is rounded exactly once, to the nearest representable value with ties
+    /// to even. Values too large to fit will result in ±∞.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use float16::*;
-    /// let bytes = bf16::from_f32(12.5).to_ne_bytes();
-    /// assert_eq!(bytes, if cfg!(target_endian = "big") {
-    ///     [0x41, 0x48]
-    /// } else {
-    ///     [0x48, 0x41]
-    /// });
+    /// // Q12.4 fixed-point value of 12.5
+    /// assert_eq!(bf16::from_fixed_point(200, 4), bf16::from_f32(12.5));
+    /// assert_eq!(bf16::from_fixed_point(-3, 1), bf16::from_f32(-1.5));
     /// ```
     #[inline]
     #[must_use]
-    pub const fn to_ne_bytes(self) -> [u8; 2] {
-        self.0.to_ne_bytes()
+    pub const fn from_fixed_point(value: i64, fractional_bits: u32) -> bf16 {
+        bf16(fixed::fixed_to_half(value, fractional_bits, 8, 7))
     }
 
-    /// Creates a floating point value from its representation as a byte array
-    /// in little endian.
+    /// Converts a [`struct@bf16`] value to a binary fixed-point integer.
+    ///
+    /// The result is `self * 2^fractional_bits`, rounded to the nearest
+    /// integer with ties to even. Returns `None` if the value is NaN,
+    /// infinite, or the result does not fit in an [`i64`].
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use float16::*;
-    /// let value = bf16::from_le_bytes([0x48, 0x41]);
-    /// assert_eq!(value, bf16::from_f32(12.5));
+    /// assert_eq!(bf16::from_f32(12.5).to_fixed_point(4), Some(200));
+    /// assert_eq!(bf16::from_f32(-1.5).to_fixed_point(0), Some(-2));
+    /// assert_eq!(bf16::MAX.to_fixed_point(0), None);
     /// ```
     #[inline]
     #[must_use]
-    pub const fn from_le_bytes(bytes: [u8; 2]) -> bf16 {
-        bf16::from_bits(u16::from_le_bytes(bytes))
+    pub const fn to_fixed_point(self, fractional_bits: u32) -> Option<i64> {
+        fixed::half_to_fixed(self.0, fractional_bits, 8, 7)
     }
 
-    /// Creates a floating point value from its representation as a byte array
-    /// in big endian.
+    /// Converts a normalized [`u8`], such as an 8-bit pixel channel, to a
+    /// [`struct@bf16`] value in the range `[0.0, 1.0]`.
+    ///
+    /// The result is `v / 255` rounded to the nearest representable value, so
+    /// `0` maps to `0.0` and `255` maps to `1.0`. Every [`u8`] value round
+    /// trips through [`to_u8_norm`][Self::to_u8_norm].
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use float16::*;
-    /// let value = bf16::from_be_bytes([0x41, 0x48]);
-    /// assert_eq!(value, bf16::from_f32(12.5));
+    /// assert_eq!(bf16::from_u8_norm(0), bf16::ZERO);
+    /// assert_eq!(bf16::from_u8_norm(255), bf16::ONE);
+    /// assert_eq!(bf16::from_u8_norm(51), bf16::from_f32(0.2));
     /// ```
     #[inline]
     #[must_use]
-    pub const fn from_be_bytes(bytes: [u8; 2]) -> bf16 {
-        bf16::from_bits(u16::from_be_bytes(bytes))
+    pub fn from_u8_norm(v: u8) -> bf16 {
+        bf16::from_f32(v as f32 / 255.0)
     }
 
-    /// Creates a floating point value from its representation as a byte array
-    /// in native endian.
+    /// Converts a [`struct@bf16`] value in the range `[0.0, 1.0]` to a
+    /// normalized [`u8`], such as an 8-bit pixel channel.
     ///
-    /// As the target platform's native endianness is used, portable code likely
-    /// wants to use [`from_be_bytes`][bf16::from_be_bytes] or
-    /// [`from_le_bytes`][bf16::from_le_bytes], as appropriate instead.
+    /// The result is `self * 255` rounded to the nearest integer, with
+    /// half-way cases rounded up. Values below `0.0` saturate to `0`, values
+    /// above `1.0` saturate to `255`, and NaN converts to `0`.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use float16::*;
-    /// let value = bf16::from_ne_bytes(if cfg!(target_endian = "big") {
-    ///     [0x41, 0x48]
-    /// } else {
-    ///     [0x48, 0x41]
-    /// });
-    /// assert_eq!(value, bf16::from_f32(12.5));
+    /// assert_eq!(bf16::ONE.to_u8_norm(), 255);
+    /// assert_eq!(bf16::from_f32(0.5).to_u8_norm(), 128);
+    /// assert_eq!(bf16::from_f32(-0.5).to_u8_norm(), 0);
+    /// assert_eq!(bf16::from_f32(2.0).to_u8_norm(), 255);
+    /// assert_eq!(bf16::NAN.to_u8_norm(), 0);
     /// ```
     #[inline]
     #[must_use]
-    pub const fn from_ne_bytes(bytes: [u8; 2]) -> bf16 {
-        bf16::from_bits(u16::from_ne_bytes(bytes))
+    pub fn to_u8_norm(self) -> u8 {
+        // The product is exact and so is adding one half, so truncating gives
+        // the rounded value. Float to integer casts saturate, and NaN casts
+        // to zero.
+        (self.to_f32() * 255.0 + 0.5) as u8
     }
 
-    /// Converts a [`struct@bf16`] value into an [`f32`] value.
-    ///
-    /// This conversion is lossless as all values can be represented exactly in
-    /// [`f32`].
+    /// Converts a [`struct@bf16`] into the underlying bit representation.
     #[inline]
     #[must_use]
-    pub fn to_f32(self) -> f32 {
-        self.to_f32_const()
+    pub const fn to_bits(self) -> u16 {
+        self.0
     }
 
-    /// Converts a [`struct@bf16`] value into an [`f32`] value.
+    /// Constructs a [`struct@bf16`] value from its sign, biased exponent and
+    /// mantissa fields.
     ///
-    /// This function is identical to [`to_f32`][Self::to_f32] except it never
-    /// uses hardware intrinsics, which allows it to be `const`.
-    /// [`to_f32`][Self::to_f32] should be preferred in any non-`const`
-    /// context.
+    /// The `mantissa` is the 7-bit fraction field, without the hidden bit, and
+    /// is masked to 7 bits. In debug builds, this panics if `mantissa` does
+    /// not fit in 7 bits: use [`from_parts_checked`][Self::from_parts_checked]
+    /// to validate the fields instead.
     ///
-    /// This conversion is lossless as all values can be represented exactly in
-    /// [`f32`].
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(bf16::from_parts(false, 127, 0), bf16::ONE);
+    /// assert_eq!(bf16::from_parts(true, 128, 0x40), bf16::from_f32(-3.0));
+    /// assert_eq!(bf16::from_parts(false, 255, 0), bf16::INFINITY);
+    /// ```
     #[inline]
     #[must_use]
-    pub const fn to_f32_const(self) -> f32 {
-        convert::bf16_to_f32(self.0)
-    }
-
-    /// Convert the data to an `f32` type, used for numerical operations.
-    #[inline(always)]
-    pub fn as_f32(self) -> f32 {
-        self.to_f32_const()
-    }
-
-    /// Convert the data to an `f32` type, used for numerical operations.
-    #[inline(always)]
-    pub const fn as_f32_const(self) -> f32 {
-        self.to_f32_const()
+    pub const fn from_parts(sign: bool, biased_exponent: u8, mantissa: u8) -> bf16 {
+        debug_assert!(mantissa as u16 <= Self::MAN_MASK, "mantissa does not fit in 7 bits");
+        let sign = if sign {
+            Self::SIGN_MASK
+        } else {
+            0
+        };
+        let exp = (biased_exponent as u16) << 7;
+        bf16(sign | exp | (mantissa as u16 & Self::MAN_MASK))
     }
 
-    /// Converts a [`struct@bf16`] value into an [`f64`] value.
+    /// Constructs a [`struct@bf16`] value from its sign, biased exponent and
+    /// mantissa fields, returning `None` if `mantissa` does not fit in 7 bits.
     ///
-    /// This conversion is lossless as all values can be represented exactly in
-    /// [`f64`].
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(bf16::from_parts_checked(false, 127, 0x7F), Some(bf16::from_bits(0x3FFF)));
+    /// assert_eq!(bf16::from_parts_checked(false, 127, 0x80), None);
+    /// ```
     #[inline]
     #[must_use]
-    pub fn to_f64(self) -> f64 {
-        self.to_f64_const()
+    pub const fn from_parts_checked(sign: bool, biased_exponent: u8, mantissa: u8) -> Option<bf16> {
+        if mantissa as u16 > Self::MAN_MASK {
+            None
+        } else {
+            Some(Self::from_parts(sign, biased_exponent, mantissa))
+        }
     }
 
-    /// Converts a [`struct@bf16`] value into an [`f64`] value.
+    /// Splits a [`struct@bf16`] value into its sign, biased exponent and
+    /// mantissa fields.
     ///
-    /// This function is identical to [`to_f64`][Self::to_f64] except it never
-    /// uses hardware intrinsics, which allows it to be `const`.
-    /// [`to_f64`][Self::to_f64] should be preferred in any non-`const`
-    /// context.
+    /// The sign is `true` if the sign bit is set, and the mantissa is the
+    /// 7-bit fraction field, without the hidden bit. This is the inverse of
+    /// [`from_parts`][Self::from_parts].
     ///
-    /// This conversion is lossless as all values can be represented exactly in
-    /// [`f64`].
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(bf16::from_f32(-3.0).to_parts(), (true, 128, 0x40));
+    /// assert_eq!(bf16::MIN_POSITIVE_SUBNORMAL.to_parts(), (false, 0, 1));
+    /// ```
     #[inline]
     #[must_use]
-    pub const fn to_f64_const(self) -> f64 {
-        convert::bf16_to_f64(self.0)
-    }
-
-    /// Convert the data to an `f64` type, used for numerical operations.
-    #[inline(always)]
-    pub fn as_f64(self) -> f64 {
-        self.to_f64_const()
-    }
-
-    /// Convert the data to an `f64` type, used for numerical operations.
-    #[inline(always)]
-    pub const fn as_f64_const(self) -> f64 {
-        self.to_f64_const()
+    pub const fn to_parts(self) -> (bool, u8, u8) {
+        let sign = self.0 & Self::SIGN_MASK != 0;
+        let exp = ((self.0 & Self::EXP_MASK) >> 7) as u8;
+        let man = (self.0 & Self::MAN_MASK) as u8;
+        (sign, exp, man)
     }
 
-    /// Returns `true` if this value is NaN and `false` otherwise.
+    /// Returns the number of ones in the bits of the [`struct@bf16`] value.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use float16::*;
-    ///
-    /// let nan = bf16::NAN;
-    /// let f = bf16::from_f32(7.0_f32);
-    ///
-    /// assert!(nan.is_nan());
-    /// assert!(!f.is_nan());
+    /// assert_eq!(bf16::ONE.count_ones(), 7);
+    /// assert_eq!(bf16::NEG_ZERO.count_ones(), 1);
     /// ```
     #[inline]
     #[must_use]
-    pub const fn is_nan(self) -> bool {
-        self.0 & Self::NOT_SIGN > Self::EXP_MASK
-    }
-
-    /// Computes the absolute value of `self`.
-    #[must_use]
-    #[inline(always)]
-    pub const fn abs(self) -> Self {
-        Self(self.0 & !Self::SIGN_MASK)
+    pub const fn count_ones(self) -> u32 {
+        self.0.count_ones()
     }
 
-    /// Returns `true` if this value is ±∞ and `false` otherwise.
+    /// Returns the number of zeros in the bits of the [`struct@bf16`] value.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use float16::*;
-    ///
-    /// let f = bf16::from_f32(7.0f32);
-    /// let inf = bf16::INFINITY;
-    /// let neg_inf = bf16::NEG_INFINITY;
-    /// let nan = bf16::NAN;
-    ///
-    /// assert!(!f.is_infinite());
-    /// assert!(!nan.is_infinite());
-    ///
-    /// assert!(inf.is_infinite());
-    /// assert!(neg_inf.is_infinite());
+    /// assert_eq!(bf16::ONE.count_zeros(), 9);
     /// ```
     #[inline]
     #[must_use]
-    pub const fn is_infinite(self) -> bool {
-        self.0 & Self::NOT_SIGN == Self::EXP_MASK
+    pub const fn count_zeros(self) -> u32 {
+        self.0.count_zeros()
     }
 
-    /// Returns `true` if this number is neither infinite nor NaN.
+    /// Returns the number of leading zeros in the 7-bit mantissa field,
+    /// without the hidden bit.
+    ///
+    /// This is 7 if the mantissa is zero. For a subnormal value, this is
+    /// the shift that normalizes the mantissa, less one.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use float16::*;
-    ///
-    /// let f = bf16::from_f32(7.0f32);
-    /// let inf = bf16::INFINITY;
-    /// let neg_inf = bf16::NEG_INFINITY;
-    /// let nan = bf16::NAN;
-    ///
-    /// assert!(f.is_finite());
-    ///
-    /// assert!(!nan.is_finite());
-    /// assert!(!inf.is_finite());
-    /// assert!(!neg_inf.is_finite());
+    /// assert_eq!(bf16::ONE.leading_zeros_mantissa(), 7);
+    /// assert_eq!(bf16::from_f32(1.5).leading_zeros_mantissa(), 0);
+    /// assert_eq!(bf16::MIN_POSITIVE_SUBNORMAL.leading_zeros_mantissa(), 6);
     /// ```
     #[inline]
     #[must_use]
-    pub const fn is_finite(self) -> bool {
-        self.0 & Self::EXP_MASK != Self::EXP_MASK
+    pub const fn leading_zeros_mantissa(self) -> u32 {
+        leading_zeros_u16(self.0 & Self::MAN_MASK) - 9
     }
 
-    /// Returns `true` if the number is [subnormal].
+    /// Returns the number of trailing zeros in the 7-bit mantissa field,
+    /// without the hidden bit.
     ///
-    /// [subnormal]: https://en.wikipedia.org/wiki/Denormal_number
-    #[must_use]
-    #[inline(always)]
-    pub const fn is_subnormal(self) -> bool {
-        matches!(self.classify(), FpCategory::Subnormal)
-    }
-
-    /// Returns `true` if the number is neither zero, infinite, subnormal, or
-    /// NaN.
+    /// This is 7 if the mantissa is zero.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use float16::*;
-    ///
-    /// let min = bf16::MIN_POSITIVE;
-    /// let max = bf16::MAX;
-    /// let lower_than_min = bf16::from_f32(1.0e-39_f32);
-    /// let zero = bf16::from_f32(0.0_f32);
-    ///
-    /// assert!(min.is_normal());
-    /// assert!(max.is_normal());
-    ///
-    /// assert!(!zero.is_normal());
-    /// assert!(!bf16::NAN.is_normal());
-    /// assert!(!bf16::INFINITY.is_normal());
-    /// // Values between 0 and `min` are subnormal.
-    /// assert!(!lower_than_min.is_normal());
+    /// assert_eq!(bf16::ONE.trailing_zeros_mantissa(), 7);
+    /// assert_eq!(bf16::from_f32(1.5).trailing_zeros_mantissa(), 6);
+    /// assert_eq!(bf16::MIN_POSITIVE_SUBNORMAL.trailing_zeros_mantissa(), 0);
     /// ```
     #[inline]
     #[must_use]
-    pub const fn is_normal(self) -> bool {
-        let exp = self.0 & Self::EXP_MASK;
-        exp != Self::EXP_MASK && exp != 0
+    pub const fn trailing_zeros_mantissa(self) -> u32 {
+        ((self.0 & Self::MAN_MASK) | (Self::MAN_MASK + 1)).trailing_zeros()
     }
 
-    /// Returns the floating point category of the number.
+    /// Returns the range of unbiased exponents of normal [`struct@bf16`]
+    /// values, `-126..=127`.
     ///
-    /// If only one property is going to be tested, it is generally faster to
-    /// use the specific predicate instead.
+    /// A normal value is `1.m × 2^e` for an exponent `e` in this range. This
+    /// differs by one from [`MIN_EXP`][Self::MIN_EXP] and
+    /// [`MAX_EXP`][Self::MAX_EXP], which follow the C convention of a
+    /// significand in `[0.5, 1)`.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use std::num::FpCategory;
     /// # use float16::*;
-    ///
-    /// let num = bf16::from_f32(12.4_f32);
-    /// let inf = bf16::INFINITY;
-    ///
-    /// assert_eq!(num.classify(), FpCategory::Normal);
-    /// assert_eq!(inf.classify(), FpCategory::Infinite);
+    /// let range = bf16::exponent_range();
+    /// assert_eq!(*range.start(), bf16::MIN_EXP - 1);
+    /// assert_eq!(*range.end(), bf16::MAX_EXP - 1);
+    /// assert_eq!(bf16::MIN_POSITIVE.to_f64(), 2f64.powi(*range.start()));
     /// ```
     #[inline]
     #[must_use]
-    pub const fn classify(self) -> FpCategory {
-        let exp = self.0 & Self::EXP_MASK;
-        let man = self.0 & Self::MAN_MASK;
-        match (exp, man) {
-            (0, 0) => FpCategory::Zero,
-            (0, _) => FpCategory::Subnormal,
-            (Self::EXP_MASK, 0) => FpCategory::Infinite,
-            (Self::EXP_MASK, _) => FpCategory::Nan,
-            _ => FpCategory::Normal,
-        }
+    pub const fn exponent_range() -> RangeInclusive<i32> {
+        RangeInclusive::new(Self::MIN_EXP - 1, Self::MAX_EXP - 1)
     }
 
-    /// Returns a number that represents the sign of `self`.
+    /// Returns the exponent of the least significant bit of subnormal
+    /// [`struct@bf16`] values, `-133`.
     ///
-    /// * 1.0 if the number is positive, +0.0 or [`INFINITY`][bf16::INFINITY]
-    /// * −1.0 if the number is negative, −0.0` or
-    ///   [`NEG_INFINITY`][bf16::NEG_INFINITY]
-    /// * [`NAN`][bf16::NAN] if the number is NaN
+    /// Every subnormal value is an integer multiple of `2^-133`, which is
+    /// [`MIN_POSITIVE_SUBNORMAL`][Self::MIN_POSITIVE_SUBNORMAL].
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use float16::*;
+    /// let exp = bf16::subnormal_exponent();
+    /// assert_eq!(bf16::MIN_POSITIVE_SUBNORMAL.to_f64(), 2f64.powi(exp));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn subnormal_exponent() -> i32 {
+        Self::MIN_EXP - 1 - (Self::MANTISSA_DIGITS as i32 - 1)
+    }
+
+    /// Returns the unbiased exponent of the value, `floor(log2(|self|))`.
     ///
-    /// let f = bf16::from_f32(3.5_f32);
+    /// For normal values this is the exponent field without the bias, and
+    /// for subnormal values it is below the [normal
+    /// range][Self::exponent_range], as low as
+    /// [`subnormal_exponent`][Self::subnormal_exponent]. Returns `None` for
+    /// zero, infinite and NaN values.
     ///
-    /// assert_eq!(f.signum(), bf16::from_f32(1.0));
-    /// assert_eq!(bf16::NEG_INFINITY.signum(), bf16::from_f32(-1.0));
+    /// # Examples
     ///
-    /// assert!(bf16::NAN.signum().is_nan());
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(bf16::ONE.decode_exponent(), Some(0));
+    /// assert_eq!(bf16::from_f32(-1000.0).decode_exponent(), Some(9));
+    /// assert_eq!(bf16::from_f32(1e-40).decode_exponent(), Some(-133));
+    /// assert_eq!(bf16::ZERO.decode_exponent(), None);
+    /// assert_eq!(bf16::NAN.decode_exponent(), None);
     /// ```
     #[inline]
     #[must_use]
-    pub const fn signum(self) -> bf16 {
-        if self.is_nan() {
-            self
-        } else if self.0 & Self::SIGN_MASK != 0 {
-            Self::NEG_ONE
+    pub const fn decode_exponent(self) -> Option<i32> {
+        let biased = ((self.0 & Self::EXP_MASK) >> 7) as i32;
+        let man = self.0 & Self::MAN_MASK;
+        if biased == 0xFF {
+            None
+        } else if biased == 0 {
+            if man == 0 {
+                None
+            } else {
+                let msb = 15 - leading_zeros_u16(man) as i32;
+                Some(Self::subnormal_exponent() + msb)
+            }
         } else {
-            Self::ONE
+            Some(biased - 127)
         }
     }
 
-    /// Returns `true` if and only if `self` has a positive sign, including
-    /// +0.0, NaNs with a positive sign bit and +∞.
+    /// Parses a [`struct@bf16`] value from a hexadecimal floating point
+    /// literal, such as `0x1.8p+1`.
+    ///
+    /// The literal has an optional sign, a `0x` or `0X` prefix, hexadecimal
+    /// digits with an optional fractional part after a `.`, and a mandatory
+    /// binary exponent: a `p` or `P` followed by a signed decimal integer. The
+    /// value is rounded to the nearest representable value with ties to
+    /// even. `inf`, `infinity` and `nan` are also accepted, ignoring case.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseHalfError`] if `src` is not a valid literal.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use float16::*;
-    ///
-    /// let nan = bf16::NAN;
-    /// let f = bf16::from_f32(7.0_f32);
-    /// let g = bf16::from_f32(-7.0_f32);
-    ///
-    /// assert!(f.is_sign_positive());
-    /// assert!(!g.is_sign_positive());
-    /// // NaN can be either positive or negative
-    /// assert!(nan.is_sign_positive() != nan.is_sign_negative());
+    /// assert_eq!(bf16::from_hex_str("0x1.8p+1"), Ok(bf16::from_f32(3.0)));
+    /// assert_eq!(bf16::from_hex_str("-0x1.fep+127"), Ok(bf16::MIN));
+    /// assert_eq!(bf16::from_hex_str("0x1p-133"), Ok(bf16::MIN_POSITIVE_SUBNORMAL));
+    /// assert!(bf16::from_hex_str("1.5").is_err());
     /// ```
     #[inline]
-    #[must_use]
-    pub const fn is_sign_positive(self) -> bool {
-        self.0 & Self::SIGN_MASK == 0
+    pub fn from_hex_str(src: &str) -> Result<bf16, ParseHalfError> {
+        hex::parse_hex(src, 8, 7).map(bf16)
     }
 
-    /// Returns `true` if and only if `self` has a negative sign, including
-    /// −0.0, NaNs with a negative sign bit and −∞.
+    /// Returns a value that displays as a hexadecimal floating point
+    /// literal, which can be parsed exactly by
+    /// [`from_hex_str`][Self::from_hex_str].
+    ///
+    /// See [`HexFloat`] for the format. This does not allocate, unlike
+    /// `to_hex_string`.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use float16::*;
+    /// use core::fmt::Write;
     ///
-    /// let nan = bf16::NAN;
-    /// let f = bf16::from_f32(7.0f32);
-    /// let g = bf16::from_f32(-7.0f32);
-    ///
-    /// assert!(!f.is_sign_negative());
-    /// assert!(g.is_sign_negative());
-    /// // NaN can be either positive or negative
-    /// assert!(nan.is_sign_positive() != nan.is_sign_negative());
+    /// let mut s = String::new();
+    /// write!(s, "{}", bf16::from_f32(3.0).to_hex()).unwrap();
+    /// assert_eq!(s, "0x1.8p+1");
     /// ```
     #[inline]
     #[must_use]
-    pub const fn is_sign_negative(self) -> bool {
-        self.0 & Self::SIGN_MASK != 0
+    #[cfg(not(target_arch = "spirv"))]
+    pub const fn to_hex(self) -> HexFloat {
+        HexFloat::new(self.0, 8, 7)
     }
 
-    /// Returns a number composed of the magnitude of `self` and the sign of
-    /// `sign`.
+    /// Formats the value as a hexadecimal floating point literal, which can
+    /// be parsed exactly by [`from_hex_str`][Self::from_hex_str].
     ///
-    /// Equal to `self` if the sign of `self` and `sign` are the same, otherwise
-    /// equal to `-self`. If `self` is NaN, then NaN with the sign of `sign`
-    /// is returned.
+    /// See [`HexFloat`] for the format.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```rust
     /// # use float16::*;
-    /// let f = bf16::from_f32(3.5);
-    ///
-    /// assert_eq!(f.copysign(bf16::from_f32(0.42)), bf16::from_f32(3.5));
-    /// assert_eq!(f.copysign(bf16::from_f32(-0.42)), bf16::from_f32(-3.5));
-    /// assert_eq!((-f).copysign(bf16::from_f32(0.42)), bf16::from_f32(3.5));
-    /// assert_eq!((-f).copysign(bf16::from_f32(-0.42)), bf16::from_f32(-3.5));
-    ///
-    /// assert!(bf16::NAN.copysign(bf16::from_f32(1.0)).is_nan());
+    /// assert_eq!(bf16::from_f32(3.0).to_hex_string(), "0x1.8p+1");
+    /// assert_eq!(bf16::MIN_POSITIVE_SUBNORMAL.to_hex_string(), "0x0.02p-126");
+    /// assert_eq!(bf16::NEG_INFINITY.to_hex_string(), "-inf");
     /// ```
     #[inline]
     #[must_use]
-    pub const fn copysign(self, sign: bf16) -> bf16 {
-        bf16((sign.0 & Self::SIGN_MASK) | (self.0 & Self::NOT_SIGN))
-    }
-
-    /// Takes the reciprocal (inverse) of a number, `1/x`.
-    #[must_use]
-    #[inline(always)]
-    pub fn recip(self) -> Self {
-        Self::ONE / self
-    }
-
-    /// Converts radians to degrees.
-    #[must_use]
-    #[inline(always)]
-    pub fn to_degrees(self) -> Self {
-        self * Self::from(180u8) / Self::PI
-    }
-
-    /// Converts degrees to radians.
-    #[must_use]
-    #[inline(always)]
-    pub fn to_radians(self) -> Self {
-        self * Self::PI / Self::from(180u8)
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn to_hex_string(self) -> std::string::String {
+        std::string::ToString::to_string(&self.to_hex())
     }
 
-    /// Returns the maximum of the two numbers.
-    ///
-    /// If one of the arguments is NaN, then the other argument is returned.
+    /// Returns the memory representation of the underlying bit representation
+    /// as a byte array in little-endian byte order.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```rust
     /// # use float16::*;
-    /// let x = bf16::from_f32(1.0);
-    /// let y = bf16::from_f32(2.0);
-    ///
-    /// assert_eq!(x.max(y), y);
+    /// let bytes = bf16::from_f32(12.5).to_le_bytes();
+    /// assert_eq!(bytes, [0x48, 0x41]);
     /// ```
     #[inline]
     #[must_use]
-    pub const fn max(self, other: bf16) -> bf16 {
-        if self.is_nan() || gt(other, self) {
-            other
-        } else {
-            self
-        }
+    pub const fn to_le_bytes(self) -> [u8; 2] {
+        self.0.to_le_bytes()
     }
 
-    /// Returns the minimum of the two numbers.
-    ///
-    /// If one of the arguments is NaN, then the other argument is returned.
+    /// Returns the memory representation of the underlying bit representation
+    /// as a byte array in big-endian (network) byte order.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```rust
     /// # use float16::*;
-    /// let x = bf16::from_f32(1.0);
-    /// let y = bf16::from_f32(2.0);
-    ///
-    /// assert_eq!(x.min(y), x);
+    /// let bytes = bf16::from_f32(12.5).to_be_bytes();
+    /// assert_eq!(bytes, [0x41, 0x48]);
     /// ```
     #[inline]
     #[must_use]
-    pub const fn min(self, other: bf16) -> bf16 {
-        if self.is_nan() || lt(other, self) {
-            other
-        } else {
-            self
-        }
+    pub const fn to_be_bytes(self) -> [u8; 2] {
+        self.0.to_be_bytes()
     }
 
-    /// Restrict a value to a certain interval unless it is NaN.
+    /// Returns the memory representation of the underlying bit representation
+    /// as a byte array in native byte order.
     ///
-    /// Returns `max` if `self` is greater than `max`, and `min` if `self` is
-    /// less than `min`. Otherwise this returns `self`.
+    /// As the target platform's native endianness is used, portable code should
+    /// use [`to_be_bytes`][bf16::to_be_bytes] or
+    /// [`to_le_bytes`][bf16::to_le_bytes], as appropriate, instead.
     ///
-    /// Note that this function returns NaN if the initial value was NaN as
-    /// well.
+    /// # Examples
     ///
-    /// # Panics
-    /// Panics if `min > max`, `min` is NaN, or `max` is NaN.
+    /// ```rust
+    /// # use float16::*;
+    /// let bytes = bf16::from_f32(12.5).to_ne_bytes();
+    /// assert_eq!(bytes, if cfg!(target_endian = "big") {
+    ///     [0x41, 0x48]
+    /// } else {
+    ///     [0x48, 0x41]
+    /// });
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_ne_bytes(self) -> [u8; 2] {
+        self.0.to_ne_bytes()
+    }
+
+    /// Creates a floating point value from its representation as a byte array
+    /// in little endian.
     ///
     /// # Examples
     ///
+    /// ```rust
+    /// # use float16::*;
+    /// let value = bf16::from_le_bytes([0x48, 0x41]);
+    /// assert_eq!(value, bf16::from_f32(12.5));
     /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_le_bytes(bytes: [u8; 2]) -> bf16 {
+        bf16::from_bits(u16::from_le_bytes(bytes))
+    }
+
+    /// Creates a floating point value from its representation as a byte array
+    /// in big endian.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
     /// # use float16::*;
-    /// assert!(bf16::from_f32(-3.0).clamp(bf16::from_f32(-2.0), bf16::from_f32(1.0)) == bf16::from_f32(-2.0));
-    /// assert!(bf16::from_f32(0.0).clamp(bf16::from_f32(-2.0), bf16::from_f32(1.0)) == bf16::from_f32(0.0));
-    /// assert!(bf16::from_f32(2.0).clamp(bf16::from_f32(-2.0), bf16::from_f32(1.0)) == bf16::from_f32(1.0));
-    /// assert!(bf16::NAN.clamp(bf16::from_f32(-2.0), bf16::from_f32(1.0)).is_nan());
+    /// let value = bf16::from_be_bytes([0x41, 0x48]);
+    /// assert_eq!(value, bf16::from_f32(12.5));
     /// ```
     #[inline]
     #[must_use]
-    pub const fn clamp(self, min: bf16, max: bf16) -> bf16 {
-        assert!(le(min, max));
-        let mut x = self;
-        if lt(x, min) {
-            x = min;
-        }
-        if gt(x, max) {
-            x = max;
-        }
-        x
+    pub const fn from_be_bytes(bytes: [u8; 2]) -> bf16 {
+        bf16::from_bits(u16::from_be_bytes(bytes))
     }
 
-    /// Returns the ordering between `self` and `other`.
+    /// Creates a floating point value from its representation as a byte array
+    /// in native endian.
     ///
-    /// Unlike the standard partial comparison between floating point numbers,
-    /// this comparison always produces an ordering in accordance to
-    /// the `totalOrder` predicate as defined in the IEEE 754 (2008 revision)
-    /// floating point standard. The values are ordered in the following
-    /// sequence:
+    /// As the target platform's native endianness is used, portable code likely
+    /// wants to use [`from_be_bytes`][bf16::from_be_bytes] or
+    /// [`from_le_bytes`][bf16::from_le_bytes], as appropriate instead.
     ///
-    /// - negative quiet NaN
-    /// - negative signaling NaN
-    /// - negative infinity
-    /// - negative numbers
-    /// - negative subnormal numbers
-    /// - negative zero
-    /// - positive zero
-    /// - positive subnormal numbers
-    /// - positive numbers
-    /// - positive infinity
-    /// - positive signaling NaN
-    /// - positive quiet NaN.
+    /// # Examples
     ///
-    /// The ordering established by this function does not always agree with the
-    /// [`PartialOrd`] and [`PartialEq`] implementations of `bf16`. For example,
-    /// they consider negative and positive zero equal, while `total_cmp`
-    /// doesn't.
+    /// ```rust
+    /// # use float16::*;
+    /// let value = bf16::from_ne_bytes(if cfg!(target_endian = "big") {
+    ///     [0x41, 0x48]
+    /// } else {
+    ///     [0x48, 0x41]
+    /// });
+    /// assert_eq!(value, bf16::from_f32(12.5));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_ne_bytes(bytes: [u8; 2]) -> bf16 {
+        bf16::from_bits(u16::from_ne_bytes(bytes))
+    }
+
+    /// Creates a slice of values from bytes with each value in little endian,
+    /// borrowing the bytes when possible.
     ///
-    /// The interpretation of the signaling NaN bit follows the definition in
-    /// the IEEE 754 standard, which may not match the interpretation by some of
-    /// the older, non-conformant (e.g. MIPS) hardware implementations.
+    /// The bytes are borrowed without copying if they are aligned for
+    /// [`struct@bf16`] and the target is little endian. Otherwise, they are
+    /// copied into a new vector, swapping the bytes of each value on big
+    /// endian targets.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OddLength`] if the length of `bytes` is not a multiple of 2.
     ///
     /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let bytes = [0x48, 0x41, 0x00, 0x3C];
+    /// let values = bf16::cow_from_le_bytes(&bytes).unwrap();
+    /// assert_eq!(&*values, &[bf16::from_f32(12.5), bf16::from_bits(0x3C00)]);
+    ///
+    /// assert!(bf16::cow_from_le_bytes(&bytes[1..]).is_err());
     /// ```
-    /// # use float16::bf16;
-    /// let mut v: Vec<bf16> = vec![];
-    /// v.push(bf16::ONE);
-    /// v.push(bf16::INFINITY);
-    /// v.push(bf16::NEG_INFINITY);
-    /// v.push(bf16::NAN);
-    /// v.push(bf16::MAX_SUBNORMAL);
-    /// v.push(-bf16::MAX_SUBNORMAL);
-    /// v.push(bf16::ZERO);
-    /// v.push(bf16::NEG_ZERO);
-    /// v.push(bf16::NEG_ONE);
-    /// v.push(bf16::MIN_POSITIVE);
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn cow_from_le_bytes(bytes: &[u8]) -> Result<Cow<'_, [bf16]>, OddLength> {
+        Self::cow_from_bytes(bytes, cfg!(target_endian = "little"), bf16::from_le_bytes)
+    }
+
+    /// Creates a slice of values from bytes with each value in big endian,
+    /// borrowing the bytes when possible.
     ///
-    /// v.sort_by(|a, b| a.total_cmp(&b));
+    /// The bytes are borrowed without copying if they are aligned for
+    /// [`struct@bf16`] and the target is big endian. Otherwise, they are copied
+    /// into a new vector, swapping the bytes of each value on little endian
+    /// targets.
     ///
-    /// assert!(v
-    ///     .into_iter()
-    ///     .zip(
-    ///         [
-    ///             bf16::NEG_INFINITY,
-    ///             bf16::NEG_ONE,
-    ///             -bf16::MAX_SUBNORMAL,
-    ///             bf16::NEG_ZERO,
-    ///             bf16::ZERO,
-    ///             bf16::MAX_SUBNORMAL,
-    ///             bf16::MIN_POSITIVE,
-    ///             bf16::ONE,
-    ///             bf16::INFINITY,
-    ///             bf16::NAN
-    ///         ]
-    ///         .iter()
-    ///     )
-    ///     .all(|(a, b)| a.to_bits() == b.to_bits()));
+    /// # Errors
+    ///
+    /// Returns [`OddLength`] if the length of `bytes` is not a multiple of 2.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let bytes = [0x41, 0x48, 0x3C, 0x00];
+    /// let values = bf16::cow_from_be_bytes(&bytes).unwrap();
+    /// assert_eq!(&*values, &[bf16::from_f32(12.5), bf16::from_bits(0x3C00)]);
     /// ```
-    // Implementation based on: https://doc.rust-lang.org/std/primitive.f32.html#method.total_cmp
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn cow_from_be_bytes(bytes: &[u8]) -> Result<Cow<'_, [bf16]>, OddLength> {
+        Self::cow_from_bytes(bytes, cfg!(target_endian = "big"), bf16::from_be_bytes)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn cow_from_bytes(
+        bytes: &[u8],
+        native: bool,
+        from_bytes: fn([u8; 2]) -> bf16,
+    ) -> Result<Cow<'_, [bf16]>, OddLength> {
+        if bytes.len() % 2 != 0 {
+            return Err(OddLength(()));
+        }
+        if native {
+            // SAFETY: `bf16` is a `u16` with no invalid bit patterns, and
+            // `align_to` only returns the aligned middle as `bf16` values.
+            let (prefix, values, _) = unsafe { bytes.align_to::<bf16>() };
+            if prefix.is_empty() && values.len() * 2 == bytes.len() {
+                return Ok(Cow::Borrowed(values));
+            }
+        }
+        let values: Vec<bf16> = bytes.chunks_exact(2).map(|b| from_bytes([b[0], b[1]])).collect();
+        Ok(Cow::Owned(values))
+    }
+
+    /// Converts a [`struct@bf16`] value into an [`f32`] value.
+    ///
+    /// This conversion is lossless as all values can be represented exactly in
+    /// [`f32`].
     #[inline]
     #[must_use]
-    pub fn total_cmp(&self, other: &Self) -> Ordering {
-        let mut left = self.to_bits() as i16;
-        let mut right = other.to_bits() as i16;
-        left ^= (((left >> 15) as u16) >> 1) as i16;
-        right ^= (((right >> 15) as u16) >> 1) as i16;
-        left.cmp(&right)
+    pub fn to_f32(self) -> f32 {
+        self.to_f32_const()
     }
 
-    /// Approximate number of [`struct@bf16`] significant digits in base 10
-    pub const DIGITS: u32 = 2;
-    /// [`struct@bf16`]
-    /// [machine epsilon](https://en.wikipedia.org/wiki/Machine_epsilon) value
+    /// Converts a [`struct@bf16`] value into an [`f32`] value.
     ///
-    /// This is the difference between 1.0 and the next largest representable
-    /// number.
-    pub const EPSILON: bf16 = bf16(0x3C00u16);
-    /// [`struct@bf16`] positive Infinity (+∞)
-    pub const INFINITY: bf16 = bf16(0x7F80u16);
-    /// Number of [`struct@bf16`] significant digits in base 2
-    pub const MANTISSA_DIGITS: u32 = 8;
-    /// Largest finite [`struct@bf16`] value
-    pub const MAX: bf16 = bf16(0x7F7F);
-    /// Maximum possible [`struct@bf16`] power of 10 exponent
-    pub const MAX_10_EXP: i32 = 38;
-    /// Maximum possible [`struct@bf16`] power of 2 exponent
-    pub const MAX_EXP: i32 = 128;
-    /// Smallest finite [`struct@bf16`] value
-    pub const MIN: bf16 = bf16(0xFF7F);
-    /// Minimum possible normal [`struct@bf16`] power of 10 exponent
-    pub const MIN_10_EXP: i32 = -37;
-    /// One greater than the minimum possible normal [`struct@bf16`] power of 2
-    /// exponent
-    pub const MIN_EXP: i32 = -125;
-    /// Smallest positive normal [`struct@bf16`] value
-    pub const MIN_POSITIVE: bf16 = bf16(0x0080u16);
-    /// [`struct@bf16`] Not a Number (NaN)
-    pub const NAN: bf16 = bf16(0x7FC0u16);
-    /// [`struct@bf16`] negative infinity (-∞).
-    pub const NEG_INFINITY: bf16 = bf16(0xFF80u16);
-    /// The radix or base of the internal representation of [`struct@bf16`]
-    pub const RADIX: u32 = 2;
+    /// This function is identical to [`to_f32`][Self::to_f32] except it never
+    /// uses hardware intrinsics, which allows it to be `const`.
+    /// [`to_f32`][Self::to_f32] should be preferred in any non-`const`
+    /// context.
+    ///
+    /// This conversion is lossless as all values can be represented exactly in
+    /// [`f32`].
+    #[inline]
+    #[must_use]
+    pub const fn to_f32_const(self) -> f32 {
+        arch::canonical_nan_f32(convert::bf16_to_f32(self.0))
+    }
 
-    /// Minimum positive subnormal [`struct@bf16`] value
-    pub const MIN_POSITIVE_SUBNORMAL: bf16 = bf16(0x0001u16);
-    /// Maximum subnormal [`struct@bf16`] value
-    pub const MAX_SUBNORMAL: bf16 = bf16(0x007Fu16);
+    /// Convert the data to an `f32` type, used for numerical operations.
+    #[inline(always)]
+    pub fn as_f32(self) -> f32 {
+        self.to_f32_const()
+    }
 
-    /// [`struct@bf16`] 1
-    pub const ONE: bf16 = bf16(0x3F80u16);
-    /// [`struct@bf16`] 0
-    pub const ZERO: bf16 = bf16(0x0000u16);
-    /// [`struct@bf16`] -0
-    pub const NEG_ZERO: bf16 = bf16(0x8000u16);
-    /// [`struct@bf16`] -1
-    pub const NEG_ONE: bf16 = bf16(0xBF80u16);
+    /// Convert the data to an `f32` type, used for numerical operations.
+    #[inline(always)]
+    pub const fn as_f32_const(self) -> f32 {
+        self.to_f32_const()
+    }
 
-    /// [`struct@bf16`] Euler's number (ℯ)
-    pub const E: bf16 = bf16(0x402Eu16);
-    /// [`struct@bf16`] Archimedes' constant (π)
-    pub const PI: bf16 = bf16(0x4049u16);
-    /// [`struct@bf16`] 1/π
-    pub const FRAC_1_PI: bf16 = bf16(0x3EA3u16);
-    /// [`struct@bf16`] 1/√2
-    pub const FRAC_1_SQRT_2: bf16 = bf16(0x3F35u16);
-    /// [`struct@bf16`] 2/π
-    pub const FRAC_2_PI: bf16 = bf16(0x3F23u16);
-    /// [`struct@bf16`] 2/√π
-    pub const FRAC_2_SQRT_PI: bf16 = bf16(0x3F90u16);
-    /// [`struct@bf16`] π/2
-    pub const FRAC_PI_2: bf16 = bf16(0x3FC9u16);
-    /// [`struct@bf16`] π/3
-    pub const FRAC_PI_3: bf16 = bf16(0x3F86u16);
-    /// [`struct@bf16`] π/4
-    pub const FRAC_PI_4: bf16 = bf16(0x3F49u16);
-    /// [`struct@bf16`] π/6
-    pub const FRAC_PI_6: bf16 = bf16(0x3F06u16);
-    /// [`struct@bf16`] π/8
-    pub const FRAC_PI_8: bf16 = bf16(0x3EC9u16);
-    /// [`struct@bf16`] 𝗅𝗇 10
-    pub const LN_10: bf16 = bf16(0x4013u16);
-    /// [`struct@bf16`] 𝗅𝗇 2
-    pub const LN_2: bf16 = bf16(0x3F31u16);
-    /// [`struct@bf16`] 𝗅𝗈𝗀₁₀ℯ
-    pub const LOG10_E: bf16 = bf16(0x3EDEu16);
-    /// [`struct@bf16`] 𝗅𝗈𝗀₁₀2
-    pub const LOG10_2: bf16 = bf16(0x3E9Au16);
-    /// [`struct@bf16`] 𝗅𝗈𝗀₂ℯ
-    pub const LOG2_E: bf16 = bf16(0x3FB9u16);
-    /// [`struct@bf16`] 𝗅𝗈𝗀₂10
-    pub const LOG2_10: bf16 = bf16(0x4055u16);
-    /// [`struct@bf16`] √2
-    pub const SQRT_2: bf16 = bf16(0x3FB5u16);
-
-    /// Sign bit
-    pub const SIGN_MASK: u16 = 0x8000;
-    // Private helper for comparisons.
-    const NOT_SIGN: u16 = !Self::SIGN_MASK;
-
-    /// Exponent mask
-    pub const EXP_MASK: u16 = 0x7F80;
-
-    /// Mask for the hidden bit.
-    pub const HIDDEN_BIT_MASK: u16 = 0x0080;
-
-    /// Mantissa mask
-    pub const MAN_MASK: u16 = 0x007F;
-
-    /// Minimum representable positive value (min subnormal)
-    pub const TINY_BITS: u16 = 0x1;
-
-    /// Minimum representable negative value (min negative subnormal)
-    pub const NEG_TINY_BITS: u16 = Self::TINY_BITS | Self::SIGN_MASK;
-}
+    /// Converts a [`struct@bf16`] value into an [`f64`] value.
+    ///
+    /// This conversion is lossless as all values can be represented exactly in
+    /// [`f64`].
+    #[inline]
+    #[must_use]
+    pub fn to_f64(self) -> f64 {
+        self.to_f64_const()
+    }
 
-macro_rules! from_int_impl {
-    ($t:ty, $func:ident) => {
-        /// Create from the integral type, as if by an `as` cast.
-        #[inline(always)]
-        pub const fn $func(value: $t) -> Self {
-            Self::from_f32_const(value as f32)
-        }
-    };
-}
+    /// Converts a [`struct@bf16`] value into an [`f64`] value.
+    ///
+    /// This function is identical to [`to_f64`][Self::to_f64] except it never
+    /// uses hardware intrinsics, which allows it to be `const`.
+    /// [`to_f64`][Self::to_f64] should be preferred in any non-`const`
+    /// context.
+    ///
+    /// This conversion is lossless as all values can be represented exactly in
+    /// [`f64`].
+    #[inline]
+    #[must_use]
+    pub const fn to_f64_const(self) -> f64 {
+        arch::canonical_nan_f64(convert::bf16_to_f64(self.0))
+    }
 
-impl bf16 {
-    from_int_impl!(u8, from_u8);
-    from_int_impl!(u16, from_u16);
-    from_int_impl!(u32, from_u32);
-    from_int_impl!(u64, from_u64);
-    from_int_impl!(u128, from_u128);
-    from_int_impl!(i8, from_i8);
-    from_int_impl!(i16, from_i16);
-    from_int_impl!(i32, from_i32);
-    from_int_impl!(i64, from_i64);
-    from_int_impl!(i128, from_i128);
-}
+    /// Convert the data to an `f64` type, used for numerical operations.
+    #[inline(always)]
+    pub fn as_f64(self) -> f64 {
+        self.to_f64_const()
+    }
 
-impl From<bf16> for f32 {
-    #[inline]
-    fn from(x: bf16) -> f32 {
-        x.to_f32()
+    /// Convert the data to an `f64` type, used for numerical operations.
+    #[inline(always)]
+    pub const fn as_f64_const(self) -> f64 {
+        self.to_f64_const()
     }
-}
 
-impl From<bf16> for f64 {
+    /// Converts a [`struct@bf16`] value to the nearest [`struct@f16`] value.
+    ///
+    /// The mantissa of every [`struct@bf16`] value fits in a
+    /// [`struct@f16`], but the exponent range is much smaller, so values too
+    /// large for [`struct@f16`] result in ±∞, and values too small round to
+    /// subnormals or ±0, with ties to even. NaN values are preserved and
+    /// quiet. This is the same as
+    /// [`f16::from_bf16_lossy`][crate::f16::from_bf16_lossy], and the same as
+    /// converting through [`f32`], which is exact for every
+    /// [`struct@bf16`] value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(bf16::from_f32(1.5).to_f16(), f16::from_f32(1.5));
+    /// assert_eq!(bf16::from_f32(1e5).to_f16(), f16::INFINITY);
+    /// assert_eq!(bf16::from_f32(1e-7).to_f16(), f16::from_f32(1e-7));
+    /// assert_eq!(bf16::MIN_POSITIVE.to_f16(), f16::ZERO);
+    ///
+    /// const HALF: f16 = bf16::ONE.to_f16();
+    /// assert_eq!(HALF, f16::ONE);
+    /// ```
     #[inline]
-    fn from(x: bf16) -> f64 {
-        x.to_f64()
+    #[must_use]
+    pub const fn to_f16(self) -> f16 {
+        f16::from_bf16_lossy(self)
     }
-}
 
-impl From<i8> for bf16 {
+    /// Returns `true` if this value is NaN and `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    ///
+    /// let nan = bf16::NAN;
+    /// let f = bf16::from_f32(7.0_f32);
+    ///
+    /// assert!(nan.is_nan());
+    /// assert!(!f.is_nan());
+    /// ```
     #[inline]
-    fn from(x: i8) -> bf16 {
-        // Convert to f32, then to bf16
-        bf16::from_f32(f32::from(x))
+    #[must_use]
+    pub const fn is_nan(self) -> bool {
+        self.0 & Self::NOT_SIGN > Self::EXP_MASK
     }
-}
 
-impl From<u8> for bf16 {
+    /// Returns `true` if this value is a signaling `NaN`.
+    ///
+    /// Following IEEE 754-2008, a `NaN` is signaling if the most significant
+    /// bit of the mantissa, the quiet bit, is clear. Conversions to and from
+    /// [`f32`] and [`f64`] quiet signaling `NaN` values, as with
+    /// [`quiet`][Self::quiet], so these can only be constructed from their
+    /// bits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert!(bf16::SIGNALING_NAN.is_nan_signaling());
+    /// assert!(!bf16::NAN.is_nan_signaling());
+    /// assert!(!bf16::from_f32(f32::from_bits(0x7FA0_0000)).is_nan_signaling());
+    /// assert!(bf16::from_f32(bf16::SIGNALING_NAN.to_f32()).is_nan_quiet());
+    /// ```
     #[inline]
-    fn from(x: u8) -> bf16 {
-        // Convert to f32, then to f16
-        bf16::from_f32(f32::from(x))
+    #[must_use]
+    pub const fn is_nan_signaling(self) -> bool {
+        self.is_nan() && self.0 & Self::QUIET_BIT == 0
     }
-}
-
-impl TryFrom<f32> for bf16 {
-    type Error = TryFromFloatError;
 
+    /// Returns `true` if this value is a quiet `NaN`.
+    ///
+    /// Following IEEE 754-2008, a `NaN` is quiet if the most significant bit
+    /// of the mantissa, the quiet bit, is set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert!(bf16::NAN.is_nan_quiet());
+    /// assert!(!bf16::SIGNALING_NAN.is_nan_quiet());
+    /// assert!(!bf16::ONE.is_nan_quiet());
+    /// ```
     #[inline]
-    fn try_from(x: f32) -> Result<Self, Self::Error> {
-        Self::from_f32_lossless(x).ok_or(TryFromFloatError(()))
+    #[must_use]
+    pub const fn is_nan_quiet(self) -> bool {
+        self.is_nan() && self.0 & Self::QUIET_BIT != 0
     }
-}
-
-impl TryFrom<f64> for bf16 {
-    type Error = TryFromFloatError;
 
+    /// Sets the quiet bit of a signaling `NaN`, keeping its sign and payload.
+    ///
+    /// All other values, including quiet `NaN` values, are returned
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let nan = -bf16::from_bits(0x7F81);
+    /// assert!(nan.is_nan_signaling());
+    /// assert_eq!(nan.quiet().to_bits(), 0xFFC1);
+    ///
+    /// assert_eq!(bf16::NAN.quiet().to_bits(), bf16::NAN.to_bits());
+    /// assert_eq!(bf16::ONE.quiet(), bf16::ONE);
+    /// ```
     #[inline]
-    fn try_from(x: f64) -> Result<Self, Self::Error> {
-        Self::from_f64_lossless(x).ok_or(TryFromFloatError(()))
+    #[must_use]
+    pub const fn quiet(self) -> bf16 {
+        if self.is_nan() {
+            bf16(self.0 | Self::QUIET_BIT)
+        } else {
+            self
+        }
     }
-}
 
-impl PartialEq for bf16 {
-    fn eq(&self, other: &bf16) -> bool {
-        eq(*self, *other)
+    /// Returns the canonical form of `self`, for hashing or serializing
+    /// values deterministically.
+    ///
+    /// Every `NaN`, of either sign, with any payload, quiet or signaling, is
+    /// replaced by [`NAN`][Self::NAN], and all other values, including −0,
+    /// are returned unchanged. See
+    /// [`canonicalize_zero`][Self::canonicalize_zero] to also replace −0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let nan = bf16::from_bits(0xFF85);
+    /// assert_eq!(nan.canonicalize().to_bits(), bf16::NAN.to_bits());
+    ///
+    /// assert_eq!(bf16::NEG_ZERO.canonicalize().to_bits(), 0x8000);
+    /// assert_eq!(bf16::MAX.canonicalize(), bf16::MAX);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn canonicalize(self) -> bf16 {
+        if self.is_nan() {
+            Self::NAN
+        } else {
+            self
+        }
     }
-}
 
-impl PartialOrd for bf16 {
-    fn partial_cmp(&self, other: &bf16) -> Option<Ordering> {
-        if self.is_nan() || other.is_nan() {
-            None
+    /// Returns the canonical form of `self`, as with
+    /// [`canonicalize`][Self::canonicalize], but also replaces −0 with +0.
+    ///
+    /// Values that compare equal have the same canonical form, and every
+    /// `NaN` has the same canonical form, so the bits of the result can be
+    /// hashed or compared in place of the value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(bf16::NEG_ZERO.canonicalize_zero().to_bits(), 0);
+    /// assert_eq!((-bf16::NAN).canonicalize_zero().to_bits(), 0x7FC0);
+    /// assert_eq!(bf16::NEG_ONE.canonicalize_zero(), bf16::NEG_ONE);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn canonicalize_zero(self) -> bf16 {
+        if self.is_nan() {
+            Self::NAN
+        } else if self.0 == Self::SIGN_MASK {
+            Self::ZERO
         } else {
-            let neg = self.0 & Self::SIGN_MASK != 0;
-            let other_neg = other.0 & Self::SIGN_MASK != 0;
-            match (neg, other_neg) {
-                (false, false) => Some(self.0.cmp(&other.0)),
-                (false, true) => {
-                    if (self.0 | other.0) & Self::NOT_SIGN == 0 {
-                        Some(Ordering::Equal)
-                    } else {
-                        Some(Ordering::Greater)
-                    }
-                },
-                (true, false) => {
-                    if (self.0 | other.0) & Self::NOT_SIGN == 0 {
-                        Some(Ordering::Equal)
-                    } else {
-                        Some(Ordering::Less)
-                    }
-                },
-                (true, true) => Some(other.0.cmp(&self.0)),
-            }
+            self
         }
     }
 
-    fn lt(&self, other: &bf16) -> bool {
-        lt(*self, *other)
+    /// Computes the absolute value of `self`.
+    #[must_use]
+    #[inline(always)]
+    pub const fn abs(self) -> Self {
+        Self(self.0 & !Self::SIGN_MASK)
     }
 
-    fn le(&self, other: &bf16) -> bool {
-        le(*self, *other)
+    /// Returns `true` if this value is ±∞ and `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    ///
+    /// let f = bf16::from_f32(7.0f32);
+    /// let inf = bf16::INFINITY;
+    /// let neg_inf = bf16::NEG_INFINITY;
+    /// let nan = bf16::NAN;
+    ///
+    /// assert!(!f.is_infinite());
+    /// assert!(!nan.is_infinite());
+    ///
+    /// assert!(inf.is_infinite());
+    /// assert!(neg_inf.is_infinite());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_infinite(self) -> bool {
+        self.0 & Self::NOT_SIGN == Self::EXP_MASK
     }
 
-    fn gt(&self, other: &bf16) -> bool {
-        gt(*self, *other)
+    /// Returns `true` if this number is neither infinite nor NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    ///
+    /// let f = bf16::from_f32(7.0f32);
+    /// let inf = bf16::INFINITY;
+    /// let neg_inf = bf16::NEG_INFINITY;
+    /// let nan = bf16::NAN;
+    ///
+    /// assert!(f.is_finite());
+    ///
+    /// assert!(!nan.is_finite());
+    /// assert!(!inf.is_finite());
+    /// assert!(!neg_inf.is_finite());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_finite(self) -> bool {
+        self.0 & Self::EXP_MASK != Self::EXP_MASK
     }
 
-    fn ge(&self, other: &bf16) -> bool {
-        ge(*self, *other)
+    /// Returns `true` if the number is [subnormal].
+    ///
+    /// [subnormal]: https://en.wikipedia.org/wiki/Denormal_number
+    #[must_use]
+    #[inline(always)]
+    pub const fn is_subnormal(self) -> bool {
+        matches!(self.classify(), FpCategory::Subnormal)
     }
-}
-
-#[cfg(not(target_arch = "spirv"))]
-impl FromStr for bf16 {
-    type Err = ParseFloatError;
 
+    /// Returns `true` if the number is neither zero, infinite, subnormal, or
+    /// NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    ///
+    /// let min = bf16::MIN_POSITIVE;
+    /// let max = bf16::MAX;
+    /// let lower_than_min = bf16::from_f32(1.0e-39_f32);
+    /// let zero = bf16::from_f32(0.0_f32);
+    ///
+    /// assert!(min.is_normal());
+    /// assert!(max.is_normal());
+    ///
+    /// assert!(!zero.is_normal());
+    /// assert!(!bf16::NAN.is_normal());
+    /// assert!(!bf16::INFINITY.is_normal());
+    /// // Values between 0 and `min` are subnormal.
+    /// assert!(!lower_than_min.is_normal());
+    /// ```
     #[inline]
-    fn from_str(src: &str) -> Result<bf16, ParseFloatError> {
-        f32::from_str(src).map(bf16::from_f32)
+    #[must_use]
+    pub const fn is_normal(self) -> bool {
+        let exp = self.0 & Self::EXP_MASK;
+        exp != Self::EXP_MASK && exp != 0
     }
-}
 
-#[cfg(not(target_arch = "spirv"))]
-impl Debug for bf16 {
+    /// Returns `true` if this value is positive or negative zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// const IS_ZERO: bool = bf16::NEG_ZERO.is_zero();
+    /// assert!(IS_ZERO);
+    /// assert!(bf16::ZERO.is_zero());
+    /// assert!(!bf16::MIN_POSITIVE_SUBNORMAL.is_zero());
+    /// assert!(!bf16::NAN.is_zero());
+    /// ```
     #[inline]
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        Debug::fmt(&self.to_f32(), f)
+    #[must_use]
+    pub const fn is_zero(self) -> bool {
+        self.0 & Self::NOT_SIGN == 0
     }
-}
 
-#[cfg(not(target_arch = "spirv"))]
-impl Display for bf16 {
+    /// Returns `true` if this value is a finite integer, including zero.
+    ///
+    /// Every finite value with a magnitude of at least `2^7` is an
+    /// integer. Infinite and NaN values are not integers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert!(bf16::from_f32(-3.).is_integer());
+    /// assert!(bf16::NEG_ZERO.is_integer());
+    /// assert!(bf16::from_f32(384.).is_integer());
+    /// assert!(!bf16::from_f32(0.75).is_integer());
+    /// assert!(!bf16::MIN_POSITIVE_SUBNORMAL.is_integer());
+    /// assert!(!bf16::INFINITY.is_integer());
+    /// ```
     #[inline]
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        Display::fmt(&self.to_f32(), f)
+    #[must_use]
+    pub const fn is_integer(self) -> bool {
+        let mag = self.0 & Self::NOT_SIGN;
+        // The power of two of the leading bit, for normal values.
+        let exp = (mag >> 7) as i32 - 127;
+        if mag >= Self::EXP_MASK {
+            false
+        } else if mag == 0 || exp >= 7 {
+            true
+        } else if exp < 0 {
+            false
+        } else {
+            // The mantissa bits below the units bit must be zero.
+            mag & (Self::MAN_MASK >> exp) == 0
+        }
     }
-}
 
-#[cfg(not(target_arch = "spirv"))]
-impl LowerExp for bf16 {
+    /// Returns `true` if this value is an odd integer.
+    ///
+    /// This is useful for the special cases of powers, where the sign of the
+    /// result depends on whether the exponent is an odd integer. Values with a
+    /// magnitude of at least `2^8` are even.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert!(bf16::from_f32(-3.).is_odd_integer());
+    /// assert!(bf16::ONE.is_odd_integer());
+    /// assert!(!bf16::from_f32(2.).is_odd_integer());
+    /// assert!(!bf16::from_f32(1.5).is_odd_integer());
+    /// assert!(!bf16::NAN.is_odd_integer());
+    /// ```
     #[inline]
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:e}", self.to_f32())
+    #[must_use]
+    pub const fn is_odd_integer(self) -> bool {
+        let mag = self.0 & Self::NOT_SIGN;
+        match (mag >> 7) as i32 - 127 {
+            // The units bit is the implicit leading bit for `1`, and the
+            // lowest bit of the mantissa for `2^7`.
+            exp @ 0..=7 => {
+                let significand = (mag & Self::MAN_MASK) | (Self::MAN_MASK + 1);
+                self.is_integer() && (significand >> (7 - exp)) & 1 == 1
+            },
+            _ => false,
+        }
     }
-}
 
-#[cfg(not(target_arch = "spirv"))]
-impl UpperExp for bf16 {
+    /// Returns `true` if this value is an even integer, including zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert!(bf16::from_f32(-4.).is_even_integer());
+    /// assert!(bf16::ZERO.is_even_integer());
+    /// assert!(bf16::MAX.is_even_integer());
+    /// assert!(!bf16::ONE.is_even_integer());
+    /// assert!(!bf16::from_f32(0.5).is_even_integer());
+    /// assert!(!bf16::INFINITY.is_even_integer());
+    /// ```
     #[inline]
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:E}", self.to_f32())
+    #[must_use]
+    pub const fn is_even_integer(self) -> bool {
+        self.is_integer() && !self.is_odd_integer()
     }
-}
 
-#[cfg(not(target_arch = "spirv"))]
-impl Binary for bf16 {
+    /// Returns the raw 8-bit exponent field, with the bias of 127.
+    ///
+    /// This is `0` for zero and subnormal values, and `255` for
+    /// infinite and NaN values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(bf16::ONE.biased_exponent(), 127);
+    /// assert_eq!(bf16::from_f32(-6.5).biased_exponent(), 129);
+    /// assert_eq!(bf16::MIN_POSITIVE_SUBNORMAL.biased_exponent(), 0);
+    /// assert_eq!(bf16::INFINITY.biased_exponent(), 255);
+    /// ```
     #[inline]
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:b}", self.0)
+    #[must_use]
+    pub const fn biased_exponent(self) -> u16 {
+        (self.0 & Self::EXP_MASK) >> 7
     }
-}
 
-#[cfg(not(target_arch = "spirv"))]
-impl Octal for bf16 {
+    /// Returns the exponent field without the bias of 127.
+    ///
+    /// For normal values, this is the power of two of the leading bit, so
+    /// the value is `±1.mantissa × 2^exponent`. Zero and subnormal values
+    /// return `-126`, the minimum normal exponent, since they are
+    /// `±0.mantissa × 2^-126`. Infinite and NaN values return `128`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(bf16::ONE.unbiased_exponent(), 0);
+    /// assert_eq!(bf16::from_f32(6.5).unbiased_exponent(), 2);
+    /// assert_eq!(bf16::from_f32(0.25).unbiased_exponent(), -2);
+    /// assert_eq!(bf16::MIN_POSITIVE_SUBNORMAL.unbiased_exponent(), -126);
+    /// assert_eq!(bf16::MIN_POSITIVE.unbiased_exponent(), -126);
+    /// ```
     #[inline]
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:o}", self.0)
+    #[must_use]
+    pub const fn unbiased_exponent(self) -> i16 {
+        let exp = self.biased_exponent() as i16;
+        if exp == 0 {
+            -126
+        } else {
+            exp - 127
+        }
     }
-}
 
-#[cfg(not(target_arch = "spirv"))]
-impl LowerHex for bf16 {
+    /// Returns the raw 7-bit mantissa field, without the implicit
+    /// leading bit of normal values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(bf16::ONE.mantissa(), 0);
+    /// assert_eq!(bf16::from_f32(6.5).mantissa(), 0x50);
+    /// assert_eq!(bf16::MIN_POSITIVE_SUBNORMAL.mantissa(), 1);
+    /// assert_eq!(bf16::MAX.mantissa(), 0x7f);
+    /// ```
     #[inline]
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:x}", self.0)
+    #[must_use]
+    pub const fn mantissa(self) -> u16 {
+        self.0 & Self::MAN_MASK
     }
-}
 
-#[cfg(not(target_arch = "spirv"))]
-impl UpperHex for bf16 {
+    /// Multiplies the value by `2^n`, like `scalbn` or `ldexp` in C.
+    ///
+    /// The exponent bits are adjusted directly, so this is exact unless the
+    /// result overflows to infinity, above a binary exponent of 127, or falls
+    /// into the subnormal range, below -126, where it is rounded to the
+    /// nearest value, ties to even, which may be zero. Zero, infinite and
+    /// NaN values are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(bf16::from_f32(3.).scale(4), bf16::from_f32(48.));
+    /// assert_eq!(bf16::MIN_POSITIVE.scale(-7), bf16::MIN_POSITIVE_SUBNORMAL);
+    /// assert_eq!(bf16::MIN_POSITIVE_SUBNORMAL.scale(133), bf16::ONE);
+    /// assert_eq!(bf16::MAX.scale(1), bf16::INFINITY);
+    /// assert_eq!(bf16::ONE.scale(-135), bf16::ZERO);
+    /// ```
     #[inline]
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:X}", self.0)
-    }
-}
+    #[must_use]
+    pub const fn scale(self, n: i32) -> bf16 {
+        let sign = self.0 & Self::SIGN_MASK;
+        let abs = self.0 & Self::NOT_SIGN;
+        if abs == 0 || abs >= Self::EXP_MASK || n == 0 {
+            return self;
+        }
 
-impl Neg for bf16 {
-    type Output = Self;
+        // Normalize subnormal values, so the significand has its leading bit
+        // at the hidden bit.
+        let mut exp = (abs >> 7) as i32;
+        let mut man = abs & Self::MAN_MASK;
+        if exp == 0 {
+            exp = 1;
+            while man & Self::HIDDEN_BIT_MASK == 0 {
+                man <<= 1;
+                exp -= 1;
+            }
+        } else {
+            man |= Self::HIDDEN_BIT_MASK;
+        }
 
-    #[inline]
-    fn neg(self) -> Self::Output {
-        Self(self.0 ^ Self::SIGN_MASK)
+        // Any larger shift overflows or underflows, and this keeps the sum
+        // from overflowing.
+        let n = if n > 512 {
+            512
+        } else if n < -512 {
+            -512
+        } else {
+            n
+        };
+        let exp = exp + n;
+        if exp > 254 {
+            bf16(sign | Self::EXP_MASK)
+        } else if exp > 0 {
+            bf16(sign | ((exp as u16) << 7) | (man & Self::MAN_MASK))
+        } else {
+            // Subnormal, rounded to nearest, ties to even. A carry into the
+            // hidden bit gives the minimum normal value.
+            let shift = (1 - exp) as u32;
+            if shift > 8 {
+                return bf16(sign);
+            }
+            let kept = man >> shift;
+            let rem = man & ((1 << shift) - 1);
+            let half = 1 << (shift - 1);
+            let round = (rem > half || (rem == half && kept & 1 == 1)) as u16;
+            bf16(sign | (kept + round))
+        }
     }
-}
 
-impl Neg for &bf16 {
-    type Output = <bf16 as Neg>::Output;
+    /// Returns the floating point category of the number.
+    ///
+    /// If only one property is going to be tested, it is generally faster to
+    /// use the specific predicate instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::num::FpCategory;
+    /// # use float16::*;
+    ///
+    /// let num = bf16::from_f32(12.4_f32);
+    /// let inf = bf16::INFINITY;
+    ///
+    /// assert_eq!(num.classify(), FpCategory::Normal);
+    /// assert_eq!(inf.classify(), FpCategory::Infinite);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn classify(self) -> FpCategory {
+        let exp = self.0 & Self::EXP_MASK;
+        let man = self.0 & Self::MAN_MASK;
+        match (exp, man) {
+            (0, 0) => FpCategory::Zero,
+            (0, _) => FpCategory::Subnormal,
+            (Self::EXP_MASK, 0) => FpCategory::Infinite,
+            (Self::EXP_MASK, _) => FpCategory::Nan,
+            _ => FpCategory::Normal,
+        }
+    }
 
+    /// Returns a number that represents the sign of `self`.
+    ///
+    /// * 1.0 if the number is positive, +0.0 or [`INFINITY`][bf16::INFINITY]
+    /// * −1.0 if the number is negative, −0.0` or
+    ///   [`NEG_INFINITY`][bf16::NEG_INFINITY]
+    /// * [`NAN`][bf16::NAN] if the number is NaN
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    ///
+    /// let f = bf16::from_f32(3.5_f32);
+    ///
+    /// assert_eq!(f.signum(), bf16::from_f32(1.0));
+    /// assert_eq!(bf16::NEG_INFINITY.signum(), bf16::from_f32(-1.0));
+    ///
+    /// assert!(bf16::NAN.signum().is_nan());
+    /// ```
     #[inline]
-    fn neg(self) -> Self::Output {
-        Neg::neg(*self)
+    #[must_use]
+    pub const fn signum(self) -> bf16 {
+        if self.is_nan() {
+            self
+        } else if self.0 & Self::SIGN_MASK != 0 {
+            Self::NEG_ONE
+        } else {
+            Self::ONE
+        }
     }
-}
 
-impl Add for bf16 {
-    type Output = Self;
+    /// Returns `true` if and only if `self` has a positive sign, including
+    /// +0.0, NaNs with a positive sign bit and +∞.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    ///
+    /// let nan = bf16::NAN;
+    /// let f = bf16::from_f32(7.0_f32);
+    /// let g = bf16::from_f32(-7.0_f32);
+    ///
+    /// assert!(f.is_sign_positive());
+    /// assert!(!g.is_sign_positive());
+    /// // NaN can be either positive or negative
+    /// assert!(nan.is_sign_positive() != nan.is_sign_negative());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_sign_positive(self) -> bool {
+        self.0 & Self::SIGN_MASK == 0
+    }
+
+    /// Returns `true` if and only if `self` has a negative sign, including
+    /// −0.0, NaNs with a negative sign bit and −∞.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    ///
+    /// let nan = bf16::NAN;
+    /// let f = bf16::from_f32(7.0f32);
+    /// let g = bf16::from_f32(-7.0f32);
+    ///
+    /// assert!(!f.is_sign_negative());
+    /// assert!(g.is_sign_negative());
+    /// // NaN can be either positive or negative
+    /// assert!(nan.is_sign_positive() != nan.is_sign_negative());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_sign_negative(self) -> bool {
+        self.0 & Self::SIGN_MASK != 0
+    }
+
+    /// Returns a number composed of the magnitude of `self` and the sign of
+    /// `sign`.
+    ///
+    /// Equal to `self` if the sign of `self` and `sign` are the same, otherwise
+    /// equal to `-self`. If `self` is NaN, then NaN with the sign of `sign`
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let f = bf16::from_f32(3.5);
+    ///
+    /// assert_eq!(f.copysign(bf16::from_f32(0.42)), bf16::from_f32(3.5));
+    /// assert_eq!(f.copysign(bf16::from_f32(-0.42)), bf16::from_f32(-3.5));
+    /// assert_eq!((-f).copysign(bf16::from_f32(0.42)), bf16::from_f32(3.5));
+    /// assert_eq!((-f).copysign(bf16::from_f32(-0.42)), bf16::from_f32(-3.5));
+    ///
+    /// assert!(bf16::NAN.copysign(bf16::from_f32(1.0)).is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn copysign(self, sign: bf16) -> bf16 {
+        bf16((sign.0 & Self::SIGN_MASK) | (self.0 & Self::NOT_SIGN))
+    }
+
+    /// Takes the reciprocal (inverse) of a number, `1/x`.
+    #[must_use]
+    #[inline(always)]
+    pub fn recip(self) -> Self {
+        Self::ONE / self
+    }
+
+    /// Computes the sum of a slice of values.
+    ///
+    /// This is identical to [`Iterator::sum`]: the values are summed in [`f32`]
+    /// precision, in order, and the result is rounded once.
+    /// An empty slice sums to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let values = [bf16::from_f32(1.5), bf16::from_f32(2.0), bf16::from_f32(-0.25)];
+    /// assert_eq!(bf16::sum_slice(&values), bf16::from_f32(3.25));
+    /// assert_eq!(bf16::sum_slice(&values), values.iter().sum());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sum_slice(values: &[bf16]) -> bf16 {
+        bf16::from_f32(values.iter().map(|f| f.to_f32()).sum())
+    }
+
+    /// Computes the product of a slice of values.
+    ///
+    /// This is identical to [`Iterator::product`]: the values are multiplied
+    /// in [`f32`] precision, in order, and the result is rounded once.
+    /// The product of an empty slice is one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let values = [bf16::from_f32(1.5), bf16::from_f32(2.0), bf16::from_f32(-0.25)];
+    /// assert_eq!(bf16::product_slice(&values), bf16::from_f32(-0.75));
+    /// assert_eq!(bf16::product_slice(&values), values.iter().product());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn product_slice(values: &[bf16]) -> bf16 {
+        bf16::from_f32(values.iter().map(|f| f.to_f32()).product())
+    }
+
+    /// Computes `self + rhs`, returning `None` if the result is not finite.
+    ///
+    /// This returns `None` if either value is NaN, if the sum overflows to
+    /// ±∞, or if it is NaN, as `∞ + -∞` is. Otherwise, this returns the same
+    /// value as the `+` operator, so checked operations can be chained with
+    /// `?` and only ever produce finite values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// assert_eq!(bf16::ONE.checked_add(bf16::ONE), Some(bf16::from_f32(2.0)));
+    /// assert_eq!(bf16::MAX.checked_add(bf16::MAX), None);
+    /// assert_eq!(bf16::INFINITY.checked_add(bf16::ONE), None);
+    ///
+    /// fn axpy(a: bf16, x: bf16, y: bf16) -> Option<bf16> {
+    ///     a.checked_mul(x)?.checked_add(y)
+    /// }
+    /// assert_eq!(axpy(bf16::from_f32(2.0), bf16::ONE, bf16::ONE), Some(bf16::from_f32(3.0)));
+    /// assert_eq!(axpy(bf16::MAX, bf16::MAX, bf16::MIN), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_add(self, rhs: bf16) -> Option<bf16> {
+        let value = self + rhs;
+        if value.is_finite() {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Computes `self - rhs`, returning `None` if the result is not finite.
+    ///
+    /// This returns `None` if either value is NaN, if the difference
+    /// overflows to ±∞, or if it is NaN, as `∞ - ∞` is. Otherwise, this
+    /// returns the same value as the `-` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// assert_eq!(bf16::ONE.checked_sub(bf16::from_f32(0.5)), Some(bf16::from_f32(0.5)));
+    /// assert_eq!(bf16::MIN.checked_sub(bf16::MAX), None);
+    /// assert_eq!(bf16::NAN.checked_sub(bf16::ONE), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_sub(self, rhs: bf16) -> Option<bf16> {
+        let value = self - rhs;
+        if value.is_finite() {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Computes `self * rhs`, returning `None` if the result is not finite.
+    ///
+    /// This returns `None` if either value is NaN, if the product overflows
+    /// to ±∞, or if it is NaN, as `0 * ∞` is. Otherwise, this returns the
+    /// same value as the `*` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// assert_eq!(bf16::from_f32(3.0).checked_mul(bf16::from_f32(0.5)), Some(bf16::from_f32(1.5)));
+    /// assert_eq!(bf16::MAX.checked_mul(bf16::from_f32(2.0)), None);
+    /// assert_eq!(bf16::ZERO.checked_mul(bf16::INFINITY), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_mul(self, rhs: bf16) -> Option<bf16> {
+        let value = self * rhs;
+        if value.is_finite() {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Computes `self / rhs`, returning `None` if the result is not finite.
+    ///
+    /// This returns `None` if either value is NaN, if the quotient is ±∞,
+    /// as it is for a non-zero value divided by zero, or if it is NaN, as
+    /// `0 / 0` is. Otherwise, this returns the same value as the `/`
+    /// operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// assert_eq!(bf16::ONE.checked_div(bf16::from_f32(4.0)), Some(bf16::from_f32(0.25)));
+    /// assert_eq!(bf16::ONE.checked_div(bf16::ZERO), None);
+    /// assert_eq!(bf16::ONE.checked_div(bf16::INFINITY), Some(bf16::ZERO));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_div(self, rhs: bf16) -> Option<bf16> {
+        let value = self / rhs;
+        if value.is_finite() {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Computes `self % rhs`, returning `None` if the result is not finite.
+    ///
+    /// The result is NaN, and so this returns `None`, if either value is NaN,
+    /// `self` is infinite, or `rhs` is zero. Otherwise, this returns the same
+    /// value as the `%` operator, including `self` when `rhs` is infinite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let two = bf16::from_f32(2.0);
+    /// assert_eq!(bf16::from_f32(7.0).checked_rem(two), Some(bf16::ONE));
+    /// assert_eq!(bf16::ONE.checked_rem(bf16::INFINITY), Some(bf16::ONE));
+    /// assert_eq!(bf16::ONE.checked_rem(bf16::ZERO), None);
+    /// assert_eq!(bf16::INFINITY.checked_rem(two), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_rem(self, rhs: bf16) -> Option<bf16> {
+        let value = self % rhs;
+        if value.is_finite() {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Computes `-self`, returning `None` if the result is not finite.
+    ///
+    /// Like the other checked operations, this returns `None` for NaN and ±∞
+    /// even though negation is always exact, so a chain of checked operations
+    /// only produces finite values. Otherwise, this returns the same value as
+    /// the `-` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// assert_eq!(bf16::ONE.checked_neg(), Some(bf16::NEG_ONE));
+    /// assert_eq!(bf16::ZERO.checked_neg().map(bf16::to_bits), Some(bf16::NEG_ZERO.to_bits()));
+    /// assert_eq!(bf16::INFINITY.checked_neg(), None);
+    /// assert_eq!(bf16::NAN.checked_neg(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn checked_neg(self) -> Option<bf16> {
+        if self.is_finite() {
+            Some(bf16(self.0 ^ Self::SIGN_MASK))
+        } else {
+            None
+        }
+    }
+
+    /// Converts radians to degrees.
+    #[must_use]
+    #[inline(always)]
+    pub fn to_degrees(self) -> Self {
+        self * Self::from(180u8) / Self::PI
+    }
+
+    /// Converts degrees to radians.
+    #[must_use]
+    #[inline(always)]
+    pub fn to_radians(self) -> Self {
+        self * Self::PI / Self::from(180u8)
+    }
+
+    /// Linearly interpolates between `self` and `other` by `t`.
+    ///
+    /// This is computed in [`f32`], or in [`f64`] when the difference of the
+    /// endpoints overflows [`f32`], and then rounded to the nearest
+    /// [`struct@bf16`] value. Either way each operation rounds before the
+    /// final conversion, so it may differ from the exactly rounded
+    /// [`lerp_const`][Self::lerp_const] in the last place. The result is
+    /// exactly `self` when `t` is `0` and exactly `other` when `t` is `1`, and
+    /// is monotonic in `t`. Values of `t` outside of `[0, 1]` extrapolate
+    /// beyond the endpoints, and NaN in any argument returns NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let a = bf16::from_f32(1.0);
+    /// let b = bf16::from_f32(3.0);
+    /// assert_eq!(a.lerp(b, bf16::ZERO), a);
+    /// assert_eq!(a.lerp(b, bf16::from_f32(0.25)), bf16::from_f32(1.5));
+    /// assert_eq!(a.lerp(b, bf16::ONE), b);
+    /// assert_eq!(bf16::MIN.lerp(bf16::MAX, bf16::from_f32(0.5)), bf16::ZERO);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn lerp(self, other: bf16, t: bf16) -> bf16 {
+        let (a, b, t) = (self.to_f32(), other.to_f32(), t.to_f32());
+        let diff = b - a;
+        if diff.is_infinite() && a.is_finite() && b.is_finite() {
+            let (a, b, t) = (a as f64, b as f64, t as f64);
+            let diff = b - a;
+            let value = if t <= 0.5 {
+                a + t * diff
+            } else {
+                b - (1.0 - t) * diff
+            };
+            return bf16::from_f64(value);
+        }
+        // Interpolate from the nearer endpoint, so both endpoints are exact.
+        let value = if t <= 0.5 {
+            a + t * diff
+        } else {
+            b - (1.0 - t) * diff
+        };
+        bf16::from_f32(value)
+    }
+
+    /// Linearly interpolates between `self` and `other` by `t`, in a `const`
+    /// context.
+    ///
+    /// This computes `self + t * (other - self)` exactly with integer
+    /// arithmetic and rounds once to the nearest [`struct@bf16`] value, with
+    /// ties to even, so it can differ from [`lerp`][Self::lerp] in the last
+    /// place. The result is exactly `self` when `t` is `0` and exactly `other`
+    /// when `t` is `1`, and is monotonic in `t`. NaN in any argument returns
+    /// NaN, as do infinite arguments unless `t` selects an endpoint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// const A: bf16 = bf16::ONE;
+    /// const B: bf16 = bf16::from_f32_const(3.0);
+    /// const MID: bf16 = A.lerp_const(B, bf16::from_f32_const(0.5));
+    /// assert_eq!(MID, bf16::from_f32(2.0));
+    /// assert_eq!(A.lerp_const(B, bf16::ZERO), A);
+    /// assert_eq!(A.lerp_const(B, bf16::ONE), B);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn lerp_const(self, other: bf16, t: bf16) -> bf16 {
+        bf16(exact::lerp(self.0, other.0, t.0, 8, 7))
+    }
+
+    /// Returns if the integer `n` is exactly representable as a
+    /// [`struct@bf16`].
+    ///
+    /// Every integer with a magnitude up to
+    /// [`MAX_CONSECUTIVE_INTEGER`][Self::MAX_CONSECUTIVE_INTEGER] is
+    /// representable, while larger integers are only representable if they
+    /// fit in the significand and do not overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// assert!(bf16::can_represent_integer(256));
+    /// assert!(!bf16::can_represent_integer(257));
+    /// assert!(bf16::can_represent_integer(-256 - 2));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn can_represent_integer(n: i64) -> bool {
+        let n = n.unsigned_abs();
+        if n == 0 {
+            return true;
+        }
+        let width = 64 - n.leading_zeros();
+        let significant = width - n.trailing_zeros();
+        width <= Self::MAX_EXP as u32 && significant <= Self::MANTISSA_DIGITS
+    }
+
+    /// Adds one to an integer value, returning `None` if `self` is not an
+    /// integer or the result is not exactly representable.
+    ///
+    /// This is intended for counters, which stop working once adding one
+    /// would round back to the same value or skip an integer. Values beyond
+    /// [`MAX_CONSECUTIVE_INTEGER`][Self::MAX_CONSECUTIVE_INTEGER] return
+    /// `None`, as do infinities and NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let x = bf16::from_f32(255.0);
+    /// assert_eq!(x.increment_integer(), Some(bf16::MAX_CONSECUTIVE_INTEGER));
+    /// assert_eq!(bf16::MAX_CONSECUTIVE_INTEGER.increment_integer(), None);
+    /// assert_eq!(bf16::from_f32(0.5).increment_integer(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn increment_integer(self) -> Option<bf16> {
+        let value = self.to_f32();
+        // Saturating casts of large or non-finite values do not round-trip.
+        let n = value as i64;
+        if n as f32 != value {
+            return None;
+        }
+        let next = n.checked_add(1)?;
+        if Self::can_represent_integer(next) {
+            Some(bf16::from_f32(next as f32))
+        } else {
+            None
+        }
+    }
+
+    /// Rounds to the nearest integer, rounding half-way cases away from
+    /// zero.
+    ///
+    /// Values that are not exactly half-way between two integers round to
+    /// the nearest integer. The sign is preserved, so values in `(-0.5, 0)`
+    /// round to `-0`. Infinities, NaN and values that are already integers
+    /// are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// assert_eq!(bf16::from_f32(2.5).round_ties_away(), bf16::from_f32(3.0));
+    /// assert_eq!(bf16::from_f32(-0.5).round_ties_away(), bf16::from_f32(-1.0));
+    /// assert_eq!(bf16::from_f32(1.25).round_ties_away(), bf16::from_f32(1.0));
+    /// assert_eq!(bf16::from_f32(-0.25).round_ties_away().to_bits(), bf16::NEG_ZERO.to_bits());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn round_ties_away(self) -> bf16 {
+        let sign = self.0 & Self::SIGN_MASK;
+        let biased = ((self.0 & Self::EXP_MASK) >> 7) as i32;
+        let exp = biased - 127;
+        if biased == 0xFF || exp >= 7 {
+            // Infinite, NaN, or so large that every value is an integer.
+            self
+        } else if exp < -1 {
+            // Less than 0.5 in magnitude.
+            bf16(sign)
+        } else if exp == -1 {
+            // At least 0.5 but less than 1 in magnitude.
+            bf16(sign | Self::ONE.0)
+        } else {
+            // Add half of the lowest integer bit to the magnitude, and then
+            // clear the fraction. A carry out of the mantissa increments the
+            // exponent, which is still correct.
+            let fraction_bits = (7 - exp) as u32;
+            let half = 1u16 << (fraction_bits - 1);
+            let mask = (1u16 << fraction_bits) - 1;
+            let magnitude = ((self.0 & !Self::SIGN_MASK) + half) & !mask;
+            bf16(sign | magnitude)
+        }
+    }
+
+    /// Returns `e^(self)`, (the exponential function).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let one = bf16::ONE;
+    /// assert_eq!(one.exp(), bf16::E);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn exp(self) -> Self {
+        Self::from_f32(self.to_f32().exp())
+    }
+
+    /// Returns `2^(self)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let f = bf16::from_f32(3.0);
+    /// assert_eq!(f.exp2(), bf16::from_f32(8.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn exp2(self) -> Self {
+        Self::from_f32(self.to_f32().exp2())
+    }
+
+    /// Returns the natural logarithm of the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// assert_eq!(bf16::E.ln(), bf16::ONE);
+    /// assert!(bf16::from_f32(-1.0).ln().is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn ln(self) -> Self {
+        Self::from_f32(self.to_f32().ln())
+    }
+
+    /// Returns the base 2 logarithm of the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let f = bf16::from_f32(8.0);
+    /// assert_eq!(f.log2(), bf16::from_f32(3.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn log2(self) -> Self {
+        Self::from_f32(self.to_f32().log2())
+    }
+
+    /// Returns the base 10 logarithm of the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let f = bf16::from_f32(100.0);
+    /// assert_eq!(f.log10(), bf16::from_f32(2.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn log10(self) -> Self {
+        Self::from_f32(self.to_f32().log10())
+    }
+
+    /// Returns `e^(self) - 1` in a way that is accurate even if the number is
+    /// close to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let x = bf16::from_f32(1e-3);
+    /// // The Taylor series is exact to within the precision here.
+    /// let approx = x.to_f32() + x.to_f32() * x.to_f32() / 2.0;
+    /// assert_eq!(x.exp_m1(), bf16::from_f32(approx));
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn exp_m1(self) -> Self {
+        Self::from_f32(self.to_f32().exp_m1())
+    }
+
+    /// Returns `ln(1+n)` (natural logarithm) more accurately than if the
+    /// operations were performed separately.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let x = bf16::from_f32(1e-3);
+    /// // The Taylor series is exact to within the precision here.
+    /// let approx = x.to_f32() - x.to_f32() * x.to_f32() / 2.0;
+    /// assert_eq!(x.ln_1p(), bf16::from_f32(approx));
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn ln_1p(self) -> Self {
+        Self::from_f32(self.to_f32().ln_1p())
+    }
+
+    /// Computes the sine of a number (in radians).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let x = bf16::FRAC_PI_2;
+    /// assert_eq!(x.sin(), bf16::ONE);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn sin(self) -> Self {
+        Self::from_f32(self.to_f32().sin())
+    }
+
+    /// Computes the cosine of a number (in radians).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let x = bf16::ZERO;
+    /// assert_eq!(x.cos(), bf16::ONE);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn cos(self) -> Self {
+        Self::from_f32(self.to_f32().cos())
+    }
+
+    /// Computes the tangent of a number (in radians).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let x = bf16::FRAC_PI_4;
+    /// assert!((x.tan() - bf16::ONE).abs() <= bf16::EPSILON);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn tan(self) -> Self {
+        Self::from_f32(self.to_f32().tan())
+    }
+
+    /// Computes the arcsine of a number. Return value is in radians in the
+    /// range [-pi/2, pi/2] or NaN if the number is outside the range [-1, 1].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// assert_eq!(bf16::ONE.asin(), bf16::FRAC_PI_2);
+    /// assert!(bf16::from_f32(2.0).asin().is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn asin(self) -> Self {
+        Self::from_f32(self.to_f32().asin())
+    }
+
+    /// Computes the arccosine of a number. Return value is in radians in the
+    /// range [0, pi] or NaN if the number is outside the range [-1, 1].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// assert_eq!(bf16::NEG_ONE.acos(), bf16::PI);
+    /// assert!(bf16::from_f32(2.0).acos().is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn acos(self) -> Self {
+        Self::from_f32(self.to_f32().acos())
+    }
+
+    /// Computes the arctangent of a number. Return value is in radians in the
+    /// range [-pi/2, pi/2].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// assert_eq!(bf16::ONE.atan(), bf16::FRAC_PI_4);
+    /// assert_eq!(bf16::INFINITY.atan(), bf16::FRAC_PI_2);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn atan(self) -> Self {
+        Self::from_f32(self.to_f32().atan())
+    }
+
+    /// Computes the four quadrant arctangent of `self` (`y`) and `other` (`x`)
+    /// in radians.
+    ///
+    /// * `x = 0`, `y = 0`: `0`
+    /// * `x >= 0`: `arctan(y/x)` -> `[-pi/2, pi/2]`
+    /// * `y >= 0`: `arctan(y/x) + pi` -> `(pi/2, pi]`
+    /// * `y < 0`: `arctan(y/x) - pi` -> `(-pi, -pi/2)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let y = bf16::NEG_ONE;
+    /// let x = bf16::NEG_ONE;
+    /// assert_eq!(y.atan2(x), bf16::from_f32(-3.0 * core::f32::consts::FRAC_PI_4));
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn atan2(self, other: Self) -> Self {
+        Self::from_f32(self.to_f32().atan2(other.to_f32()))
+    }
+
+    /// Computes the length of the hypotenuse of a right-angle triangle given
+    /// legs of length `self` and `other`, `sqrt(self² + other²)`.
+    ///
+    /// This is computed with [`f32::hypot`] and then rounded to the nearest
+    /// [`struct@bf16`] value, so the squares never overflow or underflow, and
+    /// the result is only infinite if the exact result rounds to infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let a = bf16::from_f32(3.0);
+    /// let b = bf16::from_f32(4.0);
+    /// assert_eq!(a.hypot(b), bf16::from_f32(5.0));
+    ///
+    /// // The squares overflow, but the result does not.
+    /// let a = bf16::from_f32(3e38);
+    /// let b = bf16::from_f32(1e38);
+    /// assert!((a * a + b * b).is_infinite());
+    /// assert!(a.hypot(b).is_finite());
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn hypot(self, other: Self) -> Self {
+        Self::from_f32(self.to_f32().hypot(other.to_f32()))
+    }
+
+    /// Simultaneously computes the sine and cosine of the number, `x`.
+    /// Returns `(sin(x), cos(x))`.
+    ///
+    /// Both are computed with a single [`f32::sin_cos`] call, and each is then
+    /// rounded to [`struct@bf16`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let (sin, cos) = bf16::FRAC_PI_2.sin_cos();
+    /// assert_eq!(sin, bf16::ONE);
+    /// assert_eq!(cos, bf16::FRAC_PI_2.cos());
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn sin_cos(self) -> (Self, Self) {
+        let (sin, cos) = self.to_f32().sin_cos();
+        (Self::from_f32(sin), Self::from_f32(cos))
+    }
+
+    /// Returns the maximum of the two numbers.
+    ///
+    /// If one of the arguments is NaN, then the other argument is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let x = bf16::from_f32(1.0);
+    /// let y = bf16::from_f32(2.0);
+    ///
+    /// assert_eq!(x.max(y), y);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn max(self, other: bf16) -> bf16 {
+        if self.is_nan() || gt(other, self) {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the minimum of the two numbers.
+    ///
+    /// If one of the arguments is NaN, then the other argument is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let x = bf16::from_f32(1.0);
+    /// let y = bf16::from_f32(2.0);
+    ///
+    /// assert_eq!(x.min(y), x);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn min(self, other: bf16) -> bf16 {
+        if self.is_nan() || lt(other, self) {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the maximum of three numbers.
+    ///
+    /// This is the same as `a.max(b).max(c)`: NaN arguments are ignored, and
+    /// the result is only NaN if all three arguments are NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let [a, b, c] = [1., -2., 3.].map(bf16::from_f32);
+    ///
+    /// assert_eq!(bf16::max3(a, b, c), c);
+    /// assert_eq!(bf16::max3(a, b, bf16::NAN), a);
+    /// assert!(bf16::max3(bf16::NAN, bf16::NAN, bf16::NAN).is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn max3(a: bf16, b: bf16, c: bf16) -> bf16 {
+        a.max(b).max(c)
+    }
+
+    /// Returns the minimum of three numbers.
+    ///
+    /// This is the same as `a.min(b).min(c)`: NaN arguments are ignored, and
+    /// the result is only NaN if all three arguments are NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let [a, b, c] = [1., -2., 3.].map(bf16::from_f32);
+    ///
+    /// assert_eq!(bf16::min3(a, b, c), b);
+    /// assert_eq!(bf16::min3(bf16::NAN, a, c), a);
+    /// assert!(bf16::min3(bf16::NAN, bf16::NAN, bf16::NAN).is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn min3(a: bf16, b: bf16, c: bf16) -> bf16 {
+        a.min(b).min(c)
+    }
+
+    /// Restrict a value to a certain interval unless it is NaN.
+    ///
+    /// Returns `max` if `self` is greater than `max`, and `min` if `self` is
+    /// less than `min`. Otherwise this returns `self`.
+    ///
+    /// Note that this function returns NaN if the initial value was NaN as
+    /// well.
+    ///
+    /// # Panics
+    /// Panics if `min > max`, `min` is NaN, or `max` is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// assert!(bf16::from_f32(-3.0).clamp(bf16::from_f32(-2.0), bf16::from_f32(1.0)) == bf16::from_f32(-2.0));
+    /// assert!(bf16::from_f32(0.0).clamp(bf16::from_f32(-2.0), bf16::from_f32(1.0)) == bf16::from_f32(0.0));
+    /// assert!(bf16::from_f32(2.0).clamp(bf16::from_f32(-2.0), bf16::from_f32(1.0)) == bf16::from_f32(1.0));
+    /// assert!(bf16::NAN.clamp(bf16::from_f32(-2.0), bf16::from_f32(1.0)).is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn clamp(self, min: bf16, max: bf16) -> bf16 {
+        assert!(le(min, max));
+        let mut x = self;
+        if lt(x, min) {
+            x = min;
+        }
+        if gt(x, max) {
+            x = max;
+        }
+        x
+    }
+
+    /// Returns the ordering between `self` and `other`.
+    ///
+    /// Unlike the standard partial comparison between floating point numbers,
+    /// this comparison always produces an ordering in accordance to
+    /// the `totalOrder` predicate as defined in the IEEE 754 (2008 revision)
+    /// floating point standard. The values are ordered in the following
+    /// sequence:
+    ///
+    /// - negative quiet NaN
+    /// - negative signaling NaN
+    /// - negative infinity
+    /// - negative numbers
+    /// - negative subnormal numbers
+    /// - negative zero
+    /// - positive zero
+    /// - positive subnormal numbers
+    /// - positive numbers
+    /// - positive infinity
+    /// - positive signaling NaN
+    /// - positive quiet NaN.
+    ///
+    /// The ordering established by this function does not always agree with the
+    /// [`PartialOrd`] and [`PartialEq`] implementations of `bf16`. For example,
+    /// they consider negative and positive zero equal, while `total_cmp`
+    /// doesn't.
+    ///
+    /// The interpretation of the signaling NaN bit follows the definition in
+    /// the IEEE 754 standard, which may not match the interpretation by some of
+    /// the older, non-conformant (e.g. MIPS) hardware implementations.
+    ///
+    /// # Examples
+    /// ```
+    /// # use float16::bf16;
+    /// let mut v: Vec<bf16> = vec![];
+    /// v.push(bf16::ONE);
+    /// v.push(bf16::INFINITY);
+    /// v.push(bf16::NEG_INFINITY);
+    /// v.push(bf16::NAN);
+    /// v.push(bf16::MAX_SUBNORMAL);
+    /// v.push(-bf16::MAX_SUBNORMAL);
+    /// v.push(bf16::ZERO);
+    /// v.push(bf16::NEG_ZERO);
+    /// v.push(bf16::NEG_ONE);
+    /// v.push(bf16::MIN_POSITIVE);
+    ///
+    /// v.sort_by(|a, b| a.total_cmp(&b));
+    ///
+    /// assert!(v
+    ///     .into_iter()
+    ///     .zip(
+    ///         [
+    ///             bf16::NEG_INFINITY,
+    ///             bf16::NEG_ONE,
+    ///             -bf16::MAX_SUBNORMAL,
+    ///             bf16::NEG_ZERO,
+    ///             bf16::ZERO,
+    ///             bf16::MAX_SUBNORMAL,
+    ///             bf16::MIN_POSITIVE,
+    ///             bf16::ONE,
+    ///             bf16::INFINITY,
+    ///             bf16::NAN
+    ///         ]
+    ///         .iter()
+    ///     )
+    ///     .all(|(a, b)| a.to_bits() == b.to_bits()));
+    /// ```
+    // Implementation based on: https://doc.rust-lang.org/std/primitive.f32.html#method.total_cmp
+    #[inline]
+    #[must_use]
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        let mut left = self.to_bits() as i16;
+        let mut right = other.to_bits() as i16;
+        left ^= (((left >> 15) as u16) >> 1) as i16;
+        right ^= (((right >> 15) as u16) >> 1) as i16;
+        left.cmp(&right)
+    }
+
+    /// Returns the bits of the value mapped so that unsigned integer
+    /// comparisons follow the [`total_cmp`][Self::total_cmp] order.
+    ///
+    /// Negative NaNs map to the smallest integers and positive NaNs to the
+    /// largest, and `-0` maps to one less than `+0`. Adjacent values in the
+    /// total order map to adjacent integers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// assert!(bf16::NEG_ONE.to_ordered_bits() < bf16::NEG_ZERO.to_ordered_bits());
+    /// assert_eq!(bf16::NEG_ZERO.to_ordered_bits() + 1, bf16::ZERO.to_ordered_bits());
+    /// assert_eq!(bf16::MAX.to_ordered_bits() + 1, bf16::INFINITY.to_ordered_bits());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_ordered_bits(self) -> u16 {
+        if self.0 & Self::SIGN_MASK != 0 {
+            !self.0
+        } else {
+            self.0 | Self::SIGN_MASK
+        }
+    }
+
+    /// Returns the distance between two values in units in the last place
+    /// (ulps), the number of steps between adjacent [`struct@bf16`] values
+    /// needed to reach `other` from `self`.
+    ///
+    /// Equal values have a distance of 0 and adjacent values a distance of
+    /// 1, where `-0` and `+0` are treated as the same value and infinities
+    /// are adjacent to the largest finite values. Returns `None` if either
+    /// value is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let one = bf16::ONE;
+    /// assert_eq!(one.ulp_distance(one + bf16::EPSILON), Some(1));
+    /// assert_eq!(bf16::NEG_ZERO.ulp_distance(bf16::ZERO), Some(0));
+    /// assert_eq!(
+    ///     (-bf16::MIN_POSITIVE_SUBNORMAL).ulp_distance(bf16::MIN_POSITIVE_SUBNORMAL),
+    ///     Some(2)
+    /// );
+    /// assert_eq!(one.ulp_distance(bf16::NAN), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn ulp_distance(self, other: bf16) -> Option<u16> {
+        if self.is_nan() || other.is_nan() {
+            return None;
+        }
+        let (a, b) = (self.to_ordered_bits(), other.to_ordered_bits());
+        let distance = a.abs_diff(b);
+        // `-0` and `+0` are adjacent in the total order, so do not count the
+        // step between them when crossing zero.
+        if (self.0 ^ other.0) & Self::SIGN_MASK != 0 {
+            Some(distance - 1)
+        } else {
+            Some(distance)
+        }
+    }
+
+    /// Returns the value halfway between `self` and `other` in the order of
+    /// the representable values, for bisecting over the values themselves.
+    ///
+    /// This is not the numeric midpoint `(self + other) / 2`: values are
+    /// counted by their position, as in [`ulp_distance`][Self::ulp_distance],
+    /// where `-0` and `+0` are the same value, so the result has about as
+    /// many values between it and `self` as between it and `other`. For
+    /// example, the numeric midpoint of `1` and `2^16` is close to `2^15`, but
+    /// this is `2^8`. Repeatedly bisecting an interval in this way
+    /// reaches adjacent values after at most 16 steps, even from
+    /// [`NEG_INFINITY`][Self::NEG_INFINITY] to [`INFINITY`][Self::INFINITY].
+    ///
+    /// The result is strictly between the arguments unless they are equal or
+    /// adjacent, in which case it is the smaller argument. Zero is returned as
+    /// `+0`. If either argument is NaN, the first NaN argument is returned,
+    /// made quiet.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let (a, b) = (bf16::ONE, bf16::from_f32(65536.));
+    /// assert_eq!(a.bisect(b), bf16::from_f32(256.));
+    /// assert_eq!(b.bisect(a), bf16::from_f32(256.));
+    /// assert_eq!(bf16::NEG_INFINITY.bisect(bf16::INFINITY).to_bits(), 0);
+    /// assert_eq!(bf16::ONE.bisect(bf16::ONE), bf16::ONE);
+    /// assert!(bf16::ONE.bisect(bf16::NAN).is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn bisect(self, other: bf16) -> bf16 {
+        if self.is_nan() {
+            return self.quiet();
+        } else if other.is_nan() {
+            return other.quiet();
+        }
+        // The position of each value, where `-0` and `+0` are both 0. The
+        // shift rounds the sum down, towards the smaller argument.
+        const fn position(x: bf16) -> i32 {
+            let mag = (x.0 & bf16::NOT_SIGN) as i32;
+            if x.0 & bf16::SIGN_MASK != 0 {
+                -mag
+            } else {
+                mag
+            }
+        }
+        let mid = (position(self) + position(other)) >> 1;
+        if mid < 0 {
+            bf16(Self::SIGN_MASK | (-mid) as u16)
+        } else {
+            bf16(mid as u16)
+        }
+    }
+
+    /// Finds a root of `f` between `lo` and `hi` by [bisection][Self::bisect]
+    /// over the representable values, or returns `None` if there is no sign
+    /// change.
+    ///
+    /// If `f` is zero at either bound, that bound is returned. Otherwise,
+    /// `f(lo)` and `f(hi)` must have opposite signs, and the interval is
+    /// halved until it holds a value where `f` is zero, or two adjacent values
+    /// where `f` changes sign. Of those two values, the one where `f` has the
+    /// smaller magnitude is returned, preferring `lo`, so for a continuous `f`
+    /// that is computed accurately, this is the representable value nearest
+    /// to the root. `f` is called at most 18 times.
+    ///
+    /// The bounds may be given in either order. Returns `None` if either
+    /// bound is NaN, or `f` returns NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let f = |x: bf16| bf16::from_f32(x.to_f32() * x.to_f32() - 2.);
+    /// assert_eq!(bf16::find_root(f, bf16::ZERO, bf16::from_f32(2.)), Some(bf16::SQRT_2));
+    /// assert_eq!(bf16::find_root(f, bf16::from_f32(-2.), bf16::ZERO), Some(-bf16::SQRT_2));
+    /// assert_eq!(bf16::find_root(f, bf16::from_f32(2.), bf16::from_f32(3.)), None);
+    /// ```
+    #[must_use]
+    pub fn find_root(f: impl Fn(bf16) -> bf16, lo: bf16, hi: bf16) -> Option<bf16> {
+        if lo.is_nan() || hi.is_nan() {
+            return None;
+        }
+        let (mut lo, mut hi) = if hi < lo {
+            (hi, lo)
+        } else {
+            (lo, hi)
+        };
+        let (mut f_lo, mut f_hi) = (f(lo), f(hi));
+        if f_lo.is_zero() {
+            return Some(lo);
+        } else if f_hi.is_zero() {
+            return Some(hi);
+        } else if f_lo.is_nan()
+            || f_hi.is_nan()
+            || f_lo.is_sign_negative() == f_hi.is_sign_negative()
+        {
+            return None;
+        }
+        while lo.ulp_distance(hi) > Some(1) {
+            let mid = lo.bisect(hi);
+            let f_mid = f(mid);
+            if f_mid.is_zero() {
+                return Some(mid);
+            } else if f_mid.is_nan() {
+                return None;
+            } else if f_mid.is_sign_negative() == f_lo.is_sign_negative() {
+                lo = mid;
+                f_lo = f_mid;
+            } else {
+                hi = mid;
+                f_hi = f_mid;
+            }
+        }
+        if f_hi.abs() < f_lo.abs() {
+            Some(hi)
+        } else {
+            Some(lo)
+        }
+    }
+
+    /// Returns the absolute difference between two values, `|self - other|`.
+    ///
+    /// This computes the difference exactly with integer arithmetic and
+    /// rounds once to the nearest [`struct@bf16`] value, so it is the same as
+    /// `(self - other).abs()`, but can be used in a `const` context. `-0` and
+    /// `+0` have a difference of `+0`. NaN arguments and infinities of the
+    /// same sign return NaN, and any other infinite argument returns
+    /// [`INFINITY`][Self::INFINITY], including infinities of opposite signs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let (a, b) = (bf16::from_f32(1.5), bf16::from_f32(-2.));
+    /// assert_eq!(a.abs_diff(b), bf16::from_f32(3.5));
+    /// assert_eq!(b.abs_diff(a), bf16::from_f32(3.5));
+    /// assert_eq!(bf16::NEG_ZERO.abs_diff(bf16::ZERO).to_bits(), 0);
+    /// assert_eq!(bf16::INFINITY.abs_diff(bf16::NEG_INFINITY), bf16::INFINITY);
+    /// assert!(bf16::INFINITY.abs_diff(bf16::INFINITY).is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn abs_diff(self, other: bf16) -> bf16 {
+        bf16(exact::abs_diff(self.0, other.0, 8, 7))
+    }
+
+    /// Returns the distance between two values in units in the last place
+    /// (ulps), or `0` if either value is NaN.
+    ///
+    /// This is the same as [`ulp_distance`][Self::ulp_distance], with NaN
+    /// mapped to the sentinel `0` instead of `None`, so NaN arguments cannot
+    /// be told apart from equal values. Use
+    /// [`ulp_distance`][Self::ulp_distance] when the arguments may be NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let one = bf16::ONE;
+    /// assert_eq!(one.abs_diff_ulp(one + bf16::EPSILON), 1);
+    /// assert_eq!(bf16::NEG_ZERO.abs_diff_ulp(bf16::ZERO), 0);
+    /// assert_eq!(bf16::MAX.abs_diff_ulp(bf16::INFINITY), 1);
+    /// assert_eq!(one.abs_diff_ulp(bf16::NAN), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn abs_diff_ulp(self, other: bf16) -> u16 {
+        match self.ulp_distance(other) {
+            Some(distance) => distance,
+            None => 0,
+        }
+    }
+
+    /// Returns if two values are within `max_ulps` units in the last place
+    /// of each other.
+    ///
+    /// This uses the [`ulp_distance`][Self::ulp_distance] between the
+    /// values, so values on either side of zero compare sensibly and `-0`
+    /// equals `+0`. NaN never compares equal, and infinities only compare
+    /// equal to themselves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let x = bf16::ONE;
+    /// let y = x + bf16::EPSILON;
+    /// assert!(x != y);
+    /// assert!(x.ulps_eq(y, 1));
+    /// assert!(!x.ulps_eq(y, 0));
+    /// assert!(!bf16::NAN.ulps_eq(bf16::NAN, u16::MAX));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn ulps_eq(self, other: bf16, max_ulps: u16) -> bool {
+        if self.is_infinite() || other.is_infinite() {
+            return self.0 == other.0;
+        }
+        match self.ulp_distance(other) {
+            Some(distance) => distance <= max_ulps,
+            None => false,
+        }
+    }
+
+    /// Returns if two values are within an absolute difference of
+    /// `max_abs_diff` or within `max_ulps` units in the last place of each
+    /// other.
+    ///
+    /// The absolute difference handles values near zero, where a small
+    /// error is a large number of ulps, while the ulps handle large values.
+    /// The difference is computed in [`f32`] precision, so it is exact. NaN
+    /// never compares equal, and infinities only compare equal to themselves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let tiny = bf16::MIN_POSITIVE;
+    /// assert!(tiny.approx_eq(-tiny, bf16::EPSILON, 0));
+    /// assert!(!tiny.approx_eq(-tiny, bf16::ZERO, 4));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn approx_eq(self, other: bf16, max_abs_diff: bf16, max_ulps: u16) -> bool {
+        if self.is_infinite() || other.is_infinite() {
+            return self.0 == other.0;
+        }
+        let diff = self.to_f32() - other.to_f32();
+        let max = max_abs_diff.to_f32();
+        (-max..=max).contains(&diff) || self.ulps_eq(other, max_ulps)
+    }
+
+    /// Returns the smallest positive normal [`struct@bf16`] value,
+    /// [`MIN_POSITIVE`][Self::MIN_POSITIVE].
+    ///
+    /// This is the same as the constant, for generic code that needs a
+    /// function, and avoids confusing it with [`MIN`][Self::MIN], which is
+    /// the most negative finite value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(bf16::min_normal(), bf16::MIN_POSITIVE);
+    /// assert!(bf16::min_normal().is_normal());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn min_normal() -> bf16 {
+        Self::MIN_POSITIVE
+    }
+
+    /// Returns the largest finite [`struct@bf16`] value, [`MAX`][Self::MAX].
+    ///
+    /// This is the same as the constant, for generic code that needs a
+    /// function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(bf16::max_normal(), bf16::MAX);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn max_normal() -> bf16 {
+        Self::MAX
+    }
+
+    /// Returns the smallest positive subnormal [`struct@bf16`] value,
+    /// [`MIN_POSITIVE_SUBNORMAL`][Self::MIN_POSITIVE_SUBNORMAL].
+    ///
+    /// This is the same as the constant, for generic code that needs a
+    /// function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(bf16::min_positive_subnormal(), bf16::MIN_POSITIVE_SUBNORMAL);
+    /// assert!(bf16::min_positive_subnormal().is_subnormal());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn min_positive_subnormal() -> bf16 {
+        Self::MIN_POSITIVE_SUBNORMAL
+    }
+
+    /// Approximate number of [`struct@bf16`] significant digits in base 10
+    pub const DIGITS: u32 = 2;
+    /// [`struct@bf16`]
+    /// [machine epsilon](https://en.wikipedia.org/wiki/Machine_epsilon) value
+    ///
+    /// This is the difference between 1.0 and the next largest representable
+    /// number.
+    pub const EPSILON: bf16 = bf16(0x3C00u16);
+    /// [`struct@bf16`] positive Infinity (+∞)
+    pub const INFINITY: bf16 = bf16(0x7F80u16);
+    /// Number of [`struct@bf16`] significant digits in base 2
+    pub const MANTISSA_DIGITS: u32 = 8;
+    /// Largest finite [`struct@bf16`] value
+    pub const MAX: bf16 = bf16(0x7F7F);
+    /// Maximum possible [`struct@bf16`] power of 10 exponent
+    pub const MAX_10_EXP: i32 = 38;
+    /// Maximum possible [`struct@bf16`] power of 2 exponent
+    pub const MAX_EXP: i32 = 128;
+    /// Smallest finite [`struct@bf16`] value
+    pub const MIN: bf16 = bf16(0xFF7F);
+    /// Minimum possible normal [`struct@bf16`] power of 10 exponent
+    pub const MIN_10_EXP: i32 = -37;
+    /// One greater than the minimum possible normal [`struct@bf16`] power of 2
+    /// exponent
+    pub const MIN_EXP: i32 = -125;
+    /// Smallest positive normal [`struct@bf16`] value
+    pub const MIN_POSITIVE: bf16 = bf16(0x0080u16);
+    /// [`struct@bf16`] Not a Number (NaN)
+    pub const NAN: bf16 = bf16(0x7FC0u16);
+    /// [`struct@bf16`] signaling Not a Number (NaN), with the quiet bit clear
+    pub const SIGNALING_NAN: bf16 = bf16(0x7FA0u16);
+    /// [`struct@bf16`] negative infinity (-∞).
+    pub const NEG_INFINITY: bf16 = bf16(0xFF80u16);
+    /// The radix or base of the internal representation of [`struct@bf16`]
+    pub const RADIX: u32 = 2;
+
+    /// Minimum positive subnormal [`struct@bf16`] value
+    pub const MIN_POSITIVE_SUBNORMAL: bf16 = bf16(0x0001u16);
+    /// Maximum subnormal [`struct@bf16`] value
+    pub const MAX_SUBNORMAL: bf16 = bf16(0x007Fu16);
+
+    /// Largest integer such that every integer of smaller magnitude is
+    /// exactly representable as a [`struct@bf16`], 256
+    pub const MAX_CONSECUTIVE_INTEGER: bf16 = bf16(0x4380u16);
+
+    /// [`struct@bf16`] 1
+    pub const ONE: bf16 = bf16(0x3F80u16);
+    /// [`struct@bf16`] 0
+    pub const ZERO: bf16 = bf16(0x0000u16);
+    /// [`struct@bf16`] -0
+    pub const NEG_ZERO: bf16 = bf16(0x8000u16);
+    /// [`struct@bf16`] -1
+    pub const NEG_ONE: bf16 = bf16(0xBF80u16);
+
+    /// [`struct@bf16`] Euler's number (ℯ)
+    pub const E: bf16 = bf16(0x402Eu16);
+    /// [`struct@bf16`] Archimedes' constant (π)
+    pub const PI: bf16 = bf16(0x4049u16);
+    /// [`struct@bf16`] 1/π
+    pub const FRAC_1_PI: bf16 = bf16(0x3EA3u16);
+    /// [`struct@bf16`] 1/√2
+    pub const FRAC_1_SQRT_2: bf16 = bf16(0x3F35u16);
+    /// [`struct@bf16`] 2/π
+    pub const FRAC_2_PI: bf16 = bf16(0x3F23u16);
+    /// [`struct@bf16`] 2/√π
+    pub const FRAC_2_SQRT_PI: bf16 = bf16(0x3F90u16);
+    /// [`struct@bf16`] π/2
+    pub const FRAC_PI_2: bf16 = bf16(0x3FC9u16);
+    /// [`struct@bf16`] π/3
+    pub const FRAC_PI_3: bf16 = bf16(0x3F86u16);
+    /// [`struct@bf16`] π/4
+    pub const FRAC_PI_4: bf16 = bf16(0x3F49u16);
+    /// [`struct@bf16`] π/6
+    pub const FRAC_PI_6: bf16 = bf16(0x3F06u16);
+    /// [`struct@bf16`] π/8
+    pub const FRAC_PI_8: bf16 = bf16(0x3EC9u16);
+    /// [`struct@bf16`] 𝗅𝗇 10
+    pub const LN_10: bf16 = bf16(0x4013u16);
+    /// [`struct@bf16`] 𝗅𝗇 2
+    pub const LN_2: bf16 = bf16(0x3F31u16);
+    /// [`struct@bf16`] 𝗅𝗈𝗀₁₀ℯ
+    pub const LOG10_E: bf16 = bf16(0x3EDEu16);
+    /// [`struct@bf16`] 𝗅𝗈𝗀₁₀2
+    pub const LOG10_2: bf16 = bf16(0x3E9Au16);
+    /// [`struct@bf16`] 𝗅𝗈𝗀₂ℯ
+    pub const LOG2_E: bf16 = bf16(0x3FB9u16);
+    /// [`struct@bf16`] 𝗅𝗈𝗀₂10
+    pub const LOG2_10: bf16 = bf16(0x4055u16);
+    /// [`struct@bf16`] √2
+    pub const SQRT_2: bf16 = bf16(0x3FB5u16);
+
+    /// Sign bit
+    pub const SIGN_MASK: u16 = 0x8000;
+    // Private helper for comparisons.
+    const NOT_SIGN: u16 = !Self::SIGN_MASK;
+
+    /// Exponent mask
+    pub const EXP_MASK: u16 = 0x7F80;
+
+    /// Mask for the hidden bit.
+    pub const HIDDEN_BIT_MASK: u16 = 0x0080;
+
+    /// Mantissa mask
+    pub const MAN_MASK: u16 = 0x007F;
+    // Private helper for signaling NaNs.
+    const QUIET_BIT: u16 = 0x0040;
+
+    /// Minimum representable positive value (min subnormal)
+    pub const TINY_BITS: u16 = 0x1;
+
+    /// Minimum representable negative value (min negative subnormal)
+    pub const NEG_TINY_BITS: u16 = Self::TINY_BITS | Self::SIGN_MASK;
+}
+
+// Every mathematical constant is the correctly rounded value of the
+// corresponding `f64` constant, checked at compile time.
+macro_rules! assert_consts {
+    ($($name:ident)*) => {
+        const _: () = {
+            $(
+                assert!(
+                    bf16::$name.0 == bf16::from_f64_const(core::f64::consts::$name).0,
+                    concat!("`bf16::", stringify!($name), "` is not correctly rounded"),
+                );
+            )*
+        };
+    };
+}
+
+assert_consts! {
+    E PI FRAC_1_PI FRAC_1_SQRT_2 FRAC_2_PI FRAC_2_SQRT_PI FRAC_PI_2 FRAC_PI_3 FRAC_PI_4 FRAC_PI_6
+    FRAC_PI_8 LN_10 LN_2 LOG10_E LOG10_2 LOG2_E LOG2_10 SQRT_2
+}
+
+macro_rules! from_int_lossless_impl {
+    ($t:ty, $func:ident) => {
+        /// Create from the integral type, if it is exactly representable.
+        ///
+        /// Returns `None` if the integer would be rounded or is too large to
+        /// fit. `0` is `+0`.
+        #[inline]
+        #[must_use]
+        #[allow(unused_comparisons)]
+        pub const fn $func(value: $t) -> Option<Self> {
+            let negative = value < 0;
+            let mag = if negative {
+                (value as i128).unsigned_abs()
+            } else {
+                value as u128
+            };
+            match fixed::int_to_half_exact(negative, mag, 8, 7) {
+                Some(bits) => Some(Self(bits)),
+                None => None,
+            }
+        }
+    };
+}
+
+macro_rules! from_int_impl {
+    ($t:ty, $func:ident) => {
+        /// Create from the integral type, as if by an `as` cast.
+        #[inline(always)]
+        pub const fn $func(value: $t) -> Self {
+            Self::from_f32_const(value as f32)
+        }
+    };
+}
+
+macro_rules! to_int_impl {
+    ($t:ty, $func:ident) => {
+        /// Convert to the integral type, as if by an `as` cast.
+        ///
+        /// This truncates toward zero, saturates values outside the range of
+        /// the type, including ±∞, to its minimum or maximum, and converts
+        /// NaN to `0`.
+        #[inline]
+        #[must_use]
+        pub const fn $func(self) -> $t {
+            let (negative, mag) = fixed::half_to_int_trunc(self.0, 8, 7);
+            if negative {
+                if mag >= (<$t>::MIN as i128).unsigned_abs() {
+                    <$t>::MIN
+                } else {
+                    (mag as $t).wrapping_neg()
+                }
+            } else if mag > <$t>::MAX as u128 {
+                <$t>::MAX
+            } else {
+                mag as $t
+            }
+        }
+    };
+}
+
+impl bf16 {
+    from_int_impl!(u8, from_u8);
+    from_int_impl!(u16, from_u16);
+    from_int_impl!(u32, from_u32);
+    from_int_impl!(u64, from_u64);
+    from_int_impl!(u128, from_u128);
+    from_int_impl!(i8, from_i8);
+    from_int_impl!(i16, from_i16);
+    from_int_impl!(i32, from_i32);
+    from_int_impl!(i64, from_i64);
+    from_int_impl!(i128, from_i128);
+    from_int_lossless_impl!(u8, from_u8_lossless);
+    from_int_lossless_impl!(u16, from_u16_lossless);
+    from_int_lossless_impl!(u32, from_u32_lossless);
+    from_int_lossless_impl!(u64, from_u64_lossless);
+    from_int_lossless_impl!(u128, from_u128_lossless);
+    from_int_lossless_impl!(i8, from_i8_lossless);
+    from_int_lossless_impl!(i16, from_i16_lossless);
+    from_int_lossless_impl!(i32, from_i32_lossless);
+    from_int_lossless_impl!(i64, from_i64_lossless);
+    from_int_lossless_impl!(i128, from_i128_lossless);
+    to_int_impl!(u8, to_u8);
+    to_int_impl!(u16, to_u16);
+    to_int_impl!(u32, to_u32);
+    to_int_impl!(u64, to_u64);
+    to_int_impl!(u128, to_u128);
+    to_int_impl!(i8, to_i8);
+    to_int_impl!(i16, to_i16);
+    to_int_impl!(i32, to_i32);
+    to_int_impl!(i64, to_i64);
+    to_int_impl!(i128, to_i128);
+
+    /// Constructs a [`struct@bf16`] value from an [`i64`], if it is exactly
+    /// representable.
+    ///
+    /// Every integer up to `256` in magnitude is exact. Above that, an integer
+    /// is only exact if it has at most 8 significant bits, such as even
+    /// integers up to `512` or any power of two, however large. Returns `None`
+    /// if the integer would be rounded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(bf16::from_i64_exact(-256), Some(bf16::from_f32(-256.0)));
+    /// assert_eq!(bf16::from_i64_exact(257), None);
+    /// assert_eq!(bf16::from_i64_exact(258), Some(bf16::from_f32(258.0)));
+    /// assert_eq!(bf16::from_i64_exact(i64::MIN), Some(bf16::from_f64(-2f64.powi(63))));
+    /// assert_eq!(bf16::from_i64_exact(i64::MAX), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_i64_exact(value: i64) -> Option<bf16> {
+        Self::from_i64_lossless(value)
+    }
+
+    /// Converts a [`bool`] to [`bf16::ONE`][Self::ONE] if it is `true`, or
+    /// [`bf16::ZERO`][Self::ZERO] if it is `false`.
+    ///
+    /// This is the same as converting `value as u8`, and is also available
+    /// as the [`From<bool>`] implementation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(bf16::from_bool(true), bf16::ONE);
+    /// assert_eq!(bf16::from(false).to_bits(), bf16::ZERO.to_bits());
+    ///
+    /// const MASK: [bf16; 2] = [bf16::from_bool(false), bf16::from_bool(true)];
+    /// assert_eq!(MASK, [bf16::ZERO, bf16::ONE]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_bool(value: bool) -> bf16 {
+        if value {
+            Self::ONE
+        } else {
+            Self::ZERO
+        }
+    }
+}
+
+impl From<bf16> for f32 {
+    #[inline]
+    fn from(x: bf16) -> f32 {
+        x.to_f32()
+    }
+}
+
+impl From<bf16> for f64 {
+    #[inline]
+    fn from(x: bf16) -> f64 {
+        x.to_f64()
+    }
+}
+
+impl From<i8> for bf16 {
+    #[inline]
+    fn from(x: i8) -> bf16 {
+        // Convert to f32, then to bf16
+        bf16::from_f32(f32::from(x))
+    }
+}
+
+impl From<u8> for bf16 {
+    #[inline]
+    fn from(x: u8) -> bf16 {
+        // Convert to f32, then to f16
+        bf16::from_f32(f32::from(x))
+    }
+}
+
+impl From<bool> for bf16 {
+    #[inline]
+    fn from(x: bool) -> bf16 {
+        bf16::from_bool(x)
+    }
+}
+
+macro_rules! try_from_int_impl {
+    ($($t:ty => $func:ident),*) => {$(
+        impl TryFrom<$t> for bf16 {
+            type Error = TryFromFloatError;
+
+            /// Converts the integer if it is exactly representable, as with
+            #[doc = concat!("[`bf16::", stringify!($func), "`][crate::bf16::", stringify!($func), "].")]
+            #[inline]
+            fn try_from(x: $t) -> Result<Self, Self::Error> {
+                Self::$func(x).ok_or(TryFromFloatError(()))
+            }
+        }
+    )*};
+}
+
+try_from_int_impl! {
+    u16 => from_u16_lossless,
+    u32 => from_u32_lossless,
+    u64 => from_u64_lossless,
+    i16 => from_i16_lossless,
+    i32 => from_i32_lossless,
+    i64 => from_i64_lossless
+}
+
+impl TryFrom<f32> for bf16 {
+    type Error = TryFromFloatError;
+
+    #[inline]
+    fn try_from(x: f32) -> Result<Self, Self::Error> {
+        Self::from_f32_lossless(x).ok_or(TryFromFloatError(()))
+    }
+}
+
+impl TryFrom<f64> for bf16 {
+    type Error = TryFromFloatError;
+
+    #[inline]
+    fn try_from(x: f64) -> Result<Self, Self::Error> {
+        Self::from_f64_lossless(x).ok_or(TryFromFloatError(()))
+    }
+}
+
+impl TryFrom<f16> for bf16 {
+    type Error = TryFromFloatError;
+
+    /// Converts the value if it is exactly representable, as with
+    /// [`bf16::from_f16_lossless`][crate::bf16::from_f16_lossless].
+    #[inline]
+    fn try_from(x: f16) -> Result<Self, Self::Error> {
+        Self::from_f16_lossless(x).ok_or(TryFromFloatError(()))
+    }
+}
+
+macro_rules! try_from_bf16_impl {
+    ($($t:ty)*) => {$(
+        impl TryFrom<bf16> for $t {
+            type Error = TryFromHalfError;
+
+            /// Converts the value to the integer type, failing if it is NaN,
+            /// infinite, out of range or not an integer. `-0` converts to
+            /// `0`.
+            #[inline]
+            fn try_from(x: bf16) -> Result<Self, Self::Error> {
+                let (negative, mag, fractional) =
+                    fixed::half_to_int_checked(x.0, 8, 7).map_err(TryFromHalfError)?;
+                let value = if negative {
+                    if mag > (<$t>::MIN as i128).unsigned_abs() {
+                        return Err(TryFromHalfError(TryFromHalfErrorKind::OutOfRange));
+                    }
+                    (mag as $t).wrapping_neg()
+                } else {
+                    if mag > <$t>::MAX as u128 {
+                        return Err(TryFromHalfError(TryFromHalfErrorKind::OutOfRange));
+                    }
+                    mag as $t
+                };
+                if fractional {
+                    Err(TryFromHalfError(TryFromHalfErrorKind::Fractional))
+                } else {
+                    Ok(value)
+                }
+            }
+        }
+    )*};
+}
+
+try_from_bf16_impl! { u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 }
+
+#[cfg(not(feature = "total-order-impls"))]
+impl PartialEq for bf16 {
+    fn eq(&self, other: &bf16) -> bool {
+        eq(*self, *other)
+    }
+}
+
+#[cfg(not(feature = "total-order-impls"))]
+impl PartialOrd for bf16 {
+    fn partial_cmp(&self, other: &bf16) -> Option<Ordering> {
+        if self.is_nan() || other.is_nan() {
+            None
+        } else {
+            let neg = self.0 & Self::SIGN_MASK != 0;
+            let other_neg = other.0 & Self::SIGN_MASK != 0;
+            match (neg, other_neg) {
+                (false, false) => Some(self.0.cmp(&other.0)),
+                (false, true) => {
+                    if (self.0 | other.0) & Self::NOT_SIGN == 0 {
+                        Some(Ordering::Equal)
+                    } else {
+                        Some(Ordering::Greater)
+                    }
+                },
+                (true, false) => {
+                    if (self.0 | other.0) & Self::NOT_SIGN == 0 {
+                        Some(Ordering::Equal)
+                    } else {
+                        Some(Ordering::Less)
+                    }
+                },
+                (true, true) => Some(other.0.cmp(&self.0)),
+            }
+        }
+    }
+
+    fn lt(&self, other: &bf16) -> bool {
+        lt(*self, *other)
+    }
+
+    fn le(&self, other: &bf16) -> bool {
+        le(*self, *other)
+    }
+
+    fn gt(&self, other: &bf16) -> bool {
+        gt(*self, *other)
+    }
+
+    fn ge(&self, other: &bf16) -> bool {
+        ge(*self, *other)
+    }
+}
+
+/// With the `total-order-impls` feature, values are equal only if their bits
+/// are equal, so `-0 != +0` and every NaN equals itself.
+#[cfg(feature = "total-order-impls")]
+impl PartialEq for bf16 {
+    #[inline]
+    fn eq(&self, other: &bf16) -> bool {
+        self.0 == other.0
+    }
+}
+
+/// With the `total-order-impls` feature, equality is the bitwise equality of
+/// [`PartialEq`].
+#[cfg(feature = "total-order-impls")]
+impl Eq for bf16 {
+}
+
+/// With the `total-order-impls` feature, this is the order of
+/// [`total_cmp`][bf16::total_cmp], which disagrees with the IEEE 754
+/// comparisons: `-0 < +0`, and NaN values are ordered by sign and payload
+/// below or above every other value.
+#[cfg(feature = "total-order-impls")]
+impl PartialOrd for bf16 {
+    #[inline]
+    fn partial_cmp(&self, other: &bf16) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// With the `total-order-impls` feature, this is the order of
+/// [`total_cmp`][bf16::total_cmp].
+#[cfg(feature = "total-order-impls")]
+impl Ord for bf16 {
+    #[inline]
+    fn cmp(&self, other: &bf16) -> Ordering {
+        self.total_cmp(other)
+    }
+}
+
+/// With the `total-order-impls` feature, this hashes the bits, consistent
+/// with the bitwise equality of [`PartialEq`].
+#[cfg(feature = "total-order-impls")]
+impl Hash for bf16 {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl FromStr for bf16 {
+    type Err = ParseFloatError;
+
+    #[inline]
+    fn from_str(src: &str) -> Result<bf16, ParseFloatError> {
+        decimal::parse_decimal(src, 8, 7).map(bf16)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl Debug for bf16 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        Debug::fmt(&self.to_f32(), f)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl Display for bf16 {
+    /// Formats the value using the shortest decimal that parses back to the
+    /// same value, unless a precision is given.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        if f.precision().is_some() || !self.is_finite() {
+            return Display::fmt(&self.to_f32(), f);
+        }
+        shortest::fmt_positional(f, self.0, 8, 7)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl LowerExp for bf16 {
+    /// Formats the value in scientific notation using the shortest decimal
+    /// that parses back to the same value, unless a precision is given.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        if f.precision().is_some() || !self.is_finite() {
+            return LowerExp::fmt(&self.to_f32(), f);
+        }
+        shortest::fmt_scientific(f, self.0, 8, 7, 'e')
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl UpperExp for bf16 {
+    /// Formats the value in scientific notation using the shortest decimal
+    /// that parses back to the same value, unless a precision is given.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        if f.precision().is_some() || !self.is_finite() {
+            return UpperExp::fmt(&self.to_f32(), f);
+        }
+        shortest::fmt_scientific(f, self.0, 8, 7, 'E')
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl Binary for bf16 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{:b}", self.0)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl Octal for bf16 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{:o}", self.0)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl LowerHex for bf16 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{:x}", self.0)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl UpperHex for bf16 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{:X}", self.0)
+    }
+}
+
+impl Neg for bf16 {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self(self.0 ^ Self::SIGN_MASK)
+    }
+}
+
+impl Neg for &bf16 {
+    type Output = <bf16 as Neg>::Output;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Neg::neg(*self)
+    }
+}
+
+impl Add for bf16 {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_f32(Self::to_f32(self) + Self::to_f32(rhs))
+    }
+}
+
+impl Add<&bf16> for bf16 {
+    type Output = <bf16 as Add<bf16>>::Output;
+
+    #[inline]
+    fn add(self, rhs: &bf16) -> Self::Output {
+        self.add(*rhs)
+    }
+}
+
+impl Add<&bf16> for &bf16 {
+    type Output = <bf16 as Add<bf16>>::Output;
+
+    #[inline]
+    fn add(self, rhs: &bf16) -> Self::Output {
+        (*self).add(*rhs)
+    }
+}
+
+impl Add<bf16> for &bf16 {
+    type Output = <bf16 as Add<bf16>>::Output;
+
+    #[inline]
+    fn add(self, rhs: bf16) -> Self::Output {
+        (*self).add(rhs)
+    }
+}
+
+impl AddAssign for bf16 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = (*self).add(rhs);
+    }
+}
+
+impl AddAssign<&bf16> for bf16 {
+    #[inline]
+    fn add_assign(&mut self, rhs: &bf16) {
+        *self = (*self).add(rhs);
+    }
+}
+
+impl Sub for bf16 {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::from_f32(Self::to_f32(self) - Self::to_f32(rhs))
+    }
+}
+
+impl Sub<&bf16> for bf16 {
+    type Output = <bf16 as Sub<bf16>>::Output;
+
+    #[inline]
+    fn sub(self, rhs: &bf16) -> Self::Output {
+        self.sub(*rhs)
+    }
+}
+
+impl Sub<&bf16> for &bf16 {
+    type Output = <bf16 as Sub<bf16>>::Output;
+
+    #[inline]
+    fn sub(self, rhs: &bf16) -> Self::Output {
+        (*self).sub(*rhs)
+    }
+}
+
+impl Sub<bf16> for &bf16 {
+    type Output = <bf16 as Sub<bf16>>::Output;
+
+    #[inline]
+    fn sub(self, rhs: bf16) -> Self::Output {
+        (*self).sub(rhs)
+    }
+}
+
+impl SubAssign for bf16 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = (*self).sub(rhs);
+    }
+}
+
+impl SubAssign<&bf16> for bf16 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &bf16) {
+        *self = (*self).sub(rhs);
+    }
+}
+
+impl Mul for bf16 {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::from_f32(Self::to_f32(self) * Self::to_f32(rhs))
+    }
+}
+
+impl Mul<&bf16> for bf16 {
+    type Output = <bf16 as Mul<bf16>>::Output;
+
+    #[inline]
+    fn mul(self, rhs: &bf16) -> Self::Output {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&bf16> for &bf16 {
+    type Output = <bf16 as Mul<bf16>>::Output;
+
+    #[inline]
+    fn mul(self, rhs: &bf16) -> Self::Output {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<bf16> for &bf16 {
+    type Output = <bf16 as Mul<bf16>>::Output;
+
+    #[inline]
+    fn mul(self, rhs: bf16) -> Self::Output {
+        (*self).mul(rhs)
+    }
+}
+
+impl MulAssign for bf16 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = (*self).mul(rhs);
+    }
+}
+
+impl MulAssign<&bf16> for bf16 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &bf16) {
+        *self = (*self).mul(rhs);
+    }
+}
+
+impl Div for bf16 {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        Self::from_f32(Self::to_f32(self) / Self::to_f32(rhs))
+    }
+}
+
+impl Div<&bf16> for bf16 {
+    type Output = <bf16 as Div<bf16>>::Output;
+
+    #[inline]
+    fn div(self, rhs: &bf16) -> Self::Output {
+        self.div(*rhs)
+    }
+}
+
+impl Div<&bf16> for &bf16 {
+    type Output = <bf16 as Div<bf16>>::Output;
+
+    #[inline]
+    fn div(self, rhs: &bf16) -> Self::Output {
+        (*self).div(*rhs)
+    }
+}
+
+impl Div<bf16> for &bf16 {
+    type Output = <bf16 as Div<bf16>>::Output;
+
+    #[inline]
+    fn div(self, rhs: bf16) -> Self::Output {
+        (*self).div(rhs)
+    }
+}
+
+impl DivAssign for bf16 {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = (*self).div(rhs);
+    }
+}
+
+impl DivAssign<&bf16> for bf16 {
+    #[inline]
+    fn div_assign(&mut self, rhs: &bf16) {
+        *self = (*self).div(rhs);
+    }
+}
+
+impl Rem for bf16 {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        Self::from_f32(Self::to_f32(self) % Self::to_f32(rhs))
+    }
+}
+
+impl Rem<&bf16> for bf16 {
+    type Output = <bf16 as Rem<bf16>>::Output;
+
+    #[inline]
+    fn rem(self, rhs: &bf16) -> Self::Output {
+        self.rem(*rhs)
+    }
+}
+
+impl Rem<&bf16> for &bf16 {
+    type Output = <bf16 as Rem<bf16>>::Output;
+
+    #[inline]
+    fn rem(self, rhs: &bf16) -> Self::Output {
+        (*self).rem(*rhs)
+    }
+}
+
+impl Rem<bf16> for &bf16 {
+    type Output = <bf16 as Rem<bf16>>::Output;
+
+    #[inline]
+    fn rem(self, rhs: bf16) -> Self::Output {
+        (*self).rem(rhs)
+    }
+}
+
+impl RemAssign for bf16 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = (*self).rem(rhs);
+    }
+}
+
+impl RemAssign<&bf16> for bf16 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: &bf16) {
+        *self = (*self).rem(rhs);
+    }
+}
+
+impl Product for bf16 {
+    #[inline]
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        bf16::from_f32(iter.map(|f| f.to_f32()).product())
+    }
+}
+
+impl<'a> Product<&'a bf16> for bf16 {
+    #[inline]
+    fn product<I: Iterator<Item = &'a bf16>>(iter: I) -> Self {
+        bf16::from_f32(iter.map(|f| f.to_f32()).product())
+    }
+}
+
+impl Sum for bf16 {
+    #[inline]
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        bf16::from_f32(iter.map(|f| f.to_f32()).sum())
+    }
+}
+
+impl<'a> Sum<&'a bf16> for bf16 {
+    #[inline]
+    fn sum<I: Iterator<Item = &'a bf16>>(iter: I) -> Self {
+        bf16::from_f32(iter.map(|f| f.to_f32()).sum())
+    }
+}
+
+#[cfg(not(feature = "total-order-impls"))]
+#[inline]
+const fn eq(lhs: bf16, rhs: bf16) -> bool {
+    if lhs.is_nan() || rhs.is_nan() {
+        false
+    } else {
+        (lhs.0 == rhs.0) || ((lhs.0 | rhs.0) & bf16::NOT_SIGN == 0)
+    }
+}
+
+#[inline]
+const fn lt(lhs: bf16, rhs: bf16) -> bool {
+    if lhs.is_nan() || rhs.is_nan() {
+        false
+    } else {
+        let neg = lhs.0 & bf16::SIGN_MASK != 0;
+        let rhs_neg = rhs.0 & bf16::SIGN_MASK != 0;
+        match (neg, rhs_neg) {
+            (false, false) => lhs.0 < rhs.0,
+            (false, true) => false,
+            (true, false) => (lhs.0 | rhs.0) & bf16::NOT_SIGN != 0,
+            (true, true) => lhs.0 > rhs.0,
+        }
+    }
+}
+
+#[inline]
+const fn le(lhs: bf16, rhs: bf16) -> bool {
+    if lhs.is_nan() || rhs.is_nan() {
+        false
+    } else {
+        let neg = lhs.0 & bf16::SIGN_MASK != 0;
+        let rhs_neg = rhs.0 & bf16::SIGN_MASK != 0;
+        match (neg, rhs_neg) {
+            (false, false) => lhs.0 <= rhs.0,
+            (false, true) => (lhs.0 | rhs.0) & bf16::NOT_SIGN == 0,
+            (true, false) => true,
+            (true, true) => lhs.0 >= rhs.0,
+        }
+    }
+}
+
+#[inline]
+const fn gt(lhs: bf16, rhs: bf16) -> bool {
+    if lhs.is_nan() || rhs.is_nan() {
+        false
+    } else {
+        let neg = lhs.0 & bf16::SIGN_MASK != 0;
+        let rhs_neg = rhs.0 & bf16::SIGN_MASK != 0;
+        match (neg, rhs_neg) {
+            (false, false) => lhs.0 > rhs.0,
+            (false, true) => (lhs.0 | rhs.0) & bf16::NOT_SIGN != 0,
+            (true, false) => false,
+            (true, true) => lhs.0 < rhs.0,
+        }
+    }
+}
+
+#[cfg(not(feature = "total-order-impls"))]
+#[inline]
+const fn ge(lhs: bf16, rhs: bf16) -> bool {
+    if lhs.is_nan() || rhs.is_nan() {
+        false
+    } else {
+        let neg = lhs.0 & bf16::SIGN_MASK != 0;
+        let rhs_neg = rhs.0 & bf16::SIGN_MASK != 0;
+        match (neg, rhs_neg) {
+            (false, false) => lhs.0 >= rhs.0,
+            (false, true) => true,
+            (true, false) => (lhs.0 | rhs.0) & bf16::NOT_SIGN == 0,
+            (true, true) => lhs.0 <= rhs.0,
+        }
+    }
+}
+
+#[allow(clippy::cognitive_complexity, clippy::float_cmp, clippy::neg_cmp_op_on_partial_ord)]
+#[cfg(test)]
+mod test {
+    use core::cmp::Ordering;
+
+    use super::*;
+
+    #[test]
+    fn test_bf16_consts_from_f32() {
+        let one = bf16::from_f32(1.0);
+        let zero = bf16::from_f32(0.0);
+        let neg_zero = bf16::from_f32(-0.0);
+        let neg_one = bf16::from_f32(-1.0);
+        let inf = bf16::from_f32(core::f32::INFINITY);
+        let neg_inf = bf16::from_f32(core::f32::NEG_INFINITY);
+        let nan = bf16::from_f32(core::f32::NAN);
+
+        assert_eq!(bf16::ONE, one);
+        assert_eq!(bf16::ZERO, zero);
+        assert!(zero.is_sign_positive());
+        assert_eq!(bf16::NEG_ZERO, neg_zero);
+        assert!(neg_zero.is_sign_negative());
+        assert_eq!(bf16::NEG_ONE, neg_one);
+        assert!(neg_one.is_sign_negative());
+        assert_eq!(bf16::INFINITY, inf);
+        assert_eq!(bf16::NEG_INFINITY, neg_inf);
+        assert!(nan.is_nan());
+        assert!(bf16::NAN.is_nan());
+
+        let e = bf16::from_f32(core::f32::consts::E);
+        let pi = bf16::from_f32(core::f32::consts::PI);
+        let frac_1_pi = bf16::from_f32(core::f32::consts::FRAC_1_PI);
+        let frac_1_sqrt_2 = bf16::from_f32(core::f32::consts::FRAC_1_SQRT_2);
+        let frac_2_pi = bf16::from_f32(core::f32::consts::FRAC_2_PI);
+        let frac_2_sqrt_pi = bf16::from_f32(core::f32::consts::FRAC_2_SQRT_PI);
+        let frac_pi_2 = bf16::from_f32(core::f32::consts::FRAC_PI_2);
+        let frac_pi_3 = bf16::from_f32(core::f32::consts::FRAC_PI_3);
+        let frac_pi_4 = bf16::from_f32(core::f32::consts::FRAC_PI_4);
+        let frac_pi_6 = bf16::from_f32(core::f32::consts::FRAC_PI_6);
+        let frac_pi_8 = bf16::from_f32(core::f32::consts::FRAC_PI_8);
+        let ln_10 = bf16::from_f32(core::f32::consts::LN_10);
+        let ln_2 = bf16::from_f32(core::f32::consts::LN_2);
+        let log10_e = bf16::from_f32(core::f32::consts::LOG10_E);
+        // core::f32::consts::LOG10_2 requires rustc 1.43.0
+        let log10_2 = bf16::from_f32(2f32.log10());
+        let log2_e = bf16::from_f32(core::f32::consts::LOG2_E);
+        // core::f32::consts::LOG2_10 requires rustc 1.43.0
+        let log2_10 = bf16::from_f32(10f32.log2());
+        let sqrt_2 = bf16::from_f32(core::f32::consts::SQRT_2);
+
+        assert_eq!(bf16::E, e);
+        assert_eq!(bf16::PI, pi);
+        assert_eq!(bf16::FRAC_1_PI, frac_1_pi);
+        assert_eq!(bf16::FRAC_1_SQRT_2, frac_1_sqrt_2);
+        assert_eq!(bf16::FRAC_2_PI, frac_2_pi);
+        assert_eq!(bf16::FRAC_2_SQRT_PI, frac_2_sqrt_pi);
+        assert_eq!(bf16::FRAC_PI_2, frac_pi_2);
+        assert_eq!(bf16::FRAC_PI_3, frac_pi_3);
+        assert_eq!(bf16::FRAC_PI_4, frac_pi_4);
+        assert_eq!(bf16::FRAC_PI_6, frac_pi_6);
+        assert_eq!(bf16::FRAC_PI_8, frac_pi_8);
+        assert_eq!(bf16::LN_10, ln_10);
+        assert_eq!(bf16::LN_2, ln_2);
+        assert_eq!(bf16::LOG10_E, log10_e);
+        assert_eq!(bf16::LOG10_2, log10_2);
+        assert_eq!(bf16::LOG2_E, log2_e);
+        assert_eq!(bf16::LOG2_10, log2_10);
+        assert_eq!(bf16::SQRT_2, sqrt_2);
+    }
+
+    #[test]
+    fn test_bf16_consts_from_f64() {
+        let one = bf16::from_f64(1.0);
+        let zero = bf16::from_f64(0.0);
+        let neg_zero = bf16::from_f64(-0.0);
+        let inf = bf16::from_f64(core::f64::INFINITY);
+        let neg_inf = bf16::from_f64(core::f64::NEG_INFINITY);
+        let nan = bf16::from_f64(core::f64::NAN);
+
+        assert_eq!(bf16::ONE, one);
+        assert_eq!(bf16::ZERO, zero);
+        assert_eq!(bf16::NEG_ZERO, neg_zero);
+        assert_eq!(bf16::INFINITY, inf);
+        assert_eq!(bf16::NEG_INFINITY, neg_inf);
+        assert!(nan.is_nan());
+        assert!(bf16::NAN.is_nan());
+
+        let e = bf16::from_f64(core::f64::consts::E);
+        let pi = bf16::from_f64(core::f64::consts::PI);
+        let frac_1_pi = bf16::from_f64(core::f64::consts::FRAC_1_PI);
+        let frac_1_sqrt_2 = bf16::from_f64(core::f64::consts::FRAC_1_SQRT_2);
+        let frac_2_pi = bf16::from_f64(core::f64::consts::FRAC_2_PI);
+        let frac_2_sqrt_pi = bf16::from_f64(core::f64::consts::FRAC_2_SQRT_PI);
+        let frac_pi_2 = bf16::from_f64(core::f64::consts::FRAC_PI_2);
+        let frac_pi_3 = bf16::from_f64(core::f64::consts::FRAC_PI_3);
+        let frac_pi_4 = bf16::from_f64(core::f64::consts::FRAC_PI_4);
+        let frac_pi_6 = bf16::from_f64(core::f64::consts::FRAC_PI_6);
+        let frac_pi_8 = bf16::from_f64(core::f64::consts::FRAC_PI_8);
+        let ln_10 = bf16::from_f64(core::f64::consts::LN_10);
+        let ln_2 = bf16::from_f64(core::f64::consts::LN_2);
+        let log10_e = bf16::from_f64(core::f64::consts::LOG10_E);
+        // core::f64::consts::LOG10_2 requires rustc 1.43.0
+        let log10_2 = bf16::from_f64(2f64.log10());
+        let log2_e = bf16::from_f64(core::f64::consts::LOG2_E);
+        // core::f64::consts::LOG2_10 requires rustc 1.43.0
+        let log2_10 = bf16::from_f64(10f64.log2());
+        let sqrt_2 = bf16::from_f64(core::f64::consts::SQRT_2);
+
+        assert_eq!(bf16::E, e);
+        assert_eq!(bf16::PI, pi);
+        assert_eq!(bf16::FRAC_1_PI, frac_1_pi);
+        assert_eq!(bf16::FRAC_1_SQRT_2, frac_1_sqrt_2);
+        assert_eq!(bf16::FRAC_2_PI, frac_2_pi);
+        assert_eq!(bf16::FRAC_2_SQRT_PI, frac_2_sqrt_pi);
+        assert_eq!(bf16::FRAC_PI_2, frac_pi_2);
+        assert_eq!(bf16::FRAC_PI_3, frac_pi_3);
+        assert_eq!(bf16::FRAC_PI_4, frac_pi_4);
+        assert_eq!(bf16::FRAC_PI_6, frac_pi_6);
+        assert_eq!(bf16::FRAC_PI_8, frac_pi_8);
+        assert_eq!(bf16::LN_10, ln_10);
+        assert_eq!(bf16::LN_2, ln_2);
+        assert_eq!(bf16::LOG10_E, log10_e);
+        assert_eq!(bf16::LOG10_2, log10_2);
+        assert_eq!(bf16::LOG2_E, log2_e);
+        assert_eq!(bf16::LOG2_10, log2_10);
+        assert_eq!(bf16::SQRT_2, sqrt_2);
+    }
+
+    #[test]
+    fn test_nan_conversion_to_smaller() {
+        let nan64 = f64::from_bits(0x7FF0_0000_0000_0001u64);
+        let neg_nan64 = f64::from_bits(0xFFF0_0000_0000_0001u64);
+        let nan32 = f32::from_bits(0x7F80_0001u32);
+        let neg_nan32 = f32::from_bits(0xFF80_0001u32);
+        let nan32_from_64 = nan64 as f32;
+        let neg_nan32_from_64 = neg_nan64 as f32;
+        let nan16_from_64 = bf16::from_f64(nan64);
+        let neg_nan16_from_64 = bf16::from_f64(neg_nan64);
+        let nan16_from_32 = bf16::from_f32(nan32);
+        let neg_nan16_from_32 = bf16::from_f32(neg_nan32);
+
+        assert!(nan64.is_nan() && nan64.is_sign_positive());
+        assert!(neg_nan64.is_nan() && neg_nan64.is_sign_negative());
+        assert!(nan32.is_nan() && nan32.is_sign_positive());
+        assert!(neg_nan32.is_nan() && neg_nan32.is_sign_negative());
+
+        // f32/f64 NaN conversion sign is non-deterministic: https://github.com/VoidStarKat/half-rs/issues/103
+        assert!(neg_nan32_from_64.is_nan());
+        assert!(nan32_from_64.is_nan());
+        assert!(nan16_from_64.is_nan());
+        assert!(neg_nan16_from_64.is_nan());
+        assert!(nan16_from_32.is_nan());
+        assert!(neg_nan16_from_32.is_nan());
+    }
+
+    #[test]
+    fn test_nan_conversion_to_larger() {
+        let nan16 = bf16::from_bits(0x7F81u16);
+        let neg_nan16 = bf16::from_bits(0xFF81u16);
+        let nan32 = f32::from_bits(0x7F80_0001u32);
+        let neg_nan32 = f32::from_bits(0xFF80_0001u32);
+        let nan32_from_16 = f32::from(nan16);
+        let neg_nan32_from_16 = f32::from(neg_nan16);
+        let nan64_from_16 = f64::from(nan16);
+        let neg_nan64_from_16 = f64::from(neg_nan16);
+        let nan64_from_32 = f64::from(nan32);
+        let neg_nan64_from_32 = f64::from(neg_nan32);
 
-    #[inline]
-    fn add(self, rhs: Self) -> Self::Output {
-        Self::from_f32(Self::to_f32(self) + Self::to_f32(rhs))
+        assert!(nan16.is_nan() && nan16.is_sign_positive());
+        assert!(neg_nan16.is_nan() && neg_nan16.is_sign_negative());
+        assert!(nan32.is_nan() && nan32.is_sign_positive());
+        assert!(neg_nan32.is_nan() && neg_nan32.is_sign_negative());
+
+        // // f32/f64 NaN conversion sign is non-deterministic: https://github.com/VoidStarKat/half-rs/issues/103
+        assert!(nan32_from_16.is_nan());
+        assert!(neg_nan32_from_16.is_nan());
+        assert!(nan64_from_16.is_nan());
+        assert!(neg_nan64_from_16.is_nan());
+        assert!(nan64_from_32.is_nan());
+        assert!(neg_nan64_from_32.is_nan());
     }
-}
 
-impl Add<&bf16> for bf16 {
-    type Output = <bf16 as Add<bf16>>::Output;
+    #[test]
+    fn test_bf16_to_f32() {
+        let f = bf16::from_f32(7.0);
+        assert_eq!(f.to_f32(), 7.0f32);
 
-    #[inline]
-    fn add(self, rhs: &bf16) -> Self::Output {
-        self.add(*rhs)
-    }
-}
+        // 7.1 is NOT exactly representable in 16-bit, it's rounded
+        let f = bf16::from_f32(7.1);
+        let diff = (f.to_f32() - 7.1f32).abs();
+        // diff must be <= 4 * EPSILON, as 7 has two more significant bits than 1
+        assert!(diff <= 4.0 * bf16::EPSILON.to_f32());
 
-impl Add<&bf16> for &bf16 {
-    type Output = <bf16 as Add<bf16>>::Output;
+        let tiny32 = f32::from_bits(0x0001_0000u32);
+        assert_eq!(bf16::from_bits(0x0001).to_f32(), tiny32);
+        assert_eq!(bf16::from_bits(0x0005).to_f32(), 5.0 * tiny32);
 
-    #[inline]
-    fn add(self, rhs: &bf16) -> Self::Output {
-        (*self).add(*rhs)
+        assert_eq!(bf16::from_bits(0x0001), bf16::from_f32(tiny32));
+        assert_eq!(bf16::from_bits(0x0005), bf16::from_f32(5.0 * tiny32));
     }
-}
 
-impl Add<bf16> for &bf16 {
-    type Output = <bf16 as Add<bf16>>::Output;
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_bf16_to_f64() {
+        let f = bf16::from_f64(7.0);
+        assert_eq!(f.to_f64(), 7.0f64);
 
-    #[inline]
-    fn add(self, rhs: bf16) -> Self::Output {
-        (*self).add(rhs)
-    }
-}
+        // 7.1 is NOT exactly representable in 16-bit, it's rounded
+        let f = bf16::from_f64(7.1);
+        let diff = (f.to_f64() - 7.1f64).abs();
+        // diff must be <= 4 * EPSILON, as 7 has two more significant bits than 1
+        assert!(diff <= 4.0 * bf16::EPSILON.to_f64());
 
-impl AddAssign for bf16 {
-    #[inline]
-    fn add_assign(&mut self, rhs: Self) {
-        *self = (*self).add(rhs);
-    }
-}
+        let tiny64 = 2.0f64.powi(-133);
+        assert_eq!(bf16::from_bits(0x0001).to_f64(), tiny64);
+        assert_eq!(bf16::from_bits(0x0005).to_f64(), 5.0 * tiny64);
 
-impl AddAssign<&bf16> for bf16 {
-    #[inline]
-    fn add_assign(&mut self, rhs: &bf16) {
-        *self = (*self).add(rhs);
+        assert_eq!(bf16::from_bits(0x0001), bf16::from_f64(tiny64));
+        assert_eq!(bf16::from_bits(0x0005), bf16::from_f64(5.0 * tiny64));
     }
-}
 
-impl Sub for bf16 {
-    type Output = Self;
+    #[test]
+    #[cfg(not(feature = "total-order-impls"))]
+    fn test_comparisons() {
+        let zero = bf16::from_f64(0.0);
+        let one = bf16::from_f64(1.0);
+        let neg_zero = bf16::from_f64(-0.0);
+        let neg_one = bf16::from_f64(-1.0);
 
-    #[inline]
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self::from_f32(Self::to_f32(self) - Self::to_f32(rhs))
-    }
-}
+        assert_eq!(zero.partial_cmp(&neg_zero), Some(Ordering::Equal));
+        assert_eq!(neg_zero.partial_cmp(&zero), Some(Ordering::Equal));
+        assert!(zero == neg_zero);
+        assert!(neg_zero == zero);
+        assert!(!(zero != neg_zero));
+        assert!(!(neg_zero != zero));
+        assert!(!(zero < neg_zero));
+        assert!(!(neg_zero < zero));
+        assert!(zero <= neg_zero);
+        assert!(neg_zero <= zero);
+        assert!(!(zero > neg_zero));
+        assert!(!(neg_zero > zero));
+        assert!(zero >= neg_zero);
+        assert!(neg_zero >= zero);
 
-impl Sub<&bf16> for bf16 {
-    type Output = <bf16 as Sub<bf16>>::Output;
+        assert_eq!(one.partial_cmp(&neg_zero), Some(Ordering::Greater));
+        assert_eq!(neg_zero.partial_cmp(&one), Some(Ordering::Less));
+        assert!(!(one == neg_zero));
+        assert!(!(neg_zero == one));
+        assert!(one != neg_zero);
+        assert!(neg_zero != one);
+        assert!(!(one < neg_zero));
+        assert!(neg_zero < one);
+        assert!(!(one <= neg_zero));
+        assert!(neg_zero <= one);
+        assert!(one > neg_zero);
+        assert!(!(neg_zero > one));
+        assert!(one >= neg_zero);
+        assert!(!(neg_zero >= one));
 
-    #[inline]
-    fn sub(self, rhs: &bf16) -> Self::Output {
-        self.sub(*rhs)
+        assert_eq!(one.partial_cmp(&neg_one), Some(Ordering::Greater));
+        assert_eq!(neg_one.partial_cmp(&one), Some(Ordering::Less));
+        assert!(!(one == neg_one));
+        assert!(!(neg_one == one));
+        assert!(one != neg_one);
+        assert!(neg_one != one);
+        assert!(!(one < neg_one));
+        assert!(neg_one < one);
+        assert!(!(one <= neg_one));
+        assert!(neg_one <= one);
+        assert!(one > neg_one);
+        assert!(!(neg_one > one));
+        assert!(one >= neg_one);
+        assert!(!(neg_one >= one));
     }
-}
 
-impl Sub<&bf16> for &bf16 {
-    type Output = <bf16 as Sub<bf16>>::Output;
+    #[test]
+    #[allow(clippy::erasing_op, clippy::identity_op)]
+    #[cfg_attr(miri, ignore)]
+    fn round_to_even_f32() {
+        // smallest positive subnormal = 0b0.0000_001 * 2^-126 = 2^-133
+        let min_sub = bf16::from_bits(1);
+        let min_sub_f = (-133f32).exp2();
+        assert_eq!(bf16::from_f32(min_sub_f).to_bits(), min_sub.to_bits());
+        assert_eq!(f32::from(min_sub).to_bits(), min_sub_f.to_bits());
 
-    #[inline]
-    fn sub(self, rhs: &bf16) -> Self::Output {
-        (*self).sub(*rhs)
-    }
-}
+        // 0.0000000_011111 rounded to 0.0000000 (< tie, no rounding)
+        // 0.0000000_100000 rounded to 0.0000000 (tie and even, remains at even)
+        // 0.0000000_100001 rounded to 0.0000001 (> tie, rounds up)
+        assert_eq!(bf16::from_f32(min_sub_f * 0.49).to_bits(), min_sub.to_bits() * 0);
+        assert_eq!(bf16::from_f32(min_sub_f * 0.50).to_bits(), min_sub.to_bits() * 0);
+        assert_eq!(bf16::from_f32(min_sub_f * 0.51).to_bits(), min_sub.to_bits() * 1);
 
-impl Sub<bf16> for &bf16 {
-    type Output = <bf16 as Sub<bf16>>::Output;
+        // 0.0000001_011111 rounded to 0.0000001 (< tie, no rounding)
+        // 0.0000001_100000 rounded to 0.0000010 (tie and odd, rounds up to even)
+        // 0.0000001_100001 rounded to 0.0000010 (> tie, rounds up)
+        assert_eq!(bf16::from_f32(min_sub_f * 1.49).to_bits(), min_sub.to_bits() * 1);
+        assert_eq!(bf16::from_f32(min_sub_f * 1.50).to_bits(), min_sub.to_bits() * 2);
+        assert_eq!(bf16::from_f32(min_sub_f * 1.51).to_bits(), min_sub.to_bits() * 2);
 
-    #[inline]
-    fn sub(self, rhs: bf16) -> Self::Output {
-        (*self).sub(rhs)
-    }
-}
+        // 0.0000010_011111 rounded to 0.0000010 (< tie, no rounding)
+        // 0.0000010_100000 rounded to 0.0000010 (tie and even, remains at even)
+        // 0.0000010_100001 rounded to 0.0000011 (> tie, rounds up)
+        assert_eq!(bf16::from_f32(min_sub_f * 2.49).to_bits(), min_sub.to_bits() * 2);
+        assert_eq!(bf16::from_f32(min_sub_f * 2.50).to_bits(), min_sub.to_bits() * 2);
+        assert_eq!(bf16::from_f32(min_sub_f * 2.51).to_bits(), min_sub.to_bits() * 3);
 
-impl SubAssign for bf16 {
-    #[inline]
-    fn sub_assign(&mut self, rhs: Self) {
-        *self = (*self).sub(rhs);
+        assert_eq!(bf16::from_f32(250.49f32).to_bits(), bf16::from_f32(250.0).to_bits());
+        assert_eq!(bf16::from_f32(250.50f32).to_bits(), bf16::from_f32(250.0).to_bits());
+        assert_eq!(bf16::from_f32(250.51f32).to_bits(), bf16::from_f32(251.0).to_bits());
+        assert_eq!(bf16::from_f32(251.49f32).to_bits(), bf16::from_f32(251.0).to_bits());
+        assert_eq!(bf16::from_f32(251.50f32).to_bits(), bf16::from_f32(252.0).to_bits());
+        assert_eq!(bf16::from_f32(251.51f32).to_bits(), bf16::from_f32(252.0).to_bits());
+        assert_eq!(bf16::from_f32(252.49f32).to_bits(), bf16::from_f32(252.0).to_bits());
+        assert_eq!(bf16::from_f32(252.50f32).to_bits(), bf16::from_f32(252.0).to_bits());
+        assert_eq!(bf16::from_f32(252.51f32).to_bits(), bf16::from_f32(253.0).to_bits());
     }
-}
 
-impl SubAssign<&bf16> for bf16 {
-    #[inline]
-    fn sub_assign(&mut self, rhs: &bf16) {
-        *self = (*self).sub(rhs);
-    }
-}
+    #[test]
+    #[allow(clippy::erasing_op, clippy::identity_op)]
+    #[cfg_attr(miri, ignore)]
+    fn round_to_even_f64() {
+        // smallest positive subnormal = 0b0.0000_001 * 2^-126 = 2^-133
+        let min_sub = bf16::from_bits(1);
+        let min_sub_f = (-133f64).exp2();
+        assert_eq!(bf16::from_f64(min_sub_f).to_bits(), min_sub.to_bits());
+        assert_eq!(f64::from(min_sub).to_bits(), min_sub_f.to_bits());
 
-impl Mul for bf16 {
-    type Output = Self;
+        // 0.0000000_011111 rounded to 0.0000000 (< tie, no rounding)
+        // 0.0000000_100000 rounded to 0.0000000 (tie and even, remains at even)
+        // 0.0000000_100001 rounded to 0.0000001 (> tie, rounds up)
+        assert_eq!(bf16::from_f64(min_sub_f * 0.49).to_bits(), min_sub.to_bits() * 0);
+        assert_eq!(bf16::from_f64(min_sub_f * 0.50).to_bits(), min_sub.to_bits() * 0);
+        assert_eq!(bf16::from_f64(min_sub_f * 0.51).to_bits(), min_sub.to_bits() * 1);
 
-    #[inline]
-    fn mul(self, rhs: Self) -> Self::Output {
-        Self::from_f32(Self::to_f32(self) * Self::to_f32(rhs))
-    }
-}
+        // 0.0000001_011111 rounded to 0.0000001 (< tie, no rounding)
+        // 0.0000001_100000 rounded to 0.0000010 (tie and odd, rounds up to even)
+        // 0.0000001_100001 rounded to 0.0000010 (> tie, rounds up)
+        assert_eq!(bf16::from_f64(min_sub_f * 1.49).to_bits(), min_sub.to_bits() * 1);
+        assert_eq!(bf16::from_f64(min_sub_f * 1.50).to_bits(), min_sub.to_bits() * 2);
+        assert_eq!(bf16::from_f64(min_sub_f * 1.51).to_bits(), min_sub.to_bits() * 2);
 
-impl Mul<&bf16> for bf16 {
-    type Output = <bf16 as Mul<bf16>>::Output;
+        // 0.0000010_011111 rounded to 0.0000010 (< tie, no rounding)
+        // 0.0000010_100000 rounded to 0.0000010 (tie and even, remains at even)
+        // 0.0000010_100001 rounded to 0.0000011 (> tie, rounds up)
+        assert_eq!(bf16::from_f64(min_sub_f * 2.49).to_bits(), min_sub.to_bits() * 2);
+        assert_eq!(bf16::from_f64(min_sub_f * 2.50).to_bits(), min_sub.to_bits() * 2);
+        assert_eq!(bf16::from_f64(min_sub_f * 2.51).to_bits(), min_sub.to_bits() * 3);
 
-    #[inline]
-    fn mul(self, rhs: &bf16) -> Self::Output {
-        self.mul(*rhs)
+        assert_eq!(bf16::from_f64(250.49f64).to_bits(), bf16::from_f64(250.0).to_bits());
+        assert_eq!(bf16::from_f64(250.50f64).to_bits(), bf16::from_f64(250.0).to_bits());
+        assert_eq!(bf16::from_f64(250.51f64).to_bits(), bf16::from_f64(251.0).to_bits());
+        assert_eq!(bf16::from_f64(251.49f64).to_bits(), bf16::from_f64(251.0).to_bits());
+        assert_eq!(bf16::from_f64(251.50f64).to_bits(), bf16::from_f64(252.0).to_bits());
+        assert_eq!(bf16::from_f64(251.51f64).to_bits(), bf16::from_f64(252.0).to_bits());
+        assert_eq!(bf16::from_f64(252.49f64).to_bits(), bf16::from_f64(252.0).to_bits());
+        assert_eq!(bf16::from_f64(252.50f64).to_bits(), bf16::from_f64(252.0).to_bits());
+        assert_eq!(bf16::from_f64(252.51f64).to_bits(), bf16::from_f64(253.0).to_bits());
     }
-}
 
-impl Mul<&bf16> for &bf16 {
-    type Output = <bf16 as Mul<bf16>>::Output;
+    #[test]
+    fn from_f32_lossless() {
+        let from_f32 = |v: f32| bf16::from_f32_lossless(v);
+        let roundtrip = |v: f32, expected: Option<bf16>| {
+            let half = from_f32(v);
+            assert_eq!(half, expected);
+            if !expected.is_none() {
+                let as_f32 = expected.unwrap().to_f32_const();
+                assert_eq!(v, as_f32);
+            }
+        };
 
-    #[inline]
-    fn mul(self, rhs: &bf16) -> Self::Output {
-        (*self).mul(*rhs)
+        assert_eq!(from_f32(f32::NAN).map(bf16::is_nan), Some(true));
+        roundtrip(f32::INFINITY, Some(bf16::INFINITY));
+        roundtrip(f32::NEG_INFINITY, Some(bf16::NEG_INFINITY));
+        roundtrip(f32::from_bits(0b0_00000000_00000000000000000000000), Some(bf16(0)));
+        roundtrip(
+            f32::from_bits(0b1_00000000_00000000000000000000000),
+            Some(bf16(bf16::SIGN_MASK)),
+        );
+        roundtrip(f32::from_bits(1), None);
+        roundtrip(f32::from_bits(0b0_00001010_10101001010110100101110), None);
+        roundtrip(f32::from_bits(0b0_00001010_10101001010110100101110), None);
+        roundtrip(f32::from_bits(0b0_00001010_10101011000000000000000), None);
+        roundtrip(
+            f32::from_bits(0b0_00001010_10101010000000000000000),
+            Some(bf16(0b0_00001010_1010101)),
+        );
+        roundtrip(f32::from_bits(0b0_00000000_10000000000000000000000), Some(bf16(0x40)));
+        // special truncation with denormals, etc.
+        roundtrip(f32::from_bits(0b0_00000000_00000001000000000000000), None);
+        roundtrip(f32::from_bits(0b0_00000000_00000010000000000000000), Some(bf16(1)));
+        roundtrip(f32::from_bits(0b0_00000000_00000100000000000000000), Some(bf16(2)));
+        roundtrip(f32::from_bits(0b0_00000000_00000110000000000000000), Some(bf16(3)));
+        roundtrip(f32::from_bits(0b0_00000000_00000111000000000000000), None);
+        roundtrip(f32::from_bits(0b0_00001011_10100111101101101001001), None);
+        // 1.99170198e-35 and has bits until 16 to the end, so truncated 2
+        roundtrip(f32::from_bits(0b0_00001011_10100111100000000000000), None);
+        // 1.99170198e-35 and has bits until 15 to the end, so truncated 1
+        roundtrip(f32::from_bits(0b0_00001011_10100111000000000000000), None);
+        // 1.99170198e-35 and has bits until 15 to the end, so truncated 1
+        roundtrip(f32::from_bits(0b0_00001011_10100110000000000000000), Some(bf16(0x05d3)));
     }
-}
 
-impl Mul<bf16> for &bf16 {
-    type Output = <bf16 as Mul<bf16>>::Output;
+    #[test]
+    fn from_f64_lossless() {
+        let from_f64 = |v: f64| bf16::from_f64_lossless(v);
+        let roundtrip = |v: f64, expected: Option<bf16>| {
+            let half = from_f64(v);
+            assert_eq!(half, expected);
+            if !expected.is_none() {
+                let as_f64 = expected.unwrap().to_f64_const();
+                assert_eq!(v, as_f64);
+            }
+        };
 
-    #[inline]
-    fn mul(self, rhs: bf16) -> Self::Output {
-        (*self).mul(rhs)
+        assert_eq!(from_f64(f64::NAN).map(bf16::is_nan), Some(true));
+        roundtrip(f64::INFINITY, Some(bf16::INFINITY));
+        roundtrip(f64::NEG_INFINITY, Some(bf16::NEG_INFINITY));
+        roundtrip(
+            f64::from_bits(0b0_00000000000_0000000000000000000000000000000000000000000000000000),
+            Some(bf16(0)),
+        );
+        roundtrip(
+            f64::from_bits(0b1_00000000000_0000000000000000000000000000000000000000000000000000),
+            Some(bf16(bf16::SIGN_MASK)),
+        );
+        roundtrip(
+            f64::from_bits(0b0_01110001010_1010100101011010010110110111111110000111101000001111),
+            None,
+        );
+        // 1.99170198e-35 and has bits until 44 to the end, so truncated 1
+        roundtrip(
+            f64::from_bits(0b0_01110001010_1010100100000000000000000000000000000000000000000000),
+            None,
+        );
+        roundtrip(
+            f64::from_bits(0b0_01110001010_1010100000000000000000000000000000000000000000000000),
+            Some(bf16(0x0554)),
+        );
+        roundtrip(
+            f64::from_bits(0b0_01110001010_1010101000000000000000000000000000000000000000000000),
+            Some(bf16(0x0555)),
+        );
+        roundtrip(
+            f64::from_bits(0b0_01110001010_1010110000000000000000000000000000000000000000000000),
+            Some(bf16(0x0556)),
+        );
+        roundtrip(
+            f64::from_bits(0b0_01110001010_1010111000000000000000000000000000000000000000000000),
+            Some(bf16(0x0557)),
+        );
+        roundtrip(
+            f64::from_bits(0b0_01110001010_1010101100000000000000000000000000000000000000000000),
+            None,
+        );
+        roundtrip(
+            f64::from_bits(0b0_01110001010_1010100110000000000000000000000000000000000000000000),
+            None,
+        );
+        roundtrip(
+            f64::from_bits(0b1_01110001010_1010100000000000000000000000000000000000000000000000),
+            Some(bf16(0x8554)),
+        );
+        roundtrip(
+            f64::from_bits(0b1_01110001010_1010101000000000000000000000000000000000000000000000),
+            Some(bf16(0x8555)),
+        );
+        // exp out of range but finite
+        roundtrip(
+            f64::from_bits(0b1_11110001010_1010101000000000000000000000000000000000000000000000),
+            None,
+        );
+        // explicitly check denormals
+        roundtrip(
+            f64::from_bits(0b0_01101111010_0000000000000000000000000000000000000000000000000000),
+            Some(bf16(1)),
+        );
+        roundtrip(
+            f64::from_bits(0b0_01101111011_1000000000000000000000000000000000000000000000000000),
+            Some(bf16(3)),
+        );
+        roundtrip(
+            f64::from_bits(0b0_01101111011_1100000000000000000000000000000000000000000000000000),
+            None,
+        );
+        // Due to being denormal, this is truncated out
+        roundtrip(
+            f64::from_bits(0b0_01101111010_0001000000000000000000000000000000000000000000000000),
+            None,
+        );
+        roundtrip(
+            f64::from_bits(0b0_01101111010_1000000000000000000000000000000000000000000000000000),
+            None,
+        );
     }
-}
 
-impl MulAssign for bf16 {
-    #[inline]
-    fn mul_assign(&mut self, rhs: Self) {
-        *self = (*self).mul(rhs);
-    }
-}
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_parts_roundtrip() {
+        for bits in 0..=u16::MAX {
+            let value = bf16::from_bits(bits);
+            let (sign, exp, man) = value.to_parts();
+            assert_eq!(bf16::from_parts(sign, exp, man).to_bits(), bits);
+            assert_eq!(bf16::from_parts_checked(sign, exp, man).map(bf16::to_bits), Some(bits));
+        }
 
-impl MulAssign<&bf16> for bf16 {
-    #[inline]
-    fn mul_assign(&mut self, rhs: &bf16) {
-        *self = (*self).mul(rhs);
+        assert_eq!(bf16::from_parts_checked(false, 0, 0x80), None);
+        assert_eq!(bf16::from_parts_checked(true, 255, u8::MAX), None);
     }
-}
 
-impl Div for bf16 {
-    type Output = Self;
-
-    #[inline]
-    fn div(self, rhs: Self) -> Self::Output {
-        Self::from_f32(Self::to_f32(self) / Self::to_f32(rhs))
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn test_from_parts_invalid_mantissa() {
+        let _ = bf16::from_parts(false, 127, 0x80);
     }
-}
 
-impl Div<&bf16> for bf16 {
-    type Output = <bf16 as Div<bf16>>::Output;
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_from_fixed_point() {
+        for &fractional_bits in &[0u32, 4, 8, 16, 32, 60] {
+            let scale = (1u64 << fractional_bits) as f64;
+            for value in -(1i64 << 17)..(1i64 << 17) {
+                let expected = bf16::from_f64(value as f64 / scale);
+                let actual = bf16::from_fixed_point(value, fractional_bits);
+                assert_eq!(
+                    actual.to_bits(),
+                    expected.to_bits(),
+                    "{} >> {}",
+                    value,
+                    fractional_bits
+                );
+            }
+        }
 
-    #[inline]
-    fn div(self, rhs: &bf16) -> Self::Output {
-        self.div(*rhs)
+        assert_eq!(bf16::from_fixed_point(i64::MIN, 0), bf16::from_f64(-9223372036854775808.));
+        assert_eq!(bf16::from_fixed_point(i64::MAX, 0), bf16::from_f64(9223372036854775808.));
+        assert_eq!(bf16::from_fixed_point(1, 133), bf16::MIN_POSITIVE_SUBNORMAL);
+        assert_eq!(bf16::from_fixed_point(1, 134).to_bits(), 0);
+        assert_eq!(bf16::from_fixed_point(3, 134), bf16::from_bits(2));
+        assert_eq!(bf16::from_fixed_point(-1, u32::MAX).to_bits(), 0x8000);
     }
-}
 
-impl Div<&bf16> for &bf16 {
-    type Output = <bf16 as Div<bf16>>::Output;
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_to_fixed_point() {
+        for &fractional_bits in &[0u32, 8, 16, 32, 60] {
+            let scale = (1u64 << fractional_bits) as f64;
+            for bits in 0..=u16::MAX {
+                let value = bf16::from_bits(bits);
+                let fixed = value.to_fixed_point(fractional_bits);
+                let exact = value.to_f64() * scale;
+                if !value.is_finite() || exact.abs() >= 9223372036854775808. {
+                    // `-2^63` is the only value at the boundary that fits.
+                    if exact != -9223372036854775808. {
+                        assert_eq!(fixed, None);
+                    }
+                } else if exact.fract() == 0. {
+                    assert_eq!(fixed, Some(exact as i64));
+                    assert_eq!(
+                        bf16::from_fixed_point(exact as i64, fractional_bits).to_f64(),
+                        value.to_f64()
+                    );
+                } else {
+                    let diff = (fixed.unwrap() as f64 - exact).abs();
+                    assert!(diff <= 0.5, "{} << {}", value, fractional_bits);
+                    if diff == 0.5 {
+                        assert_eq!(fixed.unwrap() % 2, 0);
+                    }
+                }
+            }
+        }
 
-    #[inline]
-    fn div(self, rhs: &bf16) -> Self::Output {
-        (*self).div(*rhs)
+        assert_eq!(bf16::from_f64(-9223372036854775808.).to_fixed_point(0), Some(i64::MIN));
+        assert_eq!(bf16::from_f64(9223372036854775808.).to_fixed_point(0), None);
+        assert_eq!(bf16::from_f32(2.5).to_fixed_point(0), Some(2));
+        assert_eq!(bf16::MAX.to_fixed_point(0), None);
+        assert_eq!(bf16::NAN.to_fixed_point(0), None);
     }
-}
-
-impl Div<bf16> for &bf16 {
-    type Output = <bf16 as Div<bf16>>::Output;
 
-    #[inline]
-    fn div(self, rhs: bf16) -> Self::Output {
-        (*self).div(rhs)
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_exp_log() {
+        let two = bf16::from_f32(2.0);
+        assert_eq!(two.exp(), bf16::from_f32(2.0f32.exp()));
+        assert_eq!(two.exp2(), bf16::from_f32(4.0));
+        assert_eq!(two.ln(), bf16::LN_2);
+        assert_eq!(two.log2(), bf16::ONE);
+        assert_eq!(bf16::from_f32(1000.0).log10(), bf16::from_f32(3.0));
+
+        // Results overflow or underflow in the narrower type.
+        assert_eq!(bf16::from_f32(89.0).exp(), bf16::INFINITY);
+        assert_eq!(bf16::from_f32(-100.0).exp(), bf16::ZERO);
+
+        assert_eq!(bf16::NEG_INFINITY.exp(), bf16::ZERO);
+        assert_eq!(bf16::INFINITY.exp(), bf16::INFINITY);
+        assert_eq!(bf16::ZERO.ln(), bf16::NEG_INFINITY);
+        assert_eq!(bf16::NEG_ONE.ln_1p(), bf16::NEG_INFINITY);
+        assert_eq!(bf16::NEG_INFINITY.exp_m1(), bf16::NEG_ONE);
+        assert_eq!(bf16::NEG_ZERO.exp_m1().to_bits(), bf16::NEG_ZERO.to_bits());
+        assert_eq!(bf16::NEG_ZERO.ln_1p().to_bits(), bf16::NEG_ZERO.to_bits());
+        assert!(bf16::NEG_ONE.log2().is_nan());
+        assert!(bf16::NAN.exp().is_nan());
+        assert!(bf16::NAN.ln().is_nan());
     }
-}
 
-impl DivAssign for bf16 {
-    #[inline]
-    fn div_assign(&mut self, rhs: Self) {
-        *self = (*self).div(rhs);
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_from_f32_unchecked_normal() {
+        let infinity = f32::INFINITY.to_bits();
+        for bits in (0..=infinity).step_by(97).chain(infinity - 0x1_0000..=infinity) {
+            for &value in &[f32::from_bits(bits), -f32::from_bits(bits)] {
+                let expected = bf16::from_f32(value);
+                let actual = unsafe { bf16::from_f32_unchecked_normal(value) };
+                assert_eq!(actual.to_bits(), expected.to_bits(), "{}", value);
+            }
+        }
     }
-}
 
-impl DivAssign<&bf16> for bf16 {
-    #[inline]
-    fn div_assign(&mut self, rhs: &bf16) {
-        *self = (*self).div(rhs);
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn test_from_f32_unchecked_normal_nan() {
+        let _ = unsafe { bf16::from_f32_unchecked_normal(f32::NAN) };
     }
-}
-
-impl Rem for bf16 {
-    type Output = Self;
 
-    fn rem(self, rhs: Self) -> Self::Output {
-        Self::from_f32(Self::to_f32(self) % Self::to_f32(rhs))
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_trigonometry() {
+        let x = bf16::from_f32(0.5);
+        assert_eq!(x.sin(), bf16::from_f32(x.to_f32().sin()));
+        assert_eq!(x.cos(), bf16::from_f32(x.to_f32().cos()));
+        assert_eq!(x.tan(), bf16::from_f32(x.to_f32().tan()));
+        assert_eq!(x.asin(), bf16::from_f32(x.to_f32().asin()));
+        assert_eq!(x.acos(), bf16::from_f32(x.to_f32().acos()));
+        assert_eq!(x.atan(), bf16::from_f32(x.to_f32().atan()));
+        assert_eq!(x.atan2(bf16::NEG_ONE), bf16::from_f32(0.5f32.atan2(-1.0)));
+        assert_eq!(x.sin_cos(), (x.sin(), x.cos()));
+
+        assert_eq!(bf16::NEG_ZERO.sin().to_bits(), bf16::NEG_ZERO.to_bits());
+        assert_eq!(bf16::ZERO.atan2(bf16::NEG_ONE), bf16::PI);
+        assert_eq!(bf16::NEG_ZERO.atan2(bf16::NEG_ONE), -bf16::PI);
+        assert!(bf16::INFINITY.sin().is_nan());
+        assert!(bf16::NEG_INFINITY.cos().is_nan());
+        assert!(bf16::NAN.tan().is_nan());
+        assert!(bf16::from_f32(1.5).acos().is_nan());
+        assert!(bf16::NAN.atan2(bf16::ONE).is_nan());
+        let (sin, cos) = bf16::INFINITY.sin_cos();
+        assert!(sin.is_nan() && cos.is_nan());
     }
-}
 
-impl Rem<&bf16> for bf16 {
-    type Output = <bf16 as Rem<bf16>>::Output;
+    #[test]
+    fn test_sum_product_slice() {
+        let mut values = [bf16::ZERO; 300];
+        for (i, v) in values.iter_mut().enumerate() {
+            *v = bf16::from_f32((i as f32 - 150.) * 0.37 + 0.01);
+        }
+        for len in [0, 1, 7, 64, 65, 300] {
+            let values = &values[..len];
+            let sum: bf16 = values.iter().sum();
+            let product: bf16 = values.iter().copied().product();
+            assert_eq!(bf16::sum_slice(values).to_bits(), sum.to_bits());
+            assert_eq!(bf16::product_slice(values).to_bits(), product.to_bits());
+        }
 
-    #[inline]
-    fn rem(self, rhs: &bf16) -> Self::Output {
-        self.rem(*rhs)
+        assert_eq!(bf16::sum_slice(&[]).to_f64(), 0.);
+        assert_eq!(bf16::product_slice(&[]), bf16::ONE);
+        let zeros = [bf16::NEG_ZERO; 3];
+        assert_eq!(bf16::sum_slice(&zeros).to_bits(), zeros.iter().sum::<bf16>().to_bits());
+        assert!(bf16::sum_slice(&[bf16::ONE, bf16::NAN]).is_nan());
+        assert!(bf16::product_slice(&[bf16::INFINITY, bf16::ZERO]).is_nan());
     }
-}
-
-impl Rem<&bf16> for &bf16 {
-    type Output = <bf16 as Rem<bf16>>::Output;
 
-    #[inline]
-    fn rem(self, rhs: &bf16) -> Self::Output {
-        (*self).rem(*rhs)
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_sum_product_slice_large() {
+        let values = [bf16::MAX; 100_000];
+        assert_eq!(bf16::sum_slice(&values), bf16::INFINITY);
+        assert_eq!(bf16::sum_slice(&values), values.iter().sum());
+        let values = [bf16::ONE; 100_000];
+        assert_eq!(bf16::sum_slice(&values), values.iter().sum());
+        assert_eq!(bf16::product_slice(&values), bf16::ONE);
+        let values = [-bf16::from_f32(1.001); 100_001];
+        assert_eq!(bf16::product_slice(&values), values.iter().product());
     }
-}
 
-impl Rem<bf16> for &bf16 {
-    type Output = <bf16 as Rem<bf16>>::Output;
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(miri, ignore)]
+    fn test_display_round_trip() {
+        use std::string::ToString;
+
+        assert_eq!(bf16::from_f32(1.1).to_string(), "1.1");
+        assert_eq!(bf16::PI.to_string(), "3.14");
+        assert_eq!(bf16::from_f32(-0.0).to_string(), "-0");
+        assert_eq!(bf16::from_f32(1e30).to_string(), "1000000000000000000000000000000");
+        assert_eq!(format!("{:>+8}", bf16::from_f32(1.1)), "    +1.1");
+
+        for bits in 0..=u16::MAX {
+            let value = bf16::from_bits(bits);
+            let display = value.to_string();
+            let parsed: bf16 = display.parse().unwrap();
+            if value.is_nan() {
+                assert!(parsed.is_nan());
+                continue;
+            }
+            assert_eq!(parsed.to_bits(), bits, "{}", display);
+            if value.is_infinite() {
+                continue;
+            }
 
-    #[inline]
-    fn rem(self, rhs: bf16) -> Self::Output {
-        (*self).rem(rhs)
+            // Removing the last digit must not round-trip.
+            let digits =
+                display.trim_start_matches('-').trim_start_matches(['0', '.']).replace('.', "");
+            let digits = digits.trim_end_matches('0');
+            assert!(digits.len() <= 5, "{}", display);
+            if digits.len() > 1 {
+                let shorter = format!("{:.*e}", digits.len() - 2, value.to_f32());
+                let parsed: bf16 = shorter.parse().unwrap();
+                assert_ne!(parsed.to_bits(), bits, "{} {}", display, shorter);
+            }
+        }
     }
-}
 
-impl RemAssign for bf16 {
-    #[inline]
-    fn rem_assign(&mut self, rhs: Self) {
-        *self = (*self).rem(rhs);
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(miri, ignore)]
+    fn test_exp_round_trip() {
+        assert_eq!(format!("{:e}", bf16::from_f32(1.1)), "1.1e0");
+        assert_eq!(format!("{:E}", bf16::from_f32(-1.1)), "-1.1E0");
+        assert_eq!(format!("{:e}", bf16::ZERO), "0e0");
+        assert_eq!(format!("{:e}", bf16::INFINITY), "inf");
+        assert_eq!(format!("{:e}", bf16::NAN), "NaN");
+        assert_eq!(format!("{:>10e}", bf16::from_f32(1.1)), "     1.1e0");
+        // An explicit precision formats the exact value.
+        assert_eq!(
+            format!("{:.7e}", bf16::from_f32(1.1)),
+            format!("{:.7e}", bf16::from_f32(1.1).to_f32())
+        );
+        assert_eq!(format!("{:.2E}", bf16::from_f32(1.1)), "1.10E0");
+
+        for bits in 0..=u16::MAX {
+            let value = bf16::from_bits(bits);
+            if !value.is_finite() {
+                continue;
+            }
+            let lower = format!("{:e}", value);
+            let upper = format!("{:E}", value);
+            assert_eq!(lower.to_uppercase(), upper);
+            let parsed: bf16 = lower.parse().unwrap();
+            assert_eq!(parsed.to_bits(), bits, "{}", lower);
+
+            // The significand has the same digits as the shortest display.
+            // With 8 significant bits, some values need a 4th decimal digit.
+            let digits = lower.trim_start_matches('-').split('e').next().unwrap().replace('.', "");
+            assert!(digits.len() <= 4, "{}", lower);
+            assert_eq!(lower.parse::<f64>().unwrap(), value.to_string().parse::<f64>().unwrap());
+        }
     }
-}
 
-impl RemAssign<&bf16> for bf16 {
-    #[inline]
-    fn rem_assign(&mut self, rhs: &bf16) {
-        *self = (*self).rem(rhs);
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(miri, ignore)]
+    fn test_hex_round_trip() {
+        for bits in 0..=u16::MAX {
+            let value = bf16::from_bits(bits);
+            let hex = value.to_hex_string();
+            let parsed = bf16::from_hex_str(&hex).unwrap();
+            if value.is_nan() {
+                assert_eq!(hex, "NaN");
+                assert!(parsed.is_nan());
+            } else {
+                assert_eq!(parsed.to_bits(), bits, "{}", hex);
+            }
+        }
     }
-}
 
-impl Product for bf16 {
-    #[inline]
-    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
-        bf16::from_f32(iter.map(|f| f.to_f32()).product())
+    #[test]
+    fn test_from_hex_str() {
+        assert_eq!(bf16::from_hex_str("0x1p0"), Ok(bf16::ONE));
+        assert_eq!(bf16::from_hex_str("0x1.01p0"), Ok(bf16::ONE));
+        assert_eq!(bf16::from_hex_str("0x1.03p0"), Ok(bf16::from_bits(0x3F82)));
+        assert_eq!(bf16::from_hex_str("0x1.0100000000000000000001p0"), Ok(bf16::from_bits(0x3F81)));
+        assert_eq!(bf16::from_hex_str("0x1.ffp127"), Ok(bf16::INFINITY));
+        assert_eq!(bf16::from_hex_str("0x1p-134"), Ok(bf16::ZERO));
+        assert_eq!(bf16::from_hex_str("0x3p-135"), Ok(bf16::MIN_POSITIVE_SUBNORMAL));
+        assert_eq!(bf16::from_hex_str("-0x0.0p0").map(bf16::to_bits), Ok(0x8000));
+        assert!(bf16::from_hex_str("nan").unwrap().is_nan());
+
+        for src in [
+            "",
+            "0x",
+            "0xp1",
+            "0x1",
+            "0x1p",
+            "0x1.8p+",
+            "0x1.8.8p1",
+            "0x1p1x",
+            " 0x1p1",
+            "1p1",
+            "0x1p1.5",
+            "0x1p--1",
+            "-inff",
+        ] {
+            assert_eq!(bf16::from_hex_str(src), Err(ParseHalfError(())), "{}", src);
+        }
     }
-}
 
-impl<'a> Product<&'a bf16> for bf16 {
-    #[inline]
-    fn product<I: Iterator<Item = &'a bf16>>(iter: I) -> Self {
-        bf16::from_f32(iter.map(|f| f.to_f32()).product())
+    /// `frexp` from C, returning a significand in `[0.5, 1)` and an exponent.
+    fn frexp(x: f64) -> (f64, i32) {
+        let bits = x.to_bits();
+        let biased = ((bits >> 52) & 0x7FF) as i32;
+        if biased == 0 {
+            // Only zero can be subnormal, since every bf16 is normal as an f64.
+            assert_eq!(x, 0.);
+            return (x, 0);
+        }
+        let man = f64::from_bits((bits & !(0x7FFu64 << 52)) | (1022u64 << 52));
+        (man, biased - 1022)
     }
-}
 
-impl Sum for bf16 {
-    #[inline]
-    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-        bf16::from_f32(iter.map(|f| f.to_f32()).sum())
+    #[test]
+    fn test_exponents() {
+        let range = bf16::exponent_range();
+        assert_eq!(range, -126..=127);
+        assert_eq!(*range.start(), bf16::MIN_EXP - 1);
+        assert_eq!(*range.end(), bf16::MAX_EXP - 1);
+        assert_eq!(bf16::subnormal_exponent(), -133);
+        assert_eq!(bf16::MIN_POSITIVE.decode_exponent(), Some(*range.start()));
+        assert_eq!(bf16::MAX.decode_exponent(), Some(*range.end()));
+        assert_eq!(
+            bf16::MIN_POSITIVE_SUBNORMAL.decode_exponent(),
+            Some(bf16::subnormal_exponent())
+        );
+        assert_eq!(bf16::MIN_POSITIVE.to_f64(), 2f64.powi(*range.start()));
+        assert_eq!(bf16::MIN_POSITIVE_SUBNORMAL.to_f64(), 2f64.powi(bf16::subnormal_exponent()));
+        assert_eq!(frexp(bf16::MAX.to_f64()).1, bf16::MAX_EXP);
+        assert_eq!(frexp(bf16::MIN_POSITIVE.to_f64()).1, bf16::MIN_EXP);
     }
-}
 
-impl<'a> Sum<&'a bf16> for bf16 {
-    #[inline]
-    fn sum<I: Iterator<Item = &'a bf16>>(iter: I) -> Self {
-        bf16::from_f32(iter.map(|f| f.to_f32()).sum())
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_decode_exponent() {
+        for bits in 0..=u16::MAX {
+            let value = bf16::from_bits(bits);
+            let exp = value.decode_exponent();
+            if value.is_finite() && value.to_f64() != 0. {
+                let (man, frexp_exp) = frexp(value.to_f64());
+                assert!((0.5..1.).contains(&man.abs()));
+                assert_eq!(exp, Some(frexp_exp - 1), "{:?}", value);
+                assert_eq!(value.is_normal(), bf16::exponent_range().contains(&exp.unwrap()));
+            } else {
+                assert_eq!(exp, None, "{:?}", value);
+            }
+        }
     }
-}
 
-#[inline]
-const fn eq(lhs: bf16, rhs: bf16) -> bool {
-    if lhs.is_nan() || rhs.is_nan() {
-        false
-    } else {
-        (lhs.0 == rhs.0) || ((lhs.0 | rhs.0) & bf16::NOT_SIGN == 0)
+    /// Rounds half-way cases away from zero with an `f64` reference, since
+    /// `x + 0.5` is exact for every bf16 value below `2^52`.
+    fn round_ties_away_reference(x: f64) -> f64 {
+        if x.is_sign_negative() {
+            -round_ties_away_reference(-x)
+        } else {
+            (x + 0.5) as i64 as f64
+        }
     }
-}
 
-#[inline]
-const fn lt(lhs: bf16, rhs: bf16) -> bool {
-    if lhs.is_nan() || rhs.is_nan() {
-        false
-    } else {
-        let neg = lhs.0 & bf16::SIGN_MASK != 0;
-        let rhs_neg = rhs.0 & bf16::SIGN_MASK != 0;
-        match (neg, rhs_neg) {
-            (false, false) => lhs.0 < rhs.0,
-            (false, true) => false,
-            (true, false) => (lhs.0 | rhs.0) & bf16::NOT_SIGN != 0,
-            (true, true) => lhs.0 > rhs.0,
+    #[test]
+    fn test_round_ties_away() {
+        let f = bf16::from_f32;
+        assert_eq!(f(0.5).round_ties_away(), f(1.0));
+        assert_eq!(f(1.5).round_ties_away(), f(2.0));
+        assert_eq!(f(2.5).round_ties_away(), f(3.0));
+        assert_eq!(f(-2.5).round_ties_away(), f(-3.0));
+        assert_eq!(f(3.5).round_ties_away(), f(4.0));
+        assert_eq!(f(128.0).round_ties_away(), f(128.0));
+        assert_eq!(bf16::MAX.round_ties_away(), bf16::MAX);
+        assert_eq!(bf16::MIN.round_ties_away(), bf16::MIN);
+        assert_eq!(bf16::INFINITY.round_ties_away(), bf16::INFINITY);
+        assert_eq!(bf16::NEG_INFINITY.round_ties_away(), bf16::NEG_INFINITY);
+        assert!(bf16::NAN.round_ties_away().is_nan());
+        assert_eq!(bf16::MIN_POSITIVE_SUBNORMAL.round_ties_away().to_bits(), 0);
+        assert_eq!((-bf16::MIN_POSITIVE).round_ties_away().to_bits(), bf16::NEG_ZERO.to_bits());
+        assert_eq!(bf16::NEG_ZERO.round_ties_away().to_bits(), bf16::NEG_ZERO.to_bits());
+
+        // Every value in [0, 4], and their negatives.
+        for bits in 0..=f(4.0).to_bits() {
+            for x in [bf16::from_bits(bits), -bf16::from_bits(bits)] {
+                let rounded = x.round_ties_away();
+                let expected = round_ties_away_reference(x.to_f64());
+                assert_eq!(rounded.to_f64(), expected, "{:?}", x);
+                assert_eq!(rounded.is_sign_negative(), x.is_sign_negative(), "{:?}", x);
+            }
         }
-    }
-}
 
-#[inline]
-const fn le(lhs: bf16, rhs: bf16) -> bool {
-    if lhs.is_nan() || rhs.is_nan() {
-        false
-    } else {
-        let neg = lhs.0 & bf16::SIGN_MASK != 0;
-        let rhs_neg = rhs.0 & bf16::SIGN_MASK != 0;
-        match (neg, rhs_neg) {
-            (false, false) => lhs.0 <= rhs.0,
-            (false, true) => (lhs.0 | rhs.0) & bf16::NOT_SIGN == 0,
-            (true, false) => true,
-            (true, true) => lhs.0 >= rhs.0,
+        // Every finite value.
+        for bits in 0..=u16::MAX {
+            let x = bf16::from_bits(bits);
+            if x.is_finite() && (-1e15..1e15).contains(&x.to_f64()) {
+                assert_eq!(x.round_ties_away().to_f64(), round_ties_away_reference(x.to_f64()));
+            } else if x.is_finite() {
+                assert_eq!(x.round_ties_away(), x);
+            }
         }
     }
-}
 
-#[inline]
-const fn gt(lhs: bf16, rhs: bf16) -> bool {
-    if lhs.is_nan() || rhs.is_nan() {
-        false
-    } else {
-        let neg = lhs.0 & bf16::SIGN_MASK != 0;
-        let rhs_neg = rhs.0 & bf16::SIGN_MASK != 0;
-        match (neg, rhs_neg) {
-            (false, false) => lhs.0 > rhs.0,
-            (false, true) => (lhs.0 | rhs.0) & bf16::NOT_SIGN != 0,
-            (true, false) => false,
-            (true, true) => lhs.0 < rhs.0,
+    #[test]
+    fn test_lerp() {
+        let f = bf16::from_f32;
+        let values = [
+            bf16::MIN,
+            f(-3.5),
+            bf16::NEG_ZERO,
+            bf16::MIN_POSITIVE_SUBNORMAL,
+            f(0.1),
+            f(7.0),
+            bf16::MAX,
+        ];
+        for &a in &values {
+            for &b in &values {
+                // Endpoints are exact.
+                assert_eq!(a.lerp(b, bf16::ZERO).to_f64(), a.to_f64(), "{:?} {:?}", a, b);
+                assert_eq!(a.lerp(b, bf16::ONE).to_f64(), b.to_f64(), "{:?} {:?}", a, b);
+                assert!(a.lerp(b, f(0.5)).is_finite(), "{:?} {:?}", a, b);
+
+                // Monotonic in `t`.
+                let mut previous = a.lerp(b, f(-2.0));
+                for i in -15..=24 {
+                    let value = a.lerp(b, f(i as f32 / 8.0));
+                    if a.to_f64() <= b.to_f64() {
+                        assert!(previous.to_f64() <= value.to_f64(), "{:?} {:?} {}", a, b, i);
+                    } else {
+                        assert!(previous.to_f64() >= value.to_f64(), "{:?} {:?} {}", a, b, i);
+                    }
+                    previous = value;
+                }
+            }
         }
+
+        // Opposite signs near the extremes, where `b - a` overflows `f32`.
+        assert_eq!(bf16::MIN.lerp(bf16::MAX, f(0.5)), bf16::ZERO);
+        assert_eq!(bf16::MAX.lerp(bf16::MIN, f(0.25)), f(bf16::MAX.to_f32() / 2.0));
+        assert_eq!(bf16::MIN.lerp(bf16::MAX, f(0.75)), f(bf16::MAX.to_f32() / 2.0));
+        assert_eq!(bf16::MIN.lerp(bf16::MAX, f(2.0)), bf16::INFINITY);
+
+        // Extrapolation.
+        let (a, b) = (f(2.0), f(4.0));
+        assert_eq!(a.lerp(b, f(0.5)), f(3.0));
+        assert_eq!(a.lerp(b, f(-1.0)), bf16::ZERO);
+        assert_eq!(a.lerp(b, f(3.0)), f(8.0));
+
+        // NaN propagation.
+        assert!(bf16::NAN.lerp(b, bf16::ZERO).is_nan());
+        assert!(a.lerp(bf16::NAN, bf16::ONE).is_nan());
+        assert!(a.lerp(b, bf16::NAN).is_nan());
+        assert!(bf16::MIN.lerp(bf16::MAX, bf16::NAN).is_nan());
     }
-}
 
-#[inline]
-const fn ge(lhs: bf16, rhs: bf16) -> bool {
-    if lhs.is_nan() || rhs.is_nan() {
-        false
-    } else {
-        let neg = lhs.0 & bf16::SIGN_MASK != 0;
-        let rhs_neg = rhs.0 & bf16::SIGN_MASK != 0;
-        match (neg, rhs_neg) {
-            (false, false) => lhs.0 >= rhs.0,
-            (false, true) => true,
-            (true, false) => (lhs.0 | rhs.0) & bf16::NOT_SIGN == 0,
-            (true, true) => lhs.0 <= rhs.0,
+    /// Returns the value of `bf16` in units of `2^-70`.
+    fn scaled(x: bf16) -> i128 {
+        (x.to_f64() * SCALE) as i128
+    }
+
+    const SCALE: f64 = 1180591620717411303424.0;
+
+    fn from_ordered_bits(key: u16) -> bf16 {
+        if key & 0x8000 != 0 {
+            bf16::from_bits(key & 0x7FFF)
+        } else {
+            bf16::from_bits(!key)
         }
     }
-}
 
-#[allow(clippy::cognitive_complexity, clippy::float_cmp, clippy::neg_cmp_op_on_partial_ord)]
-#[cfg(test)]
-mod test {
-    use core::cmp::Ordering;
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_lerp_const() {
+        // Zero, or values between `2^-20` and `2^20`, so the exact value fits
+        // in an `i128` at this scale.
+        let sample = |state: &mut u64| loop {
+            *state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            let x = bf16::from_bits((*state >> 48) as u16);
+            let exp = (x.to_bits() >> 7) & 0xFF;
+            if x.to_bits() & 0x7FFF == 0 || (107..=147).contains(&exp) {
+                return x;
+            }
+        };
 
-    use super::*;
+        let mut state = 0x853C_49E6_748F_EA9Bu64;
+        for _ in 0..100_000 {
+            let (a, b, t) = (sample(&mut state), sample(&mut state), sample(&mut state));
+            let result = a.lerp_const(b, t);
+
+            // The exact value, in units of `2^-70`.
+            let (sa, sb, st) = (scaled(a), scaled(b), scaled(t));
+            let unit = 1i128 << (70 / 2);
+            let exact = sa + (st / unit) * ((sb - sa) / unit);
+
+            if exact == 0 {
+                assert_eq!(result.to_bits(), 0, "{:?} {:?} {:?}", a, b, t);
+                continue;
+            }
+
+            // Nearer than either neighbor, or a tie with an even mantissa.
+            let value = scaled(result);
+            let key = result.to_ordered_bits();
+            for &neighbor in &[key - 1, key + 1] {
+                let other = from_ordered_bits(neighbor);
+                if !other.is_finite() || other == result {
+                    continue;
+                }
+                let (distance, other_distance) =
+                    ((exact - value).abs(), (exact - scaled(other)).abs());
+                assert!(
+                    distance < other_distance
+                        || (distance == other_distance && result.to_bits() & 1 == 0),
+                    "{:?} {:?} {:?} {:?}",
+                    a,
+                    b,
+                    t,
+                    result
+                );
+            }
+        }
+
+        // Extremes of the range, which are outside of the sampled values.
+        let f = bf16::from_f32;
+        assert_eq!(bf16::MAX.lerp_const(bf16::MIN, f(-0.25)), bf16::INFINITY);
+        assert_eq!(bf16::MAX.lerp_const(bf16::MIN, f(0.5)), bf16::ZERO);
+        assert_eq!(bf16::MIN.lerp_const(bf16::MAX, f(0.75)), f(0.5) * bf16::MAX);
+        let tiny = bf16::MIN_POSITIVE_SUBNORMAL;
+        assert_eq!(tiny.lerp_const(-tiny, f(0.125)), tiny);
+        assert_eq!(bf16::MAX.lerp_const(tiny, bf16::MAX), bf16::NEG_INFINITY);
+        // Exact cancellation of `self + t * other`, leaving `-t * self`, which
+        // is lost when `other - self` is rounded.
+        let a = f(-3.0);
+        let b = bf16::from_bits(0x7C40); // 3 * 2^120
+        let t = bf16::from_bits(0x0380); // 2^-120
+        assert_eq!(a.lerp_const(b, t), bf16::from_bits(0x0440)); // 3 * 2^-120
+
+        // Endpoints are exact, even for non-finite values.
+        assert_eq!(bf16::INFINITY.lerp_const(bf16::ONE, bf16::ZERO), bf16::INFINITY);
+        assert_eq!(bf16::ONE.lerp_const(bf16::NEG_INFINITY, bf16::ONE), bf16::NEG_INFINITY);
+        assert_eq!(bf16::NEG_ZERO.lerp_const(bf16::ONE, bf16::NEG_ZERO).to_bits(), 0x8000);
+        assert!(bf16::INFINITY.lerp_const(bf16::ONE, bf16::from_f32(0.5)).is_nan());
+        assert!(bf16::NAN.lerp_const(bf16::ONE, bf16::ZERO).is_nan());
+        assert!(bf16::ONE.lerp_const(bf16::NAN, bf16::ONE).is_nan());
+        assert!(bf16::ONE.lerp_const(bf16::ONE, bf16::INFINITY).is_nan());
+    }
 
     #[test]
-    fn test_bf16_consts_from_f32() {
-        let one = bf16::from_f32(1.0);
-        let zero = bf16::from_f32(0.0);
-        let neg_zero = bf16::from_f32(-0.0);
-        let neg_one = bf16::from_f32(-1.0);
-        let inf = bf16::from_f32(core::f32::INFINITY);
-        let neg_inf = bf16::from_f32(core::f32::NEG_INFINITY);
-        let nan = bf16::from_f32(core::f32::NAN);
+    fn test_consecutive_integers() {
+        let f = bf16::from_f32;
+        assert_eq!(bf16::MAX_CONSECUTIVE_INTEGER, f(256.));
+        assert!(bf16::can_represent_integer(256 - 1));
+        assert!(bf16::can_represent_integer(256));
+        assert!(!bf16::can_represent_integer(256 + 1));
+        assert!(bf16::can_represent_integer(256 + 2));
+        assert!(bf16::can_represent_integer(-256));
+        assert!(!bf16::can_represent_integer(-256 - 1));
+        assert!(bf16::can_represent_integer(0));
+        assert!(!bf16::can_represent_integer(i64::MAX));
+        assert_eq!(bf16::can_represent_integer(i64::MIN), bf16::MAX_EXP > 63);
+
+        assert_eq!(f(255.).increment_integer(), Some(bf16::MAX_CONSECUTIVE_INTEGER));
+        assert_eq!(bf16::MAX_CONSECUTIVE_INTEGER.increment_integer(), None);
+        assert_eq!(f(258.).increment_integer(), None);
+        assert_eq!(f(-256.).increment_integer(), Some(f(-255.)));
+        assert_eq!(f(-1.).increment_integer(), Some(bf16::ZERO));
+        assert_eq!(bf16::NEG_ZERO.increment_integer(), Some(bf16::ONE));
+        assert_eq!(f(1.5).increment_integer(), None);
+        assert_eq!(bf16::MIN_POSITIVE_SUBNORMAL.increment_integer(), None);
+        assert_eq!(bf16::MAX.increment_integer(), None);
+        assert_eq!(bf16::MIN.increment_integer(), None);
+        assert_eq!(bf16::INFINITY.increment_integer(), None);
+        assert_eq!(bf16::NAN.increment_integer(), None);
+
+        // Counting up from the smallest consecutive integer stops exactly at
+        // the limit.
+        let mut counter = -bf16::MAX_CONSECUTIVE_INTEGER;
+        let mut count = 0;
+        while let Some(next) = counter.increment_integer() {
+            assert_eq!(next.to_f32(), counter.to_f32() + 1.);
+            counter = next;
+            count += 1;
+        }
+        assert_eq!(counter, bf16::MAX_CONSECUTIVE_INTEGER);
+        assert_eq!(count, 2 * 256);
 
-        assert_eq!(bf16::ONE, one);
-        assert_eq!(bf16::ZERO, zero);
-        assert!(zero.is_sign_positive());
-        assert_eq!(bf16::NEG_ZERO, neg_zero);
-        assert!(neg_zero.is_sign_negative());
-        assert_eq!(bf16::NEG_ONE, neg_one);
-        assert!(neg_one.is_sign_negative());
-        assert_eq!(bf16::INFINITY, inf);
-        assert_eq!(bf16::NEG_INFINITY, neg_inf);
-        assert!(nan.is_nan());
-        assert!(bf16::NAN.is_nan());
+        // Representable integers are exactly those that round-trip.
+        for n in -70_000i64..=70_000 {
+            let roundtrip = bf16::from_f64(n as f64).to_f64() == n as f64;
+            assert_eq!(bf16::can_represent_integer(n), roundtrip, "{}", n);
+        }
+        // Every integer below 2^53 is exact as an `f64`.
+        for shift in 0..52 {
+            for n in [1i64 << shift, (1i64 << shift) + 1, 0xFF << shift, -(0x1FF << shift)] {
+                let roundtrip = bf16::from_f64(n as f64).to_f64() == n as f64;
+                assert_eq!(bf16::can_represent_integer(n), roundtrip, "{}", n);
+            }
+        }
+    }
 
-        let e = bf16::from_f32(core::f32::consts::E);
-        let pi = bf16::from_f32(core::f32::consts::PI);
-        let frac_1_pi = bf16::from_f32(core::f32::consts::FRAC_1_PI);
-        let frac_1_sqrt_2 = bf16::from_f32(core::f32::consts::FRAC_1_SQRT_2);
-        let frac_2_pi = bf16::from_f32(core::f32::consts::FRAC_2_PI);
-        let frac_2_sqrt_pi = bf16::from_f32(core::f32::consts::FRAC_2_SQRT_PI);
-        let frac_pi_2 = bf16::from_f32(core::f32::consts::FRAC_PI_2);
-        let frac_pi_3 = bf16::from_f32(core::f32::consts::FRAC_PI_3);
-        let frac_pi_4 = bf16::from_f32(core::f32::consts::FRAC_PI_4);
-        let frac_pi_6 = bf16::from_f32(core::f32::consts::FRAC_PI_6);
-        let frac_pi_8 = bf16::from_f32(core::f32::consts::FRAC_PI_8);
-        let ln_10 = bf16::from_f32(core::f32::consts::LN_10);
-        let ln_2 = bf16::from_f32(core::f32::consts::LN_2);
-        let log10_e = bf16::from_f32(core::f32::consts::LOG10_E);
-        // core::f32::consts::LOG10_2 requires rustc 1.43.0
-        let log10_2 = bf16::from_f32(2f32.log10());
-        let log2_e = bf16::from_f32(core::f32::consts::LOG2_E);
-        // core::f32::consts::LOG2_10 requires rustc 1.43.0
-        let log2_10 = bf16::from_f32(10f32.log2());
-        let sqrt_2 = bf16::from_f32(core::f32::consts::SQRT_2);
+    #[test]
+    fn test_ulp_distance() {
+        let ulp = bf16::MIN_POSITIVE_SUBNORMAL;
+        assert_eq!(bf16::ONE.ulp_distance(bf16::ONE + bf16::EPSILON), Some(1));
+        assert_eq!(bf16::ZERO.ulp_distance(bf16::NEG_ZERO), Some(0));
+        assert_eq!((-ulp).ulp_distance(ulp), Some(2));
+        assert_eq!(bf16::MAX.ulp_distance(bf16::INFINITY), Some(1));
+        assert_eq!(bf16::MIN.ulp_distance(bf16::MAX), Some(2 * 0x7F7F));
+        assert_eq!(bf16::NAN.ulp_distance(bf16::ONE), None);
+        assert_eq!(bf16::NEG_ONE.to_ordered_bits() + 0x3F80, bf16::NEG_ZERO.to_ordered_bits());
+        assert_eq!(bf16::NEG_ZERO.to_ordered_bits() + 1, bf16::ZERO.to_ordered_bits());
+    }
 
-        assert_eq!(bf16::E, e);
-        assert_eq!(bf16::PI, pi);
-        assert_eq!(bf16::FRAC_1_PI, frac_1_pi);
-        assert_eq!(bf16::FRAC_1_SQRT_2, frac_1_sqrt_2);
-        assert_eq!(bf16::FRAC_2_PI, frac_2_pi);
-        assert_eq!(bf16::FRAC_2_SQRT_PI, frac_2_sqrt_pi);
-        assert_eq!(bf16::FRAC_PI_2, frac_pi_2);
-        assert_eq!(bf16::FRAC_PI_3, frac_pi_3);
-        assert_eq!(bf16::FRAC_PI_4, frac_pi_4);
-        assert_eq!(bf16::FRAC_PI_6, frac_pi_6);
-        assert_eq!(bf16::FRAC_PI_8, frac_pi_8);
-        assert_eq!(bf16::LN_10, ln_10);
-        assert_eq!(bf16::LN_2, ln_2);
-        assert_eq!(bf16::LOG10_E, log10_e);
-        assert_eq!(bf16::LOG10_2, log10_2);
-        assert_eq!(bf16::LOG2_E, log2_e);
-        assert_eq!(bf16::LOG2_10, log2_10);
-        assert_eq!(bf16::SQRT_2, sqrt_2);
+    #[test]
+    fn test_ulps_eq() {
+        // The classic case of two results one rounding step apart.
+        let a = bf16::from_f32(0.3) + bf16::from_f32(0.6);
+        let b = bf16::from_f32(0.9);
+        let distance = a.ulp_distance(b).unwrap();
+        assert!(distance > 0);
+        assert!(a.ulps_eq(b, distance));
+        assert!(!a.ulps_eq(b, distance - 1));
+        assert!(a.approx_eq(b, bf16::EPSILON, 0));
+        assert!(!a.approx_eq(b, bf16::ZERO, distance - 1));
+
+        // Across zero.
+        let tiny = bf16::MIN_POSITIVE_SUBNORMAL;
+        assert!(bf16::ZERO.ulps_eq(bf16::NEG_ZERO, 0));
+        assert!(tiny.ulps_eq(-tiny, 2));
+        assert!(!tiny.ulps_eq(-tiny, 1));
+        assert!(bf16::MIN_POSITIVE.approx_eq(
+            -bf16::MIN_POSITIVE,
+            bf16::MIN_POSITIVE + bf16::MIN_POSITIVE,
+            0
+        ));
+
+        // NaN and infinities.
+        for &x in &[bf16::NAN, -bf16::NAN, bf16::ONE, bf16::INFINITY] {
+            assert!(!bf16::NAN.ulps_eq(x, u16::MAX));
+            assert!(!x.approx_eq(bf16::NAN, bf16::INFINITY, u16::MAX));
+        }
+        assert!(bf16::INFINITY.ulps_eq(bf16::INFINITY, 0));
+        assert!(bf16::NEG_INFINITY.approx_eq(bf16::NEG_INFINITY, bf16::ZERO, 0));
+        assert!(!bf16::MAX.ulps_eq(bf16::INFINITY, u16::MAX));
+        assert!(!bf16::INFINITY.approx_eq(bf16::MAX, bf16::INFINITY, u16::MAX));
+        assert!(!bf16::INFINITY.ulps_eq(bf16::NEG_INFINITY, u16::MAX));
+        assert!(bf16::MIN.ulps_eq(bf16::MAX, u16::MAX));
     }
 
     #[test]
-    fn test_bf16_consts_from_f64() {
-        let one = bf16::from_f64(1.0);
-        let zero = bf16::from_f64(0.0);
-        let neg_zero = bf16::from_f64(-0.0);
-        let inf = bf16::from_f64(core::f64::INFINITY);
-        let neg_inf = bf16::from_f64(core::f64::NEG_INFINITY);
-        let nan = bf16::from_f64(core::f64::NAN);
+    fn test_u8_norm() {
+        for v in 0..=u8::MAX {
+            let x = bf16::from_u8_norm(v);
+            assert_eq!(x.to_u8_norm(), v);
+            assert!(x >= bf16::ZERO && x <= bf16::ONE);
+            // The nearest value to `v / 255` is within half an ULP.
+            let exact = v as f64 / 255.0;
+            let error = (x.to_f64() - exact) * 2.0;
+            let ulp = bf16::from_bits(x.to_bits() + 1).to_f64() - x.to_f64();
+            assert!(-ulp <= error && error <= ulp, "{}", v);
+        }
+    }
 
-        assert_eq!(bf16::ONE, one);
-        assert_eq!(bf16::ZERO, zero);
-        assert_eq!(bf16::NEG_ZERO, neg_zero);
-        assert_eq!(bf16::INFINITY, inf);
-        assert_eq!(bf16::NEG_INFINITY, neg_inf);
-        assert!(nan.is_nan());
-        assert!(bf16::NAN.is_nan());
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_to_u8_norm_all() {
+        for bits in 0..=u16::MAX {
+            let x = bf16::from_bits(bits);
+            let expected = if x.is_nan() || x.to_f64() <= 0.0 {
+                0
+            } else if x.to_f64() >= 1.0 {
+                255
+            } else {
+                (x.to_f64() * 255.0 + 0.5) as u8
+            };
+            assert_eq!(x.to_u8_norm(), expected, "{:?}", x);
+        }
+    }
 
-        let e = bf16::from_f64(core::f64::consts::E);
-        let pi = bf16::from_f64(core::f64::consts::PI);
-        let frac_1_pi = bf16::from_f64(core::f64::consts::FRAC_1_PI);
-        let frac_1_sqrt_2 = bf16::from_f64(core::f64::consts::FRAC_1_SQRT_2);
-        let frac_2_pi = bf16::from_f64(core::f64::consts::FRAC_2_PI);
-        let frac_2_sqrt_pi = bf16::from_f64(core::f64::consts::FRAC_2_SQRT_PI);
-        let frac_pi_2 = bf16::from_f64(core::f64::consts::FRAC_PI_2);
-        let frac_pi_3 = bf16::from_f64(core::f64::consts::FRAC_PI_3);
-        let frac_pi_4 = bf16::from_f64(core::f64::consts::FRAC_PI_4);
-        let frac_pi_6 = bf16::from_f64(core::f64::consts::FRAC_PI_6);
-        let frac_pi_8 = bf16::from_f64(core::f64::consts::FRAC_PI_8);
-        let ln_10 = bf16::from_f64(core::f64::consts::LN_10);
-        let ln_2 = bf16::from_f64(core::f64::consts::LN_2);
-        let log10_e = bf16::from_f64(core::f64::consts::LOG10_E);
-        // core::f64::consts::LOG10_2 requires rustc 1.43.0
-        let log10_2 = bf16::from_f64(2f64.log10());
-        let log2_e = bf16::from_f64(core::f64::consts::LOG2_E);
-        // core::f64::consts::LOG2_10 requires rustc 1.43.0
-        let log2_10 = bf16::from_f64(10f64.log2());
-        let sqrt_2 = bf16::from_f64(core::f64::consts::SQRT_2);
+    #[test]
+    fn test_checked_rem_neg() {
+        let f = bf16::from_f32;
+        let finite = [
+            bf16::ZERO,
+            bf16::NEG_ZERO,
+            bf16::MIN_POSITIVE_SUBNORMAL,
+            bf16::MIN_POSITIVE,
+            f(-0.75),
+            bf16::ONE,
+            f(3.5),
+            f(-100.0),
+            bf16::MAX,
+            bf16::MIN,
+        ];
+        let nans = [bf16::NAN, -bf16::NAN, bf16::from_bits(bf16::NAN.to_bits() | 1)];
+        let infinities = [bf16::INFINITY, bf16::NEG_INFINITY];
+
+        // NaN operands, infinite dividends and zero divisors are `None`.
+        for &x in finite.iter().chain(&nans).chain(&infinities) {
+            for &nan in &nans {
+                assert_eq!(x.checked_rem(nan), None);
+                assert_eq!(nan.checked_rem(x), None);
+            }
+            assert_eq!(x.checked_rem(bf16::ZERO), None);
+            assert_eq!(x.checked_rem(bf16::NEG_ZERO), None);
+            for &inf in &infinities {
+                assert_eq!(inf.checked_rem(x), None);
+            }
+        }
 
-        assert_eq!(bf16::E, e);
-        assert_eq!(bf16::PI, pi);
-        assert_eq!(bf16::FRAC_1_PI, frac_1_pi);
-        assert_eq!(bf16::FRAC_1_SQRT_2, frac_1_sqrt_2);
-        assert_eq!(bf16::FRAC_2_PI, frac_2_pi);
-        assert_eq!(bf16::FRAC_2_SQRT_PI, frac_2_sqrt_pi);
-        assert_eq!(bf16::FRAC_PI_2, frac_pi_2);
-        assert_eq!(bf16::FRAC_PI_3, frac_pi_3);
-        assert_eq!(bf16::FRAC_PI_4, frac_pi_4);
-        assert_eq!(bf16::FRAC_PI_6, frac_pi_6);
-        assert_eq!(bf16::FRAC_PI_8, frac_pi_8);
-        assert_eq!(bf16::LN_10, ln_10);
-        assert_eq!(bf16::LN_2, ln_2);
-        assert_eq!(bf16::LOG10_E, log10_e);
-        assert_eq!(bf16::LOG10_2, log10_2);
-        assert_eq!(bf16::LOG2_E, log2_e);
-        assert_eq!(bf16::LOG2_10, log2_10);
-        assert_eq!(bf16::SQRT_2, sqrt_2);
+        // Everything else matches the operator.
+        for &x in &finite {
+            for &y in finite.iter().chain(&infinities) {
+                if y.to_f64() == 0. {
+                    continue;
+                }
+                let value = x.checked_rem(y).unwrap();
+                assert_eq!(value.to_bits(), (x % y).to_bits(), "{:?} {:?}", x, y);
+            }
+            assert_eq!(x.checked_neg().map(bf16::to_bits), Some((-x).to_bits()));
+        }
+        for &x in nans.iter().chain(&infinities) {
+            assert_eq!(x.checked_neg(), None);
+        }
+    }
+
+    /// Rounds `x` with `mode` from the bracketing values around the nearest
+    /// value, as the reference for [`bf16::from_f32_round`].
+    fn round_reference(x: f32, mode: RoundingMode) -> bf16 {
+        let negative = x.is_sign_negative();
+        let abs = f32::from_bits(x.to_bits() & 0x7FFF_FFFF);
+        let nearest = bf16::from_f32(abs);
+        if nearest.to_f32() == abs || abs.is_nan() {
+            return bf16::from_f32(x);
+        }
+        let (lo, hi) = if nearest.to_f32() < abs {
+            (nearest, bf16::from_bits(nearest.to_bits() + 1))
+        } else {
+            (bf16::from_bits(nearest.to_bits() - 1), nearest)
+        };
+        let tie = abs as f64 - lo.to_f64() == hi.to_f64() - abs as f64;
+        let away = match mode {
+            RoundingMode::NearestEven => nearest == hi,
+            RoundingMode::NearestAway => tie || nearest == hi,
+            RoundingMode::TowardZero => false,
+            RoundingMode::TowardPositive => !negative,
+            RoundingMode::TowardNegative => negative,
+        };
+        let result = if away {
+            hi
+        } else {
+            lo
+        };
+        if negative {
+            -result
+        } else {
+            result
+        }
     }
 
+    const MODES: [RoundingMode; 5] = [
+        RoundingMode::NearestEven,
+        RoundingMode::NearestAway,
+        RoundingMode::TowardZero,
+        RoundingMode::TowardPositive,
+        RoundingMode::TowardNegative,
+    ];
+
     #[test]
-    fn test_nan_conversion_to_smaller() {
-        let nan64 = f64::from_bits(0x7FF0_0000_0000_0001u64);
-        let neg_nan64 = f64::from_bits(0xFFF0_0000_0000_0001u64);
-        let nan32 = f32::from_bits(0x7F80_0001u32);
-        let neg_nan32 = f32::from_bits(0xFF80_0001u32);
-        let nan32_from_64 = nan64 as f32;
-        let neg_nan32_from_64 = neg_nan64 as f32;
-        let nan16_from_64 = bf16::from_f64(nan64);
-        let neg_nan16_from_64 = bf16::from_f64(neg_nan64);
-        let nan16_from_32 = bf16::from_f32(nan32);
-        let neg_nan16_from_32 = bf16::from_f32(neg_nan32);
+    fn test_from_f32_round_ties() {
+        use RoundingMode::*;
+
+        // Even and odd values, the largest subnormal to the smallest normal,
+        // and zero to the smallest subnormal.
+        for &(bits, even) in
+            &[(0x3F80u16, 0x3F80u16), (0x3F81, 0x3F82), (0x007F, 0x0080), (0x0000, 0x0000)]
+        {
+            let x = f32::from_bits(((bits as u32) << 16) | 0x8000);
+            for &sign in &[1.0f32, -1.0] {
+                let s = if sign < 0.0 {
+                    0x8000
+                } else {
+                    0
+                };
+                let lo = s | bits;
+                let hi = s | (bits + 1);
+                let x = x * sign;
+                assert_eq!(bf16::from_f32_round(x, NearestEven).to_bits(), s | even);
+                assert_eq!(bf16::from_f32_round(x, NearestAway).to_bits(), hi);
+                assert_eq!(bf16::from_f32_round(x, TowardZero).to_bits(), lo);
+                assert_eq!(
+                    bf16::from_f32_round(x, TowardPositive).to_bits(),
+                    if sign > 0.0 {
+                        hi
+                    } else {
+                        lo
+                    }
+                );
+                assert_eq!(
+                    bf16::from_f32_round(x, TowardNegative).to_bits(),
+                    if sign > 0.0 {
+                        lo
+                    } else {
+                        hi
+                    }
+                );
+            }
+        }
+    }
 
-        assert!(nan64.is_nan() && nan64.is_sign_positive());
-        assert!(neg_nan64.is_nan() && neg_nan64.is_sign_negative());
-        assert!(nan32.is_nan() && nan32.is_sign_positive());
-        assert!(neg_nan32.is_nan() && neg_nan32.is_sign_negative());
+    #[test]
+    fn test_from_f32_round_overflow() {
+        use RoundingMode::*;
+
+        // Above MAX, below and at half-way to the next power of two.
+        let max = bf16::MAX.to_f32();
+        let above = f32::from_bits(max.to_bits() + 1);
+        let below_tie = f32::from_bits(max.to_bits() + 0x7FFF);
+        let tie = f32::from_bits(max.to_bits() + 0x8000);
+        for &x in &[above, below_tie, tie, f32::MAX] {
+            let is_tie = x >= tie;
+            assert_eq!(
+                bf16::from_f32_round(x, NearestEven),
+                if is_tie {
+                    bf16::INFINITY
+                } else {
+                    bf16::MAX
+                }
+            );
+            assert_eq!(
+                bf16::from_f32_round(x, NearestAway),
+                if is_tie {
+                    bf16::INFINITY
+                } else {
+                    bf16::MAX
+                }
+            );
+            assert_eq!(bf16::from_f32_round(x, TowardZero), bf16::MAX);
+            assert_eq!(bf16::from_f32_round(x, TowardPositive), bf16::INFINITY);
+            assert_eq!(bf16::from_f32_round(x, TowardNegative), bf16::MAX);
+            assert_eq!(bf16::from_f32_round(-x, TowardZero), bf16::MIN);
+            assert_eq!(bf16::from_f32_round(-x, TowardPositive), bf16::MIN);
+            assert_eq!(bf16::from_f32_round(-x, TowardNegative), bf16::NEG_INFINITY);
+        }
+        for &mode in &MODES {
+            assert_eq!(bf16::from_f32_round(max, mode), bf16::MAX);
+            assert_eq!(bf16::from_f32_round(f32::INFINITY, mode), bf16::INFINITY);
+            assert_eq!(bf16::from_f32_round(f32::NEG_INFINITY, mode), bf16::NEG_INFINITY);
+            assert!(bf16::from_f32_round(f32::NAN, mode).is_nan());
+            assert_eq!(bf16::from_f32_round(0.0, mode).to_bits(), 0);
+            assert_eq!(bf16::from_f32_round(-0.0, mode).to_bits(), 0x8000);
+        }
 
-        // f32/f64 NaN conversion sign is non-deterministic: https://github.com/VoidStarKat/half-rs/issues/103
-        assert!(neg_nan32_from_64.is_nan());
-        assert!(nan32_from_64.is_nan());
-        assert!(nan16_from_64.is_nan());
-        assert!(neg_nan16_from_64.is_nan());
-        assert!(nan16_from_32.is_nan());
-        assert!(neg_nan16_from_32.is_nan());
+        // The smallest f32 subnormal only rounds away from zero when directed.
+        let tiny = f32::from_bits(1);
+        assert_eq!(bf16::from_f32_round(tiny, NearestEven).to_bits(), 0);
+        assert_eq!(bf16::from_f32_round(tiny, NearestAway).to_bits(), 0);
+        assert_eq!(bf16::from_f32_round(tiny, TowardPositive).to_bits(), 1);
+        assert_eq!(bf16::from_f32_round(-tiny, TowardNegative).to_bits(), 0x8001);
+        assert_eq!(bf16::from_f32_round(-tiny, TowardPositive).to_bits(), 0x8000);
     }
 
     #[test]
-    fn test_nan_conversion_to_larger() {
-        let nan16 = bf16::from_bits(0x7F81u16);
-        let neg_nan16 = bf16::from_bits(0xFF81u16);
-        let nan32 = f32::from_bits(0x7F80_0001u32);
-        let neg_nan32 = f32::from_bits(0xFF80_0001u32);
-        let nan32_from_16 = f32::from(nan16);
-        let neg_nan32_from_16 = f32::from(neg_nan16);
-        let nan64_from_16 = f64::from(nan16);
-        let neg_nan64_from_16 = f64::from(neg_nan16);
-        let nan64_from_32 = f64::from(nan32);
-        let neg_nan64_from_32 = f64::from(neg_nan32);
+    #[cfg_attr(miri, ignore)]
+    fn test_from_f32_round_reference() {
+        for bits in (0..=u32::MAX).step_by(4099) {
+            let x = f32::from_bits(bits);
+            if x.is_nan() {
+                continue;
+            }
+            for &mode in &MODES {
+                let expected = round_reference(x, mode);
+                let actual = bf16::from_f32_round(x, mode);
+                assert_eq!(actual.to_bits(), expected.to_bits(), "{:e} {:?}", x, mode);
+            }
+        }
+    }
 
-        assert!(nan16.is_nan() && nan16.is_sign_positive());
-        assert!(neg_nan16.is_nan() && neg_nan16.is_sign_negative());
-        assert!(nan32.is_nan() && nan32.is_sign_positive());
-        assert!(neg_nan32.is_nan() && neg_nan32.is_sign_negative());
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_cow_from_bytes() {
+        let values = [bf16::ONE, bf16::from_f32(-2.5), bf16::NAN, bf16::MAX, bf16::ZERO];
+        let mut words = [0u16; 6];
+        let mut le = [0u8; 12];
+        let mut be = [0u8; 12];
+        for (i, v) in values.iter().enumerate() {
+            le[2 * i..2 * i + 2].copy_from_slice(&v.to_le_bytes());
+            be[2 * i..2 * i + 2].copy_from_slice(&v.to_be_bytes());
+        }
 
-        // // f32/f64 NaN conversion sign is non-deterministic: https://github.com/VoidStarKat/half-rs/issues/103
-        assert!(nan32_from_16.is_nan());
-        assert!(neg_nan32_from_16.is_nan());
-        assert!(nan64_from_16.is_nan());
-        assert!(neg_nan64_from_16.is_nan());
-        assert!(nan64_from_32.is_nan());
-        assert!(neg_nan64_from_32.is_nan());
+        // Store the bytes in an aligned buffer, so offset slices are misaligned.
+        let native = if cfg!(target_endian = "little") {
+            &le
+        } else {
+            &be
+        };
+        for (i, w) in words.iter_mut().enumerate() {
+            *w = u16::from_ne_bytes([native[2 * i], native[2 * i + 1]]);
+        }
+        let aligned = unsafe { core::slice::from_raw_parts(words.as_ptr().cast::<u8>(), 12) };
+        let (le_values, be_values) = if cfg!(target_endian = "little") {
+            (bf16::cow_from_le_bytes(&aligned[..10]), bf16::cow_from_be_bytes(&be[..10]))
+        } else {
+            (bf16::cow_from_le_bytes(&le[..10]), bf16::cow_from_be_bytes(&aligned[..10]))
+        };
+        for cow in [le_values.unwrap(), be_values.unwrap()] {
+            let borrowed = matches!(cow, Cow::Borrowed(_));
+            assert_eq!(borrowed, cow.as_ptr().cast::<u8>() == aligned.as_ptr());
+            let bits: Vec<u16> = cow.iter().map(|v| v.to_bits()).collect();
+            assert_eq!(bits, values.iter().map(|v| v.to_bits()).collect::<Vec<_>>());
+        }
+
+        // Offset by one byte, so the values must be copied.
+        let mut shifted = [0u8; 14];
+        let start = if shifted.as_ptr() as usize % 2 == 0 {
+            1
+        } else {
+            0
+        };
+        shifted[start..start + 12].copy_from_slice(native);
+        let unaligned = &shifted[start..start + 10];
+        assert_ne!(unaligned.as_ptr() as usize % 2, 0);
+        let cow = if cfg!(target_endian = "little") {
+            bf16::cow_from_le_bytes(unaligned)
+        } else {
+            bf16::cow_from_be_bytes(unaligned)
+        }
+        .unwrap();
+        assert!(matches!(cow, Cow::Owned(_)));
+        assert_eq!(cow[1], values[1]);
+
+        // Odd lengths are errors, even when aligned.
+        assert_eq!(bf16::cow_from_le_bytes(&aligned[..9]), Err(OddLength(())));
+        assert_eq!(bf16::cow_from_be_bytes(&be[1..4]), Err(OddLength(())));
+        assert_eq!(bf16::cow_from_le_bytes(&[]).unwrap().len(), 0);
     }
 
     #[test]
-    fn test_bf16_to_f32() {
-        let f = bf16::from_f32(7.0);
-        assert_eq!(f.to_f32(), 7.0f32);
+    fn test_hardware_bf16_available() {
+        if !cfg!(target_arch = "aarch64") {
+            assert!(!bf16::hardware_bf16_available());
+        }
+    }
 
-        // 7.1 is NOT exactly representable in 16-bit, it's rounded
-        let f = bf16::from_f32(7.1);
-        let diff = (f.to_f32() - 7.1f32).abs();
-        // diff must be <= 4 * EPSILON, as 7 has two more significant bits than 1
-        assert!(diff <= 4.0 * bf16::EPSILON.to_f32());
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_from_f32_saturating() {
+        // Every f32 between MAX and f32::MAX, where the upper half rounds to
+        // infinity with nearest-even.
+        let max = bf16::MAX.to_f32().to_bits();
+        for bits in max..=f32::MAX.to_bits() {
+            let x = f32::from_bits(bits);
+            let overflows = bits - max >= 0x8000;
+            assert_eq!(bf16::from_f32(x).is_infinite(), overflows, "{:e}", x);
+            assert_eq!(bf16::from_f32_saturating(x), bf16::MAX, "{:e}", x);
+            assert_eq!(bf16::from_f32_saturating(-x), bf16::MIN, "{:e}", x);
+        }
 
-        let tiny32 = f32::from_bits(0x0001_0000u32);
-        assert_eq!(bf16::from_bits(0x0001).to_f32(), tiny32);
-        assert_eq!(bf16::from_bits(0x0005).to_f32(), 5.0 * tiny32);
+        assert_eq!(bf16::from_f32_saturating(f32::INFINITY), bf16::INFINITY);
+        assert_eq!(bf16::from_f32_saturating(f32::NEG_INFINITY), bf16::NEG_INFINITY);
+        assert!(bf16::from_f32_saturating(f32::NAN).is_nan());
+        assert!(bf16::from_f32_saturating(-f32::NAN).is_nan());
+        for &x in &[0.0f32, -0.0, 1e-40, -1.5, 3.0e38, -3.3e38, f32::MIN_POSITIVE] {
+            assert_eq!(bf16::from_f32_saturating(x).to_bits(), bf16::from_f32(x).to_bits());
+        }
+    }
 
-        assert_eq!(bf16::from_bits(0x0001), bf16::from_f32(tiny32));
-        assert_eq!(bf16::from_bits(0x0005), bf16::from_f32(5.0 * tiny32));
+    #[test]
+    fn test_normal_bounds() {
+        const MIN: bf16 = bf16::min_normal();
+        const MAX: bf16 = bf16::max_normal();
+        assert_eq!(MIN.to_bits(), bf16::MIN_POSITIVE.to_bits());
+        assert_eq!(MAX.to_bits(), bf16::MAX.to_bits());
+        assert_eq!(
+            bf16::min_positive_subnormal().to_bits(),
+            bf16::MIN_POSITIVE_SUBNORMAL.to_bits()
+        );
+        assert!(MIN.is_normal() && MAX.is_normal());
+        assert!(!bf16::from_bits(MIN.to_bits() - 1).is_normal());
+        assert!(!bf16::from_bits(MAX.to_bits() + 1).is_finite());
+        assert!(bf16::MIN < MIN);
     }
 
     #[test]
     #[cfg_attr(miri, ignore)]
-    fn test_bf16_to_f64() {
-        let f = bf16::from_f64(7.0);
-        assert_eq!(f.to_f64(), 7.0f64);
-
-        // 7.1 is NOT exactly representable in 16-bit, it's rounded
-        let f = bf16::from_f64(7.1);
-        let diff = (f.to_f64() - 7.1f64).abs();
-        // diff must be <= 4 * EPSILON, as 7 has two more significant bits than 1
-        assert!(diff <= 4.0 * bf16::EPSILON.to_f64());
+    fn test_integer_predicates() {
+        const PREDICATES: [bool; 4] = [
+            bf16::NEG_ZERO.is_zero(),
+            bf16::NEG_ZERO.is_integer(),
+            bf16::NEG_ZERO.is_odd_integer(),
+            bf16::NEG_ZERO.is_even_integer(),
+        ];
+        assert_eq!(PREDICATES, [true, true, false, true]);
+
+        for bits in 0..=u16::MAX {
+            let x = bf16::from_bits(bits);
+            let f = x.to_f64();
+            let integer = f.is_finite() && f.fract() == 0.;
+            assert_eq!(x.is_zero(), f == 0., "{:?}", x);
+            assert_eq!(x.is_integer(), integer, "{:?}", x);
+            assert_eq!(x.is_odd_integer(), integer && f % 2. != 0., "{:?}", x);
+            assert_eq!(x.is_even_integer(), integer && f % 2. == 0., "{:?}", x);
+        }
+    }
 
-        let tiny64 = 2.0f64.powi(-133);
-        assert_eq!(bf16::from_bits(0x0001).to_f64(), tiny64);
-        assert_eq!(bf16::from_bits(0x0005).to_f64(), 5.0 * tiny64);
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_bisect() {
+        const MID: bf16 = bf16::NEG_ONE.bisect(bf16::ONE);
+        assert_eq!(MID.to_bits(), 0);
+
+        let values = crate::testing::stratified_values(2, 0x2317);
+        for &a in &values {
+            for &b in &values {
+                let mid = a.bisect(b);
+                if a.is_nan() || b.is_nan() {
+                    assert!(mid.is_nan_quiet());
+                    continue;
+                }
+                assert_eq!(mid.to_bits(), b.bisect(a).to_bits());
+                let (lo, hi) = if a < b {
+                    (a, b)
+                } else {
+                    (b, a)
+                };
+                if lo.ulp_distance(hi) > Some(1) {
+                    assert!(lo < mid && mid < hi, "{:?} {:?} {:?}", a, b, mid);
+                    // The halves differ by at most one value.
+                    let (left, right) = (lo.abs_diff_ulp(mid), mid.abs_diff_ulp(hi));
+                    assert!(right - left <= 1, "{:?} {:?} {:?}", a, b, mid);
+                } else {
+                    assert_eq!(mid.ulp_distance(lo), Some(0), "{:?} {:?} {:?}", a, b, mid);
+                }
+            }
+        }
+    }
 
-        assert_eq!(bf16::from_bits(0x0001), bf16::from_f64(tiny64));
-        assert_eq!(bf16::from_bits(0x0005), bf16::from_f64(5.0 * tiny64));
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_bisect_steps() {
+        // Searching for any value from the whole range takes at most 16
+        // steps.
+        for bits in 0..=u16::MAX {
+            let target = bf16::from_bits(bits);
+            if target.is_nan() {
+                continue;
+            }
+            let (mut lo, mut hi) = (bf16::NEG_INFINITY, bf16::INFINITY);
+            let mut steps = 0;
+            while lo.ulp_distance(hi) > Some(1) {
+                let mid = lo.bisect(hi);
+                if target <= mid {
+                    hi = mid;
+                } else {
+                    lo = mid;
+                }
+                steps += 1;
+            }
+            assert!(steps <= 16, "{:?}", target);
+            let found = |x: bf16| x.ulp_distance(target) == Some(0);
+            assert!(found(lo) || found(hi), "{:?}", target);
+        }
     }
 
     #[test]
-    fn test_comparisons() {
-        let zero = bf16::from_f64(0.0);
-        let one = bf16::from_f64(1.0);
-        let neg_zero = bf16::from_f64(-0.0);
-        let neg_one = bf16::from_f64(-1.0);
+    fn test_find_root() {
+        let f = |x: bf16| bf16::from_f64(x.to_f64() * x.to_f64() - 2.);
+        let (zero, two) = (bf16::ZERO, bf16::from_f32(2.));
+        assert_eq!(bf16::find_root(f, zero, two), Some(bf16::SQRT_2));
+        assert_eq!(bf16::find_root(f, two, zero), Some(bf16::SQRT_2));
+        assert_eq!(bf16::find_root(f, -two, zero), Some(-bf16::SQRT_2));
+        assert_eq!(bf16::find_root(f, bf16::MIN, zero), Some(-bf16::SQRT_2));
+        assert_eq!(bf16::find_root(f, two, bf16::INFINITY), None);
+        assert_eq!(bf16::find_root(f, bf16::NAN, two), None);
+
+        // The nearest value to the root, on either side.
+        for root in [0.1, -3.7, 1000.3, 1e-3] {
+            let f = |x: bf16| bf16::from_f64(x.to_f64() - root);
+            let expected = bf16::from_f64(root);
+            assert_eq!(bf16::find_root(f, bf16::NEG_INFINITY, bf16::INFINITY), Some(expected));
+        }
 
-        assert_eq!(zero.partial_cmp(&neg_zero), Some(Ordering::Equal));
-        assert_eq!(neg_zero.partial_cmp(&zero), Some(Ordering::Equal));
-        assert!(zero == neg_zero);
-        assert!(neg_zero == zero);
-        assert!(!(zero != neg_zero));
-        assert!(!(neg_zero != zero));
-        assert!(!(zero < neg_zero));
-        assert!(!(neg_zero < zero));
-        assert!(zero <= neg_zero);
-        assert!(neg_zero <= zero);
-        assert!(!(zero > neg_zero));
-        assert!(!(neg_zero > zero));
-        assert!(zero >= neg_zero);
-        assert!(neg_zero >= zero);
+        // Exact roots, at a bound or in between.
+        let f = |x: bf16| x;
+        assert_eq!(bf16::find_root(f, bf16::NEG_ONE, bf16::ONE).map(bf16::to_bits), Some(0));
+        assert_eq!(bf16::find_root(f, bf16::NEG_ZERO, bf16::ONE).map(bf16::to_bits), Some(0x8000));
+        assert_eq!(bf16::find_root(|x| x - bf16::ONE, bf16::ONE, two), Some(bf16::ONE));
+        assert_eq!(bf16::find_root(|_| bf16::NAN, bf16::NEG_ONE, bf16::ONE), None);
+
+        // A step function is bracketed down to adjacent values.
+        let step = bf16::from_f32(1.5);
+        let calls = core::cell::Cell::new(0);
+        let f = |x: bf16| {
+            calls.set(calls.get() + 1);
+            if x < step {
+                bf16::NEG_ONE
+            } else {
+                bf16::ONE
+            }
+        };
+        assert_eq!(
+            bf16::find_root(f, bf16::NEG_INFINITY, bf16::INFINITY),
+            Some(bf16::from_bits(step.to_bits() - 1))
+        );
+        assert!(calls.get() <= 18);
+    }
 
-        assert_eq!(one.partial_cmp(&neg_zero), Some(Ordering::Greater));
-        assert_eq!(neg_zero.partial_cmp(&one), Some(Ordering::Less));
-        assert!(!(one == neg_zero));
-        assert!(!(neg_zero == one));
-        assert!(one != neg_zero);
-        assert!(neg_zero != one);
-        assert!(!(one < neg_zero));
-        assert!(neg_zero < one);
-        assert!(!(one <= neg_zero));
-        assert!(neg_zero <= one);
-        assert!(one > neg_zero);
-        assert!(!(neg_zero > one));
-        assert!(one >= neg_zero);
-        assert!(!(neg_zero >= one));
+    #[test]
+    fn test_from_f32_stochastic() {
+        // Every random value gives an expected result that is exactly the
+        // value, since the dropped bits are exactly the 16 random bits.
+        let values = [
+            f32::from_bits(0x3F80_4000),
+            0.1,
+            -2.71,
+            1000.7,
+            -1e30,
+            f32::MAX,
+            f32::from_bits(0x0000_1234),
+            -f32::from_bits(0x007F_FFFF),
+        ];
+        for &value in &values {
+            let mut sum = 0f64;
+            let (mut below, mut above) = (bf16::INFINITY, bf16::NEG_INFINITY);
+            for random in 0..=u16::MAX {
+                let result = bf16::from_f32_stochastic(value, random);
+                if result.is_finite() {
+                    sum += result.to_f64();
+                } else {
+                    // The next value above the largest finite value.
+                    sum += result.to_f64().signum() * 2f64.powi(128);
+                }
+                below = below.min(result);
+                above = above.max(result);
+            }
+            assert_eq!(sum / 65536., value as f64, "{}", value);
+            // Only the two neighbors are possible, and 0 truncates.
+            assert!(below.to_f64() <= value as f64 && value as f64 <= above.to_f64());
+            assert!(above.to_bits() & 0x7FFF <= (below.to_bits() & 0x7FFF) + 1);
+            assert_eq!(bf16::from_f32_stochastic(value, 0), bf16::from_f32_truncate(value));
+        }
 
-        assert_eq!(one.partial_cmp(&neg_one), Some(Ordering::Greater));
-        assert_eq!(neg_one.partial_cmp(&one), Some(Ordering::Less));
-        assert!(!(one == neg_one));
-        assert!(!(neg_one == one));
-        assert!(one != neg_one);
-        assert!(neg_one != one);
-        assert!(!(one < neg_one));
-        assert!(neg_one < one);
-        assert!(!(one <= neg_one));
-        assert!(neg_one <= one);
-        assert!(one > neg_one);
-        assert!(!(neg_one > one));
-        assert!(one >= neg_one);
-        assert!(!(neg_one >= one));
+        // With a generator, the mean approaches the value.
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+        let mut rng = StdRng::seed_from_u64(0x5EED);
+        let value = 0.3f32;
+        let mean =
+            (0..10_000).map(|_| bf16::from_f32_stochastic(value, rng.gen()).to_f64()).sum::<f64>()
+                / 10_000.;
+        let ulp = bf16::EPSILON.to_f64() / 4.;
+        assert!((mean - value as f64).abs() < ulp / 10., "{}", mean);
+        assert_ne!(bf16::from_f32(value).to_f64(), value as f64);
+
+        // Exact values, overflow, carries and special values.
+        for random in [0u16, 1, 0x8000, 0xFFFF] {
+            assert_eq!(bf16::from_f32_stochastic(1.5, random), bf16::from_f32(1.5));
+            assert_eq!(bf16::from_f32_stochastic(f32::INFINITY, random), bf16::INFINITY);
+            assert_eq!(bf16::from_f32_stochastic(f32::NEG_INFINITY, random), bf16::NEG_INFINITY);
+            assert!(bf16::from_f32_stochastic(f32::NAN, random).is_nan());
+            assert!(bf16::from_f32_stochastic(f32::from_bits(0x7F80_0001), random).is_nan());
+            assert_eq!(bf16::from_f32_stochastic(-0.0, random).to_bits(), 0x8000);
+        }
+        assert_eq!(bf16::from_f32_stochastic(f32::MAX, 0x0000), bf16::MAX);
+        assert_eq!(bf16::from_f32_stochastic(f32::MAX, 0x0001), bf16::INFINITY);
+        assert_eq!(bf16::from_f32_stochastic(f32::MIN, 0x0001), bf16::NEG_INFINITY);
+        assert_eq!(bf16::from_f32_stochastic(f32::from_bits(0x007F_FFFF), 1), bf16::MIN_POSITIVE);
+        assert_eq!(bf16::from_f32_stochastic(f32::from_bits(0x0000_0001), 0xFFFF).to_bits(), 1);
+        assert_eq!(bf16::from_f32_stochastic(f32::from_bits(0x0000_0001), 0xFFFE).to_bits(), 0);
+
+        // The result is reproducible, and does not depend on the target.
+        const ROUNDED: bf16 = bf16::from_f32_stochastic(0.1, 0x1234);
+        assert_eq!(ROUNDED, bf16::from_f32_stochastic(0.1, 0x1234));
+        assert_eq!(ROUNDED.to_bits(), 0x3DCC);
     }
 
     #[test]
-    #[allow(clippy::erasing_op, clippy::identity_op)]
-    #[cfg_attr(miri, ignore)]
-    fn round_to_even_f32() {
-        // smallest positive subnormal = 0b0.0000_001 * 2^-126 = 2^-133
-        let min_sub = bf16::from_bits(1);
-        let min_sub_f = (-133f32).exp2();
-        assert_eq!(bf16::from_f32(min_sub_f).to_bits(), min_sub.to_bits());
-        assert_eq!(f32::from(min_sub).to_bits(), min_sub_f.to_bits());
+    fn test_from_f32_truncate() {
+        // The low 16 bits are dropped, which differs from rounding by 1 ulp
+        // when they are at least half an ulp, except for ties to even.
+        for &(bits, truncated, rounded) in &[
+            (0x3F80_0000u32, 0x3F80u16, 0x3F80u16),
+            (0x3F80_7FFF, 0x3F80, 0x3F80),
+            (0x3F80_8000, 0x3F80, 0x3F80),
+            (0x3F81_8000, 0x3F81, 0x3F82),
+            (0x3F80_8001, 0x3F80, 0x3F81),
+            (0x3F80_FFFF, 0x3F80, 0x3F81),
+            (0xBF80_FFFF, 0xBF80, 0xBF81),
+            (0x7F7F_FFFF, 0x7F7F, 0x7F80),
+            (0x0000_FFFF, 0x0000, 0x0001),
+            (0x007F_FFFF, 0x007F, 0x0080),
+        ] {
+            let value = f32::from_bits(bits);
+            assert_eq!(bf16::from_f32_truncate(value).to_bits(), truncated, "{:#010x}", bits);
+            assert_eq!(bf16::from_f32(value).to_bits(), rounded, "{:#010x}", bits);
+        }
 
-        // 0.0000000_011111 rounded to 0.0000000 (< tie, no rounding)
-        // 0.0000000_100000 rounded to 0.0000000 (tie and even, remains at even)
-        // 0.0000000_100001 rounded to 0.0000001 (> tie, rounds up)
-        assert_eq!(bf16::from_f32(min_sub_f * 0.49).to_bits(), min_sub.to_bits() * 0);
-        assert_eq!(bf16::from_f32(min_sub_f * 0.50).to_bits(), min_sub.to_bits() * 0);
-        assert_eq!(bf16::from_f32(min_sub_f * 0.51).to_bits(), min_sub.to_bits() * 1);
+        const TRUNCATED: bf16 = bf16::from_f32_truncate(1.0);
+        assert_eq!(TRUNCATED, bf16::ONE);
+        assert_eq!(bf16::from_f32_truncate(f32::INFINITY), bf16::INFINITY);
+        assert_eq!(bf16::from_f32_truncate(f32::NEG_INFINITY), bf16::NEG_INFINITY);
+
+        // NaNs with the payload only in the dropped bits never become
+        // infinities, and are quiet.
+        for &low in &[0x0001u32, 0x8000, 0xFFFF, 0x7F_FFFF, 0x40_0000] {
+            for &sign in &[0u32, 0x8000_0000] {
+                let nan = f32::from_bits(sign | 0x7F80_0000 | low);
+                let result = bf16::from_f32_truncate(nan);
+                assert!(result.is_nan());
+                assert_ne!(result.to_bits() & 0x0040, 0);
+                assert_eq!(
+                    result.is_sign_negative(),
+                    sign != 0 && !cfg!(feature = "canonical-nan")
+                );
+            }
+        }
+    }
 
-        // 0.0000001_011111 rounded to 0.0000001 (< tie, no rounding)
-        // 0.0000001_100000 rounded to 0.0000010 (tie and odd, rounds up to even)
-        // 0.0000001_100001 rounded to 0.0000010 (> tie, rounds up)
-        assert_eq!(bf16::from_f32(min_sub_f * 1.49).to_bits(), min_sub.to_bits() * 1);
-        assert_eq!(bf16::from_f32(min_sub_f * 1.50).to_bits(), min_sub.to_bits() * 2);
-        assert_eq!(bf16::from_f32(min_sub_f * 1.51).to_bits(), min_sub.to_bits() * 2);
+    #[test]
+    fn test_bit_counts() {
+        for bits in 0..=u16::MAX {
+            let x = bf16::from_bits(bits);
+            assert_eq!(x.count_ones(), bits.count_ones());
+            assert_eq!(x.count_ones() + x.count_zeros(), 16);
+            let man = bits & bf16::MAN_MASK;
+            let leading = (0..7).rev().take_while(|&i| man & (1 << i) == 0).count() as u32;
+            let trailing = (0..7).take_while(|&i| man & (1 << i) == 0).count() as u32;
+            assert_eq!(x.leading_zeros_mantissa(), leading, "{:#06x}", bits);
+            assert_eq!(x.trailing_zeros_mantissa(), trailing, "{:#06x}", bits);
+        }
+        const ONES: u32 = bf16::NAN.count_ones();
+        assert_eq!(ONES, bf16::NAN.to_bits().count_ones());
+        assert_eq!(bf16::ZERO.leading_zeros_mantissa(), 7);
+        assert_eq!(bf16::ZERO.trailing_zeros_mantissa(), 7);
+        assert_eq!(bf16::MAX_SUBNORMAL.leading_zeros_mantissa(), 0);
+        assert_eq!(bf16::MAX_SUBNORMAL.trailing_zeros_mantissa(), 0);
+    }
 
-        // 0.0000010_011111 rounded to 0.0000010 (< tie, no rounding)
-        // 0.0000010_100000 rounded to 0.0000010 (tie and even, remains at even)
-        // 0.0000010_100001 rounded to 0.0000011 (> tie, rounds up)
-        assert_eq!(bf16::from_f32(min_sub_f * 2.49).to_bits(), min_sub.to_bits() * 2);
-        assert_eq!(bf16::from_f32(min_sub_f * 2.50).to_bits(), min_sub.to_bits() * 2);
-        assert_eq!(bf16::from_f32(min_sub_f * 2.51).to_bits(), min_sub.to_bits() * 3);
+    #[test]
+    fn test_abs_diff() {
+        // The difference is rounded once, the same as subtracting in `f32`.
+        let step = if cfg!(miri) {
+            4099
+        } else {
+            97
+        };
+        for a in (0..=u16::MAX).step_by(step) {
+            for b in (0..=u16::MAX).step_by(step + 6) {
+                let (a, b) = (bf16::from_bits(a), bf16::from_bits(b));
+                let expected = (a - b).abs();
+                let actual = a.abs_diff(b);
+                assert!(
+                    actual.to_bits() == expected.to_bits()
+                        || (actual.is_nan() && expected.is_nan()),
+                    "{:?} {:?}",
+                    a,
+                    b
+                );
+                assert_eq!(a.abs_diff_ulp(b), a.ulp_distance(b).unwrap_or(0));
+            }
+        }
 
-        assert_eq!(bf16::from_f32(250.49f32).to_bits(), bf16::from_f32(250.0).to_bits());
-        assert_eq!(bf16::from_f32(250.50f32).to_bits(), bf16::from_f32(250.0).to_bits());
-        assert_eq!(bf16::from_f32(250.51f32).to_bits(), bf16::from_f32(251.0).to_bits());
-        assert_eq!(bf16::from_f32(251.49f32).to_bits(), bf16::from_f32(251.0).to_bits());
-        assert_eq!(bf16::from_f32(251.50f32).to_bits(), bf16::from_f32(252.0).to_bits());
-        assert_eq!(bf16::from_f32(251.51f32).to_bits(), bf16::from_f32(252.0).to_bits());
-        assert_eq!(bf16::from_f32(252.49f32).to_bits(), bf16::from_f32(252.0).to_bits());
-        assert_eq!(bf16::from_f32(252.50f32).to_bits(), bf16::from_f32(252.0).to_bits());
-        assert_eq!(bf16::from_f32(252.51f32).to_bits(), bf16::from_f32(253.0).to_bits());
+        const DIFF: bf16 = bf16::MAX.abs_diff(bf16::MIN);
+        assert_eq!(DIFF, bf16::INFINITY);
+        assert_eq!(bf16::MAX.abs_diff(bf16::MAX).to_bits(), 0);
+        assert_eq!(bf16::ZERO.abs_diff(bf16::NEG_ZERO).to_bits(), 0);
+        assert_eq!(bf16::ONE.abs_diff(bf16::NEG_INFINITY), bf16::INFINITY);
+        assert!(bf16::NEG_INFINITY.abs_diff(bf16::NEG_INFINITY).is_nan());
+        assert!(bf16::NAN.abs_diff(bf16::ONE).is_nan());
+        assert!(bf16::ONE.abs_diff(-bf16::NAN).is_nan());
+        assert_eq!(
+            bf16::MIN_POSITIVE_SUBNORMAL.abs_diff(-bf16::MIN_POSITIVE_SUBNORMAL),
+            bf16::from_bits(2)
+        );
+
+        const ULPS: u16 = bf16::NEG_INFINITY.abs_diff_ulp(bf16::INFINITY);
+        assert_eq!(ULPS, 2 * bf16::INFINITY.to_bits());
+        assert_eq!(bf16::NAN.abs_diff_ulp(bf16::NAN), 0);
+        assert_eq!(bf16::ONE.abs_diff_ulp(-bf16::ONE), 2 * bf16::ONE.to_bits());
     }
 
     #[test]
-    #[allow(clippy::erasing_op, clippy::identity_op)]
-    #[cfg_attr(miri, ignore)]
-    fn round_to_even_f64() {
-        // smallest positive subnormal = 0b0.0000_001 * 2^-126 = 2^-133
-        let min_sub = bf16::from_bits(1);
-        let min_sub_f = (-133f64).exp2();
-        assert_eq!(bf16::from_f64(min_sub_f).to_bits(), min_sub.to_bits());
-        assert_eq!(f64::from(min_sub).to_bits(), min_sub_f.to_bits());
+    #[cfg(feature = "total-order-impls")]
+    fn test_total_order_impls() {
+        use core::hash::{Hash, Hasher};
 
-        // 0.0000000_011111 rounded to 0.0000000 (< tie, no rounding)
-        // 0.0000000_100000 rounded to 0.0000000 (tie and even, remains at even)
-        // 0.0000000_100001 rounded to 0.0000001 (> tie, rounds up)
-        assert_eq!(bf16::from_f64(min_sub_f * 0.49).to_bits(), min_sub.to_bits() * 0);
-        assert_eq!(bf16::from_f64(min_sub_f * 0.50).to_bits(), min_sub.to_bits() * 0);
-        assert_eq!(bf16::from_f64(min_sub_f * 0.51).to_bits(), min_sub.to_bits() * 1);
+        struct Recorder(u64);
+
+        impl Hasher for Recorder {
+            fn finish(&self) -> u64 {
+                self.0
+            }
+
+            fn write(&mut self, bytes: &[u8]) {
+                for &byte in bytes {
+                    self.0 = self.0.wrapping_mul(257).wrapping_add(byte as u64);
+                }
+            }
+        }
+
+        fn hash(value: impl Hash) -> u64 {
+            let mut recorder = Recorder(0);
+            value.hash(&mut recorder);
+            recorder.finish()
+        }
+
+        // Every operator agrees with `total_cmp`, and equality is bitwise.
+        let step = if cfg!(miri) {
+            4099
+        } else {
+            61
+        };
+        for a in (0..=u16::MAX).step_by(step) {
+            for b in (0..=u16::MAX).step_by(step + 6) {
+                let (x, y) = (bf16::from_bits(a), bf16::from_bits(b));
+                let ordering = x.total_cmp(&y);
+                assert_eq!(x.cmp(&y), ordering);
+                assert_eq!(x.partial_cmp(&y), Some(ordering));
+                assert_eq!(x == y, a == b);
+                assert_eq!(x < y, ordering == Ordering::Less);
+                assert_eq!(x <= y, ordering != Ordering::Greater);
+                assert_eq!(x > y, ordering == Ordering::Greater);
+                assert_eq!(x >= y, ordering != Ordering::Less);
+            }
+            assert_eq!(hash(bf16::from_bits(a)), hash(a));
+        }
 
-        // 0.0000001_011111 rounded to 0.0000001 (< tie, no rounding)
-        // 0.0000001_100000 rounded to 0.0000010 (tie and odd, rounds up to even)
-        // 0.0000001_100001 rounded to 0.0000010 (> tie, rounds up)
-        assert_eq!(bf16::from_f64(min_sub_f * 1.49).to_bits(), min_sub.to_bits() * 1);
-        assert_eq!(bf16::from_f64(min_sub_f * 1.50).to_bits(), min_sub.to_bits() * 2);
-        assert_eq!(bf16::from_f64(min_sub_f * 1.51).to_bits(), min_sub.to_bits() * 2);
+        assert!(bf16::NEG_ZERO < bf16::ZERO);
+        assert_ne!(bf16::NEG_ZERO, bf16::ZERO);
+        assert_eq!(bf16::NAN, bf16::NAN);
+        assert!(bf16::INFINITY < bf16::NAN);
+        assert!(-bf16::NAN < bf16::NEG_INFINITY);
+
+        let mut values = [bf16::NAN, bf16::ONE, bf16::ZERO, -bf16::NAN, bf16::NEG_ZERO, bf16::MIN];
+        values.sort_unstable();
+        assert_eq!(values.map(bf16::to_bits), [
+            (-bf16::NAN).to_bits(),
+            bf16::MIN.to_bits(),
+            bf16::NEG_ZERO.to_bits(),
+            bf16::ZERO.to_bits(),
+            bf16::ONE.to_bits(),
+            bf16::NAN.to_bits(),
+        ]);
+
+        // The inherent methods keep the IEEE 754 semantics.
+        assert_eq!(bf16::NAN.max(bf16::ONE).to_bits(), bf16::ONE.to_bits());
+        assert_eq!(bf16::ONE.min(bf16::NAN).to_bits(), bf16::ONE.to_bits());
+        assert_eq!(bf16::ZERO.clamp(bf16::NEG_ZERO, bf16::NEG_ZERO).to_bits(), 0);
+        assert_eq!(bf16::NEG_ZERO.max(bf16::ZERO).to_bits(), bf16::NEG_ZERO.to_bits());
+    }
 
-        // 0.0000010_011111 rounded to 0.0000010 (< tie, no rounding)
-        // 0.0000010_100000 rounded to 0.0000010 (tie and even, remains at even)
-        // 0.0000010_100001 rounded to 0.0000011 (> tie, rounds up)
-        assert_eq!(bf16::from_f64(min_sub_f * 2.49).to_bits(), min_sub.to_bits() * 2);
-        assert_eq!(bf16::from_f64(min_sub_f * 2.50).to_bits(), min_sub.to_bits() * 2);
-        assert_eq!(bf16::from_f64(min_sub_f * 2.51).to_bits(), min_sub.to_bits() * 3);
+    #[test]
+    fn test_to_int() {
+        // The same as an `as` cast from `f32`, which is exact.
+        for bits in 0..=u16::MAX {
+            let value = bf16::from_bits(bits);
+            let x = value.to_f32();
+            assert_eq!(value.to_u8(), x as u8, "{:?}", value);
+            assert_eq!(value.to_u16(), x as u16, "{:?}", value);
+            assert_eq!(value.to_u32(), x as u32, "{:?}", value);
+            assert_eq!(value.to_u64(), x as u64, "{:?}", value);
+            assert_eq!(value.to_u128(), x as u128, "{:?}", value);
+            assert_eq!(value.to_i8(), x as i8, "{:?}", value);
+            assert_eq!(value.to_i16(), x as i16, "{:?}", value);
+            assert_eq!(value.to_i32(), x as i32, "{:?}", value);
+            assert_eq!(value.to_i64(), x as i64, "{:?}", value);
+            assert_eq!(value.to_i128(), x as i128, "{:?}", value);
+        }
 
-        assert_eq!(bf16::from_f64(250.49f64).to_bits(), bf16::from_f64(250.0).to_bits());
-        assert_eq!(bf16::from_f64(250.50f64).to_bits(), bf16::from_f64(250.0).to_bits());
-        assert_eq!(bf16::from_f64(250.51f64).to_bits(), bf16::from_f64(251.0).to_bits());
-        assert_eq!(bf16::from_f64(251.49f64).to_bits(), bf16::from_f64(251.0).to_bits());
-        assert_eq!(bf16::from_f64(251.50f64).to_bits(), bf16::from_f64(252.0).to_bits());
-        assert_eq!(bf16::from_f64(251.51f64).to_bits(), bf16::from_f64(252.0).to_bits());
-        assert_eq!(bf16::from_f64(252.49f64).to_bits(), bf16::from_f64(252.0).to_bits());
-        assert_eq!(bf16::from_f64(252.50f64).to_bits(), bf16::from_f64(252.0).to_bits());
-        assert_eq!(bf16::from_f64(252.51f64).to_bits(), bf16::from_f64(253.0).to_bits());
+        // The largest values that fit, and the next values, which saturate.
+        let f = |x: f64| {
+            let value = bf16::from_f64(x);
+            assert_eq!(value.to_f64(), x);
+            value
+        };
+        assert_eq!(f(255.).to_u8(), 255);
+        assert_eq!(f(256.).to_u8(), u8::MAX);
+        assert_eq!(f(65280.).to_u16(), 65280);
+        assert_eq!(f(65536.).to_u16(), u16::MAX);
+        assert_eq!(f(4278190080.).to_u32(), 4278190080);
+        assert_eq!(f(4294967296.).to_u32(), u32::MAX);
+        assert_eq!(f(18374686479671623680.).to_u64(), 18374686479671623680);
+        assert_eq!(f(18446744073709551616.).to_u64(), u64::MAX);
+        assert_eq!(bf16::MAX.to_u128(), 0xFF00_0000_0000_0000_0000_0000_0000_0000);
+        assert_eq!(bf16::INFINITY.to_u128(), u128::MAX);
+        assert_eq!(f(127.).to_i8(), 127);
+        assert_eq!(f(128.).to_i8(), i8::MAX);
+        assert_eq!(f(-128.).to_i8(), i8::MIN);
+        assert_eq!(f(-130.).to_i8(), i8::MIN);
+        assert_eq!(f(32640.).to_i16(), 32640);
+        assert_eq!(f(32768.).to_i16(), i16::MAX);
+        assert_eq!(f(2139095040.).to_i32(), 2139095040);
+        assert_eq!(f(2147483648.).to_i32(), i32::MAX);
+        assert_eq!(f(-2147483648.).to_i32(), i32::MIN);
+        assert_eq!(f(-2164260864.).to_i32(), i32::MIN);
+        assert_eq!(f(9187343239835811840.).to_i64(), 9187343239835811840);
+        assert_eq!(f(9223372036854775808.).to_i64(), i64::MAX);
+        assert_eq!(f(-9223372036854775808.).to_i64(), i64::MIN);
+        assert_eq!(f(1.7014118346046923e38).to_i128(), i128::MAX);
+        assert_eq!(f(-1.7014118346046923e38).to_i128(), i128::MIN);
+
+        // Truncation toward zero, negative values for unsigned types, and NaN.
+        const TRUNCATED: i32 = bf16::from_f32_const(-2.75).to_i32();
+        assert_eq!(TRUNCATED, -2);
+        assert_eq!(bf16::from_f32(2.75).to_u8(), 2);
+        assert_eq!(bf16::from_f32(-2.75).to_u32(), 0);
+        assert_eq!(bf16::NEG_INFINITY.to_u64(), 0);
+        assert_eq!(bf16::NEG_INFINITY.to_i16(), i16::MIN);
+        assert_eq!(bf16::MIN_POSITIVE_SUBNORMAL.to_i32(), 0);
+        assert_eq!(bf16::from_f32(0.99).to_i64(), 0);
+        assert_eq!(bf16::NAN.to_i32(), 0);
+        assert_eq!((-bf16::NAN).to_u8(), 0);
     }
 
     #[test]
-    fn from_f32_lossless() {
-        let from_f32 = |v: f32| bf16::from_f32_lossless(v);
-        let roundtrip = |v: f32, expected: Option<bf16>| {
-            let half = from_f32(v);
-            assert_eq!(half, expected);
-            if !expected.is_none() {
-                let as_f32 = expected.unwrap().to_f32_const();
-                assert_eq!(v, as_f32);
+    fn test_from_f16_lossy() {
+        // Identical to converting through `f32`, which rounds once.
+        for bits in 0..=u16::MAX {
+            let value = f16::from_bits(bits);
+            let expected = bf16::from_f32(value.to_f32());
+            let actual = bf16::from_f16_lossy(value);
+            if value.is_nan() {
+                assert!(actual.is_nan());
+                if !cfg!(feature = "canonical-nan") {
+                    assert_eq!(actual.to_bits() & 0x8000, bits & 0x8000);
+                }
+            } else {
+                assert_eq!(actual.to_bits(), expected.to_bits(), "{:#06x}", bits);
             }
-        };
+        }
 
-        assert_eq!(from_f32(f32::NAN).map(bf16::is_nan), Some(true));
-        roundtrip(f32::INFINITY, Some(bf16::INFINITY));
-        roundtrip(f32::NEG_INFINITY, Some(bf16::NEG_INFINITY));
-        roundtrip(f32::from_bits(0b0_00000000_00000000000000000000000), Some(bf16(0)));
-        roundtrip(
-            f32::from_bits(0b1_00000000_00000000000000000000000),
-            Some(bf16(bf16::SIGN_MASK)),
-        );
-        roundtrip(f32::from_bits(1), None);
-        roundtrip(f32::from_bits(0b0_00001010_10101001010110100101110), None);
-        roundtrip(f32::from_bits(0b0_00001010_10101001010110100101110), None);
-        roundtrip(f32::from_bits(0b0_00001010_10101011000000000000000), None);
-        roundtrip(
-            f32::from_bits(0b0_00001010_10101010000000000000000),
-            Some(bf16(0b0_00001010_1010101)),
-        );
-        roundtrip(f32::from_bits(0b0_00000000_10000000000000000000000), Some(bf16(0x40)));
-        // special truncation with denormals, etc.
-        roundtrip(f32::from_bits(0b0_00000000_00000001000000000000000), None);
-        roundtrip(f32::from_bits(0b0_00000000_00000010000000000000000), Some(bf16(1)));
-        roundtrip(f32::from_bits(0b0_00000000_00000100000000000000000), Some(bf16(2)));
-        roundtrip(f32::from_bits(0b0_00000000_00000110000000000000000), Some(bf16(3)));
-        roundtrip(f32::from_bits(0b0_00000000_00000111000000000000000), None);
-        roundtrip(f32::from_bits(0b0_00001011_10100111101101101001001), None);
-        // 1.99170198e-35 and has bits until 16 to the end, so truncated 2
-        roundtrip(f32::from_bits(0b0_00001011_10100111100000000000000), None);
-        // 1.99170198e-35 and has bits until 15 to the end, so truncated 1
-        roundtrip(f32::from_bits(0b0_00001011_10100111000000000000000), None);
-        // 1.99170198e-35 and has bits until 15 to the end, so truncated 1
-        roundtrip(f32::from_bits(0b0_00001011_10100110000000000000000), Some(bf16(0x05d3)));
+        const ONE: bf16 = bf16::from_f16_lossy(f16::ONE);
+        assert_eq!(ONE, bf16::ONE);
+        assert_eq!(bf16::from_f16_lossy(f16::MAX).to_f32(), 65536.);
+        assert_eq!(bf16::from_f16_lossy(f16::NEG_INFINITY), bf16::NEG_INFINITY);
+        assert_eq!(bf16::from_f16_lossy(f16::NEG_ZERO).to_bits(), 0x8000);
+        // Ties round to even.
+        let tie = f16::from_f32(1.0 + 4.0 / 1024.0);
+        assert_eq!(bf16::from_f16_lossy(tie), bf16::ONE);
+        let tie = f16::from_f32(1.0 + 12.0 / 1024.0);
+        assert_eq!(bf16::from_f16_lossy(tie).to_f32(), 1.0 + 16.0 / 1024.0);
     }
 
     #[test]
-    fn from_f64_lossless() {
-        let from_f64 = |v: f64| bf16::from_f64_lossless(v);
-        let roundtrip = |v: f64, expected: Option<bf16>| {
-            let half = from_f64(v);
-            assert_eq!(half, expected);
-            if !expected.is_none() {
-                let as_f64 = expected.unwrap().to_f64_const();
-                assert_eq!(v, as_f64);
+    fn test_from_str_extremes() {
+        // Parsing through `f32` rounds these to the midpoint above the
+        // largest value, and then to infinity.
+        let below_overflow = "3.39617752e38";
+        assert_eq!(bf16::from_f32(below_overflow.parse().unwrap()), bf16::INFINITY);
+        assert_eq!(below_overflow.parse::<bf16>().unwrap(), bf16::MAX);
+        assert_eq!("-3.39617752e38".parse::<bf16>().unwrap(), bf16::MIN);
+        assert_eq!("3.39617753e38".parse::<bf16>().unwrap(), bf16::INFINITY);
+
+        // The same at the smallest subnormal, which is 2^-133.
+        let above_half = "4.5917748078995605780028770985243971789791623311409668808935613526500674197450280189514160156251e-41";
+        assert_eq!(bf16::from_f32(above_half.parse().unwrap()), bf16::ZERO);
+        assert_eq!(above_half.parse::<bf16>().unwrap(), bf16::MIN_POSITIVE_SUBNORMAL);
+        assert_eq!("4.59e-41".parse::<bf16>().unwrap(), bf16::ZERO);
+        assert_eq!("1e-40".parse::<bf16>().unwrap().to_bits(), 0x0001);
+        assert_eq!("9.2e-41".parse::<bf16>().unwrap(), bf16::MIN_POSITIVE_SUBNORMAL);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_try_into_int() {
+        // The same as a cast, when the value is an integer in range.
+        for bits in 0..=u16::MAX {
+            let value = bf16::from_bits(bits);
+            let x = value.to_f64();
+            let kind = |range: bool| {
+                if value.is_nan() {
+                    TryFromHalfErrorKind::Nan
+                } else if value.is_infinite() {
+                    TryFromHalfErrorKind::Infinite
+                } else if !range {
+                    TryFromHalfErrorKind::OutOfRange
+                } else {
+                    TryFromHalfErrorKind::Fractional
+                }
+            };
+            let exact = x.is_finite() && x.fract() == 0.;
+            match u8::try_from(value) {
+                Ok(v) => assert!(exact && v as f64 == x),
+                Err(e) => assert_eq!(e.kind(), kind(x.trunc() >= 0. && x.trunc() <= 255.)),
             }
-        };
+            match i16::try_from(value) {
+                Ok(v) => assert!(exact && v as f64 == x),
+                Err(e) => assert_eq!(e.kind(), kind(x.trunc() >= -32768. && x.trunc() <= 32767.)),
+            }
+            match i64::try_from(value) {
+                Ok(v) => assert!(exact && v as f64 == x),
+                Err(e) => assert_eq!(
+                    e.kind(),
+                    kind(x.trunc().abs() < 2f64.powi(63) || x == -(2f64.powi(63)))
+                ),
+            }
+            match u32::try_from(value) {
+                Ok(v) => assert!(exact && v as f64 == x),
+                Err(e) => assert_eq!(e.kind(), kind(x.trunc() >= 0. && x.trunc() < 2f64.powi(32))),
+            }
+        }
 
-        assert_eq!(from_f64(f64::NAN).map(bf16::is_nan), Some(true));
-        roundtrip(f64::INFINITY, Some(bf16::INFINITY));
-        roundtrip(f64::NEG_INFINITY, Some(bf16::NEG_INFINITY));
-        roundtrip(
-            f64::from_bits(0b0_00000000000_0000000000000000000000000000000000000000000000000000),
-            Some(bf16(0)),
-        );
-        roundtrip(
-            f64::from_bits(0b1_00000000000_0000000000000000000000000000000000000000000000000000),
-            Some(bf16(bf16::SIGN_MASK)),
-        );
-        roundtrip(
-            f64::from_bits(0b0_01110001010_1010100101011010010110110111111110000111101000001111),
-            None,
-        );
-        // 1.99170198e-35 and has bits until 44 to the end, so truncated 1
-        roundtrip(
-            f64::from_bits(0b0_01110001010_1010100100000000000000000000000000000000000000000000),
-            None,
-        );
-        roundtrip(
-            f64::from_bits(0b0_01110001010_1010100000000000000000000000000000000000000000000000),
-            Some(bf16(0x0554)),
+        assert_eq!(
+            u8::try_from(bf16::from_f32(0.5)).unwrap_err().kind(),
+            TryFromHalfErrorKind::Fractional
         );
-        roundtrip(
-            f64::from_bits(0b0_01110001010_1010101000000000000000000000000000000000000000000000),
-            Some(bf16(0x0555)),
+        assert_eq!(
+            i32::try_from(bf16::from_f32(-0.5)).unwrap_err().kind(),
+            TryFromHalfErrorKind::Fractional
         );
-        roundtrip(
-            f64::from_bits(0b0_01110001010_1010110000000000000000000000000000000000000000000000),
-            Some(bf16(0x0556)),
+        assert_eq!(
+            u8::try_from(bf16::from_f32(-0.5)).unwrap_err().kind(),
+            TryFromHalfErrorKind::Fractional
         );
-        roundtrip(
-            f64::from_bits(0b0_01110001010_1010111000000000000000000000000000000000000000000000),
-            Some(bf16(0x0557)),
+        assert_eq!(
+            u8::try_from(bf16::from_f32(-1.)).unwrap_err().kind(),
+            TryFromHalfErrorKind::OutOfRange
         );
-        roundtrip(
-            f64::from_bits(0b0_01110001010_1010101100000000000000000000000000000000000000000000),
-            None,
+        assert_eq!(u8::try_from(bf16::NEG_ZERO), Ok(0));
+        assert_eq!(i64::try_from(bf16::NEG_ZERO), Ok(0));
+        assert_eq!(u8::try_from(bf16::from_f32(255.)), Ok(255));
+        assert_eq!(
+            u8::try_from(bf16::from_f32(256.)).unwrap_err().kind(),
+            TryFromHalfErrorKind::OutOfRange
         );
-        roundtrip(
-            f64::from_bits(0b0_01110001010_1010100110000000000000000000000000000000000000000000),
-            None,
+        assert_eq!(u8::try_from(bf16::NAN).unwrap_err().kind(), TryFromHalfErrorKind::Nan);
+        assert_eq!(i32::try_from(-bf16::NAN).unwrap_err().kind(), TryFromHalfErrorKind::Nan);
+        assert_eq!(
+            i32::try_from(bf16::INFINITY).unwrap_err().kind(),
+            TryFromHalfErrorKind::Infinite
         );
-        roundtrip(
-            f64::from_bits(0b1_01110001010_1010100000000000000000000000000000000000000000000000),
-            Some(bf16(0x8554)),
+        assert_eq!(
+            u128::try_from(bf16::NEG_INFINITY).unwrap_err().kind(),
+            TryFromHalfErrorKind::Infinite
         );
-        roundtrip(
-            f64::from_bits(0b1_01110001010_1010101000000000000000000000000000000000000000000000),
-            Some(bf16(0x8555)),
+        assert_eq!(
+            i8::try_from(bf16::MIN_POSITIVE_SUBNORMAL).unwrap_err().kind(),
+            TryFromHalfErrorKind::Fractional
         );
-        // exp out of range but finite
-        roundtrip(
-            f64::from_bits(0b1_11110001010_1010101000000000000000000000000000000000000000000000),
-            None,
+        assert_eq!(i16::try_from(bf16::from_f32(-3.)), Ok(-3));
+        assert_eq!(u128::try_from(bf16::MAX), Ok(0xFF00_0000_0000_0000_0000_0000_0000_0000));
+        assert_eq!(i128::try_from(bf16::MAX).unwrap_err().kind(), TryFromHalfErrorKind::OutOfRange);
+        assert_eq!(i128::try_from(bf16::from_f64(-(2f64.powi(127)))), Ok(i128::MIN));
+        assert_eq!(
+            i64::try_from(bf16::from_f64(2f64.powi(63))).unwrap_err().kind(),
+            TryFromHalfErrorKind::OutOfRange
         );
-        // explicitly check denormals
-        roundtrip(
-            f64::from_bits(0b0_01101111010_0000000000000000000000000000000000000000000000000000),
-            Some(bf16(1)),
+        assert_eq!(i64::try_from(bf16::from_f64(-(2f64.powi(63)))), Ok(i64::MIN));
+        assert_eq!(u64::try_from(bf16::from_f64(2f64.powi(63))), Ok(1 << 63));
+        assert_eq!(i8::try_from(bf16::from_f32(-128.)), Ok(i8::MIN));
+        assert_eq!(
+            i8::try_from(bf16::from_f32(-130.)).unwrap_err().kind(),
+            TryFromHalfErrorKind::OutOfRange
         );
-        roundtrip(
-            f64::from_bits(0b0_01101111011_1000000000000000000000000000000000000000000000000000),
-            Some(bf16(3)),
+        assert_eq!(
+            u8::try_from(bf16::from_f32(127.5)).unwrap_err().kind(),
+            TryFromHalfErrorKind::Fractional
         );
-        roundtrip(
-            f64::from_bits(0b0_01101111011_1100000000000000000000000000000000000000000000000000),
-            None,
+        assert_eq!(
+            u16::try_from(bf16::from_f32(65536.)).unwrap_err().kind(),
+            TryFromHalfErrorKind::OutOfRange
         );
-        // Due to being denormal, this is truncated out
-        roundtrip(
-            f64::from_bits(0b0_01101111010_0001000000000000000000000000000000000000000000000000),
-            None,
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_hypot() {
+        let hypot = |a: f32, b: f32| bf16::from_f32(a).hypot(bf16::from_f32(b));
+        assert_eq!(hypot(3., 4.), bf16::from_f32(5.));
+        assert_eq!(hypot(-3., 4.), bf16::from_f32(5.));
+        assert_eq!(hypot(0., -0.), bf16::ZERO);
+        assert_eq!(bf16::MAX.hypot(bf16::ZERO), bf16::MAX);
+        assert_eq!(bf16::MIN.hypot(bf16::MIN_POSITIVE_SUBNORMAL), bf16::MAX);
+        assert!(bf16::MAX.hypot(bf16::MAX).is_infinite());
+        assert!(bf16::NAN.hypot(bf16::ONE).is_nan());
+        assert_eq!(bf16::NAN.hypot(bf16::NEG_INFINITY), bf16::INFINITY);
+
+        // Subnormals, whose squares underflow to zero.
+        let tiny = bf16::MIN_POSITIVE_SUBNORMAL;
+        assert_eq!(tiny * tiny, bf16::ZERO);
+        assert_eq!(tiny.hypot(bf16::ZERO), tiny);
+        let three = bf16::from_f32(tiny.to_f32() * 3.);
+        let four = bf16::from_f32(tiny.to_f32() * 4.);
+        assert_eq!(three.hypot(four), bf16::from_f32(tiny.to_f32() * 5.));
+
+        // Sampled pairs match a single rounding of the exact result.
+        for bits in (0..=u16::MAX).step_by(7) {
+            let a = bf16::from_bits(bits);
+            let b = bf16::from_bits(bits.wrapping_mul(31));
+            let expected = bf16::from_f64(a.to_f64().hypot(b.to_f64()));
+            let actual = a.hypot(b);
+            assert!(
+                actual.to_bits() == expected.to_bits() || (actual.is_nan() && expected.is_nan())
+            );
+        }
+
+        // Values near the largest value, where the naive sum overflows.
+        let a = bf16::from_f32(3e38);
+        let b = bf16::from_f32(1e38);
+        assert!((a * a + b * b).is_infinite());
+        assert_eq!(a.hypot(b), bf16::from_f64(3e38f64.hypot(1e38)));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_from_f64_of_f32() {
+        use crate::HalfFloatSliceExt;
+
+        // Every f64 that is exactly an f32 converts the same as the f32, on
+        // every backend. The sweep covers every exponent, with every pattern
+        // of the mantissa bits from the lowest kept bit to the round bit, and
+        // sticky bits below them.
+        let mut narrow = [0f32; 4096];
+        let mut wide = [0f64; 4096];
+        let mut from_narrow = [bf16::ZERO; 4096];
+        let mut from_wide = [bf16::ZERO; 4096];
+        for sign in [0u32, 0x8000_0000] {
+            for exp in 0..=0xFFu32 {
+                for low in [0u32, 1, 0x400, 0x7FF] {
+                    for (high, (x, y)) in narrow.iter_mut().zip(wide.iter_mut()).enumerate() {
+                        *x = f32::from_bits(sign | (exp << 23) | ((high as u32) << 11) | low);
+                        *y = *x as f64;
+                    }
+                    for (&x, &wide) in narrow.iter().zip(wide.iter()) {
+                        if x.is_nan() {
+                            assert!(bf16::from_f64(wide).is_nan());
+                            assert!(bf16::from_f64_const(wide).is_nan());
+                            continue;
+                        }
+                        let expected = bf16::from_f32_const(x).to_bits();
+                        assert_eq!(bf16::from_f32(x).to_bits(), expected, "{:#010x}", x.to_bits());
+                        assert_eq!(
+                            bf16::from_f64(wide).to_bits(),
+                            expected,
+                            "{:#010x}",
+                            x.to_bits()
+                        );
+                        assert_eq!(
+                            bf16::from_f64_const(wide).to_bits(),
+                            expected,
+                            "{:#010x}",
+                            x.to_bits()
+                        );
+                    }
+                    if exp == 0xFF {
+                        continue;
+                    }
+                    from_narrow.convert_from_f32_slice(&narrow);
+                    from_wide.convert_from_f64_slice(&wide);
+                    assert_eq!(from_narrow.map(bf16::to_bits), from_wide.map(bf16::to_bits));
+                    for (&x, y) in narrow.iter().zip(from_narrow.iter()) {
+                        assert_eq!(bf16::from_f32_const(x).to_bits(), y.to_bits());
+                    }
+                }
+            }
+        }
+
+        // Regressions: a subnormal result, which was rounded at the wrong bit,
+        // and a tie broken by bits in the low half of the f64 mantissa.
+        let x = f32::from_bits(0x0020_8001);
+        assert_eq!(bf16::from_f64(x as f64).to_bits(), bf16::from_f32(x).to_bits());
+        assert_eq!(bf16::from_f64(x as f64).to_bits(), 0x0021);
+        let x = 1. + 2f64.powi(-8) + 2f64.powi(-40);
+        assert_eq!(bf16::from_f64(x).to_bits(), 0x3F81);
+        assert_eq!(bf16::from_f64(-x).to_bits(), 0xBF81);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_try_from_int() {
+        assert_eq!(bf16::try_from(256i32).ok(), Some(bf16::from_f32(256.)));
+        assert_eq!(bf16::try_from(-256i16).ok(), Some(bf16::from_f32(-256.)));
+        assert!(bf16::try_from(257u16).is_err());
+        assert!(bf16::try_from(-257i64).is_err());
+        assert_eq!(bf16::try_from(258u32).ok(), Some(bf16::from_f32(258.)));
+        assert!(bf16::try_from(511i32).is_err());
+        assert_eq!(bf16::try_from(512u64).ok(), Some(bf16::from_f32(512.)));
+        assert!(bf16::try_from(513u64).is_err());
+        assert_eq!(bf16::try_from(0i32).map(bf16::to_bits).ok(), Some(0));
+        assert_eq!(bf16::try_from(0u64).map(bf16::to_bits).ok(), Some(0));
+
+        // Large powers of two, and multiples of them, are exact.
+        assert_eq!(bf16::try_from(1u64 << 63).ok(), Some(bf16::from_f64(2f64.powi(63))));
+        assert_eq!(bf16::try_from(i64::MIN).ok(), Some(bf16::from_f64(-2f64.powi(63))));
+        assert_eq!(
+            bf16::try_from(0xFF00_0000_0000_0000u64).ok(),
+            Some(bf16::from_f64(255. * 2f64.powi(56)))
         );
-        roundtrip(
-            f64::from_bits(0b0_01101111010_1000000000000000000000000000000000000000000000000000),
-            None,
+        assert_eq!(bf16::try_from(1i32 << 30).ok(), Some(bf16::from_f64(2f64.powi(30))));
+        assert_eq!(bf16::try_from(-3i32 << 29).ok(), Some(bf16::from_f64(-3. * 2f64.powi(29))));
+        assert!(bf16::try_from(u64::MAX).is_err());
+        assert!(bf16::try_from(i64::MAX).is_err());
+        assert!(bf16::try_from(0x1FF0_0000_0000_0000u64).is_err());
+        assert!(bf16::try_from(u32::MAX).is_err());
+        assert!(bf16::try_from(i16::MIN + 1).is_err());
+        assert_eq!(bf16::try_from(i16::MIN).ok(), Some(bf16::from_f32(-32768.)));
+
+        // The exact integers are the integral values of bf16.
+        for x in -70000i32..=70000 {
+            let rounded = bf16::from_f32(x as f32);
+            let expected = (rounded.to_f32() == x as f32).then(|| rounded);
+            assert_eq!(bf16::from_i64_exact(x as i64), expected, "{}", x);
+            assert_eq!(bf16::try_from(x).ok(), expected);
+            if let Ok(x) = u32::try_from(x) {
+                assert_eq!(bf16::try_from(x).ok(), expected);
+            }
+        }
+        for bits in 0..=0x7F80u16 {
+            let value = bf16::from_bits(bits);
+            if value.to_f64() >= 1. && value.to_f64() < 2f64.powi(63) {
+                let x = value.to_f64() as i64;
+                if value.to_f64().fract() == 0. {
+                    assert_eq!(bf16::from_i64_exact(x), Some(value));
+                    assert_eq!(bf16::from_i64_exact(-x), Some(-value));
+                    assert_eq!(bf16::try_from(x as u64).ok(), Some(value));
+                }
+            }
+        }
+        const EXACT: Option<bf16> = bf16::from_i64_exact(-1 << 40);
+        assert_eq!(EXACT, Some(bf16::from_f64(-2f64.powi(40))));
+    }
+
+    #[test]
+    fn test_from_int_lossless() {
+        // Integers with few significant bits, shifted over the whole range of
+        // each type, against a conversion of the exact f64 value.
+        let expected = |x: f64| {
+            let value = bf16::from_f64(x);
+            (value.is_finite() && value.to_f64() == x).then(|| value)
+        };
+        for base in [1u128, 3, 5, 127, 255, 257, 511, 1023, 2047, 2049, 4095] {
+            for shift in 0..128 {
+                let mag = match base.checked_shl(shift) {
+                    Some(mag) if mag >> shift == base => mag,
+                    _ => continue,
+                };
+                let x = mag as f64;
+                assert_eq!(bf16::from_u128_lossless(mag), expected(x), "{}", mag);
+                if let Ok(mag) = u64::try_from(mag) {
+                    assert_eq!(bf16::from_u64_lossless(mag), expected(x), "{}", mag);
+                }
+                if let Ok(mag) = u32::try_from(mag) {
+                    assert_eq!(bf16::from_u32_lossless(mag), expected(x), "{}", mag);
+                }
+                if let Ok(mag) = u16::try_from(mag) {
+                    assert_eq!(bf16::from_u16_lossless(mag), expected(x), "{}", mag);
+                }
+                if let Ok(mag) = u8::try_from(mag) {
+                    assert_eq!(bf16::from_u8_lossless(mag), expected(x), "{}", mag);
+                }
+                if let Ok(value) = i128::try_from(mag) {
+                    assert_eq!(bf16::from_i128_lossless(value), expected(x), "{}", value);
+                    assert_eq!(bf16::from_i128_lossless(-value), expected(-x), "{}", value);
+                }
+                if let Ok(value) = i64::try_from(mag) {
+                    assert_eq!(bf16::from_i64_lossless(value), expected(x), "{}", value);
+                    assert_eq!(bf16::from_i64_lossless(-value), expected(-x), "{}", value);
+                }
+                if let Ok(value) = i32::try_from(mag) {
+                    assert_eq!(bf16::from_i32_lossless(value), expected(x), "{}", value);
+                    assert_eq!(bf16::from_i32_lossless(-value), expected(-x), "{}", value);
+                }
+                if let Ok(value) = i16::try_from(mag) {
+                    assert_eq!(bf16::from_i16_lossless(value), expected(x), "{}", value);
+                    assert_eq!(bf16::from_i16_lossless(-value), expected(-x), "{}", value);
+                }
+                if let Ok(value) = i8::try_from(mag) {
+                    assert_eq!(bf16::from_i8_lossless(value), expected(x), "{}", value);
+                    assert_eq!(bf16::from_i8_lossless(-value), expected(-x), "{}", value);
+                }
+            }
+        }
+
+        // The extremes of each type, and zero, which is always +0.
+        assert_eq!(bf16::from_i8_lossless(i8::MIN), Some(bf16::from_f32(-128.)));
+        assert_eq!(bf16::from_i8_lossless(i8::MAX), Some(bf16::from_f32(127.)));
+        assert_eq!(bf16::from_u8_lossless(u8::MAX), Some(bf16::from_f32(255.)));
+        assert_eq!(bf16::from_u16_lossless(u16::MAX), None);
+        assert_eq!(bf16::from_i32_lossless(i32::MAX), None);
+        assert_eq!(bf16::from_u128_lossless(u128::MAX), None);
+        assert_eq!(bf16::from_i64_lossless(0).map(bf16::to_bits), Some(0));
+        assert_eq!(bf16::from_i128_lossless(0).map(bf16::to_bits), Some(0));
+        assert_eq!(bf16::from_u32_lossless(0).map(bf16::to_bits), Some(0));
+        assert_eq!(bf16::from_i128_lossless(i128::MIN), Some(bf16::from_f64(-(2f64.powi(127)))));
+        assert_eq!(bf16::from_u128_lossless(0xFF << 120), Some(bf16::MAX));
+
+        const VALUE: Option<bf16> = bf16::from_u64_lossless(258);
+        assert_eq!(VALUE, Some(bf16::from_f32(258.)));
+    }
+
+    #[test]
+    fn test_consts_rounding() {
+        use core::f64::consts;
+
+        // The same check as the compile-time one, on the runtime paths, and
+        // through an f32, where the first rounding is never a midpoint.
+        let pairs = [
+            (bf16::E, consts::E),
+            (bf16::PI, consts::PI),
+            (bf16::FRAC_1_PI, consts::FRAC_1_PI),
+            (bf16::FRAC_1_SQRT_2, consts::FRAC_1_SQRT_2),
+            (bf16::FRAC_2_PI, consts::FRAC_2_PI),
+            (bf16::FRAC_2_SQRT_PI, consts::FRAC_2_SQRT_PI),
+            (bf16::FRAC_PI_2, consts::FRAC_PI_2),
+            (bf16::FRAC_PI_3, consts::FRAC_PI_3),
+            (bf16::FRAC_PI_4, consts::FRAC_PI_4),
+            (bf16::FRAC_PI_6, consts::FRAC_PI_6),
+            (bf16::FRAC_PI_8, consts::FRAC_PI_8),
+            (bf16::LN_10, consts::LN_10),
+            (bf16::LN_2, consts::LN_2),
+            (bf16::LOG10_E, consts::LOG10_E),
+            (bf16::LOG10_2, consts::LOG10_2),
+            (bf16::LOG2_E, consts::LOG2_E),
+            (bf16::LOG2_10, consts::LOG2_10),
+            (bf16::SQRT_2, consts::SQRT_2),
+        ];
+        for (constant, x) in pairs {
+            assert_eq!(bf16::from_f64(x).to_bits(), constant.to_bits(), "{}", x);
+            assert_eq!(bf16::from_f64_const(x).to_bits(), constant.to_bits(), "{}", x);
+            assert_eq!(bf16::from_f32(x as f32).to_bits(), constant.to_bits(), "{}", x);
+            // The value is within half a step of the exact constant.
+            let next = bf16::from_bits(constant.to_bits() + 1).to_f64();
+            let prev = bf16::from_bits(constant.to_bits() - 1).to_f64();
+            let value = constant.to_f64();
+            assert!((value - x).abs() < (next - value) / 2., "{}", x);
+            assert!((value - x).abs() < (value - prev) / 2., "{}", x);
+        }
+    }
+
+    #[test]
+    fn test_from_bool() {
+        assert_eq!(bf16::from_bool(true).to_bits(), bf16::ONE.to_bits());
+        assert_eq!(bf16::from_bool(false).to_bits(), bf16::ZERO.to_bits());
+        for value in [false, true] {
+            assert_eq!(bf16::from(value).to_bits(), bf16::from(value as u8).to_bits());
+            assert_eq!(bf16::from(value).to_f32(), f32::from(value as u8));
+        }
+    }
+
+    #[test]
+    fn test_checked_arith() {
+        let f = bf16::from_f32;
+        let values = [
+            bf16::ZERO,
+            bf16::NEG_ZERO,
+            bf16::MIN_POSITIVE_SUBNORMAL,
+            bf16::MIN_POSITIVE,
+            f(-0.75),
+            bf16::ONE,
+            f(3.5),
+            f(-100.0),
+            bf16::MAX,
+            bf16::MIN,
+            bf16::INFINITY,
+            bf16::NEG_INFINITY,
+            bf16::NAN,
+            -bf16::NAN,
+        ];
+        type Op = (fn(bf16, bf16) -> Option<bf16>, fn(bf16, bf16) -> bf16);
+        let ops: [Op; 4] = [
+            (bf16::checked_add, |x, y| x + y),
+            (bf16::checked_sub, |x, y| x - y),
+            (bf16::checked_mul, |x, y| x * y),
+            (bf16::checked_div, |x, y| x / y),
+        ];
+        // Only finite results are returned, and they match the operators.
+        for (checked, op) in ops {
+            for &x in &values {
+                for &y in &values {
+                    let expected = op(x, y);
+                    match checked(x, y) {
+                        Some(value) => assert_eq!(value.to_bits(), expected.to_bits()),
+                        None => assert!(!expected.is_finite(), "{:?} {:?}", x, y),
+                    }
+                    if x.is_nan() || y.is_nan() || x.is_infinite() {
+                        assert_eq!(checked(x, y), None, "{:?} {:?}", x, y);
+                    }
+                }
+            }
+        }
+        assert_eq!(bf16::MAX.checked_add(bf16::MAX), None);
+        assert_eq!(bf16::MIN.checked_mul(f(2.)), None);
+        assert_eq!(bf16::ONE.checked_div(bf16::NEG_ZERO), None);
+        assert_eq!(
+            f(-3.).checked_div(bf16::INFINITY).map(bf16::to_bits),
+            Some(bf16::NEG_ZERO.to_bits())
         );
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_to_f16() {
+        // Converting to `f32` is exact, so converting through it rounds
+        // once, and the results are identical, including NaN payloads.
+        for bits in 0..=u16::MAX {
+            let value = bf16::from_bits(bits);
+            let wide = value.to_f32();
+            if !value.is_nan() {
+                assert_eq!(bf16::from_f32(wide).to_bits(), bits);
+            }
+            let expected = f16::from_f32(wide);
+            assert_eq!(value.to_f16().to_bits(), expected.to_bits(), "{:#06x}", bits);
+            assert_eq!(value.to_f16().to_bits(), f16::from_bf16_lossy(value).to_bits());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_try_from_f16() {
+        // Converting to `f32` is exact, so a value is lossless if it is the
+        // same after rounding from `f32`.
+        let mut count = 0;
+        for bits in 0..=u16::MAX {
+            let value = f16::from_bits(bits);
+            let result = bf16::try_from(value).ok();
+            assert_eq!(
+                result.map(bf16::to_bits),
+                bf16::from_f16_lossless(value).map(bf16::to_bits)
+            );
+            if value.is_nan() {
+                assert!(result.unwrap().is_nan(), "{:#06x}", bits);
+            } else if bf16::from_f32(value.to_f32()).to_f32() == value.to_f32() {
+                assert_eq!(
+                    result.unwrap().to_f32().to_bits(),
+                    value.to_f32().to_bits(),
+                    "{:#06x}",
+                    bits
+                );
+                count += 1;
+            } else {
+                assert!(result.is_none(), "{:#06x}", bits);
+            }
+        }
+        // The 30 normal exponents with the 3 lowest mantissa bits zero, ±0 and
+        // the subnormals with at most 8 significant bits, and ±∞.
+        assert_eq!(count, 2 * (30 * 128 + (1 + 255 + 128 + 128) + 1));
+        assert!(bf16::try_from(f16::from_f32(1. + 1. / 128.)).is_ok());
+        assert!(bf16::try_from(f16::from_f32(1. + 1. / 256.)).is_err());
+        assert!(bf16::try_from(f16::MAX).is_err());
+    }
+
+    #[test]
+    fn test_saturating_from() {
+        // Values just above MAX, and far above it.
+        let max = bf16::MAX.to_f32();
+        let above =
+            [f32::from_bits(max.to_bits() + 1), max * (1. + bf16::EPSILON.to_f32() / 2.), f32::MAX];
+        for x in above.iter().copied().chain([f32::INFINITY]) {
+            assert_eq!(bf16::saturating_from_f32(x), bf16::MAX, "{:e}", x);
+            assert_eq!(bf16::saturating_from_f32(-x), bf16::MIN, "{:e}", x);
+            assert_eq!(bf16::saturating_from_f64(x as f64), bf16::MAX, "{:e}", x);
+            assert_eq!(bf16::saturating_from_f64(-x as f64), bf16::MIN, "{:e}", x);
+        }
+        assert_eq!(bf16::saturating_from_f64(f64::MAX), bf16::MAX);
+        assert_eq!(bf16::saturating_from_f64(f64::NEG_INFINITY), bf16::MIN);
+        assert!(bf16::saturating_from_f32(f32::NAN).is_nan());
+        assert!(bf16::saturating_from_f64(-f64::NAN).is_nan());
+
+        // Only infinities differ from the existing saturating conversions.
+        assert_eq!(bf16::from_f32_saturating(f32::INFINITY), bf16::INFINITY);
+        for &x in &above {
+            assert_eq!(bf16::saturating_from_f32(x), bf16::from_f32_saturating(x));
+        }
+
+        // Every other value is unchanged.
+        for bits in (0..=u16::MAX).step_by(7) {
+            let value = bf16::from_bits(bits);
+            if value.is_finite() {
+                assert_eq!(bf16::saturating_from_f32(value.to_f32()).to_bits(), bits);
+                assert_eq!(bf16::saturating_from_f64(value.to_f64()).to_bits(), bits);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_exponent_mantissa() {
+        for bits in 0..=u16::MAX {
+            let value = bf16::from_bits(bits);
+            let (exp, man) = (value.biased_exponent(), value.mantissa());
+            assert_eq!(bits & bf16::SIGN_MASK | exp << 7 | man, bits);
+            assert!(exp < 256 && man < 128);
+            if value.is_finite() {
+                // The fields decode to the value, with the leading bit only
+                // for normal values.
+                let leading = if value.is_normal() {
+                    128
+                } else {
+                    0
+                };
+                let scale = f64::powi(2., (value.unbiased_exponent() - 7) as i32);
+                let magnitude = (leading + man) as f64 * scale;
+                assert_eq!(value.to_f64().abs(), magnitude, "{:#06x}", bits);
+            } else {
+                assert_eq!(value.unbiased_exponent(), 128);
+            }
+            assert_eq!(value.unbiased_exponent(), exp.max(1) as i16 - 127);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_from_json_number() {
+        // Every value is exact, and the next `f64` above it is not.
+        for bits in 0..=u16::MAX {
+            let value = bf16::from_bits(bits);
+            let n = value.to_f64();
+            let (result, exact) = bf16::from_json_number(n);
+            assert!(exact, "{:#06x}", bits);
+            assert!(result.to_bits() == bits || value.is_nan(), "{:#06x}", bits);
+            if value.is_finite() {
+                let next = f64::from_bits(n.to_bits() + 1);
+                assert_eq!(bf16::from_json_number(next), (bf16::from_f64(next), false));
+            }
+        }
+
+        let cases = [
+            (3.3895313892515355e38, 0x7F7F, true),
+            (3.4e38, 0x7F80, false),
+            (f64::powi(2., -126), 0x0080, true),
+            (f64::powi(2., -133), 0x0001, true),
+            (f64::powi(2., -134), 0x0000, false),
+            (1.5 * f64::powi(2., -133), 0x0002, false),
+            (1. + f64::powi(2., -7), 0x3F81, true),
+            (1. + f64::powi(2., -8), 0x3F80, false),
+            (-(1. + 3. * f64::powi(2., -8)), 0xBF82, false),
+            (-0., 0x8000, true),
+            (f64::NEG_INFINITY, 0xFF80, true),
+        ];
+        for (n, bits, exact) in cases {
+            let (value, flag) = bf16::from_json_number(n);
+            assert_eq!((value.to_bits(), flag), (bits, exact), "{:e}", n);
+        }
+        let (value, exact) = bf16::from_json_number(f64::NAN);
+        assert!(value.is_nan() && exact);
+    }
+
+    #[test]
+    fn test_scale() {
+        // Subnormal values scale up into the normal range, exactly.
+        assert_eq!(bf16::MIN_POSITIVE_SUBNORMAL.scale(7), bf16::MIN_POSITIVE);
+        assert_eq!(bf16::MIN_POSITIVE.scale(127), bf16::from_f32(2.));
+        assert_eq!(bf16::from_bits(0x0015).scale(3).to_bits(), 0x00A8);
+        // Normal values scale down into the subnormal range, rounding to even.
+        assert_eq!(bf16::MIN_POSITIVE.scale(-1).to_bits(), 0x0040);
+        assert_eq!(bf16::from_bits(0x0083).scale(-1).to_bits(), 0x0042);
+        assert_eq!(bf16::from_bits(0x0081).scale(-1).to_bits(), 0x0040);
+        assert_eq!(bf16::from_f32(-1.5).scale(-134).to_bits(), 0x8001);
+        assert_eq!(bf16::ONE.scale(-134).to_bits(), 0x0000);
+        // Overflow and the values returned unchanged.
+        assert_eq!(bf16::MIN.scale(1), bf16::NEG_INFINITY);
+        assert_eq!(bf16::MIN_POSITIVE_SUBNORMAL.scale(i32::MAX), bf16::INFINITY);
+        assert_eq!(bf16::MAX.scale(i32::MIN), bf16::ZERO);
+        assert_eq!(bf16::NEG_ZERO.scale(5).to_bits(), 0x8000);
+        assert_eq!(bf16::NAN.scale(-5).to_bits(), bf16::NAN.to_bits());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_scale_exhaustive() {
+        for bits in 0..=u16::MAX {
+            let x = bf16::from_bits(bits);
+            for n in (-290..=290).step_by(7).chain(-3..=3) {
+                let expected = bf16::from_f64(x.to_f64() * 2f64.powi(n));
+                let scaled = x.scale(n);
+                if x.is_nan() {
+                    assert_eq!(scaled.to_bits(), bits);
+                } else {
+                    assert_eq!(scaled.to_bits(), expected.to_bits(), "{:#06x} {}", bits, n);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_canonicalize() {
+        for bits in 0..=u16::MAX {
+            let x = bf16::from_bits(bits);
+            let canonical = x.canonicalize();
+            let zero = x.canonicalize_zero();
+            if x.is_nan() {
+                assert_eq!(canonical.to_bits(), bf16::NAN.to_bits());
+                assert_eq!(zero.to_bits(), bf16::NAN.to_bits());
+            } else {
+                assert_eq!(canonical.to_bits(), bits);
+                let expected = if bits == 0x8000 {
+                    0
+                } else {
+                    bits
+                };
+                assert_eq!(zero.to_bits(), expected);
+            }
+            // Both are idempotent.
+            assert_eq!(canonical.canonicalize().to_bits(), canonical.to_bits());
+            assert_eq!(zero.canonicalize_zero().to_bits(), zero.to_bits());
+            assert_eq!(canonical.canonicalize_zero().to_bits(), zero.to_bits());
+        }
+    }
+
+    #[test]
+    fn test_signaling_nan() {
+        let snan = bf16::SIGNALING_NAN;
+        assert!(snan.is_nan());
+        assert!(snan.is_nan_signaling() && !snan.is_nan_quiet());
+        assert!(bf16::NAN.is_nan_quiet() && !bf16::NAN.is_nan_signaling());
+        assert_eq!(bf16::from_bits(snan.to_bits()).to_bits(), 0x7FA0);
+        assert_eq!(snan.quiet().to_bits(), 0x7FE0);
+        assert_eq!((-snan).quiet().to_bits(), 0xFFE0);
+
+        for bits in 0..=u16::MAX {
+            let value = bf16::from_bits(bits);
+            assert_eq!(value.is_nan(), value.is_nan_signaling() || value.is_nan_quiet());
+            assert!(!(value.is_nan_signaling() && value.is_nan_quiet()));
+            let quiet = value.quiet();
+            if value.is_nan() {
+                assert!(quiet.is_nan_quiet(), "{:#06x}", bits);
+                assert_eq!(quiet.to_bits() & !0x0040, bits & !0x0040);
+                // Conversions quiet the NaN, keeping its sign and payload,
+                // unless all NaN results are canonical.
+                let converted = if cfg!(feature = "canonical-nan") {
+                    bf16::NAN
+                } else {
+                    quiet
+                };
+                let wide = value.to_f32().to_bits();
+                assert_eq!(wide, (converted.to_bits() as u32) << 16);
+                assert_eq!(bf16::from_f32(f32::from_bits(wide)).to_bits(), converted.to_bits());
+                let wide = f32::from_bits((bits as u32) << 16);
+                assert_eq!(bf16::from_f32(wide).to_bits(), converted.to_bits());
+                assert_eq!(bf16::from_f64(value.to_f64()).to_bits(), converted.to_bits());
+            } else {
+                assert_eq!(quiet.to_bits(), bits);
+            }
+        }
+
+        // Signaling NaNs with the payload only in the dropped bits are quiet
+        // NaNs, not infinities.
+        for &bits in &[0x7F80_0001u32, 0xFF80_0001, 0x7FA0_0000, 0x7F80_FFFF] {
+            let value = bf16::from_f32(f32::from_bits(bits));
+            assert!(value.is_nan_quiet(), "{:#010x}", bits);
+            assert_eq!(
+                value.is_sign_negative(),
+                bits >> 31 == 1 && !cfg!(feature = "canonical-nan")
+            );
+            assert_eq!(bf16::from_f32_truncate(f32::from_bits(bits)).to_bits(), value.to_bits());
+        }
+    }
+
     #[test]
     fn test_max() {
         let a = bf16::from_f32(0.0);
@@ -2027,4 +6770,28 @@ mod test {
         let b = bf16::NAN;
         assert!(a.min(b).is_nan());
     }
+
+    #[test]
+    fn test_min3_max3() {
+        const MIN: bf16 = bf16::min3(bf16::ONE, bf16::NEG_ONE, bf16::ZERO);
+        const MAX: bf16 = bf16::max3(bf16::ONE, bf16::NEG_ONE, bf16::ZERO);
+        assert_eq!(MIN, bf16::NEG_ONE);
+        assert_eq!(MAX, bf16::ONE);
+
+        let values =
+            [bf16::NEG_INFINITY, bf16::from_f32(-2.), bf16::ZERO, bf16::from_f32(42.), bf16::NAN];
+        for &a in &values {
+            for &b in &values {
+                for &c in &values {
+                    let min = bf16::min3(a, b, c).to_f32();
+                    let max = bf16::max3(a, b, c).to_f32();
+                    // The same as for `f32`, which also ignores NaN.
+                    let expected = a.to_f32().min(b.to_f32()).min(c.to_f32());
+                    assert!(min == expected || (min.is_nan() && expected.is_nan()));
+                    let expected = a.to_f32().max(b.to_f32()).max(c.to_f32());
+                    assert!(max == expected || (max.is_nan() && expected.is_nan()));
+                }
+            }
+        }
+    }
 }
@@ -1,6 +1,21 @@
 use core::mem;
 
 use crate::leading_zeros::leading_zeros_u16;
+use crate::minifloat;
+use crate::rounding::RoundingMode;
+
+/// Replaces a NaN result with the canonical [`struct@bf16`] NaN, `0x7FC0`,
+/// with the `canonical-nan` feature. Otherwise, the bits are unchanged.
+///
+/// [`struct@bf16`]: crate::bf16
+#[inline(always)]
+pub(crate) const fn canonical_nan(bits: u16) -> u16 {
+    if cfg!(feature = "canonical-nan") && bits & 0x7FFF > 0x7F80 {
+        0x7FC0
+    } else {
+        bits
+    }
+}
 
 #[inline]
 pub(crate) const fn f32_to_bf16(value: f32) -> u16 {
@@ -25,71 +40,67 @@ pub(crate) const fn f32_to_bf16(value: f32) -> u16 {
 }
 
 #[inline]
-pub(crate) const fn f64_to_bf16(value: f64) -> u16 {
+pub(crate) const fn f32_to_bf16_round(value: f32, mode: RoundingMode) -> u16 {
     // TODO: Replace mem::transmute with to_bits() once to_bits is const-stabilized
-    // Convert to raw bytes, truncating the last 32-bits of mantissa; that precision
-    // will always be lost on half-precision.
-    let val: u64 = unsafe { mem::transmute::<f64, u64>(value) };
-    let x = (val >> 32) as u32;
+    let x: u32 = unsafe { mem::transmute::<f32, u32>(value) };
 
-    // Extract IEEE754 components
-    let sign = x & 0x8000_0000u32;
-    let exp = x & 0x7FF0_0000u32;
-    let man = x & 0x000F_FFFFu32;
-
-    // Check for all exponent bits being set, which is Infinity or NaN
-    if exp == 0x7FF0_0000u32 {
-        // Set mantissa MSB for NaN (and also keep shifted mantissa bits).
-        // We also have to check the last 32 bits.
-        let nan_bit = if man == 0 && (val as u32 == 0) {
-            0
-        } else {
-            0x0040u32
-        };
-        return ((sign >> 16) | 0x7F80u32 | nan_bit | (man >> 13)) as u16;
+    // Infinity and NaN keep their bits, with NaN quieted the same as f32_to_bf16
+    if x & 0x7F80_0000u32 == 0x7F80_0000u32 {
+        return f32_to_bf16(value);
     }
 
-    // The number is normalized, start assembling half precision version
-    let half_sign = sign >> 16;
-    // Unbias the exponent, then bias for bfloat16 precision
-    let unbiased_exp = ((exp >> 20) as i64) - 1023;
-    let half_exp = unbiased_exp + 127;
-
-    // Check for exponent overflow, return +infinity
-    if half_exp >= 0xFF {
-        return (half_sign | 0x7F80u32) as u16;
+    // bfloat16 has the same exponent range as f32, so this only drops the low
+    // mantissa bits. A carry out of the mantissa correctly increments the
+    // exponent, including from the largest finite value to infinity, and from
+    // the largest subnormal to the smallest normal.
+    let negative = x & 0x8000_0000u32 != 0;
+    let lsb = x & 0x0001_0000u32 != 0;
+    let round = x & 0x0000_8000u32 != 0;
+    let sticky = x & 0x0000_7FFFu32 != 0;
+    if mode.round_up(negative, lsb, round, sticky) {
+        (x >> 16) as u16 + 1
+    } else {
+        (x >> 16) as u16
     }
+}
 
-    // Check for underflow
-    if half_exp <= 0 {
-        // Check mantissa for what we can do
-        if 7 - half_exp > 21 {
-            // No rounding possibility, so this is a full underflow, return signed zero
-            return half_sign as u16;
-        }
-        // Don't forget about hidden leading mantissa bit when assembling mantissa
-        let man = man | 0x0010_0000u32;
-        let mut half_man = man >> (14 - half_exp);
-        // Check for rounding
-        let round_bit = 1 << (13 - half_exp);
-        if (man & round_bit) != 0 && (man & (3 * round_bit - 1)) != 0 {
-            half_man += 1;
-        }
-        // No exponent for subnormals
-        return (half_sign | half_man) as u16;
-    }
+/// Converts with stochastic rounding, adding `random` to the 16 dropped
+/// mantissa bits before truncating them.
+#[inline]
+pub(crate) const fn f32_to_bf16_stochastic(value: f32, random: u16) -> u16 {
+    // TODO: Replace mem::transmute with to_bits() once to_bits is const-stabilized
+    let x: u32 = unsafe { mem::transmute::<f32, u32>(value) };
 
-    // Rebias the exponent
-    let half_exp = (half_exp as u32) << 7;
-    let half_man = man >> 13;
-    // Check for rounding
-    let round_bit = 0x0000_1000u32;
-    if (man & round_bit) != 0 && (man & (3 * round_bit - 1)) != 0 {
-        // Round it
-        ((half_sign | half_exp | half_man) + 1) as u16
-    } else {
-        (half_sign | half_exp | half_man) as u16
+    // Infinity and NaN keep their bits, with NaN quieted the same as f32_to_bf16
+    if x & 0x7F80_0000u32 == 0x7F80_0000u32 {
+        return f32_to_bf16(value);
     }
+
+    // Adding to the magnitude carries into the exponent the same as rounding,
+    // including to infinity, and cannot reach the sign bit.
+    let sign = x & 0x8000_0000u32;
+    let magnitude = (x & 0x7FFF_FFFFu32) + random as u32;
+    ((sign | magnitude) >> 16) as u16
+}
+
+/// Converts a non-NaN [`f32`] without checking for NaN.
+#[inline]
+pub(crate) const fn f32_to_bf16_non_nan(value: f32) -> u16 {
+    // TODO: Replace mem::transmute with to_bits() once to_bits is const-stabilized
+    let x: u32 = unsafe { mem::transmute::<f32, u32>(value) };
+    // Round to nearest, ties to even: any carry out of the mantissa correctly
+    // increments the exponent, including to infinity.
+    ((x + 0x7FFFu32 + ((x >> 16) & 1)) >> 16) as u16
+}
+
+#[inline]
+pub(crate) const fn f64_to_bf16(value: f64) -> u16 {
+    // TODO: Replace mem::transmute with to_bits() once to_bits is const-stabilized
+    // Keep every bit of the mantissa so the value is only rounded once. The
+    // low bits are needed to break ties, and for values that are subnormal in
+    // bfloat16, to round at the right bit.
+    let x: u64 = unsafe { mem::transmute::<f64, u64>(value) };
+    minifloat::narrow(x, 11, 52, 8, 7) as u16
 }
 
 #[inline]
@@ -1,8 +1,25 @@
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, vec::Vec};
+#[cfg(feature = "total-order-impls")]
+use core::hash::{Hash, Hasher};
 use core::{
     cmp::Ordering,
     iter::{Product, Sum},
     num::FpCategory,
-    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+    ops::{
+        Add,
+        AddAssign,
+        Div,
+        DivAssign,
+        Mul,
+        MulAssign,
+        Neg,
+        RangeInclusive,
+        Rem,
+        RemAssign,
+        Sub,
+        SubAssign,
+    },
 };
 #[cfg(not(target_arch = "spirv"))]
 use core::{
@@ -22,7 +39,23 @@ use core::{
     str::FromStr,
 };
 
-use crate::error::TryFromFloatError;
+use crate::bf16;
+#[cfg(not(target_arch = "spirv"))]
+use crate::decimal;
+#[cfg(feature = "alloc")]
+use crate::error::OddLength;
+use crate::error::{ParseHalfError, TryFromFloatError, TryFromHalfError, TryFromHalfErrorKind};
+use crate::exact;
+use crate::fixed;
+use crate::hex;
+#[cfg(not(target_arch = "spirv"))]
+use crate::hex::HexFloat;
+use crate::leading_zeros::leading_zeros_u16;
+use crate::minifloat;
+use crate::rounding::RoundingMode;
+#[cfg(not(target_arch = "spirv"))]
+use crate::shortest;
+use crate::slice::HalfFloatSliceExt;
 use crate::try_from::try_from_lossless;
 
 pub(crate) mod arch;
@@ -33,6 +66,13 @@ pub(crate) mod arch;
 /// This 16-bit floating point type is intended for efficient storage where the
 /// full range and precision of a larger floating point value is not required.
 ///
+/// # Math functions
+///
+/// The math functions that need the `std` feature, such as `exp`, `ln` and
+/// `sin`, are computed in [`f32`] and then rounded to [`struct@f16`]. The
+/// [`f32`] result is already rounded, so in rare cases the result differs
+/// from the correctly rounded value by double rounding.
+///
 /// [`binary16`]: https://en.wikipedia.org/wiki/Half-precision_floating-point_format
 #[repr(C)]
 #[allow(non_camel_case_types)]
@@ -83,7 +123,77 @@ impl f16 {
     #[inline]
     #[must_use]
     pub const fn from_f32_const(value: f32) -> f16 {
-        f16(arch::f32_to_f16_fallback(value))
+        f16(arch::canonical_nan(arch::f32_to_f16_fallback(value)))
+    }
+
+    /// Constructs a 16-bit floating point value from a 32-bit floating point
+    /// value, rounding with the given [`RoundingMode`].
+    ///
+    /// This is identical to [`from_f32_const`][Self::from_f32_const] with
+    /// [`RoundingMode::NearestEven`]. The directed modes also apply to
+    /// overflow and underflow: values too large to fit only result in ±∞ if
+    /// the mode rounds away from zero, and would otherwise be the largest
+    /// finite value of the same sign, while values too small to fit result in
+    /// the smallest subnormal instead of ±0 when rounding away from zero. NaN
+    /// values are preserved.
+    ///
+    /// This never uses hardware intrinsics, which allows it to be `const`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let third = 1.0 / 3.0;
+    /// assert_eq!(f16::from_f32_round(third, RoundingMode::TowardZero).to_bits(), 0x3555);
+    /// assert_eq!(f16::from_f32_round(third, RoundingMode::TowardPositive).to_bits(), 0x3556);
+    ///
+    /// assert_eq!(f16::from_f32_round(1e6, RoundingMode::NearestEven), f16::INFINITY);
+    /// assert_eq!(f16::from_f32_round(1e6, RoundingMode::TowardZero), f16::MAX);
+    /// assert_eq!(f16::from_f32_round(1e-10, RoundingMode::TowardPositive), f16::from_bits(1));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_f32_round(value: f32, mode: RoundingMode) -> f16 {
+        f16(arch::canonical_nan(arch::f32_to_f16_round_fallback(value, mode)))
+    }
+
+    /// Constructs a 16-bit floating point value from a 32-bit floating point
+    /// value with stochastic rounding, using the caller's `random` bits.
+    ///
+    /// The value rounds away from zero with a probability proportional to the
+    /// distance from the value below it, so the expected result is the
+    /// original value. This adds `random` to the 16 bits just below the
+    /// rounding point, then truncates, so `random` should be uniformly
+    /// distributed, and lower bits of the value are ignored. The result is
+    /// exactly reproducible for the same `value` and `random`: `0` always
+    /// rounds toward zero, like [`RoundingMode::TowardZero`].
+    ///
+    /// Overflow and underflow work the same as in the normal path. Values of
+    /// magnitude at least `2^16` result in ±∞, values between
+    /// [`MAX`][Self::MAX] and `2^16` round stochastically between the two,
+    /// and values round to subnormals or ±0 stochastically. NaN values are
+    /// preserved.
+    ///
+    /// This never uses hardware intrinsics, which allows it to be `const`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// // 1 + 2^-12 is a quarter of the way from 1 to the next value, 1 + 2^-10.
+    /// let x = 1.0 + f32::powi(2.0, -12);
+    /// assert_eq!(f16::from_f32_stochastic(x, 0), f16::ONE);
+    /// assert_eq!(f16::from_f32_stochastic(x, 0xBFFF), f16::ONE);
+    /// assert_eq!(f16::from_f32_stochastic(x, 0xC000), f16::ONE + f16::EPSILON);
+    ///
+    /// // A quarter of all random values round up.
+    /// let up = (0..=u16::MAX).filter(|&r| f16::from_f32_stochastic(x, r) > f16::ONE).count();
+    /// assert_eq!(up, 0x4000);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_f32_stochastic(value: f32, random: u16) -> f16 {
+        f16(arch::canonical_nan(arch::f32_to_f16_stochastic(value, random)))
     }
 
     /// Constructs a 16-bit floating point value from a 32-bit floating point
@@ -98,7 +208,41 @@ impl f16 {
     #[inline]
     #[must_use]
     pub fn from_f32_instrinsic(value: f32) -> f16 {
-        f16(arch::f32_to_f16(value))
+        f16(arch::canonical_nan(arch::f32_to_f16(value)))
+    }
+
+    /// Constructs a 16-bit floating point value from a 32-bit floating point
+    /// value that rounds to a normal 16-bit value, skipping the handling of
+    /// NaN, infinity, overflow and underflow.
+    ///
+    /// This is rounded to the nearest representable 16-bit value, identically
+    /// to [`from_f32`][Self::from_f32], but avoids the branches needed for
+    /// special values, which makes it faster in hot loops where the inputs
+    /// are known to be in range.
+    ///
+    /// # Safety
+    ///
+    /// `value` must be finite with a magnitude of at least
+    /// [`f16::MIN_POSITIVE`][crate::f16::MIN_POSITIVE] and below `65520`, the
+    /// smallest magnitude that rounds to infinity. The result is
+    /// unspecified otherwise. This is checked with a debug assertion.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let value = unsafe { f16::from_f32_unchecked_normal(3.14159) };
+    /// assert_eq!(value, f16::from_f32(3.14159));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub unsafe fn from_f32_unchecked_normal(value: f32) -> f16 {
+        let abs = f32::from_bits(value.to_bits() & 0x7FFF_FFFF);
+        debug_assert!(
+            (6.103_515_6e-5..65520.).contains(&abs),
+            "value does not round to a normal f16"
+        );
+        f16(arch::f32_to_f16_normal(value))
     }
 
     /// Create a [`struct@f16`] loslessly from an [`f32`].
@@ -136,6 +280,157 @@ impl f16 {
         )
     }
 
+    /// Constructs a 16-bit floating point value from a 32-bit floating point
+    /// value, saturating to the finite range instead of overflowing to ±∞.
+    ///
+    /// This is identical to [`from_f32`][Self::from_f32], except finite
+    /// values that would round to ±∞ result in [`MAX`][Self::MAX] or
+    /// [`MIN`][Self::MIN] instead. Infinite values still result in ±∞, so
+    /// only values that were already infinite are infinite, and NaN values are
+    /// preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(f16::from_f32(65520.0), f16::INFINITY);
+    /// assert_eq!(f16::from_f32_saturating(65520.0), f16::MAX);
+    /// assert_eq!(f16::from_f32_saturating(-1e10), f16::MIN);
+    /// assert_eq!(f16::from_f32_saturating(f32::INFINITY), f16::INFINITY);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_f32_saturating(value: f32) -> f16 {
+        let result = Self::from_f32(value);
+        if result.is_infinite() && value.is_finite() {
+            if value.is_sign_negative() {
+                f16::MIN
+            } else {
+                f16::MAX
+            }
+        } else {
+            result
+        }
+    }
+
+    /// Constructs a 16-bit floating point value from a 32-bit floating point
+    /// value, clamping to the finite range.
+    ///
+    /// Values at or above [`MAX`][Self::MAX] result in [`MAX`][Self::MAX],
+    /// and values at or below [`MIN`][Self::MIN] result in
+    /// [`MIN`][Self::MIN], including ±∞, so the result is always finite
+    /// unless the value is NaN, which is preserved. Other values are the same
+    /// as [`from_f32`][Self::from_f32]. Unlike
+    /// [`from_f32_saturating`][Self::from_f32_saturating], this also clamps
+    /// infinite values, which suits pipelines such as HDR tone mapping, where
+    /// every later calculation should stay finite.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(f16::from_f32(65520.0), f16::INFINITY);
+    /// assert_eq!(f16::saturating_from_f32(65520.0), f16::MAX);
+    /// assert_eq!(f16::saturating_from_f32(f32::INFINITY), f16::MAX);
+    /// assert_eq!(f16::saturating_from_f32(f32::NEG_INFINITY), f16::MIN);
+    /// assert_eq!(f16::saturating_from_f32(1.5), f16::from_f32(1.5));
+    /// assert!(f16::saturating_from_f32(f32::NAN).is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn saturating_from_f32(value: f32) -> f16 {
+        Self::from_f32(value).clamp_infinite()
+    }
+
+    /// Constructs a 16-bit floating point value from a 64-bit floating point
+    /// value, clamping to the finite range.
+    ///
+    /// This is the same as [`saturating_from_f32`][Self::saturating_from_f32],
+    /// but rounds from the [`f64`] value once, like
+    /// [`from_f64`][Self::from_f64].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(f16::saturating_from_f64(1e5), f16::MAX);
+    /// assert_eq!(f16::saturating_from_f64(f64::NEG_INFINITY), f16::MIN);
+    /// assert_eq!(f16::saturating_from_f64(-0.25), f16::from_f64(-0.25));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn saturating_from_f64(value: f64) -> f16 {
+        Self::from_f64(value).clamp_infinite()
+    }
+
+    /// Converts a number read from JSON, which is an [`f64`], and returns if
+    /// the conversion was exact.
+    ///
+    /// The value is rounded once, as with [`from_f64`][Self::from_f64], and
+    /// the flag is `true` only if the number is exactly the returned value,
+    /// so numbers that are rounded, overflow to ±∞ or underflow to ±0 are
+    /// flagged. This is cheaper than converting the number twice, to round it
+    /// and to check it with [`from_f64_lossless`][Self::from_f64_lossless].
+    /// JSON has no NaN or infinite numbers, but they are converted exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let (value, exact) = f16::from_json_number(0.5);
+    /// assert_eq!((value, exact), (f16::from_f32(0.5), true));
+    ///
+    /// // 0.1 is rounded, and 1e5 overflows to infinity.
+    /// assert_eq!(f16::from_json_number(0.1), (f16::from_f64(0.1), false));
+    /// assert_eq!(f16::from_json_number(1e5), (f16::INFINITY, false));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_json_number(n: f64) -> (f16, bool) {
+        let value = Self::from_f64(n);
+        // Widening is exact, so this compares the number with the value.
+        (value, n.is_nan() || value.to_f64() == n)
+    }
+
+    /// Replaces ±∞ with [`MAX`][Self::MAX] or [`MIN`][Self::MIN].
+    #[inline]
+    const fn clamp_infinite(self) -> f16 {
+        if self.is_infinite() {
+            if self.is_sign_negative() {
+                Self::MIN
+            } else {
+                Self::MAX
+            }
+        } else {
+            self
+        }
+    }
+
+    /// Constructs a 16-bit floating point value from a 32-bit floating point
+    /// value, flushing subnormal inputs and results to zero.
+    ///
+    /// This matches conversions on hardware running with flush-to-zero (FTZ)
+    /// and denormals-are-zero (DAZ) enabled. [`f32`] subnormal inputs are
+    /// treated as zero, and results that would be [`struct@f16`] subnormals
+    /// after rounding are flushed to zero, keeping the sign. Values that round
+    /// up to [`MIN_POSITIVE`][Self::MIN_POSITIVE] are not flushed, and all
+    /// other results are identical to [`from_f32`][Self::from_f32].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert!(f16::from_f32(1e-5).is_subnormal());
+    /// assert_eq!(f16::from_f32_ftz(1e-5).to_bits(), f16::ZERO.to_bits());
+    /// assert_eq!(f16::from_f32_ftz(-1e-5).to_bits(), f16::NEG_ZERO.to_bits());
+    /// assert_eq!(f16::from_f32_ftz(1.5), f16::from_f32(1.5));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_f32_ftz(value: f32) -> f16 {
+        Self::from_f32(value).ftz()
+    }
+
     /// Constructs a 16-bit floating point value from a 64-bit floating point
     /// value.
     ///
@@ -148,6 +443,20 @@ impl f16 {
     ///
     /// This will prefer correctness over speed: on x86 systems, this currently
     /// uses a software rather than an instrinsic implementation on x86.
+    ///
+    /// The value is rounded exactly once from the full [`f64`]. For every
+    /// [`f64`] that is exactly an [`f32`] value, the result is identical to
+    /// [`from_f32`][Self::from_f32] of that [`f32`], on every backend, which
+    /// is tested for every exponent and every pattern of the rounded mantissa
+    /// bits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let x = 1.00048828125f32;
+    /// assert_eq!(f16::from_f64(x as f64), f16::from_f32(x));
+    /// ```
     #[inline]
     #[must_use]
     pub fn from_f64(value: f64) -> f16 {
@@ -176,7 +485,36 @@ impl f16 {
     #[inline]
     #[must_use]
     pub const fn from_f64_const(value: f64) -> f16 {
-        f16(arch::f64_to_f16_fallback(value))
+        f16(arch::canonical_nan(arch::f64_to_f16_fallback(value)))
+    }
+
+    /// Constructs a 16-bit floating point value from a 64-bit floating point
+    /// value, rounding with the given [`RoundingMode`].
+    ///
+    /// The value is rounded exactly once from the full [`f64`], on every
+    /// target, so it never has the double rounding of converting through an
+    /// [`f32`] first. With [`RoundingMode::NearestEven`], this is identical to
+    /// [`from_f64_const`][Self::from_f64_const]. The directed modes apply to
+    /// overflow and underflow the same as
+    /// [`from_f32_round`][Self::from_f32_round].
+    ///
+    /// This never uses hardware intrinsics, which allows it to be `const`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// // Just below half-way between 2047 and 2048, which is half-way once
+    /// // rounded to an f32.
+    /// let x = f64::from_bits(0x409F_FDFF_FFFF_0000);
+    /// assert_eq!(f16::from_f64_round(x, RoundingMode::NearestEven).to_f32(), 2047.0);
+    /// assert_eq!(f16::from_f64_round(x, RoundingMode::NearestAway).to_f32(), 2047.0);
+    /// assert_eq!(f16::from_f64_round(x, RoundingMode::TowardPositive).to_f32(), 2048.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_f64_round(value: f64, mode: RoundingMode) -> f16 {
+        f16(arch::canonical_nan(arch::f64_to_f16_round_fallback(value, mode)))
     }
 
     /// Constructs a 16-bit floating point value from a 64-bit floating point
@@ -195,7 +533,28 @@ impl f16 {
     #[inline]
     #[must_use]
     pub fn from_f64_instrinsic(value: f64) -> f16 {
-        f16(arch::f64_to_f16(value))
+        f16(arch::canonical_nan(arch::f64_to_f16(value)))
+    }
+
+    /// Returns if conversions of [`struct@f16`] values use hardware
+    /// instructions on the current CPU.
+    ///
+    /// This is the `f16c` feature on `x86` and `x86_64`, `fp16` on `aarch64`
+    /// and `lsx` on `loongarch64`. With the `std` feature, this is detected at
+    /// runtime, the same as the conversions themselves. Otherwise, it is only
+    /// `true` if the feature is enabled at compile time, such as with
+    /// `-C target-feature=+f16c`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// println!("running with hardware f16 support: {}", f16::hardware_f16_available());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn hardware_f16_available() -> bool {
+        arch::hardware_f16_available()
     }
 
     /// Create a [`struct@f16`] loslessly from an [`f64`].
@@ -233,1898 +592,7283 @@ impl f16 {
         )
     }
 
-    /// Converts a [`struct@f16`] into the underlying bit representation.
+    /// Constructs a [`struct@f16`] value from a [`struct@bf16`] value, rounding
+    /// to the nearest representable value.
+    ///
+    /// The mantissa is zero-extended from 7 to 10 bits, which is exact, but
+    /// the exponent range of [`struct@bf16`] is much larger, so this is only
+    /// lossless for values within the range of [`struct@f16`]. Values too
+    /// large for [`struct@f16`] result in ±∞, and values too small round to
+    /// subnormals or ±0, with ties to even. NaN values are preserved and quiet.
+    ///
+    /// This is identical to converting through [`f32`], but does not use an
+    /// intermediate [`f32`] value, which also allows it to be `const`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let x = bf16::from_f32(1.5);
+    /// assert_eq!(f16::from_bf16_lossy(x), f16::from_f32(1.5));
+    ///
+    /// assert_eq!(f16::from_bf16_lossy(bf16::MAX), f16::INFINITY);
+    /// assert_eq!(f16::from_bf16_lossy(bf16::MIN_POSITIVE), f16::ZERO);
+    /// ```
     #[inline]
     #[must_use]
-    pub const fn to_bits(self) -> u16 {
-        self.0
+    pub const fn from_bf16_lossy(value: bf16) -> f16 {
+        f16(arch::canonical_nan(minifloat::narrow(value.to_bits() as u64, 8, 7, 5, 10) as u16))
     }
 
-    /// Returns the memory representation of the underlying bit representation
-    /// as a byte array in little-endian byte order.
+    /// Create a [`struct@f16`] losslessly from a [`struct@bf16`].
+    ///
+    /// This is only true if the [`struct@bf16`] is non-finite (infinite or
+    /// NaN), or its exponent is within the range of [`struct@f16`],
+    /// including the subnormals, and no non-zero mantissa bits would be
+    /// truncated. The 7-bit mantissa always fits, so only values that are too
+    /// large, or too small for the precision of the subnormals, fail.
+    ///
+    /// This does not respect signalling NaNs: if the value is NaN, then it
+    /// will return a quiet NaN.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use float16::*;
-    /// let bytes = f16::from_f32(12.5).to_le_bytes();
-    /// assert_eq!(bytes, [0x40, 0x4A]);
+    /// let x = bf16::from_f32(1.5);
+    /// assert_eq!(f16::from_bf16_lossless(x), Some(f16::from_f32(1.5)));
+    ///
+    /// assert_eq!(f16::from_bf16_lossless(bf16::from_f32(65536.)), None);
+    /// assert_eq!(f16::from_bf16_lossless(bf16::MIN_POSITIVE), None);
+    /// assert_eq!(f16::from_bf16_lossless(bf16::INFINITY), Some(f16::INFINITY));
     /// ```
     #[inline]
-    #[must_use]
-    pub const fn to_le_bytes(self) -> [u8; 2] {
-        self.0.to_le_bytes()
+    pub const fn from_bf16_lossless(value: bf16) -> Option<f16> {
+        // Every result is exactly a `bf16` value, so it only converts back to
+        // the same value if nothing was rounded.
+        let result = Self::from_bf16_lossy(value);
+        if value.is_nan() || result.to_bf16().to_bits() == value.to_bits() {
+            Some(result)
+        } else {
+            None
+        }
     }
 
-    /// Returns the memory representation of the underlying bit representation
-    /// as a byte array in big-endian (network) byte order.
+    /// Constructs a 16-bit floating point value from a 64-bit floating point
+    /// value, saturating to the finite range instead of overflowing to ±∞.
+    ///
+    /// This is identical to [`from_f64`][Self::from_f64], except finite
+    /// values that would round to ±∞ result in [`MAX`][Self::MAX] or
+    /// [`MIN`][Self::MIN] instead. Infinite values still result in ±∞, so
+    /// only values that were already infinite are infinite, and NaN values are
+    /// preserved.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use float16::*;
-    /// let bytes = f16::from_f32(12.5).to_be_bytes();
-    /// assert_eq!(bytes, [0x4A, 0x40]);
+    /// assert_eq!(f16::from_f64(65520.0), f16::INFINITY);
+    /// assert_eq!(f16::from_f64_saturating(65520.0), f16::MAX);
+    /// assert_eq!(f16::from_f64_saturating(-1e10), f16::MIN);
+    /// assert_eq!(f16::from_f64_saturating(f64::INFINITY), f16::INFINITY);
     /// ```
     #[inline]
     #[must_use]
-    pub const fn to_be_bytes(self) -> [u8; 2] {
-        self.0.to_be_bytes()
+    pub fn from_f64_saturating(value: f64) -> f16 {
+        let result = Self::from_f64(value);
+        if result.is_infinite() && value.is_finite() {
+            if value.is_sign_negative() {
+                f16::MIN
+            } else {
+                f16::MAX
+            }
+        } else {
+            result
+        }
     }
 
-    /// Returns the memory representation of the underlying bit representation
-    /// as a byte array in native byte order.
+    /// Constructs a 16-bit floating point value from a binary fixed-point
+    /// integer.
     ///
-    /// As the target platform's native endianness is used, portable code should
-    /// use [`to_be_bytes`][Self::to_be_bytes] or
-    /// [`to_le_bytes`][Self::to_le_bytes], as appropriate, instead.
+    /// The result is `value / 2^fractional_bits`, so a Q12.4 value would use
+    /// `fractional_bits = 4`. This only uses integer arithmetic, so the value
+    /// is rounded exactly once, to the nearest representable value with ties
+    /// to even. Values too large to fit will result in ±∞.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use float16::*;
-    /// let bytes = f16::from_f32(12.5).to_ne_bytes();
-    /// assert_eq!(bytes, if cfg!(target_endian = "big") {
-    ///     [0x4A, 0x40]
-    /// } else {
-    ///     [0x40, 0x4A]
-    /// });
+    /// // Q12.4 fixed-point value of 12.5
+    /// assert_eq!(f16::from_fixed_point(200, 4), f16::from_f32(12.5));
+    /// assert_eq!(f16::from_fixed_point(-3, 1), f16::from_f32(-1.5));
     /// ```
     #[inline]
     #[must_use]
-    pub const fn to_ne_bytes(self) -> [u8; 2] {
-        self.0.to_ne_bytes()
+    pub const fn from_fixed_point(value: i64, fractional_bits: u32) -> f16 {
+        f16(fixed::fixed_to_half(value, fractional_bits, 5, 10))
     }
 
-    /// Creates a floating point value from its representation as a byte array
-    /// in little endian.
+    /// Converts a [`struct@f16`] value to a binary fixed-point integer.
+    ///
+    /// The result is `self * 2^fractional_bits`, rounded to the nearest
+    /// integer with ties to even. Returns `None` if the value is NaN,
+    /// infinite, or the result does not fit in an [`i64`].
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use float16::*;
-    /// let value = f16::from_le_bytes([0x40, 0x4A]);
-    /// assert_eq!(value, f16::from_f32(12.5));
+    /// assert_eq!(f16::from_f32(12.5).to_fixed_point(4), Some(200));
+    /// assert_eq!(f16::from_f32(-1.5).to_fixed_point(0), Some(-2));
+    /// assert_eq!(f16::INFINITY.to_fixed_point(4), None);
     /// ```
     #[inline]
     #[must_use]
-    pub const fn from_le_bytes(bytes: [u8; 2]) -> f16 {
-        f16::from_bits(u16::from_le_bytes(bytes))
+    pub const fn to_fixed_point(self, fractional_bits: u32) -> Option<i64> {
+        fixed::half_to_fixed(self.0, fractional_bits, 5, 10)
     }
 
-    /// Creates a floating point value from its representation as a byte array
-    /// in big endian.
+    /// Constructs a 16-bit floating point value from an [`i32`].
+    ///
+    /// The integer is rounded exactly once to the nearest representable value,
+    /// with ties to even, without converting through an [`f32`] first.
+    /// Integers up to `2048` in magnitude are exact. Integers of at least
+    /// `65520` in magnitude are too large to fit and result in ±∞.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use float16::*;
-    /// let value = f16::from_be_bytes([0x4A, 0x40]);
-    /// assert_eq!(value, f16::from_f32(12.5));
+    /// assert_eq!(f16::from_i32(-1000).to_f32(), -1000.0);
+    /// // 2049 is half-way between 2048 and 2050, and rounds to even.
+    /// assert_eq!(f16::from_i32(2049).to_f32(), 2048.0);
+    /// assert_eq!(f16::from_i32(65519), f16::MAX);
+    /// assert_eq!(f16::from_i32(i32::MAX), f16::INFINITY);
     /// ```
     #[inline]
     #[must_use]
-    pub const fn from_be_bytes(bytes: [u8; 2]) -> f16 {
-        f16::from_bits(u16::from_be_bytes(bytes))
+    pub const fn from_i32(value: i32) -> f16 {
+        f16(arch::i32_to_f16_round(value, RoundingMode::NearestEven))
     }
 
-    /// Creates a floating point value from its representation as a byte array
-    /// in native endian.
+    /// Constructs a 16-bit floating point value from an [`i32`], rounding
+    /// with the given [`RoundingMode`].
     ///
-    /// As the target platform's native endianness is used, portable code likely
-    /// wants to use [`from_be_bytes`][Self::from_be_bytes] or
-    /// [`from_le_bytes`][Self::from_le_bytes], as appropriate instead.
+    /// The integer is rounded exactly once, without converting through an
+    /// [`f32`] first. With [`RoundingMode::NearestEven`], this is identical to
+    /// [`from_i32`][Self::from_i32]. The directed modes apply to overflow the
+    /// same as [`from_f32_round`][Self::from_f32_round], so integers too large
+    /// to fit only result in ±∞ if the mode rounds away from zero.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use float16::*;
-    /// let value = f16::from_ne_bytes(if cfg!(target_endian = "big") {
-    ///     [0x4A, 0x40]
-    /// } else {
-    ///     [0x40, 0x4A]
-    /// });
-    /// assert_eq!(value, f16::from_f32(12.5));
+    /// assert_eq!(f16::from_i32_with_mode(2049, RoundingMode::NearestAway).to_f32(), 2050.0);
+    /// assert_eq!(f16::from_i32_with_mode(-2049, RoundingMode::TowardZero).to_f32(), -2048.0);
+    /// assert_eq!(f16::from_i32_with_mode(-2049, RoundingMode::TowardNegative).to_f32(), -2050.0);
+    /// assert_eq!(f16::from_i32_with_mode(i32::MAX, RoundingMode::TowardZero), f16::MAX);
     /// ```
     #[inline]
     #[must_use]
-    pub const fn from_ne_bytes(bytes: [u8; 2]) -> f16 {
-        f16::from_bits(u16::from_ne_bytes(bytes))
+    pub const fn from_i32_with_mode(value: i32, mode: RoundingMode) -> f16 {
+        f16(arch::i32_to_f16_round(value, mode))
     }
 
-    /// Converts a [`struct@f16`] value into a `f32` value.
+    /// Constructs a 16-bit floating point value from an [`i32`], if it is
+    /// exactly representable.
     ///
-    /// This conversion is lossless as all 16-bit floating point values can be
-    /// represented exactly in 32-bit floating point.
+    /// Every integer up to `2048` in magnitude is exact. Above that, only
+    /// multiples of the spacing between values are, such as even integers up
+    /// to `4096`, and integers of at least `65520` in magnitude are too large.
+    /// Returns `None` if the integer would be rounded.
     ///
-    /// This will prefer correctness over speed. Currently, this always
-    /// uses an intrinsic if available.
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(f16::from_i32_exact(-2048), Some(f16::from_f32(-2048.0)));
+    /// assert_eq!(f16::from_i32_exact(2049), None);
+    /// assert_eq!(f16::from_i32_exact(2050), Some(f16::from_f32(2050.0)));
+    /// assert_eq!(f16::from_i32_exact(65504), Some(f16::MAX));
+    /// assert_eq!(f16::from_i32_exact(65536), None);
+    ///
+    /// const THOUSAND: Option<f16> = f16::from_i32_exact(1000);
+    /// assert_eq!(THOUSAND, Some(f16::from_f32(1000.0)));
+    /// ```
     #[inline]
     #[must_use]
-    pub fn to_f32(self) -> f32 {
-        self.to_f32_intrinsic()
+    pub const fn from_i32_exact(value: i32) -> Option<f16> {
+        Self::from_i32_lossless(value)
     }
 
-    /// Converts a [`struct@f16`] value into a `f32` value.
+    /// Converts a [`bool`] to [`f16::ONE`][Self::ONE] if it is `true`, or
+    /// [`f16::ZERO`][Self::ZERO] if it is `false`.
     ///
-    /// This function is identical to [`to_f32`][Self::to_f32] except it never
-    /// uses hardware intrinsics, which allows it to be `const`.
-    /// [`to_f32`][Self::to_f32] should be preferred in any non-`const`
-    /// context.
+    /// This is the same as converting `value as u8`, and is also available
+    /// as the [`From<bool>`] implementation.
     ///
-    /// This conversion is lossless as all 16-bit floating point values can be
-    /// represented exactly in 32-bit floating point.
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(f16::from_bool(true), f16::ONE);
+    /// assert_eq!(f16::from(false).to_bits(), f16::ZERO.to_bits());
+    ///
+    /// const MASK: [f16; 2] = [f16::from_bool(false), f16::from_bool(true)];
+    /// assert_eq!(MASK, [f16::ZERO, f16::ONE]);
+    /// ```
     #[inline]
     #[must_use]
-    pub const fn to_f32_const(self) -> f32 {
-        arch::f16_to_f32_fallback(self.0)
-    }
-
-    /// Converts a [`struct@f16`] value into a `f32` value.
-    ///
-    /// This conversion is lossless as all 16-bit floating point values can be
-    /// represented exactly in 32-bit floating point.
-    #[inline]
-    #[must_use]
-    pub fn to_f32_intrinsic(self) -> f32 {
-        arch::f16_to_f32(self.0)
-    }
-
-    /// Convert the data to an `f32` type, used for numerical operations.
-    #[inline(always)]
-    pub fn as_f32(self) -> f32 {
-        self.to_f32_const()
-    }
-
-    /// Convert the data to an `f32` type, used for numerical operations.
-    #[inline(always)]
-    pub const fn as_f32_const(self) -> f32 {
-        self.to_f32_const()
+    pub const fn from_bool(value: bool) -> f16 {
+        if value {
+            Self::ONE
+        } else {
+            Self::ZERO
+        }
     }
 
-    /// Converts a [`struct@f16`] value into a `f64` value.
+    /// Converts a [`struct@f16`] value to an [`i32`], truncating toward
+    /// zero.
     ///
-    /// This conversion is lossless as all 16-bit floating point values can be
-    /// represented exactly in 64-bit floating point.
+    /// Returns `None` if the value is NaN or infinite. Every finite
+    /// [`struct@f16`] value fits in an [`i32`].
     ///
-    /// This will prefer correctness over speed: on x86 systems, this currently
-    /// uses a software rather than an instrinsic implementation on x86.
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(f16::from_f32(-2.75).to_i32_trunc(), Some(-2));
+    /// assert_eq!(f16::MAX.to_i32_trunc(), Some(65504));
+    /// assert_eq!(f16::MIN_POSITIVE_SUBNORMAL.to_i32_trunc(), Some(0));
+    /// assert_eq!(f16::NAN.to_i32_trunc(), None);
+    /// assert_eq!(f16::NEG_INFINITY.to_i32_trunc(), None);
+    /// ```
     #[inline]
     #[must_use]
-    pub fn to_f64(self) -> f64 {
-        self.to_f64_const()
+    pub const fn to_i32_trunc(self) -> Option<i32> {
+        arch::f16_to_i32_trunc(self.0)
     }
 
-    /// Converts a [`struct@f16`] value into a `f64` value.
+    /// Converts a normalized [`u8`], such as an 8-bit pixel channel, to a
+    /// [`struct@f16`] value in the range `[0.0, 1.0]`.
     ///
-    /// This function is identical to [`to_f64`][Self::to_f64] except it never
-    /// uses hardware intrinsics, which allows it to be `const`.
-    /// [`to_f64`][Self::to_f64] should be preferred in any non-`const`
-    /// context.
+    /// The result is `v / 255` rounded to the nearest representable value, so
+    /// `0` maps to `0.0` and `255` maps to `1.0`. Every [`u8`] value round
+    /// trips through [`to_u8_norm`][Self::to_u8_norm].
     ///
-    /// This conversion is lossless as all 16-bit floating point values can be
-    /// represented exactly in 64-bit floating point.
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(f16::from_u8_norm(0), f16::ZERO);
+    /// assert_eq!(f16::from_u8_norm(255), f16::ONE);
+    /// assert_eq!(f16::from_u8_norm(51), f16::from_f32(0.2));
+    /// ```
     #[inline]
     #[must_use]
-    pub const fn to_f64_const(self) -> f64 {
-        arch::f16_to_f64_fallback(self.0)
+    pub fn from_u8_norm(v: u8) -> f16 {
+        f16::from_f32(v as f32 / 255.0)
     }
 
-    /// Converts a [`struct@f16`] value into a `f32` value.
+    /// Converts a [`struct@f16`] value in the range `[0.0, 1.0]` to a
+    /// normalized [`u8`], such as an 8-bit pixel channel.
     ///
-    /// This conversion is lossless as all 16-bit floating point values can be
-    /// represented exactly in 32-bit floating point.
+    /// The result is `self * 255` rounded to the nearest integer, with
+    /// half-way cases rounded up. Values below `0.0` saturate to `0`, values
+    /// above `1.0` saturate to `255`, and NaN converts to `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(f16::ONE.to_u8_norm(), 255);
+    /// assert_eq!(f16::from_f32(0.5).to_u8_norm(), 128);
+    /// assert_eq!(f16::from_f32(-0.5).to_u8_norm(), 0);
+    /// assert_eq!(f16::from_f32(2.0).to_u8_norm(), 255);
+    /// assert_eq!(f16::NAN.to_u8_norm(), 0);
+    /// ```
     #[inline]
     #[must_use]
-    pub fn to_f64_intrinsic(self) -> f64 {
-        arch::f16_to_f64(self.0)
-    }
-
-    /// Convert the data to an `f64` type, used for numerical operations.
-    #[inline(always)]
-    pub fn as_f64(self) -> f64 {
-        self.to_f64_const()
+    pub fn to_u8_norm(self) -> u8 {
+        // The product is exact and so is adding one half, so truncating gives
+        // the rounded value. Float to integer casts saturate, and NaN casts
+        // to zero.
+        (self.to_f32() * 255.0 + 0.5) as u8
     }
 
-    /// Convert the data to an `f64` type, used for numerical operations.
-    #[inline(always)]
-    pub const fn as_f64_const(self) -> f64 {
-        self.to_f64_const()
+    /// Converts a [`struct@f16`] into the underlying bit representation.
+    #[inline]
+    #[must_use]
+    pub const fn to_bits(self) -> u16 {
+        self.0
     }
 
-    /// Returns `true` if this value is `NaN` and `false` otherwise.
+    /// Constructs a 16-bit floating point value from its sign, biased exponent
+    /// and mantissa fields.
+    ///
+    /// The `biased_exponent` is the 5-bit exponent field and the `mantissa` is
+    /// the 10-bit fraction field, without the hidden bit, and both are masked
+    /// to their widths. In debug builds, this panics if either field does not
+    /// fit: use [`from_parts_checked`][Self::from_parts_checked] to validate
+    /// the fields instead.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use float16::*;
-    ///
-    /// let nan = f16::NAN;
-    /// let f = f16::from_f32(7.0_f32);
-    ///
-    /// assert!(nan.is_nan());
-    /// assert!(!f.is_nan());
+    /// assert_eq!(f16::from_parts(false, 15, 0), f16::ONE);
+    /// assert_eq!(f16::from_parts(true, 16, 0x200), f16::from_f32(-3.0));
+    /// assert_eq!(f16::from_parts(false, 31, 0), f16::INFINITY);
     /// ```
     #[inline]
     #[must_use]
-    pub const fn is_nan(self) -> bool {
-        self.0 & Self::NOT_SIGN > Self::EXP_MASK
-    }
-
-    /// Computes the absolute value of `self`.
-    #[must_use]
-    #[inline(always)]
-    pub const fn abs(self) -> Self {
-        Self(self.0 & !Self::SIGN_MASK)
+    pub const fn from_parts(sign: bool, biased_exponent: u8, mantissa: u16) -> f16 {
+        debug_assert!(biased_exponent <= 0x1F, "biased exponent does not fit in 5 bits");
+        debug_assert!(mantissa <= Self::MAN_MASK, "mantissa does not fit in 10 bits");
+        let sign = if sign {
+            Self::SIGN_MASK
+        } else {
+            0
+        };
+        let exp = ((biased_exponent as u16) << 10) & Self::EXP_MASK;
+        f16(sign | exp | (mantissa & Self::MAN_MASK))
     }
 
-    /// Returns `true` if this value is ±∞ and `false`.
-    /// otherwise.
+    /// Constructs a 16-bit floating point value from its sign, biased exponent
+    /// and mantissa fields, returning `None` if `biased_exponent` does not fit
+    /// in 5 bits or `mantissa` does not fit in 10 bits.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use float16::*;
+    /// assert_eq!(f16::from_parts_checked(false, 15, 0x3FF), Some(f16::from_bits(0x3FFF)));
+    /// assert_eq!(f16::from_parts_checked(false, 32, 0), None);
+    /// assert_eq!(f16::from_parts_checked(false, 15, 0x400), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_parts_checked(sign: bool, biased_exponent: u8, mantissa: u16) -> Option<f16> {
+        if biased_exponent > 0x1F || mantissa > Self::MAN_MASK {
+            None
+        } else {
+            Some(Self::from_parts(sign, biased_exponent, mantissa))
+        }
+    }
+
+    /// Splits a 16-bit floating point value into its sign, biased exponent and
+    /// mantissa fields.
     ///
-    /// let f = f16::from_f32(7.0f32);
-    /// let inf = f16::INFINITY;
-    /// let neg_inf = f16::NEG_INFINITY;
-    /// let nan = f16::NAN;
+    /// The sign is `true` if the sign bit is set, the biased exponent is the
+    /// 5-bit exponent field, and the mantissa is the 10-bit fraction field,
+    /// without the hidden bit. This is the inverse of
+    /// [`from_parts`][Self::from_parts].
     ///
-    /// assert!(!f.is_infinite());
-    /// assert!(!nan.is_infinite());
+    /// # Examples
     ///
-    /// assert!(inf.is_infinite());
-    /// assert!(neg_inf.is_infinite());
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(f16::from_f32(-3.0).to_parts(), (true, 16, 0x200));
+    /// assert_eq!(f16::MIN_POSITIVE_SUBNORMAL.to_parts(), (false, 0, 1));
     /// ```
     #[inline]
     #[must_use]
-    pub const fn is_infinite(self) -> bool {
-        self.0 & Self::NOT_SIGN == Self::EXP_MASK
+    pub const fn to_parts(self) -> (bool, u8, u16) {
+        let sign = self.0 & Self::SIGN_MASK != 0;
+        let exp = ((self.0 & Self::EXP_MASK) >> 10) as u8;
+        let man = self.0 & Self::MAN_MASK;
+        (sign, exp, man)
     }
 
-    /// Returns `true` if this number is neither infinite nor `NaN`.
+    /// Returns the number of ones in the bits of the [`struct@f16`] value.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use float16::*;
-    ///
-    /// let f = f16::from_f32(7.0f32);
-    /// let inf = f16::INFINITY;
-    /// let neg_inf = f16::NEG_INFINITY;
-    /// let nan = f16::NAN;
-    ///
-    /// assert!(f.is_finite());
-    ///
-    /// assert!(!nan.is_finite());
-    /// assert!(!inf.is_finite());
-    /// assert!(!neg_inf.is_finite());
+    /// assert_eq!(f16::ONE.count_ones(), 4);
+    /// assert_eq!(f16::NEG_ZERO.count_ones(), 1);
     /// ```
     #[inline]
     #[must_use]
-    pub const fn is_finite(self) -> bool {
-        self.0 & Self::EXP_MASK != Self::EXP_MASK
+    pub const fn count_ones(self) -> u32 {
+        self.0.count_ones()
     }
 
-    /// Returns `true` if the number is [subnormal].
+    /// Returns the number of zeros in the bits of the [`struct@f16`] value.
     ///
-    /// [subnormal]: https://en.wikipedia.org/wiki/Denormal_number
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(f16::ONE.count_zeros(), 12);
+    /// ```
+    #[inline]
     #[must_use]
-    #[inline(always)]
-    pub const fn is_subnormal(self) -> bool {
-        matches!(self.classify(), FpCategory::Subnormal)
+    pub const fn count_zeros(self) -> u32 {
+        self.0.count_zeros()
     }
 
-    /// Returns `true` if the number is neither zero, infinite, subnormal, or
-    /// `NaN`.
+    /// Returns the number of leading zeros in the 10-bit mantissa field,
+    /// without the hidden bit.
+    ///
+    /// This is 10 if the mantissa is zero. For a subnormal value, this is
+    /// the shift that normalizes the mantissa, less one.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use float16::*;
+    /// assert_eq!(f16::ONE.leading_zeros_mantissa(), 10);
+    /// assert_eq!(f16::from_f32(1.5).leading_zeros_mantissa(), 0);
+    /// assert_eq!(f16::MIN_POSITIVE_SUBNORMAL.leading_zeros_mantissa(), 9);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn leading_zeros_mantissa(self) -> u32 {
+        leading_zeros_u16(self.0 & Self::MAN_MASK) - 6
+    }
+
+    /// Returns the number of trailing zeros in the 10-bit mantissa field,
+    /// without the hidden bit.
     ///
-    /// let min = f16::MIN_POSITIVE;
-    /// let max = f16::MAX;
-    /// let lower_than_min = f16::from_f32(1.0e-10_f32);
-    /// let zero = f16::from_f32(0.0_f32);
+    /// This is 10 if the mantissa is zero.
     ///
-    /// assert!(min.is_normal());
-    /// assert!(max.is_normal());
+    /// # Examples
     ///
-    /// assert!(!zero.is_normal());
-    /// assert!(!f16::NAN.is_normal());
-    /// assert!(!f16::INFINITY.is_normal());
-    /// // Values between `0` and `min` are Subnormal.
-    /// assert!(!lower_than_min.is_normal());
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(f16::ONE.trailing_zeros_mantissa(), 10);
+    /// assert_eq!(f16::from_f32(1.5).trailing_zeros_mantissa(), 9);
+    /// assert_eq!(f16::MIN_POSITIVE_SUBNORMAL.trailing_zeros_mantissa(), 0);
     /// ```
     #[inline]
     #[must_use]
-    pub const fn is_normal(self) -> bool {
-        let exp = self.0 & Self::EXP_MASK;
-        exp != Self::EXP_MASK && exp != 0
+    pub const fn trailing_zeros_mantissa(self) -> u32 {
+        ((self.0 & Self::MAN_MASK) | (Self::MAN_MASK + 1)).trailing_zeros()
     }
 
-    /// Returns the floating point category of the number.
+    /// Returns the range of unbiased exponents of normal [`struct@f16`]
+    /// values, `-14..=15`.
     ///
-    /// If only one property is going to be tested, it is generally faster to
-    /// use the specific predicate instead.
+    /// A normal value is `1.m × 2^e` for an exponent `e` in this range. This
+    /// differs by one from [`MIN_EXP`][Self::MIN_EXP] and
+    /// [`MAX_EXP`][Self::MAX_EXP], which follow the C convention of a
+    /// significand in `[0.5, 1)`.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use std::num::FpCategory;
     /// # use float16::*;
-    ///
-    /// let num = f16::from_f32(12.4_f32);
-    /// let inf = f16::INFINITY;
-    ///
-    /// assert_eq!(num.classify(), FpCategory::Normal);
-    /// assert_eq!(inf.classify(), FpCategory::Infinite);
+    /// let range = f16::exponent_range();
+    /// assert_eq!(*range.start(), f16::MIN_EXP - 1);
+    /// assert_eq!(*range.end(), f16::MAX_EXP - 1);
+    /// assert_eq!(f16::MIN_POSITIVE.to_f64(), 2f64.powi(*range.start()));
     /// ```
     #[inline]
     #[must_use]
-    pub const fn classify(self) -> FpCategory {
-        let exp = self.0 & Self::EXP_MASK;
-        let man = self.0 & Self::MAN_MASK;
-        match (exp, man) {
-            (0, 0) => FpCategory::Zero,
-            (0, _) => FpCategory::Subnormal,
-            (Self::EXP_MASK, 0) => FpCategory::Infinite,
-            (Self::EXP_MASK, _) => FpCategory::Nan,
-            _ => FpCategory::Normal,
-        }
+    pub const fn exponent_range() -> RangeInclusive<i32> {
+        RangeInclusive::new(Self::MIN_EXP - 1, Self::MAX_EXP - 1)
     }
 
-    /// Returns a number that represents the sign of `self`.
+    /// Returns the exponent of the least significant bit of subnormal
+    /// [`struct@f16`] values, `-24`.
     ///
-    /// * `1.0` if the number is positive, `+0.0` or [`INFINITY`][f16::INFINITY]
-    /// * `-1.0` if the number is negative, `-0.0` or
-    ///   [`NEG_INFINITY`][f16::NEG_INFINITY]
-    /// * [`NAN`][f16::NAN] if the number is `NaN`
+    /// Every subnormal value is an integer multiple of `2^-24`, which is
+    /// [`MIN_POSITIVE_SUBNORMAL`][Self::MIN_POSITIVE_SUBNORMAL].
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use float16::*;
+    /// let exp = f16::subnormal_exponent();
+    /// assert_eq!(f16::MIN_POSITIVE_SUBNORMAL.to_f64(), 2f64.powi(exp));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn subnormal_exponent() -> i32 {
+        Self::MIN_EXP - 1 - (Self::MANTISSA_DIGITS as i32 - 1)
+    }
+
+    /// Returns the unbiased exponent of the value, `floor(log2(|self|))`.
     ///
-    /// let f = f16::from_f32(3.5_f32);
+    /// For normal values this is the exponent field without the bias, and
+    /// for subnormal values it is below the [normal
+    /// range][Self::exponent_range], as low as
+    /// [`subnormal_exponent`][Self::subnormal_exponent]. Returns `None` for
+    /// zero, infinite and NaN values.
     ///
-    /// assert_eq!(f.signum(), f16::from_f32(1.0));
-    /// assert_eq!(f16::NEG_INFINITY.signum(), f16::from_f32(-1.0));
+    /// # Examples
     ///
-    /// assert!(f16::NAN.signum().is_nan());
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(f16::ONE.decode_exponent(), Some(0));
+    /// assert_eq!(f16::from_f32(-1000.0).decode_exponent(), Some(9));
+    /// assert_eq!(f16::from_f32(6.0e-8).decode_exponent(), Some(-24));
+    /// assert_eq!(f16::ZERO.decode_exponent(), None);
+    /// assert_eq!(f16::NAN.decode_exponent(), None);
     /// ```
     #[inline]
     #[must_use]
-    pub const fn signum(self) -> f16 {
-        if self.is_nan() {
-            self
-        } else if self.0 & Self::SIGN_MASK != 0 {
-            Self::NEG_ONE
+    pub const fn decode_exponent(self) -> Option<i32> {
+        let biased = ((self.0 & Self::EXP_MASK) >> 10) as i32;
+        let man = self.0 & Self::MAN_MASK;
+        if biased == 0x1F {
+            None
+        } else if biased == 0 {
+            if man == 0 {
+                None
+            } else {
+                let msb = 15 - leading_zeros_u16(man) as i32;
+                Some(Self::subnormal_exponent() + msb)
+            }
         } else {
-            Self::ONE
+            Some(biased - 15)
         }
     }
 
-    /// Returns `true` if and only if `self` has a positive sign, including
-    /// `+0.0`, `NaNs` with a positive sign bit and +∞.
+    /// Parses a [`struct@f16`] value from a hexadecimal floating point
+    /// literal, such as `0x1.8p+1`.
+    ///
+    /// The literal has an optional sign, a `0x` or `0X` prefix, hexadecimal
+    /// digits with an optional fractional part after a `.`, and a mandatory
+    /// binary exponent: a `p` or `P` followed by a signed decimal integer. The
+    /// value is rounded to the nearest representable value with ties to
+    /// even. `inf`, `infinity` and `nan` are also accepted, ignoring case.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseHalfError`] if `src` is not a valid literal.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use float16::*;
+    /// assert_eq!(f16::from_hex_str("0x1.8p+1"), Ok(f16::from_f32(3.0)));
+    /// assert_eq!(f16::from_hex_str("-0x1.ffcp+15"), Ok(f16::MIN));
+    /// assert_eq!(f16::from_hex_str("0x1p-24"), Ok(f16::MIN_POSITIVE_SUBNORMAL));
+    /// assert!(f16::from_hex_str("1.5").is_err());
+    /// ```
+    #[inline]
+    pub fn from_hex_str(src: &str) -> Result<f16, ParseHalfError> {
+        hex::parse_hex(src, 5, 10).map(f16)
+    }
+
+    /// Returns a value that displays as a hexadecimal floating point
+    /// literal, which can be parsed exactly by
+    /// [`from_hex_str`][Self::from_hex_str].
     ///
-    /// let nan = f16::NAN;
-    /// let f = f16::from_f32(7.0_f32);
-    /// let g = f16::from_f32(-7.0_f32);
+    /// See [`HexFloat`] for the format. This does not allocate, unlike
+    /// `to_hex_string`.
     ///
-    /// assert!(f.is_sign_positive());
-    /// assert!(!g.is_sign_positive());
-    /// // `NaN` can be either positive or negative
-    /// assert!(nan.is_sign_positive() != nan.is_sign_negative());
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// use core::fmt::Write;
+    ///
+    /// let mut s = String::new();
+    /// write!(s, "{}", f16::from_f32(3.0).to_hex()).unwrap();
+    /// assert_eq!(s, "0x1.8p+1");
     /// ```
     #[inline]
     #[must_use]
-    pub const fn is_sign_positive(self) -> bool {
-        self.0 & Self::SIGN_MASK == 0
+    #[cfg(not(target_arch = "spirv"))]
+    pub const fn to_hex(self) -> HexFloat {
+        HexFloat::new(self.0, 5, 10)
     }
 
-    /// Returns `true` if and only if `self` has a negative sign, including
-    /// `-0.0`, `NaNs` with a negative sign bit and −∞.
+    /// Formats the value as a hexadecimal floating point literal, which can
+    /// be parsed exactly by [`from_hex_str`][Self::from_hex_str].
+    ///
+    /// See [`HexFloat`] for the format.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use float16::*;
-    ///
-    /// let nan = f16::NAN;
-    /// let f = f16::from_f32(7.0f32);
-    /// let g = f16::from_f32(-7.0f32);
-    ///
-    /// assert!(!f.is_sign_negative());
-    /// assert!(g.is_sign_negative());
-    /// // `NaN` can be either positive or negative
-    /// assert!(nan.is_sign_positive() != nan.is_sign_negative());
+    /// assert_eq!(f16::from_f32(3.0).to_hex_string(), "0x1.8p+1");
+    /// assert_eq!(f16::MIN_POSITIVE_SUBNORMAL.to_hex_string(), "0x0.004p-14");
+    /// assert_eq!(f16::NEG_INFINITY.to_hex_string(), "-inf");
     /// ```
     #[inline]
     #[must_use]
-    pub const fn is_sign_negative(self) -> bool {
-        self.0 & Self::SIGN_MASK != 0
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn to_hex_string(self) -> std::string::String {
+        std::string::ToString::to_string(&self.to_hex())
     }
 
-    /// Returns a number composed of the magnitude of `self` and the sign of
-    /// `sign`.
+    /// Formats the value the same as NumPy formats a `numpy.float16` scalar,
+    /// with `str`, or `repr` before NumPy 2.0.
     ///
-    /// Equal to `self` if the sign of `self` and `sign` are the same, otherwise
-    /// equal to `-self`. If `self` is NaN, then NaN with the sign of `sign`
-    /// is returned.
+    /// The digits are the shortest that parse back to the same value, as
+    /// with the [`Display`] implementation, except that NumPy never uses a
+    /// decimal exactly halfway to a neighboring value, so `4112` is written as
+    /// `4112.0` rather than `4110.0`. NumPy writes zero and magnitudes from
+    /// `1e-4` up to `1e16` positionally with at least one fractional digit,
+    /// and other values in scientific notation with a signed exponent of at
+    /// least two digits. NaN is always `nan`, without a sign. This is
+    /// useful to produce logs that are byte-identical to those written from
+    /// NumPy.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```rust
     /// # use float16::*;
-    /// let f = f16::from_f32(3.5);
+    /// assert_eq!(f16::from_f32(2.).to_numpy_repr_string(), "2.0");
+    /// assert_eq!(f16::MAX.to_numpy_repr_string(), "65500.0");
+    /// assert_eq!(f16::from_f32(4112.).to_numpy_repr_string(), "4112.0");
+    /// assert_eq!(f16::from_f32(0.1).to_numpy_repr_string(), "0.1");
+    /// assert_eq!(f16::from_f32(1.5e-5).to_numpy_repr_string(), "1.5e-05");
+    /// assert_eq!(f16::NEG_ZERO.to_numpy_repr_string(), "-0.0");
+    /// assert_eq!((-f16::NAN).to_numpy_repr_string(), "nan");
+    /// ```
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn to_numpy_repr_string(self) -> std::string::String {
+        let decimal = shortest::Decimal::shortest_exclusive(self.0, 5, 10);
+        let mut s = std::string::String::new();
+        shortest::write_numpy_repr(&mut s, self.to_f32(), decimal)
+            .expect("a String never fails to write");
+        s
+    }
+
+    /// Returns the memory representation of the underlying bit representation
+    /// as a byte array in little-endian byte order.
     ///
-    /// assert_eq!(f.copysign(f16::from_f32(0.42)), f16::from_f32(3.5));
-    /// assert_eq!(f.copysign(f16::from_f32(-0.42)), f16::from_f32(-3.5));
-    /// assert_eq!((-f).copysign(f16::from_f32(0.42)), f16::from_f32(3.5));
-    /// assert_eq!((-f).copysign(f16::from_f32(-0.42)), f16::from_f32(-3.5));
+    /// # Examples
     ///
-    /// assert!(f16::NAN.copysign(f16::from_f32(1.0)).is_nan());
+    /// ```rust
+    /// # use float16::*;
+    /// let bytes = f16::from_f32(12.5).to_le_bytes();
+    /// assert_eq!(bytes, [0x40, 0x4A]);
     /// ```
     #[inline]
     #[must_use]
-    pub const fn copysign(self, sign: f16) -> f16 {
-        f16((sign.0 & Self::SIGN_MASK) | (self.0 & Self::NOT_SIGN))
+    pub const fn to_le_bytes(self) -> [u8; 2] {
+        self.0.to_le_bytes()
     }
 
-    /// Takes the reciprocal (inverse) of a number, `1/x`.
+    /// Returns the memory representation of the underlying bit representation
+    /// as a byte array in big-endian (network) byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let bytes = f16::from_f32(12.5).to_be_bytes();
+    /// assert_eq!(bytes, [0x4A, 0x40]);
+    /// ```
+    #[inline]
     #[must_use]
-    #[inline(always)]
-    pub fn recip(self) -> Self {
-        Self::ONE / self
+    pub const fn to_be_bytes(self) -> [u8; 2] {
+        self.0.to_be_bytes()
     }
 
-    /// Converts radians to degrees.
+    /// Returns the memory representation of the underlying bit representation
+    /// as a byte array in native byte order.
+    ///
+    /// As the target platform's native endianness is used, portable code should
+    /// use [`to_be_bytes`][Self::to_be_bytes] or
+    /// [`to_le_bytes`][Self::to_le_bytes], as appropriate, instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let bytes = f16::from_f32(12.5).to_ne_bytes();
+    /// assert_eq!(bytes, if cfg!(target_endian = "big") {
+    ///     [0x4A, 0x40]
+    /// } else {
+    ///     [0x40, 0x4A]
+    /// });
+    /// ```
+    #[inline]
     #[must_use]
-    #[inline(always)]
-    pub fn to_degrees(self) -> Self {
-        self * Self::from(180u8) / Self::PI
+    pub const fn to_ne_bytes(self) -> [u8; 2] {
+        self.0.to_ne_bytes()
     }
 
-    /// Converts degrees to radians.
+    /// Creates a floating point value from its representation as a byte array
+    /// in little endian.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let value = f16::from_le_bytes([0x40, 0x4A]);
+    /// assert_eq!(value, f16::from_f32(12.5));
+    /// ```
+    #[inline]
     #[must_use]
-    #[inline(always)]
-    pub fn to_radians(self) -> Self {
-        self * Self::PI / Self::from(180u8)
+    pub const fn from_le_bytes(bytes: [u8; 2]) -> f16 {
+        f16::from_bits(u16::from_le_bytes(bytes))
     }
 
-    /// Returns the maximum of the two numbers.
-    ///
-    /// If one of the arguments is NaN, then the other argument is returned.
+    /// Creates a floating point value from its representation as a byte array
+    /// in big endian.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```rust
     /// # use float16::*;
-    /// let x = f16::from_f32(1.0);
-    /// let y = f16::from_f32(2.0);
-    ///
-    /// assert_eq!(x.max(y), y);
+    /// let value = f16::from_be_bytes([0x4A, 0x40]);
+    /// assert_eq!(value, f16::from_f32(12.5));
     /// ```
     #[inline]
     #[must_use]
-    pub const fn max(self, other: f16) -> f16 {
-        if self.is_nan() || gt(other, self) {
-            other
-        } else {
-            self
-        }
+    pub const fn from_be_bytes(bytes: [u8; 2]) -> f16 {
+        f16::from_bits(u16::from_be_bytes(bytes))
     }
 
-    /// Returns the minimum of the two numbers.
+    /// Creates a floating point value from its representation as a byte array
+    /// in native endian.
     ///
-    /// If one of the arguments is NaN, then the other argument is returned.
+    /// As the target platform's native endianness is used, portable code likely
+    /// wants to use [`from_be_bytes`][Self::from_be_bytes] or
+    /// [`from_le_bytes`][Self::from_le_bytes], as appropriate instead.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```rust
     /// # use float16::*;
-    /// let x = f16::from_f32(1.0);
-    /// let y = f16::from_f32(2.0);
-    ///
-    /// assert_eq!(x.min(y), x);
+    /// let value = f16::from_ne_bytes(if cfg!(target_endian = "big") {
+    ///     [0x4A, 0x40]
+    /// } else {
+    ///     [0x40, 0x4A]
+    /// });
+    /// assert_eq!(value, f16::from_f32(12.5));
     /// ```
     #[inline]
     #[must_use]
-    pub const fn min(self, other: f16) -> f16 {
-        if self.is_nan() || lt(other, self) {
-            other
-        } else {
-            self
-        }
+    pub const fn from_ne_bytes(bytes: [u8; 2]) -> f16 {
+        f16::from_bits(u16::from_ne_bytes(bytes))
     }
 
-    /// Restrict a value to a certain interval unless it is NaN.
+    /// Creates a slice of values from bytes with each value in little endian,
+    /// borrowing the bytes when possible.
     ///
-    /// Returns `max` if `self` is greater than `max`, and `min` if `self` is
-    /// less than `min`. Otherwise this returns `self`.
+    /// The bytes are borrowed without copying if they are aligned for
+    /// [`struct@f16`] and the target is little endian. Otherwise, they are
+    /// copied into a new vector, swapping the bytes of each value on big
+    /// endian targets.
     ///
-    /// Note that this function returns NaN if the initial value was NaN as
-    /// well.
+    /// # Errors
     ///
-    /// # Panics
-    /// Panics if `min > max`, `min` is NaN, or `max` is NaN.
+    /// Returns [`OddLength`] if the length of `bytes` is not a multiple of 2.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```rust
     /// # use float16::*;
-    /// assert!(f16::from_f32(-3.0).clamp(f16::from_f32(-2.0), f16::from_f32(1.0)) == f16::from_f32(-2.0));
-    /// assert!(f16::from_f32(0.0).clamp(f16::from_f32(-2.0), f16::from_f32(1.0)) == f16::from_f32(0.0));
-    /// assert!(f16::from_f32(2.0).clamp(f16::from_f32(-2.0), f16::from_f32(1.0)) == f16::from_f32(1.0));
-    /// assert!(f16::NAN.clamp(f16::from_f32(-2.0), f16::from_f32(1.0)).is_nan());
+    /// let bytes = [0x40, 0x4A, 0x00, 0x3C];
+    /// let values = f16::cow_from_le_bytes(&bytes).unwrap();
+    /// assert_eq!(&*values, &[f16::from_f32(12.5), f16::from_bits(0x3C00)]);
+    ///
+    /// assert!(f16::cow_from_le_bytes(&bytes[1..]).is_err());
     /// ```
+    #[cfg(feature = "alloc")]
     #[inline]
-    #[must_use]
-    pub const fn clamp(self, min: f16, max: f16) -> f16 {
-        assert!(le(min, max));
-        let mut x = self;
-        if lt(x, min) {
-            x = min;
-        }
-        if gt(x, max) {
-            x = max;
-        }
-        x
+    pub fn cow_from_le_bytes(bytes: &[u8]) -> Result<Cow<'_, [f16]>, OddLength> {
+        Self::cow_from_bytes(bytes, cfg!(target_endian = "little"), f16::from_le_bytes)
     }
 
-    /// Returns the ordering between `self` and `other`.
-    ///
-    /// Unlike the standard partial comparison between floating point numbers,
-    /// this comparison always produces an ordering in accordance to
-    /// the `totalOrder` predicate as defined in the IEEE 754 (2008 revision)
-    /// floating point standard. The values are ordered in the following
-    /// sequence:
+    /// Creates a slice of values from bytes with each value in big endian,
+    /// borrowing the bytes when possible.
     ///
-    /// - negative quiet NaN
-    /// - negative signaling NaN
-    /// - negative infinity
-    /// - negative numbers
-    /// - negative subnormal numbers
-    /// - negative zero
-    /// - positive zero
-    /// - positive subnormal numbers
-    /// - positive numbers
-    /// - positive infinity
-    /// - positive signaling NaN
-    /// - positive quiet NaN.
+    /// The bytes are borrowed without copying if they are aligned for
+    /// [`struct@f16`] and the target is big endian. Otherwise, they are copied
+    /// into a new vector, swapping the bytes of each value on little endian
+    /// targets.
     ///
-    /// The ordering established by this function does not always agree with the
-    /// [`PartialOrd`] and [`PartialEq`] implementations of `f16`. For example,
-    /// they consider negative and positive zero equal, while `total_cmp`
-    /// doesn't.
+    /// # Errors
     ///
-    /// The interpretation of the signaling NaN bit follows the definition in
-    /// the IEEE 754 standard, which may not match the interpretation by some of
-    /// the older, non-conformant (e.g. MIPS) hardware implementations.
+    /// Returns [`OddLength`] if the length of `bytes` is not a multiple of 2.
     ///
     /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let bytes = [0x4A, 0x40, 0x3C, 0x00];
+    /// let values = f16::cow_from_be_bytes(&bytes).unwrap();
+    /// assert_eq!(&*values, &[f16::from_f32(12.5), f16::from_bits(0x3C00)]);
     /// ```
-    /// # use float16::f16;
-    /// let mut v: Vec<f16> = vec![];
-    /// v.push(f16::ONE);
-    /// v.push(f16::INFINITY);
-    /// v.push(f16::NEG_INFINITY);
-    /// v.push(f16::NAN);
-    /// v.push(f16::MAX_SUBNORMAL);
-    /// v.push(-f16::MAX_SUBNORMAL);
-    /// v.push(f16::ZERO);
-    /// v.push(f16::NEG_ZERO);
-    /// v.push(f16::NEG_ONE);
-    /// v.push(f16::MIN_POSITIVE);
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn cow_from_be_bytes(bytes: &[u8]) -> Result<Cow<'_, [f16]>, OddLength> {
+        Self::cow_from_bytes(bytes, cfg!(target_endian = "big"), f16::from_be_bytes)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn cow_from_bytes(
+        bytes: &[u8],
+        native: bool,
+        from_bytes: fn([u8; 2]) -> f16,
+    ) -> Result<Cow<'_, [f16]>, OddLength> {
+        if bytes.len() % 2 != 0 {
+            return Err(OddLength(()));
+        }
+        if native {
+            // SAFETY: `f16` is a `u16` with no invalid bit patterns, and
+            // `align_to` only returns the aligned middle as `f16` values.
+            let (prefix, values, _) = unsafe { bytes.align_to::<f16>() };
+            if prefix.is_empty() && values.len() * 2 == bytes.len() {
+                return Ok(Cow::Borrowed(values));
+            }
+        }
+        let values: Vec<f16> = bytes.chunks_exact(2).map(|b| from_bytes([b[0], b[1]])).collect();
+        Ok(Cow::Owned(values))
+    }
+
+    /// Converts a [`struct@f16`] value into a `f32` value.
     ///
-    /// v.sort_by(|a, b| a.total_cmp(&b));
+    /// This conversion is lossless as all 16-bit floating point values can be
+    /// represented exactly in 32-bit floating point.
     ///
-    /// assert!(v
-    ///     .into_iter()
-    ///     .zip(
-    ///         [
-    ///             f16::NEG_INFINITY,
-    ///             f16::NEG_ONE,
-    ///             -f16::MAX_SUBNORMAL,
-    ///             f16::NEG_ZERO,
-    ///             f16::ZERO,
-    ///             f16::MAX_SUBNORMAL,
-    ///             f16::MIN_POSITIVE,
-    ///             f16::ONE,
-    ///             f16::INFINITY,
-    ///             f16::NAN
-    ///         ]
-    ///         .iter()
-    ///     )
-    ///     .all(|(a, b)| a.to_bits() == b.to_bits()));
-    /// ```
-    // Implementation based on: https://doc.rust-lang.org/std/primitive.f32.html#method.total_cmp
+    /// This will prefer correctness over speed. Currently, this always
+    /// uses an intrinsic if available.
     #[inline]
     #[must_use]
-    pub fn total_cmp(&self, other: &Self) -> Ordering {
-        let mut left = self.to_bits() as i16;
-        let mut right = other.to_bits() as i16;
-        left ^= (((left >> 15) as u16) >> 1) as i16;
-        right ^= (((right >> 15) as u16) >> 1) as i16;
-        left.cmp(&right)
+    pub fn to_f32(self) -> f32 {
+        self.to_f32_intrinsic()
     }
 
-    /// Approximate number of [`struct@f16`] significant digits in base 10
-    pub const DIGITS: u32 = 3;
-    /// [`struct@f16`]
-    /// [machine epsilon](https://en.wikipedia.org/wiki/Machine_epsilon) value
+    /// Converts a [`struct@f16`] value into a `f32` value.
     ///
-    /// This is the difference between 1.0 and the next largest representable
-    /// number.
-    pub const EPSILON: f16 = f16(0x1400u16);
-    /// [`struct@f16`] positive Infinity (+∞)
-    pub const INFINITY: f16 = f16(0x7C00u16);
-    /// Number of [`struct@f16`] significant digits in base 2
-    pub const MANTISSA_DIGITS: u32 = 11;
-    /// Largest finite [`struct@f16`] value
-    pub const MAX: f16 = f16(0x7BFF);
-    /// Maximum possible [`struct@f16`] power of 10 exponent
-    pub const MAX_10_EXP: i32 = 4;
-    /// Maximum possible [`struct@f16`] power of 2 exponent
-    pub const MAX_EXP: i32 = 16;
-    /// Smallest finite [`struct@f16`] value
-    pub const MIN: f16 = f16(0xFBFF);
-    /// Minimum possible normal [`struct@f16`] power of 10 exponent
-    pub const MIN_10_EXP: i32 = -4;
-    /// One greater than the minimum possible normal [`struct@f16`] power of 2
-    /// exponent
-    pub const MIN_EXP: i32 = -13;
-    /// Smallest positive normal [`struct@f16`] value
-    pub const MIN_POSITIVE: f16 = f16(0x0400u16);
-    /// [`struct@f16`] Not a Number (NaN)
-    pub const NAN: f16 = f16(0x7E00u16);
-    /// [`struct@f16`] negative infinity (-∞)
-    pub const NEG_INFINITY: f16 = f16(0xFC00u16);
-    /// The radix or base of the internal representation of [`struct@f16`]
-    pub const RADIX: u32 = 2;
-
-    /// Minimum positive subnormal [`struct@f16`] value
-    pub const MIN_POSITIVE_SUBNORMAL: f16 = f16(0x0001u16);
-    /// Maximum subnormal [`struct@f16`] value
-    pub const MAX_SUBNORMAL: f16 = f16(0x03FFu16);
+    /// This function is identical to [`to_f32`][Self::to_f32] except it never
+    /// uses hardware intrinsics, which allows it to be `const`.
+    /// [`to_f32`][Self::to_f32] should be preferred in any non-`const`
+    /// context.
+    ///
+    /// This conversion is lossless as all 16-bit floating point values can be
+    /// represented exactly in 32-bit floating point.
+    #[inline]
+    #[must_use]
+    pub const fn to_f32_const(self) -> f32 {
+        arch::canonical_nan_f32(arch::f16_to_f32_fallback(self.0))
+    }
 
-    /// [`struct@f16`] 1
-    pub const ONE: f16 = f16(0x3C00u16);
-    /// [`struct@f16`] 0
-    pub const ZERO: f16 = f16(0x0000u16);
-    /// [`struct@f16`] -0
-    pub const NEG_ZERO: f16 = f16(0x8000u16);
-    /// [`struct@f16`] -1
-    pub const NEG_ONE: f16 = f16(0xBC00u16);
+    /// Converts a [`struct@f16`] value into a `f32` value.
+    ///
+    /// This conversion is lossless as all 16-bit floating point values can be
+    /// represented exactly in 32-bit floating point.
+    #[inline]
+    #[must_use]
+    pub fn to_f32_intrinsic(self) -> f32 {
+        arch::canonical_nan_f32(arch::f16_to_f32(self.0))
+    }
 
-    /// [`struct@f16`] Euler's number (ℯ)
-    pub const E: f16 = f16(0x4170u16);
-    /// [`struct@f16`] Archimedes' constant (π)
-    pub const PI: f16 = f16(0x4248u16);
-    /// [`struct@f16`] 1/π
-    pub const FRAC_1_PI: f16 = f16(0x3518u16);
-    /// [`struct@f16`] 1/√2
-    pub const FRAC_1_SQRT_2: f16 = f16(0x39A8u16);
-    /// [`struct@f16`] 2/π
-    pub const FRAC_2_PI: f16 = f16(0x3918u16);
-    /// [`struct@f16`] 2/√π
-    pub const FRAC_2_SQRT_PI: f16 = f16(0x3C83u16);
-    /// [`struct@f16`] π/2
-    pub const FRAC_PI_2: f16 = f16(0x3E48u16);
-    /// [`struct@f16`] π/3
-    pub const FRAC_PI_3: f16 = f16(0x3C30u16);
-    /// [`struct@f16`] π/4
-    pub const FRAC_PI_4: f16 = f16(0x3A48u16);
-    /// [`struct@f16`] π/6
-    pub const FRAC_PI_6: f16 = f16(0x3830u16);
-    /// [`struct@f16`] π/8
-    pub const FRAC_PI_8: f16 = f16(0x3648u16);
-    /// [`struct@f16`] 𝗅𝗇 10
-    pub const LN_10: f16 = f16(0x409Bu16);
-    /// [`struct@f16`] 𝗅𝗇 2
-    pub const LN_2: f16 = f16(0x398Cu16);
-    /// [`struct@f16`] 𝗅𝗈𝗀₁₀ℯ
-    pub const LOG10_E: f16 = f16(0x36F3u16);
-    /// [`struct@f16`] 𝗅𝗈𝗀₁₀2
-    pub const LOG10_2: f16 = f16(0x34D1u16);
-    /// [`struct@f16`] 𝗅𝗈𝗀₂ℯ
-    pub const LOG2_E: f16 = f16(0x3DC5u16);
-    /// [`struct@f16`] 𝗅𝗈𝗀₂10
-    pub const LOG2_10: f16 = f16(0x42A5u16);
-    /// [`struct@f16`] √2
-    pub const SQRT_2: f16 = f16(0x3DA8u16);
+    /// Convert the data to an `f32` type, used for numerical operations.
+    #[inline(always)]
+    pub fn as_f32(self) -> f32 {
+        self.to_f32_const()
+    }
 
-    /// Sign bit
-    pub const SIGN_MASK: u16 = 0x8000;
-    // Private helper for comparisons.
-    const NOT_SIGN: u16 = !Self::SIGN_MASK;
+    /// Convert the data to an `f32` type, used for numerical operations.
+    #[inline(always)]
+    pub const fn as_f32_const(self) -> f32 {
+        self.to_f32_const()
+    }
 
-    /// Exponent mask
-    pub const EXP_MASK: u16 = 0x7C00;
+    /// Converts a [`struct@f16`] value into a `f64` value.
+    ///
+    /// This conversion is lossless as all 16-bit floating point values can be
+    /// represented exactly in 64-bit floating point.
+    ///
+    /// This will prefer correctness over speed: on x86 systems, this currently
+    /// uses a software rather than an instrinsic implementation on x86.
+    #[inline]
+    #[must_use]
+    pub fn to_f64(self) -> f64 {
+        self.to_f64_const()
+    }
 
-    /// Mask for the hidden bit.
-    pub const HIDDEN_BIT_MASK: u16 = 0x0400;
+    /// Converts a [`struct@f16`] value into a `f64` value.
+    ///
+    /// This function is identical to [`to_f64`][Self::to_f64] except it never
+    /// uses hardware intrinsics, which allows it to be `const`.
+    /// [`to_f64`][Self::to_f64] should be preferred in any non-`const`
+    /// context.
+    ///
+    /// This conversion is lossless as all 16-bit floating point values can be
+    /// represented exactly in 64-bit floating point.
+    #[inline]
+    #[must_use]
+    pub const fn to_f64_const(self) -> f64 {
+        arch::canonical_nan_f64(arch::f16_to_f64_fallback(self.0))
+    }
 
-    /// Mantissa mask
-    pub const MAN_MASK: u16 = 0x03FF;
+    /// Converts a [`struct@f16`] value into a `f32` value.
+    ///
+    /// This conversion is lossless as all 16-bit floating point values can be
+    /// represented exactly in 32-bit floating point.
+    #[inline]
+    #[must_use]
+    pub fn to_f64_intrinsic(self) -> f64 {
+        arch::canonical_nan_f64(arch::f16_to_f64(self.0))
+    }
 
-    /// Minimum representable positive value (min subnormal)
-    pub const TINY_BITS: u16 = 0x1;
+    /// Convert the data to an `f64` type, used for numerical operations.
+    #[inline(always)]
+    pub fn as_f64(self) -> f64 {
+        self.to_f64_const()
+    }
 
-    /// Minimum representable negative value (min negative subnormal)
-    pub const NEG_TINY_BITS: u16 = Self::TINY_BITS | Self::SIGN_MASK;
-}
+    /// Convert the data to an `f64` type, used for numerical operations.
+    #[inline(always)]
+    pub const fn as_f64_const(self) -> f64 {
+        self.to_f64_const()
+    }
 
-macro_rules! from_int_impl {
-    ($t:ty, $func:ident) => {
-        /// Create from the integral type, as if by an `as` cast.
-        #[inline(always)]
-        pub const fn $func(value: $t) -> Self {
-            Self::from_f32_const(value as f32)
-        }
-    };
-}
+    /// Converts a [`struct@f16`] value to the nearest [`struct@bf16`] value.
+    ///
+    /// Every [`struct@f16`] value, including the subnormals, is within the
+    /// range of [`struct@bf16`], but only the 7 highest of the 10 mantissa
+    /// bits fit, so this rounds to the nearest value with ties to even. NaN
+    /// values are preserved and quiet. This is the same as
+    /// [`bf16::from_f16_lossy`][crate::bf16::from_f16_lossy], and the same as
+    /// converting through [`f32`], which is exact for every
+    /// [`struct@f16`] value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(f16::from_f32(1.5).to_bf16(), bf16::from_f32(1.5));
+    /// assert_eq!(f16::MAX.to_bf16().to_f32(), 65536.0);
+    ///
+    /// let x = f16::from_f32(0.1);
+    /// assert_eq!(x.to_bf16(), bf16::from_f32(x.to_f32()));
+    ///
+    /// const HALF: bf16 = f16::ONE.to_bf16();
+    /// assert_eq!(HALF, bf16::ONE);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_bf16(self) -> bf16 {
+        bf16::from_f16_lossy(self)
+    }
 
-impl f16 {
-    from_int_impl!(u8, from_u8);
-    from_int_impl!(u16, from_u16);
-    from_int_impl!(u32, from_u32);
-    from_int_impl!(u64, from_u64);
-    from_int_impl!(u128, from_u128);
-    from_int_impl!(i8, from_i8);
-    from_int_impl!(i16, from_i16);
-    from_int_impl!(i32, from_i32);
-    from_int_impl!(i64, from_i64);
-    from_int_impl!(i128, from_i128);
-}
+    /// Returns `true` if this value is `NaN` and `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    ///
+    /// let nan = f16::NAN;
+    /// let f = f16::from_f32(7.0_f32);
+    ///
+    /// assert!(nan.is_nan());
+    /// assert!(!f.is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_nan(self) -> bool {
+        self.0 & Self::NOT_SIGN > Self::EXP_MASK
+    }
 
-impl From<f16> for f32 {
+    /// Returns `true` if this value is a signaling `NaN`.
+    ///
+    /// Following IEEE 754-2008, a `NaN` is signaling if the most significant
+    /// bit of the mantissa, the quiet bit, is clear. Arithmetic on a
+    /// signaling `NaN` results in a quiet `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert!(f16::SIGNALING_NAN.is_nan_signaling());
+    /// assert!(!f16::NAN.is_nan_signaling());
+    /// assert!(!f16::INFINITY.is_nan_signaling());
+    /// ```
     #[inline]
-    fn from(x: f16) -> f32 {
-        x.to_f32()
+    #[must_use]
+    pub const fn is_nan_signaling(self) -> bool {
+        self.is_nan() && self.0 & Self::QUIET_BIT == 0
     }
-}
 
-impl From<f16> for f64 {
+    /// Returns `true` if this value is a quiet `NaN`.
+    ///
+    /// Following IEEE 754-2008, a `NaN` is quiet if the most significant bit
+    /// of the mantissa, the quiet bit, is set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert!(f16::NAN.is_nan_quiet());
+    /// assert!(!f16::SIGNALING_NAN.is_nan_quiet());
+    /// assert!(!f16::ONE.is_nan_quiet());
+    /// ```
     #[inline]
-    fn from(x: f16) -> f64 {
-        x.to_f64()
+    #[must_use]
+    pub const fn is_nan_quiet(self) -> bool {
+        self.is_nan() && self.0 & Self::QUIET_BIT != 0
     }
-}
 
-impl From<i8> for f16 {
+    /// Sets the quiet bit of a signaling `NaN`, keeping its sign and payload.
+    ///
+    /// All other values, including quiet `NaN` values, are returned
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let nan = -f16::from_bits(0x7C01);
+    /// assert!(nan.is_nan_signaling());
+    /// assert_eq!(nan.quiet().to_bits(), 0xFE01);
+    ///
+    /// assert_eq!(f16::NAN.quiet().to_bits(), f16::NAN.to_bits());
+    /// assert_eq!(f16::ONE.quiet(), f16::ONE);
+    /// ```
     #[inline]
-    fn from(x: i8) -> f16 {
-        // Convert to f32, then to f16
-        f16::from_f32(f32::from(x))
+    #[must_use]
+    pub const fn quiet(self) -> f16 {
+        if self.is_nan() {
+            f16(self.0 | Self::QUIET_BIT)
+        } else {
+            self
+        }
     }
-}
 
-impl From<u8> for f16 {
+    /// Constructs a quiet `NaN` carrying `payload` in the 9 mantissa bits
+    /// below the quiet bit.
+    ///
+    /// Returns [`None`] if the payload does not fit in 9 bits. The payload is
+    /// kept by [`to_bits`][Self::to_bits], and, quieted, by the conversions
+    /// to and from [`f32`] and [`f64`] and by arithmetic with a single `NaN`
+    /// operand, following the hardware. With two `NaN` operands, which
+    /// payload is kept is unspecified, and with the `canonical-nan` feature,
+    /// conversions and arithmetic replace it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let nan = f16::nan_with_payload(0x1AB).unwrap();
+    /// assert!(nan.is_nan_quiet());
+    /// assert_eq!(nan.to_bits(), 0x7FAB);
+    /// assert_eq!(nan.nan_payload(), Some(0x1AB));
+    ///
+    /// assert_eq!(f16::nan_with_payload(0).map(f16::to_bits), Some(0x7E00));
+    /// assert_eq!(f16::nan_with_payload(0x200), None);
+    /// ```
     #[inline]
-    fn from(x: u8) -> f16 {
-        // Convert to f32, then to f16
-        f16::from_f32(f32::from(x))
+    #[must_use]
+    pub const fn nan_with_payload(payload: u16) -> Option<f16> {
+        if payload > Self::PAYLOAD_MASK {
+            None
+        } else {
+            Some(f16(Self::NAN.0 | payload))
+        }
+    }
+
+    /// Constructs a signaling `NaN` carrying `payload` in the 9 mantissa bits
+    /// below the quiet bit.
+    ///
+    /// Returns [`None`] if the payload does not fit in 9 bits, or if it is
+    /// zero, since a signaling `NaN` with no payload is an infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let nan = f16::signaling_nan_with_payload(0x100).unwrap();
+    /// assert!(nan.is_nan_signaling());
+    /// assert_eq!(nan.to_bits(), f16::SIGNALING_NAN.to_bits());
+    ///
+    /// assert_eq!(f16::signaling_nan_with_payload(0), None);
+    /// assert_eq!(f16::signaling_nan_with_payload(0x200), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn signaling_nan_with_payload(payload: u16) -> Option<f16> {
+        if payload == 0 || payload > Self::PAYLOAD_MASK {
+            None
+        } else {
+            Some(f16(Self::EXP_MASK | payload))
+        }
+    }
+
+    /// Returns the payload of a `NaN`, the 9 mantissa bits below the quiet
+    /// bit, or [`None`] if the value is not `NaN`.
+    ///
+    /// The sign and the quiet bit are not part of the payload.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(f16::from_bits(0xFE05).nan_payload(), Some(5));
+    /// assert_eq!(f16::SIGNALING_NAN.nan_payload(), Some(0x100));
+    /// assert_eq!(f16::NAN.nan_payload(), Some(0));
+    /// assert_eq!(f16::INFINITY.nan_payload(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn nan_payload(self) -> Option<u16> {
+        if self.is_nan() {
+            Some(self.0 & Self::PAYLOAD_MASK)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the canonical form of `self`, for hashing or serializing
+    /// values deterministically.
+    ///
+    /// Every `NaN`, of either sign, with any payload, quiet or signaling, is
+    /// replaced by [`NAN`][Self::NAN], and all other values, including −0,
+    /// are returned unchanged. See
+    /// [`canonicalize_zero`][Self::canonicalize_zero] to also replace −0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let nan = -f16::signaling_nan_with_payload(0x2A).unwrap();
+    /// assert_eq!(nan.canonicalize().to_bits(), f16::NAN.to_bits());
+    ///
+    /// assert_eq!(f16::NEG_ZERO.canonicalize().to_bits(), 0x8000);
+    /// assert_eq!(f16::MAX.canonicalize(), f16::MAX);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn canonicalize(self) -> f16 {
+        if self.is_nan() {
+            Self::NAN
+        } else {
+            self
+        }
+    }
+
+    /// Returns the canonical form of `self`, as with
+    /// [`canonicalize`][Self::canonicalize], but also replaces −0 with +0.
+    ///
+    /// Values that compare equal have the same canonical form, and every
+    /// `NaN` has the same canonical form, so the bits of the result can be
+    /// hashed or compared in place of the value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(f16::NEG_ZERO.canonicalize_zero().to_bits(), 0);
+    /// assert_eq!((-f16::NAN).canonicalize_zero().to_bits(), 0x7E00);
+    /// assert_eq!(f16::NEG_ONE.canonicalize_zero(), f16::NEG_ONE);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn canonicalize_zero(self) -> f16 {
+        if self.is_nan() {
+            Self::NAN
+        } else if self.0 == Self::SIGN_MASK {
+            Self::ZERO
+        } else {
+            self
+        }
+    }
+
+    /// Computes the absolute value of `self`.
+    #[must_use]
+    #[inline(always)]
+    pub const fn abs(self) -> Self {
+        Self(self.0 & !Self::SIGN_MASK)
+    }
+
+    /// Returns `true` if this value is ±∞ and `false`.
+    /// otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    ///
+    /// let f = f16::from_f32(7.0f32);
+    /// let inf = f16::INFINITY;
+    /// let neg_inf = f16::NEG_INFINITY;
+    /// let nan = f16::NAN;
+    ///
+    /// assert!(!f.is_infinite());
+    /// assert!(!nan.is_infinite());
+    ///
+    /// assert!(inf.is_infinite());
+    /// assert!(neg_inf.is_infinite());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_infinite(self) -> bool {
+        self.0 & Self::NOT_SIGN == Self::EXP_MASK
+    }
+
+    /// Returns `true` if this number is neither infinite nor `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    ///
+    /// let f = f16::from_f32(7.0f32);
+    /// let inf = f16::INFINITY;
+    /// let neg_inf = f16::NEG_INFINITY;
+    /// let nan = f16::NAN;
+    ///
+    /// assert!(f.is_finite());
+    ///
+    /// assert!(!nan.is_finite());
+    /// assert!(!inf.is_finite());
+    /// assert!(!neg_inf.is_finite());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_finite(self) -> bool {
+        self.0 & Self::EXP_MASK != Self::EXP_MASK
+    }
+
+    /// Returns `true` if the number is [subnormal].
+    ///
+    /// [subnormal]: https://en.wikipedia.org/wiki/Denormal_number
+    #[must_use]
+    #[inline(always)]
+    pub const fn is_subnormal(self) -> bool {
+        matches!(self.classify(), FpCategory::Subnormal)
+    }
+
+    /// Returns `true` if the number is neither zero, infinite, subnormal, or
+    /// `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    ///
+    /// let min = f16::MIN_POSITIVE;
+    /// let max = f16::MAX;
+    /// let lower_than_min = f16::from_f32(1.0e-10_f32);
+    /// let zero = f16::from_f32(0.0_f32);
+    ///
+    /// assert!(min.is_normal());
+    /// assert!(max.is_normal());
+    ///
+    /// assert!(!zero.is_normal());
+    /// assert!(!f16::NAN.is_normal());
+    /// assert!(!f16::INFINITY.is_normal());
+    /// // Values between `0` and `min` are Subnormal.
+    /// assert!(!lower_than_min.is_normal());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_normal(self) -> bool {
+        let exp = self.0 & Self::EXP_MASK;
+        exp != Self::EXP_MASK && exp != 0
+    }
+
+    /// Returns `true` if this value is positive or negative zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// const IS_ZERO: bool = f16::NEG_ZERO.is_zero();
+    /// assert!(IS_ZERO);
+    /// assert!(f16::ZERO.is_zero());
+    /// assert!(!f16::MIN_POSITIVE_SUBNORMAL.is_zero());
+    /// assert!(!f16::NAN.is_zero());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_zero(self) -> bool {
+        self.0 & Self::NOT_SIGN == 0
+    }
+
+    /// Returns `true` if this value is a finite integer, including zero.
+    ///
+    /// Every finite value with a magnitude of at least `2^10` is an
+    /// integer. Infinite and NaN values are not integers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert!(f16::from_f32(-3.).is_integer());
+    /// assert!(f16::NEG_ZERO.is_integer());
+    /// assert!(f16::from_f32(1024.).is_integer());
+    /// assert!(!f16::from_f32(2.5).is_integer());
+    /// assert!(!f16::MIN_POSITIVE_SUBNORMAL.is_integer());
+    /// assert!(!f16::INFINITY.is_integer());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_integer(self) -> bool {
+        let mag = self.0 & Self::NOT_SIGN;
+        // The power of two of the leading bit, for normal values.
+        let exp = (mag >> 10) as i32 - 15;
+        if mag >= Self::EXP_MASK {
+            false
+        } else if mag == 0 || exp >= 10 {
+            true
+        } else if exp < 0 {
+            false
+        } else {
+            // The mantissa bits below the units bit must be zero.
+            mag & (Self::MAN_MASK >> exp) == 0
+        }
+    }
+
+    /// Returns `true` if this value is an odd integer.
+    ///
+    /// This is useful for the special cases of powers, where the sign of the
+    /// result depends on whether the exponent is an odd integer. Values with a
+    /// magnitude of at least `2^11` are even.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert!(f16::from_f32(-3.).is_odd_integer());
+    /// assert!(f16::ONE.is_odd_integer());
+    /// assert!(!f16::from_f32(2.).is_odd_integer());
+    /// assert!(!f16::from_f32(1.5).is_odd_integer());
+    /// assert!(!f16::NAN.is_odd_integer());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_odd_integer(self) -> bool {
+        let mag = self.0 & Self::NOT_SIGN;
+        match (mag >> 10) as i32 - 15 {
+            // The units bit is the implicit leading bit for `1`, and the
+            // lowest bit of the mantissa for `2^10`.
+            exp @ 0..=10 => {
+                let significand = (mag & Self::MAN_MASK) | (Self::MAN_MASK + 1);
+                self.is_integer() && (significand >> (10 - exp)) & 1 == 1
+            },
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if this value is an even integer, including zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert!(f16::from_f32(-4.).is_even_integer());
+    /// assert!(f16::ZERO.is_even_integer());
+    /// assert!(f16::MAX.is_even_integer());
+    /// assert!(!f16::ONE.is_even_integer());
+    /// assert!(!f16::from_f32(0.5).is_even_integer());
+    /// assert!(!f16::INFINITY.is_even_integer());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_even_integer(self) -> bool {
+        self.is_integer() && !self.is_odd_integer()
+    }
+
+    /// Returns the raw 5-bit exponent field, with the bias of 15.
+    ///
+    /// This is `0` for zero and subnormal values, and `31` for
+    /// infinite and NaN values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(f16::ONE.biased_exponent(), 15);
+    /// assert_eq!(f16::from_f32(-6.5).biased_exponent(), 17);
+    /// assert_eq!(f16::MIN_POSITIVE_SUBNORMAL.biased_exponent(), 0);
+    /// assert_eq!(f16::INFINITY.biased_exponent(), 31);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn biased_exponent(self) -> u16 {
+        (self.0 & Self::EXP_MASK) >> 10
+    }
+
+    /// Returns the exponent field without the bias of 15.
+    ///
+    /// For normal values, this is the power of two of the leading bit, so
+    /// the value is `±1.mantissa × 2^exponent`. Zero and subnormal values
+    /// return `-14`, the minimum normal exponent, since they are
+    /// `±0.mantissa × 2^-14`. Infinite and NaN values return `16`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(f16::ONE.unbiased_exponent(), 0);
+    /// assert_eq!(f16::from_f32(6.5).unbiased_exponent(), 2);
+    /// assert_eq!(f16::from_f32(0.25).unbiased_exponent(), -2);
+    /// assert_eq!(f16::MIN_POSITIVE_SUBNORMAL.unbiased_exponent(), -14);
+    /// assert_eq!(f16::MIN_POSITIVE.unbiased_exponent(), -14);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn unbiased_exponent(self) -> i16 {
+        let exp = self.biased_exponent() as i16;
+        if exp == 0 {
+            -14
+        } else {
+            exp - 15
+        }
+    }
+
+    /// Returns the raw 10-bit mantissa field, without the implicit
+    /// leading bit of normal values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(f16::ONE.mantissa(), 0);
+    /// assert_eq!(f16::from_f32(6.5).mantissa(), 0x280);
+    /// assert_eq!(f16::MIN_POSITIVE_SUBNORMAL.mantissa(), 1);
+    /// assert_eq!(f16::MAX.mantissa(), 0x3ff);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn mantissa(self) -> u16 {
+        self.0 & Self::MAN_MASK
+    }
+
+    /// Multiplies the value by `2^n`, like `scalbn` or `ldexp` in C.
+    ///
+    /// The exponent bits are adjusted directly, so this is exact unless the
+    /// result overflows to infinity, above a binary exponent of 15, or falls
+    /// into the subnormal range, below -14, where it is rounded to the
+    /// nearest value, ties to even, which may be zero. Zero, infinite and
+    /// NaN values are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(f16::from_f32(3.).scale(4), f16::from_f32(48.));
+    /// assert_eq!(f16::MIN_POSITIVE.scale(-10), f16::MIN_POSITIVE_SUBNORMAL);
+    /// assert_eq!(f16::MIN_POSITIVE_SUBNORMAL.scale(24), f16::ONE);
+    /// assert_eq!(f16::MAX.scale(1), f16::INFINITY);
+    /// assert_eq!(f16::ONE.scale(-26), f16::ZERO);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn scale(self, n: i32) -> f16 {
+        let sign = self.0 & Self::SIGN_MASK;
+        let abs = self.0 & Self::NOT_SIGN;
+        if abs == 0 || abs >= Self::EXP_MASK || n == 0 {
+            return self;
+        }
+
+        // Normalize subnormal values, so the significand has its leading bit
+        // at the hidden bit.
+        let mut exp = (abs >> 10) as i32;
+        let mut man = abs & Self::MAN_MASK;
+        if exp == 0 {
+            exp = 1;
+            while man & Self::HIDDEN_BIT_MASK == 0 {
+                man <<= 1;
+                exp -= 1;
+            }
+        } else {
+            man |= Self::HIDDEN_BIT_MASK;
+        }
+
+        // Any larger shift overflows or underflows, and this keeps the sum
+        // from overflowing.
+        let n = if n > 64 {
+            64
+        } else if n < -64 {
+            -64
+        } else {
+            n
+        };
+        let exp = exp + n;
+        if exp > 30 {
+            f16(sign | Self::EXP_MASK)
+        } else if exp > 0 {
+            f16(sign | ((exp as u16) << 10) | (man & Self::MAN_MASK))
+        } else {
+            // Subnormal, rounded to nearest, ties to even. A carry into the
+            // hidden bit gives the minimum normal value.
+            let shift = (1 - exp) as u32;
+            if shift > 11 {
+                return f16(sign);
+            }
+            let kept = man >> shift;
+            let rem = man & ((1 << shift) - 1);
+            let half = 1 << (shift - 1);
+            let round = (rem > half || (rem == half && kept & 1 == 1)) as u16;
+            f16(sign | (kept + round))
+        }
+    }
+
+    /// Flushes a [subnormal] value to zero, keeping the sign.
+    ///
+    /// All other values, including NaN, are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(f16::MIN_POSITIVE_SUBNORMAL.ftz().to_bits(), f16::ZERO.to_bits());
+    /// assert_eq!((-f16::MIN_POSITIVE_SUBNORMAL).ftz().to_bits(), f16::NEG_ZERO.to_bits());
+    /// assert_eq!(f16::MIN_POSITIVE.ftz(), f16::MIN_POSITIVE);
+    /// ```
+    ///
+    /// [subnormal]: https://en.wikipedia.org/wiki/Denormal_number
+    #[inline]
+    #[must_use]
+    pub const fn ftz(self) -> f16 {
+        if self.0 & Self::EXP_MASK == 0 {
+            f16(self.0 & 0x8000)
+        } else {
+            self
+        }
+    }
+
+    /// Returns the floating point category of the number.
+    ///
+    /// If only one property is going to be tested, it is generally faster to
+    /// use the specific predicate instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::num::FpCategory;
+    /// # use float16::*;
+    ///
+    /// let num = f16::from_f32(12.4_f32);
+    /// let inf = f16::INFINITY;
+    ///
+    /// assert_eq!(num.classify(), FpCategory::Normal);
+    /// assert_eq!(inf.classify(), FpCategory::Infinite);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn classify(self) -> FpCategory {
+        let exp = self.0 & Self::EXP_MASK;
+        let man = self.0 & Self::MAN_MASK;
+        match (exp, man) {
+            (0, 0) => FpCategory::Zero,
+            (0, _) => FpCategory::Subnormal,
+            (Self::EXP_MASK, 0) => FpCategory::Infinite,
+            (Self::EXP_MASK, _) => FpCategory::Nan,
+            _ => FpCategory::Normal,
+        }
+    }
+
+    /// Returns a number that represents the sign of `self`.
+    ///
+    /// * `1.0` if the number is positive, `+0.0` or [`INFINITY`][f16::INFINITY]
+    /// * `-1.0` if the number is negative, `-0.0` or
+    ///   [`NEG_INFINITY`][f16::NEG_INFINITY]
+    /// * [`NAN`][f16::NAN] if the number is `NaN`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    ///
+    /// let f = f16::from_f32(3.5_f32);
+    ///
+    /// assert_eq!(f.signum(), f16::from_f32(1.0));
+    /// assert_eq!(f16::NEG_INFINITY.signum(), f16::from_f32(-1.0));
+    ///
+    /// assert!(f16::NAN.signum().is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn signum(self) -> f16 {
+        if self.is_nan() {
+            self
+        } else if self.0 & Self::SIGN_MASK != 0 {
+            Self::NEG_ONE
+        } else {
+            Self::ONE
+        }
+    }
+
+    /// Returns `true` if and only if `self` has a positive sign, including
+    /// `+0.0`, `NaNs` with a positive sign bit and +∞.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    ///
+    /// let nan = f16::NAN;
+    /// let f = f16::from_f32(7.0_f32);
+    /// let g = f16::from_f32(-7.0_f32);
+    ///
+    /// assert!(f.is_sign_positive());
+    /// assert!(!g.is_sign_positive());
+    /// // `NaN` can be either positive or negative
+    /// assert!(nan.is_sign_positive() != nan.is_sign_negative());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_sign_positive(self) -> bool {
+        self.0 & Self::SIGN_MASK == 0
+    }
+
+    /// Returns `true` if and only if `self` has a negative sign, including
+    /// `-0.0`, `NaNs` with a negative sign bit and −∞.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    ///
+    /// let nan = f16::NAN;
+    /// let f = f16::from_f32(7.0f32);
+    /// let g = f16::from_f32(-7.0f32);
+    ///
+    /// assert!(!f.is_sign_negative());
+    /// assert!(g.is_sign_negative());
+    /// // `NaN` can be either positive or negative
+    /// assert!(nan.is_sign_positive() != nan.is_sign_negative());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_sign_negative(self) -> bool {
+        self.0 & Self::SIGN_MASK != 0
+    }
+
+    /// Returns a number composed of the magnitude of `self` and the sign of
+    /// `sign`.
+    ///
+    /// Equal to `self` if the sign of `self` and `sign` are the same, otherwise
+    /// equal to `-self`. If `self` is NaN, then NaN with the sign of `sign`
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let f = f16::from_f32(3.5);
+    ///
+    /// assert_eq!(f.copysign(f16::from_f32(0.42)), f16::from_f32(3.5));
+    /// assert_eq!(f.copysign(f16::from_f32(-0.42)), f16::from_f32(-3.5));
+    /// assert_eq!((-f).copysign(f16::from_f32(0.42)), f16::from_f32(3.5));
+    /// assert_eq!((-f).copysign(f16::from_f32(-0.42)), f16::from_f32(-3.5));
+    ///
+    /// assert!(f16::NAN.copysign(f16::from_f32(1.0)).is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn copysign(self, sign: f16) -> f16 {
+        f16((sign.0 & Self::SIGN_MASK) | (self.0 & Self::NOT_SIGN))
+    }
+
+    /// Takes the reciprocal (inverse) of a number, `1/x`.
+    #[must_use]
+    #[inline(always)]
+    pub fn recip(self) -> Self {
+        Self::ONE / self
+    }
+
+    /// Computes the sum of a slice of values.
+    ///
+    /// This is identical to [`Iterator::sum`]: the values are summed in [`f32`]
+    /// precision, in order, and the result is rounded once. Both convert the
+    /// values to [`f32`] in chunks, using the vectorized conversions when
+    /// hardware support is available. On `aarch64` targets with the `fp16`
+    /// feature, both use the native half-precision arithmetic instead.
+    /// An empty slice sums to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let values = [f16::from_f32(1.5), f16::from_f32(2.0), f16::from_f32(-0.25)];
+    /// assert_eq!(f16::sum_slice(&values), f16::from_f32(3.25));
+    /// assert_eq!(f16::sum_slice(&values), values.iter().sum());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sum_slice(values: &[f16]) -> f16 {
+        f16(arch::canonical_nan(arch::sum_f16_slice(values.reinterpret_cast())))
+    }
+
+    /// Computes the product of a slice of values.
+    ///
+    /// This is identical to [`Iterator::product`]: the values are multiplied in
+    /// [`f32`] precision, in order, and the result is rounded once. Both
+    /// convert the values to [`f32`] in chunks, using the vectorized
+    /// conversions when hardware support is available. On `aarch64` targets
+    /// with the `fp16` feature, both use the native half-precision
+    /// arithmetic instead. The product of an empty slice is one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let values = [f16::from_f32(1.5), f16::from_f32(2.0), f16::from_f32(-0.25)];
+    /// assert_eq!(f16::product_slice(&values), f16::from_f32(-0.75));
+    /// assert_eq!(f16::product_slice(&values), values.iter().product());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn product_slice(values: &[f16]) -> f16 {
+        f16(arch::canonical_nan(arch::product_f16_slice(values.reinterpret_cast())))
+    }
+
+    /// Computes a fast estimate of the reciprocal (inverse) of a number,
+    /// `1/x`.
+    ///
+    /// On `aarch64` targets with the `fp16` feature, this uses the hardware
+    /// reciprocal estimate instruction, which is only accurate to about 8
+    /// bits. Otherwise, this computes `1/x` in [`f32`] precision, which is
+    /// exact to within rounding. Use [`recip_refined`][Self::recip_refined]
+    /// for a more accurate result that is still fast on hardware.
+    ///
+    /// `±0` results in `±∞`, `±∞` results in `±0`, and subnormals whose
+    /// reciprocal is too large result in `±∞`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let x = f16::from_f32(4.0);
+    /// assert!((x.recip_estimate() - f16::from_f32(0.25)).abs() <= f16::from_f32(0.25 / 256.0));
+    /// assert_eq!(f16::ZERO.recip_estimate(), f16::INFINITY);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn recip_estimate(self) -> Self {
+        f16(arch::canonical_nan(arch::recip_estimate_f16(self.0)))
+    }
+
+    /// Computes the reciprocal (inverse) of a number, `1/x`, by refining
+    /// [`recip_estimate`][Self::recip_estimate] with one Newton-Raphson step.
+    ///
+    /// On `aarch64` targets with the `fp16` feature, this uses the hardware
+    /// reciprocal estimate and step instructions, and the result is within 1
+    /// ulp of [`recip`][Self::recip] for normal values. Otherwise, this is
+    /// identical to [`recip`][Self::recip].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let x = f16::from_f32(4.0);
+    /// assert_eq!(x.recip_refined(), f16::from_f32(0.25));
+    /// assert_eq!(f16::NEG_INFINITY.recip_refined(), f16::NEG_ZERO);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn recip_refined(self) -> Self {
+        f16(arch::canonical_nan(arch::recip_refined_f16(self.0)))
+    }
+
+    /// Computes the IEEE 754 remainder of `self` divided by `rhs`.
+    ///
+    /// This is `self - n * rhs`, where `n` is the quotient `self / rhs`
+    /// rounded to the nearest integer with ties to even, so the result is in
+    /// `[-|rhs|/2, |rhs|/2]`. This differs from the `%` operator, which
+    /// truncates the quotient like C's `fmod`. The result is always exact, and
+    /// is computed in [`f64`] precision without any intermediate rounding.
+    ///
+    /// Returns NaN if either value is NaN, `self` is infinite, or `rhs` is
+    /// zero, and `self` if `rhs` is infinite and `self` is finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let two = f16::from_f32(2.0);
+    /// assert_eq!(f16::from_f32(5.0).ieee_rem(two), f16::ONE);
+    /// assert_eq!(f16::from_f32(7.0).ieee_rem(two), f16::NEG_ONE);
+    /// assert_eq!(f16::from_f32(7.0) % two, f16::ONE);
+    /// assert!(f16::ONE.ieee_rem(f16::ZERO).is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn ieee_rem(self, rhs: f16) -> f16 {
+        let x = self.to_f64();
+        let y = f64::from_bits(rhs.to_f64().to_bits() & !(1 << 63));
+        // Reduce modulo `2 * |rhs|` to keep the parity of the quotient, then
+        // round the remaining quotient in `[0, 2)` to nearest, ties to even.
+        // Every step is exact, since the operands only have 11 significant
+        // bits and exponents differing by at most 40.
+        let mut r = f64::from_bits((x % (y + y)).to_bits() & !(1 << 63));
+        if r + r > y {
+            r -= y;
+            if r + r >= y {
+                r -= y;
+            }
+        }
+        let sign = x.to_bits() & (1 << 63);
+        f16::from_f64(f64::from_bits(r.to_bits() ^ sign))
+    }
+
+    /// Computes `self + rhs`, returning `None` if the result is not finite.
+    ///
+    /// This returns `None` if either value is NaN, if the sum overflows to
+    /// ±∞, or if it is NaN, as `∞ + -∞` is. Otherwise, this returns the same
+    /// value as the `+` operator, so checked operations can be chained with
+    /// `?` and only ever produce finite values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// assert_eq!(f16::ONE.checked_add(f16::ONE), Some(f16::from_f32(2.0)));
+    /// assert_eq!(f16::MAX.checked_add(f16::MAX), None);
+    /// assert_eq!(f16::INFINITY.checked_add(f16::ONE), None);
+    ///
+    /// fn axpy(a: f16, x: f16, y: f16) -> Option<f16> {
+    ///     a.checked_mul(x)?.checked_add(y)
+    /// }
+    /// assert_eq!(axpy(f16::from_f32(2.0), f16::ONE, f16::ONE), Some(f16::from_f32(3.0)));
+    /// assert_eq!(axpy(f16::MAX, f16::MAX, f16::MIN), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_add(self, rhs: f16) -> Option<f16> {
+        let value = self + rhs;
+        if value.is_finite() {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Computes `self - rhs`, returning `None` if the result is not finite.
+    ///
+    /// This returns `None` if either value is NaN, if the difference
+    /// overflows to ±∞, or if it is NaN, as `∞ - ∞` is. Otherwise, this
+    /// returns the same value as the `-` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// assert_eq!(f16::ONE.checked_sub(f16::from_f32(0.5)), Some(f16::from_f32(0.5)));
+    /// assert_eq!(f16::MIN.checked_sub(f16::MAX), None);
+    /// assert_eq!(f16::NAN.checked_sub(f16::ONE), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_sub(self, rhs: f16) -> Option<f16> {
+        let value = self - rhs;
+        if value.is_finite() {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Computes `self * rhs`, returning `None` if the result is not finite.
+    ///
+    /// This returns `None` if either value is NaN, if the product overflows
+    /// to ±∞, or if it is NaN, as `0 * ∞` is. Otherwise, this returns the
+    /// same value as the `*` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// assert_eq!(f16::from_f32(3.0).checked_mul(f16::from_f32(0.5)), Some(f16::from_f32(1.5)));
+    /// assert_eq!(f16::MAX.checked_mul(f16::from_f32(2.0)), None);
+    /// assert_eq!(f16::ZERO.checked_mul(f16::INFINITY), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_mul(self, rhs: f16) -> Option<f16> {
+        let value = self * rhs;
+        if value.is_finite() {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Computes `self / rhs`, returning `None` if the result is not finite.
+    ///
+    /// This returns `None` if either value is NaN, if the quotient is ±∞,
+    /// as it is for a non-zero value divided by zero, or if it is NaN, as
+    /// `0 / 0` is. Otherwise, this returns the same value as the `/`
+    /// operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// assert_eq!(f16::ONE.checked_div(f16::from_f32(4.0)), Some(f16::from_f32(0.25)));
+    /// assert_eq!(f16::ONE.checked_div(f16::ZERO), None);
+    /// assert_eq!(f16::ONE.checked_div(f16::INFINITY), Some(f16::ZERO));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_div(self, rhs: f16) -> Option<f16> {
+        let value = self / rhs;
+        if value.is_finite() {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Computes `self % rhs`, returning `None` if the result is not finite.
+    ///
+    /// The result is NaN, and so this returns `None`, if either value is NaN,
+    /// `self` is infinite, or `rhs` is zero. Otherwise, this returns the same
+    /// value as the `%` operator, including `self` when `rhs` is infinite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let two = f16::from_f32(2.0);
+    /// assert_eq!(f16::from_f32(7.0).checked_rem(two), Some(f16::ONE));
+    /// assert_eq!(f16::ONE.checked_rem(f16::INFINITY), Some(f16::ONE));
+    /// assert_eq!(f16::ONE.checked_rem(f16::ZERO), None);
+    /// assert_eq!(f16::INFINITY.checked_rem(two), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_rem(self, rhs: f16) -> Option<f16> {
+        let value = self % rhs;
+        if value.is_finite() {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Computes `-self`, returning `None` if the result is not finite.
+    ///
+    /// Like the other checked operations, this returns `None` for NaN and ±∞
+    /// even though negation is always exact, so a chain of checked operations
+    /// only produces finite values. Otherwise, this returns the same value as
+    /// the `-` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// assert_eq!(f16::ONE.checked_neg(), Some(f16::NEG_ONE));
+    /// assert_eq!(f16::ZERO.checked_neg().map(f16::to_bits), Some(f16::NEG_ZERO.to_bits()));
+    /// assert_eq!(f16::INFINITY.checked_neg(), None);
+    /// assert_eq!(f16::NAN.checked_neg(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn checked_neg(self) -> Option<f16> {
+        if self.is_finite() {
+            Some(f16(self.0 ^ Self::SIGN_MASK))
+        } else {
+            None
+        }
+    }
+
+    /// Converts radians to degrees.
+    #[must_use]
+    #[inline(always)]
+    pub fn to_degrees(self) -> Self {
+        self * Self::from(180u8) / Self::PI
+    }
+
+    /// Converts degrees to radians.
+    #[must_use]
+    #[inline(always)]
+    pub fn to_radians(self) -> Self {
+        self * Self::PI / Self::from(180u8)
+    }
+
+    /// Computes the reciprocal square root of a number, `1/sqrt(x)`.
+    ///
+    /// On `aarch64` with the `fp16` target feature, this refines the hardware
+    /// reciprocal square root estimate with one Newton-Raphson step, and the
+    /// result is within 1 ulp of the exact value for normal values.
+    /// Otherwise, this is computed as `1.0 / x.sqrt()` in [`f32`] and then
+    /// rounded to the nearest [`struct@f16`] value.
+    ///
+    /// Returns `+∞` for `+0`, `-∞` for `-0`, `+0` for `+∞`, and NaN for
+    /// negative values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let x = f16::from_f32(4.0);
+    /// assert_eq!(x.rsqrt(), f16::from_f32(0.5));
+    /// assert_eq!(f16::ZERO.rsqrt(), f16::INFINITY);
+    /// assert!(f16::NEG_ONE.rsqrt().is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn rsqrt(self) -> Self {
+        f16(arch::canonical_nan(arch::rsqrt_f16(self.0)))
+    }
+
+    /// Linearly interpolates between `self` and `other` by `t`.
+    ///
+    /// This is computed in [`f32`], where each operation rounds, and then
+    /// rounded to the nearest [`struct@f16`] value, so it may differ from the
+    /// exactly rounded [`lerp_const`][Self::lerp_const] in the last place. The
+    /// result is exactly `self` when `t` is `0` and exactly `other` when `t`
+    /// is `1`, and is monotonic in `t`. Values of `t` outside of `[0, 1]`
+    /// extrapolate beyond the endpoints, and NaN in any argument returns NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let a = f16::from_f32(1.0);
+    /// let b = f16::from_f32(3.0);
+    /// assert_eq!(a.lerp(b, f16::ZERO), a);
+    /// assert_eq!(a.lerp(b, f16::from_f32(0.25)), f16::from_f32(1.5));
+    /// assert_eq!(a.lerp(b, f16::ONE), b);
+    /// assert_eq!(a.lerp(b, f16::from_f32(2.0)), f16::from_f32(5.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn lerp(self, other: f16, t: f16) -> f16 {
+        let (a, b, t) = (self.to_f32(), other.to_f32(), t.to_f32());
+        // Interpolate from the nearer endpoint, so both endpoints are exact.
+        let value = if t <= 0.5 {
+            a + t * (b - a)
+        } else {
+            b - (1.0 - t) * (b - a)
+        };
+        f16::from_f32(value)
+    }
+
+    /// Linearly interpolates between `self` and `other` by `t`, in a `const`
+    /// context.
+    ///
+    /// This computes `self + t * (other - self)` exactly with integer
+    /// arithmetic and rounds once to the nearest [`struct@f16`] value, with
+    /// ties to even, so it can differ from [`lerp`][Self::lerp] in the last
+    /// place. The result is exactly `self` when `t` is `0` and exactly `other`
+    /// when `t` is `1`, and is monotonic in `t`. NaN in any argument returns
+    /// NaN, as do infinite arguments unless `t` selects an endpoint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// const A: f16 = f16::ONE;
+    /// const B: f16 = f16::from_f32_const(3.0);
+    /// const MID: f16 = A.lerp_const(B, f16::from_f32_const(0.5));
+    /// assert_eq!(MID, f16::from_f32(2.0));
+    /// assert_eq!(A.lerp_const(B, f16::ZERO), A);
+    /// assert_eq!(A.lerp_const(B, f16::ONE), B);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn lerp_const(self, other: f16, t: f16) -> f16 {
+        f16(exact::lerp(self.0, other.0, t.0, 5, 10))
+    }
+
+    /// Returns if the integer `n` is exactly representable as a
+    /// [`struct@f16`].
+    ///
+    /// Every integer with a magnitude up to
+    /// [`MAX_CONSECUTIVE_INTEGER`][Self::MAX_CONSECUTIVE_INTEGER] is
+    /// representable, while larger integers are only representable if they
+    /// fit in the significand and do not overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// assert!(f16::can_represent_integer(2048));
+    /// assert!(!f16::can_represent_integer(2049));
+    /// assert!(f16::can_represent_integer(-2048 - 2));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn can_represent_integer(n: i64) -> bool {
+        let n = n.unsigned_abs();
+        if n == 0 {
+            return true;
+        }
+        let width = 64 - n.leading_zeros();
+        let significant = width - n.trailing_zeros();
+        width <= Self::MAX_EXP as u32 && significant <= Self::MANTISSA_DIGITS
+    }
+
+    /// Adds one to an integer value, returning `None` if `self` is not an
+    /// integer or the result is not exactly representable.
+    ///
+    /// This is intended for counters, which stop working once adding one
+    /// would round back to the same value or skip an integer. Values beyond
+    /// [`MAX_CONSECUTIVE_INTEGER`][Self::MAX_CONSECUTIVE_INTEGER] return
+    /// `None`, as do infinities and NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let x = f16::from_f32(2047.0);
+    /// assert_eq!(x.increment_integer(), Some(f16::MAX_CONSECUTIVE_INTEGER));
+    /// assert_eq!(f16::MAX_CONSECUTIVE_INTEGER.increment_integer(), None);
+    /// assert_eq!(f16::from_f32(0.5).increment_integer(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn increment_integer(self) -> Option<f16> {
+        let value = self.to_f32();
+        // Saturating casts of large or non-finite values do not round-trip.
+        let n = value as i64;
+        if n as f32 != value {
+            return None;
+        }
+        let next = n.checked_add(1)?;
+        if Self::can_represent_integer(next) {
+            Some(f16::from_f32(next as f32))
+        } else {
+            None
+        }
+    }
+
+    /// Rounds to the nearest integer, rounding half-way cases away from
+    /// zero.
+    ///
+    /// Values that are not exactly half-way between two integers round to
+    /// the nearest integer. The sign is preserved, so values in `(-0.5, 0)`
+    /// round to `-0`. Infinities, NaN and values that are already integers
+    /// are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// assert_eq!(f16::from_f32(2.5).round_ties_away(), f16::from_f32(3.0));
+    /// assert_eq!(f16::from_f32(-0.5).round_ties_away(), f16::from_f32(-1.0));
+    /// assert_eq!(f16::from_f32(1.25).round_ties_away(), f16::from_f32(1.0));
+    /// assert_eq!(f16::from_f32(-0.25).round_ties_away().to_bits(), f16::NEG_ZERO.to_bits());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn round_ties_away(self) -> f16 {
+        let sign = self.0 & Self::SIGN_MASK;
+        let biased = ((self.0 & Self::EXP_MASK) >> 10) as i32;
+        let exp = biased - 15;
+        if biased == 0x1F || exp >= 10 {
+            // Infinite, NaN, or so large that every value is an integer.
+            self
+        } else if exp < -1 {
+            // Less than 0.5 in magnitude.
+            f16(sign)
+        } else if exp == -1 {
+            // At least 0.5 but less than 1 in magnitude.
+            f16(sign | Self::ONE.0)
+        } else {
+            // Add half of the lowest integer bit to the magnitude, and then
+            // clear the fraction. A carry out of the mantissa increments the
+            // exponent, which is still correct.
+            let fraction_bits = (10 - exp) as u32;
+            let half = 1u16 << (fraction_bits - 1);
+            let mask = (1u16 << fraction_bits) - 1;
+            let magnitude = ((self.0 & !Self::SIGN_MASK) + half) & !mask;
+            f16(sign | magnitude)
+        }
+    }
+
+    /// Returns `e^(self)`, (the exponential function).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let one = f16::ONE;
+    /// assert_eq!(one.exp(), f16::E);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn exp(self) -> Self {
+        Self::from_f32(self.to_f32().exp())
+    }
+
+    /// Returns `2^(self)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let f = f16::from_f32(3.0);
+    /// assert_eq!(f.exp2(), f16::from_f32(8.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn exp2(self) -> Self {
+        Self::from_f32(self.to_f32().exp2())
+    }
+
+    /// Returns the natural logarithm of the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// assert_eq!(f16::E.ln(), f16::ONE);
+    /// assert!(f16::from_f32(-1.0).ln().is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn ln(self) -> Self {
+        Self::from_f32(self.to_f32().ln())
+    }
+
+    /// Returns the base 2 logarithm of the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let f = f16::from_f32(8.0);
+    /// assert_eq!(f.log2(), f16::from_f32(3.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn log2(self) -> Self {
+        Self::from_f32(self.to_f32().log2())
+    }
+
+    /// Returns the base 10 logarithm of the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let f = f16::from_f32(100.0);
+    /// assert_eq!(f.log10(), f16::from_f32(2.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn log10(self) -> Self {
+        Self::from_f32(self.to_f32().log10())
+    }
+
+    /// Returns `e^(self) - 1` in a way that is accurate even if the number is
+    /// close to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let x = f16::from_f32(1e-3);
+    /// // The Taylor series is exact to within the precision here.
+    /// let approx = x.to_f32() + x.to_f32() * x.to_f32() / 2.0;
+    /// assert_eq!(x.exp_m1(), f16::from_f32(approx));
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn exp_m1(self) -> Self {
+        Self::from_f32(self.to_f32().exp_m1())
+    }
+
+    /// Returns `ln(1+n)` (natural logarithm) more accurately than if the
+    /// operations were performed separately.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let x = f16::from_f32(1e-3);
+    /// // The Taylor series is exact to within the precision here.
+    /// let approx = x.to_f32() - x.to_f32() * x.to_f32() / 2.0;
+    /// assert_eq!(x.ln_1p(), f16::from_f32(approx));
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn ln_1p(self) -> Self {
+        Self::from_f32(self.to_f32().ln_1p())
+    }
+
+    /// Computes the sine of a number (in radians).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let x = f16::FRAC_PI_2;
+    /// assert_eq!(x.sin(), f16::ONE);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn sin(self) -> Self {
+        Self::from_f32(self.to_f32().sin())
+    }
+
+    /// Computes the cosine of a number (in radians).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let x = f16::ZERO;
+    /// assert_eq!(x.cos(), f16::ONE);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn cos(self) -> Self {
+        Self::from_f32(self.to_f32().cos())
+    }
+
+    /// Computes the tangent of a number (in radians).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let x = f16::FRAC_PI_4;
+    /// assert!((x.tan() - f16::ONE).abs() <= f16::EPSILON);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn tan(self) -> Self {
+        Self::from_f32(self.to_f32().tan())
+    }
+
+    /// Computes the arcsine of a number. Return value is in radians in the
+    /// range [-pi/2, pi/2] or NaN if the number is outside the range [-1, 1].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// assert_eq!(f16::ONE.asin(), f16::FRAC_PI_2);
+    /// assert!(f16::from_f32(2.0).asin().is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn asin(self) -> Self {
+        Self::from_f32(self.to_f32().asin())
+    }
+
+    /// Computes the arccosine of a number. Return value is in radians in the
+    /// range [0, pi] or NaN if the number is outside the range [-1, 1].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// assert_eq!(f16::NEG_ONE.acos(), f16::PI);
+    /// assert!(f16::from_f32(2.0).acos().is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn acos(self) -> Self {
+        Self::from_f32(self.to_f32().acos())
+    }
+
+    /// Computes the arctangent of a number. Return value is in radians in the
+    /// range [-pi/2, pi/2].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// assert_eq!(f16::ONE.atan(), f16::FRAC_PI_4);
+    /// assert_eq!(f16::INFINITY.atan(), f16::FRAC_PI_2);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn atan(self) -> Self {
+        Self::from_f32(self.to_f32().atan())
+    }
+
+    /// Computes the four quadrant arctangent of `self` (`y`) and `other` (`x`)
+    /// in radians.
+    ///
+    /// * `x = 0`, `y = 0`: `0`
+    /// * `x >= 0`: `arctan(y/x)` -> `[-pi/2, pi/2]`
+    /// * `y >= 0`: `arctan(y/x) + pi` -> `(pi/2, pi]`
+    /// * `y < 0`: `arctan(y/x) - pi` -> `(-pi, -pi/2)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let y = f16::NEG_ONE;
+    /// let x = f16::NEG_ONE;
+    /// assert_eq!(y.atan2(x), f16::from_f32(-3.0 * core::f32::consts::FRAC_PI_4));
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn atan2(self, other: Self) -> Self {
+        Self::from_f32(self.to_f32().atan2(other.to_f32()))
+    }
+
+    /// Computes the length of the hypotenuse of a right-angle triangle given
+    /// legs of length `self` and `other`, `sqrt(self² + other²)`.
+    ///
+    /// This is computed with [`f32::hypot`] and then rounded to the nearest
+    /// [`struct@f16`] value, so the squares never overflow or underflow, and
+    /// the result is only infinite if the exact result rounds to infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let a = f16::from_f32(3.0);
+    /// let b = f16::from_f32(4.0);
+    /// assert_eq!(a.hypot(b), f16::from_f32(5.0));
+    ///
+    /// // `300² + 400²` overflows, but the result does not.
+    /// let a = f16::from_f32(300.0);
+    /// let b = f16::from_f32(400.0);
+    /// assert!((a * a + b * b).is_infinite());
+    /// assert_eq!(a.hypot(b), f16::from_f32(500.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn hypot(self, other: Self) -> Self {
+        Self::from_f32(self.to_f32().hypot(other.to_f32()))
+    }
+
+    /// Simultaneously computes the sine and cosine of the number, `x`.
+    /// Returns `(sin(x), cos(x))`.
+    ///
+    /// Both are computed with a single [`f32::sin_cos`] call, and each is then
+    /// rounded to [`struct@f16`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let (sin, cos) = f16::FRAC_PI_2.sin_cos();
+    /// assert_eq!(sin, f16::ONE);
+    /// assert_eq!(cos, f16::FRAC_PI_2.cos());
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    pub fn sin_cos(self) -> (Self, Self) {
+        let (sin, cos) = self.to_f32().sin_cos();
+        (Self::from_f32(sin), Self::from_f32(cos))
+    }
+
+    /// Computes the sum of two numbers along with the rounding error of the
+    /// sum, as an error-free transformation.
+    ///
+    /// Returns `(hi, lo)`, where `hi` is `a + b` and `lo` is the exact
+    /// rounding error, so `hi + lo` is exactly the sum of `a` and `b`. The
+    /// error of a rounded sum is always exactly representable, so this is
+    /// exact whenever `hi` is finite. If `hi` is infinite or NaN, `lo` is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let a = f16::from_f32(2048.0);
+    /// let b = f16::from_f32(1.5);
+    /// let (hi, lo) = f16::two_sum(a, b);
+    ///
+    /// assert_eq!(hi, f16::from_f32(2050.0));
+    /// assert_eq!(lo, f16::from_f32(-0.5));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn two_sum(a: f16, b: f16) -> (f16, f16) {
+        let hi = a + b;
+        if !hi.is_finite() {
+            return (hi, Self::NAN);
+        }
+        // Every `f16` is a multiple of 2^-24 below 2^16, so this is exact.
+        let lo = a.to_f64() + b.to_f64() - hi.to_f64();
+        (hi, Self::from_f64(lo))
+    }
+
+    /// Computes the product of two numbers along with the rounding error of
+    /// the product, as an error-free transformation.
+    ///
+    /// Returns `(hi, lo)`, where `hi` is `a * b` and `lo` is the rounding
+    /// error. The product of two [`struct@f16`] values is always exactly
+    /// representable as an [`f32`], so the error is computed exactly and then
+    /// rounded to [`struct@f16`]. The error is exactly representable, so
+    /// `hi + lo` is exactly the product of `a` and `b`, whenever `|hi|` is
+    /// finite and at least `0.25`: smaller products may have an error below
+    /// the subnormal range. If `hi` is infinite or NaN, `lo` is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let a = f16::from_f32(1.0 + 1.0 / 1024.0);
+    /// let (hi, lo) = f16::two_prod(a, a);
+    ///
+    /// assert_eq!(hi, f16::from_f32(1.0 + 2.0 / 1024.0));
+    /// assert_eq!(lo, f16::from_f32(1.0 / (1024.0 * 1024.0)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn two_prod(a: f16, b: f16) -> (f16, f16) {
+        let hi = a * b;
+        if !hi.is_finite() {
+            return (hi, Self::NAN);
+        }
+        // Both the product and the difference are exact in `f32`.
+        let lo = a.to_f32() * b.to_f32() - hi.to_f32();
+        (hi, Self::from_f32(lo))
+    }
+
+    /// Computes `self * b + c` in [`f32`], for accumulating products of
+    /// [`struct@f16`] values without losing precision.
+    ///
+    /// The product of two [`struct@f16`] values is always exactly
+    /// representable as an [`f32`], so the result is rounded once, the same
+    /// as `self.to_f32().mul_add(b.to_f32(), c)`, but without needing FMA
+    /// hardware or the standard library. See
+    /// [`HalfFloatSliceExt::fma_accumulate`] for the vectorized version.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let a = f16::from_f32(1.0 + 1.0 / 1024.0);
+    ///
+    /// // The low bit of the product would round away in `f16`.
+    /// assert_eq!(a.fma_f32_accum(a, -1.0), 2.0 / 1024.0 + 1.0 / (1024.0 * 1024.0));
+    /// assert_eq!(f16::MAX.fma_f32_accum(f16::MAX, 0.0), 65504.0 * 65504.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn fma_f32_accum(self, b: f16, c: f32) -> f32 {
+        self.to_f32() * b.to_f32() + c
+    }
+
+    /// Returns the maximum of the two numbers.
+    ///
+    /// If one of the arguments is NaN, then the other argument is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let x = f16::from_f32(1.0);
+    /// let y = f16::from_f32(2.0);
+    ///
+    /// assert_eq!(x.max(y), y);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn max(self, other: f16) -> f16 {
+        if self.is_nan() || gt(other, self) {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the minimum of the two numbers.
+    ///
+    /// If one of the arguments is NaN, then the other argument is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let x = f16::from_f32(1.0);
+    /// let y = f16::from_f32(2.0);
+    ///
+    /// assert_eq!(x.min(y), x);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn min(self, other: f16) -> f16 {
+        if self.is_nan() || lt(other, self) {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the maximum of three numbers.
+    ///
+    /// This is the same as `a.max(b).max(c)`: NaN arguments are ignored, and
+    /// the result is only NaN if all three arguments are NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let [a, b, c] = [1., -2., 3.].map(f16::from_f32);
+    ///
+    /// assert_eq!(f16::max3(a, b, c), c);
+    /// assert_eq!(f16::max3(a, b, f16::NAN), a);
+    /// assert!(f16::max3(f16::NAN, f16::NAN, f16::NAN).is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn max3(a: f16, b: f16, c: f16) -> f16 {
+        a.max(b).max(c)
+    }
+
+    /// Returns the minimum of three numbers.
+    ///
+    /// This is the same as `a.min(b).min(c)`: NaN arguments are ignored, and
+    /// the result is only NaN if all three arguments are NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let [a, b, c] = [1., -2., 3.].map(f16::from_f32);
+    ///
+    /// assert_eq!(f16::min3(a, b, c), b);
+    /// assert_eq!(f16::min3(f16::NAN, a, c), a);
+    /// assert!(f16::min3(f16::NAN, f16::NAN, f16::NAN).is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn min3(a: f16, b: f16, c: f16) -> f16 {
+        a.min(b).min(c)
+    }
+
+    /// Restrict a value to a certain interval unless it is NaN.
+    ///
+    /// Returns `max` if `self` is greater than `max`, and `min` if `self` is
+    /// less than `min`. Otherwise this returns `self`.
+    ///
+    /// Note that this function returns NaN if the initial value was NaN as
+    /// well.
+    ///
+    /// # Panics
+    /// Panics if `min > max`, `min` is NaN, or `max` is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// assert!(f16::from_f32(-3.0).clamp(f16::from_f32(-2.0), f16::from_f32(1.0)) == f16::from_f32(-2.0));
+    /// assert!(f16::from_f32(0.0).clamp(f16::from_f32(-2.0), f16::from_f32(1.0)) == f16::from_f32(0.0));
+    /// assert!(f16::from_f32(2.0).clamp(f16::from_f32(-2.0), f16::from_f32(1.0)) == f16::from_f32(1.0));
+    /// assert!(f16::NAN.clamp(f16::from_f32(-2.0), f16::from_f32(1.0)).is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn clamp(self, min: f16, max: f16) -> f16 {
+        assert!(le(min, max));
+        let mut x = self;
+        if lt(x, min) {
+            x = min;
+        }
+        if gt(x, max) {
+            x = max;
+        }
+        x
+    }
+
+    /// Returns the ordering between `self` and `other`.
+    ///
+    /// Unlike the standard partial comparison between floating point numbers,
+    /// this comparison always produces an ordering in accordance to
+    /// the `totalOrder` predicate as defined in the IEEE 754 (2008 revision)
+    /// floating point standard. The values are ordered in the following
+    /// sequence:
+    ///
+    /// - negative quiet NaN
+    /// - negative signaling NaN
+    /// - negative infinity
+    /// - negative numbers
+    /// - negative subnormal numbers
+    /// - negative zero
+    /// - positive zero
+    /// - positive subnormal numbers
+    /// - positive numbers
+    /// - positive infinity
+    /// - positive signaling NaN
+    /// - positive quiet NaN.
+    ///
+    /// The ordering established by this function does not always agree with the
+    /// [`PartialOrd`] and [`PartialEq`] implementations of `f16`. For example,
+    /// they consider negative and positive zero equal, while `total_cmp`
+    /// doesn't.
+    ///
+    /// The interpretation of the signaling NaN bit follows the definition in
+    /// the IEEE 754 standard, which may not match the interpretation by some of
+    /// the older, non-conformant (e.g. MIPS) hardware implementations.
+    ///
+    /// # Examples
+    /// ```
+    /// # use float16::f16;
+    /// let mut v: Vec<f16> = vec![];
+    /// v.push(f16::ONE);
+    /// v.push(f16::INFINITY);
+    /// v.push(f16::NEG_INFINITY);
+    /// v.push(f16::NAN);
+    /// v.push(f16::MAX_SUBNORMAL);
+    /// v.push(-f16::MAX_SUBNORMAL);
+    /// v.push(f16::ZERO);
+    /// v.push(f16::NEG_ZERO);
+    /// v.push(f16::NEG_ONE);
+    /// v.push(f16::MIN_POSITIVE);
+    ///
+    /// v.sort_by(|a, b| a.total_cmp(&b));
+    ///
+    /// assert!(v
+    ///     .into_iter()
+    ///     .zip(
+    ///         [
+    ///             f16::NEG_INFINITY,
+    ///             f16::NEG_ONE,
+    ///             -f16::MAX_SUBNORMAL,
+    ///             f16::NEG_ZERO,
+    ///             f16::ZERO,
+    ///             f16::MAX_SUBNORMAL,
+    ///             f16::MIN_POSITIVE,
+    ///             f16::ONE,
+    ///             f16::INFINITY,
+    ///             f16::NAN
+    ///         ]
+    ///         .iter()
+    ///     )
+    ///     .all(|(a, b)| a.to_bits() == b.to_bits()));
+    /// ```
+    // Implementation based on: https://doc.rust-lang.org/std/primitive.f32.html#method.total_cmp
+    #[inline]
+    #[must_use]
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        let mut left = self.to_bits() as i16;
+        let mut right = other.to_bits() as i16;
+        left ^= (((left >> 15) as u16) >> 1) as i16;
+        right ^= (((right >> 15) as u16) >> 1) as i16;
+        left.cmp(&right)
+    }
+
+    /// Returns the bits of the value mapped so that unsigned integer
+    /// comparisons follow the [`total_cmp`][Self::total_cmp] order.
+    ///
+    /// Negative NaNs map to the smallest integers and positive NaNs to the
+    /// largest, and `-0` maps to one less than `+0`. Adjacent values in the
+    /// total order map to adjacent integers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// assert!(f16::NEG_ONE.to_ordered_bits() < f16::NEG_ZERO.to_ordered_bits());
+    /// assert_eq!(f16::NEG_ZERO.to_ordered_bits() + 1, f16::ZERO.to_ordered_bits());
+    /// assert_eq!(f16::MAX.to_ordered_bits() + 1, f16::INFINITY.to_ordered_bits());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_ordered_bits(self) -> u16 {
+        if self.0 & Self::SIGN_MASK != 0 {
+            !self.0
+        } else {
+            self.0 | Self::SIGN_MASK
+        }
+    }
+
+    /// Returns the distance between two values in units in the last place
+    /// (ulps), the number of steps between adjacent [`struct@f16`] values
+    /// needed to reach `other` from `self`.
+    ///
+    /// Equal values have a distance of 0 and adjacent values a distance of
+    /// 1, where `-0` and `+0` are treated as the same value and infinities
+    /// are adjacent to the largest finite values. Returns `None` if either
+    /// value is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let one = f16::ONE;
+    /// assert_eq!(one.ulp_distance(one + f16::EPSILON), Some(1));
+    /// assert_eq!(f16::NEG_ZERO.ulp_distance(f16::ZERO), Some(0));
+    /// assert_eq!(
+    ///     (-f16::MIN_POSITIVE_SUBNORMAL).ulp_distance(f16::MIN_POSITIVE_SUBNORMAL),
+    ///     Some(2)
+    /// );
+    /// assert_eq!(one.ulp_distance(f16::NAN), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn ulp_distance(self, other: f16) -> Option<u16> {
+        if self.is_nan() || other.is_nan() {
+            return None;
+        }
+        let (a, b) = (self.to_ordered_bits(), other.to_ordered_bits());
+        let distance = a.abs_diff(b);
+        // `-0` and `+0` are adjacent in the total order, so do not count the
+        // step between them when crossing zero.
+        if (self.0 ^ other.0) & Self::SIGN_MASK != 0 {
+            Some(distance - 1)
+        } else {
+            Some(distance)
+        }
+    }
+
+    /// Returns the value halfway between `self` and `other` in the order of
+    /// the representable values, for bisecting over the values themselves.
+    ///
+    /// This is not the numeric midpoint `(self + other) / 2`: values are
+    /// counted by their position, as in [`ulp_distance`][Self::ulp_distance],
+    /// where `-0` and `+0` are the same value, so the result has about as
+    /// many values between it and `self` as between it and `other`. For
+    /// example, the numeric midpoint of `1` and `2^10` is close to `2^9`, but
+    /// this is `2^5`. Repeatedly bisecting an interval in this way
+    /// reaches adjacent values after at most 16 steps, even from
+    /// [`NEG_INFINITY`][Self::NEG_INFINITY] to [`INFINITY`][Self::INFINITY].
+    ///
+    /// The result is strictly between the arguments unless they are equal or
+    /// adjacent, in which case it is the smaller argument. Zero is returned as
+    /// `+0`. If either argument is NaN, the first NaN argument is returned,
+    /// made quiet.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let (a, b) = (f16::ONE, f16::from_f32(1024.));
+    /// assert_eq!(a.bisect(b), f16::from_f32(32.));
+    /// assert_eq!(b.bisect(a), f16::from_f32(32.));
+    /// assert_eq!(f16::NEG_INFINITY.bisect(f16::INFINITY).to_bits(), 0);
+    /// assert_eq!(f16::ONE.bisect(f16::ONE), f16::ONE);
+    /// assert!(f16::ONE.bisect(f16::NAN).is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn bisect(self, other: f16) -> f16 {
+        if self.is_nan() {
+            return self.quiet();
+        } else if other.is_nan() {
+            return other.quiet();
+        }
+        // The position of each value, where `-0` and `+0` are both 0. The
+        // shift rounds the sum down, towards the smaller argument.
+        const fn position(x: f16) -> i32 {
+            let mag = (x.0 & f16::NOT_SIGN) as i32;
+            if x.0 & f16::SIGN_MASK != 0 {
+                -mag
+            } else {
+                mag
+            }
+        }
+        let mid = (position(self) + position(other)) >> 1;
+        if mid < 0 {
+            f16(Self::SIGN_MASK | (-mid) as u16)
+        } else {
+            f16(mid as u16)
+        }
+    }
+
+    /// Finds a root of `f` between `lo` and `hi` by [bisection][Self::bisect]
+    /// over the representable values, or returns `None` if there is no sign
+    /// change.
+    ///
+    /// If `f` is zero at either bound, that bound is returned. Otherwise,
+    /// `f(lo)` and `f(hi)` must have opposite signs, and the interval is
+    /// halved until it holds a value where `f` is zero, or two adjacent values
+    /// where `f` changes sign. Of those two values, the one where `f` has the
+    /// smaller magnitude is returned, preferring `lo`, so for a continuous `f`
+    /// that is computed accurately, this is the representable value nearest
+    /// to the root. `f` is called at most 18 times.
+    ///
+    /// The bounds may be given in either order. Returns `None` if either
+    /// bound is NaN, or `f` returns NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let f = |x: f16| f16::from_f32(x.to_f32() * x.to_f32() - 2.);
+    /// assert_eq!(f16::find_root(f, f16::ZERO, f16::from_f32(2.)), Some(f16::SQRT_2));
+    /// assert_eq!(f16::find_root(f, f16::from_f32(-2.), f16::ZERO), Some(-f16::SQRT_2));
+    /// assert_eq!(f16::find_root(f, f16::from_f32(2.), f16::from_f32(3.)), None);
+    /// ```
+    #[must_use]
+    pub fn find_root(f: impl Fn(f16) -> f16, lo: f16, hi: f16) -> Option<f16> {
+        if lo.is_nan() || hi.is_nan() {
+            return None;
+        }
+        let (mut lo, mut hi) = if hi < lo {
+            (hi, lo)
+        } else {
+            (lo, hi)
+        };
+        let (mut f_lo, mut f_hi) = (f(lo), f(hi));
+        if f_lo.is_zero() {
+            return Some(lo);
+        } else if f_hi.is_zero() {
+            return Some(hi);
+        } else if f_lo.is_nan()
+            || f_hi.is_nan()
+            || f_lo.is_sign_negative() == f_hi.is_sign_negative()
+        {
+            return None;
+        }
+        while lo.ulp_distance(hi) > Some(1) {
+            let mid = lo.bisect(hi);
+            let f_mid = f(mid);
+            if f_mid.is_zero() {
+                return Some(mid);
+            } else if f_mid.is_nan() {
+                return None;
+            } else if f_mid.is_sign_negative() == f_lo.is_sign_negative() {
+                lo = mid;
+                f_lo = f_mid;
+            } else {
+                hi = mid;
+                f_hi = f_mid;
+            }
+        }
+        if f_hi.abs() < f_lo.abs() {
+            Some(hi)
+        } else {
+            Some(lo)
+        }
+    }
+
+    /// Returns the absolute difference between two values, `|self - other|`.
+    ///
+    /// This computes the difference exactly with integer arithmetic and
+    /// rounds once to the nearest [`struct@f16`] value, so it is the same as
+    /// `(self - other).abs()`, but can be used in a `const` context. `-0` and
+    /// `+0` have a difference of `+0`. NaN arguments and infinities of the
+    /// same sign return NaN, and any other infinite argument returns
+    /// [`INFINITY`][Self::INFINITY], including infinities of opposite signs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let (a, b) = (f16::from_f32(1.5), f16::from_f32(-2.));
+    /// assert_eq!(a.abs_diff(b), f16::from_f32(3.5));
+    /// assert_eq!(b.abs_diff(a), f16::from_f32(3.5));
+    /// assert_eq!(f16::NEG_ZERO.abs_diff(f16::ZERO).to_bits(), 0);
+    /// assert_eq!(f16::INFINITY.abs_diff(f16::NEG_INFINITY), f16::INFINITY);
+    /// assert!(f16::INFINITY.abs_diff(f16::INFINITY).is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn abs_diff(self, other: f16) -> f16 {
+        f16(exact::abs_diff(self.0, other.0, 5, 10))
+    }
+
+    /// Returns the distance between two values in units in the last place
+    /// (ulps), or `0` if either value is NaN.
+    ///
+    /// This is the same as [`ulp_distance`][Self::ulp_distance], with NaN
+    /// mapped to the sentinel `0` instead of `None`, so NaN arguments cannot
+    /// be told apart from equal values. Use
+    /// [`ulp_distance`][Self::ulp_distance] when the arguments may be NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let one = f16::ONE;
+    /// assert_eq!(one.abs_diff_ulp(one + f16::EPSILON), 1);
+    /// assert_eq!(f16::NEG_ZERO.abs_diff_ulp(f16::ZERO), 0);
+    /// assert_eq!(f16::MAX.abs_diff_ulp(f16::INFINITY), 1);
+    /// assert_eq!(one.abs_diff_ulp(f16::NAN), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn abs_diff_ulp(self, other: f16) -> u16 {
+        match self.ulp_distance(other) {
+            Some(distance) => distance,
+            None => 0,
+        }
+    }
+
+    /// Returns if two values are within `max_ulps` units in the last place
+    /// of each other.
+    ///
+    /// This uses the [`ulp_distance`][Self::ulp_distance] between the
+    /// values, so values on either side of zero compare sensibly and `-0`
+    /// equals `+0`. NaN never compares equal, and infinities only compare
+    /// equal to themselves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let x = f16::ONE;
+    /// let y = x + f16::EPSILON;
+    /// assert!(x != y);
+    /// assert!(x.ulps_eq(y, 1));
+    /// assert!(!x.ulps_eq(y, 0));
+    /// assert!(!f16::NAN.ulps_eq(f16::NAN, u16::MAX));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn ulps_eq(self, other: f16, max_ulps: u16) -> bool {
+        if self.is_infinite() || other.is_infinite() {
+            return self.0 == other.0;
+        }
+        match self.ulp_distance(other) {
+            Some(distance) => distance <= max_ulps,
+            None => false,
+        }
+    }
+
+    /// Returns if two values are within an absolute difference of
+    /// `max_abs_diff` or within `max_ulps` units in the last place of each
+    /// other.
+    ///
+    /// The absolute difference handles values near zero, where a small
+    /// error is a large number of ulps, while the ulps handle large values.
+    /// The difference is computed in [`f32`] precision, so it is exact. NaN
+    /// never compares equal, and infinities only compare equal to themselves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float16::*;
+    /// let tiny = f16::MIN_POSITIVE;
+    /// assert!(tiny.approx_eq(-tiny, f16::EPSILON, 0));
+    /// assert!(!tiny.approx_eq(-tiny, f16::ZERO, 4));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn approx_eq(self, other: f16, max_abs_diff: f16, max_ulps: u16) -> bool {
+        if self.is_infinite() || other.is_infinite() {
+            return self.0 == other.0;
+        }
+        let diff = self.to_f32() - other.to_f32();
+        let max = max_abs_diff.to_f32();
+        (-max..=max).contains(&diff) || self.ulps_eq(other, max_ulps)
+    }
+
+    /// Returns the smallest positive normal [`struct@f16`] value,
+    /// [`MIN_POSITIVE`][Self::MIN_POSITIVE].
+    ///
+    /// This is the same as the constant, for generic code that needs a
+    /// function, and avoids confusing it with [`MIN`][Self::MIN], which is
+    /// the most negative finite value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(f16::min_normal(), f16::MIN_POSITIVE);
+    /// assert!(f16::min_normal().is_normal());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn min_normal() -> f16 {
+        Self::MIN_POSITIVE
+    }
+
+    /// Returns the largest finite [`struct@f16`] value, [`MAX`][Self::MAX].
+    ///
+    /// This is the same as the constant, for generic code that needs a
+    /// function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(f16::max_normal(), f16::MAX);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn max_normal() -> f16 {
+        Self::MAX
+    }
+
+    /// Returns the smallest positive subnormal [`struct@f16`] value,
+    /// [`MIN_POSITIVE_SUBNORMAL`][Self::MIN_POSITIVE_SUBNORMAL].
+    ///
+    /// This is the same as the constant, for generic code that needs a
+    /// function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// assert_eq!(f16::min_positive_subnormal(), f16::MIN_POSITIVE_SUBNORMAL);
+    /// assert!(f16::min_positive_subnormal().is_subnormal());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn min_positive_subnormal() -> f16 {
+        Self::MIN_POSITIVE_SUBNORMAL
+    }
+
+    /// Approximate number of [`struct@f16`] significant digits in base 10
+    pub const DIGITS: u32 = 3;
+    /// [`struct@f16`]
+    /// [machine epsilon](https://en.wikipedia.org/wiki/Machine_epsilon) value
+    ///
+    /// This is the difference between 1.0 and the next largest representable
+    /// number.
+    pub const EPSILON: f16 = f16(0x1400u16);
+    /// [`struct@f16`] positive Infinity (+∞)
+    pub const INFINITY: f16 = f16(0x7C00u16);
+    /// Number of [`struct@f16`] significant digits in base 2
+    pub const MANTISSA_DIGITS: u32 = 11;
+    /// Largest finite [`struct@f16`] value
+    pub const MAX: f16 = f16(0x7BFF);
+    /// Maximum possible [`struct@f16`] power of 10 exponent
+    pub const MAX_10_EXP: i32 = 4;
+    /// Maximum possible [`struct@f16`] power of 2 exponent
+    pub const MAX_EXP: i32 = 16;
+    /// Smallest finite [`struct@f16`] value
+    pub const MIN: f16 = f16(0xFBFF);
+    /// Minimum possible normal [`struct@f16`] power of 10 exponent
+    pub const MIN_10_EXP: i32 = -4;
+    /// One greater than the minimum possible normal [`struct@f16`] power of 2
+    /// exponent
+    pub const MIN_EXP: i32 = -13;
+    /// Smallest positive normal [`struct@f16`] value
+    pub const MIN_POSITIVE: f16 = f16(0x0400u16);
+    /// [`struct@f16`] Not a Number (NaN)
+    pub const NAN: f16 = f16(0x7E00u16);
+    /// [`struct@f16`] signaling Not a Number (NaN), with the quiet bit clear
+    pub const SIGNALING_NAN: f16 = f16(0x7D00u16);
+    /// [`struct@f16`] negative infinity (-∞)
+    pub const NEG_INFINITY: f16 = f16(0xFC00u16);
+    /// The radix or base of the internal representation of [`struct@f16`]
+    pub const RADIX: u32 = 2;
+
+    /// Minimum positive subnormal [`struct@f16`] value
+    pub const MIN_POSITIVE_SUBNORMAL: f16 = f16(0x0001u16);
+    /// Maximum subnormal [`struct@f16`] value
+    pub const MAX_SUBNORMAL: f16 = f16(0x03FFu16);
+
+    /// Largest integer such that every integer of smaller magnitude is
+    /// exactly representable as a [`struct@f16`], 2048
+    pub const MAX_CONSECUTIVE_INTEGER: f16 = f16(0x6800u16);
+
+    /// [`struct@f16`] 1
+    pub const ONE: f16 = f16(0x3C00u16);
+    /// [`struct@f16`] 0
+    pub const ZERO: f16 = f16(0x0000u16);
+    /// [`struct@f16`] -0
+    pub const NEG_ZERO: f16 = f16(0x8000u16);
+    /// [`struct@f16`] -1
+    pub const NEG_ONE: f16 = f16(0xBC00u16);
+
+    /// [`struct@f16`] Euler's number (ℯ)
+    pub const E: f16 = f16(0x4170u16);
+    /// [`struct@f16`] Archimedes' constant (π)
+    pub const PI: f16 = f16(0x4248u16);
+    /// [`struct@f16`] 1/π
+    pub const FRAC_1_PI: f16 = f16(0x3518u16);
+    /// [`struct@f16`] 1/√2
+    pub const FRAC_1_SQRT_2: f16 = f16(0x39A8u16);
+    /// [`struct@f16`] 2/π
+    pub const FRAC_2_PI: f16 = f16(0x3918u16);
+    /// [`struct@f16`] 2/√π
+    pub const FRAC_2_SQRT_PI: f16 = f16(0x3C83u16);
+    /// [`struct@f16`] π/2
+    pub const FRAC_PI_2: f16 = f16(0x3E48u16);
+    /// [`struct@f16`] π/3
+    pub const FRAC_PI_3: f16 = f16(0x3C30u16);
+    /// [`struct@f16`] π/4
+    pub const FRAC_PI_4: f16 = f16(0x3A48u16);
+    /// [`struct@f16`] π/6
+    pub const FRAC_PI_6: f16 = f16(0x3830u16);
+    /// [`struct@f16`] π/8
+    pub const FRAC_PI_8: f16 = f16(0x3648u16);
+    /// [`struct@f16`] 𝗅𝗇 10
+    pub const LN_10: f16 = f16(0x409Bu16);
+    /// [`struct@f16`] 𝗅𝗇 2
+    pub const LN_2: f16 = f16(0x398Cu16);
+    /// [`struct@f16`] 𝗅𝗈𝗀₁₀ℯ
+    pub const LOG10_E: f16 = f16(0x36F3u16);
+    /// [`struct@f16`] 𝗅𝗈𝗀₁₀2
+    pub const LOG10_2: f16 = f16(0x34D1u16);
+    /// [`struct@f16`] 𝗅𝗈𝗀₂ℯ
+    pub const LOG2_E: f16 = f16(0x3DC5u16);
+    /// [`struct@f16`] 𝗅𝗈𝗀₂10
+    pub const LOG2_10: f16 = f16(0x42A5u16);
+    /// [`struct@f16`] √2
+    pub const SQRT_2: f16 = f16(0x3DA8u16);
+
+    /// Sign bit
+    pub const SIGN_MASK: u16 = 0x8000;
+    // Private helper for comparisons.
+    const NOT_SIGN: u16 = !Self::SIGN_MASK;
+
+    /// Exponent mask
+    pub const EXP_MASK: u16 = 0x7C00;
+
+    /// Mask for the hidden bit.
+    pub const HIDDEN_BIT_MASK: u16 = 0x0400;
+
+    /// Mantissa mask
+    pub const MAN_MASK: u16 = 0x03FF;
+    // Private helpers for signaling NaNs and NaN payloads.
+    const QUIET_BIT: u16 = 0x0200;
+    const PAYLOAD_MASK: u16 = 0x01FF;
+
+    /// Minimum representable positive value (min subnormal)
+    pub const TINY_BITS: u16 = 0x1;
+
+    /// Minimum representable negative value (min negative subnormal)
+    pub const NEG_TINY_BITS: u16 = Self::TINY_BITS | Self::SIGN_MASK;
+}
+
+// Every mathematical constant is the correctly rounded value of the
+// corresponding `f64` constant, checked at compile time.
+macro_rules! assert_consts {
+    ($($name:ident)*) => {
+        const _: () = {
+            $(
+                assert!(
+                    f16::$name.0 == f16::from_f64_const(core::f64::consts::$name).0,
+                    concat!("`f16::", stringify!($name), "` is not correctly rounded"),
+                );
+            )*
+        };
+    };
+}
+
+assert_consts! {
+    E PI FRAC_1_PI FRAC_1_SQRT_2 FRAC_2_PI FRAC_2_SQRT_PI FRAC_PI_2 FRAC_PI_3 FRAC_PI_4 FRAC_PI_6
+    FRAC_PI_8 LN_10 LN_2 LOG10_E LOG10_2 LOG2_E LOG2_10 SQRT_2
+}
+
+macro_rules! from_int_lossless_impl {
+    ($t:ty, $func:ident) => {
+        /// Create from the integral type, if it is exactly representable.
+        ///
+        /// Returns `None` if the integer would be rounded or is too large to
+        /// fit. `0` is `+0`.
+        #[inline]
+        #[must_use]
+        #[allow(unused_comparisons)]
+        pub const fn $func(value: $t) -> Option<Self> {
+            let negative = value < 0;
+            let mag = if negative {
+                (value as i128).unsigned_abs()
+            } else {
+                value as u128
+            };
+            match fixed::int_to_half_exact(negative, mag, 5, 10) {
+                Some(bits) => Some(Self(bits)),
+                None => None,
+            }
+        }
+    };
+}
+
+macro_rules! from_int_impl {
+    ($t:ty, $func:ident) => {
+        /// Create from the integral type, as if by an `as` cast.
+        #[inline(always)]
+        pub const fn $func(value: $t) -> Self {
+            Self::from_f32_const(value as f32)
+        }
+    };
+}
+
+impl f16 {
+    from_int_impl!(u8, from_u8);
+    from_int_impl!(u16, from_u16);
+    from_int_impl!(u32, from_u32);
+    from_int_impl!(u64, from_u64);
+    from_int_impl!(u128, from_u128);
+    from_int_impl!(i8, from_i8);
+    from_int_impl!(i16, from_i16);
+    from_int_impl!(i64, from_i64);
+    from_int_impl!(i128, from_i128);
+    from_int_lossless_impl!(u8, from_u8_lossless);
+    from_int_lossless_impl!(u16, from_u16_lossless);
+    from_int_lossless_impl!(u32, from_u32_lossless);
+    from_int_lossless_impl!(u64, from_u64_lossless);
+    from_int_lossless_impl!(u128, from_u128_lossless);
+    from_int_lossless_impl!(i8, from_i8_lossless);
+    from_int_lossless_impl!(i16, from_i16_lossless);
+    from_int_lossless_impl!(i32, from_i32_lossless);
+    from_int_lossless_impl!(i64, from_i64_lossless);
+    from_int_lossless_impl!(i128, from_i128_lossless);
+}
+
+impl From<f16> for f32 {
+    #[inline]
+    fn from(x: f16) -> f32 {
+        x.to_f32()
+    }
+}
+
+impl From<f16> for f64 {
+    #[inline]
+    fn from(x: f16) -> f64 {
+        x.to_f64()
+    }
+}
+
+impl From<i8> for f16 {
+    #[inline]
+    fn from(x: i8) -> f16 {
+        // Convert to f32, then to f16
+        f16::from_f32(f32::from(x))
+    }
+}
+
+impl From<u8> for f16 {
+    #[inline]
+    fn from(x: u8) -> f16 {
+        // Convert to f32, then to f16
+        f16::from_f32(f32::from(x))
+    }
+}
+
+impl From<bool> for f16 {
+    #[inline]
+    fn from(x: bool) -> f16 {
+        f16::from_bool(x)
+    }
+}
+
+macro_rules! try_from_int_impl {
+    ($($t:ty => $func:ident),*) => {$(
+        impl TryFrom<$t> for f16 {
+            type Error = TryFromFloatError;
+
+            /// Converts the integer if it is exactly representable, as with
+            #[doc = concat!("[`f16::", stringify!($func), "`][crate::f16::", stringify!($func), "].")]
+            #[inline]
+            fn try_from(x: $t) -> Result<Self, Self::Error> {
+                Self::$func(x).ok_or(TryFromFloatError(()))
+            }
+        }
+    )*};
+}
+
+try_from_int_impl! {
+    u16 => from_u16_lossless,
+    u32 => from_u32_lossless,
+    u64 => from_u64_lossless,
+    i16 => from_i16_lossless,
+    i32 => from_i32_lossless,
+    i64 => from_i64_lossless
+}
+
+impl TryFrom<f32> for f16 {
+    type Error = TryFromFloatError;
+
+    #[inline]
+    fn try_from(x: f32) -> Result<Self, Self::Error> {
+        Self::from_f32_lossless(x).ok_or(TryFromFloatError(()))
+    }
+}
+
+impl TryFrom<f64> for f16 {
+    type Error = TryFromFloatError;
+
+    #[inline]
+    fn try_from(x: f64) -> Result<Self, Self::Error> {
+        Self::from_f64_lossless(x).ok_or(TryFromFloatError(()))
+    }
+}
+
+impl TryFrom<bf16> for f16 {
+    type Error = TryFromFloatError;
+
+    /// Converts the value if it is exactly representable, as with
+    /// [`f16::from_bf16_lossless`][crate::f16::from_bf16_lossless].
+    #[inline]
+    fn try_from(x: bf16) -> Result<Self, Self::Error> {
+        Self::from_bf16_lossless(x).ok_or(TryFromFloatError(()))
+    }
+}
+
+macro_rules! try_from_f16_impl {
+    ($($t:ty)*) => {$(
+        impl TryFrom<f16> for $t {
+            type Error = TryFromHalfError;
+
+            /// Converts the value to the integer type, failing if it is NaN,
+            /// infinite, out of range or not an integer. `-0` converts to
+            /// `0`.
+            #[inline]
+            fn try_from(x: f16) -> Result<Self, Self::Error> {
+                let (negative, mag, fractional) =
+                    fixed::half_to_int_checked(x.0, 5, 10).map_err(TryFromHalfError)?;
+                let value = if negative {
+                    if mag > (<$t>::MIN as i128).unsigned_abs() {
+                        return Err(TryFromHalfError(TryFromHalfErrorKind::OutOfRange));
+                    }
+                    (mag as $t).wrapping_neg()
+                } else {
+                    if mag > <$t>::MAX as u128 {
+                        return Err(TryFromHalfError(TryFromHalfErrorKind::OutOfRange));
+                    }
+                    mag as $t
+                };
+                if fractional {
+                    Err(TryFromHalfError(TryFromHalfErrorKind::Fractional))
+                } else {
+                    Ok(value)
+                }
+            }
+        }
+    )*};
+}
+
+try_from_f16_impl! { u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 }
+
+#[cfg(not(feature = "total-order-impls"))]
+impl PartialEq for f16 {
+    #[inline]
+    fn eq(&self, other: &f16) -> bool {
+        eq(*self, *other)
+    }
+}
+
+#[cfg(not(feature = "total-order-impls"))]
+impl PartialOrd for f16 {
+    #[inline]
+    fn partial_cmp(&self, other: &f16) -> Option<Ordering> {
+        if self.is_nan() || other.is_nan() {
+            None
+        } else {
+            let neg = self.0 & Self::SIGN_MASK != 0;
+            let other_neg = other.0 & Self::SIGN_MASK != 0;
+            match (neg, other_neg) {
+                (false, false) => Some(self.0.cmp(&other.0)),
+                (false, true) => {
+                    if (self.0 | other.0) & Self::NOT_SIGN == 0 {
+                        Some(Ordering::Equal)
+                    } else {
+                        Some(Ordering::Greater)
+                    }
+                },
+                (true, false) => {
+                    if (self.0 | other.0) & Self::NOT_SIGN == 0 {
+                        Some(Ordering::Equal)
+                    } else {
+                        Some(Ordering::Less)
+                    }
+                },
+                (true, true) => Some(other.0.cmp(&self.0)),
+            }
+        }
+    }
+
+    #[inline]
+    fn lt(&self, other: &f16) -> bool {
+        lt(*self, *other)
+    }
+
+    #[inline]
+    fn le(&self, other: &f16) -> bool {
+        le(*self, *other)
+    }
+
+    #[inline]
+    fn gt(&self, other: &f16) -> bool {
+        gt(*self, *other)
+    }
+
+    #[inline]
+    fn ge(&self, other: &f16) -> bool {
+        ge(*self, *other)
+    }
+}
+
+/// With the `total-order-impls` feature, values are equal only if their bits
+/// are equal, so `-0 != +0` and every NaN equals itself.
+#[cfg(feature = "total-order-impls")]
+impl PartialEq for f16 {
+    #[inline]
+    fn eq(&self, other: &f16) -> bool {
+        self.0 == other.0
+    }
+}
+
+/// With the `total-order-impls` feature, equality is the bitwise equality of
+/// [`PartialEq`].
+#[cfg(feature = "total-order-impls")]
+impl Eq for f16 {
+}
+
+/// With the `total-order-impls` feature, this is the order of
+/// [`total_cmp`][f16::total_cmp], which disagrees with the IEEE 754
+/// comparisons: `-0 < +0`, and NaN values are ordered by sign and payload
+/// below or above every other value.
+#[cfg(feature = "total-order-impls")]
+impl PartialOrd for f16 {
+    #[inline]
+    fn partial_cmp(&self, other: &f16) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// With the `total-order-impls` feature, this is the order of
+/// [`total_cmp`][f16::total_cmp].
+#[cfg(feature = "total-order-impls")]
+impl Ord for f16 {
+    #[inline]
+    fn cmp(&self, other: &f16) -> Ordering {
+        self.total_cmp(other)
+    }
+}
+
+/// With the `total-order-impls` feature, this hashes the bits, consistent
+/// with the bitwise equality of [`PartialEq`].
+#[cfg(feature = "total-order-impls")]
+impl Hash for f16 {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl FromStr for f16 {
+    type Err = ParseFloatError;
+
+    #[inline]
+    fn from_str(src: &str) -> Result<f16, ParseFloatError> {
+        decimal::parse_decimal(src, 5, 10).map(f16)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl Debug for f16 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        Debug::fmt(&self.to_f32(), f)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl Display for f16 {
+    /// Formats the value using the shortest decimal that parses back to the
+    /// same value, unless a precision is given.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        if f.precision().is_some() || !self.is_finite() {
+            return Display::fmt(&self.to_f32(), f);
+        }
+        shortest::fmt_positional(f, self.0, 5, 10)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl LowerExp for f16 {
+    /// Formats the value in scientific notation using the shortest decimal
+    /// that parses back to the same value, unless a precision is given.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        if f.precision().is_some() || !self.is_finite() {
+            return LowerExp::fmt(&self.to_f32(), f);
+        }
+        shortest::fmt_scientific(f, self.0, 5, 10, 'e')
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl UpperExp for f16 {
+    /// Formats the value in scientific notation using the shortest decimal
+    /// that parses back to the same value, unless a precision is given.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        if f.precision().is_some() || !self.is_finite() {
+            return UpperExp::fmt(&self.to_f32(), f);
+        }
+        shortest::fmt_scientific(f, self.0, 5, 10, 'E')
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl Binary for f16 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{:b}", self.0)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl Octal for f16 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{:o}", self.0)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl LowerHex for f16 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{:x}", self.0)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl UpperHex for f16 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{:X}", self.0)
+    }
+}
+
+impl Neg for f16 {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self(self.0 ^ Self::SIGN_MASK)
+    }
+}
+
+impl Neg for &f16 {
+    type Output = <f16 as Neg>::Output;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Neg::neg(*self)
+    }
+}
+
+impl Add for f16 {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        f16(arch::canonical_nan(arch::add_f16(self.0, rhs.0)))
+    }
+}
+
+impl Add<&f16> for f16 {
+    type Output = <f16 as Add<f16>>::Output;
+
+    #[inline]
+    fn add(self, rhs: &f16) -> Self::Output {
+        self.add(*rhs)
+    }
+}
+
+impl Add<&f16> for &f16 {
+    type Output = <f16 as Add<f16>>::Output;
+
+    #[inline]
+    fn add(self, rhs: &f16) -> Self::Output {
+        (*self).add(*rhs)
+    }
+}
+
+impl Add<f16> for &f16 {
+    type Output = <f16 as Add<f16>>::Output;
+
+    #[inline]
+    fn add(self, rhs: f16) -> Self::Output {
+        (*self).add(rhs)
+    }
+}
+
+impl AddAssign for f16 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = (*self).add(rhs);
+    }
+}
+
+impl AddAssign<&f16> for f16 {
+    #[inline]
+    fn add_assign(&mut self, rhs: &f16) {
+        *self = (*self).add(rhs);
+    }
+}
+
+impl Sub for f16 {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        f16(arch::canonical_nan(arch::subtract_f16(self.0, rhs.0)))
+    }
+}
+
+impl Sub<&f16> for f16 {
+    type Output = <f16 as Sub<f16>>::Output;
+
+    #[inline]
+    fn sub(self, rhs: &f16) -> Self::Output {
+        self.sub(*rhs)
+    }
+}
+
+impl Sub<&f16> for &f16 {
+    type Output = <f16 as Sub<f16>>::Output;
+
+    #[inline]
+    fn sub(self, rhs: &f16) -> Self::Output {
+        (*self).sub(*rhs)
+    }
+}
+
+impl Sub<f16> for &f16 {
+    type Output = <f16 as Sub<f16>>::Output;
+
+    #[inline]
+    fn sub(self, rhs: f16) -> Self::Output {
+        (*self).sub(rhs)
+    }
+}
+
+impl SubAssign for f16 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = (*self).sub(rhs);
+    }
+}
+
+impl SubAssign<&f16> for f16 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &f16) {
+        *self = (*self).sub(rhs);
+    }
+}
+
+impl Mul for f16 {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        f16(arch::canonical_nan(arch::multiply_f16(self.0, rhs.0)))
+    }
+}
+
+impl Mul<&f16> for f16 {
+    type Output = <f16 as Mul<f16>>::Output;
+
+    #[inline]
+    fn mul(self, rhs: &f16) -> Self::Output {
+        self.mul(*rhs)
+    }
+}
+
+impl Mul<&f16> for &f16 {
+    type Output = <f16 as Mul<f16>>::Output;
+
+    #[inline]
+    fn mul(self, rhs: &f16) -> Self::Output {
+        (*self).mul(*rhs)
+    }
+}
+
+impl Mul<f16> for &f16 {
+    type Output = <f16 as Mul<f16>>::Output;
+
+    #[inline]
+    fn mul(self, rhs: f16) -> Self::Output {
+        (*self).mul(rhs)
+    }
+}
+
+impl MulAssign for f16 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = (*self).mul(rhs);
+    }
+}
+
+impl MulAssign<&f16> for f16 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &f16) {
+        *self = (*self).mul(rhs);
+    }
+}
+
+impl Div for f16 {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        f16(arch::canonical_nan(arch::divide_f16(self.0, rhs.0)))
+    }
+}
+
+impl Div<&f16> for f16 {
+    type Output = <f16 as Div<f16>>::Output;
+
+    #[inline]
+    fn div(self, rhs: &f16) -> Self::Output {
+        self.div(*rhs)
+    }
+}
+
+impl Div<&f16> for &f16 {
+    type Output = <f16 as Div<f16>>::Output;
+
+    #[inline]
+    fn div(self, rhs: &f16) -> Self::Output {
+        (*self).div(*rhs)
+    }
+}
+
+impl Div<f16> for &f16 {
+    type Output = <f16 as Div<f16>>::Output;
+
+    #[inline]
+    fn div(self, rhs: f16) -> Self::Output {
+        (*self).div(rhs)
+    }
+}
+
+impl DivAssign for f16 {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = (*self).div(rhs);
+    }
+}
+
+impl DivAssign<&f16> for f16 {
+    #[inline]
+    fn div_assign(&mut self, rhs: &f16) {
+        *self = (*self).div(rhs);
+    }
+}
+
+impl Rem for f16 {
+    type Output = Self;
+
+    #[inline]
+    fn rem(self, rhs: Self) -> Self::Output {
+        f16(arch::canonical_nan(arch::remainder_f16(self.0, rhs.0)))
+    }
+}
+
+impl Rem<&f16> for f16 {
+    type Output = <f16 as Rem<f16>>::Output;
+
+    #[inline]
+    fn rem(self, rhs: &f16) -> Self::Output {
+        self.rem(*rhs)
+    }
+}
+
+impl Rem<&f16> for &f16 {
+    type Output = <f16 as Rem<f16>>::Output;
+
+    #[inline]
+    fn rem(self, rhs: &f16) -> Self::Output {
+        (*self).rem(*rhs)
+    }
+}
+
+impl Rem<f16> for &f16 {
+    type Output = <f16 as Rem<f16>>::Output;
+
+    #[inline]
+    fn rem(self, rhs: f16) -> Self::Output {
+        (*self).rem(rhs)
+    }
+}
+
+impl RemAssign for f16 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = (*self).rem(rhs);
+    }
+}
+
+impl RemAssign<&f16> for f16 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: &f16) {
+        *self = (*self).rem(rhs);
+    }
+}
+
+impl Product for f16 {
+    #[inline]
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        f16(arch::canonical_nan(arch::product_f16(iter.map(|f| f.to_bits()))))
+    }
+}
+
+impl<'a> Product<&'a f16> for f16 {
+    #[inline]
+    fn product<I: Iterator<Item = &'a f16>>(iter: I) -> Self {
+        f16(arch::canonical_nan(arch::product_f16(iter.map(|f| f.to_bits()))))
+    }
+}
+
+impl Sum for f16 {
+    #[inline]
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        f16(arch::canonical_nan(arch::sum_f16(iter.map(|f| f.to_bits()))))
+    }
+}
+
+impl<'a> Sum<&'a f16> for f16 {
+    #[inline]
+    fn sum<I: Iterator<Item = &'a f16>>(iter: I) -> Self {
+        f16(arch::canonical_nan(arch::sum_f16(iter.map(|f| f.to_bits()))))
+    }
+}
+
+#[cfg(not(feature = "total-order-impls"))]
+#[inline]
+const fn eq(lhs: f16, rhs: f16) -> bool {
+    if lhs.is_nan() || rhs.is_nan() {
+        false
+    } else {
+        (lhs.0 == rhs.0) || ((lhs.0 | rhs.0) & f16::NOT_SIGN == 0)
+    }
+}
+
+#[inline]
+const fn lt(lhs: f16, rhs: f16) -> bool {
+    if lhs.is_nan() || rhs.is_nan() {
+        false
+    } else {
+        let neg = lhs.0 & f16::SIGN_MASK != 0;
+        let rhs_neg = rhs.0 & f16::SIGN_MASK != 0;
+        match (neg, rhs_neg) {
+            (false, false) => lhs.0 < rhs.0,
+            (false, true) => false,
+            (true, false) => (lhs.0 | rhs.0) & f16::NOT_SIGN != 0,
+            (true, true) => lhs.0 > rhs.0,
+        }
+    }
+}
+
+#[inline]
+const fn le(lhs: f16, rhs: f16) -> bool {
+    if lhs.is_nan() || rhs.is_nan() {
+        false
+    } else {
+        let neg = lhs.0 & f16::SIGN_MASK != 0;
+        let rhs_neg = rhs.0 & f16::SIGN_MASK != 0;
+        match (neg, rhs_neg) {
+            (false, false) => lhs.0 <= rhs.0,
+            (false, true) => (lhs.0 | rhs.0) & f16::NOT_SIGN == 0,
+            (true, false) => true,
+            (true, true) => lhs.0 >= rhs.0,
+        }
+    }
+}
+
+#[inline]
+const fn gt(lhs: f16, rhs: f16) -> bool {
+    if lhs.is_nan() || rhs.is_nan() {
+        false
+    } else {
+        let neg = lhs.0 & f16::SIGN_MASK != 0;
+        let rhs_neg = rhs.0 & f16::SIGN_MASK != 0;
+        match (neg, rhs_neg) {
+            (false, false) => lhs.0 > rhs.0,
+            (false, true) => (lhs.0 | rhs.0) & f16::NOT_SIGN != 0,
+            (true, false) => false,
+            (true, true) => lhs.0 < rhs.0,
+        }
+    }
+}
+
+#[cfg(not(feature = "total-order-impls"))]
+#[inline]
+const fn ge(lhs: f16, rhs: f16) -> bool {
+    if lhs.is_nan() || rhs.is_nan() {
+        false
+    } else {
+        let neg = lhs.0 & f16::SIGN_MASK != 0;
+        let rhs_neg = rhs.0 & f16::SIGN_MASK != 0;
+        match (neg, rhs_neg) {
+            (false, false) => lhs.0 >= rhs.0,
+            (false, true) => true,
+            (true, false) => (lhs.0 | rhs.0) & f16::NOT_SIGN == 0,
+            (true, true) => lhs.0 <= rhs.0,
+        }
+    }
+}
+
+#[allow(clippy::cognitive_complexity, clippy::float_cmp, clippy::neg_cmp_op_on_partial_ord)]
+#[cfg(test)]
+mod test {
+    use core::cmp::Ordering;
+    use core::mem;
+
+    use super::*;
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_f16_consts() {
+        // DIGITS
+        let digits = ((f16::MANTISSA_DIGITS as f32 - 1.0) * 2f32.log10()).floor() as u32;
+        assert_eq!(f16::DIGITS, digits);
+        // sanity check to show test is good
+        let digits32 = ((core::f32::MANTISSA_DIGITS as f32 - 1.0) * 2f32.log10()).floor() as u32;
+        assert_eq!(core::f32::DIGITS, digits32);
+
+        // EPSILON
+        let one = f16::from_f32(1.0);
+        let one_plus_epsilon = f16::from_bits(one.to_bits() + 1);
+        let epsilon = f16::from_f32(one_plus_epsilon.to_f32() - 1.0);
+        assert_eq!(f16::EPSILON, epsilon);
+        // sanity check to show test is good
+        let one_plus_epsilon32 = f32::from_bits(1.0f32.to_bits() + 1);
+        let epsilon32 = one_plus_epsilon32 - 1f32;
+        assert_eq!(core::f32::EPSILON, epsilon32);
+
+        // MAX, MIN and MIN_POSITIVE
+        let max = f16::from_bits(f16::INFINITY.to_bits() - 1);
+        let min = f16::from_bits(f16::NEG_INFINITY.to_bits() - 1);
+        let min_pos = f16::from_f32(2f32.powi(f16::MIN_EXP - 1));
+        assert_eq!(f16::MAX, max);
+        assert_eq!(f16::MIN, min);
+        assert_eq!(f16::MIN_POSITIVE, min_pos);
+        // sanity check to show test is good
+        let max32 = f32::from_bits(core::f32::INFINITY.to_bits() - 1);
+        let min32 = f32::from_bits(core::f32::NEG_INFINITY.to_bits() - 1);
+        let min_pos32 = 2f32.powi(core::f32::MIN_EXP - 1);
+        assert_eq!(core::f32::MAX, max32);
+        assert_eq!(core::f32::MIN, min32);
+        assert_eq!(core::f32::MIN_POSITIVE, min_pos32);
+
+        // MIN_10_EXP and MAX_10_EXP
+        let ten_to_min = 10f32.powi(f16::MIN_10_EXP);
+        assert!(ten_to_min / 10.0 < f16::MIN_POSITIVE.to_f32());
+        assert!(ten_to_min > f16::MIN_POSITIVE.to_f32());
+        let ten_to_max = 10f32.powi(f16::MAX_10_EXP);
+        assert!(ten_to_max < f16::MAX.to_f32());
+        assert!(ten_to_max * 10.0 > f16::MAX.to_f32());
+        // sanity check to show test is good
+        let ten_to_min32 = 10f64.powi(core::f32::MIN_10_EXP);
+        assert!(ten_to_min32 / 10.0 < f64::from(core::f32::MIN_POSITIVE));
+        assert!(ten_to_min32 > f64::from(core::f32::MIN_POSITIVE));
+        let ten_to_max32 = 10f64.powi(core::f32::MAX_10_EXP);
+        assert!(ten_to_max32 < f64::from(core::f32::MAX));
+        assert!(ten_to_max32 * 10.0 > f64::from(core::f32::MAX));
+    }
+
+    #[test]
+    fn test_f16_consts_from_f32() {
+        let one = f16::from_f32(1.0);
+        let zero = f16::from_f32(0.0);
+        let neg_zero = f16::from_f32(-0.0);
+        let neg_one = f16::from_f32(-1.0);
+        let inf = f16::from_f32(core::f32::INFINITY);
+        let neg_inf = f16::from_f32(core::f32::NEG_INFINITY);
+        let nan = f16::from_f32(core::f32::NAN);
+
+        assert_eq!(f16::ONE, one);
+        assert_eq!(f16::ZERO, zero);
+        assert!(zero.is_sign_positive());
+        assert_eq!(f16::NEG_ZERO, neg_zero);
+        assert!(neg_zero.is_sign_negative());
+        assert_eq!(f16::NEG_ONE, neg_one);
+        assert!(neg_one.is_sign_negative());
+        assert_eq!(f16::INFINITY, inf);
+        assert_eq!(f16::NEG_INFINITY, neg_inf);
+        assert!(nan.is_nan());
+        assert!(f16::NAN.is_nan());
+
+        let e = f16::from_f32(core::f32::consts::E);
+        let pi = f16::from_f32(core::f32::consts::PI);
+        let frac_1_pi = f16::from_f32(core::f32::consts::FRAC_1_PI);
+        let frac_1_sqrt_2 = f16::from_f32(core::f32::consts::FRAC_1_SQRT_2);
+        let frac_2_pi = f16::from_f32(core::f32::consts::FRAC_2_PI);
+        let frac_2_sqrt_pi = f16::from_f32(core::f32::consts::FRAC_2_SQRT_PI);
+        let frac_pi_2 = f16::from_f32(core::f32::consts::FRAC_PI_2);
+        let frac_pi_3 = f16::from_f32(core::f32::consts::FRAC_PI_3);
+        let frac_pi_4 = f16::from_f32(core::f32::consts::FRAC_PI_4);
+        let frac_pi_6 = f16::from_f32(core::f32::consts::FRAC_PI_6);
+        let frac_pi_8 = f16::from_f32(core::f32::consts::FRAC_PI_8);
+        let ln_10 = f16::from_f32(core::f32::consts::LN_10);
+        let ln_2 = f16::from_f32(core::f32::consts::LN_2);
+        let log10_e = f16::from_f32(core::f32::consts::LOG10_E);
+        // core::f32::consts::LOG10_2 requires rustc 1.43.0
+        let log10_2 = f16::from_f32(2f32.log10());
+        let log2_e = f16::from_f32(core::f32::consts::LOG2_E);
+        // core::f32::consts::LOG2_10 requires rustc 1.43.0
+        let log2_10 = f16::from_f32(10f32.log2());
+        let sqrt_2 = f16::from_f32(core::f32::consts::SQRT_2);
+
+        assert_eq!(f16::E, e);
+        assert_eq!(f16::PI, pi);
+        assert_eq!(f16::FRAC_1_PI, frac_1_pi);
+        assert_eq!(f16::FRAC_1_SQRT_2, frac_1_sqrt_2);
+        assert_eq!(f16::FRAC_2_PI, frac_2_pi);
+        assert_eq!(f16::FRAC_2_SQRT_PI, frac_2_sqrt_pi);
+        assert_eq!(f16::FRAC_PI_2, frac_pi_2);
+        assert_eq!(f16::FRAC_PI_3, frac_pi_3);
+        assert_eq!(f16::FRAC_PI_4, frac_pi_4);
+        assert_eq!(f16::FRAC_PI_6, frac_pi_6);
+        assert_eq!(f16::FRAC_PI_8, frac_pi_8);
+        assert_eq!(f16::LN_10, ln_10);
+        assert_eq!(f16::LN_2, ln_2);
+        assert_eq!(f16::LOG10_E, log10_e);
+        assert_eq!(f16::LOG10_2, log10_2);
+        assert_eq!(f16::LOG2_E, log2_e);
+        assert_eq!(f16::LOG2_10, log2_10);
+        assert_eq!(f16::SQRT_2, sqrt_2);
+    }
+
+    #[test]
+    fn test_f16_consts_from_f64() {
+        let one = f16::from_f64(1.0);
+        let zero = f16::from_f64(0.0);
+        let neg_zero = f16::from_f64(-0.0);
+        let inf = f16::from_f64(core::f64::INFINITY);
+        let neg_inf = f16::from_f64(core::f64::NEG_INFINITY);
+        let nan = f16::from_f64(core::f64::NAN);
+
+        assert_eq!(f16::ONE, one);
+        assert_eq!(f16::ZERO, zero);
+        assert!(zero.is_sign_positive());
+        assert_eq!(f16::NEG_ZERO, neg_zero);
+        assert!(neg_zero.is_sign_negative());
+        assert_eq!(f16::INFINITY, inf);
+        assert_eq!(f16::NEG_INFINITY, neg_inf);
+        assert!(nan.is_nan());
+        assert!(f16::NAN.is_nan());
+
+        let e = f16::from_f64(core::f64::consts::E);
+        let pi = f16::from_f64(core::f64::consts::PI);
+        let frac_1_pi = f16::from_f64(core::f64::consts::FRAC_1_PI);
+        let frac_1_sqrt_2 = f16::from_f64(core::f64::consts::FRAC_1_SQRT_2);
+        let frac_2_pi = f16::from_f64(core::f64::consts::FRAC_2_PI);
+        let frac_2_sqrt_pi = f16::from_f64(core::f64::consts::FRAC_2_SQRT_PI);
+        let frac_pi_2 = f16::from_f64(core::f64::consts::FRAC_PI_2);
+        let frac_pi_3 = f16::from_f64(core::f64::consts::FRAC_PI_3);
+        let frac_pi_4 = f16::from_f64(core::f64::consts::FRAC_PI_4);
+        let frac_pi_6 = f16::from_f64(core::f64::consts::FRAC_PI_6);
+        let frac_pi_8 = f16::from_f64(core::f64::consts::FRAC_PI_8);
+        let ln_10 = f16::from_f64(core::f64::consts::LN_10);
+        let ln_2 = f16::from_f64(core::f64::consts::LN_2);
+        let log10_e = f16::from_f64(core::f64::consts::LOG10_E);
+        // core::f64::consts::LOG10_2 requires rustc 1.43.0
+        let log10_2 = f16::from_f64(2f64.log10());
+        let log2_e = f16::from_f64(core::f64::consts::LOG2_E);
+        // core::f64::consts::LOG2_10 requires rustc 1.43.0
+        let log2_10 = f16::from_f64(10f64.log2());
+        let sqrt_2 = f16::from_f64(core::f64::consts::SQRT_2);
+
+        assert_eq!(f16::E, e);
+        assert_eq!(f16::PI, pi);
+        assert_eq!(f16::FRAC_1_PI, frac_1_pi);
+        assert_eq!(f16::FRAC_1_SQRT_2, frac_1_sqrt_2);
+        assert_eq!(f16::FRAC_2_PI, frac_2_pi);
+        assert_eq!(f16::FRAC_2_SQRT_PI, frac_2_sqrt_pi);
+        assert_eq!(f16::FRAC_PI_2, frac_pi_2);
+        assert_eq!(f16::FRAC_PI_3, frac_pi_3);
+        assert_eq!(f16::FRAC_PI_4, frac_pi_4);
+        assert_eq!(f16::FRAC_PI_6, frac_pi_6);
+        assert_eq!(f16::FRAC_PI_8, frac_pi_8);
+        assert_eq!(f16::LN_10, ln_10);
+        assert_eq!(f16::LN_2, ln_2);
+        assert_eq!(f16::LOG10_E, log10_e);
+        assert_eq!(f16::LOG10_2, log10_2);
+        assert_eq!(f16::LOG2_E, log2_e);
+        assert_eq!(f16::LOG2_10, log2_10);
+        assert_eq!(f16::SQRT_2, sqrt_2);
+    }
+
+    #[test]
+    fn test_nan_conversion_to_smaller() {
+        let nan64 = f64::from_bits(0x7FF0_0000_0000_0001u64);
+        let neg_nan64 = f64::from_bits(0xFFF0_0000_0000_0001u64);
+        let nan32 = f32::from_bits(0x7F80_0001u32);
+        let neg_nan32 = f32::from_bits(0xFF80_0001u32);
+        let nan32_from_64 = nan64 as f32;
+        let neg_nan32_from_64 = neg_nan64 as f32;
+        let nan16_from_64 = f16::from_f64(nan64);
+        let neg_nan16_from_64 = f16::from_f64(neg_nan64);
+        let nan16_from_32 = f16::from_f32(nan32);
+        let neg_nan16_from_32 = f16::from_f32(neg_nan32);
+
+        assert!(nan64.is_nan() && nan64.is_sign_positive());
+        assert!(neg_nan64.is_nan() && neg_nan64.is_sign_negative());
+        assert!(nan32.is_nan() && nan32.is_sign_positive());
+        assert!(neg_nan32.is_nan() && neg_nan32.is_sign_negative());
+
+        // f32/f64 NaN conversion sign is non-deterministic: https://github.com/VoidStarKat/half-rs/issues/103
+        assert!(nan32_from_64.is_nan());
+        assert!(neg_nan32_from_64.is_nan());
+        assert!(nan16_from_64.is_nan());
+        assert!(neg_nan16_from_64.is_nan());
+        assert!(nan16_from_32.is_nan());
+        assert!(neg_nan16_from_32.is_nan());
+    }
+
+    #[test]
+    fn test_nan_conversion_to_larger() {
+        let nan16 = f16::from_bits(0x7C01u16);
+        let neg_nan16 = f16::from_bits(0xFC01u16);
+        let nan32 = f32::from_bits(0x7F80_0001u32);
+        let neg_nan32 = f32::from_bits(0xFF80_0001u32);
+        let nan32_from_16 = f32::from(nan16);
+        let neg_nan32_from_16 = f32::from(neg_nan16);
+        let nan64_from_16 = f64::from(nan16);
+        let neg_nan64_from_16 = f64::from(neg_nan16);
+        let nan64_from_32 = f64::from(nan32);
+        let neg_nan64_from_32 = f64::from(neg_nan32);
+
+        assert!(nan16.is_nan() && nan16.is_sign_positive());
+        assert!(neg_nan16.is_nan() && neg_nan16.is_sign_negative());
+        assert!(nan32.is_nan() && nan32.is_sign_positive());
+        assert!(neg_nan32.is_nan() && neg_nan32.is_sign_negative());
+
+        // f32/f64 NaN conversion sign is non-deterministic: https://github.com/VoidStarKat/half-rs/issues/103
+        assert!(nan32_from_16.is_nan());
+        assert!(neg_nan32_from_16.is_nan());
+        assert!(nan64_from_16.is_nan());
+        assert!(neg_nan64_from_16.is_nan());
+        assert!(nan64_from_32.is_nan());
+        assert!(neg_nan64_from_32.is_nan());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_f16_to_f32() {
+        let f = f16::from_f32(7.0);
+        assert_eq!(f.to_f32(), 7.0f32);
+
+        // 7.1 is NOT exactly representable in 16-bit, it's rounded
+        let f = f16::from_f32(7.1);
+        let diff = (f.to_f32() - 7.1f32).abs();
+        // diff must be <= 4 * EPSILON, as 7 has two more significant bits than 1
+        assert!(diff <= 4.0 * f16::EPSILON.to_f32());
+
+        assert_eq!(f16::from_bits(0x0000_0001).to_f32(), 2.0f32.powi(-24));
+        assert_eq!(f16::from_bits(0x0000_0005).to_f32(), 5.0 * 2.0f32.powi(-24));
+
+        assert_eq!(f16::from_bits(0x0000_0001), f16::from_f32(2.0f32.powi(-24)));
+        assert_eq!(f16::from_bits(0x0000_0005), f16::from_f32(5.0 * 2.0f32.powi(-24)));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_f16_to_f64() {
+        let f = f16::from_f64(7.0);
+        assert_eq!(f.to_f64(), 7.0f64);
+
+        // 7.1 is NOT exactly representable in 16-bit, it's rounded
+        let f = f16::from_f64(7.1);
+        let diff = (f.to_f64() - 7.1f64).abs();
+        // diff must be <= 4 * EPSILON, as 7 has two more significant bits than 1
+        assert!(diff <= 4.0 * f16::EPSILON.to_f64());
+
+        assert_eq!(f16::from_bits(0x0000_0001).to_f64(), 2.0f64.powi(-24));
+        assert_eq!(f16::from_bits(0x0000_0005).to_f64(), 5.0 * 2.0f64.powi(-24));
+
+        assert_eq!(f16::from_bits(0x0000_0001), f16::from_f64(2.0f64.powi(-24)));
+        assert_eq!(f16::from_bits(0x0000_0005), f16::from_f64(5.0 * 2.0f64.powi(-24)));
+    }
+
+    #[test]
+    #[cfg(not(feature = "total-order-impls"))]
+    fn test_comparisons() {
+        let zero = f16::from_f64(0.0);
+        let one = f16::from_f64(1.0);
+        let neg_zero = f16::from_f64(-0.0);
+        let neg_one = f16::from_f64(-1.0);
+
+        assert_eq!(zero.partial_cmp(&neg_zero), Some(Ordering::Equal));
+        assert_eq!(neg_zero.partial_cmp(&zero), Some(Ordering::Equal));
+        assert!(zero == neg_zero);
+        assert!(neg_zero == zero);
+        assert!(!(zero != neg_zero));
+        assert!(!(neg_zero != zero));
+        assert!(!(zero < neg_zero));
+        assert!(!(neg_zero < zero));
+        assert!(zero <= neg_zero);
+        assert!(neg_zero <= zero);
+        assert!(!(zero > neg_zero));
+        assert!(!(neg_zero > zero));
+        assert!(zero >= neg_zero);
+        assert!(neg_zero >= zero);
+
+        assert_eq!(one.partial_cmp(&neg_zero), Some(Ordering::Greater));
+        assert_eq!(neg_zero.partial_cmp(&one), Some(Ordering::Less));
+        assert!(!(one == neg_zero));
+        assert!(!(neg_zero == one));
+        assert!(one != neg_zero);
+        assert!(neg_zero != one);
+        assert!(!(one < neg_zero));
+        assert!(neg_zero < one);
+        assert!(!(one <= neg_zero));
+        assert!(neg_zero <= one);
+        assert!(one > neg_zero);
+        assert!(!(neg_zero > one));
+        assert!(one >= neg_zero);
+        assert!(!(neg_zero >= one));
+
+        assert_eq!(one.partial_cmp(&neg_one), Some(Ordering::Greater));
+        assert_eq!(neg_one.partial_cmp(&one), Some(Ordering::Less));
+        assert!(!(one == neg_one));
+        assert!(!(neg_one == one));
+        assert!(one != neg_one);
+        assert!(neg_one != one);
+        assert!(!(one < neg_one));
+        assert!(neg_one < one);
+        assert!(!(one <= neg_one));
+        assert!(neg_one <= one);
+        assert!(one > neg_one);
+        assert!(!(neg_one > one));
+        assert!(one >= neg_one);
+        assert!(!(neg_one >= one));
+    }
+
+    #[test]
+    #[allow(clippy::erasing_op, clippy::identity_op)]
+    #[cfg_attr(miri, ignore)]
+    fn round_to_even_f32() {
+        // smallest positive subnormal = 0b0.0000_0000_01 * 2^-14 = 2^-24
+        let min_sub = f16::from_bits(1);
+        let min_sub_f = (-24f32).exp2();
+        assert_eq!(f16::from_f32(min_sub_f).to_bits(), min_sub.to_bits());
+        assert_eq!(f32::from(min_sub).to_bits(), min_sub_f.to_bits());
+
+        // 0.0000000000_011111 rounded to 0.0000000000 (< tie, no rounding)
+        // 0.0000000000_100000 rounded to 0.0000000000 (tie and even, remains at even)
+        // 0.0000000000_100001 rounded to 0.0000000001 (> tie, rounds up)
+        assert_eq!(f16::from_f32(min_sub_f * 0.49).to_bits(), min_sub.to_bits() * 0);
+        assert_eq!(f16::from_f32(min_sub_f * 0.50).to_bits(), min_sub.to_bits() * 0);
+        assert_eq!(f16::from_f32(min_sub_f * 0.51).to_bits(), min_sub.to_bits() * 1);
+
+        // 0.0000000001_011111 rounded to 0.0000000001 (< tie, no rounding)
+        // 0.0000000001_100000 rounded to 0.0000000010 (tie and odd, rounds up to even)
+        // 0.0000000001_100001 rounded to 0.0000000010 (> tie, rounds up)
+        assert_eq!(f16::from_f32(min_sub_f * 1.49).to_bits(), min_sub.to_bits() * 1);
+        assert_eq!(f16::from_f32(min_sub_f * 1.50).to_bits(), min_sub.to_bits() * 2);
+        assert_eq!(f16::from_f32(min_sub_f * 1.51).to_bits(), min_sub.to_bits() * 2);
+
+        // 0.0000000010_011111 rounded to 0.0000000010 (< tie, no rounding)
+        // 0.0000000010_100000 rounded to 0.0000000010 (tie and even, remains at even)
+        // 0.0000000010_100001 rounded to 0.0000000011 (> tie, rounds up)
+        assert_eq!(f16::from_f32(min_sub_f * 2.49).to_bits(), min_sub.to_bits() * 2);
+        assert_eq!(f16::from_f32(min_sub_f * 2.50).to_bits(), min_sub.to_bits() * 2);
+        assert_eq!(f16::from_f32(min_sub_f * 2.51).to_bits(), min_sub.to_bits() * 3);
+
+        assert_eq!(f16::from_f32(2000.49f32).to_bits(), f16::from_f32(2000.0).to_bits());
+        assert_eq!(f16::from_f32(2000.50f32).to_bits(), f16::from_f32(2000.0).to_bits());
+        assert_eq!(f16::from_f32(2000.51f32).to_bits(), f16::from_f32(2001.0).to_bits());
+        assert_eq!(f16::from_f32(2001.49f32).to_bits(), f16::from_f32(2001.0).to_bits());
+        assert_eq!(f16::from_f32(2001.50f32).to_bits(), f16::from_f32(2002.0).to_bits());
+        assert_eq!(f16::from_f32(2001.51f32).to_bits(), f16::from_f32(2002.0).to_bits());
+        assert_eq!(f16::from_f32(2002.49f32).to_bits(), f16::from_f32(2002.0).to_bits());
+        assert_eq!(f16::from_f32(2002.50f32).to_bits(), f16::from_f32(2002.0).to_bits());
+        assert_eq!(f16::from_f32(2002.51f32).to_bits(), f16::from_f32(2003.0).to_bits());
+    }
+
+    #[test]
+    #[allow(clippy::erasing_op, clippy::identity_op)]
+    #[cfg_attr(miri, ignore)]
+    fn round_to_even_f64() {
+        // smallest positive subnormal = 0b0.0000_0000_01 * 2^-14 = 2^-24
+        let min_sub = f16::from_bits(1);
+        let min_sub_f = (-24f64).exp2();
+        assert_eq!(f16::from_f64(min_sub_f).to_bits(), min_sub.to_bits());
+        assert_eq!(f64::from(min_sub).to_bits(), min_sub_f.to_bits());
+
+        // 0.0000000000_011111 rounded to 0.0000000000 (< tie, no rounding)
+        // 0.0000000000_100000 rounded to 0.0000000000 (tie and even, remains at even)
+        // 0.0000000000_100001 rounded to 0.0000000001 (> tie, rounds up)
+        assert_eq!(f16::from_f64(min_sub_f * 0.49).to_bits(), min_sub.to_bits() * 0);
+        assert_eq!(f16::from_f64(min_sub_f * 0.50).to_bits(), min_sub.to_bits() * 0);
+        assert_eq!(f16::from_f64(min_sub_f * 0.51).to_bits(), min_sub.to_bits() * 1);
+
+        // 0.0000000001_011111 rounded to 0.0000000001 (< tie, no rounding)
+        // 0.0000000001_100000 rounded to 0.0000000010 (tie and odd, rounds up to even)
+        // 0.0000000001_100001 rounded to 0.0000000010 (> tie, rounds up)
+        assert_eq!(f16::from_f64(min_sub_f * 1.49).to_bits(), min_sub.to_bits() * 1);
+        assert_eq!(f16::from_f64(min_sub_f * 1.50).to_bits(), min_sub.to_bits() * 2);
+        assert_eq!(f16::from_f64(min_sub_f * 1.51).to_bits(), min_sub.to_bits() * 2);
+
+        // 0.0000000010_011111 rounded to 0.0000000010 (< tie, no rounding)
+        // 0.0000000010_100000 rounded to 0.0000000010 (tie and even, remains at even)
+        // 0.0000000010_100001 rounded to 0.0000000011 (> tie, rounds up)
+        assert_eq!(f16::from_f64(min_sub_f * 2.49).to_bits(), min_sub.to_bits() * 2);
+        assert_eq!(f16::from_f64(min_sub_f * 2.50).to_bits(), min_sub.to_bits() * 2);
+        assert_eq!(f16::from_f64(min_sub_f * 2.51).to_bits(), min_sub.to_bits() * 3);
+
+        assert_eq!(f16::from_f64(2000.49f64).to_bits(), f16::from_f64(2000.0).to_bits());
+        assert_eq!(f16::from_f64(2000.50f64).to_bits(), f16::from_f64(2000.0).to_bits());
+        assert_eq!(f16::from_f64(2000.51f64).to_bits(), f16::from_f64(2001.0).to_bits());
+        assert_eq!(f16::from_f64(2001.49f64).to_bits(), f16::from_f64(2001.0).to_bits());
+        assert_eq!(f16::from_f64(2001.50f64).to_bits(), f16::from_f64(2002.0).to_bits());
+        assert_eq!(f16::from_f64(2001.51f64).to_bits(), f16::from_f64(2002.0).to_bits());
+        assert_eq!(f16::from_f64(2002.49f64).to_bits(), f16::from_f64(2002.0).to_bits());
+        assert_eq!(f16::from_f64(2002.50f64).to_bits(), f16::from_f64(2002.0).to_bits());
+        assert_eq!(f16::from_f64(2002.51f64).to_bits(), f16::from_f64(2003.0).to_bits());
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(f16::ONE + f16::ONE, f16::from_f32(2.));
+        assert_eq!(f16::ONE - f16::ONE, f16::ZERO);
+        assert_eq!(f16::ONE * f16::ONE, f16::ONE);
+        assert_eq!(f16::from_f32(2.) * f16::from_f32(2.), f16::from_f32(4.));
+        assert_eq!(f16::ONE / f16::ONE, f16::ONE);
+        assert_eq!(f16::from_f32(4.) / f16::from_f32(2.), f16::from_f32(2.));
+        assert_eq!(f16::from_f32(4.) % f16::from_f32(3.), f16::from_f32(1.));
+    }
+
+    #[test]
+    fn issue_116() {
+        // SEE: https://github.com/starkat99/half-rs/issues/116
+        //  This is lossy until `_mm_cvtpd_ph` will be stable on x86.
+        let max_diff = if cfg!(any(target_arch = "x86", target_arch = "x86_64")) {
+            1
+        } else {
+            0
+        };
+
+        // from the round-to-even section of the test case
+        let x: f64 = unsafe { mem::transmute(0x3f0ffbfffffffffcu64) };
+        let bits = f16::from_f64(x).to_bits();
+        let const_bits = f16::from_f64_const(x).to_bits();
+        let inst_bits = f16::from_f64_instrinsic(x).to_bits();
+        assert_eq!(const_bits, bits);
+        assert!(inst_bits.abs_diff(bits) <= max_diff);
+
+        // from the double rounding section of the test case
+        // comment from the cpython test case: should be 2047, if double-rounded
+        // 64>32>16, becomes 2048
+        let x: f64 = unsafe { mem::transmute(0x409ffdffffff0000u64) };
+        let bits = f16::from_f64(x).to_bits();
+        let const_bits = f16::from_f64_const(x).to_bits();
+        let inst_bits = f16::from_f64_instrinsic(x).to_bits();
+        assert_eq!(const_bits, bits);
+        assert!(inst_bits.abs_diff(bits) <= max_diff);
+    }
+
+    #[test]
+    fn from_f32_lossless() {
+        let from_f32 = |v: f32| f16::from_f32_lossless(v);
+        let roundtrip = |v: f32, expected: Option<f16>| {
+            let half = from_f32(v);
+            assert_eq!(half, expected);
+            if !expected.is_none() {
+                let as_f32 = expected.unwrap().to_f32_const();
+                assert_eq!(v, as_f32);
+            }
+        };
+
+        assert_eq!(from_f32(f32::NAN).map(f16::is_nan), Some(true));
+        roundtrip(f32::INFINITY, Some(f16::INFINITY));
+        roundtrip(f32::NEG_INFINITY, Some(f16::NEG_INFINITY));
+        roundtrip(f32::from_bits(0b0_00000000_00000000000000000000000), Some(f16(0)));
+        roundtrip(f32::from_bits(0b1_00000000_00000000000000000000000), Some(f16(f16::SIGN_MASK)));
+        roundtrip(f32::from_bits(1), None);
+
+        // special truncation with denormals, etc.
+        roundtrip(f32::from_bits(0b0_01100111_00000000000000000000000), Some(f16(1)));
+        roundtrip(f32::from_bits(0b0_01101000_00000000000000000000000), Some(f16(2)));
+        roundtrip(f32::from_bits(0b0_01101000_10000000000000000000000), Some(f16(3)));
+        roundtrip(f32::from_bits(0b0_01100111_10000000000000000000000), None);
+        roundtrip(f32::from_bits(0b0_01101000_11000000000000000000000), None);
+        // ~2.2888184e-5 and has bits until 16 to the end, so truncated 2. but this is
+        // denormal as f16
+        roundtrip(f32::from_bits(0b0_01101111_00000000000000000000000), Some(f16(0x100)));
+        roundtrip(f32::from_bits(0b0_01101111_10000000000000000000000), Some(f16(0x180)));
+        roundtrip(f32::from_bits(0b0_01101111_11000000000000000000000), Some(f16(0x1c0)));
+        roundtrip(f32::from_bits(0b0_01101111_11000001000000000000000), Some(f16(0x1c1)));
+        roundtrip(f32::from_bits(0b0_01101111_11000001100000000000000), None);
+        //2.0f32
+        roundtrip(f32::from_bits(0b0_10000000_00000000000000000000000), Some(f16(0x4000)));
+        roundtrip(f32::from_bits(0b0_10000000_10000000000000000000000), Some(f16(0x4200)));
+        roundtrip(f32::from_bits(0b0_10000000_10000000010000000000000), Some(f16(0x4201)));
+        roundtrip(f32::from_bits(0b0_10000000_10000000011000000000000), None);
+        // check overflow
+        roundtrip(f32::from_bits(0b0_10001111_00000000000000000000000), None);
+        roundtrip(f32::from_bits(0b0_10001110_00000000000000000000000), Some(f16(0x7800)));
+    }
+
+    #[test]
+    fn from_f64_lossless() {
+        let from_f64 = |v: f64| f16::from_f64_lossless(v);
+        let roundtrip = |v: f64, expected: Option<f16>| {
+            let half = from_f64(v);
+            assert_eq!(half, expected);
+            if !expected.is_none() {
+                let as_f64 = expected.unwrap().to_f64_const();
+                assert_eq!(v, as_f64);
+            }
+        };
+
+        assert_eq!(from_f64(f64::NAN).map(f16::is_nan), Some(true));
+        roundtrip(f64::INFINITY, Some(f16::INFINITY));
+        roundtrip(f64::NEG_INFINITY, Some(f16::NEG_INFINITY));
+        roundtrip(
+            f64::from_bits(0b0_00000000000_0000000000000000000000000000000000000000000000000000),
+            Some(f16(0)),
+        );
+        roundtrip(
+            f64::from_bits(0b1_00000000000_0000000000000000000000000000000000000000000000000000),
+            Some(f16(f16::SIGN_MASK)),
+        );
+        roundtrip(
+            f64::from_bits(0b0_01110001010_1010100101011010010110110111111110000111101000001111),
+            None,
+        );
+        // check overflow to inf
+        roundtrip(
+            f64::from_bits(0b0_10000001110_1000000000000000000000000000000000000000000000000000),
+            Some(f16(0x7a00)),
+        );
+        roundtrip(
+            f64::from_bits(0b0_10000001111_1000000000000000000000000000000000000000000000000000),
+            None,
+        );
+        // check denormals and truncation
+        roundtrip(
+            f64::from_bits(0b0_01111100111_0000000000000000000000000000000000000000000000000000),
+            Some(f16(1)),
+        );
+        roundtrip(
+            f64::from_bits(0b0_01111100111_1000000000000000000000000000000000000000000000000000),
+            None,
+        );
+        roundtrip(
+            f64::from_bits(0b0_01111101000_0000000000000000000000000000000000000000000000000000),
+            Some(f16(2)),
+        );
+        roundtrip(
+            f64::from_bits(0b0_01111101000_1000000000000000000000000000000000000000000000000000),
+            Some(f16(3)),
+        );
+        roundtrip(
+            f64::from_bits(0b0_01111101000_1100000000000000000000000000000000000000000000000000),
+            None,
+        );
+        // check basic, normal and positive numbers
+        roundtrip(
+            f64::from_bits(0b0_01111111000_0000000000000000000000000000000000000000000000000000),
+            Some(f16(0x2000)),
+        );
+        roundtrip(
+            f64::from_bits(0b0_01111111000_1000000000000000000000000000000000000000000000000000),
+            Some(f16(0x2200)),
+        );
+        roundtrip(
+            f64::from_bits(0b0_01111111000_1110000000000000000000000000000000000000000000000000),
+            Some(f16(0x2380)),
+        );
+        roundtrip(
+            f64::from_bits(0b0_01111111000_1110000001000000000000000000000000000000000000000000),
+            Some(f16(0x2381)),
+        );
+        roundtrip(
+            f64::from_bits(0b0_01111111000_1110000001100000000000000000000000000000000000000000),
+            None,
+        );
     }
-}
 
-impl TryFrom<f32> for f16 {
-    type Error = TryFromFloatError;
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_from_fixed_point() {
+        for &fractional_bits in &[0u32, 4, 8, 12, 16, 24, 30] {
+            let scale = (1u64 << fractional_bits) as f64;
+            for value in -(1i64 << 17)..(1i64 << 17) {
+                let expected = f16::from_f64_const(value as f64 / scale);
+                let actual = f16::from_fixed_point(value, fractional_bits);
+                assert_eq!(
+                    actual.to_bits(),
+                    expected.to_bits(),
+                    "{} >> {}",
+                    value,
+                    fractional_bits
+                );
+            }
+        }
 
-    #[inline]
-    fn try_from(x: f32) -> Result<Self, Self::Error> {
-        Self::from_f32_lossless(x).ok_or(TryFromFloatError(()))
+        assert_eq!(f16::from_fixed_point(65519, 0), f16::MAX);
+        assert_eq!(f16::from_fixed_point(65520, 0), f16::INFINITY);
+        assert_eq!(f16::from_fixed_point(i64::MAX, 0), f16::INFINITY);
+        assert_eq!(f16::from_fixed_point(i64::MIN, 0), f16::NEG_INFINITY);
+        assert_eq!(f16::from_fixed_point(i64::MIN, 48), f16::from_f32(-32768.));
+        assert_eq!(f16::from_fixed_point(1, 24), f16::MIN_POSITIVE_SUBNORMAL);
+        assert_eq!(f16::from_fixed_point(1, 25).to_bits(), 0);
+        assert_eq!(f16::from_fixed_point(3, 25), f16::from_bits(2));
+        assert_eq!(f16::from_fixed_point(i64::MAX, 200).to_bits(), 0);
+        assert_eq!(f16::from_fixed_point(-1, u32::MAX).to_bits(), 0x8000);
     }
-}
 
-impl TryFrom<f64> for f16 {
-    type Error = TryFromFloatError;
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_to_fixed_point() {
+        // Every finite value is an integer multiple of the smallest subnormal.
+        for bits in 0..=u16::MAX {
+            let value = f16::from_bits(bits);
+            let fixed = value.to_fixed_point(24);
+            if value.is_finite() {
+                let fixed = fixed.unwrap();
+                assert_eq!(fixed as f64, value.to_f64() * (1u64 << 24) as f64);
+                assert_eq!(f16::from_fixed_point(fixed, 24).to_f64(), value.to_f64());
+            } else {
+                assert_eq!(fixed, None);
+            }
+        }
 
-    #[inline]
-    fn try_from(x: f64) -> Result<Self, Self::Error> {
-        Self::from_f64_lossless(x).ok_or(TryFromFloatError(()))
+        assert_eq!(f16::from_f32(0.5).to_fixed_point(0), Some(0));
+        assert_eq!(f16::from_f32(1.5).to_fixed_point(0), Some(2));
+        assert_eq!(f16::from_f32(2.5).to_fixed_point(0), Some(2));
+        assert_eq!(f16::from_f32(-2.75).to_fixed_point(1), Some(-6));
+        assert_eq!(f16::MIN_POSITIVE_SUBNORMAL.to_fixed_point(0), Some(0));
+        assert_eq!(f16::MAX.to_fixed_point(47), Some(65504 << 47));
+        assert_eq!(f16::MAX.to_fixed_point(48), None);
+        assert_eq!(f16::from_f32(-32768.).to_fixed_point(48), Some(i64::MIN));
+        assert_eq!(f16::from_f32(32768.).to_fixed_point(48), None);
+        assert_eq!(f16::MAX.to_fixed_point(u32::MAX), None);
+        assert_eq!(f16::NAN.to_fixed_point(0), None);
+        assert_eq!(f16::NEG_INFINITY.to_fixed_point(0), None);
     }
-}
 
-impl PartialEq for f16 {
-    #[inline]
-    fn eq(&self, other: &f16) -> bool {
-        eq(*self, *other)
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_exp_log() {
+        let two = f16::from_f32(2.0);
+        assert_eq!(two.exp(), f16::from_f32(2.0f32.exp()));
+        assert_eq!(two.exp2(), f16::from_f32(4.0));
+        assert_eq!(two.ln(), f16::LN_2);
+        assert_eq!(two.log2(), f16::ONE);
+        assert_eq!(f16::from_f32(1000.0).log10(), f16::from_f32(3.0));
+
+        // Results overflow or underflow in the narrower type.
+        assert_eq!(f16::from_f32(12.0).exp(), f16::INFINITY);
+        assert_eq!(f16::from_f32(-20.0).exp(), f16::ZERO);
+
+        assert_eq!(f16::NEG_INFINITY.exp(), f16::ZERO);
+        assert_eq!(f16::INFINITY.exp(), f16::INFINITY);
+        assert_eq!(f16::ZERO.ln(), f16::NEG_INFINITY);
+        assert_eq!(f16::NEG_ONE.ln_1p(), f16::NEG_INFINITY);
+        assert_eq!(f16::NEG_INFINITY.exp_m1(), f16::NEG_ONE);
+        assert_eq!(f16::NEG_ZERO.exp_m1().to_bits(), f16::NEG_ZERO.to_bits());
+        assert_eq!(f16::NEG_ZERO.ln_1p().to_bits(), f16::NEG_ZERO.to_bits());
+        assert!(f16::NEG_ONE.log2().is_nan());
+        assert!(f16::NAN.exp().is_nan());
+        assert!(f16::NAN.ln().is_nan());
     }
-}
 
-impl PartialOrd for f16 {
-    #[inline]
-    fn partial_cmp(&self, other: &f16) -> Option<Ordering> {
-        if self.is_nan() || other.is_nan() {
-            None
-        } else {
-            let neg = self.0 & Self::SIGN_MASK != 0;
-            let other_neg = other.0 & Self::SIGN_MASK != 0;
-            match (neg, other_neg) {
-                (false, false) => Some(self.0.cmp(&other.0)),
-                (false, true) => {
-                    if (self.0 | other.0) & Self::NOT_SIGN == 0 {
-                        Some(Ordering::Equal)
-                    } else {
-                        Some(Ordering::Greater)
-                    }
-                },
-                (true, false) => {
-                    if (self.0 | other.0) & Self::NOT_SIGN == 0 {
-                        Some(Ordering::Equal)
-                    } else {
-                        Some(Ordering::Less)
-                    }
-                },
-                (true, true) => Some(other.0.cmp(&self.0)),
-            }
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_parts_roundtrip() {
+        for bits in 0..=u16::MAX {
+            let value = f16::from_bits(bits);
+            let (sign, exp, man) = value.to_parts();
+            assert_eq!(f16::from_parts(sign, exp, man).to_bits(), bits);
+            assert_eq!(f16::from_parts_checked(sign, exp, man).map(f16::to_bits), Some(bits));
         }
-    }
-
-    #[inline]
-    fn lt(&self, other: &f16) -> bool {
-        lt(*self, *other)
-    }
 
-    #[inline]
-    fn le(&self, other: &f16) -> bool {
-        le(*self, *other)
+        assert_eq!(f16::from_parts_checked(false, 0x20, 0), None);
+        assert_eq!(f16::from_parts_checked(false, 0, 0x400), None);
+        assert_eq!(f16::from_parts_checked(true, u8::MAX, u16::MAX), None);
     }
 
-    #[inline]
-    fn gt(&self, other: &f16) -> bool {
-        gt(*self, *other)
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn test_from_parts_invalid_exponent() {
+        let _ = f16::from_parts(false, 0x20, 0);
     }
 
-    #[inline]
-    fn ge(&self, other: &f16) -> bool {
-        ge(*self, *other)
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn test_from_parts_invalid_mantissa() {
+        let _ = f16::from_parts(false, 15, 0x400);
     }
-}
-
-#[cfg(not(target_arch = "spirv"))]
-impl FromStr for f16 {
-    type Err = ParseFloatError;
 
-    #[inline]
-    fn from_str(src: &str) -> Result<f16, ParseFloatError> {
-        f32::from_str(src).map(f16::from_f32)
-    }
-}
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_two_sum() {
+        let mut values = [f16::ZERO; 64 * 4 + 4];
+        crate::testing::fill_stratified(&mut values, 0x2516);
+        for &a in &values {
+            for &b in &values {
+                let (hi, lo) = f16::two_sum(a, b);
+                if !hi.is_finite() {
+                    assert!(lo.is_nan());
+                    continue;
+                }
+                assert_eq!(hi.to_bits(), (a + b).to_bits());
+                assert_eq!(hi.to_f64() + lo.to_f64(), a.to_f64() + b.to_f64(), "{} + {}", a, b);
+            }
+        }
 
-#[cfg(not(target_arch = "spirv"))]
-impl Debug for f16 {
-    #[inline]
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        Debug::fmt(&self.to_f32(), f)
+        let (hi, lo) = f16::two_sum(f16::MAX, f16::MAX);
+        assert_eq!(hi, f16::INFINITY);
+        assert!(lo.is_nan());
+        let (hi, lo) = f16::two_sum(f16::ONE, f16::MIN_POSITIVE_SUBNORMAL);
+        assert_eq!(hi, f16::ONE);
+        assert_eq!(lo, f16::MIN_POSITIVE_SUBNORMAL);
     }
-}
 
-#[cfg(not(target_arch = "spirv"))]
-impl Display for f16 {
-    #[inline]
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        Display::fmt(&self.to_f32(), f)
-    }
-}
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_two_prod() {
+        let mut values = [f16::ZERO; 64 * 4 + 4];
+        crate::testing::fill_stratified(&mut values, 0x2517);
+        for &a in &values {
+            for &b in &values {
+                let (hi, lo) = f16::two_prod(a, b);
+                if !hi.is_finite() {
+                    assert!(lo.is_nan());
+                    continue;
+                }
+                let exact = a.to_f64() * b.to_f64();
+                assert_eq!(hi.to_bits(), (a * b).to_bits());
+                assert_eq!(lo, f16::from_f64(exact - hi.to_f64()));
+                if hi.abs() >= f16::from_f32(0.25) {
+                    assert_eq!(hi.to_f64() + lo.to_f64(), exact, "{} * {}", a, b);
+                }
+            }
+        }
 
-#[cfg(not(target_arch = "spirv"))]
-impl LowerExp for f16 {
-    #[inline]
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:e}", self.to_f32())
+        let (hi, lo) = f16::two_prod(f16::MAX, f16::from_f32(2.0));
+        assert_eq!(hi, f16::INFINITY);
+        assert!(lo.is_nan());
     }
-}
 
-#[cfg(not(target_arch = "spirv"))]
-impl UpperExp for f16 {
-    #[inline]
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:E}", self.to_f32())
-    }
-}
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_from_f32_unchecked_normal() {
+        let min = f16::MIN_POSITIVE.to_f32().to_bits();
+        let max = 65520f32.to_bits();
+        for bits in (min..max).step_by(101).chain(max - 64..max) {
+            for &value in &[f32::from_bits(bits), -f32::from_bits(bits)] {
+                let expected = f16::from_f32_const(value);
+                let actual = unsafe { f16::from_f32_unchecked_normal(value) };
+                assert_eq!(actual.to_bits(), expected.to_bits(), "{}", value);
+            }
+        }
 
-#[cfg(not(target_arch = "spirv"))]
-impl Binary for f16 {
-    #[inline]
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:b}", self.0)
+        // Every normal value and the midpoints around it, including ties.
+        for bits in 0x0400u16..0x7C00 {
+            let value = f16::from_bits(bits).to_f32();
+            let ulp = f16::from_bits(bits + 1).to_f32() - value;
+            for &x in &[value, value + ulp / 2., value + ulp / 4., value - ulp / 4.] {
+                if x < f16::MIN_POSITIVE.to_f32() || x >= 65520. {
+                    continue;
+                }
+                let expected = f16::from_f32_const(x);
+                assert_eq!(unsafe { f16::from_f32_unchecked_normal(x) }, expected);
+                assert_eq!(unsafe { f16::from_f32_unchecked_normal(-x) }, -expected);
+            }
+        }
     }
-}
 
-#[cfg(not(target_arch = "spirv"))]
-impl Octal for f16 {
-    #[inline]
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:o}", self.0)
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn test_from_f32_unchecked_normal_subnormal() {
+        let _ = unsafe { f16::from_f32_unchecked_normal(1e-5) };
     }
-}
 
-#[cfg(not(target_arch = "spirv"))]
-impl LowerHex for f16 {
-    #[inline]
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:x}", self.0)
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn test_from_f32_unchecked_normal_overflow() {
+        let _ = unsafe { f16::from_f32_unchecked_normal(-65520.) };
     }
-}
 
-#[cfg(not(target_arch = "spirv"))]
-impl UpperHex for f16 {
-    #[inline]
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:X}", self.0)
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn test_from_f32_unchecked_normal_nan() {
+        let _ = unsafe { f16::from_f32_unchecked_normal(f32::NAN) };
     }
-}
-
-impl Neg for f16 {
-    type Output = Self;
 
-    #[inline]
-    fn neg(self) -> Self::Output {
-        Self(self.0 ^ Self::SIGN_MASK)
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_trigonometry() {
+        let x = f16::from_f32(0.5);
+        assert_eq!(x.sin(), f16::from_f32(x.to_f32().sin()));
+        assert_eq!(x.cos(), f16::from_f32(x.to_f32().cos()));
+        assert_eq!(x.tan(), f16::from_f32(x.to_f32().tan()));
+        assert_eq!(x.asin(), f16::from_f32(x.to_f32().asin()));
+        assert_eq!(x.acos(), f16::from_f32(x.to_f32().acos()));
+        assert_eq!(x.atan(), f16::from_f32(x.to_f32().atan()));
+        assert_eq!(x.atan2(f16::NEG_ONE), f16::from_f32(0.5f32.atan2(-1.0)));
+        assert_eq!(x.sin_cos(), (x.sin(), x.cos()));
+
+        assert_eq!(f16::NEG_ZERO.sin().to_bits(), f16::NEG_ZERO.to_bits());
+        assert_eq!(f16::ZERO.atan2(f16::NEG_ONE), f16::PI);
+        assert_eq!(f16::NEG_ZERO.atan2(f16::NEG_ONE), -f16::PI);
+        assert!(f16::INFINITY.sin().is_nan());
+        assert!(f16::NEG_INFINITY.cos().is_nan());
+        assert!(f16::NAN.tan().is_nan());
+        assert!(f16::from_f32(1.5).acos().is_nan());
+        assert!(f16::NAN.atan2(f16::ONE).is_nan());
+        let (sin, cos) = f16::INFINITY.sin_cos();
+        assert!(sin.is_nan() && cos.is_nan());
     }
-}
 
-impl Neg for &f16 {
-    type Output = <f16 as Neg>::Output;
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_recip_estimate() {
+        for bits in 0..0x7C00u16 {
+            for &x in &[f16::from_bits(bits), -f16::from_bits(bits)] {
+                let expected = x.recip();
+                let estimate = x.recip_estimate();
+                let refined = x.recip_refined();
+                if expected.is_infinite() {
+                    // Estimates near the overflow threshold may be finite.
+                    let min = f16::from_f32(32768.);
+                    assert!(estimate.abs() >= min && estimate.signum() == x.signum(), "{:?}", x);
+                    assert!(refined.abs() >= min && refined.signum() == x.signum(), "{:?}", x);
+                    continue;
+                }
+                // The raw estimate is only accurate to about 8 bits.
+                let diff = (estimate.to_f32() - expected.to_f32()).abs();
+                assert!(diff <= expected.to_f32().abs() / 256., "{:?}", x);
+                if x.is_normal() {
+                    let ulps = (refined.to_bits() as i32 - expected.to_bits() as i32).abs();
+                    assert!(ulps <= 1, "{:?}", x);
+                }
+            }
+        }
 
-    #[inline]
-    fn neg(self) -> Self::Output {
-        Neg::neg(*self)
+        assert_eq!(f16::ZERO.recip_estimate(), f16::INFINITY);
+        assert_eq!(f16::NEG_ZERO.recip_refined(), f16::NEG_INFINITY);
+        assert_eq!(f16::INFINITY.recip_estimate().to_bits(), 0);
+        assert_eq!(f16::NEG_INFINITY.recip_refined().to_bits(), 0x8000);
+        assert_eq!(f16::MIN_POSITIVE_SUBNORMAL.recip_estimate(), f16::INFINITY);
+        assert!(f16::NAN.recip_estimate().is_nan());
+        assert!(f16::NAN.recip_refined().is_nan());
     }
-}
-
-impl Add for f16 {
-    type Output = Self;
 
-    #[inline]
-    fn add(self, rhs: Self) -> Self::Output {
-        f16(arch::add_f16(self.0, rhs.0))
-    }
-}
+    #[test]
+    fn test_sum_product_slice() {
+        let mut values = [f16::ZERO; 300];
+        for (i, v) in values.iter_mut().enumerate() {
+            *v = f16::from_f32((i as f32 - 150.) * 0.37 + 0.01);
+        }
+        for len in [0, 1, 7, 64, 65, 300] {
+            let values = &values[..len];
+            let sum: f16 = values.iter().sum();
+            let product: f16 = values.iter().copied().product();
+            assert_eq!(f16::sum_slice(values).to_bits(), sum.to_bits());
+            assert_eq!(f16::product_slice(values).to_bits(), product.to_bits());
+        }
 
-impl Add<&f16> for f16 {
-    type Output = <f16 as Add<f16>>::Output;
+        // Iterators are buffered in chunks, which must not change the result
+        // of accumulating each value in `f32`.
+        #[cfg(not(target_arch = "aarch64"))]
+        for step in [1, 2, 3] {
+            let filtered = || values.iter().filter(move |v| v.to_bits() % step == 0);
+            let sum: f32 = filtered().map(|v| v.to_f32()).sum();
+            let product: f32 = filtered().map(|v| v.to_f32()).product();
+            assert_eq!(filtered().sum::<f16>().to_bits(), f16::from_f32(sum).to_bits());
+            assert_eq!(filtered().product::<f16>().to_bits(), f16::from_f32(product).to_bits());
+        }
 
-    #[inline]
-    fn add(self, rhs: &f16) -> Self::Output {
-        self.add(*rhs)
+        assert_eq!(f16::sum_slice(&[]).to_f64(), 0.);
+        assert_eq!(f16::product_slice(&[]), f16::ONE);
+        let zeros = [f16::NEG_ZERO; 3];
+        assert_eq!(f16::sum_slice(&zeros).to_bits(), zeros.iter().sum::<f16>().to_bits());
+        assert!(f16::sum_slice(&[f16::ONE, f16::NAN]).is_nan());
+        assert!(f16::product_slice(&[f16::INFINITY, f16::ZERO]).is_nan());
     }
-}
-
-impl Add<&f16> for &f16 {
-    type Output = <f16 as Add<f16>>::Output;
 
-    #[inline]
-    fn add(self, rhs: &f16) -> Self::Output {
-        (*self).add(*rhs)
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_sum_product_slice_large() {
+        let values = [f16::MAX; 100_000];
+        assert_eq!(f16::sum_slice(&values), f16::INFINITY);
+        assert_eq!(f16::sum_slice(&values), values.iter().sum());
+        let values = [f16::ONE; 100_000];
+        assert_eq!(f16::sum_slice(&values), values.iter().sum());
+        assert_eq!(f16::product_slice(&values), f16::ONE);
+        let values = [-f16::from_f32(1.001); 100_001];
+        assert_eq!(f16::product_slice(&values), values.iter().product());
     }
-}
 
-impl Add<f16> for &f16 {
-    type Output = <f16 as Add<f16>>::Output;
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(miri, ignore)]
+    fn test_display_round_trip() {
+        use std::string::ToString;
+
+        assert_eq!(f16::from_f32(1.1).to_string(), "1.1");
+        assert_eq!(f16::from_f32(65504.0).to_string(), "65500");
+        assert_eq!(f16::from_f32(-0.0).to_string(), "-0");
+        assert_eq!(f16::from_f32(0.1).to_string(), "0.1");
+        assert_eq!(format!("{:>+8}", f16::from_f32(1.1)), "    +1.1");
+
+        for bits in 0..=u16::MAX {
+            let value = f16::from_bits(bits);
+            let display = value.to_string();
+            let parsed: f16 = display.parse().unwrap();
+            if value.is_nan() {
+                assert!(parsed.is_nan());
+                continue;
+            }
+            assert_eq!(parsed.to_bits(), bits, "{}", display);
+            if value.is_infinite() {
+                continue;
+            }
 
-    #[inline]
-    fn add(self, rhs: f16) -> Self::Output {
-        (*self).add(rhs)
+            // Removing the last digit must not round-trip.
+            let digits =
+                display.trim_start_matches('-').trim_start_matches(['0', '.']).replace('.', "");
+            let digits = digits.trim_end_matches('0');
+            assert!(digits.len() <= 5, "{}", display);
+            if digits.len() > 1 {
+                let shorter = format!("{:.*e}", digits.len() - 2, value.to_f32());
+                let parsed: f16 = shorter.parse().unwrap();
+                assert_ne!(parsed.to_bits(), bits, "{} {}", display, shorter);
+            }
+        }
     }
-}
 
-impl AddAssign for f16 {
-    #[inline]
-    fn add_assign(&mut self, rhs: Self) {
-        *self = (*self).add(rhs);
-    }
-}
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(miri, ignore)]
+    fn test_exp_round_trip() {
+        assert_eq!(format!("{:e}", f16::from_f32(1.1)), "1.1e0");
+        assert_eq!(format!("{:E}", f16::from_f32(-1.1)), "-1.1E0");
+        assert_eq!(format!("{:e}", f16::ZERO), "0e0");
+        assert_eq!(format!("{:e}", f16::INFINITY), "inf");
+        assert_eq!(format!("{:e}", f16::NAN), "NaN");
+        assert_eq!(format!("{:>10e}", f16::from_f32(1.1)), "     1.1e0");
+        // An explicit precision formats the exact value.
+        assert_eq!(
+            format!("{:.7e}", f16::from_f32(1.1)),
+            format!("{:.7e}", f16::from_f32(1.1).to_f32())
+        );
+        assert_eq!(format!("{:.2E}", f16::from_f32(1.1)), "1.10E0");
 
-impl AddAssign<&f16> for f16 {
-    #[inline]
-    fn add_assign(&mut self, rhs: &f16) {
-        *self = (*self).add(rhs);
+        for bits in 0..=u16::MAX {
+            let value = f16::from_bits(bits);
+            if !value.is_finite() {
+                continue;
+            }
+            let lower = format!("{:e}", value);
+            let upper = format!("{:E}", value);
+            assert_eq!(lower.to_uppercase(), upper);
+            let parsed: f16 = lower.parse().unwrap();
+            assert_eq!(parsed.to_bits(), bits, "{}", lower);
+
+            // The significand has the same digits as the shortest display.
+            let digits = lower.trim_start_matches('-').split('e').next().unwrap().replace('.', "");
+            assert!(digits.len() <= 5, "{}", lower);
+            assert_eq!(lower.parse::<f64>().unwrap(), value.to_string().parse::<f64>().unwrap());
+        }
     }
-}
 
-impl Sub for f16 {
-    type Output = Self;
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(miri, ignore)]
+    fn test_hex_round_trip() {
+        for bits in 0..=u16::MAX {
+            let value = f16::from_bits(bits);
+            let hex = value.to_hex_string();
+            let parsed = f16::from_hex_str(&hex).unwrap();
+            if value.is_nan() {
+                assert_eq!(hex, "NaN");
+                assert!(parsed.is_nan());
+            } else {
+                assert_eq!(parsed.to_bits(), bits, "{}", hex);
+            }
+        }
+    }
 
-    #[inline]
-    fn sub(self, rhs: Self) -> Self::Output {
-        f16(arch::subtract_f16(self.0, rhs.0))
+    #[test]
+    fn test_from_hex_str() {
+        assert_eq!(f16::from_hex_str("0x1p0"), Ok(f16::ONE));
+        assert_eq!(f16::from_hex_str("+0X1P-0"), Ok(f16::ONE));
+        assert_eq!(f16::from_hex_str("0x.8p1"), Ok(f16::ONE));
+        assert_eq!(f16::from_hex_str("0x10p-4"), Ok(f16::ONE));
+        assert_eq!(f16::from_hex_str("0xAbC.dEfp0"), Ok(f16::from_f64(2748.87109375)));
+        assert_eq!(f16::from_hex_str("-0x0p0").map(f16::to_bits), Ok(0x8000));
+        assert_eq!(f16::from_hex_str("0x0.000p+99999999999999999999"), Ok(f16::ZERO));
+        // Ties to even, including with the sticky bit from long strings.
+        assert_eq!(f16::from_hex_str("0x1.002p0"), Ok(f16::ONE));
+        assert_eq!(f16::from_hex_str("0x1.006p0"), Ok(f16::from_bits(0x3C02)));
+        assert_eq!(f16::from_hex_str("0x1.0020000000000000000001p0"), Ok(f16::from_bits(0x3C01)));
+        assert_eq!(f16::from_hex_str("0x1.ffep15"), Ok(f16::INFINITY));
+        assert_eq!(f16::from_hex_str("0x1.ffdfffffffffffffffffp15"), Ok(f16::MAX));
+        assert_eq!(f16::from_hex_str("0x1p-25"), Ok(f16::ZERO));
+        assert_eq!(
+            f16::from_hex_str("0x1.000000000000000000001p-25"),
+            Ok(f16::MIN_POSITIVE_SUBNORMAL)
+        );
+        assert_eq!(f16::from_hex_str("0x1p+99999999999999999999"), Ok(f16::INFINITY));
+        assert_eq!(f16::from_hex_str("-INF"), Ok(f16::NEG_INFINITY));
+        assert_eq!(f16::from_hex_str("Infinity"), Ok(f16::INFINITY));
+        assert!(f16::from_hex_str("NaN").unwrap().is_nan());
+
+        for src in [
+            "",
+            "0x",
+            "0xp1",
+            "0x1",
+            "0x1p",
+            "0x1.8p+",
+            "0x1.8.8p1",
+            "0x1p1x",
+            " 0x1p1",
+            "1p1",
+            "0x1p1.5",
+            "0x1p--1",
+            "-inff",
+        ] {
+            assert_eq!(f16::from_hex_str(src), Err(ParseHalfError(())), "{}", src);
+        }
     }
-}
 
-impl Sub<&f16> for f16 {
-    type Output = <f16 as Sub<f16>>::Output;
+    /// `frexp` from C, returning a significand in `[0.5, 1)` and an exponent.
+    fn frexp(x: f64) -> (f64, i32) {
+        let bits = x.to_bits();
+        let biased = ((bits >> 52) & 0x7FF) as i32;
+        if biased == 0 {
+            // Only zero can be subnormal, since every f16 is normal as an f64.
+            assert_eq!(x, 0.);
+            return (x, 0);
+        }
+        let man = f64::from_bits((bits & !(0x7FFu64 << 52)) | (1022u64 << 52));
+        (man, biased - 1022)
+    }
 
-    #[inline]
-    fn sub(self, rhs: &f16) -> Self::Output {
-        self.sub(*rhs)
+    #[test]
+    fn test_exponents() {
+        let range = f16::exponent_range();
+        assert_eq!(range, -14..=15);
+        assert_eq!(*range.start(), f16::MIN_EXP - 1);
+        assert_eq!(*range.end(), f16::MAX_EXP - 1);
+        assert_eq!(f16::subnormal_exponent(), -24);
+        assert_eq!(f16::MIN_POSITIVE.decode_exponent(), Some(*range.start()));
+        assert_eq!(f16::MAX.decode_exponent(), Some(*range.end()));
+        assert_eq!(f16::MIN_POSITIVE_SUBNORMAL.decode_exponent(), Some(f16::subnormal_exponent()));
+        assert_eq!(f16::MIN_POSITIVE.to_f64(), 2f64.powi(*range.start()));
+        assert_eq!(f16::MIN_POSITIVE_SUBNORMAL.to_f64(), 2f64.powi(f16::subnormal_exponent()));
+        assert_eq!(frexp(f16::MAX.to_f64()).1, f16::MAX_EXP);
+        assert_eq!(frexp(f16::MIN_POSITIVE.to_f64()).1, f16::MIN_EXP);
     }
-}
 
-impl Sub<&f16> for &f16 {
-    type Output = <f16 as Sub<f16>>::Output;
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_decode_exponent() {
+        for bits in 0..=u16::MAX {
+            let value = f16::from_bits(bits);
+            let exp = value.decode_exponent();
+            if value.is_finite() && value.to_f64() != 0. {
+                let (man, frexp_exp) = frexp(value.to_f64());
+                assert!((0.5..1.).contains(&man.abs()));
+                assert_eq!(exp, Some(frexp_exp - 1), "{:?}", value);
+                assert_eq!(value.is_normal(), f16::exponent_range().contains(&exp.unwrap()));
+            } else {
+                assert_eq!(exp, None, "{:?}", value);
+            }
+        }
+    }
 
-    #[inline]
-    fn sub(self, rhs: &f16) -> Self::Output {
-        (*self).sub(*rhs)
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_rsqrt() {
+        assert_eq!(f16::ZERO.rsqrt().to_bits(), f16::INFINITY.to_bits());
+        assert_eq!(f16::NEG_ZERO.rsqrt().to_bits(), f16::NEG_INFINITY.to_bits());
+        assert_eq!(f16::INFINITY.rsqrt().to_bits(), f16::ZERO.to_bits());
+        assert!(f16::NEG_INFINITY.rsqrt().is_nan());
+        assert!(f16::NEG_ONE.rsqrt().is_nan());
+        assert!(f16::from_f32(-0.25).rsqrt().is_nan());
+        assert!(f16::NAN.rsqrt().is_nan());
+        assert_eq!(f16::from_f32(0.25).rsqrt(), f16::from_f32(2.0));
+
+        // Within 1 ulp of the result computed in `f64` for every normal value.
+        for bits in f16::MIN_POSITIVE.to_bits()..=f16::MAX.to_bits() {
+            let x = f16::from_bits(bits);
+            let expected = f16::from_f64(1.0 / x.to_f64().sqrt());
+            let actual = x.rsqrt();
+            assert!(actual.is_finite(), "{:?}", x);
+            let distance = actual.ulp_distance(expected).unwrap();
+            assert!(distance <= 1, "{:?}: {:?} != {:?}", x, actual, expected);
+        }
     }
-}
 
-impl Sub<f16> for &f16 {
-    type Output = <f16 as Sub<f16>>::Output;
+    #[test]
+    fn test_ieee_rem() {
+        let f = f16::from_f32;
+        let two = f(2.0);
+        // Half-way quotients round to even.
+        assert_eq!(f(1.0).ieee_rem(two), f(1.0));
+        assert_eq!(f(3.0).ieee_rem(two), f(-1.0));
+        assert_eq!(f(5.0).ieee_rem(two), f(1.0));
+        assert_eq!(f(7.0).ieee_rem(two), f(-1.0));
+        assert_eq!(f(-5.0).ieee_rem(two), f(-1.0));
+        assert_eq!(f(-7.0).ieee_rem(two), f(1.0));
+        assert_eq!(f(5.0).ieee_rem(f(-2.0)), f(1.0));
+        assert_eq!(f(0.75).ieee_rem(f(0.5)), f(-0.25));
+        assert_eq!(f(1.25).ieee_rem(f(0.5)), f(0.25));
+        // Other quotients round to nearest.
+        assert_eq!(f(5.5).ieee_rem(two), f(-0.5));
+        assert_eq!(f(4.5).ieee_rem(two), f(0.5));
+        assert_eq!(f16::MAX.ieee_rem(f16::MIN_POSITIVE_SUBNORMAL), f16::ZERO);
+        assert_eq!(f16::MIN_POSITIVE_SUBNORMAL.ieee_rem(f16::MAX), f16::MIN_POSITIVE_SUBNORMAL);
+
+        // Zero results keep the sign of the dividend.
+        assert_eq!(f(4.0).ieee_rem(two).to_bits(), f16::ZERO.to_bits());
+        assert_eq!(f(-4.0).ieee_rem(two).to_bits(), f16::NEG_ZERO.to_bits());
+
+        for x in [f16::ZERO, f16::NEG_ZERO, f16::ONE, f16::MIN, f16::MIN_POSITIVE_SUBNORMAL] {
+            assert_eq!(x.ieee_rem(f16::INFINITY).to_bits(), x.to_bits());
+            assert_eq!(x.ieee_rem(f16::NEG_INFINITY).to_bits(), x.to_bits());
+            assert!(x.ieee_rem(f16::ZERO).is_nan());
+            assert!(x.ieee_rem(f16::NEG_ZERO).is_nan());
+            assert!(x.ieee_rem(f16::NAN).is_nan());
+            assert!(f16::NAN.ieee_rem(x).is_nan());
+            assert!(f16::INFINITY.ieee_rem(x).is_nan());
+            assert!(f16::NEG_INFINITY.ieee_rem(x).is_nan());
+        }
 
-    #[inline]
-    fn sub(self, rhs: f16) -> Self::Output {
-        (*self).sub(rhs)
+        // The result is within half of the divisor and differs from the
+        // dividend by an integer multiple of the divisor.
+        for x in (0..=u16::MAX).step_by(97).map(f16::from_bits).filter(|x| x.is_finite()) {
+            for y in (1..0x7C00).step_by(89).map(f16::from_bits) {
+                let r = x.ieee_rem(y).to_f64();
+                assert!(r.abs() <= y.to_f64() / 2.0, "{:?} {:?}", x, y);
+                let n = (x.to_f64() - r) / y.to_f64();
+                assert_eq!(n, n.trunc(), "{:?} {:?}", x, y);
+                if r.abs() == y.to_f64() / 2.0 {
+                    assert_eq!(n % 2.0, 0.0, "{:?} {:?}", x, y);
+                }
+            }
+        }
     }
-}
 
-impl SubAssign for f16 {
-    #[inline]
-    fn sub_assign(&mut self, rhs: Self) {
-        *self = (*self).sub(rhs);
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(miri, ignore)]
+    fn test_ordered_bits() {
+        // Every non-NaN value in total order maps to consecutive integers.
+        let mut values: Vec<f16> = (0..=u16::MAX).map(f16::from_bits).collect();
+        values.sort_by(|a, b| a.total_cmp(b));
+        for pair in values.windows(2) {
+            assert_eq!(pair[0].to_ordered_bits() + 1, pair[1].to_ordered_bits());
+        }
+        assert_eq!(values[0].to_ordered_bits(), 0);
     }
-}
 
-impl SubAssign<&f16> for f16 {
-    #[inline]
-    fn sub_assign(&mut self, rhs: &f16) {
-        *self = (*self).sub(rhs);
+    #[test]
+    fn test_ulp_distance() {
+        let ulp = f16::MIN_POSITIVE_SUBNORMAL;
+        assert_eq!(f16::ONE.ulp_distance(f16::ONE), Some(0));
+        assert_eq!(f16::ONE.ulp_distance(f16::ONE + f16::EPSILON), Some(1));
+        assert_eq!(f16::ONE.ulp_distance(f16::from_bits(0x3BFF)), Some(1));
+        assert_eq!(f16::ZERO.ulp_distance(f16::NEG_ZERO), Some(0));
+        assert_eq!(f16::NEG_ZERO.ulp_distance(ulp), Some(1));
+        assert_eq!(f16::ZERO.ulp_distance(-ulp), Some(1));
+        assert_eq!((-ulp).ulp_distance(ulp), Some(2));
+        assert_eq!(f16::MAX.ulp_distance(f16::INFINITY), Some(1));
+        assert_eq!(f16::MIN.ulp_distance(f16::MAX), Some(2 * 0x7BFF));
+        assert_eq!(f16::NEG_INFINITY.ulp_distance(f16::INFINITY), Some(2 * 0x7C00));
+        assert_eq!(f16::NAN.ulp_distance(f16::ONE), None);
+        assert_eq!(f16::ONE.ulp_distance(-f16::NAN), None);
+        assert_eq!(f16::NAN.ulp_distance(f16::NAN), None);
+
+        // Symmetric, and consistent with the distance from zero.
+        for bits in (0..=u16::MAX).step_by(251) {
+            let x = f16::from_bits(bits);
+            for other in (0..=u16::MAX).step_by(257) {
+                let y = f16::from_bits(other);
+                assert_eq!(x.ulp_distance(y), y.ulp_distance(x));
+                if !x.is_nan() && !y.is_nan() && x.is_sign_negative() != y.is_sign_negative() {
+                    let expected = (bits & 0x7FFF) + (other & 0x7FFF);
+                    assert_eq!(x.ulp_distance(y), Some(expected));
+                }
+            }
+        }
     }
-}
 
-impl Mul for f16 {
-    type Output = Self;
+    #[test]
+    fn test_lerp() {
+        let f = f16::from_f32;
+        let values = [
+            f16::MIN,
+            f(-3.5),
+            f16::NEG_ZERO,
+            f16::MIN_POSITIVE_SUBNORMAL,
+            f(0.1),
+            f(7.0),
+            f16::MAX,
+        ];
+        for &a in &values {
+            for &b in &values {
+                // Endpoints are exact.
+                assert_eq!(a.lerp(b, f16::ZERO).to_f64(), a.to_f64(), "{:?} {:?}", a, b);
+                assert_eq!(a.lerp(b, f16::ONE).to_f64(), b.to_f64(), "{:?} {:?}", a, b);
+
+                // Monotonic in `t`.
+                let mut previous = a.lerp(b, f(-2.0));
+                for i in -15..=24 {
+                    let value = a.lerp(b, f(i as f32 / 8.0));
+                    if a.to_f64() <= b.to_f64() {
+                        assert!(previous.to_f64() <= value.to_f64(), "{:?} {:?} {}", a, b, i);
+                    } else {
+                        assert!(previous.to_f64() >= value.to_f64(), "{:?} {:?} {}", a, b, i);
+                    }
+                    previous = value;
+                }
+            }
+        }
 
-    #[inline]
-    fn mul(self, rhs: Self) -> Self::Output {
-        f16(arch::multiply_f16(self.0, rhs.0))
+        // Extrapolation.
+        let (a, b) = (f(2.0), f(4.0));
+        assert_eq!(a.lerp(b, f(0.5)), f(3.0));
+        assert_eq!(a.lerp(b, f(-1.0)), f16::ZERO);
+        assert_eq!(a.lerp(b, f(3.0)), f(8.0));
+        assert_eq!(a.lerp(b, f16::from_f32(1e5)), f16::INFINITY);
+
+        // NaN propagation.
+        assert!(f16::NAN.lerp(b, f16::ZERO).is_nan());
+        assert!(a.lerp(f16::NAN, f16::ONE).is_nan());
+        assert!(a.lerp(b, f16::NAN).is_nan());
     }
-}
-
-impl Mul<&f16> for f16 {
-    type Output = <f16 as Mul<f16>>::Output;
 
-    #[inline]
-    fn mul(self, rhs: &f16) -> Self::Output {
-        self.mul(*rhs)
+    /// Returns the value of `f16` in units of `2^-48`.
+    fn scaled(x: f16) -> i128 {
+        (x.to_f64() * SCALE) as i128
     }
-}
 
-impl Mul<&f16> for &f16 {
-    type Output = <f16 as Mul<f16>>::Output;
+    const SCALE: f64 = 281474976710656.0;
 
-    #[inline]
-    fn mul(self, rhs: &f16) -> Self::Output {
-        (*self).mul(*rhs)
+    fn from_ordered_bits(key: u16) -> f16 {
+        if key & 0x8000 != 0 {
+            f16::from_bits(key & 0x7FFF)
+        } else {
+            f16::from_bits(!key)
+        }
     }
-}
 
-impl Mul<f16> for &f16 {
-    type Output = <f16 as Mul<f16>>::Output;
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_lerp_const() {
+        // Any finite value.
+        let sample = |state: &mut u64| loop {
+            *state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            let x = f16::from_bits((*state >> 48) as u16);
+            if x.is_finite() {
+                return x;
+            }
+        };
 
-    #[inline]
-    fn mul(self, rhs: f16) -> Self::Output {
-        (*self).mul(rhs)
-    }
-}
+        let mut state = 0x853C_49E6_748F_EA9Bu64;
+        for _ in 0..100_000 {
+            let (a, b, t) = (sample(&mut state), sample(&mut state), sample(&mut state));
+            let result = a.lerp_const(b, t);
+
+            // The exact value, in units of `2^-48`.
+            let (sa, sb, st) = (scaled(a), scaled(b), scaled(t));
+            let unit = 1i128 << (48 / 2);
+            let exact = sa + (st / unit) * ((sb - sa) / unit);
+            if result.is_infinite() {
+                let limit = (65520.0 * SCALE) as i128;
+                assert!(exact.abs() >= limit && (exact < 0) == result.is_sign_negative());
+                continue;
+            }
+            if exact == 0 {
+                assert_eq!(result.to_bits(), 0, "{:?} {:?} {:?}", a, b, t);
+                continue;
+            }
 
-impl MulAssign for f16 {
-    #[inline]
-    fn mul_assign(&mut self, rhs: Self) {
-        *self = (*self).mul(rhs);
+            // Nearer than either neighbor, or a tie with an even mantissa.
+            let value = scaled(result);
+            let key = result.to_ordered_bits();
+            for &neighbor in &[key - 1, key + 1] {
+                let other = from_ordered_bits(neighbor);
+                if !other.is_finite() || other == result {
+                    continue;
+                }
+                let (distance, other_distance) =
+                    ((exact - value).abs(), (exact - scaled(other)).abs());
+                assert!(
+                    distance < other_distance
+                        || (distance == other_distance && result.to_bits() & 1 == 0),
+                    "{:?} {:?} {:?} {:?}",
+                    a,
+                    b,
+                    t,
+                    result
+                );
+            }
+        }
+
+        // Rounding to the extremes of the range.
+        let f = f16::from_f32;
+        assert_eq!(f16::MAX.lerp_const(f16::MIN, f(-0.25)), f16::INFINITY);
+        assert_eq!(f16::MAX.lerp_const(f16::ZERO, f(-0.0001)), f16::MAX);
+        let tiny = f16::MIN_POSITIVE_SUBNORMAL;
+        assert_eq!(tiny.lerp_const(-tiny, f(0.5)).to_bits(), 0);
+        assert_eq!(tiny.lerp_const(-tiny, f(0.25)), f16::ZERO);
+        assert_eq!(tiny.lerp_const(tiny + tiny + tiny, f(0.25)), tiny + tiny);
+
+        // Endpoints are exact, even for non-finite values.
+        assert_eq!(f16::INFINITY.lerp_const(f16::ONE, f16::ZERO), f16::INFINITY);
+        assert_eq!(f16::ONE.lerp_const(f16::NEG_INFINITY, f16::ONE), f16::NEG_INFINITY);
+        assert_eq!(f16::NEG_ZERO.lerp_const(f16::ONE, f16::NEG_ZERO).to_bits(), 0x8000);
+        assert!(f16::INFINITY.lerp_const(f16::ONE, f16::from_f32(0.5)).is_nan());
+        assert!(f16::NAN.lerp_const(f16::ONE, f16::ZERO).is_nan());
+        assert!(f16::ONE.lerp_const(f16::NAN, f16::ONE).is_nan());
+        assert!(f16::ONE.lerp_const(f16::ONE, f16::INFINITY).is_nan());
     }
-}
 
-impl MulAssign<&f16> for f16 {
-    #[inline]
-    fn mul_assign(&mut self, rhs: &f16) {
-        *self = (*self).mul(rhs);
+    /// Rounds half-way cases away from zero with an `f64` reference, since
+    /// `x + 0.5` is exact for every f16 value below `2^52`.
+    fn round_ties_away_reference(x: f64) -> f64 {
+        if x.is_sign_negative() {
+            -round_ties_away_reference(-x)
+        } else {
+            (x + 0.5) as i64 as f64
+        }
     }
-}
 
-impl Div for f16 {
-    type Output = Self;
+    #[test]
+    fn test_round_ties_away() {
+        let f = f16::from_f32;
+        assert_eq!(f(0.5).round_ties_away(), f(1.0));
+        assert_eq!(f(1.5).round_ties_away(), f(2.0));
+        assert_eq!(f(2.5).round_ties_away(), f(3.0));
+        assert_eq!(f(-2.5).round_ties_away(), f(-3.0));
+        assert_eq!(f(3.5).round_ties_away(), f(4.0));
+        assert_eq!(f(1024.0).round_ties_away(), f(1024.0));
+        assert_eq!(f16::MAX.round_ties_away(), f16::MAX);
+        assert_eq!(f16::MIN.round_ties_away(), f16::MIN);
+        assert_eq!(f16::INFINITY.round_ties_away(), f16::INFINITY);
+        assert_eq!(f16::NEG_INFINITY.round_ties_away(), f16::NEG_INFINITY);
+        assert!(f16::NAN.round_ties_away().is_nan());
+        assert_eq!(f16::MIN_POSITIVE_SUBNORMAL.round_ties_away().to_bits(), 0);
+        assert_eq!((-f16::MIN_POSITIVE).round_ties_away().to_bits(), f16::NEG_ZERO.to_bits());
+        assert_eq!(f16::NEG_ZERO.round_ties_away().to_bits(), f16::NEG_ZERO.to_bits());
+
+        // Every value in [0, 4], and their negatives.
+        for bits in 0..=f(4.0).to_bits() {
+            for x in [f16::from_bits(bits), -f16::from_bits(bits)] {
+                let rounded = x.round_ties_away();
+                let expected = round_ties_away_reference(x.to_f64());
+                assert_eq!(rounded.to_f64(), expected, "{:?}", x);
+                assert_eq!(rounded.is_sign_negative(), x.is_sign_negative(), "{:?}", x);
+            }
+        }
 
-    #[inline]
-    fn div(self, rhs: Self) -> Self::Output {
-        f16(arch::divide_f16(self.0, rhs.0))
+        // Every finite value.
+        for bits in 0..=u16::MAX {
+            let x = f16::from_bits(bits);
+            if x.is_finite() && (-1e15..1e15).contains(&x.to_f64()) {
+                assert_eq!(x.round_ties_away().to_f64(), round_ties_away_reference(x.to_f64()));
+            } else if x.is_finite() {
+                assert_eq!(x.round_ties_away(), x);
+            }
+        }
     }
-}
 
-impl Div<&f16> for f16 {
-    type Output = <f16 as Div<f16>>::Output;
+    #[test]
+    fn test_consecutive_integers() {
+        let f = f16::from_f32;
+        assert_eq!(f16::MAX_CONSECUTIVE_INTEGER, f(2048.));
+        assert!(f16::can_represent_integer(2048 - 1));
+        assert!(f16::can_represent_integer(2048));
+        assert!(!f16::can_represent_integer(2048 + 1));
+        assert!(f16::can_represent_integer(2048 + 2));
+        assert!(f16::can_represent_integer(-2048));
+        assert!(!f16::can_represent_integer(-2048 - 1));
+        assert!(f16::can_represent_integer(0));
+        assert!(!f16::can_represent_integer(i64::MAX));
+        assert_eq!(f16::can_represent_integer(i64::MIN), f16::MAX_EXP > 63);
+
+        assert_eq!(f(2047.).increment_integer(), Some(f16::MAX_CONSECUTIVE_INTEGER));
+        assert_eq!(f16::MAX_CONSECUTIVE_INTEGER.increment_integer(), None);
+        assert_eq!(f(2050.).increment_integer(), None);
+        assert_eq!(f(-2048.).increment_integer(), Some(f(-2047.)));
+        assert_eq!(f(-1.).increment_integer(), Some(f16::ZERO));
+        assert_eq!(f16::NEG_ZERO.increment_integer(), Some(f16::ONE));
+        assert_eq!(f(1.5).increment_integer(), None);
+        assert_eq!(f16::MIN_POSITIVE_SUBNORMAL.increment_integer(), None);
+        assert_eq!(f16::MAX.increment_integer(), None);
+        assert_eq!(f16::MIN.increment_integer(), None);
+        assert_eq!(f16::INFINITY.increment_integer(), None);
+        assert_eq!(f16::NAN.increment_integer(), None);
+
+        // Counting up from the smallest consecutive integer stops exactly at
+        // the limit.
+        let mut counter = -f16::MAX_CONSECUTIVE_INTEGER;
+        let mut count = 0;
+        while let Some(next) = counter.increment_integer() {
+            assert_eq!(next.to_f32(), counter.to_f32() + 1.);
+            counter = next;
+            count += 1;
+        }
+        assert_eq!(counter, f16::MAX_CONSECUTIVE_INTEGER);
+        assert_eq!(count, 2 * 2048);
 
-    #[inline]
-    fn div(self, rhs: &f16) -> Self::Output {
-        self.div(*rhs)
+        // Representable integers are exactly those that round-trip.
+        for n in -70_000i64..=70_000 {
+            let roundtrip = f16::from_f64(n as f64).to_f64() == n as f64;
+            assert_eq!(f16::can_represent_integer(n), roundtrip, "{}", n);
+        }
+        // Every integer below 2^53 is exact as an `f64`.
+        for shift in 0..52 {
+            for n in [1i64 << shift, (1i64 << shift) + 1, 0xFF << shift, -(0x1FF << shift)] {
+                let roundtrip = f16::from_f64(n as f64).to_f64() == n as f64;
+                assert_eq!(f16::can_represent_integer(n), roundtrip, "{}", n);
+            }
+        }
     }
-}
 
-impl Div<&f16> for &f16 {
-    type Output = <f16 as Div<f16>>::Output;
+    #[test]
+    fn test_ulps_eq() {
+        // The classic case of two results one rounding step apart.
+        let a = f16::from_f32(0.1) + f16::from_f32(0.2);
+        let b = f16::from_f32(0.3);
+        let distance = a.ulp_distance(b).unwrap();
+        assert!(distance > 0);
+        assert!(a.ulps_eq(b, distance));
+        assert!(!a.ulps_eq(b, distance - 1));
+        assert!(a.approx_eq(b, f16::EPSILON, 0));
+        assert!(!a.approx_eq(b, f16::ZERO, distance - 1));
+
+        // Across zero.
+        let tiny = f16::MIN_POSITIVE_SUBNORMAL;
+        assert!(f16::ZERO.ulps_eq(f16::NEG_ZERO, 0));
+        assert!(tiny.ulps_eq(-tiny, 2));
+        assert!(!tiny.ulps_eq(-tiny, 1));
+        assert!(f16::MIN_POSITIVE.approx_eq(
+            -f16::MIN_POSITIVE,
+            f16::MIN_POSITIVE + f16::MIN_POSITIVE,
+            0
+        ));
 
-    #[inline]
-    fn div(self, rhs: &f16) -> Self::Output {
-        (*self).div(*rhs)
+        // NaN and infinities.
+        for &x in &[f16::NAN, -f16::NAN, f16::ONE, f16::INFINITY] {
+            assert!(!f16::NAN.ulps_eq(x, u16::MAX));
+            assert!(!x.approx_eq(f16::NAN, f16::INFINITY, u16::MAX));
+        }
+        assert!(f16::INFINITY.ulps_eq(f16::INFINITY, 0));
+        assert!(f16::NEG_INFINITY.approx_eq(f16::NEG_INFINITY, f16::ZERO, 0));
+        assert!(!f16::MAX.ulps_eq(f16::INFINITY, u16::MAX));
+        assert!(!f16::INFINITY.approx_eq(f16::MAX, f16::INFINITY, u16::MAX));
+        assert!(!f16::INFINITY.ulps_eq(f16::NEG_INFINITY, u16::MAX));
+        assert!(f16::MIN.ulps_eq(f16::MAX, u16::MAX));
     }
-}
-
-impl Div<f16> for &f16 {
-    type Output = <f16 as Div<f16>>::Output;
 
-    #[inline]
-    fn div(self, rhs: f16) -> Self::Output {
-        (*self).div(rhs)
+    #[test]
+    fn test_u8_norm() {
+        for v in 0..=u8::MAX {
+            let x = f16::from_u8_norm(v);
+            assert_eq!(x.to_u8_norm(), v);
+            assert!(x >= f16::ZERO && x <= f16::ONE);
+            // The nearest value to `v / 255` is within half an ULP.
+            let exact = v as f64 / 255.0;
+            let error = (x.to_f64() - exact) * 2.0;
+            let ulp = f16::from_bits(x.to_bits() + 1).to_f64() - x.to_f64();
+            assert!(-ulp <= error && error <= ulp, "{}", v);
+        }
     }
-}
 
-impl DivAssign for f16 {
-    #[inline]
-    fn div_assign(&mut self, rhs: Self) {
-        *self = (*self).div(rhs);
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_to_u8_norm_all() {
+        for bits in 0..=u16::MAX {
+            let x = f16::from_bits(bits);
+            let expected = if x.is_nan() || x.to_f64() <= 0.0 {
+                0
+            } else if x.to_f64() >= 1.0 {
+                255
+            } else {
+                (x.to_f64() * 255.0 + 0.5) as u8
+            };
+            assert_eq!(x.to_u8_norm(), expected, "{:?}", x);
+        }
     }
-}
 
-impl DivAssign<&f16> for f16 {
-    #[inline]
-    fn div_assign(&mut self, rhs: &f16) {
-        *self = (*self).div(rhs);
+    /// Rounds `x` with `mode` from the bracketing values around the nearest
+    /// value, as the reference for [`f16::from_f32_round`].
+    fn round_reference(x: f32, mode: RoundingMode) -> f16 {
+        let negative = x.is_sign_negative();
+        let abs = f32::from_bits(x.to_bits() & 0x7FFF_FFFF);
+        let nearest = f16::from_f32(abs);
+        if nearest.to_f32() == abs || abs.is_nan() {
+            return f16::from_f32(x);
+        }
+        let (lo, hi) = if nearest.to_f32() < abs {
+            (nearest, f16::from_bits(nearest.to_bits() + 1))
+        } else {
+            (f16::from_bits(nearest.to_bits() - 1), nearest)
+        };
+        let tie = abs as f64 - lo.to_f64() == hi.to_f64() - abs as f64;
+        let away = match mode {
+            RoundingMode::NearestEven => nearest == hi,
+            RoundingMode::NearestAway => tie || nearest == hi,
+            RoundingMode::TowardZero => false,
+            RoundingMode::TowardPositive => !negative,
+            RoundingMode::TowardNegative => negative,
+        };
+        let result = if away {
+            hi
+        } else {
+            lo
+        };
+        if negative {
+            -result
+        } else {
+            result
+        }
     }
-}
 
-impl Rem for f16 {
-    type Output = Self;
+    const MODES: [RoundingMode; 5] = [
+        RoundingMode::NearestEven,
+        RoundingMode::NearestAway,
+        RoundingMode::TowardZero,
+        RoundingMode::TowardPositive,
+        RoundingMode::TowardNegative,
+    ];
 
-    #[inline]
-    fn rem(self, rhs: Self) -> Self::Output {
-        f16(arch::remainder_f16(self.0, rhs.0))
+    #[test]
+    fn test_from_f32_round_ties() {
+        use RoundingMode::*;
+
+        let tie = |bits: u16| {
+            let x = f16::from_bits(bits).to_f32();
+            let y = f16::from_bits(bits + 1).to_f32();
+            (x + y) / 2.0
+        };
+        // Even and odd values, and the largest subnormal to the smallest normal.
+        for &(bits, even) in
+            &[(0x3C00u16, 0x3C00u16), (0x3C01, 0x3C02), (0x03FF, 0x0400), (0x0000, 0x0000)]
+        {
+            let x = tie(bits);
+            for &sign in &[1.0f32, -1.0] {
+                let s = if sign < 0.0 {
+                    0x8000
+                } else {
+                    0
+                };
+                let lo = s | bits;
+                let hi = s | (bits + 1);
+                let x = x * sign;
+                assert_eq!(f16::from_f32_round(x, NearestEven).to_bits(), s | even);
+                assert_eq!(f16::from_f32_round(x, NearestAway).to_bits(), hi);
+                assert_eq!(f16::from_f32_round(x, TowardZero).to_bits(), lo);
+                assert_eq!(
+                    f16::from_f32_round(x, TowardPositive).to_bits(),
+                    if sign > 0.0 {
+                        hi
+                    } else {
+                        lo
+                    }
+                );
+                assert_eq!(
+                    f16::from_f32_round(x, TowardNegative).to_bits(),
+                    if sign > 0.0 {
+                        lo
+                    } else {
+                        hi
+                    }
+                );
+            }
+        }
     }
-}
 
-impl Rem<&f16> for f16 {
-    type Output = <f16 as Rem<f16>>::Output;
+    #[test]
+    fn test_from_f32_round_overflow() {
+        use RoundingMode::*;
+
+        // Half-way between MAX and the next power of two.
+        let max = f16::MAX.to_f32();
+        for &x in &[65504.5f32, 65519.99, 65520.0, 65536.0, f32::MAX] {
+            let tie = x >= 65520.0;
+            assert_eq!(
+                f16::from_f32_round(x, NearestEven),
+                if tie {
+                    f16::INFINITY
+                } else {
+                    f16::MAX
+                }
+            );
+            assert_eq!(
+                f16::from_f32_round(x, NearestAway),
+                if tie {
+                    f16::INFINITY
+                } else {
+                    f16::MAX
+                }
+            );
+            assert_eq!(f16::from_f32_round(x, TowardZero), f16::MAX);
+            assert_eq!(f16::from_f32_round(x, TowardPositive), f16::INFINITY);
+            assert_eq!(f16::from_f32_round(x, TowardNegative), f16::MAX);
+            assert_eq!(f16::from_f32_round(-x, TowardZero), f16::MIN);
+            assert_eq!(f16::from_f32_round(-x, TowardPositive), f16::MIN);
+            assert_eq!(f16::from_f32_round(-x, TowardNegative), f16::NEG_INFINITY);
+        }
+        for &mode in &MODES {
+            assert_eq!(f16::from_f32_round(max, mode), f16::MAX);
+            assert_eq!(f16::from_f32_round(f32::INFINITY, mode), f16::INFINITY);
+            assert_eq!(f16::from_f32_round(f32::NEG_INFINITY, mode), f16::NEG_INFINITY);
+            assert!(f16::from_f32_round(f32::NAN, mode).is_nan());
+        }
+    }
 
-    #[inline]
-    fn rem(self, rhs: &f16) -> Self::Output {
-        self.rem(*rhs)
+    #[test]
+    fn test_from_f32_round_underflow() {
+        use RoundingMode::*;
+
+        let tiny = f16::from_bits(1);
+        for &x in &[1e-30f32, 1e-45, 2.0e-8] {
+            assert_eq!(f16::from_f32_round(x, NearestEven).to_bits(), 0);
+            assert_eq!(f16::from_f32_round(x, NearestAway).to_bits(), 0);
+            assert_eq!(f16::from_f32_round(x, TowardZero).to_bits(), 0);
+            assert_eq!(f16::from_f32_round(x, TowardPositive), tiny);
+            assert_eq!(f16::from_f32_round(x, TowardNegative).to_bits(), 0);
+            assert_eq!(f16::from_f32_round(-x, TowardNegative), -tiny);
+            assert_eq!(f16::from_f32_round(-x, TowardPositive).to_bits(), 0x8000);
+        }
+        for &mode in &MODES {
+            assert_eq!(f16::from_f32_round(0.0, mode).to_bits(), 0);
+            assert_eq!(f16::from_f32_round(-0.0, mode).to_bits(), 0x8000);
+        }
     }
-}
 
-impl Rem<&f16> for &f16 {
-    type Output = <f16 as Rem<f16>>::Output;
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_from_f32_round_reference() {
+        for bits in (0..=u32::MAX).step_by(4099) {
+            let x = f32::from_bits(bits);
+            if x.is_nan() {
+                continue;
+            }
+            for &mode in &MODES {
+                let expected = round_reference(x, mode);
+                let actual = f16::from_f32_round(x, mode);
+                assert_eq!(actual.to_bits(), expected.to_bits(), "{:e} {:?}", x, mode);
+            }
+        }
+    }
 
-    #[inline]
-    fn rem(self, rhs: &f16) -> Self::Output {
-        (*self).rem(*rhs)
+    /// Rounds `x` with `mode` from the bracketing values found by searching
+    /// the exact [`f64`] values, as the reference for [`f16::from_f64_round`].
+    fn round_reference_f64(x: f64, mode: RoundingMode) -> f16 {
+        if x.is_nan() {
+            return f16::from_f64(x);
+        }
+        let negative = x.is_sign_negative();
+        let abs = f64::from_bits(x.to_bits() & 0x7FFF_FFFF_FFFF_FFFF);
+
+        // The largest value at or below `abs`, and the next value up, where
+        // the value above the maximum is the next power of two.
+        let (mut lo, mut hi) = (0u16, 0x7C00u16);
+        while hi - lo > 1 {
+            let mid = (lo + hi) / 2;
+            if f16::from_bits(mid).to_f64() <= abs {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        let lo_value = f16::from_bits(lo).to_f64();
+        let bits = if lo_value == abs || abs == f64::INFINITY {
+            f16::from_f64(abs).to_bits()
+        } else {
+            let hi_value = if lo == 0x7BFF {
+                65536.0
+            } else {
+                f16::from_bits(lo + 1).to_f64()
+            };
+            let (below, above) = (abs - lo_value, hi_value - abs);
+            let away = match mode {
+                RoundingMode::NearestEven => above < below || (above == below && lo % 2 == 1),
+                RoundingMode::NearestAway => above <= below,
+                RoundingMode::TowardZero => false,
+                RoundingMode::TowardPositive => !negative,
+                RoundingMode::TowardNegative => negative,
+            };
+            lo + away as u16
+        };
+        if negative {
+            -f16::from_bits(bits)
+        } else {
+            f16::from_bits(bits)
+        }
     }
-}
 
-impl Rem<f16> for &f16 {
-    type Output = <f16 as Rem<f16>>::Output;
+    #[test]
+    fn test_from_f64_round_issue_116() {
+        use RoundingMode::*;
 
-    #[inline]
-    fn rem(self, rhs: f16) -> Self::Output {
-        (*self).rem(rhs)
+        // SEE: https://github.com/starkat99/half-rs/issues/116
+        // Just below half-way between the largest subnormal and the smallest
+        // normal, and between 2047 and 2048, which both become ties when
+        // rounded to an f32 first.
+        let cases =
+            [(0x3f0ffbfffffffffcu64, 0x03FFu16, 0x0400u16), (0x409ffdffffff0000, 0x67FF, 0x6800)];
+        for &(bits, lo, hi) in &cases {
+            let x = f64::from_bits(bits);
+            assert_eq!(f16::from_f64_round(x, NearestEven).to_bits(), lo);
+            assert_eq!(f16::from_f64_round(x, NearestAway).to_bits(), lo);
+            assert_eq!(f16::from_f64_round(x, TowardZero).to_bits(), lo);
+            assert_eq!(f16::from_f64_round(x, TowardPositive).to_bits(), hi);
+            assert_eq!(f16::from_f64_round(x, TowardNegative).to_bits(), lo);
+            assert_eq!(f16::from_f64_round(-x, TowardNegative).to_bits(), 0x8000 | hi);
+            assert_eq!(f16::from_f64_round(-x, TowardPositive).to_bits(), 0x8000 | lo);
+            assert_eq!(f16::from_f64_const(x).to_bits(), lo);
+            for &mode in &MODES {
+                assert_eq!(f16::from_f64_round(x, mode), round_reference_f64(x, mode));
+            }
+        }
+
+        for &mode in &MODES {
+            assert_eq!(f16::from_f64_round(f64::INFINITY, mode), f16::INFINITY);
+            assert_eq!(f16::from_f64_round(f64::NEG_INFINITY, mode), f16::NEG_INFINITY);
+            assert!(f16::from_f64_round(f64::NAN, mode).is_nan());
+            assert_eq!(f16::from_f64_round(0.0, mode).to_bits(), 0);
+            assert_eq!(f16::from_f64_round(-0.0, mode).to_bits(), 0x8000);
+        }
+        assert_eq!(f16::from_f64_round(f64::MAX, TowardZero), f16::MAX);
+        assert_eq!(f16::from_f64_round(f64::MAX, NearestEven), f16::INFINITY);
+        assert_eq!(f16::from_f64_round(-f64::MAX, TowardPositive), f16::MIN);
+        assert_eq!(f16::from_f64_round(5e-324, TowardPositive), f16::from_bits(1));
+        assert_eq!(f16::from_f64_round(5e-324, NearestEven).to_bits(), 0);
     }
-}
 
-impl RemAssign for f16 {
-    #[inline]
-    fn rem_assign(&mut self, rhs: Self) {
-        *self = (*self).rem(rhs);
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_from_f64_round_reference() {
+        // Every tie, the f64 values around it, and values between ties.
+        for bits in 0..0x7C00u16 {
+            let lo = f16::from_bits(bits).to_f64();
+            let hi = if bits == 0x7BFF {
+                65536.0
+            } else {
+                f16::from_bits(bits + 1).to_f64()
+            };
+            let tie = (lo + hi) / 2.0;
+            let next = |x: f64, d: i64| f64::from_bits((x.to_bits() as i64 + d) as u64);
+            for &x in &[next(tie, -1), tie, next(tie, 1), (lo + tie) / 2.0, next(hi, -1)] {
+                for &x in &[x, -x] {
+                    for &mode in &MODES {
+                        let expected = round_reference_f64(x, mode);
+                        let actual = f16::from_f64_round(x, mode);
+                        assert_eq!(actual.to_bits(), expected.to_bits(), "{:e} {:?}", x, mode);
+                    }
+                }
+            }
+        }
     }
-}
 
-impl RemAssign<&f16> for f16 {
-    #[inline]
-    fn rem_assign(&mut self, rhs: &f16) {
-        *self = (*self).rem(rhs);
+    #[test]
+    fn test_checked_rem_neg() {
+        let f = f16::from_f32;
+        let finite = [
+            f16::ZERO,
+            f16::NEG_ZERO,
+            f16::MIN_POSITIVE_SUBNORMAL,
+            f16::MIN_POSITIVE,
+            f(-0.75),
+            f16::ONE,
+            f(3.5),
+            f(-100.0),
+            f16::MAX,
+            f16::MIN,
+        ];
+        let nans = [f16::NAN, -f16::NAN, f16::from_bits(f16::NAN.to_bits() | 1)];
+        let infinities = [f16::INFINITY, f16::NEG_INFINITY];
+
+        // NaN operands, infinite dividends and zero divisors are `None`.
+        for &x in finite.iter().chain(&nans).chain(&infinities) {
+            for &nan in &nans {
+                assert_eq!(x.checked_rem(nan), None);
+                assert_eq!(nan.checked_rem(x), None);
+            }
+            assert_eq!(x.checked_rem(f16::ZERO), None);
+            assert_eq!(x.checked_rem(f16::NEG_ZERO), None);
+            for &inf in &infinities {
+                assert_eq!(inf.checked_rem(x), None);
+            }
+        }
+
+        // Everything else matches the operator.
+        for &x in &finite {
+            for &y in finite.iter().chain(&infinities) {
+                if y.to_f64() == 0. {
+                    continue;
+                }
+                let value = x.checked_rem(y).unwrap();
+                assert_eq!(value.to_bits(), (x % y).to_bits(), "{:?} {:?}", x, y);
+            }
+            assert_eq!(x.checked_neg().map(f16::to_bits), Some((-x).to_bits()));
+        }
+        for &x in nans.iter().chain(&infinities) {
+            assert_eq!(x.checked_neg(), None);
+        }
     }
-}
 
-impl Product for f16 {
-    #[inline]
-    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
-        f16(arch::product_f16(iter.map(|f| f.to_bits())))
-    }
-}
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_cow_from_bytes() {
+        let values = [f16::ONE, f16::from_f32(-2.5), f16::NAN, f16::MAX, f16::ZERO];
+        let mut words = [0u16; 6];
+        let mut le = [0u8; 12];
+        let mut be = [0u8; 12];
+        for (i, v) in values.iter().enumerate() {
+            le[2 * i..2 * i + 2].copy_from_slice(&v.to_le_bytes());
+            be[2 * i..2 * i + 2].copy_from_slice(&v.to_be_bytes());
+        }
 
-impl<'a> Product<&'a f16> for f16 {
-    #[inline]
-    fn product<I: Iterator<Item = &'a f16>>(iter: I) -> Self {
-        f16(arch::product_f16(iter.map(|f| f.to_bits())))
-    }
-}
+        // Store the bytes in an aligned buffer, so offset slices are misaligned.
+        let native = if cfg!(target_endian = "little") {
+            &le
+        } else {
+            &be
+        };
+        for (i, w) in words.iter_mut().enumerate() {
+            *w = u16::from_ne_bytes([native[2 * i], native[2 * i + 1]]);
+        }
+        let aligned = unsafe { core::slice::from_raw_parts(words.as_ptr().cast::<u8>(), 12) };
+        let (le_values, be_values) = if cfg!(target_endian = "little") {
+            (f16::cow_from_le_bytes(&aligned[..10]), f16::cow_from_be_bytes(&be[..10]))
+        } else {
+            (f16::cow_from_le_bytes(&le[..10]), f16::cow_from_be_bytes(&aligned[..10]))
+        };
+        for cow in [le_values.unwrap(), be_values.unwrap()] {
+            let borrowed = matches!(cow, Cow::Borrowed(_));
+            assert_eq!(borrowed, cow.as_ptr().cast::<u8>() == aligned.as_ptr());
+            let bits: Vec<u16> = cow.iter().map(|v| v.to_bits()).collect();
+            assert_eq!(bits, values.iter().map(|v| v.to_bits()).collect::<Vec<_>>());
+        }
 
-impl Sum for f16 {
-    #[inline]
-    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-        f16(arch::sum_f16(iter.map(|f| f.to_bits())))
+        // Offset by one byte, so the values must be copied.
+        let mut shifted = [0u8; 14];
+        let start = if shifted.as_ptr() as usize % 2 == 0 {
+            1
+        } else {
+            0
+        };
+        shifted[start..start + 12].copy_from_slice(native);
+        let unaligned = &shifted[start..start + 10];
+        assert_ne!(unaligned.as_ptr() as usize % 2, 0);
+        let cow = if cfg!(target_endian = "little") {
+            f16::cow_from_le_bytes(unaligned)
+        } else {
+            f16::cow_from_be_bytes(unaligned)
+        }
+        .unwrap();
+        assert!(matches!(cow, Cow::Owned(_)));
+        assert_eq!(cow[1], values[1]);
+
+        // Odd lengths are errors, even when aligned.
+        assert_eq!(f16::cow_from_le_bytes(&aligned[..9]), Err(OddLength(())));
+        assert_eq!(f16::cow_from_be_bytes(&be[1..4]), Err(OddLength(())));
+        assert_eq!(f16::cow_from_le_bytes(&[]).unwrap().len(), 0);
     }
-}
 
-impl<'a> Sum<&'a f16> for f16 {
-    #[inline]
-    fn sum<I: Iterator<Item = &'a f16>>(iter: I) -> Self {
-        f16(arch::sum_f16(iter.map(|f| f.to_bits())))
+    #[test]
+    fn test_hardware_f16_available() {
+        let available = f16::hardware_f16_available();
+        if cfg!(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "f16c")) {
+            assert!(available);
+        }
+        #[cfg(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64")))]
+        assert_eq!(available, std::arch::is_x86_feature_detected!("f16c"));
+        if !cfg!(any(
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "aarch64",
+            target_arch = "loongarch64"
+        )) {
+            assert!(!available);
+        }
     }
-}
 
-#[inline]
-const fn eq(lhs: f16, rhs: f16) -> bool {
-    if lhs.is_nan() || rhs.is_nan() {
-        false
-    } else {
-        (lhs.0 == rhs.0) || ((lhs.0 | rhs.0) & f16::NOT_SIGN == 0)
+    #[test]
+    fn test_from_saturating() {
+        // Half-way between MAX and 65536, where nearest-even rounds to infinity.
+        let below = f32::from_bits(65520f32.to_bits() - 1);
+        for &x in &[65504.0f32, below] {
+            assert_eq!(f16::from_f32_saturating(x), f16::MAX);
+            assert_eq!(f16::from_f32_saturating(-x), f16::MIN);
+            assert_eq!(f16::from_f32(x), f16::MAX);
+        }
+        for &x in &[65520.0f32, 65536.0, 1e10, f32::MAX] {
+            assert_eq!(f16::from_f32(x), f16::INFINITY);
+            assert_eq!(f16::from_f32_saturating(x), f16::MAX);
+            assert_eq!(f16::from_f32_saturating(-x), f16::MIN);
+            assert_eq!(f16::from_f64_saturating(x as f64), f16::MAX);
+            assert_eq!(f16::from_f64_saturating(-x as f64), f16::MIN);
+        }
+        let below = f64::from_bits(65520f64.to_bits() - 1);
+        assert_eq!(f16::from_f64_saturating(below), f16::MAX);
+        assert_eq!(f16::from_f64_saturating(f64::MAX), f16::MAX);
+        assert_eq!(f16::from_f64_saturating(f64::MIN), f16::MIN);
+
+        // Infinities and NaN are unchanged, and other values are the same as
+        // the regular conversions.
+        assert_eq!(f16::from_f32_saturating(f32::INFINITY), f16::INFINITY);
+        assert_eq!(f16::from_f32_saturating(f32::NEG_INFINITY), f16::NEG_INFINITY);
+        assert_eq!(f16::from_f64_saturating(f64::INFINITY), f16::INFINITY);
+        assert_eq!(f16::from_f64_saturating(f64::NEG_INFINITY), f16::NEG_INFINITY);
+        assert!(f16::from_f32_saturating(f32::NAN).is_nan());
+        assert!(f16::from_f64_saturating(f64::NAN).is_nan());
+        for &x in &[0.0f32, -0.0, 1e-10, -1.5, 1000.25, 65503.0] {
+            assert_eq!(f16::from_f32_saturating(x).to_bits(), f16::from_f32(x).to_bits());
+            assert_eq!(
+                f16::from_f64_saturating(x as f64).to_bits(),
+                f16::from_f64(x as f64).to_bits()
+            );
+        }
     }
-}
 
-#[inline]
-const fn lt(lhs: f16, rhs: f16) -> bool {
-    if lhs.is_nan() || rhs.is_nan() {
-        false
-    } else {
-        let neg = lhs.0 & f16::SIGN_MASK != 0;
-        let rhs_neg = rhs.0 & f16::SIGN_MASK != 0;
-        match (neg, rhs_neg) {
-            (false, false) => lhs.0 < rhs.0,
-            (false, true) => false,
-            (true, false) => (lhs.0 | rhs.0) & f16::NOT_SIGN != 0,
-            (true, true) => lhs.0 > rhs.0,
+    #[test]
+    fn test_from_i32() {
+        let modes = [
+            RoundingMode::NearestEven,
+            RoundingMode::NearestAway,
+            RoundingMode::TowardZero,
+            RoundingMode::TowardPositive,
+            RoundingMode::TowardNegative,
+        ];
+        // Exact conversions, including every power of two that fits.
+        for &value in &[0, 1, -1, 2047, 2048, -2048, 4096, 32768, 65504, -65504] {
+            for &mode in &modes {
+                assert_eq!(f16::from_i32_with_mode(value, mode).to_f64(), value as f64);
+            }
+        }
+        for shift in 0..16 {
+            assert_eq!(f16::from_i32(1 << shift).to_f64(), (1 << shift) as f64);
+            assert_eq!(f16::from_i32(-(1 << shift)).to_f64(), -((1 << shift) as f64));
         }
-    }
-}
 
-#[inline]
-const fn le(lhs: f16, rhs: f16) -> bool {
-    if lhs.is_nan() || rhs.is_nan() {
-        false
-    } else {
-        let neg = lhs.0 & f16::SIGN_MASK != 0;
-        let rhs_neg = rhs.0 & f16::SIGN_MASK != 0;
-        match (neg, rhs_neg) {
-            (false, false) => lhs.0 <= rhs.0,
-            (false, true) => (lhs.0 | rhs.0) & f16::NOT_SIGN == 0,
-            (true, false) => true,
-            (true, true) => lhs.0 >= rhs.0,
+        // Near powers of two, where the spacing changes and the modes diverge:
+        // (value, nearest even, nearest away, toward zero, toward positive).
+        let cases: [(i32, [f64; 5]); 8] = [
+            (2049, [2048., 2050., 2048., 2050., 2048.]),
+            (2051, [2052., 2052., 2050., 2052., 2050.]),
+            (4095, [4096., 4096., 4094., 4096., 4094.]),
+            (4097, [4096., 4096., 4096., 4100., 4096.]),
+            (4098, [4096., 4100., 4096., 4100., 4096.]),
+            (4099, [4100., 4100., 4096., 4100., 4096.]),
+            (65519, [65504., 65504., 65504., f64::INFINITY, 65504.]),
+            (65520, [f64::INFINITY, f64::INFINITY, 65504., f64::INFINITY, 65504.]),
+        ];
+        for &(value, expected) in &cases {
+            for (&mode, &expected) in modes.iter().take(4).zip(expected.iter()) {
+                assert_eq!(f16::from_i32_with_mode(value, mode).to_f64(), expected, "{}", value);
+            }
+            // Toward negative is toward zero for positive values.
+            assert_eq!(
+                f16::from_i32_with_mode(value, RoundingMode::TowardNegative).to_f64(),
+                expected[2]
+            );
+            // Negating the value negates the result, except for the directed
+            // modes, which swap.
+            assert_eq!(f16::from_i32(-value).to_f64(), -expected[0]);
+            assert_eq!(
+                f16::from_i32_with_mode(-value, RoundingMode::TowardNegative).to_f64(),
+                -expected[3]
+            );
         }
+
+        // The boundaries of `i32`.
+        assert_eq!(f16::from_i32(i32::MAX), f16::INFINITY);
+        assert_eq!(f16::from_i32(i32::MIN), f16::NEG_INFINITY);
+        assert_eq!(f16::from_i32_with_mode(i32::MAX, RoundingMode::TowardZero), f16::MAX);
+        assert_eq!(f16::from_i32_with_mode(i32::MIN, RoundingMode::TowardZero), f16::MIN);
+        assert_eq!(f16::from_i32_with_mode(i32::MIN, RoundingMode::TowardPositive), f16::MIN);
+        assert_eq!(
+            f16::from_i32_with_mode(i32::MIN, RoundingMode::TowardNegative),
+            f16::NEG_INFINITY
+        );
+        assert_eq!(f16::from_i32(0).to_bits(), 0);
     }
-}
 
-#[inline]
-const fn gt(lhs: f16, rhs: f16) -> bool {
-    if lhs.is_nan() || rhs.is_nan() {
-        false
-    } else {
-        let neg = lhs.0 & f16::SIGN_MASK != 0;
-        let rhs_neg = rhs.0 & f16::SIGN_MASK != 0;
-        match (neg, rhs_neg) {
-            (false, false) => lhs.0 > rhs.0,
-            (false, true) => (lhs.0 | rhs.0) & f16::NOT_SIGN != 0,
-            (true, false) => false,
-            (true, true) => lhs.0 < rhs.0,
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_from_i32_reference() {
+        // Every integer up to past the overflow threshold, against rounding
+        // the exact `f64` once.
+        let modes = [
+            RoundingMode::NearestEven,
+            RoundingMode::NearestAway,
+            RoundingMode::TowardZero,
+            RoundingMode::TowardPositive,
+            RoundingMode::TowardNegative,
+        ];
+        for value in -70000..=70000 {
+            for &mode in &modes {
+                assert_eq!(
+                    f16::from_i32_with_mode(value, mode).to_bits(),
+                    f16::from_f64_round(value as f64, mode).to_bits(),
+                    "{} {:?}",
+                    value,
+                    mode
+                );
+            }
         }
     }
-}
 
-#[inline]
-const fn ge(lhs: f16, rhs: f16) -> bool {
-    if lhs.is_nan() || rhs.is_nan() {
-        false
-    } else {
-        let neg = lhs.0 & f16::SIGN_MASK != 0;
-        let rhs_neg = rhs.0 & f16::SIGN_MASK != 0;
-        match (neg, rhs_neg) {
-            (false, false) => lhs.0 >= rhs.0,
-            (false, true) => true,
-            (true, false) => (lhs.0 | rhs.0) & f16::NOT_SIGN == 0,
-            (true, true) => lhs.0 <= rhs.0,
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_to_i32_trunc() {
+        assert_eq!(f16::NAN.to_i32_trunc(), None);
+        assert_eq!(f16::INFINITY.to_i32_trunc(), None);
+        assert_eq!(f16::NEG_INFINITY.to_i32_trunc(), None);
+        assert_eq!(f16::MAX.to_i32_trunc(), Some(65504));
+        assert_eq!(f16::MIN.to_i32_trunc(), Some(-65504));
+        assert_eq!(f16::NEG_ZERO.to_i32_trunc(), Some(0));
+        assert_eq!(f16::from_f32(0.999).to_i32_trunc(), Some(0));
+        assert_eq!(f16::from_f32(-1.5).to_i32_trunc(), Some(-1));
+        assert_eq!(f16::from_f32(1023.5).to_i32_trunc(), Some(1023));
+
+        for bits in 0..=u16::MAX {
+            let value = f16::from_bits(bits);
+            let expected = if value.is_finite() {
+                Some(value.to_f64().trunc() as i32)
+            } else {
+                None
+            };
+            assert_eq!(value.to_i32_trunc(), expected, "{:#06x}", bits);
         }
     }
-}
 
-#[allow(clippy::cognitive_complexity, clippy::float_cmp, clippy::neg_cmp_op_on_partial_ord)]
-#[cfg(test)]
-mod test {
-    use core::cmp::Ordering;
-    use core::mem;
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_ftz() {
+        // Every subnormal flushes to the zero of the same sign.
+        for man in 1..0x0400u16 {
+            assert_eq!(f16::from_bits(man).ftz().to_bits(), 0x0000);
+            assert_eq!(f16::from_bits(0x8000 | man).ftz().to_bits(), 0x8000);
+            let value = f16::from_bits(man).to_f32();
+            assert_eq!(f16::from_f32_ftz(value).to_bits(), 0x0000);
+            assert_eq!(f16::from_f32_ftz(-value).to_bits(), 0x8000);
+        }
+        // Every other value is unchanged.
+        for bits in 0..=u16::MAX {
+            let value = f16::from_bits(bits);
+            if !value.is_subnormal() {
+                assert_eq!(value.ftz().to_bits(), bits);
+            }
+        }
 
-    use super::*;
+        // f32 subnormals are treated as zero.
+        for &bits in &[1u32, 0x0040_0000, 0x007F_FFFF] {
+            assert_eq!(f16::from_f32_ftz(f32::from_bits(bits)).to_bits(), 0x0000);
+            assert_eq!(f16::from_f32_ftz(f32::from_bits(0x8000_0000 | bits)).to_bits(), 0x8000);
+        }
+        // Results are flushed after rounding, so values just below
+        // `MIN_POSITIVE` that round up to it are kept.
+        let below = f16::MIN_POSITIVE.to_f32() * (1. - f32::EPSILON);
+        assert_eq!(f16::from_f32_ftz(below), f16::MIN_POSITIVE);
+        assert_eq!(f16::from_f32_ftz(-below), -f16::MIN_POSITIVE);
+
+        // Normal results are identical to `from_f32`, sampling every f32
+        // exponent and the mantissas around the rounding bits.
+        for exp in 0..=0xFFu32 {
+            for &man in &[0u32, 1, 0x0FFF, 0x1000, 0x1001, 0x2000, 0x3000, 0x7F_FFFF] {
+                for &sign in &[0u32, 0x8000_0000] {
+                    let value = f32::from_bits(sign | (exp << 23) | man);
+                    let expected = f16::from_f32(value);
+                    let actual = f16::from_f32_ftz(value);
+                    if expected.is_subnormal() {
+                        assert_eq!(actual.to_bits(), expected.to_bits() & 0x8000);
+                    } else if expected.is_nan() {
+                        assert!(actual.is_nan());
+                    } else {
+                        assert_eq!(actual.to_bits(), expected.to_bits());
+                    }
+                }
+            }
+        }
+    }
 
     #[test]
-    #[cfg_attr(miri, ignore)]
-    fn test_f16_consts() {
-        // DIGITS
-        let digits = ((f16::MANTISSA_DIGITS as f32 - 1.0) * 2f32.log10()).floor() as u32;
-        assert_eq!(f16::DIGITS, digits);
-        // sanity check to show test is good
-        let digits32 = ((core::f32::MANTISSA_DIGITS as f32 - 1.0) * 2f32.log10()).floor() as u32;
-        assert_eq!(core::f32::DIGITS, digits32);
+    fn test_normal_bounds() {
+        const MIN: f16 = f16::min_normal();
+        const MAX: f16 = f16::max_normal();
+        assert_eq!(MIN.to_bits(), f16::MIN_POSITIVE.to_bits());
+        assert_eq!(MAX.to_bits(), f16::MAX.to_bits());
+        assert_eq!(f16::min_positive_subnormal().to_bits(), f16::MIN_POSITIVE_SUBNORMAL.to_bits());
+        assert!(MIN.is_normal() && MAX.is_normal());
+        assert!(!f16::from_bits(MIN.to_bits() - 1).is_normal());
+        assert!(!f16::from_bits(MAX.to_bits() + 1).is_finite());
+        assert!(f16::MIN < MIN);
+    }
 
-        // EPSILON
-        let one = f16::from_f32(1.0);
-        let one_plus_epsilon = f16::from_bits(one.to_bits() + 1);
-        let epsilon = f16::from_f32(one_plus_epsilon.to_f32() - 1.0);
-        assert_eq!(f16::EPSILON, epsilon);
-        // sanity check to show test is good
-        let one_plus_epsilon32 = f32::from_bits(1.0f32.to_bits() + 1);
-        let epsilon32 = one_plus_epsilon32 - 1f32;
-        assert_eq!(core::f32::EPSILON, epsilon32);
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_integer_predicates() {
+        const PREDICATES: [bool; 4] = [
+            f16::NEG_ZERO.is_zero(),
+            f16::NEG_ZERO.is_integer(),
+            f16::NEG_ZERO.is_odd_integer(),
+            f16::NEG_ZERO.is_even_integer(),
+        ];
+        assert_eq!(PREDICATES, [true, true, false, true]);
+
+        for bits in 0..=u16::MAX {
+            let x = f16::from_bits(bits);
+            let f = x.to_f64();
+            let integer = f.is_finite() && f.fract() == 0.;
+            assert_eq!(x.is_zero(), f == 0., "{:?}", x);
+            assert_eq!(x.is_integer(), integer, "{:?}", x);
+            assert_eq!(x.is_odd_integer(), integer && f % 2. != 0., "{:?}", x);
+            assert_eq!(x.is_even_integer(), integer && f % 2. == 0., "{:?}", x);
+        }
+    }
 
-        // MAX, MIN and MIN_POSITIVE
-        let max = f16::from_bits(f16::INFINITY.to_bits() - 1);
-        let min = f16::from_bits(f16::NEG_INFINITY.to_bits() - 1);
-        let min_pos = f16::from_f32(2f32.powi(f16::MIN_EXP - 1));
-        assert_eq!(f16::MAX, max);
-        assert_eq!(f16::MIN, min);
-        assert_eq!(f16::MIN_POSITIVE, min_pos);
-        // sanity check to show test is good
-        let max32 = f32::from_bits(core::f32::INFINITY.to_bits() - 1);
-        let min32 = f32::from_bits(core::f32::NEG_INFINITY.to_bits() - 1);
-        let min_pos32 = 2f32.powi(core::f32::MIN_EXP - 1);
-        assert_eq!(core::f32::MAX, max32);
-        assert_eq!(core::f32::MIN, min32);
-        assert_eq!(core::f32::MIN_POSITIVE, min_pos32);
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_bisect() {
+        const MID: f16 = f16::NEG_ONE.bisect(f16::ONE);
+        assert_eq!(MID.to_bits(), 0);
+
+        let values = crate::testing::stratified_values(2, 0x2317);
+        for &a in &values {
+            for &b in &values {
+                let mid = a.bisect(b);
+                if a.is_nan() || b.is_nan() {
+                    assert!(mid.is_nan_quiet());
+                    continue;
+                }
+                assert_eq!(mid.to_bits(), b.bisect(a).to_bits());
+                let (lo, hi) = if a < b {
+                    (a, b)
+                } else {
+                    (b, a)
+                };
+                if lo.ulp_distance(hi) > Some(1) {
+                    assert!(lo < mid && mid < hi, "{:?} {:?} {:?}", a, b, mid);
+                    // The halves differ by at most one value.
+                    let (left, right) = (lo.abs_diff_ulp(mid), mid.abs_diff_ulp(hi));
+                    assert!(right - left <= 1, "{:?} {:?} {:?}", a, b, mid);
+                } else {
+                    assert_eq!(mid.ulp_distance(lo), Some(0), "{:?} {:?} {:?}", a, b, mid);
+                }
+            }
+        }
+    }
 
-        // MIN_10_EXP and MAX_10_EXP
-        let ten_to_min = 10f32.powi(f16::MIN_10_EXP);
-        assert!(ten_to_min / 10.0 < f16::MIN_POSITIVE.to_f32());
-        assert!(ten_to_min > f16::MIN_POSITIVE.to_f32());
-        let ten_to_max = 10f32.powi(f16::MAX_10_EXP);
-        assert!(ten_to_max < f16::MAX.to_f32());
-        assert!(ten_to_max * 10.0 > f16::MAX.to_f32());
-        // sanity check to show test is good
-        let ten_to_min32 = 10f64.powi(core::f32::MIN_10_EXP);
-        assert!(ten_to_min32 / 10.0 < f64::from(core::f32::MIN_POSITIVE));
-        assert!(ten_to_min32 > f64::from(core::f32::MIN_POSITIVE));
-        let ten_to_max32 = 10f64.powi(core::f32::MAX_10_EXP);
-        assert!(ten_to_max32 < f64::from(core::f32::MAX));
-        assert!(ten_to_max32 * 10.0 > f64::from(core::f32::MAX));
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_bisect_steps() {
+        // Searching for any value from the whole range takes at most 16
+        // steps.
+        for bits in 0..=u16::MAX {
+            let target = f16::from_bits(bits);
+            if target.is_nan() {
+                continue;
+            }
+            let (mut lo, mut hi) = (f16::NEG_INFINITY, f16::INFINITY);
+            let mut steps = 0;
+            while lo.ulp_distance(hi) > Some(1) {
+                let mid = lo.bisect(hi);
+                if target <= mid {
+                    hi = mid;
+                } else {
+                    lo = mid;
+                }
+                steps += 1;
+            }
+            assert!(steps <= 16, "{:?}", target);
+            let found = |x: f16| x.ulp_distance(target) == Some(0);
+            assert!(found(lo) || found(hi), "{:?}", target);
+        }
     }
 
     #[test]
-    fn test_f16_consts_from_f32() {
-        let one = f16::from_f32(1.0);
-        let zero = f16::from_f32(0.0);
-        let neg_zero = f16::from_f32(-0.0);
-        let neg_one = f16::from_f32(-1.0);
-        let inf = f16::from_f32(core::f32::INFINITY);
-        let neg_inf = f16::from_f32(core::f32::NEG_INFINITY);
-        let nan = f16::from_f32(core::f32::NAN);
+    fn test_find_root() {
+        let f = |x: f16| f16::from_f64(x.to_f64() * x.to_f64() - 2.);
+        let (zero, two) = (f16::ZERO, f16::from_f32(2.));
+        assert_eq!(f16::find_root(f, zero, two), Some(f16::SQRT_2));
+        assert_eq!(f16::find_root(f, two, zero), Some(f16::SQRT_2));
+        assert_eq!(f16::find_root(f, -two, zero), Some(-f16::SQRT_2));
+        assert_eq!(f16::find_root(f, f16::MIN, zero), Some(-f16::SQRT_2));
+        assert_eq!(f16::find_root(f, two, f16::INFINITY), None);
+        assert_eq!(f16::find_root(f, f16::NAN, two), None);
+
+        // The nearest value to the root, on either side.
+        for root in [0.1, -3.7, 1000.3, 1e-3] {
+            let f = |x: f16| f16::from_f64(x.to_f64() - root);
+            let expected = f16::from_f64(root);
+            assert_eq!(f16::find_root(f, f16::NEG_INFINITY, f16::INFINITY), Some(expected));
+        }
 
-        assert_eq!(f16::ONE, one);
-        assert_eq!(f16::ZERO, zero);
-        assert!(zero.is_sign_positive());
-        assert_eq!(f16::NEG_ZERO, neg_zero);
-        assert!(neg_zero.is_sign_negative());
-        assert_eq!(f16::NEG_ONE, neg_one);
-        assert!(neg_one.is_sign_negative());
-        assert_eq!(f16::INFINITY, inf);
-        assert_eq!(f16::NEG_INFINITY, neg_inf);
-        assert!(nan.is_nan());
-        assert!(f16::NAN.is_nan());
+        // Exact roots, at a bound or in between.
+        let f = |x: f16| x;
+        assert_eq!(f16::find_root(f, f16::NEG_ONE, f16::ONE).map(f16::to_bits), Some(0));
+        assert_eq!(f16::find_root(f, f16::NEG_ZERO, f16::ONE).map(f16::to_bits), Some(0x8000));
+        assert_eq!(f16::find_root(|x| x - f16::ONE, f16::ONE, two), Some(f16::ONE));
+        assert_eq!(f16::find_root(|_| f16::NAN, f16::NEG_ONE, f16::ONE), None);
+
+        // A step function is bracketed down to adjacent values.
+        let step = f16::from_f32(1.5);
+        let calls = core::cell::Cell::new(0);
+        let f = |x: f16| {
+            calls.set(calls.get() + 1);
+            if x < step {
+                f16::NEG_ONE
+            } else {
+                f16::ONE
+            }
+        };
+        assert_eq!(
+            f16::find_root(f, f16::NEG_INFINITY, f16::INFINITY),
+            Some(f16::from_bits(step.to_bits() - 1))
+        );
+        assert!(calls.get() <= 18);
+    }
 
-        let e = f16::from_f32(core::f32::consts::E);
-        let pi = f16::from_f32(core::f32::consts::PI);
-        let frac_1_pi = f16::from_f32(core::f32::consts::FRAC_1_PI);
-        let frac_1_sqrt_2 = f16::from_f32(core::f32::consts::FRAC_1_SQRT_2);
-        let frac_2_pi = f16::from_f32(core::f32::consts::FRAC_2_PI);
-        let frac_2_sqrt_pi = f16::from_f32(core::f32::consts::FRAC_2_SQRT_PI);
-        let frac_pi_2 = f16::from_f32(core::f32::consts::FRAC_PI_2);
-        let frac_pi_3 = f16::from_f32(core::f32::consts::FRAC_PI_3);
-        let frac_pi_4 = f16::from_f32(core::f32::consts::FRAC_PI_4);
-        let frac_pi_6 = f16::from_f32(core::f32::consts::FRAC_PI_6);
-        let frac_pi_8 = f16::from_f32(core::f32::consts::FRAC_PI_8);
-        let ln_10 = f16::from_f32(core::f32::consts::LN_10);
-        let ln_2 = f16::from_f32(core::f32::consts::LN_2);
-        let log10_e = f16::from_f32(core::f32::consts::LOG10_E);
-        // core::f32::consts::LOG10_2 requires rustc 1.43.0
-        let log10_2 = f16::from_f32(2f32.log10());
-        let log2_e = f16::from_f32(core::f32::consts::LOG2_E);
-        // core::f32::consts::LOG2_10 requires rustc 1.43.0
-        let log2_10 = f16::from_f32(10f32.log2());
-        let sqrt_2 = f16::from_f32(core::f32::consts::SQRT_2);
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_from_f32_stochastic() {
+        // Every random value gives an expected result that is exactly the
+        // value, when the dropped bits fit in the 16 random bits.
+        let values = [
+            1.0 + f32::powi(2.0, -12),
+            0.1,
+            -2.71,
+            1000.7,
+            60000.7,
+            -65500.0,
+            f32::powi(2.0, -15) * 1.3,
+            f16::MIN_POSITIVE_SUBNORMAL.to_f32() * 0.5,
+        ];
+        for &value in &values {
+            let mut sum = 0f64;
+            let (mut below, mut above) = (f16::INFINITY, f16::NEG_INFINITY);
+            for random in 0..=u16::MAX {
+                let result = f16::from_f32_stochastic(value, random);
+                sum += result.to_f64();
+                below = below.min(result);
+                above = above.max(result);
+            }
+            assert_eq!(sum / 65536., value as f64, "{}", value);
+            // Only the two neighbors are possible, and 0 truncates.
+            assert!(below.to_f64() <= value as f64 && value as f64 <= above.to_f64());
+            assert!(above.to_bits() & 0x7FFF <= (below.to_bits() & 0x7FFF) + 1);
+            assert_eq!(
+                f16::from_f32_stochastic(value, 0),
+                f16::from_f32_round(value, RoundingMode::TowardZero)
+            );
+        }
 
-        assert_eq!(f16::E, e);
-        assert_eq!(f16::PI, pi);
-        assert_eq!(f16::FRAC_1_PI, frac_1_pi);
-        assert_eq!(f16::FRAC_1_SQRT_2, frac_1_sqrt_2);
-        assert_eq!(f16::FRAC_2_PI, frac_2_pi);
-        assert_eq!(f16::FRAC_2_SQRT_PI, frac_2_sqrt_pi);
-        assert_eq!(f16::FRAC_PI_2, frac_pi_2);
-        assert_eq!(f16::FRAC_PI_3, frac_pi_3);
-        assert_eq!(f16::FRAC_PI_4, frac_pi_4);
-        assert_eq!(f16::FRAC_PI_6, frac_pi_6);
-        assert_eq!(f16::FRAC_PI_8, frac_pi_8);
-        assert_eq!(f16::LN_10, ln_10);
-        assert_eq!(f16::LN_2, ln_2);
-        assert_eq!(f16::LOG10_E, log10_e);
-        assert_eq!(f16::LOG10_2, log10_2);
-        assert_eq!(f16::LOG2_E, log2_e);
-        assert_eq!(f16::LOG2_10, log2_10);
-        assert_eq!(f16::SQRT_2, sqrt_2);
+        // With a generator, the mean approaches the value.
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+        let mut rng = StdRng::seed_from_u64(0x5EED);
+        let value = 0.3f32;
+        let mean =
+            (0..10_000).map(|_| f16::from_f32_stochastic(value, rng.gen()).to_f64()).sum::<f64>()
+                / 10_000.;
+        let ulp = f16::EPSILON.to_f64() / 4.;
+        assert!((mean - value as f64).abs() < ulp / 10., "{}", mean);
+        assert_ne!(f16::from_f32(value).to_f64(), value as f64);
+
+        // Exact values, overflow, underflow and special values.
+        for random in [0u16, 1, 0x8000, 0xFFFF] {
+            assert_eq!(f16::from_f32_stochastic(1.5, random), f16::from_f32(1.5));
+            assert_eq!(f16::from_f32_stochastic(65536.0, random), f16::INFINITY);
+            assert_eq!(f16::from_f32_stochastic(-1e10, random), f16::NEG_INFINITY);
+            assert_eq!(f16::from_f32_stochastic(f32::INFINITY, random), f16::INFINITY);
+            assert!(f16::from_f32_stochastic(f32::NAN, random).is_nan());
+            assert_eq!(f16::from_f32_stochastic(-0.0, random).to_bits(), 0x8000);
+            assert_eq!(f16::from_f32_stochastic(1e-30, random).to_bits(), 0x0000);
+            assert_eq!(f16::from_f32_stochastic(-f32::MIN_POSITIVE, random).to_bits(), 0x8000);
+        }
+        assert_eq!(f16::from_f32_stochastic(65520.0, 0x7FFF), f16::MAX);
+        assert_eq!(f16::from_f32_stochastic(65520.0, 0x8000), f16::INFINITY);
+        assert_eq!(
+            f16::from_f32_stochastic(f16::MAX_SUBNORMAL.to_f32() + 1e-8, 0xFFFF),
+            f16::MIN_POSITIVE
+        );
+
+        // The result is reproducible, and does not depend on the target.
+        const ROUNDED: f16 = f16::from_f32_stochastic(0.1, 0x1234);
+        assert_eq!(ROUNDED, f16::from_f32_stochastic(0.1, 0x1234));
+        assert_eq!(ROUNDED.to_bits(), 0x2E66);
     }
 
     #[test]
-    fn test_f16_consts_from_f64() {
-        let one = f16::from_f64(1.0);
-        let zero = f16::from_f64(0.0);
-        let neg_zero = f16::from_f64(-0.0);
-        let inf = f16::from_f64(core::f64::INFINITY);
-        let neg_inf = f16::from_f64(core::f64::NEG_INFINITY);
-        let nan = f16::from_f64(core::f64::NAN);
+    fn test_bit_counts() {
+        for bits in 0..=u16::MAX {
+            let x = f16::from_bits(bits);
+            assert_eq!(x.count_ones(), bits.count_ones());
+            assert_eq!(x.count_ones() + x.count_zeros(), 16);
+            let man = bits & f16::MAN_MASK;
+            let leading = (0..10).rev().take_while(|&i| man & (1 << i) == 0).count() as u32;
+            let trailing = (0..10).take_while(|&i| man & (1 << i) == 0).count() as u32;
+            assert_eq!(x.leading_zeros_mantissa(), leading, "{:#06x}", bits);
+            assert_eq!(x.trailing_zeros_mantissa(), trailing, "{:#06x}", bits);
+        }
+        const ONES: u32 = f16::NAN.count_ones();
+        assert_eq!(ONES, f16::NAN.to_bits().count_ones());
+        assert_eq!(f16::ZERO.leading_zeros_mantissa(), 10);
+        assert_eq!(f16::ZERO.trailing_zeros_mantissa(), 10);
+        assert_eq!(f16::MAX_SUBNORMAL.leading_zeros_mantissa(), 0);
+        assert_eq!(f16::MAX_SUBNORMAL.trailing_zeros_mantissa(), 0);
+    }
 
-        assert_eq!(f16::ONE, one);
-        assert_eq!(f16::ZERO, zero);
-        assert!(zero.is_sign_positive());
-        assert_eq!(f16::NEG_ZERO, neg_zero);
-        assert!(neg_zero.is_sign_negative());
-        assert_eq!(f16::INFINITY, inf);
-        assert_eq!(f16::NEG_INFINITY, neg_inf);
-        assert!(nan.is_nan());
-        assert!(f16::NAN.is_nan());
+    #[test]
+    fn test_abs_diff() {
+        // The difference is rounded once, the same as subtracting in `f32`.
+        let step = if cfg!(miri) {
+            4099
+        } else {
+            97
+        };
+        for a in (0..=u16::MAX).step_by(step) {
+            for b in (0..=u16::MAX).step_by(step + 6) {
+                let (a, b) = (f16::from_bits(a), f16::from_bits(b));
+                let expected = (a - b).abs();
+                let actual = a.abs_diff(b);
+                assert!(
+                    actual.to_bits() == expected.to_bits()
+                        || (actual.is_nan() && expected.is_nan()),
+                    "{:?} {:?}",
+                    a,
+                    b
+                );
+                assert_eq!(a.abs_diff_ulp(b), a.ulp_distance(b).unwrap_or(0));
+            }
+        }
 
-        let e = f16::from_f64(core::f64::consts::E);
-        let pi = f16::from_f64(core::f64::consts::PI);
-        let frac_1_pi = f16::from_f64(core::f64::consts::FRAC_1_PI);
-        let frac_1_sqrt_2 = f16::from_f64(core::f64::consts::FRAC_1_SQRT_2);
-        let frac_2_pi = f16::from_f64(core::f64::consts::FRAC_2_PI);
-        let frac_2_sqrt_pi = f16::from_f64(core::f64::consts::FRAC_2_SQRT_PI);
-        let frac_pi_2 = f16::from_f64(core::f64::consts::FRAC_PI_2);
-        let frac_pi_3 = f16::from_f64(core::f64::consts::FRAC_PI_3);
-        let frac_pi_4 = f16::from_f64(core::f64::consts::FRAC_PI_4);
-        let frac_pi_6 = f16::from_f64(core::f64::consts::FRAC_PI_6);
-        let frac_pi_8 = f16::from_f64(core::f64::consts::FRAC_PI_8);
-        let ln_10 = f16::from_f64(core::f64::consts::LN_10);
-        let ln_2 = f16::from_f64(core::f64::consts::LN_2);
-        let log10_e = f16::from_f64(core::f64::consts::LOG10_E);
-        // core::f64::consts::LOG10_2 requires rustc 1.43.0
-        let log10_2 = f16::from_f64(2f64.log10());
-        let log2_e = f16::from_f64(core::f64::consts::LOG2_E);
-        // core::f64::consts::LOG2_10 requires rustc 1.43.0
-        let log2_10 = f16::from_f64(10f64.log2());
-        let sqrt_2 = f16::from_f64(core::f64::consts::SQRT_2);
+        const DIFF: f16 = f16::MAX.abs_diff(f16::MIN);
+        assert_eq!(DIFF, f16::INFINITY);
+        assert_eq!(f16::MAX.abs_diff(f16::MAX).to_bits(), 0);
+        assert_eq!(f16::ZERO.abs_diff(f16::NEG_ZERO).to_bits(), 0);
+        assert_eq!(f16::ONE.abs_diff(f16::NEG_INFINITY), f16::INFINITY);
+        assert!(f16::NEG_INFINITY.abs_diff(f16::NEG_INFINITY).is_nan());
+        assert!(f16::NAN.abs_diff(f16::ONE).is_nan());
+        assert!(f16::ONE.abs_diff(-f16::NAN).is_nan());
+        assert_eq!(
+            f16::MIN_POSITIVE_SUBNORMAL.abs_diff(-f16::MIN_POSITIVE_SUBNORMAL),
+            f16::from_bits(2)
+        );
 
-        assert_eq!(f16::E, e);
-        assert_eq!(f16::PI, pi);
-        assert_eq!(f16::FRAC_1_PI, frac_1_pi);
-        assert_eq!(f16::FRAC_1_SQRT_2, frac_1_sqrt_2);
-        assert_eq!(f16::FRAC_2_PI, frac_2_pi);
-        assert_eq!(f16::FRAC_2_SQRT_PI, frac_2_sqrt_pi);
-        assert_eq!(f16::FRAC_PI_2, frac_pi_2);
-        assert_eq!(f16::FRAC_PI_3, frac_pi_3);
-        assert_eq!(f16::FRAC_PI_4, frac_pi_4);
-        assert_eq!(f16::FRAC_PI_6, frac_pi_6);
-        assert_eq!(f16::FRAC_PI_8, frac_pi_8);
-        assert_eq!(f16::LN_10, ln_10);
-        assert_eq!(f16::LN_2, ln_2);
-        assert_eq!(f16::LOG10_E, log10_e);
-        assert_eq!(f16::LOG10_2, log10_2);
-        assert_eq!(f16::LOG2_E, log2_e);
-        assert_eq!(f16::LOG2_10, log2_10);
-        assert_eq!(f16::SQRT_2, sqrt_2);
+        const ULPS: u16 = f16::NEG_INFINITY.abs_diff_ulp(f16::INFINITY);
+        assert_eq!(ULPS, 2 * f16::INFINITY.to_bits());
+        assert_eq!(f16::NAN.abs_diff_ulp(f16::NAN), 0);
+        assert_eq!(f16::ONE.abs_diff_ulp(-f16::ONE), 2 * f16::ONE.to_bits());
     }
 
     #[test]
-    fn test_nan_conversion_to_smaller() {
-        let nan64 = f64::from_bits(0x7FF0_0000_0000_0001u64);
-        let neg_nan64 = f64::from_bits(0xFFF0_0000_0000_0001u64);
-        let nan32 = f32::from_bits(0x7F80_0001u32);
-        let neg_nan32 = f32::from_bits(0xFF80_0001u32);
-        let nan32_from_64 = nan64 as f32;
-        let neg_nan32_from_64 = neg_nan64 as f32;
-        let nan16_from_64 = f16::from_f64(nan64);
-        let neg_nan16_from_64 = f16::from_f64(neg_nan64);
-        let nan16_from_32 = f16::from_f32(nan32);
-        let neg_nan16_from_32 = f16::from_f32(neg_nan32);
+    #[cfg(feature = "total-order-impls")]
+    fn test_total_order_impls() {
+        use core::hash::{Hash, Hasher};
 
-        assert!(nan64.is_nan() && nan64.is_sign_positive());
-        assert!(neg_nan64.is_nan() && neg_nan64.is_sign_negative());
-        assert!(nan32.is_nan() && nan32.is_sign_positive());
-        assert!(neg_nan32.is_nan() && neg_nan32.is_sign_negative());
+        struct Recorder(u64);
 
-        // f32/f64 NaN conversion sign is non-deterministic: https://github.com/VoidStarKat/half-rs/issues/103
-        assert!(nan32_from_64.is_nan());
-        assert!(neg_nan32_from_64.is_nan());
-        assert!(nan16_from_64.is_nan());
-        assert!(neg_nan16_from_64.is_nan());
-        assert!(nan16_from_32.is_nan());
-        assert!(neg_nan16_from_32.is_nan());
+        impl Hasher for Recorder {
+            fn finish(&self) -> u64 {
+                self.0
+            }
+
+            fn write(&mut self, bytes: &[u8]) {
+                for &byte in bytes {
+                    self.0 = self.0.wrapping_mul(257).wrapping_add(byte as u64);
+                }
+            }
+        }
+
+        fn hash(value: impl Hash) -> u64 {
+            let mut recorder = Recorder(0);
+            value.hash(&mut recorder);
+            recorder.finish()
+        }
+
+        // Every operator agrees with `total_cmp`, and equality is bitwise.
+        let step = if cfg!(miri) {
+            4099
+        } else {
+            61
+        };
+        for a in (0..=u16::MAX).step_by(step) {
+            for b in (0..=u16::MAX).step_by(step + 6) {
+                let (x, y) = (f16::from_bits(a), f16::from_bits(b));
+                let ordering = x.total_cmp(&y);
+                assert_eq!(x.cmp(&y), ordering);
+                assert_eq!(x.partial_cmp(&y), Some(ordering));
+                assert_eq!(x == y, a == b);
+                assert_eq!(x < y, ordering == Ordering::Less);
+                assert_eq!(x <= y, ordering != Ordering::Greater);
+                assert_eq!(x > y, ordering == Ordering::Greater);
+                assert_eq!(x >= y, ordering != Ordering::Less);
+            }
+            assert_eq!(hash(f16::from_bits(a)), hash(a));
+        }
+
+        assert!(f16::NEG_ZERO < f16::ZERO);
+        assert_ne!(f16::NEG_ZERO, f16::ZERO);
+        assert_eq!(f16::NAN, f16::NAN);
+        assert!(f16::INFINITY < f16::NAN);
+        assert!(-f16::NAN < f16::NEG_INFINITY);
+
+        let mut values = [f16::NAN, f16::ONE, f16::ZERO, -f16::NAN, f16::NEG_ZERO, f16::MIN];
+        values.sort_unstable();
+        assert_eq!(values.map(f16::to_bits), [
+            (-f16::NAN).to_bits(),
+            f16::MIN.to_bits(),
+            f16::NEG_ZERO.to_bits(),
+            f16::ZERO.to_bits(),
+            f16::ONE.to_bits(),
+            f16::NAN.to_bits(),
+        ]);
+
+        // The inherent methods keep the IEEE 754 semantics.
+        assert_eq!(f16::NAN.max(f16::ONE).to_bits(), f16::ONE.to_bits());
+        assert_eq!(f16::ONE.min(f16::NAN).to_bits(), f16::ONE.to_bits());
+        assert_eq!(f16::ZERO.clamp(f16::NEG_ZERO, f16::NEG_ZERO).to_bits(), 0);
+        assert_eq!(f16::NEG_ZERO.max(f16::ZERO).to_bits(), f16::NEG_ZERO.to_bits());
     }
 
     #[test]
-    fn test_nan_conversion_to_larger() {
-        let nan16 = f16::from_bits(0x7C01u16);
-        let neg_nan16 = f16::from_bits(0xFC01u16);
-        let nan32 = f32::from_bits(0x7F80_0001u32);
-        let neg_nan32 = f32::from_bits(0xFF80_0001u32);
-        let nan32_from_16 = f32::from(nan16);
-        let neg_nan32_from_16 = f32::from(neg_nan16);
-        let nan64_from_16 = f64::from(nan16);
-        let neg_nan64_from_16 = f64::from(neg_nan16);
-        let nan64_from_32 = f64::from(nan32);
-        let neg_nan64_from_32 = f64::from(neg_nan32);
-
-        assert!(nan16.is_nan() && nan16.is_sign_positive());
-        assert!(neg_nan16.is_nan() && neg_nan16.is_sign_negative());
-        assert!(nan32.is_nan() && nan32.is_sign_positive());
-        assert!(neg_nan32.is_nan() && neg_nan32.is_sign_negative());
+    fn test_from_bf16_lossy() {
+        // Identical to converting through `f32`, which rounds once.
+        for bits in 0..=u16::MAX {
+            let value = bf16::from_bits(bits);
+            let expected = f16::from_f32(value.to_f32());
+            let actual = f16::from_bf16_lossy(value);
+            if value.is_nan() {
+                assert!(actual.is_nan());
+                if !cfg!(feature = "canonical-nan") {
+                    assert_eq!(actual.to_bits() & 0x8000, bits & 0x8000);
+                }
+            } else {
+                assert_eq!(actual.to_bits(), expected.to_bits(), "{:#06x}", bits);
+            }
+        }
 
-        // f32/f64 NaN conversion sign is non-deterministic: https://github.com/VoidStarKat/half-rs/issues/103
-        assert!(nan32_from_16.is_nan());
-        assert!(neg_nan32_from_16.is_nan());
-        assert!(nan64_from_16.is_nan());
-        assert!(neg_nan64_from_16.is_nan());
-        assert!(nan64_from_32.is_nan());
-        assert!(neg_nan64_from_32.is_nan());
+        // Every bf16 value in the normal range of f16 is exact.
+        for bits in 0..=u16::MAX {
+            let value = bf16::from_bits(bits);
+            let x = value.to_f32().abs();
+            if (f16::MIN_POSITIVE.to_f32()..=f16::MAX.to_f32()).contains(&x) {
+                assert_eq!(f16::from_bf16_lossy(value).to_f32(), value.to_f32());
+            }
+        }
+        const ONE: f16 = f16::from_bf16_lossy(bf16::ONE);
+        assert_eq!(ONE, f16::ONE);
+        assert_eq!(f16::from_bf16_lossy(bf16::from_f32(65536.)), f16::INFINITY);
+        assert_eq!(f16::from_bf16_lossy(bf16::from_f32(-1e5)), f16::NEG_INFINITY);
+        assert_eq!(f16::from_bf16_lossy(bf16::from_f32(-1e-10)).to_bits(), 0x8000);
+        assert_eq!(f16::from_bf16_lossy(bf16::NEG_ZERO).to_bits(), 0x8000);
+        assert_eq!(f16::from_bf16_lossy(bf16::from_f32(65280.)).to_f32(), 65280.);
     }
 
     #[test]
     #[cfg_attr(miri, ignore)]
-    fn test_f16_to_f32() {
-        let f = f16::from_f32(7.0);
-        assert_eq!(f.to_f32(), 7.0f32);
+    fn test_try_into_int() {
+        // The same as a cast, when the value is an integer in range.
+        for bits in 0..=u16::MAX {
+            let value = f16::from_bits(bits);
+            let x = value.to_f64();
+            let kind = |range: bool| {
+                if value.is_nan() {
+                    TryFromHalfErrorKind::Nan
+                } else if value.is_infinite() {
+                    TryFromHalfErrorKind::Infinite
+                } else if !range {
+                    TryFromHalfErrorKind::OutOfRange
+                } else {
+                    TryFromHalfErrorKind::Fractional
+                }
+            };
+            let exact = x.is_finite() && x.fract() == 0.;
+            match u8::try_from(value) {
+                Ok(v) => assert!(exact && v as f64 == x),
+                Err(e) => assert_eq!(e.kind(), kind(x.trunc() >= 0. && x.trunc() <= 255.)),
+            }
+            match i16::try_from(value) {
+                Ok(v) => assert!(exact && v as f64 == x),
+                Err(e) => assert_eq!(e.kind(), kind(x.trunc() >= -32768. && x.trunc() <= 32767.)),
+            }
+            match i64::try_from(value) {
+                Ok(v) => assert!(exact && v as f64 == x),
+                Err(e) => assert_eq!(
+                    e.kind(),
+                    kind(x.trunc().abs() < 2f64.powi(63) || x == -(2f64.powi(63)))
+                ),
+            }
+            match u32::try_from(value) {
+                Ok(v) => assert!(exact && v as f64 == x),
+                Err(e) => assert_eq!(e.kind(), kind(x.trunc() >= 0. && x.trunc() < 2f64.powi(32))),
+            }
+        }
 
-        // 7.1 is NOT exactly representable in 16-bit, it's rounded
-        let f = f16::from_f32(7.1);
-        let diff = (f.to_f32() - 7.1f32).abs();
-        // diff must be <= 4 * EPSILON, as 7 has two more significant bits than 1
-        assert!(diff <= 4.0 * f16::EPSILON.to_f32());
+        assert_eq!(
+            u8::try_from(f16::from_f32(0.5)).unwrap_err().kind(),
+            TryFromHalfErrorKind::Fractional
+        );
+        assert_eq!(
+            i32::try_from(f16::from_f32(-0.5)).unwrap_err().kind(),
+            TryFromHalfErrorKind::Fractional
+        );
+        assert_eq!(
+            u8::try_from(f16::from_f32(-0.5)).unwrap_err().kind(),
+            TryFromHalfErrorKind::Fractional
+        );
+        assert_eq!(
+            u8::try_from(f16::from_f32(-1.)).unwrap_err().kind(),
+            TryFromHalfErrorKind::OutOfRange
+        );
+        assert_eq!(u8::try_from(f16::NEG_ZERO), Ok(0));
+        assert_eq!(i64::try_from(f16::NEG_ZERO), Ok(0));
+        assert_eq!(u8::try_from(f16::from_f32(255.)), Ok(255));
+        assert_eq!(
+            u8::try_from(f16::from_f32(256.)).unwrap_err().kind(),
+            TryFromHalfErrorKind::OutOfRange
+        );
+        assert_eq!(u8::try_from(f16::NAN).unwrap_err().kind(), TryFromHalfErrorKind::Nan);
+        assert_eq!(i32::try_from(-f16::NAN).unwrap_err().kind(), TryFromHalfErrorKind::Nan);
+        assert_eq!(
+            i32::try_from(f16::INFINITY).unwrap_err().kind(),
+            TryFromHalfErrorKind::Infinite
+        );
+        assert_eq!(
+            u128::try_from(f16::NEG_INFINITY).unwrap_err().kind(),
+            TryFromHalfErrorKind::Infinite
+        );
+        assert_eq!(
+            i8::try_from(f16::MIN_POSITIVE_SUBNORMAL).unwrap_err().kind(),
+            TryFromHalfErrorKind::Fractional
+        );
+        assert_eq!(i16::try_from(f16::from_f32(-3.)), Ok(-3));
+        assert_eq!(u16::try_from(f16::MAX), Ok(65504));
+        assert_eq!(i16::try_from(f16::MAX).unwrap_err().kind(), TryFromHalfErrorKind::OutOfRange);
+        assert_eq!(i16::try_from(f16::MIN).unwrap_err().kind(), TryFromHalfErrorKind::OutOfRange);
+        assert_eq!(i32::try_from(f16::MIN), Ok(-65504));
+        assert_eq!(i8::try_from(f16::from_f32(-128.)), Ok(i8::MIN));
+        assert_eq!(
+            i8::try_from(f16::from_f32(-129.)).unwrap_err().kind(),
+            TryFromHalfErrorKind::OutOfRange
+        );
+        assert_eq!(
+            u8::try_from(f16::from_f32(255.5)).unwrap_err().kind(),
+            TryFromHalfErrorKind::Fractional
+        );
+        assert_eq!(
+            u8::try_from(f16::from_f32(256.5)).unwrap_err().kind(),
+            TryFromHalfErrorKind::OutOfRange
+        );
+        assert_eq!(
+            i32::try_from(f16::from_f32(1000.5)).unwrap_err().kind(),
+            TryFromHalfErrorKind::Fractional
+        );
+        assert_eq!(i32::try_from(f16::from_f32(2047.)), Ok(2047));
+    }
 
-        assert_eq!(f16::from_bits(0x0000_0001).to_f32(), 2.0f32.powi(-24));
-        assert_eq!(f16::from_bits(0x0000_0005).to_f32(), 5.0 * 2.0f32.powi(-24));
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_hypot() {
+        let hypot = |a: f32, b: f32| f16::from_f32(a).hypot(f16::from_f32(b));
+        assert_eq!(hypot(3., 4.), f16::from_f32(5.));
+        assert_eq!(hypot(-3., 4.), f16::from_f32(5.));
+        assert_eq!(hypot(0., -0.), f16::ZERO);
+        assert_eq!(f16::MAX.hypot(f16::ZERO), f16::MAX);
+        assert_eq!(f16::MIN.hypot(f16::MIN_POSITIVE_SUBNORMAL), f16::MAX);
+        assert!(f16::MAX.hypot(f16::MAX).is_infinite());
+        assert!(f16::NAN.hypot(f16::ONE).is_nan());
+        assert_eq!(f16::NAN.hypot(f16::NEG_INFINITY), f16::INFINITY);
+
+        // Subnormals, whose squares underflow to zero.
+        let tiny = f16::MIN_POSITIVE_SUBNORMAL;
+        assert_eq!(tiny * tiny, f16::ZERO);
+        assert_eq!(tiny.hypot(f16::ZERO), tiny);
+        let three = f16::from_f32(tiny.to_f32() * 3.);
+        let four = f16::from_f32(tiny.to_f32() * 4.);
+        assert_eq!(three.hypot(four), f16::from_f32(tiny.to_f32() * 5.));
+
+        // Sampled pairs match a single rounding of the exact result.
+        for bits in (0..=u16::MAX).step_by(7) {
+            let a = f16::from_bits(bits);
+            let b = f16::from_bits(bits.wrapping_mul(31));
+            let expected = f16::from_f64(a.to_f64().hypot(b.to_f64()));
+            let actual = a.hypot(b);
+            assert!(
+                actual.to_bits() == expected.to_bits() || (actual.is_nan() && expected.is_nan())
+            );
+        }
 
-        assert_eq!(f16::from_bits(0x0000_0001), f16::from_f32(2.0f32.powi(-24)));
-        assert_eq!(f16::from_bits(0x0000_0005), f16::from_f32(5.0 * 2.0f32.powi(-24)));
+        // Values near the largest value, where the naive sum overflows.
+        let a = f16::from_f32(40000.);
+        let b = f16::from_f32(30000.);
+        assert!((a * a + b * b).is_infinite());
+        assert_eq!(a.hypot(b), f16::from_f32(50000.));
     }
 
     #[test]
     #[cfg_attr(miri, ignore)]
-    fn test_f16_to_f64() {
-        let f = f16::from_f64(7.0);
-        assert_eq!(f.to_f64(), 7.0f64);
+    fn test_try_from_int() {
+        assert_eq!(f16::try_from(2048i32).ok(), Some(f16::from_f32(2048.)));
+        assert_eq!(f16::try_from(-2048i16).ok(), Some(f16::from_f32(-2048.)));
+        assert!(f16::try_from(2049i32).is_err());
+        assert!(f16::try_from(-2049i64).is_err());
+        assert_eq!(f16::try_from(2050u32).ok(), Some(f16::from_f32(2050.)));
+        assert!(f16::try_from(4097u16).is_err());
+        assert!(f16::try_from(4098u64).is_err());
+        assert_eq!(f16::try_from(4100i64).ok(), Some(f16::from_f32(4100.)));
+        assert_eq!(f16::try_from(65504u16).ok(), Some(f16::MAX));
+        assert_eq!(f16::try_from(-65504i32).ok(), Some(f16::MIN));
+        assert!(f16::try_from(65535u16).is_err());
+        assert!(f16::try_from(65536u32).is_err());
+        assert!(f16::try_from(i32::MIN).is_err());
+        assert!(f16::try_from(u64::MAX).is_err());
+        assert!(f16::try_from(i64::MIN).is_err());
+        assert_eq!(f16::try_from(0i32).map(f16::to_bits).ok(), Some(0));
+
+        // The exact integers are the integral values of f16.
+        for x in -70000..=70000 {
+            let exact = f16::from_i32_exact(x);
+            let expected = (x as f32 == f16::from_f32(x as f32).to_f32()
+                && f16::from_f32(x as f32).is_finite())
+            .then(|| f16::from_f32(x as f32));
+            assert_eq!(exact, expected, "{}", x);
+            assert_eq!(f16::try_from(x).ok(), expected);
+            assert_eq!(f16::try_from(x as i64).ok(), expected);
+        }
+        const EXACT: Option<f16> = f16::from_i32_exact(-1024);
+        assert_eq!(EXACT, Some(f16::from_f32(-1024.)));
+    }
 
-        // 7.1 is NOT exactly representable in 16-bit, it's rounded
-        let f = f16::from_f64(7.1);
-        let diff = (f.to_f64() - 7.1f64).abs();
-        // diff must be <= 4 * EPSILON, as 7 has two more significant bits than 1
-        assert!(diff <= 4.0 * f16::EPSILON.to_f64());
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_from_f64_of_f32() {
+        // Every f64 that is exactly an f32 converts the same as the f32, on
+        // every backend. The sweep covers every exponent, with every pattern
+        // of the mantissa bits from the lowest kept bit to the round bit, and
+        // sticky bits below them.
+        let mut narrow = [0f32; 4096];
+        let mut wide = [0f64; 4096];
+        let mut from_narrow = [f16::ZERO; 4096];
+        let mut from_wide = [f16::ZERO; 4096];
+        for sign in [0u32, 0x8000_0000] {
+            for exp in 0..=0xFFu32 {
+                for low in [0u32, 1, 0x400, 0x7FF] {
+                    for (high, (x, y)) in narrow.iter_mut().zip(wide.iter_mut()).enumerate() {
+                        *x = f32::from_bits(sign | (exp << 23) | ((high as u32) << 11) | low);
+                        *y = *x as f64;
+                    }
+                    for (&x, &wide) in narrow.iter().zip(wide.iter()) {
+                        if x.is_nan() {
+                            assert!(f16::from_f64(wide).is_nan());
+                            assert!(f16::from_f64_const(wide).is_nan());
+                            continue;
+                        }
+                        let expected = f16::from_f32_const(x).to_bits();
+                        assert_eq!(f16::from_f32(x).to_bits(), expected, "{:#010x}", x.to_bits());
+                        assert_eq!(
+                            f16::from_f64(wide).to_bits(),
+                            expected,
+                            "{:#010x}",
+                            x.to_bits()
+                        );
+                        assert_eq!(
+                            f16::from_f64_const(wide).to_bits(),
+                            expected,
+                            "{:#010x}",
+                            x.to_bits()
+                        );
+                        let inst = f16::from_f64_instrinsic(wide).to_bits();
+                        assert_eq!(
+                            inst,
+                            f16::from_f32_instrinsic(x).to_bits(),
+                            "{:#010x}",
+                            x.to_bits()
+                        );
+                        assert_eq!(inst, expected, "{:#010x}", x.to_bits());
+                    }
+                    if exp == 0xFF {
+                        continue;
+                    }
+                    from_narrow.convert_from_f32_slice(&narrow);
+                    from_wide.convert_from_f64_slice(&wide);
+                    assert_eq!(from_narrow.map(f16::to_bits), from_wide.map(f16::to_bits));
+                    for (&x, y) in narrow.iter().zip(from_narrow.iter()) {
+                        assert_eq!(f16::from_f32_const(x).to_bits(), y.to_bits());
+                    }
+                }
+            }
+        }
+    }
 
-        assert_eq!(f16::from_bits(0x0000_0001).to_f64(), 2.0f64.powi(-24));
-        assert_eq!(f16::from_bits(0x0000_0005).to_f64(), 5.0 * 2.0f64.powi(-24));
+    #[test]
+    fn test_from_int_lossless() {
+        // Integers with few significant bits, shifted over the whole range of
+        // each type, against a conversion of the exact f64 value.
+        let expected = |x: f64| {
+            let value = f16::from_f64(x);
+            (value.is_finite() && value.to_f64() == x).then(|| value)
+        };
+        for base in [1u128, 3, 5, 127, 255, 257, 511, 1023, 2047, 2049, 4095] {
+            for shift in 0..128 {
+                let mag = match base.checked_shl(shift) {
+                    Some(mag) if mag >> shift == base => mag,
+                    _ => continue,
+                };
+                let x = mag as f64;
+                assert_eq!(f16::from_u128_lossless(mag), expected(x), "{}", mag);
+                if let Ok(mag) = u64::try_from(mag) {
+                    assert_eq!(f16::from_u64_lossless(mag), expected(x), "{}", mag);
+                }
+                if let Ok(mag) = u32::try_from(mag) {
+                    assert_eq!(f16::from_u32_lossless(mag), expected(x), "{}", mag);
+                }
+                if let Ok(mag) = u16::try_from(mag) {
+                    assert_eq!(f16::from_u16_lossless(mag), expected(x), "{}", mag);
+                }
+                if let Ok(mag) = u8::try_from(mag) {
+                    assert_eq!(f16::from_u8_lossless(mag), expected(x), "{}", mag);
+                }
+                if let Ok(value) = i128::try_from(mag) {
+                    assert_eq!(f16::from_i128_lossless(value), expected(x), "{}", value);
+                    assert_eq!(f16::from_i128_lossless(-value), expected(-x), "{}", value);
+                }
+                if let Ok(value) = i64::try_from(mag) {
+                    assert_eq!(f16::from_i64_lossless(value), expected(x), "{}", value);
+                    assert_eq!(f16::from_i64_lossless(-value), expected(-x), "{}", value);
+                }
+                if let Ok(value) = i32::try_from(mag) {
+                    assert_eq!(f16::from_i32_lossless(value), expected(x), "{}", value);
+                    assert_eq!(f16::from_i32_lossless(-value), expected(-x), "{}", value);
+                }
+                if let Ok(value) = i16::try_from(mag) {
+                    assert_eq!(f16::from_i16_lossless(value), expected(x), "{}", value);
+                    assert_eq!(f16::from_i16_lossless(-value), expected(-x), "{}", value);
+                }
+                if let Ok(value) = i8::try_from(mag) {
+                    assert_eq!(f16::from_i8_lossless(value), expected(x), "{}", value);
+                    assert_eq!(f16::from_i8_lossless(-value), expected(-x), "{}", value);
+                }
+            }
+        }
 
-        assert_eq!(f16::from_bits(0x0000_0001), f16::from_f64(2.0f64.powi(-24)));
-        assert_eq!(f16::from_bits(0x0000_0005), f16::from_f64(5.0 * 2.0f64.powi(-24)));
+        // The extremes of each type, and zero, which is always +0.
+        assert_eq!(f16::from_i8_lossless(i8::MIN), Some(f16::from_f32(-128.)));
+        assert_eq!(f16::from_i8_lossless(i8::MAX), Some(f16::from_f32(127.)));
+        assert_eq!(f16::from_u8_lossless(u8::MAX), Some(f16::from_f32(255.)));
+        assert_eq!(f16::from_u16_lossless(u16::MAX), None);
+        assert_eq!(f16::from_i32_lossless(i32::MAX), None);
+        assert_eq!(f16::from_u128_lossless(u128::MAX), None);
+        assert_eq!(f16::from_i64_lossless(0).map(f16::to_bits), Some(0));
+        assert_eq!(f16::from_i128_lossless(0).map(f16::to_bits), Some(0));
+        assert_eq!(f16::from_u32_lossless(0).map(f16::to_bits), Some(0));
+        assert_eq!(f16::from_u32_lossless(65504), Some(f16::MAX));
+        assert_eq!(f16::from_i64_lossless(-65504), Some(f16::MIN));
+        assert_eq!(f16::from_u32_lossless(65536), None);
+        assert_eq!(f16::from_i128_lossless(i128::MIN), None);
+
+        const VALUE: Option<f16> = f16::from_u64_lossless(2050);
+        assert_eq!(VALUE, Some(f16::from_f32(2050.)));
     }
 
     #[test]
-    fn test_comparisons() {
-        let zero = f16::from_f64(0.0);
-        let one = f16::from_f64(1.0);
-        let neg_zero = f16::from_f64(-0.0);
-        let neg_one = f16::from_f64(-1.0);
+    fn test_consts_rounding() {
+        use core::f64::consts;
+
+        // The same check as the compile-time one, on the runtime paths, and
+        // through an f32, where the first rounding is never a midpoint.
+        let pairs = [
+            (f16::E, consts::E),
+            (f16::PI, consts::PI),
+            (f16::FRAC_1_PI, consts::FRAC_1_PI),
+            (f16::FRAC_1_SQRT_2, consts::FRAC_1_SQRT_2),
+            (f16::FRAC_2_PI, consts::FRAC_2_PI),
+            (f16::FRAC_2_SQRT_PI, consts::FRAC_2_SQRT_PI),
+            (f16::FRAC_PI_2, consts::FRAC_PI_2),
+            (f16::FRAC_PI_3, consts::FRAC_PI_3),
+            (f16::FRAC_PI_4, consts::FRAC_PI_4),
+            (f16::FRAC_PI_6, consts::FRAC_PI_6),
+            (f16::FRAC_PI_8, consts::FRAC_PI_8),
+            (f16::LN_10, consts::LN_10),
+            (f16::LN_2, consts::LN_2),
+            (f16::LOG10_E, consts::LOG10_E),
+            (f16::LOG10_2, consts::LOG10_2),
+            (f16::LOG2_E, consts::LOG2_E),
+            (f16::LOG2_10, consts::LOG2_10),
+            (f16::SQRT_2, consts::SQRT_2),
+        ];
+        for (constant, x) in pairs {
+            assert_eq!(f16::from_f64(x).to_bits(), constant.to_bits(), "{}", x);
+            assert_eq!(f16::from_f64_const(x).to_bits(), constant.to_bits(), "{}", x);
+            assert_eq!(f16::from_f32(x as f32).to_bits(), constant.to_bits(), "{}", x);
+            // The value is within half a step of the exact constant.
+            let next = f16::from_bits(constant.to_bits() + 1).to_f64();
+            let prev = f16::from_bits(constant.to_bits() - 1).to_f64();
+            let value = constant.to_f64();
+            assert!((value - x).abs() < (next - value) / 2., "{}", x);
+            assert!((value - x).abs() < (value - prev) / 2., "{}", x);
+        }
+    }
 
-        assert_eq!(zero.partial_cmp(&neg_zero), Some(Ordering::Equal));
-        assert_eq!(neg_zero.partial_cmp(&zero), Some(Ordering::Equal));
-        assert!(zero == neg_zero);
-        assert!(neg_zero == zero);
-        assert!(!(zero != neg_zero));
-        assert!(!(neg_zero != zero));
-        assert!(!(zero < neg_zero));
-        assert!(!(neg_zero < zero));
-        assert!(zero <= neg_zero);
-        assert!(neg_zero <= zero);
-        assert!(!(zero > neg_zero));
-        assert!(!(neg_zero > zero));
-        assert!(zero >= neg_zero);
-        assert!(neg_zero >= zero);
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(miri, ignore)]
+    fn test_numpy_repr() {
+        use std::string::ToString;
+
+        // Known `str(numpy.float16(x))` outputs.
+        let cases = [
+            (0x0000, "0.0"),
+            (0x8000, "-0.0"),
+            (0x3C00, "1.0"),
+            (0xC100, "-2.5"),
+            (0x2E66, "0.1"),
+            (0x6400, "1024.0"),
+            (0x6802, "2052.0"),
+            (0x6C04, "4112.0"),
+            (0x7BFF, "65500.0"),
+            (0x068E, "0.0001"),
+            (0x068D, "9.996e-05"),
+            (0x00A8, "1e-05"),
+            (0x0001, "6e-08"),
+            (0x8001, "-6e-08"),
+            (0x7C00, "inf"),
+            (0xFC00, "-inf"),
+            (0x7E00, "nan"),
+            (0xFE00, "nan"),
+        ];
+        for (bits, repr) in cases {
+            assert_eq!(f16::from_bits(bits).to_numpy_repr_string(), repr, "{:#06x}", bits);
+        }
 
-        assert_eq!(one.partial_cmp(&neg_zero), Some(Ordering::Greater));
-        assert_eq!(neg_zero.partial_cmp(&one), Some(Ordering::Less));
-        assert!(!(one == neg_zero));
-        assert!(!(neg_zero == one));
-        assert!(one != neg_zero);
-        assert!(neg_zero != one);
-        assert!(!(one < neg_zero));
-        assert!(neg_zero < one);
-        assert!(!(one <= neg_zero));
-        assert!(neg_zero <= one);
-        assert!(one > neg_zero);
-        assert!(!(neg_zero > one));
-        assert!(one >= neg_zero);
-        assert!(!(neg_zero >= one));
+        // Every value parses back, the same as `Display`, even where NumPy
+        // avoids the halfway decimal that `Display` uses.
+        for bits in 0..=u16::MAX {
+            let value = f16::from_bits(bits);
+            if !value.is_finite() {
+                continue;
+            }
+            let repr = value.to_numpy_repr_string();
+            let parsed: f16 = repr.parse().unwrap();
+            assert_eq!(parsed.to_bits(), bits, "{}", repr);
+            let display: f16 = value.to_string().parse().unwrap();
+            assert_eq!(display.to_bits(), bits, "{}", value);
+        }
+    }
 
-        assert_eq!(one.partial_cmp(&neg_one), Some(Ordering::Greater));
-        assert_eq!(neg_one.partial_cmp(&one), Some(Ordering::Less));
-        assert!(!(one == neg_one));
-        assert!(!(neg_one == one));
-        assert!(one != neg_one);
-        assert!(neg_one != one);
-        assert!(!(one < neg_one));
-        assert!(neg_one < one);
-        assert!(!(one <= neg_one));
-        assert!(neg_one <= one);
-        assert!(one > neg_one);
-        assert!(!(neg_one > one));
-        assert!(one >= neg_one);
-        assert!(!(neg_one >= one));
+    #[test]
+    fn test_from_bool() {
+        assert_eq!(f16::from_bool(true).to_bits(), f16::ONE.to_bits());
+        assert_eq!(f16::from_bool(false).to_bits(), f16::ZERO.to_bits());
+        for value in [false, true] {
+            assert_eq!(f16::from(value).to_bits(), f16::from(value as u8).to_bits());
+            assert_eq!(f16::from(value).to_f32(), f32::from(value as u8));
+        }
+    }
+
+    #[test]
+    fn test_checked_arith() {
+        let f = f16::from_f32;
+        let values = [
+            f16::ZERO,
+            f16::NEG_ZERO,
+            f16::MIN_POSITIVE_SUBNORMAL,
+            f16::MIN_POSITIVE,
+            f(-0.75),
+            f16::ONE,
+            f(3.5),
+            f(-100.0),
+            f16::MAX,
+            f16::MIN,
+            f16::INFINITY,
+            f16::NEG_INFINITY,
+            f16::NAN,
+            -f16::NAN,
+        ];
+        type Op = (fn(f16, f16) -> Option<f16>, fn(f16, f16) -> f16);
+        let ops: [Op; 4] = [
+            (f16::checked_add, |x, y| x + y),
+            (f16::checked_sub, |x, y| x - y),
+            (f16::checked_mul, |x, y| x * y),
+            (f16::checked_div, |x, y| x / y),
+        ];
+        // Only finite results are returned, and they match the operators.
+        for (checked, op) in ops {
+            for &x in &values {
+                for &y in &values {
+                    let expected = op(x, y);
+                    match checked(x, y) {
+                        Some(value) => assert_eq!(value.to_bits(), expected.to_bits()),
+                        None => assert!(!expected.is_finite(), "{:?} {:?}", x, y),
+                    }
+                    if x.is_nan() || y.is_nan() || x.is_infinite() {
+                        assert_eq!(checked(x, y), None, "{:?} {:?}", x, y);
+                    }
+                }
+            }
+        }
+        assert_eq!(f16::MAX.checked_add(f16::MAX), None);
+        assert_eq!(f16::MIN.checked_mul(f(2.)), None);
+        assert_eq!(f16::ONE.checked_div(f16::NEG_ZERO), None);
+        assert_eq!(
+            f(-3.).checked_div(f16::INFINITY).map(f16::to_bits),
+            Some(f16::NEG_ZERO.to_bits())
+        );
     }
 
     #[test]
-    #[allow(clippy::erasing_op, clippy::identity_op)]
     #[cfg_attr(miri, ignore)]
-    fn round_to_even_f32() {
-        // smallest positive subnormal = 0b0.0000_0000_01 * 2^-14 = 2^-24
-        let min_sub = f16::from_bits(1);
-        let min_sub_f = (-24f32).exp2();
-        assert_eq!(f16::from_f32(min_sub_f).to_bits(), min_sub.to_bits());
-        assert_eq!(f32::from(min_sub).to_bits(), min_sub_f.to_bits());
+    fn test_to_bf16() {
+        // Converting to `f32` is exact, so converting through it rounds
+        // once, and the results are identical, including NaN payloads.
+        for bits in 0..=u16::MAX {
+            let value = f16::from_bits(bits);
+            let wide = value.to_f32();
+            if !value.is_nan() {
+                assert_eq!(f16::from_f32(wide).to_bits(), bits);
+            }
+            let expected = bf16::from_f32(wide);
+            assert_eq!(value.to_bf16().to_bits(), expected.to_bits(), "{:#06x}", bits);
+            assert_eq!(value.to_bf16().to_bits(), bf16::from_f16_lossy(value).to_bits());
+        }
+    }
 
-        // 0.0000000000_011111 rounded to 0.0000000000 (< tie, no rounding)
-        // 0.0000000000_100000 rounded to 0.0000000000 (tie and even, remains at even)
-        // 0.0000000000_100001 rounded to 0.0000000001 (> tie, rounds up)
-        assert_eq!(f16::from_f32(min_sub_f * 0.49).to_bits(), min_sub.to_bits() * 0);
-        assert_eq!(f16::from_f32(min_sub_f * 0.50).to_bits(), min_sub.to_bits() * 0);
-        assert_eq!(f16::from_f32(min_sub_f * 0.51).to_bits(), min_sub.to_bits() * 1);
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_try_from_bf16() {
+        // Converting to `f32` is exact, so a value is lossless if it is the
+        // same after rounding from `f32`.
+        let mut count = 0;
+        for bits in 0..=u16::MAX {
+            let value = bf16::from_bits(bits);
+            let result = f16::try_from(value).ok();
+            assert_eq!(result.map(f16::to_bits), f16::from_bf16_lossless(value).map(f16::to_bits));
+            if value.is_nan() {
+                assert!(result.unwrap().is_nan(), "{:#06x}", bits);
+            } else if f16::from_f32(value.to_f32()).to_f32() == value.to_f32() {
+                assert_eq!(
+                    result.unwrap().to_f32().to_bits(),
+                    value.to_f32().to_bits(),
+                    "{:#06x}",
+                    bits
+                );
+                count += 1;
+            } else {
+                assert!(result.is_none(), "{:#06x}", bits);
+            }
+        }
+        // ±0 and ±∞, the 30 normal exponents with any mantissa, and the
+        // subnormals with an exponent from 2^-24 to 2^-15 and no bits below
+        // 2^-24.
+        assert_eq!(count, 2 * (2 + 30 * 128 + (1 + 2 + 4 + 8 + 16 + 32 + 64 + 3 * 128)));
+        assert!(f16::try_from(bf16::from_f32(-65280.)).is_ok());
+        assert!(f16::try_from(bf16::from_f32(65536.)).is_err());
+        assert!(f16::try_from(bf16::from_f32(f16::MIN_POSITIVE_SUBNORMAL.to_f32() * 1.5)).is_err());
+    }
 
-        // 0.0000000001_011111 rounded to 0.0000000001 (< tie, no rounding)
-        // 0.0000000001_100000 rounded to 0.0000000010 (tie and odd, rounds up to even)
-        // 0.0000000001_100001 rounded to 0.0000000010 (> tie, rounds up)
-        assert_eq!(f16::from_f32(min_sub_f * 1.49).to_bits(), min_sub.to_bits() * 1);
-        assert_eq!(f16::from_f32(min_sub_f * 1.50).to_bits(), min_sub.to_bits() * 2);
-        assert_eq!(f16::from_f32(min_sub_f * 1.51).to_bits(), min_sub.to_bits() * 2);
+    #[test]
+    fn test_saturating_from() {
+        // Values just above MAX, and far above it.
+        let max = f16::MAX.to_f32();
+        let above =
+            [f32::from_bits(max.to_bits() + 1), max * (1. + f16::EPSILON.to_f32() / 2.), f32::MAX];
+        for x in above.iter().copied().chain([f32::INFINITY]) {
+            assert_eq!(f16::saturating_from_f32(x), f16::MAX, "{:e}", x);
+            assert_eq!(f16::saturating_from_f32(-x), f16::MIN, "{:e}", x);
+            assert_eq!(f16::saturating_from_f64(x as f64), f16::MAX, "{:e}", x);
+            assert_eq!(f16::saturating_from_f64(-x as f64), f16::MIN, "{:e}", x);
+        }
+        assert_eq!(f16::saturating_from_f64(f64::MAX), f16::MAX);
+        assert_eq!(f16::saturating_from_f64(f64::NEG_INFINITY), f16::MIN);
+        assert!(f16::saturating_from_f32(f32::NAN).is_nan());
+        assert!(f16::saturating_from_f64(-f64::NAN).is_nan());
+
+        // Only infinities differ from the existing saturating conversions.
+        assert_eq!(f16::from_f32_saturating(f32::INFINITY), f16::INFINITY);
+        for &x in &above {
+            assert_eq!(f16::saturating_from_f32(x), f16::from_f32_saturating(x));
+        }
 
-        // 0.0000000010_011111 rounded to 0.0000000010 (< tie, no rounding)
-        // 0.0000000010_100000 rounded to 0.0000000010 (tie and even, remains at even)
-        // 0.0000000010_100001 rounded to 0.0000000011 (> tie, rounds up)
-        assert_eq!(f16::from_f32(min_sub_f * 2.49).to_bits(), min_sub.to_bits() * 2);
-        assert_eq!(f16::from_f32(min_sub_f * 2.50).to_bits(), min_sub.to_bits() * 2);
-        assert_eq!(f16::from_f32(min_sub_f * 2.51).to_bits(), min_sub.to_bits() * 3);
+        // Every other value is unchanged.
+        for bits in (0..=u16::MAX).step_by(7) {
+            let value = f16::from_bits(bits);
+            if value.is_finite() {
+                assert_eq!(f16::saturating_from_f32(value.to_f32()).to_bits(), bits);
+                assert_eq!(f16::saturating_from_f64(value.to_f64()).to_bits(), bits);
+            }
+        }
+    }
 
-        assert_eq!(f16::from_f32(2000.49f32).to_bits(), f16::from_f32(2000.0).to_bits());
-        assert_eq!(f16::from_f32(2000.50f32).to_bits(), f16::from_f32(2000.0).to_bits());
-        assert_eq!(f16::from_f32(2000.51f32).to_bits(), f16::from_f32(2001.0).to_bits());
-        assert_eq!(f16::from_f32(2001.49f32).to_bits(), f16::from_f32(2001.0).to_bits());
-        assert_eq!(f16::from_f32(2001.50f32).to_bits(), f16::from_f32(2002.0).to_bits());
-        assert_eq!(f16::from_f32(2001.51f32).to_bits(), f16::from_f32(2002.0).to_bits());
-        assert_eq!(f16::from_f32(2002.49f32).to_bits(), f16::from_f32(2002.0).to_bits());
-        assert_eq!(f16::from_f32(2002.50f32).to_bits(), f16::from_f32(2002.0).to_bits());
-        assert_eq!(f16::from_f32(2002.51f32).to_bits(), f16::from_f32(2003.0).to_bits());
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_exponent_mantissa() {
+        for bits in 0..=u16::MAX {
+            let value = f16::from_bits(bits);
+            let (exp, man) = (value.biased_exponent(), value.mantissa());
+            assert_eq!(bits & f16::SIGN_MASK | exp << 10 | man, bits);
+            assert!(exp < 32 && man < 1024);
+            if value.is_finite() {
+                // The fields decode to the value, with the leading bit only
+                // for normal values.
+                let leading = if value.is_normal() {
+                    1024
+                } else {
+                    0
+                };
+                let scale = f64::powi(2., (value.unbiased_exponent() - 10) as i32);
+                let magnitude = (leading + man) as f64 * scale;
+                assert_eq!(value.to_f64().abs(), magnitude, "{:#06x}", bits);
+            } else {
+                assert_eq!(value.unbiased_exponent(), 16);
+            }
+            assert_eq!(value.unbiased_exponent(), exp.max(1) as i16 - 15);
+        }
     }
 
     #[test]
-    #[allow(clippy::erasing_op, clippy::identity_op)]
     #[cfg_attr(miri, ignore)]
-    fn round_to_even_f64() {
-        // smallest positive subnormal = 0b0.0000_0000_01 * 2^-14 = 2^-24
-        let min_sub = f16::from_bits(1);
-        let min_sub_f = (-24f64).exp2();
-        assert_eq!(f16::from_f64(min_sub_f).to_bits(), min_sub.to_bits());
-        assert_eq!(f64::from(min_sub).to_bits(), min_sub_f.to_bits());
+    fn test_from_json_number() {
+        // Every value is exact, and the next `f64` above it is not.
+        for bits in 0..=u16::MAX {
+            let value = f16::from_bits(bits);
+            let n = value.to_f64();
+            let (result, exact) = f16::from_json_number(n);
+            assert!(exact, "{:#06x}", bits);
+            assert!(result.to_bits() == bits || value.is_nan(), "{:#06x}", bits);
+            if value.is_finite() {
+                let next = f64::from_bits(n.to_bits() + 1);
+                assert_eq!(f16::from_json_number(next), (f16::from_f64(next), false));
+            }
+        }
 
-        // 0.0000000000_011111 rounded to 0.0000000000 (< tie, no rounding)
-        // 0.0000000000_100000 rounded to 0.0000000000 (tie and even, remains at even)
-        // 0.0000000000_100001 rounded to 0.0000000001 (> tie, rounds up)
-        assert_eq!(f16::from_f64(min_sub_f * 0.49).to_bits(), min_sub.to_bits() * 0);
-        assert_eq!(f16::from_f64(min_sub_f * 0.50).to_bits(), min_sub.to_bits() * 0);
-        assert_eq!(f16::from_f64(min_sub_f * 0.51).to_bits(), min_sub.to_bits() * 1);
+        let cases = [
+            (65504., 0x7BFF, true),
+            (65519.99, 0x7BFF, false),
+            (65520., 0x7C00, false),
+            (-1e300, 0xFC00, false),
+            (f64::powi(2., -14), 0x0400, true),
+            (f64::powi(2., -24), 0x0001, true),
+            (f64::powi(2., -25), 0x0000, false),
+            (1.5 * f64::powi(2., -24), 0x0002, false),
+            (-1e-300, 0x8000, false),
+            (1. + f64::powi(2., -10), 0x3C01, true),
+            (1. + f64::powi(2., -11), 0x3C00, false),
+            (-0., 0x8000, true),
+            (f64::INFINITY, 0x7C00, true),
+        ];
+        for (n, bits, exact) in cases {
+            let (value, flag) = f16::from_json_number(n);
+            assert_eq!((value.to_bits(), flag), (bits, exact), "{:e}", n);
+        }
+        let (value, exact) = f16::from_json_number(f64::NAN);
+        assert!(value.is_nan() && exact);
+    }
 
-        // 0.0000000001_011111 rounded to 0.0000000001 (< tie, no rounding)
-        // 0.0000000001_100000 rounded to 0.0000000010 (tie and odd, rounds up to even)
-        // 0.0000000001_100001 rounded to 0.0000000010 (> tie, rounds up)
-        assert_eq!(f16::from_f64(min_sub_f * 1.49).to_bits(), min_sub.to_bits() * 1);
-        assert_eq!(f16::from_f64(min_sub_f * 1.50).to_bits(), min_sub.to_bits() * 2);
-        assert_eq!(f16::from_f64(min_sub_f * 1.51).to_bits(), min_sub.to_bits() * 2);
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_canonicalize() {
+        for bits in 0..=u16::MAX {
+            let x = f16::from_bits(bits);
+            let canonical = x.canonicalize();
+            let zero = x.canonicalize_zero();
+            if x.is_nan() {
+                assert_eq!(canonical.to_bits(), f16::NAN.to_bits());
+                assert_eq!(zero.to_bits(), f16::NAN.to_bits());
+            } else {
+                assert_eq!(canonical.to_bits(), bits);
+                let expected = if bits == 0x8000 {
+                    0
+                } else {
+                    bits
+                };
+                assert_eq!(zero.to_bits(), expected);
+            }
+            // Both are idempotent.
+            assert_eq!(canonical.canonicalize().to_bits(), canonical.to_bits());
+            assert_eq!(zero.canonicalize_zero().to_bits(), zero.to_bits());
+            assert_eq!(canonical.canonicalize_zero().to_bits(), zero.to_bits());
+        }
+    }
 
-        // 0.0000000010_011111 rounded to 0.0000000010 (< tie, no rounding)
-        // 0.0000000010_100000 rounded to 0.0000000010 (tie and even, remains at even)
-        // 0.0000000010_100001 rounded to 0.0000000011 (> tie, rounds up)
-        assert_eq!(f16::from_f64(min_sub_f * 2.49).to_bits(), min_sub.to_bits() * 2);
-        assert_eq!(f16::from_f64(min_sub_f * 2.50).to_bits(), min_sub.to_bits() * 2);
-        assert_eq!(f16::from_f64(min_sub_f * 2.51).to_bits(), min_sub.to_bits() * 3);
+    #[test]
+    fn test_signaling_nan() {
+        let snan = f16::SIGNALING_NAN;
+        assert!(snan.is_nan());
+        assert!(snan.is_nan_signaling() && !snan.is_nan_quiet());
+        assert!(f16::NAN.is_nan_quiet() && !f16::NAN.is_nan_signaling());
+        assert_eq!(f16::from_bits(snan.to_bits()).to_bits(), 0x7D00);
+        assert_eq!(snan.quiet().to_bits(), 0x7F00);
+        assert_eq!((-snan).quiet().to_bits(), 0xFF00);
+
+        for bits in 0..=u16::MAX {
+            let value = f16::from_bits(bits);
+            assert_eq!(value.is_nan(), value.is_nan_signaling() || value.is_nan_quiet());
+            assert!(!(value.is_nan_signaling() && value.is_nan_quiet()));
+            assert_eq!(f16::from_bits(value.to_bits()).to_bits(), bits);
+            let quiet = value.quiet();
+            if value.is_nan() {
+                assert!(quiet.is_nan_quiet(), "{:#06x}", bits);
+                assert_eq!(quiet.to_bits() & !0x0200, bits & !0x0200);
+            } else {
+                assert_eq!(quiet.to_bits(), bits);
+            }
+            assert_eq!(value.is_nan_signaling(), bits & 0x7E00 == 0x7C00 && bits & 0x01FF != 0);
+        }
+    }
 
-        assert_eq!(f16::from_f64(2000.49f64).to_bits(), f16::from_f64(2000.0).to_bits());
-        assert_eq!(f16::from_f64(2000.50f64).to_bits(), f16::from_f64(2000.0).to_bits());
-        assert_eq!(f16::from_f64(2000.51f64).to_bits(), f16::from_f64(2001.0).to_bits());
-        assert_eq!(f16::from_f64(2001.49f64).to_bits(), f16::from_f64(2001.0).to_bits());
-        assert_eq!(f16::from_f64(2001.50f64).to_bits(), f16::from_f64(2002.0).to_bits());
-        assert_eq!(f16::from_f64(2001.51f64).to_bits(), f16::from_f64(2002.0).to_bits());
-        assert_eq!(f16::from_f64(2002.49f64).to_bits(), f16::from_f64(2002.0).to_bits());
-        assert_eq!(f16::from_f64(2002.50f64).to_bits(), f16::from_f64(2002.0).to_bits());
-        assert_eq!(f16::from_f64(2002.51f64).to_bits(), f16::from_f64(2003.0).to_bits());
+    #[test]
+    fn test_scale() {
+        // Subnormal values scale up into the normal range, exactly.
+        assert_eq!(f16::MIN_POSITIVE_SUBNORMAL.scale(10), f16::MIN_POSITIVE);
+        assert_eq!(f16::MIN_POSITIVE.scale(15), f16::from_f32(2.));
+        assert_eq!(f16::from_bits(0x0155).scale(3).to_bits(), 0x0954);
+        // Normal values scale down into the subnormal range, rounding to even.
+        assert_eq!(f16::MIN_POSITIVE.scale(-1).to_bits(), 0x0200);
+        assert_eq!(f16::from_bits(0x0403).scale(-1).to_bits(), 0x0202);
+        assert_eq!(f16::from_bits(0x0401).scale(-1).to_bits(), 0x0200);
+        assert_eq!(f16::from_f32(-1.5).scale(-25).to_bits(), 0x8001);
+        assert_eq!(f16::ONE.scale(-25).to_bits(), 0x0000);
+        assert_eq!(f16::NEG_ONE.scale(-60).to_bits(), 0x8000);
+        // Overflow and the values returned unchanged.
+        assert_eq!(f16::MIN.scale(1), f16::NEG_INFINITY);
+        assert_eq!(f16::MIN_POSITIVE_SUBNORMAL.scale(i32::MAX), f16::INFINITY);
+        assert_eq!(f16::MAX.scale(i32::MIN), f16::ZERO);
+        assert_eq!(f16::NEG_ZERO.scale(5).to_bits(), 0x8000);
+        assert_eq!(f16::NAN.scale(-5).to_bits(), f16::NAN.to_bits());
+        assert_eq!(f16::NEG_INFINITY.scale(-100), f16::NEG_INFINITY);
     }
 
     #[test]
-    fn arithmetic() {
-        assert_eq!(f16::ONE + f16::ONE, f16::from_f32(2.));
-        assert_eq!(f16::ONE - f16::ONE, f16::ZERO);
-        assert_eq!(f16::ONE * f16::ONE, f16::ONE);
-        assert_eq!(f16::from_f32(2.) * f16::from_f32(2.), f16::from_f32(4.));
-        assert_eq!(f16::ONE / f16::ONE, f16::ONE);
-        assert_eq!(f16::from_f32(4.) / f16::from_f32(2.), f16::from_f32(2.));
-        assert_eq!(f16::from_f32(4.) % f16::from_f32(3.), f16::from_f32(1.));
+    #[cfg_attr(miri, ignore)]
+    fn test_scale_exhaustive() {
+        for bits in 0..=u16::MAX {
+            let x = f16::from_bits(bits);
+            for n in -45..=45 {
+                let expected = f16::from_f64(x.to_f64() * 2f64.powi(n));
+                let scaled = x.scale(n);
+                if x.is_nan() {
+                    assert_eq!(scaled.to_bits(), bits);
+                } else {
+                    assert_eq!(scaled.to_bits(), expected.to_bits(), "{:#06x} {}", bits, n);
+                }
+            }
+        }
     }
 
     #[test]
-    fn issue_116() {
-        // SEE: https://github.com/starkat99/half-rs/issues/116
-        //  This is lossy until `_mm_cvtpd_ph` will be stable on x86.
-        let max_diff = if cfg!(any(target_arch = "x86", target_arch = "x86_64")) {
-            1
-        } else {
-            0
+    fn test_canonical_nan() {
+        let check = |result: f16| {
+            assert!(result.is_nan());
+            if cfg!(feature = "canonical-nan") {
+                assert_eq!(result.to_bits(), f16::NAN.to_bits());
+            }
         };
+        let nans = [0x7C01u16, 0xFC01, 0x7D00, 0x7E55, 0xFE00, 0xFFFF].map(f16::from_bits);
+        let operands = [f16::ONE, f16::NEG_ZERO, f16::INFINITY, f16::from_bits(0xFE01)];
+        for &nan in &nans {
+            for &other in &operands {
+                check(nan + other);
+                check(other - nan);
+                check(nan * other);
+                check(other / nan);
+                check(nan % other);
+                check([other, nan].iter().sum());
+            }
+        }
+        check(f16::INFINITY - f16::INFINITY);
+        check(f16::ZERO * f16::NEG_INFINITY);
+        check(f16::ZERO / f16::ZERO);
+        check(f16::ONE % f16::ZERO);
+        check(nans.iter().product());
+        check(f16::from_f32(f32::from_bits(0xFFC0_0001)));
+        check(f16::from_f64(f64::from_bits(0x7FF0_0000_0000_0001)));
+        check(f16::from_f32_const(f32::from_bits(0xFFFF_FFFF)));
+        check(f16::from_bf16_lossy(bf16::from_bits(0xFF81)));
+
+        let mut wide = [0f32; 6];
+        nans.convert_to_f32_slice(&mut wide);
+        let mut narrow = [f16::ZERO; 6];
+        narrow.convert_from_f32_slice(&wide);
+        for i in 0..nans.len() {
+            let (bits, wide_bits) = (nans[i].to_bits(), wide[i].to_bits());
+            if cfg!(feature = "canonical-nan") {
+                assert_eq!(wide_bits, f32::NAN.to_bits());
+                assert_eq!(nans[i].to_f32_const().to_bits(), f32::NAN.to_bits());
+                assert_eq!(nans[i].to_f64().to_bits(), f64::NAN.to_bits());
+                assert_eq!(narrow[i].to_bits(), f16::NAN.to_bits());
+            } else {
+                // By default, conversions keep the sign and payload, quieted.
+                let sign = (bits as u32 & 0x8000) << 16;
+                assert_eq!(wide_bits, sign | 0x7FC0_0000 | ((bits as u32 & 0x01FF) << 13));
+                assert_eq!(nans[i].to_f32_const().to_bits(), wide_bits);
+                assert_eq!(narrow[i].to_bits(), bits | 0x0200);
+            }
+            // Methods on the bits keep the payload either way.
+            assert_eq!((-nans[i]).to_bits(), bits ^ 0x8000);
+            assert_eq!(nans[i].abs().to_bits(), bits & 0x7FFF);
+            assert_eq!(f16::from_bits(bits).to_bits(), bits);
+        }
 
-        // from the round-to-even section of the test case
-        let x: f64 = unsafe { mem::transmute(0x3f0ffbfffffffffcu64) };
-        let bits = f16::from_f64(x).to_bits();
-        let const_bits = f16::from_f64_const(x).to_bits();
-        let inst_bits = f16::from_f64_instrinsic(x).to_bits();
-        assert_eq!(const_bits, bits);
-        assert!(inst_bits.abs_diff(bits) <= max_diff);
-
-        // from the double rounding section of the test case
-        // comment from the cpython test case: should be 2047, if double-rounded
-        // 64>32>16, becomes 2048
-        let x: f64 = unsafe { mem::transmute(0x409ffdffffff0000u64) };
-        let bits = f16::from_f64(x).to_bits();
-        let const_bits = f16::from_f64_const(x).to_bits();
-        let inst_bits = f16::from_f64_instrinsic(x).to_bits();
-        assert_eq!(const_bits, bits);
-        assert!(inst_bits.abs_diff(bits) <= max_diff);
+        let sum = bf16::from_bits(0xFF81) + bf16::ONE;
+        assert!(sum.is_nan());
+        if cfg!(feature = "canonical-nan") {
+            assert_eq!(sum.to_bits(), bf16::NAN.to_bits());
+            assert_eq!(bf16::from_bits(0xFFC1).to_f32().to_bits(), f32::NAN.to_bits());
+        } else {
+            assert_eq!(bf16::from_bits(0xFFC1).to_f32().to_bits(), 0xFFC1_0000);
+        }
     }
 
     #[test]
-    fn from_f32_lossless() {
-        let from_f32 = |v: f32| f16::from_f32_lossless(v);
-        let roundtrip = |v: f32, expected: Option<f16>| {
-            let half = from_f32(v);
-            assert_eq!(half, expected);
-            if !expected.is_none() {
-                let as_f32 = expected.unwrap().to_f32_const();
-                assert_eq!(v, as_f32);
+    fn test_nan_payload() {
+        assert_eq!(f16::nan_with_payload(0x200), None);
+        assert_eq!(f16::nan_with_payload(u16::MAX), None);
+        assert_eq!(f16::signaling_nan_with_payload(0), None);
+        assert_eq!(f16::signaling_nan_with_payload(0x200), None);
+        assert_eq!(f16::ONE.nan_payload(), None);
+        assert_eq!(f16::NEG_INFINITY.nan_payload(), None);
+        assert_eq!((-f16::nan_with_payload(7).unwrap()).nan_payload(), Some(7));
+
+        for payload in 0..=0x1FF {
+            let quiet = f16::nan_with_payload(payload).unwrap();
+            assert!(quiet.is_nan_quiet());
+            assert_eq!(f16::from_bits(quiet.to_bits()).nan_payload(), Some(payload));
+            let signaling = f16::signaling_nan_with_payload(payload);
+            assert_eq!(signaling.is_some(), payload != 0);
+            if let Some(signaling) = signaling {
+                assert!(signaling.is_nan_signaling());
+                assert_eq!(signaling.quiet().to_bits(), quiet.to_bits());
+                assert_eq!(f16::from_bits(signaling.to_bits()).nan_payload(), Some(payload));
             }
-        };
 
-        assert_eq!(from_f32(f32::NAN).map(f16::is_nan), Some(true));
-        roundtrip(f32::INFINITY, Some(f16::INFINITY));
-        roundtrip(f32::NEG_INFINITY, Some(f16::NEG_INFINITY));
-        roundtrip(f32::from_bits(0b0_00000000_00000000000000000000000), Some(f16(0)));
-        roundtrip(f32::from_bits(0b1_00000000_00000000000000000000000), Some(f16(f16::SIGN_MASK)));
-        roundtrip(f32::from_bits(1), None);
+            // Widening and narrowing keep the payload, and quiet the value.
+            let expected = if cfg!(feature = "canonical-nan") {
+                Some(0)
+            } else {
+                Some(payload)
+            };
+            for nan in [Some(quiet), signaling, Some(-quiet)].iter().flatten() {
+                assert_eq!(f16::from_f32(nan.to_f32()).nan_payload(), expected);
+                assert!(f16::from_f32(nan.to_f32()).is_nan_quiet());
+                assert_eq!(f16::from_f64(nan.to_f64()).nan_payload(), expected);
+                assert_eq!(f16::from_f32_const(nan.to_f32_const()).nan_payload(), expected);
+            }
 
-        // special truncation with denormals, etc.
-        roundtrip(f32::from_bits(0b0_01100111_00000000000000000000000), Some(f16(1)));
-        roundtrip(f32::from_bits(0b0_01101000_00000000000000000000000), Some(f16(2)));
-        roundtrip(f32::from_bits(0b0_01101000_10000000000000000000000), Some(f16(3)));
-        roundtrip(f32::from_bits(0b0_01100111_10000000000000000000000), None);
-        roundtrip(f32::from_bits(0b0_01101000_11000000000000000000000), None);
-        // ~2.2888184e-5 and has bits until 16 to the end, so truncated 2. but this is
-        // denormal as f16
-        roundtrip(f32::from_bits(0b0_01101111_00000000000000000000000), Some(f16(0x100)));
-        roundtrip(f32::from_bits(0b0_01101111_10000000000000000000000), Some(f16(0x180)));
-        roundtrip(f32::from_bits(0b0_01101111_11000000000000000000000), Some(f16(0x1c0)));
-        roundtrip(f32::from_bits(0b0_01101111_11000001000000000000000), Some(f16(0x1c1)));
-        roundtrip(f32::from_bits(0b0_01101111_11000001100000000000000), None);
-        //2.0f32
-        roundtrip(f32::from_bits(0b0_10000000_00000000000000000000000), Some(f16(0x4000)));
-        roundtrip(f32::from_bits(0b0_10000000_10000000000000000000000), Some(f16(0x4200)));
-        roundtrip(f32::from_bits(0b0_10000000_10000000010000000000000), Some(f16(0x4201)));
-        roundtrip(f32::from_bits(0b0_10000000_10000000011000000000000), None);
-        // check overflow
-        roundtrip(f32::from_bits(0b0_10001111_00000000000000000000000), None);
-        roundtrip(f32::from_bits(0b0_10001110_00000000000000000000000), Some(f16(0x7800)));
+            // Arithmetic with one NaN operand keeps its payload, as the
+            // hardware does for `f32`.
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+            {
+                assert_eq!((quiet + f16::ONE).nan_payload(), expected);
+                assert_eq!((f16::from_f32(2.) * quiet).nan_payload(), expected);
+                assert_eq!((quiet - f16::INFINITY).nan_payload(), expected);
+            }
+        }
     }
 
     #[test]
-    fn from_f64_lossless() {
-        let from_f64 = |v: f64| f16::from_f64_lossless(v);
-        let roundtrip = |v: f64, expected: Option<f16>| {
-            let half = from_f64(v);
-            assert_eq!(half, expected);
-            if !expected.is_none() {
-                let as_f64 = expected.unwrap().to_f64_const();
-                assert_eq!(v, as_f64);
+    #[cfg(feature = "std")]
+    #[cfg_attr(miri, ignore)]
+    fn test_fma_f32_accum() {
+        // The product is exact, so adding it rounds once, as a fused
+        // multiply-add does.
+        let accums = [0f32, -1.0, 1e-10, 3.0e38, f32::from_bits(0x3380_0001)];
+        for a in (0..=u16::MAX).step_by(97).map(f16::from_bits) {
+            for b in (0..=u16::MAX).step_by(89).map(f16::from_bits) {
+                for &c in &accums {
+                    let expected = a.to_f32().mul_add(b.to_f32(), c);
+                    let actual = a.fma_f32_accum(b, c);
+                    assert!(
+                        actual.to_bits() == expected.to_bits()
+                            || (actual.is_nan() && expected.is_nan()),
+                        "{:?} {:?} {}",
+                        a,
+                        b,
+                        c
+                    );
+                }
             }
-        };
-
-        assert_eq!(from_f64(f64::NAN).map(f16::is_nan), Some(true));
-        roundtrip(f64::INFINITY, Some(f16::INFINITY));
-        roundtrip(f64::NEG_INFINITY, Some(f16::NEG_INFINITY));
-        roundtrip(
-            f64::from_bits(0b0_00000000000_0000000000000000000000000000000000000000000000000000),
-            Some(f16(0)),
-        );
-        roundtrip(
-            f64::from_bits(0b1_00000000000_0000000000000000000000000000000000000000000000000000),
-            Some(f16(f16::SIGN_MASK)),
-        );
-        roundtrip(
-            f64::from_bits(0b0_01110001010_1010100101011010010110110111111110000111101000001111),
-            None,
-        );
-        // check overflow to inf
-        roundtrip(
-            f64::from_bits(0b0_10000001110_1000000000000000000000000000000000000000000000000000),
-            Some(f16(0x7a00)),
-        );
-        roundtrip(
-            f64::from_bits(0b0_10000001111_1000000000000000000000000000000000000000000000000000),
-            None,
-        );
-        // check denormals and truncation
-        roundtrip(
-            f64::from_bits(0b0_01111100111_0000000000000000000000000000000000000000000000000000),
-            Some(f16(1)),
-        );
-        roundtrip(
-            f64::from_bits(0b0_01111100111_1000000000000000000000000000000000000000000000000000),
-            None,
-        );
-        roundtrip(
-            f64::from_bits(0b0_01111101000_0000000000000000000000000000000000000000000000000000),
-            Some(f16(2)),
-        );
-        roundtrip(
-            f64::from_bits(0b0_01111101000_1000000000000000000000000000000000000000000000000000),
-            Some(f16(3)),
-        );
-        roundtrip(
-            f64::from_bits(0b0_01111101000_1100000000000000000000000000000000000000000000000000),
-            None,
-        );
-        // check basic, normal and positive numbers
-        roundtrip(
-            f64::from_bits(0b0_01111111000_0000000000000000000000000000000000000000000000000000),
-            Some(f16(0x2000)),
-        );
-        roundtrip(
-            f64::from_bits(0b0_01111111000_1000000000000000000000000000000000000000000000000000),
-            Some(f16(0x2200)),
-        );
-        roundtrip(
-            f64::from_bits(0b0_01111111000_1110000000000000000000000000000000000000000000000000),
-            Some(f16(0x2380)),
-        );
-        roundtrip(
-            f64::from_bits(0b0_01111111000_1110000001000000000000000000000000000000000000000000),
-            Some(f16(0x2381)),
-        );
-        roundtrip(
-            f64::from_bits(0b0_01111111000_1110000001100000000000000000000000000000000000000000),
-            None,
-        );
+        }
     }
 
     #[test]
@@ -2172,4 +7916,28 @@ mod test {
         let b = f16::NAN;
         assert!(a.min(b).is_nan());
     }
+
+    #[test]
+    fn test_min3_max3() {
+        const MIN: f16 = f16::min3(f16::ONE, f16::NEG_ONE, f16::ZERO);
+        const MAX: f16 = f16::max3(f16::ONE, f16::NEG_ONE, f16::ZERO);
+        assert_eq!(MIN, f16::NEG_ONE);
+        assert_eq!(MAX, f16::ONE);
+
+        let values =
+            [f16::NEG_INFINITY, f16::from_f32(-2.), f16::ZERO, f16::from_f32(42.), f16::NAN];
+        for &a in &values {
+            for &b in &values {
+                for &c in &values {
+                    let min = f16::min3(a, b, c).to_f32();
+                    let max = f16::max3(a, b, c).to_f32();
+                    // The same as for `f32`, which also ignores NaN.
+                    let expected = a.to_f32().min(b.to_f32()).min(c.to_f32());
+                    assert!(min == expected || (min.is_nan() && expected.is_nan()));
+                    let expected = a.to_f32().max(b.to_f32()).max(c.to_f32());
+                    assert!(max == expected || (max.is_nan() && expected.is_nan()));
+                }
+            }
+        }
+    }
 }
@@ -1,7 +1,10 @@
 #![allow(dead_code, unused_imports)]
-use core::mem;
+use core::{fmt, mem};
 
+use crate::bfloat::convert as bf16_convert;
 use crate::leading_zeros::leading_zeros_u16;
+use crate::minifloat;
+use crate::rounding::RoundingMode;
 
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod x86;
@@ -84,6 +87,164 @@ macro_rules! convert_fn {
     };
 }
 
+macro_rules! swap_bytes_fn {
+    (
+        if x86_feature("avx2") { $avx2:expr }else if aarch64_feature("neon") { $neon:expr }else { $fallback:expr }
+    ) => {
+        cfg_if::cfg_if! {
+            // Use intrinsics directly when a compile target or using no_std
+            if #[cfg(all(
+                has_x86_intrinsics,
+                any(target_arch = "x86", target_arch = "x86_64"),
+                target_feature = "avx2"
+            ))] {
+                $avx2
+            }
+            else if #[cfg(all(
+                has_aarch64_intrinsics,
+                target_arch = "aarch64",
+                target_feature = "neon"
+            ))] {
+                $neon
+            }
+
+            // Use CPU feature detection if using std
+            else if #[cfg(all(
+                has_x86_intrinsics,
+                feature = "std",
+                any(target_arch = "x86", target_arch = "x86_64")
+            ))] {
+                use std::arch::is_x86_feature_detected;
+                if is_x86_feature_detected!("avx2") {
+                    $avx2
+                } else {
+                    $fallback
+                }
+            }
+
+            // Fallback to software
+            else {
+                $fallback
+            }
+        }
+    };
+}
+
+macro_rules! mul_add_fn {
+    (
+        if x86_feature("fma") { $fma:expr }else if aarch64_feature("fhm") { $fhm:expr }else { $fallback:expr }
+    ) => {
+        cfg_if::cfg_if! {
+            // Use intrinsics directly when a compile target or using no_std
+            if #[cfg(all(
+                has_x86_intrinsics,
+                any(target_arch = "x86", target_arch = "x86_64"),
+                target_feature = "fma"
+            ))] {
+                $fma
+            }
+            else if #[cfg(all(
+                has_aarch64_intrinsics,
+                target_arch = "aarch64",
+                target_feature = "fhm"
+            ))] {
+                $fhm
+            }
+
+            // Use CPU feature detection if using std
+            else if #[cfg(all(
+                has_x86_intrinsics,
+                feature = "std",
+                any(target_arch = "x86", target_arch = "x86_64")
+            ))] {
+                use std::arch::is_x86_feature_detected;
+                if is_x86_feature_detected!("avx") && is_x86_feature_detected!("fma") {
+                    $fma
+                } else {
+                    $fallback
+                }
+            }
+            else if #[cfg(all(
+                has_aarch64_intrinsics,
+                feature = "std",
+                target_arch = "aarch64",
+            ))] {
+                use std::arch::is_aarch64_feature_detected;
+                if is_aarch64_feature_detected!("fhm") {
+                    $fhm
+                } else {
+                    $fallback
+                }
+            }
+
+            // Fallback to software
+            else {
+                $fallback
+            }
+        }
+    };
+}
+
+macro_rules! bf16_convert_fn {
+    (if aarch64_feature("bf16") { $bf16:expr }else { $fallback:expr }) => {
+        cfg_if::cfg_if! {
+            // Use intrinsics directly when a compile target or using no_std
+            if #[cfg(all(
+                has_aarch64_intrinsics,
+                target_arch = "aarch64",
+                target_feature = "bf16"
+            ))] {
+                $bf16
+            }
+
+            // Use CPU feature detection if using std
+            else if #[cfg(all(
+                has_aarch64_intrinsics,
+                feature = "std",
+                target_arch = "aarch64",
+            ))] {
+                use std::arch::is_aarch64_feature_detected;
+                if is_aarch64_feature_detected!("bf16") {
+                    $bf16
+                } else {
+                    $fallback
+                }
+            }
+
+            // Fallback to software
+            else {
+                $fallback
+            }
+        }
+    };
+}
+
+#[inline]
+pub(crate) fn hardware_f16_available() -> bool {
+    convert_fn! {
+        if x86_feature("f16c") {
+            true
+        } else if aarch64_feature("fp16") {
+            true
+        } else if loongarch64_feature("lsx") {
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[inline]
+pub(crate) fn hardware_bf16_available() -> bool {
+    bf16_convert_fn! {
+        if aarch64_feature("bf16") {
+            true
+        } else {
+            false
+        }
+    }
+}
+
 #[inline]
 pub(crate) fn f32_to_f16(f: f32) -> u16 {
     convert_fn! {
@@ -213,14 +374,14 @@ pub(crate) fn f32x8_to_f16x8(f: &[f32; 8]) -> [u16; 8] {
             {
                 let mut result = [0u16; 8];
                 convert_chunked_slice_4(f.as_slice(), result.as_mut_slice(),
-                    aarch64::f32x4_to_f16x4_fp16);
+                    aarch64::f32x4_to_f16x4_fp16, f32_to_f16_fallback);
                 result
             }
         } else if loongarch64_feature("lsx") {
             {
                 let mut result = [0u16; 8];
                 convert_chunked_slice_4(f.as_slice(), result.as_mut_slice(),
-                    loongarch64::f32x4_to_f16x4_lsx);
+                    loongarch64::f32x4_to_f16x4_lsx, f32_to_f16_fallback);
                 result
             }
         } else {
@@ -238,14 +399,14 @@ pub(crate) fn f16x8_to_f32x8(i: &[u16; 8]) -> [f32; 8] {
             {
                 let mut result = [0f32; 8];
                 convert_chunked_slice_4(i.as_slice(), result.as_mut_slice(),
-                    aarch64::f16x4_to_f32x4_fp16);
+                    aarch64::f16x4_to_f32x4_fp16, f16_to_f32_fallback);
                 result
             }
         } else if loongarch64_feature("lsx") {
             {
                 let mut result = [0f32; 8];
                 convert_chunked_slice_4(i.as_slice(), result.as_mut_slice(),
-                    loongarch64::f16x4_to_f32x4_lsx);
+                    loongarch64::f16x4_to_f32x4_lsx, f16_to_f32_fallback);
                 result
             }
         } else {
@@ -263,14 +424,14 @@ pub(crate) fn f64x8_to_f16x8(f: &[f64; 8]) -> [u16; 8] {
             {
                 let mut result = [0u16; 8];
                 convert_chunked_slice_4(f.as_slice(), result.as_mut_slice(),
-                    aarch64::f64x4_to_f16x4_fp16);
+                    aarch64::f64x4_to_f16x4_fp16, f64_to_f16_fallback);
                 result
             }
         } else if loongarch64_feature("lsx") {
             {
                 let mut result = [0u16; 8];
                 convert_chunked_slice_4(f.as_slice(), result.as_mut_slice(),
-                    loongarch64::f64x4_to_f16x4_lsx);
+                    loongarch64::f64x4_to_f16x4_lsx, f64_to_f16_fallback);
                 result
             }
         } else {
@@ -288,14 +449,14 @@ pub(crate) fn f16x8_to_f64x8(i: &[u16; 8]) -> [f64; 8] {
             {
                 let mut result = [0f64; 8];
                 convert_chunked_slice_4(i.as_slice(), result.as_mut_slice(),
-                    aarch64::f16x4_to_f64x4_fp16);
+                    aarch64::f16x4_to_f64x4_fp16, f16_to_f64_fallback);
                 result
             }
         } else if loongarch64_feature("lsx") {
             {
                 let mut result = [0f64; 8];
                 convert_chunked_slice_4(i.as_slice(), result.as_mut_slice(),
-                    loongarch64::f16x4_to_f64x4_lsx);
+                    loongarch64::f16x4_to_f64x4_lsx, f16_to_f64_fallback);
                 result
             }
         } else {
@@ -309,15 +470,37 @@ pub(crate) fn f32_to_f16_slice(src: &[f32], dst: &mut [u16]) {
     convert_fn! {
         if x86_feature("f16c") {
             convert_chunked_slice_8(src, dst, x86::f32x8_to_f16x8_x86_f16c,
-                x86::f32x4_to_f16x4_x86_f16c)
+                x86::f32x4_to_f16x4_x86_f16c, f32_to_f16_fallback)
         } else if aarch64_feature("fp16") {
-            convert_chunked_slice_4(src, dst, aarch64::f32x4_to_f16x4_fp16)
+            convert_chunked_slice_4(src, dst, aarch64::f32x4_to_f16x4_fp16, f32_to_f16_fallback)
         } else if loongarch64_feature("lsx") {
-            convert_chunked_slice_4(src, dst, loongarch64::f32x4_to_f16x4_lsx)
+            convert_chunked_slice_4(src, dst, loongarch64::f32x4_to_f16x4_lsx, f32_to_f16_fallback)
         } else {
             slice_fallback(src, dst, f32_to_f16_fallback)
         }
     }
+    canonical_nan_slice(dst, canonical_nan);
+}
+
+/// Converts values that round to normal [`struct@f16`] values, with the
+/// vectorized conversions when they are available, and otherwise with
+/// [`f32_to_f16_normal`], which skips the handling of special values.
+///
+/// [`struct@f16`]: crate::f16
+#[inline]
+pub(crate) fn f32_to_f16_slice_normal(src: &[f32], dst: &mut [u16]) {
+    convert_fn! {
+        if x86_feature("f16c") {
+            convert_chunked_slice_8(src, dst, x86::f32x8_to_f16x8_x86_f16c,
+                x86::f32x4_to_f16x4_x86_f16c, f32_to_f16_normal)
+        } else if aarch64_feature("fp16") {
+            convert_chunked_slice_4(src, dst, aarch64::f32x4_to_f16x4_fp16, f32_to_f16_normal)
+        } else if loongarch64_feature("lsx") {
+            convert_chunked_slice_4(src, dst, loongarch64::f32x4_to_f16x4_lsx, f32_to_f16_normal)
+        } else {
+            slice_fallback(src, dst, f32_to_f16_normal)
+        }
+    }
 }
 
 #[inline]
@@ -325,15 +508,16 @@ pub(crate) fn f16_to_f32_slice(src: &[u16], dst: &mut [f32]) {
     convert_fn! {
         if x86_feature("f16c") {
             convert_chunked_slice_8(src, dst, x86::f16x8_to_f32x8_x86_f16c,
-                x86::f16x4_to_f32x4_x86_f16c)
+                x86::f16x4_to_f32x4_x86_f16c, f16_to_f32_fallback)
         } else if aarch64_feature("fp16") {
-            convert_chunked_slice_4(src, dst, aarch64::f16x4_to_f32x4_fp16)
+            convert_chunked_slice_4(src, dst, aarch64::f16x4_to_f32x4_fp16, f16_to_f32_fallback)
         } else if loongarch64_feature("lsx") {
-            convert_chunked_slice_4(src, dst, loongarch64::f16x4_to_f32x4_lsx)
+            convert_chunked_slice_4(src, dst, loongarch64::f16x4_to_f32x4_lsx, f16_to_f32_fallback)
         } else {
             slice_fallback(src, dst, f16_to_f32_fallback)
         }
     }
+    canonical_nan_slice(dst, canonical_nan_f32);
 }
 
 #[inline]
@@ -341,15 +525,16 @@ pub(crate) fn f64_to_f16_slice(src: &[f64], dst: &mut [u16]) {
     convert_fn! {
         if x86_feature("f16c") {
             convert_chunked_slice_8(src, dst, x86::f64x8_to_f16x8_x86_f16c,
-                x86::f64x4_to_f16x4_x86_f16c)
+                x86::f64x4_to_f16x4_x86_f16c, f64_to_f16_fallback)
         } else if aarch64_feature("fp16") {
-            convert_chunked_slice_4(src, dst, aarch64::f64x4_to_f16x4_fp16)
+            convert_chunked_slice_4(src, dst, aarch64::f64x4_to_f16x4_fp16, f64_to_f16_fallback)
         } else if loongarch64_feature("lsx") {
-            convert_chunked_slice_4(src, dst, loongarch64::f64x4_to_f16x4_lsx)
+            convert_chunked_slice_4(src, dst, loongarch64::f64x4_to_f16x4_lsx, f64_to_f16_fallback)
         } else {
             slice_fallback(src, dst, f64_to_f16_fallback)
         }
     }
+    canonical_nan_slice(dst, canonical_nan);
 }
 
 #[inline]
@@ -357,15 +542,16 @@ pub(crate) fn f16_to_f64_slice(src: &[u16], dst: &mut [f64]) {
     convert_fn! {
         if x86_feature("f16c") {
             convert_chunked_slice_8(src, dst, x86::f16x8_to_f64x8_x86_f16c,
-                x86::f16x4_to_f64x4_x86_f16c)
+                x86::f16x4_to_f64x4_x86_f16c, f16_to_f64_fallback)
         } else if aarch64_feature("fp16") {
-            convert_chunked_slice_4(src, dst, aarch64::f16x4_to_f64x4_fp16)
+            convert_chunked_slice_4(src, dst, aarch64::f16x4_to_f64x4_fp16, f16_to_f64_fallback)
         } else if loongarch64_feature("lsx") {
-            convert_chunked_slice_4(src, dst, loongarch64::f16x4_to_f64x4_lsx)
+            convert_chunked_slice_4(src, dst, loongarch64::f16x4_to_f64x4_lsx, f16_to_f64_fallback)
         } else {
             slice_fallback(src, dst, f16_to_f64_fallback)
         }
     }
+    canonical_nan_slice(dst, canonical_nan_f64);
 }
 
 macro_rules! math_fn {
@@ -447,6 +633,54 @@ pub(crate) fn divide_f16(a: u16, b: u16) -> u16 {
     }
 }
 
+#[inline]
+pub(crate) fn recip_estimate_f16(a: u16) -> u16 {
+    math_fn! {
+        if aarch64_feature("fp16") {
+            unsafe { aarch64::recip_estimate_f16_fp16(a) }
+        } else {
+            recip_f16_fallback(a)
+        }
+    }
+}
+
+#[inline]
+pub(crate) fn recip_refined_f16(a: u16) -> u16 {
+    math_fn! {
+        if aarch64_feature("fp16") {
+            // One Newton-Raphson step, `x * (2 - a * x)`.
+            unsafe {
+                let x = aarch64::recip_estimate_f16_fp16(a);
+                aarch64::multiply_f16_fp16(x, aarch64::recip_step_f16_fp16(a, x))
+            }
+        } else {
+            recip_f16_fallback(a)
+        }
+    }
+}
+
+#[inline]
+#[cfg(feature = "std")]
+pub(crate) fn rsqrt_f16(a: u16) -> u16 {
+    math_fn! {
+        if aarch64_feature("fp16") {
+            // One Newton-Raphson step, `x * (3 - a * x * x) / 2`. Zero, infinite
+            // and NaN estimates are already exact.
+            unsafe {
+                let x = aarch64::rsqrt_estimate_f16_fp16(a);
+                if x & 0x7FFF == 0 || x & 0x7C00 == 0x7C00 {
+                    x
+                } else {
+                    let ax = aarch64::multiply_f16_fp16(a, x);
+                    aarch64::multiply_f16_fp16(x, aarch64::rsqrt_step_f16_fp16(ax, x))
+                }
+            }
+        } else {
+            rsqrt_f16_fallback(a)
+        }
+    }
+}
+
 #[inline]
 pub(crate) fn remainder_f16(a: u16, b: u16) -> u16 {
     remainder_f16_fallback(a, b)
@@ -456,7 +690,7 @@ pub(crate) fn remainder_f16(a: u16, b: u16) -> u16 {
 pub(crate) fn product_f16<I: Iterator<Item = u16>>(iter: I) -> u16 {
     math_fn! {
         if aarch64_feature("fp16") {
-            iter.fold(0, |acc, x| unsafe { aarch64::multiply_f16_fp16(acc, x) })
+            iter.fold(0x3C00, |acc, x| unsafe { aarch64::multiply_f16_fp16(acc, x) })
         } else {
             product_f16_fallback(iter)
         }
@@ -474,13 +708,138 @@ pub(crate) fn sum_f16<I: Iterator<Item = u16>>(iter: I) -> u16 {
     }
 }
 
+#[inline]
+pub(crate) fn product_f16_slice(slice: &[u16]) -> u16 {
+    math_fn! {
+        if aarch64_feature("fp16") {
+            {
+                let product = product_f16(slice.iter().copied());
+                check_reduction(slice, product, Reduction::Product);
+                product
+            }
+        } else {
+            product_f16_slice_fallback(slice)
+        }
+    }
+}
+
+#[inline]
+pub(crate) fn sum_f16_slice(slice: &[u16]) -> u16 {
+    math_fn! {
+        if aarch64_feature("fp16") {
+            {
+                let sum = sum_f16(slice.iter().copied());
+                check_reduction(slice, sum, Reduction::Sum);
+                sum
+            }
+        } else {
+            sum_f16_slice_fallback(slice)
+        }
+    }
+}
+
+/// A reduction of a slice of [`struct@f16`] values with native
+/// half-precision arithmetic.
+///
+/// [`struct@f16`]: crate::f16
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Reduction {
+    Sum,
+    Product,
+}
+
+/// An element-wise operation on slices of [`struct@f16`] or [`struct@bf16`]
+/// values.
+///
+/// [`struct@f16`]: crate::f16
+/// [`struct@bf16`]: crate::bf16
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ZipOp {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+    /// `a * b + c`, where `c` is the destination.
+    MulAdd,
+}
+
+impl ZipOp {
+    /// Applies the operation to values widened to `f32`.
+    ///
+    /// `f32` has more than twice the precision of either type, so rounding
+    /// the result once more is the same as rounding the exact result, except
+    /// for the sum of [`ZipOp::MulAdd`]. The product is exact, so that sum
+    /// is only rounded twice.
+    #[inline(always)]
+    pub(crate) fn apply_f32(self, a: f32, b: f32, c: f32) -> f32 {
+        match self {
+            ZipOp::Add => a + b,
+            ZipOp::Subtract => a - b,
+            ZipOp::Multiply => a * b,
+            ZipOp::Divide => a / b,
+            ZipOp::Remainder => a % b,
+            ZipOp::MulAdd => a * b + c,
+        }
+    }
+
+    /// Applies the operation to the bits of [`struct@f16`] values, the same
+    /// as the operators, which is the scalar reference of the kernels.
+    ///
+    /// [`struct@f16`]: crate::f16
+    #[inline]
+    fn apply(self, a: u16, b: u16, c: u16) -> u16 {
+        match self {
+            ZipOp::Add => add_f16(a, b),
+            ZipOp::Subtract => subtract_f16(a, b),
+            ZipOp::Multiply => multiply_f16(a, b),
+            ZipOp::Divide => divide_f16(a, b),
+            ZipOp::Remainder => remainder_f16(a, b),
+            ZipOp::MulAdd => f32_to_f16(f16_to_f32(a) * f16_to_f32(b) + f16_to_f32(c)),
+        }
+    }
+}
+
+/// Applies `op` to each element of `a` and `b`, storing the results in `c`,
+/// which is also the addend of [`ZipOp::MulAdd`].
+#[inline]
+pub(crate) fn zip_f16_slice(a: &[u16], b: &[u16], c: &mut [u16], op: ZipOp) {
+    convert_fn! {
+        if x86_feature("f16c") {
+            zip_chunked_slice_8(a, b, c, op, x86::zip_f16x8_x86_f16c)
+        } else if aarch64_feature("fp16") {
+            zip_chunked_slice_8(a, b, c, op, aarch64::zip_f16x8_fp16)
+        } else if loongarch64_feature("lsx") {
+            zip_chunked_slice_8(a, b, c, op, zip_f16x8_widened)
+        } else {
+            zip_f16_slice_fallback(a, b, c, op)
+        }
+    }
+    canonical_nan_slice(c, canonical_nan);
+}
+
+/// Applies `op` to x8 arrays widened to `f32` with the vectorized
+/// conversions.
+#[inline]
+fn zip_f16x8_widened(a: &[u16; 8], b: &[u16; 8], c: &[u16; 8], op: ZipOp) -> [u16; 8] {
+    let (x, y, mut z) = (f16x8_to_f32x8(a), f16x8_to_f32x8(b), f16x8_to_f32x8(c));
+    for ((z, &x), &y) in z.iter_mut().zip(x.iter()).zip(y.iter()) {
+        *z = op.apply_f32(x, y, *z);
+    }
+    f32x8_to_f16x8(&z)
+}
+
 /// Chunks sliced into x8 or x4 arrays
+///
+/// `scalar` is the scalar reference, which the results of each chunk are
+/// checked against with the `debug-vector-checks` feature.
 #[inline]
-fn convert_chunked_slice_8<S: Copy + Default, D: Copy>(
+fn convert_chunked_slice_8<S: Copy + Default + fmt::Debug, D: SameBits>(
     src: &[S],
     dst: &mut [D],
     fn8: unsafe fn(&[S; 8]) -> [D; 8],
     fn4: unsafe fn(&[S; 4]) -> [D; 4],
+    scalar: fn(S) -> D,
 ) {
     assert_eq!(src.len(), dst.len());
 
@@ -489,74 +848,610 @@ fn convert_chunked_slice_8<S: Copy + Default, D: Copy>(
     let src_chunks = src.chunks_exact(8);
     let mut dst_chunks = dst.chunks_exact_mut(8);
     let src_remainder = src_chunks.remainder();
-    for (s, d) in src_chunks.zip(&mut dst_chunks) {
+    for (i, (s, d)) in src_chunks.zip(&mut dst_chunks).enumerate() {
         let chunk: &[S; 8] = s.try_into().unwrap();
         d.copy_from_slice(unsafe { &fn8(chunk) });
+        check_chunk(i * 8, s, d, scalar);
     }
 
     // Process remainder
+    let offset = src.len() - src_remainder.len();
     if src_remainder.len() > 4 {
         let mut buf: [S; 8] = Default::default();
         buf[..src_remainder.len()].copy_from_slice(src_remainder);
         let vec = unsafe { fn8(&buf) };
         let dst_remainder = dst_chunks.into_remainder();
         dst_remainder.copy_from_slice(&vec[..dst_remainder.len()]);
+        check_chunk(offset, src_remainder, dst_remainder, scalar);
     } else if !src_remainder.is_empty() {
         let mut buf: [S; 4] = Default::default();
         buf[..src_remainder.len()].copy_from_slice(src_remainder);
         let vec = unsafe { fn4(&buf) };
         let dst_remainder = dst_chunks.into_remainder();
         dst_remainder.copy_from_slice(&vec[..dst_remainder.len()]);
+        check_chunk(offset, src_remainder, dst_remainder, scalar);
     }
 }
 
-/// Chunks sliced into x4 arrays
 #[inline]
-fn convert_chunked_slice_4<S: Copy + Default, D: Copy>(
-    src: &[S],
-    dst: &mut [D],
-    f: unsafe fn(&[S; 4]) -> [D; 4],
-) {
-    assert_eq!(src.len(), dst.len());
-
-    // TODO: Can be further optimized with array_chunks when it becomes stabilized
-
-    let src_chunks = src.chunks_exact(4);
-    let mut dst_chunks = dst.chunks_exact_mut(4);
-    let src_remainder = src_chunks.remainder();
-    for (s, d) in src_chunks.zip(&mut dst_chunks) {
-        let chunk: &[S; 4] = s.try_into().unwrap();
-        d.copy_from_slice(unsafe { &f(chunk) });
+pub(crate) fn f32_to_bf16_slice(src: &[f32], dst: &mut [u16]) {
+    bf16_convert_fn! {
+        if aarch64_feature("bf16") {
+            convert_chunked_slice_4(src, dst, aarch64::f32x4_to_bf16x4_bf16,
+                bf16_convert::f32_to_bf16)
+        } else {
+            slice_fallback(src, dst, bf16_convert::f32_to_bf16)
+        }
     }
+    canonical_nan_slice(dst, bf16_convert::canonical_nan);
+}
 
-    // Process remainder
-    if !src_remainder.is_empty() {
-        let mut buf: [S; 4] = Default::default();
-        buf[..src_remainder.len()].copy_from_slice(src_remainder);
-        let vec = unsafe { f(&buf) };
-        let dst_remainder = dst_chunks.into_remainder();
-        dst_remainder.copy_from_slice(&vec[..dst_remainder.len()]);
+/// Converts values that are not NaN to [`struct@bf16`], with the vectorized
+/// conversions when they are available, and otherwise with
+/// `f32_to_bf16_non_nan`, which skips the handling of NaN.
+///
+/// [`struct@bf16`]: crate::bf16
+#[inline]
+pub(crate) fn f32_to_bf16_slice_non_nan(src: &[f32], dst: &mut [u16]) {
+    bf16_convert_fn! {
+        if aarch64_feature("bf16") {
+            convert_chunked_slice_4(src, dst, aarch64::f32x4_to_bf16x4_bf16,
+                bf16_convert::f32_to_bf16_non_nan)
+        } else {
+            slice_fallback(src, dst, bf16_convert::f32_to_bf16_non_nan)
+        }
     }
 }
 
-/////////////// Fallbacks ////////////////
-
-// In the below functions, round to nearest, with ties to even.
-// Let us call the most significant bit that will be shifted out the round_bit.
-//
-// Round up if either
-//  a) Removed part > tie.
-//     (mantissa & round_bit) != 0 && (mantissa & (round_bit - 1)) != 0
-//  b) Removed part == tie, and retained part is odd.
-//     (mantissa & round_bit) != 0 && (mantissa & (2 * round_bit)) != 0
-// (If removed part == tie and retained part is even, do not round up.)
-// These two conditions can be combined into one:
-//     (mantissa & round_bit) != 0 && (mantissa & ((round_bit - 1) | (2 *
-// round_bit))) != 0 which can be simplified into
-//     (mantissa & round_bit) != 0 && (mantissa & (3 * round_bit - 1)) != 0
+#[inline]
+pub(crate) fn bf16_to_f32_slice(src: &[u16], dst: &mut [f32]) {
+    bf16_convert_fn! {
+        if aarch64_feature("bf16") {
+            convert_chunked_slice_4(src, dst, aarch64::bf16x4_to_f32x4_bf16,
+                bf16_convert::bf16_to_f32)
+        } else {
+            slice_fallback(src, dst, bf16_convert::bf16_to_f32)
+        }
+    }
+    canonical_nan_slice(dst, canonical_nan_f32);
+}
 
 #[inline]
-pub(crate) const fn f32_to_f16_fallback(value: f32) -> u16 {
+pub(crate) fn f16_to_bf16_slice(src: &[u16], dst: &mut [u16]) {
+    convert_fn! {
+        if x86_feature("f16c") {
+            convert_chunked_slice_8(src, dst, x86::f16x8_to_bf16x8_x86_f16c,
+                x86::f16x4_to_bf16x4_x86_f16c, f16_to_bf16_fallback)
+        } else if aarch64_feature("fp16") {
+            slice_fallback(src, dst, f16_to_bf16_fallback)
+        } else if loongarch64_feature("lsx") {
+            slice_fallback(src, dst, f16_to_bf16_fallback)
+        } else {
+            slice_fallback(src, dst, f16_to_bf16_fallback)
+        }
+    }
+    canonical_nan_slice(dst, bf16_convert::canonical_nan);
+}
+
+#[inline]
+pub(crate) fn bf16_to_f16_slice(src: &[u16], dst: &mut [u16]) {
+    convert_fn! {
+        if x86_feature("f16c") {
+            convert_chunked_slice_8(src, dst, x86::bf16x8_to_f16x8_x86_f16c,
+                x86::bf16x4_to_f16x4_x86_f16c, bf16_to_f16_fallback)
+        } else if aarch64_feature("fp16") {
+            slice_fallback(src, dst, bf16_to_f16_fallback)
+        } else if loongarch64_feature("lsx") {
+            slice_fallback(src, dst, bf16_to_f16_fallback)
+        } else {
+            slice_fallback(src, dst, bf16_to_f16_fallback)
+        }
+    }
+    canonical_nan_slice(dst, canonical_nan);
+}
+
+#[inline]
+const fn f16_to_bf16_fallback(i: u16) -> u16 {
+    minifloat::narrow(i as u64, 5, 10, 8, 7) as u16
+}
+
+#[inline]
+const fn bf16_to_f16_fallback(i: u16) -> u16 {
+    minifloat::narrow(i as u64, 8, 7, 5, 10) as u16
+}
+
+#[inline]
+pub(crate) fn u8_norm_to_f16_slice(src: &[u8], dst: &mut [u16]) {
+    convert_fn! {
+        if x86_feature("f16c") {
+            convert_chunked_slice_8(src, dst, x86::u8x8_norm_to_f16x8_x86_f16c,
+                x86::u8x4_norm_to_f16x4_x86_f16c, u8_norm_to_f16_fallback)
+        } else if aarch64_feature("fp16") {
+            slice_fallback(src, dst, u8_norm_to_f16)
+        } else if loongarch64_feature("lsx") {
+            slice_fallback(src, dst, u8_norm_to_f16)
+        } else {
+            slice_fallback(src, dst, u8_norm_to_f16)
+        }
+    }
+}
+
+#[inline]
+fn u8_norm_to_f16(v: u8) -> u16 {
+    f32_to_f16(v as f32 / 255.0)
+}
+
+#[inline]
+fn u8_norm_to_f16_fallback(v: u8) -> u16 {
+    f32_to_f16_fallback(v as f32 / 255.0)
+}
+
+#[inline]
+pub(crate) fn swap_bytes_slice(src: &[u16], dst: &mut [u16]) {
+    swap_bytes_fn! {
+        if x86_feature("avx2") {
+            swap_bytes_chunked(src, dst, x86::u16x16_swap_bytes_x86_avx2)
+        } else if aarch64_feature("neon") {
+            swap_bytes_chunked(src, dst, aarch64::u16x8_swap_bytes_neon)
+        } else {
+            slice_fallback(src, dst, u16::swap_bytes)
+        }
+    }
+}
+
+#[inline]
+pub(crate) fn swap_bytes_slice_inplace(slice: &mut [u16]) {
+    swap_bytes_fn! {
+        if x86_feature("avx2") {
+            swap_bytes_chunked_inplace(slice, x86::u16x16_swap_bytes_x86_avx2)
+        } else if aarch64_feature("neon") {
+            swap_bytes_chunked_inplace(slice, aarch64::u16x8_swap_bytes_neon)
+        } else {
+            slice.iter_mut().for_each(|x| *x = x.swap_bytes())
+        }
+    }
+}
+
+/// A kernel that computes `c[j] += a * b[j]` for a row of a matrix product.
+///
+/// Every `a` and `b` is exactly an `f16` value, so each product is exact in
+/// `f32`, and fused and unfused kernels round the sum identically.
+#[derive(Clone, Copy)]
+pub(crate) enum MulAddKernel {
+    /// Accumulates `f32` values, widened from `f16` beforehand.
+    Widened(unsafe fn(&mut [f32], f32, &[f32])),
+    /// Accumulates the bits of `f16` values, widened by the instruction.
+    Half(unsafe fn(&mut [f32], u16, &[u16])),
+}
+
+#[inline]
+pub(crate) fn mul_add_kernel() -> MulAddKernel {
+    mul_add_fn! {
+        if x86_feature("fma") {
+            MulAddKernel::Widened(x86::mul_add_row_x86_fma)
+        } else if aarch64_feature("fhm") {
+            MulAddKernel::Half(aarch64::mul_add_row_f16_fhm)
+        } else {
+            MulAddKernel::Widened(mul_add_row_fallback)
+        }
+    }
+}
+
+#[inline]
+pub(crate) fn mul_add_row_fallback(c: &mut [f32], a: f32, b: &[f32]) {
+    for (c, &b) in c.iter_mut().zip(b) {
+        *c += a * b;
+    }
+}
+
+/// Adds the squares of `values` to the lanes of `acc`, with `values[i]` added
+/// to `acc[i % 8]`.
+///
+/// Every value is exactly an `f16` value, so each square is exact in `f32`,
+/// and fused and unfused kernels round the sums identically.
+#[inline]
+pub(crate) fn sum_squares_f32(values: &[f32], acc: &mut [f32; 8]) {
+    mul_add_fn! {
+        if x86_feature("fma") {
+            unsafe { x86::sum_squares_x86_fma(values, acc) }
+        } else if aarch64_feature("fhm") {
+            sum_squares_fallback(values, acc)
+        } else {
+            sum_squares_fallback(values, acc)
+        }
+    }
+}
+
+#[inline]
+pub(crate) fn sum_squares_fallback(values: &[f32], acc: &mut [f32; 8]) {
+    let mut chunks = values.chunks_exact(8);
+    for chunk in &mut chunks {
+        for (acc, &x) in acc.iter_mut().zip(chunk) {
+            *acc += x * x;
+        }
+    }
+    for (acc, &x) in acc.iter_mut().zip(chunks.remainder()) {
+        *acc += x * x;
+    }
+}
+
+/// Computes `c[j] += a * b[j]` for a row with a [`MulAddKernel::Widened`]
+/// kernel, checked as with [`check_mul_add_row`].
+///
+/// # Safety
+///
+/// The CPU must support the kernel, as it does if it is from
+/// [`mul_add_kernel`].
+#[inline]
+pub(crate) unsafe fn mul_add_row_widened(
+    kernel: unsafe fn(&mut [f32], f32, &[f32]),
+    c: &mut [f32],
+    a: f32,
+    b: &[f32],
+) {
+    check_mul_add_row(c, a, b, |b| b, |c, b| unsafe { kernel(c, a, b) });
+}
+
+/// Computes `c[j] += a * b[j]` for a row with a [`MulAddKernel::Half`]
+/// kernel, checked as with [`check_mul_add_row`].
+///
+/// # Safety
+///
+/// The CPU must support the kernel, as it does if it is from
+/// [`mul_add_kernel`].
+#[inline]
+pub(crate) unsafe fn mul_add_row_half(
+    kernel: unsafe fn(&mut [f32], u16, &[u16]),
+    c: &mut [f32],
+    a: u16,
+    b: &[u16],
+) {
+    let wide = f16_to_f32_fallback(a);
+    check_mul_add_row(c, wide, b, f16_to_f32_fallback, |c, b| unsafe { kernel(c, a, b) });
+}
+
+/// Chunks sliced into arrays of `N` elements, swapping the bytes of the
+/// remainder individually.
+#[inline]
+fn swap_bytes_chunked<const N: usize>(
+    src: &[u16],
+    dst: &mut [u16],
+    f: unsafe fn(&[u16; N]) -> [u16; N],
+) {
+    assert_eq!(src.len(), dst.len());
+
+    let src_chunks = src.chunks_exact(N);
+    let mut dst_chunks = dst.chunks_exact_mut(N);
+    let src_remainder = src_chunks.remainder();
+    for (i, (s, d)) in src_chunks.zip(&mut dst_chunks).enumerate() {
+        let chunk: &[u16; N] = s.try_into().unwrap();
+        d.copy_from_slice(unsafe { &f(chunk) });
+        check_chunk(i * N, s, d, u16::swap_bytes);
+    }
+    slice_fallback(src_remainder, dst_chunks.into_remainder(), u16::swap_bytes);
+}
+
+/// Chunks sliced into arrays of `N` elements, swapping the bytes of the
+/// remainder individually.
+#[inline]
+fn swap_bytes_chunked_inplace<const N: usize>(
+    slice: &mut [u16],
+    f: unsafe fn(&[u16; N]) -> [u16; N],
+) {
+    let mut chunks = slice.chunks_exact_mut(N);
+    for (i, chunk) in (&mut chunks).enumerate() {
+        let swapped = unsafe { f((&*chunk).try_into().unwrap()) };
+        check_chunk(i * N, chunk, &swapped, u16::swap_bytes);
+        chunk.copy_from_slice(&swapped);
+    }
+    chunks.into_remainder().iter_mut().for_each(|x| *x = x.swap_bytes());
+}
+
+/// Chunks sliced into x4 arrays
+///
+/// `scalar` is the scalar reference, which the results of each chunk are
+/// checked against with the `debug-vector-checks` feature.
+#[inline]
+fn convert_chunked_slice_4<S: Copy + Default + fmt::Debug, D: SameBits>(
+    src: &[S],
+    dst: &mut [D],
+    f: unsafe fn(&[S; 4]) -> [D; 4],
+    scalar: fn(S) -> D,
+) {
+    assert_eq!(src.len(), dst.len());
+
+    // TODO: Can be further optimized with array_chunks when it becomes stabilized
+
+    let src_chunks = src.chunks_exact(4);
+    let mut dst_chunks = dst.chunks_exact_mut(4);
+    let src_remainder = src_chunks.remainder();
+    for (i, (s, d)) in src_chunks.zip(&mut dst_chunks).enumerate() {
+        let chunk: &[S; 4] = s.try_into().unwrap();
+        d.copy_from_slice(unsafe { &f(chunk) });
+        check_chunk(i * 4, s, d, scalar);
+    }
+
+    // Process remainder
+    if !src_remainder.is_empty() {
+        let mut buf: [S; 4] = Default::default();
+        buf[..src_remainder.len()].copy_from_slice(src_remainder);
+        let vec = unsafe { f(&buf) };
+        let dst_remainder = dst_chunks.into_remainder();
+        dst_remainder.copy_from_slice(&vec[..dst_remainder.len()]);
+        check_chunk(src.len() - src_remainder.len(), src_remainder, dst_remainder, scalar);
+    }
+}
+
+/// A vectorized kernel of an element-wise operation on x8 arrays.
+type ZipKernel = unsafe fn(&[u16; 8], &[u16; 8], &[u16; 8], ZipOp) -> [u16; 8];
+
+/// Chunks the operands of an element-wise operation into x8 arrays, where
+/// `c` is both the third operand and the destination.
+#[inline]
+fn zip_chunked_slice_8(a: &[u16], b: &[u16], c: &mut [u16], op: ZipOp, f: ZipKernel) {
+    assert_eq!(a.len(), c.len());
+    assert_eq!(b.len(), c.len());
+
+    let a_chunks = a.chunks_exact(8);
+    let b_chunks = b.chunks_exact(8);
+    let (a_remainder, b_remainder) = (a_chunks.remainder(), b_chunks.remainder());
+    let mut c_chunks = c.chunks_exact_mut(8);
+    for (i, ((x, y), z)) in a_chunks.zip(b_chunks).zip(&mut c_chunks).enumerate() {
+        let result = unsafe {
+            f(x.try_into().unwrap(), y.try_into().unwrap(), (&*z).try_into().unwrap(), op)
+        };
+        check_zip_chunk(i * 8, x, y, z, &result, op);
+        z.copy_from_slice(&result);
+    }
+
+    // Process remainder
+    let c_remainder = c_chunks.into_remainder();
+    if !c_remainder.is_empty() {
+        let len = c_remainder.len();
+        let mut buf = [[0u16; 8]; 3];
+        buf[0][..len].copy_from_slice(a_remainder);
+        buf[1][..len].copy_from_slice(b_remainder);
+        buf[2][..len].copy_from_slice(c_remainder);
+        let vec = unsafe { f(&buf[0], &buf[1], &buf[2], op) };
+        check_zip_chunk(a.len() - len, a_remainder, b_remainder, c_remainder, &vec[..len], op);
+        c_remainder.copy_from_slice(&vec[..len]);
+    }
+}
+
+/// The results of a vectorized kernel that are compared with the scalar
+/// reference with the `debug-vector-checks` feature.
+trait SameBits: Copy + fmt::Debug {
+    /// Returns if the values have the same bits, or are both NaN, since the
+    /// backends may quiet NaN values differently.
+    fn same_bits(self, other: Self) -> bool;
+}
+
+impl SameBits for u16 {
+    #[inline]
+    fn same_bits(self, other: u16) -> bool {
+        self == other
+    }
+}
+
+impl SameBits for f32 {
+    #[inline]
+    fn same_bits(self, other: f32) -> bool {
+        self.to_bits() == other.to_bits() || (self.is_nan() && other.is_nan())
+    }
+}
+
+impl SameBits for f64 {
+    #[inline]
+    fn same_bits(self, other: f64) -> bool {
+        self.to_bits() == other.to_bits() || (self.is_nan() && other.is_nan())
+    }
+}
+
+/// Checks the results of a vectorized kernel for a chunk of the slice, which
+/// starts at `offset`, against the scalar reference.
+///
+/// This only checks with the `debug-vector-checks` feature in debug builds,
+/// and panics at the first element that differs, so a broken backend is
+/// caught where it first produces a wrong result.
+#[inline]
+fn check_chunk<S: Copy + fmt::Debug, D: SameBits>(
+    offset: usize,
+    src: &[S],
+    dst: &[D],
+    scalar: fn(S) -> D,
+) {
+    #[cfg(all(feature = "debug-vector-checks", debug_assertions))]
+    for (i, (&s, &d)) in src.iter().zip(dst).enumerate() {
+        let expected = scalar(s);
+        assert!(
+            d.same_bits(expected),
+            "vectorized kernel differs from the scalar reference at index {}: {:#x?} for {:#x?}, \
+             expected {:#x?}",
+            offset + i,
+            d,
+            s,
+            expected
+        );
+    }
+    #[cfg(not(all(feature = "debug-vector-checks", debug_assertions)))]
+    let _ = (offset, src, dst, scalar);
+}
+
+/// Checks the results of an element-wise kernel for a chunk of the slices,
+/// which starts at `offset`, against the scalar reference, as with
+/// [`check_chunk`].
+#[inline]
+fn check_zip_chunk(offset: usize, a: &[u16], b: &[u16], c: &[u16], dst: &[u16], op: ZipOp) {
+    #[cfg(all(feature = "debug-vector-checks", debug_assertions))]
+    for (i, &d) in dst.iter().enumerate() {
+        let expected = op.apply(a[i], b[i], c[i]);
+        assert!(
+            d.same_bits(expected),
+            "vectorized kernel differs from the scalar reference at index {}: {:#x?} for {:?} of \
+             {:#x?}, expected {:#x?}",
+            offset + i,
+            d,
+            op,
+            (a[i], b[i], c[i]),
+            expected
+        );
+    }
+    #[cfg(not(all(feature = "debug-vector-checks", debug_assertions)))]
+    let _ = (offset, a, b, c, dst, op);
+}
+
+/// Runs a [`MulAddKernel`] for a row of `c`, with `a` and `b` widened to
+/// [`f32`] by `widen`, and checks it against the scalar reference, as with
+/// [`check_chunk`].
+///
+/// With the `debug-vector-checks` feature in debug builds, the row is run in
+/// chunks of 64 elements, a multiple of every vector width, so the kernel
+/// still handles the same remainder. Every `a` and `b` is exactly an `f16`
+/// value, so each product is exact and the kernels must round the same as
+/// `c + a * b` in [`f32`], without any tolerance.
+#[inline]
+fn check_mul_add_row<B: Copy + fmt::Debug, K: FnMut(&mut [f32], &[B])>(
+    c: &mut [f32],
+    a: f32,
+    b: &[B],
+    widen: fn(B) -> f32,
+    mut kernel: K,
+) {
+    #[cfg(all(feature = "debug-vector-checks", debug_assertions))]
+    {
+        let mut before = [0f32; 64];
+        for (i, (c, b)) in c.chunks_mut(before.len()).zip(b.chunks(before.len())).enumerate() {
+            let before = &mut before[..c.len()];
+            before.copy_from_slice(c);
+            kernel(c, b);
+            for (j, ((&d, &x), &y)) in c.iter().zip(before.iter()).zip(b).enumerate() {
+                let expected = x + a * widen(y);
+                assert!(
+                    d.same_bits(expected),
+                    "vectorized kernel differs from the scalar reference at index {}: {:?} for {:?} \
+                     + {:?} * {:?}, expected {:?}",
+                    i * 64 + j,
+                    d,
+                    x,
+                    a,
+                    y,
+                    expected
+                );
+            }
+        }
+    }
+    #[cfg(not(all(feature = "debug-vector-checks", debug_assertions)))]
+    {
+        let _ = (a, widen);
+        kernel(c, b);
+    }
+}
+
+/// Checks the result of a reduction with native half-precision arithmetic
+/// against the reduction in [`f64`], with the `debug-vector-checks` feature
+/// in debug builds.
+///
+/// The native instructions round to [`struct@f16`] after every element,
+/// unlike the fallback, so the results are only close. Each of the `n - 1`
+/// roundings has a relative error of at most `u = 2^-11`, so a sum must be
+/// within `((1 + u)^(n - 1) - 1) * Σ|x|` of the exact sum, and a product
+/// within `((1 + u)^(n - 1) - 1) * |Πx|` of the exact product. `u` is
+/// slightly larger to cover the rounding of the reference.
+///
+/// Sums of non-finite values are not checked, and infinite sums only need
+/// `Σ|x|` to be large enough to overflow. The error of products is only
+/// relative while every partial product is normal, so products are not
+/// checked if a partial product is within a factor of two of the limits of
+/// the normal range, or if the tolerance reaches `1`.
+///
+/// [`struct@f16`]: crate::f16
+#[inline]
+fn check_reduction(slice: &[u16], result: u16, reduction: Reduction) {
+    #[cfg(all(feature = "debug-vector-checks", debug_assertions))]
+    {
+        const U: f64 = 1.0 / 2048.0 + 1.0 / (1u64 << 50) as f64;
+        const MAX: f64 = 65504.0;
+        const MIN_POSITIVE: f64 = 1.0 / 16384.0;
+        let abs = |x: f64| {
+            if x < 0.0 {
+                -x
+            } else {
+                x
+            }
+        };
+
+        let mut growth = 1.0;
+        let mut exact = match reduction {
+            Reduction::Sum => 0.0,
+            Reduction::Product => 1.0,
+        };
+        let mut magnitude = 0.0;
+        let mut checked = true;
+        for (i, &x) in slice.iter().enumerate() {
+            let x = f16_to_f32_fallback(x) as f64;
+            if i > 0 {
+                growth *= 1.0 + U;
+            }
+            match reduction {
+                Reduction::Sum => {
+                    exact += x;
+                    magnitude += abs(x);
+                    checked &= x.is_finite();
+                },
+                Reduction::Product => {
+                    exact *= x;
+                    checked &= (2.0 * MIN_POSITIVE..=MAX / 2.0).contains(&abs(exact));
+                },
+            }
+        }
+        let tolerance = growth - 1.0;
+        let magnitude = match reduction {
+            Reduction::Sum => magnitude,
+            Reduction::Product => abs(exact),
+        };
+        checked &= tolerance < 1.0 || reduction == Reduction::Sum;
+
+        let value = f16_to_f32_fallback(result) as f64;
+        if checked {
+            let within = if value.is_finite() {
+                abs(value - exact) <= tolerance * magnitude
+            } else {
+                reduction == Reduction::Sum && magnitude * growth >= MAX + 16.0
+            };
+            assert!(
+                within,
+                "vectorized kernel differs from the scalar reference by more than the tolerance: \
+                 {:#x?} for the {:?} of {} values, expected {} within {}",
+                result,
+                reduction,
+                slice.len(),
+                exact,
+                tolerance * magnitude
+            );
+        }
+    }
+    #[cfg(not(all(feature = "debug-vector-checks", debug_assertions)))]
+    let _ = (slice, result, reduction);
+}
+
+/////////////// Fallbacks ////////////////
+
+// In the below functions, round to nearest, with ties to even.
+// Let us call the most significant bit that will be shifted out the round_bit.
+//
+// Round up if either
+//  a) Removed part > tie.
+//     (mantissa & round_bit) != 0 && (mantissa & (round_bit - 1)) != 0
+//  b) Removed part == tie, and retained part is odd.
+//     (mantissa & round_bit) != 0 && (mantissa & (2 * round_bit)) != 0
+// (If removed part == tie and retained part is even, do not round up.)
+// These two conditions can be combined into one:
+//     (mantissa & round_bit) != 0 && (mantissa & ((round_bit - 1) | (2 *
+// round_bit))) != 0 which can be simplified into
+//     (mantissa & round_bit) != 0 && (mantissa & (3 * round_bit - 1)) != 0
+
+#[inline]
+pub(crate) const fn f32_to_f16_fallback(value: f32) -> u16 {
     // TODO: Replace mem::transmute with to_bits() once to_bits is const-stabilized
     // Convert to raw bytes
     let x: u32 = unsafe { mem::transmute::<f32, u32>(value) };
@@ -621,53 +1516,208 @@ pub(crate) const fn f32_to_f16_fallback(value: f32) -> u16 {
 }
 
 #[inline]
-pub(crate) const fn f64_to_f16_fallback(value: f64) -> u16 {
-    // Convert to raw bytes, truncating the last 32-bits of mantissa; that precision
-    // will always be lost on half-precision.
+pub(crate) const fn f32_to_f16_round_fallback(value: f32, mode: RoundingMode) -> u16 {
     // TODO: Replace mem::transmute with to_bits() once to_bits is const-stabilized
-    let val: u64 = unsafe { mem::transmute::<f64, u64>(value) };
-    let x = (val >> 32) as u32;
+    // Convert to raw bytes
+    let x: u32 = unsafe { mem::transmute::<f32, u32>(value) };
 
     // Extract IEEE754 components
     let sign = x & 0x8000_0000u32;
-    let exp = x & 0x7FF0_0000u32;
-    let man = x & 0x000F_FFFFu32;
+    let exp = x & 0x7F80_0000u32;
+    let man = x & 0x007F_FFFFu32;
+    let negative = sign != 0;
 
     // Check for all exponent bits being set, which is Infinity or NaN
-    if exp == 0x7FF0_0000u32 {
-        // Set mantissa MSB for NaN (and also keep shifted mantissa bits).
-        // We also have to check the last 32 bits.
-        let nan_bit = if man == 0 && (val as u32 == 0) {
+    if exp == 0x7F80_0000u32 {
+        // Set mantissa MSB for NaN (and also keep shifted mantissa bits)
+        let nan_bit = if man == 0 {
             0
         } else {
             0x0200u32
         };
-        return ((sign >> 16) | 0x7C00u32 | nan_bit | (man >> 10)) as u16;
+        return ((sign >> 16) | 0x7C00u32 | nan_bit | (man >> 13)) as u16;
     }
 
     // The number is normalized, start assembling half precision version
     let half_sign = sign >> 16;
     // Unbias the exponent, then bias for half precision
-    let unbiased_exp = ((exp >> 20) as i64) - 1023;
+    let unbiased_exp = ((exp >> 23) as i32) - 127;
+    let half_exp = unbiased_exp + 15;
+
+    // Check for exponent overflow, return +infinity or the largest value for
+    // the directed modes that round toward zero
+    if half_exp >= 0x1F {
+        return if mode.overflows_to_infinity(negative) {
+            (half_sign | 0x7C00u32) as u16
+        } else {
+            (half_sign | 0x7BFFu32) as u16
+        };
+    }
+
+    // Check for underflow
+    if half_exp <= 0 {
+        // Check mantissa for what we can do
+        if 14 - half_exp > 24 {
+            // No rounding possibility, so this is a full underflow, return signed zero,
+            // or the smallest subnormal when rounding away from zero
+            let sticky = (exp | man) != 0;
+            return if mode.round_up(negative, false, false, sticky) {
+                (half_sign | 1) as u16
+            } else {
+                half_sign as u16
+            };
+        }
+        // Don't forget about hidden leading mantissa bit when assembling mantissa
+        let man = man | 0x0080_0000u32;
+        let half_man = man >> (14 - half_exp);
+        // Check for rounding (see comment above functions)
+        let round_bit = 1 << (13 - half_exp);
+        let lsb = (man & (round_bit << 1)) != 0;
+        let round = (man & round_bit) != 0;
+        let sticky = (man & (round_bit - 1)) != 0;
+        // No exponent for subnormals, and rounding up may carry into the exponent
+        return if mode.round_up(negative, lsb, round, sticky) {
+            (half_sign | (half_man + 1)) as u16
+        } else {
+            (half_sign | half_man) as u16
+        };
+    }
+
+    // Rebias the exponent
+    let half_exp = (half_exp as u32) << 10;
+    let half_man = man >> 13;
+    // Check for rounding (see comment above functions)
+    let round_bit = 0x0000_1000u32;
+    let lsb = (man & (round_bit << 1)) != 0;
+    let round = (man & round_bit) != 0;
+    let sticky = (man & (round_bit - 1)) != 0;
+    if mode.round_up(negative, lsb, round, sticky) {
+        // Round it, which may carry into the exponent or overflow to infinity
+        ((half_sign | half_exp | half_man) + 1) as u16
+    } else {
+        (half_sign | half_exp | half_man) as u16
+    }
+}
+
+/// Converts an [`f32`] to [`struct@f16`] bits with stochastic rounding, adding
+/// the 16 `random` bits just below the rounding point before truncating.
+#[inline]
+pub(crate) const fn f32_to_f16_stochastic(value: f32, random: u16) -> u16 {
+    // TODO: Replace mem::transmute with to_bits() once to_bits is const-stabilized
+    let x: u32 = unsafe { mem::transmute::<f32, u32>(value) };
+
+    // Infinity and NaN are the same as the normal path
+    if x & 0x7F80_0000u32 == 0x7F80_0000u32 {
+        return f32_to_f16_fallback(value);
+    }
+
+    let half_sign = ((x & 0x8000_0000u32) >> 16) as u16;
+    let exp = x & 0x7F80_0000u32;
+    let man = x & 0x007F_FFFFu32;
+    let half_exp = ((exp >> 23) as i32) - 127 + 15;
+
+    // Values of at least 2^16 are above every finite value, and overflow to
+    // infinity the same as the normal path
+    if half_exp >= 0x1F {
+        return half_sign | 0x7C00u16;
+    }
+
+    // Number of low bits of the full significand below the rounding point,
+    // which is more for subnormal results
+    let (man, shift) = if exp == 0 {
+        (man as u64, 14 - half_exp)
+    } else if half_exp > 0 {
+        ((man | 0x0080_0000u32) as u64, 13)
+    } else {
+        ((man | 0x0080_0000u32) as u64, 14 - half_exp)
+    };
+    // The random bits are at most 2^-16 ulp below the rounding point, so the
+    // value is always truncated to zero when it is smaller than that
+    if shift > 48 {
+        return half_sign;
+    }
+    let shift = shift as u32;
+
+    // Align the significand and the random bits, then truncate their sum, so
+    // the value rounds up with probability proportional to the dropped bits
+    let scale = if shift > 16 {
+        shift
+    } else {
+        16
+    };
+    let sum = (man << (scale - shift)) + ((random as u64) << (scale - 16));
+    let half_man = (sum >> scale) as u16;
+    if half_exp > 0 {
+        // `half_man` includes the hidden bit, so rounding up may carry into the
+        // exponent or overflow to infinity
+        half_sign | ((((half_exp - 1) as u16) << 10) + half_man)
+    } else {
+        // No exponent for subnormals, and rounding up may carry into the
+        // exponent
+        half_sign | half_man
+    }
+}
+
+/// Converts an [`f32`] that rounds to a normal [`struct@f16`] value, without
+/// any of the special cases for NaN, infinity, overflow or underflow.
+#[inline]
+pub(crate) const fn f32_to_f16_normal(value: f32) -> u16 {
+    // TODO: Replace mem::transmute with to_bits() once to_bits is const-stabilized
+    let x: u32 = unsafe { mem::transmute::<f32, u32>(value) };
+    let sign = (x >> 16) & 0x8000u32;
+    // Rebias the exponent in place, then round to nearest, ties to even: any
+    // carry out of the mantissa correctly increments the exponent.
+    let bits = (x & 0x7FFF_FFFFu32) - (112 << 23);
+    let half = (bits + 0x0FFFu32 + ((bits >> 13) & 1)) >> 13;
+    (sign | half) as u16
+}
+
+#[inline]
+pub(crate) const fn f64_to_f16_fallback(value: f64) -> u16 {
+    // TODO: Replace mem::transmute with to_bits() once to_bits is const-stabilized
+    // Convert to raw bytes, keeping every bit of the mantissa so the value is
+    // only rounded once.
+    let x: u64 = unsafe { mem::transmute::<f64, u64>(value) };
+
+    // Extract IEEE754 components
+    let sign = x & 0x8000_0000_0000_0000u64;
+    let exp = x & 0x7FF0_0000_0000_0000u64;
+    let man = x & 0x000F_FFFF_FFFF_FFFFu64;
+
+    // Check for all exponent bits being set, which is Infinity or NaN
+    if exp == 0x7FF0_0000_0000_0000u64 {
+        // Set mantissa MSB for NaN (and also keep shifted mantissa bits)
+        let nan_bit = if man == 0 {
+            0
+        } else {
+            0x0200u64
+        };
+        return ((sign >> 48) | 0x7C00u64 | nan_bit | (man >> 42)) as u16;
+    }
+
+    // The number is normalized, start assembling half precision version
+    let half_sign = sign >> 48;
+    // Unbias the exponent, then bias for half precision
+    let unbiased_exp = ((exp >> 52) as i64) - 1023;
     let half_exp = unbiased_exp + 15;
 
     // Check for exponent overflow, return +infinity
     if half_exp >= 0x1F {
-        return (half_sign | 0x7C00u32) as u16;
+        return (half_sign | 0x7C00u64) as u16;
     }
 
     // Check for underflow
     if half_exp <= 0 {
         // Check mantissa for what we can do
-        if 10 - half_exp > 21 {
+        if 43 - half_exp > 53 {
             // No rounding possibility, so this is a full underflow, return signed zero
             return half_sign as u16;
         }
         // Don't forget about hidden leading mantissa bit when assembling mantissa
-        let man = man | 0x0010_0000u32;
-        let mut half_man = man >> (11 - half_exp);
+        let man = man | 0x0010_0000_0000_0000u64;
+        let mut half_man = man >> (43 - half_exp);
         // Check for rounding (see comment above functions)
-        let round_bit = 1 << (10 - half_exp);
+        let round_bit = 1 << (42 - half_exp);
         if (man & round_bit) != 0 && (man & (3 * round_bit - 1)) != 0 {
             half_man += 1;
         }
@@ -676,10 +1726,10 @@ pub(crate) const fn f64_to_f16_fallback(value: f64) -> u16 {
     }
 
     // Rebias the exponent
-    let half_exp = (half_exp as u32) << 10;
-    let half_man = man >> 10;
+    let half_exp = (half_exp as u64) << 10;
+    let half_man = man >> 42;
     // Check for rounding (see comment above functions)
-    let round_bit = 0x0000_0200u32;
+    let round_bit = 0x0000_0200_0000_0000u64;
     if (man & round_bit) != 0 && (man & (3 * round_bit - 1)) != 0 {
         // Round it
         ((half_sign | half_exp | half_man) + 1) as u16
@@ -688,6 +1738,164 @@ pub(crate) const fn f64_to_f16_fallback(value: f64) -> u16 {
     }
 }
 
+#[inline]
+pub(crate) const fn f64_to_f16_round_fallback(value: f64, mode: RoundingMode) -> u16 {
+    // TODO: Replace mem::transmute with to_bits() once to_bits is const-stabilized
+    // Convert to raw bytes, keeping every bit of the mantissa so the value is
+    // only rounded once.
+    let x: u64 = unsafe { mem::transmute::<f64, u64>(value) };
+
+    // Extract IEEE754 components
+    let sign = x & 0x8000_0000_0000_0000u64;
+    let exp = x & 0x7FF0_0000_0000_0000u64;
+    let man = x & 0x000F_FFFF_FFFF_FFFFu64;
+    let negative = sign != 0;
+
+    // Check for all exponent bits being set, which is Infinity or NaN
+    if exp == 0x7FF0_0000_0000_0000u64 {
+        // Set mantissa MSB for NaN (and also keep shifted mantissa bits)
+        let nan_bit = if man == 0 {
+            0
+        } else {
+            0x0200u64
+        };
+        return ((sign >> 48) | 0x7C00u64 | nan_bit | (man >> 42)) as u16;
+    }
+
+    // The number is normalized, start assembling half precision version
+    let half_sign = sign >> 48;
+    // Unbias the exponent, then bias for half precision
+    let unbiased_exp = ((exp >> 52) as i64) - 1023;
+    let half_exp = unbiased_exp + 15;
+
+    // Check for exponent overflow, return +infinity or the largest value for
+    // the directed modes that round toward zero
+    if half_exp >= 0x1F {
+        return if mode.overflows_to_infinity(negative) {
+            (half_sign | 0x7C00u64) as u16
+        } else {
+            (half_sign | 0x7BFFu64) as u16
+        };
+    }
+
+    // Check for underflow
+    if half_exp <= 0 {
+        // Check mantissa for what we can do
+        if 43 - half_exp > 53 {
+            // No rounding possibility, so this is a full underflow, return signed zero,
+            // or the smallest subnormal when rounding away from zero
+            let sticky = (exp | man) != 0;
+            return if mode.round_up(negative, false, false, sticky) {
+                (half_sign | 1) as u16
+            } else {
+                half_sign as u16
+            };
+        }
+        // Don't forget about hidden leading mantissa bit when assembling mantissa
+        let man = man | 0x0010_0000_0000_0000u64;
+        let half_man = man >> (43 - half_exp);
+        // Check for rounding (see comment above functions)
+        let round_bit = 1 << (42 - half_exp);
+        let lsb = (man & (round_bit << 1)) != 0;
+        let round = (man & round_bit) != 0;
+        let sticky = (man & (round_bit - 1)) != 0;
+        // No exponent for subnormals, and rounding up may carry into the exponent
+        return if mode.round_up(negative, lsb, round, sticky) {
+            (half_sign | (half_man + 1)) as u16
+        } else {
+            (half_sign | half_man) as u16
+        };
+    }
+
+    // Rebias the exponent
+    let half_exp = (half_exp as u64) << 10;
+    let half_man = man >> 42;
+    // Check for rounding (see comment above functions)
+    let round_bit = 0x0000_0200_0000_0000u64;
+    let lsb = (man & (round_bit << 1)) != 0;
+    let round = (man & round_bit) != 0;
+    let sticky = (man & (round_bit - 1)) != 0;
+    if mode.round_up(negative, lsb, round, sticky) {
+        // Round it, which may carry into the exponent or overflow to infinity
+        ((half_sign | half_exp | half_man) + 1) as u16
+    } else {
+        (half_sign | half_exp | half_man) as u16
+    }
+}
+
+/// Converts an [`i32`] directly to [`struct@f16`] bits, rounding once with the
+/// given mode.
+#[inline]
+pub(crate) const fn i32_to_f16_round(value: i32, mode: RoundingMode) -> u16 {
+    if value == 0 {
+        return 0;
+    }
+    let negative = value < 0;
+    let half_sign: u32 = if negative {
+        0x8000
+    } else {
+        0
+    };
+    let mag = value.unsigned_abs();
+
+    // Exponent of the most significant bit, which is the unbiased exponent
+    let msb = 31 - mag.leading_zeros();
+    if msb >= 16 {
+        // At least 2^16, which is above the largest finite value
+        return if mode.overflows_to_infinity(negative) {
+            (half_sign | 0x7C00u32) as u16
+        } else {
+            (half_sign | 0x7BFFu32) as u16
+        };
+    }
+    let half_exp = (msb + 15) << 10;
+    if msb <= 10 {
+        // Exactly representable
+        let half_man = (mag << (10 - msb)) & 0x03FFu32;
+        return (half_sign | half_exp | half_man) as u16;
+    }
+
+    let shift = msb - 10;
+    let half_man = (mag >> shift) & 0x03FFu32;
+    let round_bit = 1u32 << (shift - 1);
+    let lsb = (mag & (round_bit << 1)) != 0;
+    let round = (mag & round_bit) != 0;
+    let sticky = (mag & (round_bit - 1)) != 0;
+    if mode.round_up(negative, lsb, round, sticky) {
+        // Round it, which may carry into the exponent or overflow to infinity
+        ((half_sign | half_exp | half_man) + 1) as u16
+    } else {
+        (half_sign | half_exp | half_man) as u16
+    }
+}
+
+/// Converts [`struct@f16`] bits to an [`i32`], truncating toward zero, or
+/// `None` for NaN and infinity.
+#[inline]
+pub(crate) const fn f16_to_i32_trunc(i: u16) -> Option<i32> {
+    let half_exp = ((i & 0x7C00u16) >> 10) as u32;
+    if half_exp == 0x1F {
+        return None;
+    }
+    // Below 1, including subnormals and zero
+    if half_exp < 15 {
+        return Some(0);
+    }
+    // Every finite value is below 2^16, so it always fits
+    let man = ((i & 0x03FFu16) | 0x0400u16) as i32;
+    let e = half_exp - 15;
+    let mag = if e >= 10 {
+        man << (e - 10)
+    } else {
+        man >> (10 - e)
+    };
+    if i & 0x8000u16 != 0 {
+        Some(-mag)
+    } else {
+        Some(mag)
+    }
+}
+
 #[inline]
 pub(crate) const fn f16_to_f32_fallback(i: u16) -> f32 {
     // Check for signed zero
@@ -892,6 +2100,63 @@ fn slice_fallback<S: Copy, D>(src: &[S], dst: &mut [D], f: fn(S) -> D) {
     }
 }
 
+#[inline]
+fn zip_f16_slice_fallback(a: &[u16], b: &[u16], c: &mut [u16], op: ZipOp) {
+    assert_eq!(a.len(), c.len());
+    assert_eq!(b.len(), c.len());
+    for ((&x, &y), z) in a.iter().zip(b).zip(c.iter_mut()) {
+        *z = op.apply(x, y, *z);
+    }
+}
+
+/// Replaces a NaN result with the canonical [`struct@f16`] NaN, `0x7E00`,
+/// with the `canonical-nan` feature. Otherwise, the bits are unchanged.
+///
+/// [`struct@f16`]: crate::f16
+#[inline(always)]
+pub(crate) const fn canonical_nan(bits: u16) -> u16 {
+    if cfg!(feature = "canonical-nan") && bits & 0x7FFF > 0x7C00 {
+        0x7E00
+    } else {
+        bits
+    }
+}
+
+/// Replaces a NaN result with [`f32::NAN`], with the `canonical-nan` feature.
+#[inline(always)]
+pub(crate) const fn canonical_nan_f32(value: f32) -> f32 {
+    // TODO: Replace mem::transmute with to_bits() once to_bits is const-stabilized
+    let bits: u32 = unsafe { mem::transmute::<f32, u32>(value) };
+    if cfg!(feature = "canonical-nan") && bits & 0x7FFF_FFFF > 0x7F80_0000 {
+        unsafe { mem::transmute::<u32, f32>(0x7FC0_0000) }
+    } else {
+        value
+    }
+}
+
+/// Replaces a NaN result with [`f64::NAN`], with the `canonical-nan` feature.
+#[inline(always)]
+pub(crate) const fn canonical_nan_f64(value: f64) -> f64 {
+    // TODO: Replace mem::transmute with to_bits() once to_bits is const-stabilized
+    let bits: u64 = unsafe { mem::transmute::<f64, u64>(value) };
+    if cfg!(feature = "canonical-nan") && bits & 0x7FFF_FFFF_FFFF_FFFF > 0x7FF0_0000_0000_0000 {
+        unsafe { mem::transmute::<u64, f64>(0x7FF8_0000_0000_0000) }
+    } else {
+        value
+    }
+}
+
+/// Replaces the NaN results of a slice conversion, with the `canonical-nan`
+/// feature, after the vectorized kernels have run.
+#[inline]
+fn canonical_nan_slice<T: Copy>(dst: &mut [T], canonical: fn(T) -> T) {
+    if cfg!(feature = "canonical-nan") {
+        for x in dst.iter_mut() {
+            *x = canonical(*x);
+        }
+    }
+}
+
 #[inline]
 fn add_f16_fallback(a: u16, b: u16) -> u16 {
     f32_to_f16(f16_to_f32(a) + f16_to_f32(b))
@@ -912,6 +2177,17 @@ fn divide_f16_fallback(a: u16, b: u16) -> u16 {
     f32_to_f16(f16_to_f32(a) / f16_to_f32(b))
 }
 
+#[inline]
+fn recip_f16_fallback(a: u16) -> u16 {
+    f32_to_f16(1.0 / f16_to_f32(a))
+}
+
+#[inline]
+#[cfg(feature = "std")]
+fn rsqrt_f16_fallback(a: u16) -> u16 {
+    f32_to_f16(1.0 / f16_to_f32(a).sqrt())
+}
+
 #[inline]
 fn remainder_f16_fallback(a: u16, b: u16) -> u16 {
     f32_to_f16(f16_to_f32(a) % f16_to_f32(b))
@@ -919,12 +2195,265 @@ fn remainder_f16_fallback(a: u16, b: u16) -> u16 {
 
 #[inline]
 fn product_f16_fallback<I: Iterator<Item = u16>>(iter: I) -> u16 {
-    f32_to_f16(iter.map(f16_to_f32).product())
+    f32_to_f16(fold_f16_fallback(iter, 1.0, |acc, x| acc * x))
 }
 
 #[inline]
 fn sum_f16_fallback<I: Iterator<Item = u16>>(iter: I) -> u16 {
-    f32_to_f16(iter.map(f16_to_f32).sum())
+    // Use the same initial value as `f32::sum`, including for negative zeros.
+    let init: f32 = core::iter::empty::<f32>().sum();
+    f32_to_f16(fold_f16_fallback(iter, init, |acc, x| acc + x))
+}
+
+#[inline]
+fn product_f16_slice_fallback(slice: &[u16]) -> u16 {
+    f32_to_f16(fold_f16_slice_fallback(slice, 1.0, |acc, x| acc * x))
+}
+
+#[inline]
+fn sum_f16_slice_fallback(slice: &[u16]) -> u16 {
+    // Use the same initial value as `sum_f16_fallback`, so the two are
+    // identical, including for negative zeros.
+    let init: f32 = core::iter::empty::<f32>().sum();
+    f32_to_f16(fold_f16_slice_fallback(slice, init, |acc, x| acc + x))
+}
+
+/// Folds the slice in order as [`f32`] values, converting it in chunks so the
+/// vectorized conversions can be used.
+#[inline]
+fn fold_f16_slice_fallback<F: Fn(f32, f32) -> f32>(slice: &[u16], init: f32, f: F) -> f32 {
+    let mut buffer = [0f32; 64];
+    let mut acc = init;
+    for chunk in slice.chunks(buffer.len()) {
+        let buffer = &mut buffer[..chunk.len()];
+        f16_to_f32_slice(chunk, buffer);
+        acc = buffer.iter().fold(acc, |acc, &x| f(acc, x));
+    }
+    acc
+}
+
+/// Folds the iterator in order as [`f32`] values, buffering it in chunks so
+/// the vectorized conversions can be used, like [`fold_f16_slice_fallback`].
+#[inline]
+fn fold_f16_fallback<I, F>(mut iter: I, init: f32, f: F) -> f32
+where
+    I: Iterator<Item = u16>,
+    F: Fn(f32, f32) -> f32,
+{
+    let mut halves = [0u16; 64];
+    let mut acc = init;
+    loop {
+        let mut len = 0;
+        for (half, x) in halves.iter_mut().zip(&mut iter) {
+            *half = x;
+            len += 1;
+        }
+        if len == 0 {
+            return acc;
+        }
+        acc = fold_f16_slice_fallback(&halves[..len], acc, &f);
+        if len < halves.len() {
+            return acc;
+        }
+    }
 }
 
 // TODO SIMD arithmetic
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// A broken kernel, which sets the lowest bit of the third lane if it is
+    /// negative.
+    unsafe fn broken_f32x4_to_f16x4(v: &[f32; 4]) -> [u16; 4] {
+        let mut result = v.map(f32_to_f16_fallback);
+        if v[2] < 0. {
+            result[2] ^= 1;
+        }
+        result
+    }
+
+    #[test]
+    #[cfg(all(feature = "debug-vector-checks", debug_assertions))]
+    #[should_panic(expected = "at index 6: 0xbc01 for -1.0, expected 0xbc00")]
+    fn test_broken_kernel() {
+        let mut src = [-1f32; 9];
+        src[2] = 1.;
+        let mut dst = [0u16; 9];
+        convert_chunked_slice_4(&src, &mut dst, broken_f32x4_to_f16x4, f32_to_f16_fallback);
+    }
+
+    #[test]
+    #[cfg(all(feature = "debug-vector-checks", debug_assertions))]
+    #[should_panic(expected = "at index 10: 0xbc01 for -1.0, expected 0xbc00")]
+    fn test_broken_kernel_remainder() {
+        let src = [-1f32; 11];
+        let mut dst = [0u16; 11];
+        convert_chunked_slice_8(
+            &src,
+            &mut dst,
+            f32x8_to_f16x8_fallback,
+            broken_f32x4_to_f16x4,
+            f32_to_f16_fallback,
+        );
+    }
+
+    #[test]
+    fn test_sum_squares() {
+        let mut values = [0f32; 70];
+        for (i, x) in values.iter_mut().enumerate() {
+            *x = f16_to_f32_fallback(f32_to_f16_fallback((i as f32 - 35.) * 0.37));
+        }
+        for len in [0, 3, 8, 13, 64, 70] {
+            let (mut expected, mut actual) = ([0.5f32; 8], [0.5f32; 8]);
+            sum_squares_fallback(&values[..len], &mut expected);
+            sum_squares_f32(&values[..len], &mut actual);
+            assert_eq!(actual.map(f32::to_bits), expected.map(f32::to_bits), "{}", len);
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "debug-vector-checks", debug_assertions))]
+    #[should_panic(expected = "at index 69: 2.0000002 for 1.0 + 1.0 * 1.0, expected 2.0")]
+    fn test_broken_mul_add_kernel() {
+        let mut c = [1f32; 70];
+        let b = [1f32; 70];
+        // Only the last element of each chunk of 64 is broken, so this checks
+        // that the first chunk passes and the index of the remainder.
+        let mut calls = 0;
+        check_mul_add_row(
+            &mut c,
+            1.,
+            &b,
+            |b| b,
+            |c, b| {
+                mul_add_row_fallback(c, 1., b);
+                calls += 1;
+                if calls > 1 {
+                    let last = c.last_mut().unwrap();
+                    *last = f32::from_bits(last.to_bits() ^ 1);
+                }
+            },
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "debug-vector-checks", debug_assertions))]
+    fn test_reduction_tolerance() {
+        // Emulates the native instructions, which round after every element.
+        let native = |slice: &[u16], reduction| {
+            slice.iter().fold(
+                match reduction {
+                    Reduction::Sum => 0,
+                    Reduction::Product => 0x3C00,
+                },
+                |acc, &x| {
+                    let (acc, x) = (f16_to_f32_fallback(acc), f16_to_f32_fallback(x));
+                    f32_to_f16_fallback(match reduction {
+                        Reduction::Sum => acc + x,
+                        Reduction::Product => acc * x,
+                    })
+                },
+            )
+        };
+        let mut state = 1u32;
+        let mut slice = [0u16; 300];
+        for _ in 0..64 {
+            for x in slice.iter_mut() {
+                state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                // Values in `[0.5, 2)` of either sign, so products stay normal.
+                *x = 0x3800 | (state >> 16) as u16 & 0x87FF;
+            }
+            for len in [0, 1, 2, 17, 300] {
+                for reduction in [Reduction::Sum, Reduction::Product] {
+                    let slice = &slice[..len];
+                    check_reduction(slice, native(slice, reduction), reduction);
+                }
+            }
+        }
+        // Overflow is allowed once the sum is large enough.
+        check_reduction(
+            &[0x7BFF, 0x7BFF, 0xFBFF],
+            native(&[0x7BFF, 0x7BFF, 0xFBFF], Reduction::Sum),
+            Reduction::Sum,
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "debug-vector-checks", debug_assertions))]
+    #[should_panic(expected = "by more than the tolerance: 0x4201 for the Sum of 2 values")]
+    fn test_broken_reduction() {
+        // `1 + 2` is exact, so `3.0` is the only result within the tolerance.
+        check_reduction(&[0x3C00, 0x4000], 0x4201, Reduction::Sum);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_kernels_match_scalar() {
+        // Every value with the backend of this target, which is also checked
+        // per chunk with `debug-vector-checks`. The odd length leaves a
+        // remainder in every call.
+        let mut bits = [0u16; 1021];
+        let mut wide = [0f32; 1021];
+        let mut back = [0u16; 1021];
+        for start in (0..=u16::MAX as usize).step_by(bits.len()) {
+            for (i, b) in bits.iter_mut().enumerate() {
+                *b = (start + i) as u16;
+            }
+            f16_to_f32_slice(&bits, &mut wide);
+            f32_to_f16_slice(&wide, &mut back);
+            for ((&a, &w), &b) in bits.iter().zip(&wide).zip(&back) {
+                assert!(w.same_bits(f16_to_f32_fallback(a)), "{:#06x}", a);
+                assert!(
+                    a == b || (w.is_nan() && b & 0x7C00 == 0x7C00 && b & 0x3FF != 0),
+                    "{:#06x}",
+                    a
+                );
+            }
+        }
+    }
+
+    /// Checks the specialized conversions against [`minifloat::narrow`] for
+    /// every sign, exponent and value of the 11 highest mantissa bits, which
+    /// include every bit that is kept or rounded at, with each pattern of the
+    /// lower bits that decides ties: none, only the lowest, only the highest,
+    /// or all of them set.
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_narrow_f32() {
+        for sign_exp in 0..1u32 << 9 {
+            for high in 0..1u32 << 11 {
+                for &low in &[0, 1, 0x800, 0xFFF] {
+                    let bits = sign_exp << 23 | high << 12 | low;
+                    let x = f32::from_bits(bits);
+                    let f16 = minifloat::narrow(bits as u64, 8, 23, 5, 10) as u16;
+                    let bf16 = minifloat::narrow(bits as u64, 8, 23, 8, 7) as u16;
+                    assert_eq!(f32_to_f16_fallback(x), f16, "{:#010x}", bits);
+                    assert_eq!(bf16_convert::f32_to_bf16(x), bf16, "{:#010x}", bits);
+                }
+            }
+        }
+    }
+
+    /// Checks [`f64_to_f16_fallback`] against [`minifloat::narrow`] as in
+    /// [`test_narrow_f32`], for the exponents that do not always overflow or
+    /// underflow, and the extremes of the range.
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_narrow_f64() {
+        let exps = (1023 - 28..=1023 + 16).chain([0, 1, 0x7FE, 0x7FF]);
+        for exp in exps {
+            for sign in 0..2u64 {
+                for high in 0..1u64 << 11 {
+                    for &low in &[0, 1, 1 << 40, (1 << 41) - 1] {
+                        let bits = sign << 63 | exp << 52 | high << 41 | low;
+                        let expected = minifloat::narrow(bits, 11, 52, 5, 10) as u16;
+                        let actual = f64_to_f16_fallback(f64::from_bits(bits));
+                        assert_eq!(actual, expected, "{:#018x}", bits);
+                    }
+                }
+            }
+        }
+    }
+}
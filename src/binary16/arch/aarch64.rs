@@ -3,13 +3,40 @@
 
 use core::{
     arch::{
-        aarch64::{float32x4_t, float64x2_t, uint16x4_t},
+        aarch64::{
+            float32x4_t,
+            float64x2_t,
+            uint16x4_t,
+            uint16x8_t,
+            uint32x4_t,
+            vld1q_u16,
+            vld1q_u8,
+            vrev16q_u8,
+            vst1q_u16,
+            vst1q_u8,
+        },
         asm,
     },
     mem::MaybeUninit,
     ptr,
 };
 
+use super::{f16_to_f32_fallback, zip_f16x8_widened, ZipOp};
+
+/// Applies a vector instruction to the 8 lanes of `x` and `y`.
+macro_rules! f16x8_op {
+    ($inst:literal, $x:expr, $y:expr) => {{
+        let result: uint16x8_t;
+        asm!(
+            concat!($inst, " {0:v}.8h, {1:v}.8h, {2:v}.8h"),
+            out(vreg) result,
+            in(vreg) $x,
+            in(vreg) $y,
+            options(pure, nomem, nostack));
+        result
+    }};
+}
+
 #[inline]
 #[target_feature(enable = "fp16")]
 pub(super) unsafe fn f16_to_f32_fp16(i: u16) -> f32 {
@@ -176,3 +203,150 @@ pub(super) unsafe fn divide_f16_fp16(a: u16, b: u16) -> u16 {
         options(pure, nomem, nostack));
     result
 }
+
+#[inline]
+#[target_feature(enable = "fp16")]
+pub(super) unsafe fn recip_estimate_f16_fp16(a: u16) -> u16 {
+    let result: u16;
+    asm!(
+        "frecpe {0:h}, {1:h}",
+        out(vreg) result,
+        in(vreg) a,
+        options(pure, nomem, nostack));
+    result
+}
+
+#[inline]
+#[target_feature(enable = "fp16")]
+pub(super) unsafe fn recip_step_f16_fp16(a: u16, b: u16) -> u16 {
+    let result: u16;
+    asm!(
+        "frecps {0:h}, {1:h}, {2:h}",
+        out(vreg) result,
+        in(vreg) a,
+        in(vreg) b,
+        options(pure, nomem, nostack));
+    result
+}
+
+#[inline]
+#[target_feature(enable = "fp16")]
+pub(super) unsafe fn rsqrt_estimate_f16_fp16(a: u16) -> u16 {
+    let result: u16;
+    asm!(
+        "frsqrte {0:h}, {1:h}",
+        out(vreg) result,
+        in(vreg) a,
+        options(pure, nomem, nostack));
+    result
+}
+
+#[inline]
+#[target_feature(enable = "fp16")]
+pub(super) unsafe fn rsqrt_step_f16_fp16(a: u16, b: u16) -> u16 {
+    let result: u16;
+    asm!(
+        "frsqrts {0:h}, {1:h}, {2:h}",
+        out(vreg) result,
+        in(vreg) a,
+        in(vreg) b,
+        options(pure, nomem, nostack));
+    result
+}
+
+#[inline]
+#[target_feature(enable = "fp16")]
+pub(super) unsafe fn zip_f16x8_fp16(
+    a: &[u16; 8],
+    b: &[u16; 8],
+    c: &[u16; 8],
+    op: ZipOp,
+) -> [u16; 8] {
+    let x = vld1q_u16(a.as_ptr());
+    let y = vld1q_u16(b.as_ptr());
+    let result = match op {
+        ZipOp::Add => f16x8_op!("fadd", x, y),
+        ZipOp::Subtract => f16x8_op!("fsub", x, y),
+        ZipOp::Multiply => f16x8_op!("fmul", x, y),
+        ZipOp::Divide => f16x8_op!("fdiv", x, y),
+        // There is no vector remainder, and a fused `fmla` rounds once, unlike
+        // the other backends, so both are computed in `f32`.
+        ZipOp::Remainder | ZipOp::MulAdd => return zip_f16x8_widened(a, b, c, op),
+    };
+    let mut out = MaybeUninit::<[u16; 8]>::uninit();
+    vst1q_u16(out.as_mut_ptr().cast::<u16>(), result);
+    out.assume_init()
+}
+
+#[inline]
+#[target_feature(enable = "neon")]
+pub(super) unsafe fn u16x8_swap_bytes_neon(v: &[u16; 8]) -> [u16; 8] {
+    let vec = vld1q_u8(v.as_ptr().cast::<u8>());
+    let mut result = MaybeUninit::<[u16; 8]>::uninit();
+    vst1q_u8(result.as_mut_ptr().cast::<u8>(), vrev16q_u8(vec));
+    result.assume_init()
+}
+
+/////////////// aarch64 bf16 ////////////////
+
+#[inline]
+#[target_feature(enable = "bf16")]
+pub(super) unsafe fn bf16x4_to_f32x4_bf16(v: &[u16; 4]) -> [f32; 4] {
+    let mut vec = MaybeUninit::<uint16x4_t>::uninit();
+    ptr::copy_nonoverlapping(v.as_ptr(), vec.as_mut_ptr().cast(), 4);
+    // Widening is exact, so this is the same shift as `vcvtq_f32_bf16`.
+    let result: uint32x4_t;
+    asm!(
+        "shll {0:v}.4s, {1:v}.4h, #16",
+        out(vreg) result,
+        in(vreg) vec.assume_init(),
+        options(pure, nomem, nostack, preserves_flags));
+    *(&result as *const uint32x4_t).cast()
+}
+
+#[inline]
+#[target_feature(enable = "bf16")]
+pub(super) unsafe fn f32x4_to_bf16x4_bf16(v: &[f32; 4]) -> [u16; 4] {
+    let mut vec = MaybeUninit::<float32x4_t>::uninit();
+    ptr::copy_nonoverlapping(v.as_ptr(), vec.as_mut_ptr().cast(), 4);
+    // Rounds to nearest, ties to even, and quiets NaN, the same as
+    // `vcvt_bf16_f32`.
+    let result: uint16x4_t;
+    asm!(
+        "bfcvtn {0:v}.4h, {1:v}.4s",
+        out(vreg) result,
+        in(vreg) vec.assume_init(),
+        options(pure, nomem, nostack));
+    *(&result as *const uint16x4_t).cast()
+}
+
+/////////////// aarch64 fhm ////////////////
+
+#[inline]
+#[target_feature(enable = "fhm")]
+pub(super) unsafe fn mul_add_row_f16_fhm(c: &mut [f32], a: u16, b: &[u16]) {
+    let len = c.len().min(b.len());
+    let mut j = 0;
+    while j + 4 <= len {
+        let mut acc = MaybeUninit::<float32x4_t>::uninit();
+        ptr::copy_nonoverlapping(c.as_ptr().add(j), acc.as_mut_ptr().cast(), 4);
+        let mut vec = MaybeUninit::<uint16x4_t>::uninit();
+        ptr::copy_nonoverlapping(b.as_ptr().add(j), vec.as_mut_ptr().cast(), 4);
+        // Widens both operands and accumulates with a single rounding, the
+        // same as `vfmlalq_lane_low_f16`.
+        let mut acc = acc.assume_init();
+        asm!(
+            "fmlal {0:v}.4s, {1:v}.4h, {2:v}.h[0]",
+            inout(vreg) acc,
+            in(vreg) vec.assume_init(),
+            in(vreg_low16) a,
+            options(pure, nomem, nostack));
+        ptr::copy_nonoverlapping((&acc as *const float32x4_t).cast(), c.as_mut_ptr().add(j), 4);
+        j += 4;
+    }
+    // The products are exact, so this rounds the same as the fused add.
+    let a = f16_to_f32_fallback(a);
+    for (c, &b) in c[j..len].iter_mut().zip(&b[j..len]) {
+        *c += a * f16_to_f32_fallback(b);
+    }
+}
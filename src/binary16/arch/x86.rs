@@ -10,9 +10,46 @@ use core::arch::x86::{
     __m128,
     __m128i,
     __m256,
+    __m256i,
+    _mm256_add_ps,
+    _mm256_cvtepi32_ps,
     _mm256_cvtph_ps,
     _mm256_cvtps_ph,
+    _mm256_div_ps,
+    _mm256_fmadd_ps,
+    _mm256_loadu_ps,
+    _mm256_loadu_si256,
+    _mm256_mul_ps,
+    _mm256_set1_ps,
+    _mm256_setr_epi32,
+    _mm256_setr_epi8,
+    _mm256_shuffle_epi8,
+    _mm256_storeu_ps,
+    _mm256_storeu_si256,
+    _mm256_sub_ps,
+    _mm_add_epi32,
+    _mm_and_si128,
+    _mm_andnot_si128,
+    _mm_castps_si128,
+    _mm_castsi128_ps,
+    _mm_cmpunord_ps,
+    _mm_cvtepi32_ps,
     _mm_cvtph_ps,
+    _mm_div_ps,
+    _mm_loadu_si128,
+    _mm_or_si128,
+    _mm_packs_epi32,
+    _mm_set1_epi32,
+    _mm_set1_ps,
+    _mm_setr_epi32,
+    _mm_setzero_si128,
+    _mm_srai_epi32,
+    _mm_srli_epi32,
+    _mm_srli_si128,
+    _mm_storeu_si128,
+    _mm_unpackhi_epi16,
+    _mm_unpacklo_epi16,
+    _mm_unpacklo_epi64,
     _MM_FROUND_TO_NEAREST_INT,
 };
 #[cfg(target_arch = "x86_64")]
@@ -20,15 +57,52 @@ use core::arch::x86_64::{
     __m128,
     __m128i,
     __m256,
+    __m256i,
+    _mm256_add_ps,
+    _mm256_cvtepi32_ps,
     _mm256_cvtph_ps,
     _mm256_cvtps_ph,
+    _mm256_div_ps,
+    _mm256_fmadd_ps,
+    _mm256_loadu_ps,
+    _mm256_loadu_si256,
+    _mm256_mul_ps,
+    _mm256_set1_ps,
+    _mm256_setr_epi32,
+    _mm256_setr_epi8,
+    _mm256_shuffle_epi8,
+    _mm256_storeu_ps,
+    _mm256_storeu_si256,
+    _mm256_sub_ps,
+    _mm_add_epi32,
+    _mm_and_si128,
+    _mm_andnot_si128,
+    _mm_castps_si128,
+    _mm_castsi128_ps,
+    _mm_cmpunord_ps,
+    _mm_cvtepi32_ps,
     _mm_cvtph_ps,
     _mm_cvtps_ph,
+    _mm_div_ps,
+    _mm_loadu_si128,
+    _mm_or_si128,
+    _mm_packs_epi32,
+    _mm_set1_epi32,
+    _mm_set1_ps,
+    _mm_setr_epi32,
+    _mm_setzero_si128,
+    _mm_srai_epi32,
+    _mm_srli_epi32,
+    _mm_srli_si128,
+    _mm_storeu_si128,
+    _mm_unpackhi_epi16,
+    _mm_unpacklo_epi16,
+    _mm_unpacklo_epi64,
     _MM_FROUND_TO_NEAREST_INT,
 };
 use core::{mem::MaybeUninit, ptr};
 
-use super::convert_chunked_slice_8;
+use super::{convert_chunked_slice_8, ZipOp};
 
 /////////////// x86/x86_64 f16c ////////////////
 
@@ -153,3 +227,185 @@ pub(super) unsafe fn f64x8_to_f16x8_x86_f16c(v: &[f64; 8]) -> [u16; 8] {
     ];
     f32x8_to_f16x8_x86_f16c(&v)
 }
+
+#[inline]
+#[target_feature(enable = "f16c")]
+pub(super) unsafe fn u8x4_norm_to_f16x4_x86_f16c(v: &[u8; 4]) -> [u16; 4] {
+    let ints = _mm_setr_epi32(v[0] as i32, v[1] as i32, v[2] as i32, v[3] as i32);
+    let floats = _mm_div_ps(_mm_cvtepi32_ps(ints), _mm_set1_ps(255.0));
+    let retval = _mm_cvtps_ph(floats, _MM_FROUND_TO_NEAREST_INT);
+    *(&retval as *const __m128i).cast()
+}
+
+#[inline]
+#[target_feature(enable = "f16c")]
+pub(super) unsafe fn u8x8_norm_to_f16x8_x86_f16c(v: &[u8; 8]) -> [u16; 8] {
+    let ints = _mm256_setr_epi32(
+        v[0] as i32,
+        v[1] as i32,
+        v[2] as i32,
+        v[3] as i32,
+        v[4] as i32,
+        v[5] as i32,
+        v[6] as i32,
+        v[7] as i32,
+    );
+    let floats = _mm256_div_ps(_mm256_cvtepi32_ps(ints), _mm256_set1_ps(255.0));
+    let retval = _mm256_cvtps_ph(floats, _MM_FROUND_TO_NEAREST_INT);
+    *(&retval as *const __m128i).cast()
+}
+
+/// Rounds four [`f32`] values to the nearest `bf16` values with ties to
+/// even, in the low 16 bits of each lane. NaN values keep the high part of
+/// the mantissa, with the most significant mantissa bit set.
+#[inline]
+#[target_feature(enable = "f16c")]
+unsafe fn f32x4_to_bf16_lanes(floats: __m128) -> __m128i {
+    let bits = _mm_castps_si128(floats);
+    let odd = _mm_and_si128(_mm_srli_epi32(bits, 16), _mm_set1_epi32(1));
+    let rounded = _mm_add_epi32(bits, _mm_add_epi32(odd, _mm_set1_epi32(0x7FFF)));
+    let quiet = _mm_or_si128(bits, _mm_set1_epi32(0x0040_0000));
+    let nan = _mm_castps_si128(_mm_cmpunord_ps(floats, floats));
+    let result = _mm_or_si128(_mm_and_si128(nan, quiet), _mm_andnot_si128(nan, rounded));
+    // The arithmetic shift sign-extends the 16 bits, so packing them with
+    // signed saturation is exact.
+    _mm_srai_epi32(result, 16)
+}
+
+#[inline]
+#[target_feature(enable = "f16c")]
+pub(super) unsafe fn f16x4_to_bf16x4_x86_f16c(v: &[u16; 4]) -> [u16; 4] {
+    let mut vec = MaybeUninit::<__m128i>::zeroed();
+    ptr::copy_nonoverlapping(v.as_ptr(), vec.as_mut_ptr().cast(), 4);
+    let lanes = f32x4_to_bf16_lanes(_mm_cvtph_ps(vec.assume_init()));
+    let retval = _mm_packs_epi32(lanes, _mm_setzero_si128());
+    *(&retval as *const __m128i).cast()
+}
+
+#[inline]
+#[target_feature(enable = "f16c")]
+pub(super) unsafe fn f16x8_to_bf16x8_x86_f16c(v: &[u16; 8]) -> [u16; 8] {
+    let vec = _mm_loadu_si128(v.as_ptr().cast::<__m128i>());
+    let low = f32x4_to_bf16_lanes(_mm_cvtph_ps(vec));
+    let high = f32x4_to_bf16_lanes(_mm_cvtph_ps(_mm_srli_si128(vec, 8)));
+    let mut result = MaybeUninit::<[u16; 8]>::uninit();
+    _mm_storeu_si128(result.as_mut_ptr().cast::<__m128i>(), _mm_packs_epi32(low, high));
+    result.assume_init()
+}
+
+#[inline]
+#[target_feature(enable = "f16c")]
+pub(super) unsafe fn bf16x4_to_f16x4_x86_f16c(v: &[u16; 4]) -> [u16; 4] {
+    let mut vec = MaybeUninit::<__m128i>::zeroed();
+    ptr::copy_nonoverlapping(v.as_ptr(), vec.as_mut_ptr().cast(), 4);
+    // Each `bf16` value is the high half of an `f32` value.
+    let floats = _mm_castsi128_ps(_mm_unpacklo_epi16(_mm_setzero_si128(), vec.assume_init()));
+    let retval = _mm_cvtps_ph(floats, _MM_FROUND_TO_NEAREST_INT);
+    *(&retval as *const __m128i).cast()
+}
+
+#[inline]
+#[target_feature(enable = "f16c")]
+pub(super) unsafe fn bf16x8_to_f16x8_x86_f16c(v: &[u16; 8]) -> [u16; 8] {
+    let vec = _mm_loadu_si128(v.as_ptr().cast::<__m128i>());
+    // Each `bf16` value is the high half of an `f32` value.
+    let low = _mm_castsi128_ps(_mm_unpacklo_epi16(_mm_setzero_si128(), vec));
+    let high = _mm_castsi128_ps(_mm_unpackhi_epi16(_mm_setzero_si128(), vec));
+    let low = _mm_cvtps_ph(low, _MM_FROUND_TO_NEAREST_INT);
+    let high = _mm_cvtps_ph(high, _MM_FROUND_TO_NEAREST_INT);
+    let mut result = MaybeUninit::<[u16; 8]>::uninit();
+    _mm_storeu_si128(result.as_mut_ptr().cast::<__m128i>(), _mm_unpacklo_epi64(low, high));
+    result.assume_init()
+}
+
+#[inline]
+#[target_feature(enable = "avx,f16c")]
+pub(super) unsafe fn zip_f16x8_x86_f16c(
+    a: &[u16; 8],
+    b: &[u16; 8],
+    c: &[u16; 8],
+    op: ZipOp,
+) -> [u16; 8] {
+    let x = _mm256_cvtph_ps(_mm_loadu_si128(a.as_ptr().cast::<__m128i>()));
+    let y = _mm256_cvtph_ps(_mm_loadu_si128(b.as_ptr().cast::<__m128i>()));
+    let result = match op {
+        ZipOp::Add => _mm256_add_ps(x, y),
+        ZipOp::Subtract => _mm256_sub_ps(x, y),
+        ZipOp::Multiply => _mm256_mul_ps(x, y),
+        ZipOp::Divide => _mm256_div_ps(x, y),
+        // There is no vector remainder, so only the conversions are vectorized.
+        ZipOp::Remainder => {
+            let mut lhs = [0f32; 8];
+            let mut rhs = [0f32; 8];
+            _mm256_storeu_ps(lhs.as_mut_ptr(), x);
+            _mm256_storeu_ps(rhs.as_mut_ptr(), y);
+            for (l, &r) in lhs.iter_mut().zip(rhs.iter()) {
+                *l %= r;
+            }
+            _mm256_loadu_ps(lhs.as_ptr())
+        },
+        // The products are exact, so this rounds the same as the fused add.
+        ZipOp::MulAdd => {
+            let z = _mm256_cvtph_ps(_mm_loadu_si128(c.as_ptr().cast::<__m128i>()));
+            _mm256_add_ps(_mm256_mul_ps(x, y), z)
+        },
+    };
+    let mut out = MaybeUninit::<[u16; 8]>::uninit();
+    _mm_storeu_si128(
+        out.as_mut_ptr().cast::<__m128i>(),
+        _mm256_cvtps_ph(result, _MM_FROUND_TO_NEAREST_INT),
+    );
+    out.assume_init()
+}
+
+/////////////// x86/x86_64 avx2 ////////////////
+
+#[inline]
+#[target_feature(enable = "avx2")]
+pub(super) unsafe fn u16x16_swap_bytes_x86_avx2(v: &[u16; 16]) -> [u16; 16] {
+    // The shuffle is within each 128-bit lane, so the mask repeats per lane.
+    let mask = _mm256_setr_epi8(
+        1, 0, 3, 2, 5, 4, 7, 6, 9, 8, 11, 10, 13, 12, 15, 14, 1, 0, 3, 2, 5, 4, 7, 6, 9, 8, 11, 10,
+        13, 12, 15, 14,
+    );
+    let vec = _mm256_loadu_si256(v.as_ptr().cast::<__m256i>());
+    let mut result = MaybeUninit::<[u16; 16]>::uninit();
+    _mm256_storeu_si256(result.as_mut_ptr().cast::<__m256i>(), _mm256_shuffle_epi8(vec, mask));
+    result.assume_init()
+}
+
+/////////////// x86/x86_64 fma ////////////////
+
+#[inline]
+#[target_feature(enable = "avx,fma")]
+pub(super) unsafe fn mul_add_row_x86_fma(c: &mut [f32], a: f32, b: &[f32]) {
+    let len = c.len().min(b.len());
+    let scale = _mm256_set1_ps(a);
+    let mut j = 0;
+    while j + 8 <= len {
+        let acc = _mm256_loadu_ps(c.as_ptr().add(j));
+        let vec = _mm256_loadu_ps(b.as_ptr().add(j));
+        _mm256_storeu_ps(c.as_mut_ptr().add(j), _mm256_fmadd_ps(scale, vec, acc));
+        j += 8;
+    }
+    // The products are exact, so this rounds the same as the fused add.
+    for (c, &b) in c[j..len].iter_mut().zip(&b[j..len]) {
+        *c += a * b;
+    }
+}
+
+#[inline]
+#[target_feature(enable = "avx,fma")]
+pub(super) unsafe fn sum_squares_x86_fma(values: &[f32], acc: &mut [f32; 8]) {
+    let mut sum = _mm256_loadu_ps(acc.as_ptr());
+    let mut chunks = values.chunks_exact(8);
+    for chunk in &mut chunks {
+        let vec = _mm256_loadu_ps(chunk.as_ptr());
+        sum = _mm256_fmadd_ps(vec, vec, sum);
+    }
+    _mm256_storeu_ps(acc.as_mut_ptr(), sum);
+    // The squares are exact, so this rounds the same as the fused add.
+    for (acc, &x) in acc.iter_mut().zip(chunks.remainder()) {
+        *acc += x * x;
+    }
+}
@@ -0,0 +1,142 @@
+//! `const` conversions of fixed-size arrays of [`struct@f16`] and
+//! [`struct@bf16`] values.
+//!
+//! For small arrays, such as a 3D vector or a 4×4 matrix, these avoid the
+//! overhead of the slice conversions of
+//! [`HalfFloatSliceExt`][crate::HalfFloatSliceExt], and since they are `const`,
+//! they can be used to define constant vectors and lookup tables. They use the
+//! same software conversions as [`f16::from_f32_const`] and
+//! [`f16::to_f32_const`], so the results are identical to converting each
+//! element, and, at runtime, they do not use hardware conversions.
+//!
+//! [`f16::from_f32_const`]: crate::f16::from_f32_const
+//! [`f16::to_f32_const`]: crate::f16::to_f32_const
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use float16::*;
+//! use float16::convert::{f16_array_to_f32, f32_array_to_f16};
+//!
+//! const UP: [f16; 3] = f32_array_to_f16([0.0, 1.0, 0.0]);
+//! assert_eq!(UP, [f16::ZERO, f16::ONE, f16::ZERO]);
+//! assert_eq!(f16_array_to_f32(UP), [0.0, 1.0, 0.0]);
+//! ```
+
+use crate::{bf16, f16};
+
+/// Converts an array of [`struct@f16`] values to [`f32`] values.
+///
+/// This conversion is lossless, as with [`f16::to_f32_const`].
+///
+/// [`f16::to_f32_const`]: crate::f16::to_f32_const
+#[inline]
+#[must_use]
+pub const fn f16_array_to_f32<const N: usize>(src: [f16; N]) -> [f32; N] {
+    let mut dst = [0f32; N];
+    let mut i = 0;
+    while i < N {
+        dst[i] = src[i].to_f32_const();
+        i += 1;
+    }
+    dst
+}
+
+/// Converts an array of [`f32`] values to [`struct@f16`] values.
+///
+/// Each value is rounded to the nearest [`struct@f16`] value, as with
+/// [`f16::from_f32_const`].
+///
+/// [`f16::from_f32_const`]: crate::f16::from_f32_const
+#[inline]
+#[must_use]
+pub const fn f32_array_to_f16<const N: usize>(src: [f32; N]) -> [f16; N] {
+    let mut dst = [f16::ZERO; N];
+    let mut i = 0;
+    while i < N {
+        dst[i] = f16::from_f32_const(src[i]);
+        i += 1;
+    }
+    dst
+}
+
+/// Converts an array of [`struct@bf16`] values to [`f32`] values.
+///
+/// This conversion is lossless, as with [`bf16::to_f32_const`].
+///
+/// [`bf16::to_f32_const`]: crate::bf16::to_f32_const
+#[inline]
+#[must_use]
+pub const fn bf16_array_to_f32<const N: usize>(src: [bf16; N]) -> [f32; N] {
+    let mut dst = [0f32; N];
+    let mut i = 0;
+    while i < N {
+        dst[i] = src[i].to_f32_const();
+        i += 1;
+    }
+    dst
+}
+
+/// Converts an array of [`f32`] values to [`struct@bf16`] values.
+///
+/// Each value is rounded to the nearest [`struct@bf16`] value, as with
+/// [`bf16::from_f32_const`].
+///
+/// [`bf16::from_f32_const`]: crate::bf16::from_f32_const
+#[inline]
+#[must_use]
+pub const fn f32_array_to_bf16<const N: usize>(src: [f32; N]) -> [bf16; N] {
+    let mut dst = [bf16::ZERO; N];
+    let mut i = 0;
+    while i < N {
+        dst[i] = bf16::from_f32_const(src[i]);
+        i += 1;
+    }
+    dst
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const IDENTITY: [f16; 16] = f32_array_to_f16([
+        1., 0., 0., 0., //
+        0., 1., 0., 0., //
+        0., 0., 1., 0., //
+        0., 0., 0., 1.,
+    ]);
+
+    const WIDE: [f32; 16] = f16_array_to_f32(IDENTITY);
+
+    #[test]
+    fn test_const() {
+        for (i, (&x, &y)) in IDENTITY.iter().zip(&WIDE).enumerate() {
+            let expected = if i % 5 == 0 {
+                1.
+            } else {
+                0.
+            };
+            assert_eq!(x.to_f32(), expected);
+            assert_eq!(y, expected);
+        }
+        const EMPTY: [bf16; 0] = f32_array_to_bf16([]);
+        assert_eq!(bf16_array_to_f32(EMPTY).len(), 0);
+    }
+
+    #[test]
+    fn test_matches_scalar() {
+        let src = [0.1f32, -65520., 1e-8, f32::NAN, -0., f32::INFINITY, f32::MAX, 1.0 + 1.0 / 256.];
+        let halves = f32_array_to_f16(src);
+        let bfloats = f32_array_to_bf16(src);
+        for (i, &x) in src.iter().enumerate() {
+            assert_eq!(halves[i].to_bits(), f16::from_f32(x).to_bits(), "{}", x);
+            assert_eq!(bfloats[i].to_bits(), bf16::from_f32(x).to_bits(), "{}", x);
+        }
+        let back = f16_array_to_f32(halves);
+        let wide = bf16_array_to_f32(bfloats);
+        for i in 0..src.len() {
+            assert_eq!(back[i].to_bits(), halves[i].to_f32().to_bits());
+            assert_eq!(wide[i].to_bits(), bfloats[i].to_f32().to_bits());
+        }
+    }
+}
@@ -0,0 +1,293 @@
+//! Correctly rounded parsing of decimal strings to half-precision bits.
+//!
+//! Parsing to a wider float and then rounding rounds twice, which is incorrect
+//! when the first rounding lands exactly halfway between two half-precision
+//! values. Through [`f32`], this happens for decimals with as few as 9
+//! significant digits, such as those just below the midpoint between
+//! [`bf16::MAX`] and `2^128`, which then overflow to infinity. Every midpoint
+//! is exactly an [`f64`] value, so parsing to [`f64`] is only incorrect when
+//! the result is exactly a midpoint. In that rare case, the decimal string is
+//! compared exactly against the midpoint with big integers, which decides the
+//! rounding direction.
+//!
+//! [`bf16::MAX`]: crate::bf16::MAX
+
+use core::cmp::Ordering;
+use core::num::ParseFloatError;
+use core::str::FromStr;
+
+use crate::minifloat::MiniFloatSpec;
+
+/// Number of 32-bit limbs in the big integers.
+///
+/// This holds 1280 bits, and every comparison needs at most about 950: a
+/// midpoint has at most 143 fractional bits, and the decimal has at most
+/// [`MAX_DIGITS`] digits, scaled by a power of 10 that makes it close to the
+/// midpoint.
+const LIMBS: usize = 40;
+
+/// The number of significant digits compared exactly.
+///
+/// Every midpoint has fewer than 110 significant digits, so any digits after
+/// these are only needed to know if the decimal is above the midpoint.
+const MAX_DIGITS: usize = 200;
+
+/// A fixed-width unsigned big integer, with the least significant limb first.
+#[derive(Clone, Copy)]
+struct Big([u32; LIMBS]);
+
+impl Big {
+    fn new(value: u64) -> Big {
+        let mut limbs = [0; LIMBS];
+        limbs[0] = value as u32;
+        limbs[1] = (value >> 32) as u32;
+        Big(limbs)
+    }
+
+    /// Computes `self * mul + add`.
+    fn mul_add(&mut self, mul: u32, add: u32) {
+        let mut carry = add as u64;
+        for limb in self.0.iter_mut() {
+            let value = *limb as u64 * mul as u64 + carry;
+            *limb = value as u32;
+            carry = value >> 32;
+        }
+        debug_assert_eq!(carry, 0);
+    }
+
+    /// Computes `self * 10^exp`.
+    fn mul_pow10(&mut self, mut exp: u64) {
+        while exp >= 9 {
+            self.mul_add(1_000_000_000, 0);
+            exp -= 9;
+        }
+        if exp > 0 {
+            self.mul_add(10u32.pow(exp as u32), 0);
+        }
+    }
+
+    /// Computes `self * 2^exp`.
+    fn shl(&mut self, exp: u64) {
+        let (limbs, bits) = ((exp / 32) as usize, (exp % 32) as u32);
+        debug_assert!(limbs < LIMBS);
+        if limbs > 0 {
+            self.0.copy_within(..LIMBS - limbs, limbs);
+            self.0[..limbs].fill(0);
+        }
+        if bits > 0 {
+            self.mul_add(1 << bits, 0);
+        }
+    }
+
+    fn compare(&self, other: &Big) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+/// Compares the magnitude of a decimal string, which has already been parsed
+/// as a finite, non-zero [`f64`], with the exact value of `mid`.
+fn compare(src: &str, mid: f64) -> Ordering {
+    let src = src.trim_start_matches(['+', '-']);
+    let (digits, exp) = match src.find(['e', 'E']) {
+        Some(index) => (&src[..index], &src[index + 1..]),
+        None => (src, ""),
+    };
+
+    // The decimal is `value * 10^scale`, with any digits after the first
+    // `MAX_DIGITS` significant digits only recorded by `sticky`.
+    let mut value = Big::new(0);
+    let (mut count, mut scale, mut sticky, mut fraction) = (0, 0i64, false, false);
+    for c in digits.chars() {
+        if c == '.' {
+            fraction = true;
+            continue;
+        }
+        let digit = c.to_digit(10).unwrap_or(0);
+        if count == 0 && digit == 0 {
+            scale -= fraction as i64;
+        } else if count < MAX_DIGITS {
+            value.mul_add(10, digit);
+            count += 1;
+            scale -= fraction as i64;
+        } else {
+            sticky |= digit != 0;
+            scale += !fraction as i64;
+        }
+    }
+    let (negative, exp) = match exp.strip_prefix('-') {
+        Some(exp) => (true, exp),
+        None => (false, exp.trim_start_matches('+')),
+    };
+    let exp =
+        exp.bytes().fold(0i64, |acc, b| acc.saturating_mul(10).saturating_add((b - b'0') as i64));
+    let scale = if negative {
+        scale.saturating_sub(exp)
+    } else {
+        scale.saturating_add(exp)
+    };
+
+    // The midpoint is `man * 2^exp`.
+    let bits = mid.to_bits();
+    let biased = (bits >> 52) as i64;
+    let mut other = Big::new((bits & 0x000F_FFFF_FFFF_FFFF) | (1 << 52));
+    let exp = biased - 1075;
+
+    if scale >= 0 {
+        value.mul_pow10(scale as u64);
+    } else {
+        other.mul_pow10(scale.unsigned_abs());
+    }
+    if exp >= 0 {
+        other.shl(exp as u64);
+    } else {
+        value.shl(exp.unsigned_abs());
+    }
+    match value.compare(&other) {
+        Ordering::Equal if sticky => Ordering::Greater,
+        ordering => ordering,
+    }
+}
+
+/// Parses a decimal string to the bits of the nearest value with `exp_bits`
+/// exponent bits and `man_bits` mantissa bits, with ties to even.
+///
+/// This accepts the same strings as [`f64::from_str`], and rounds exactly
+/// once, so values too large for the format overflow to ±∞ and values too
+/// small round to subnormals or ±0.
+pub(crate) fn parse_decimal(
+    src: &str,
+    exp_bits: u32,
+    man_bits: u32,
+) -> Result<u16, ParseFloatError> {
+    let value = f64::from_str(src)?;
+    let spec = MiniFloatSpec::new(exp_bits, man_bits);
+    let rounded = spec.encode_f64(value) as u16;
+    if !value.is_finite() || value == 0. {
+        return Ok(rounded);
+    }
+
+    // Find the two values around the value, where the value above the
+    // largest finite value is one ulp above it, as if the exponent were not
+    // limited.
+    let sign = rounded & 0x8000;
+    let mag = rounded & 0x7FFF;
+    let abs = value.abs();
+    let nearest = spec.to_f64(mag as u32);
+    if nearest == abs {
+        return Ok(rounded);
+    }
+    let (below, above) = if nearest < abs {
+        (mag, mag + 1)
+    } else {
+        (mag - 1, mag)
+    };
+    let below_value = spec.to_f64(below as u32);
+    let above_value = if spec.to_f64(above as u32).is_finite() {
+        spec.to_f64(above as u32)
+    } else {
+        below_value + (below_value - spec.to_f64(below as u32 - 1))
+    };
+    let mid = below_value + (above_value - below_value) / 2.;
+    if abs != mid {
+        return Ok(rounded);
+    }
+
+    // The value is a midpoint, so the decimal decides the rounding.
+    let mag = match compare(src, mid) {
+        Ordering::Less => below,
+        Ordering::Greater => above,
+        Ordering::Equal if below & 1 == 0 => below,
+        Ordering::Equal => above,
+    };
+    Ok(sign | mag)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn bf16(src: &str) -> u16 {
+        parse_decimal(src, 8, 7).unwrap()
+    }
+
+    fn f16(src: &str) -> u16 {
+        parse_decimal(src, 5, 10).unwrap()
+    }
+
+    /// The exact decimal value halfway between `bf16::MAX` and `2^128`.
+    const BF16_OVERFLOW: &str = "339617752923046005526922703901628039168";
+
+    /// The exact decimal value of `2^-134`, halfway between `0` and the
+    /// smallest `bf16` subnormal.
+    const BF16_HALF_SUBNORMAL: &str = "0.000000000000000000000000000000000000000045917748078995605780028770985243971789791623311409668808935613526500674197450280189514160156250";
+
+    #[test]
+    fn test_bf16_extremes() {
+        // The midpoint above the largest value rounds to even, which is
+        // infinity, and anything below it is the largest value.
+        assert_eq!(bf16(BF16_OVERFLOW), 0x7F80);
+        assert_eq!(bf16("339617752923046005526922703901628039167"), 0x7F7F);
+        assert_eq!(bf16("339617752923046005526922703901628039169"), 0x7F80);
+        assert_eq!(bf16("339617752923046005526922703901628039167.999999"), 0x7F7F);
+        assert_eq!(bf16("3.3961775292304600552692270390162803916e38"), 0x7F7F);
+        assert_eq!(bf16("-3.3961775292304600552692270390162803916e38"), 0xFF7F);
+        assert_eq!(bf16("-3.39617752923046005526922703901628039168E+38"), 0xFF80);
+        assert_eq!(bf16("3.39617752e38"), 0x7F7F);
+        assert_eq!(bf16("3.39617753e38"), 0x7F80);
+        assert_eq!(bf16("3.5e38"), 0x7F80);
+        assert_eq!(bf16("1e39"), 0x7F80);
+
+        // Half the smallest subnormal rounds to even, which is zero.
+        assert_eq!(bf16(BF16_HALF_SUBNORMAL), 0x0000);
+        let above = [BF16_HALF_SUBNORMAL, "1"].concat();
+        assert_eq!(bf16(&above), 0x0001);
+        let below = BF16_HALF_SUBNORMAL.replace("6250", "6249");
+        assert_eq!(bf16(&below), 0x0000);
+        let far = [BF16_HALF_SUBNORMAL, &"0".repeat(400), "1"].concat();
+        assert_eq!(bf16(&far), 0x0001);
+        assert_eq!(bf16(&["-", &far].concat()), 0x8001);
+        assert_eq!(bf16("4.5917748078995605780028770985243971789791623311409668808935613526500674197450280189514160156250e-41"), 0x0000);
+        assert_eq!(bf16("4.59177480789956057800287709852439717897916233114096688089356135265006741974502801895141601562501e-41"), 0x0001);
+
+        // Three halves of the smallest subnormal rounds to even, two.
+        let three_halves = "1.3775324423698681734008631295573191536937486993422900642680684057950202259235084056854248046875e-40";
+        assert_eq!(bf16(three_halves), 0x0002);
+        assert_eq!(bf16(&three_halves.replace("875e", "874e")), 0x0001);
+        assert_eq!(bf16("1e-50"), 0x0000);
+    }
+
+    #[test]
+    fn test_midpoints() {
+        // Halfway between 1 and the next value, and just above it, which
+        // rounds to the midpoint in both `f32` and `f64`.
+        assert_eq!(bf16("1.00390625"), 0x3F80);
+        assert_eq!(bf16("1.0039062500000000000000001"), 0x3F81);
+        assert_eq!(bf16("1.0039062499999999999999999"), 0x3F80);
+        assert_eq!(bf16("-1.0039062500000000000000001"), 0xBF81);
+        assert_eq!(bf16("100390625000000000000000001e-26"), 0x3F81);
+        assert_eq!(bf16("1.01171875"), 0x3F82);
+        assert_eq!(bf16("0.0000000000101171875e11"), 0x3F82);
+
+        // The same for f16, at the largest value and the subnormals.
+        assert_eq!(f16("65520"), 0x7C00);
+        assert_eq!(f16("65519.999999999999999999"), 0x7BFF);
+        assert_eq!(f16("0.0000000298023223876953125"), 0x0000);
+        assert_eq!(f16("0.0000000298023223876953125000001"), 0x0001);
+        assert_eq!(f16("1.00048828125000000000000001"), 0x3C01);
+    }
+
+    #[test]
+    fn test_special() {
+        assert_eq!(bf16("0"), 0x0000);
+        assert_eq!(bf16("-0.0"), 0x8000);
+        assert_eq!(bf16("inf"), 0x7F80);
+        assert_eq!(bf16("-infinity"), 0xFF80);
+        assert_eq!(bf16("NaN") & 0x7FC0, 0x7FC0);
+        assert_eq!(bf16("1.5"), 0x3FC0);
+        assert_eq!(bf16(".5"), 0x3F00);
+        assert_eq!(bf16("+2."), 0x4000);
+        assert!(parse_decimal("", 8, 7).is_err());
+        assert!(parse_decimal("1.5x", 8, 7).is_err());
+        assert!(parse_decimal("e5", 5, 10).is_err());
+    }
+}
@@ -1,4 +1,4 @@
-//! Error type for numeric conversion functions.
+//! Error types for numeric conversion and parsing functions.
 
 use core::fmt;
 
@@ -12,3 +12,146 @@ impl fmt::Display for TryFromFloatError {
         fmt::Display::fmt(msg, f)
     }
 }
+
+/// The error type returned when converting a [`struct@f16`] or
+/// [`struct@bf16`] value to an integer type fails.
+///
+/// The [`kind`][Self::kind] is the reason for the failure, so callers can,
+/// for example, round a value with a fractional part and try again.
+///
+/// [`struct@f16`]: crate::f16
+/// [`struct@bf16`]: crate::bf16
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryFromHalfError(pub(crate) TryFromHalfErrorKind);
+
+impl TryFromHalfError {
+    /// Returns the reason the conversion failed.
+    #[inline]
+    #[must_use]
+    pub const fn kind(&self) -> TryFromHalfErrorKind {
+        self.0
+    }
+}
+
+impl fmt::Display for TryFromHalfError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self.0 {
+            TryFromHalfErrorKind::Nan => "cannot convert NaN to an integral type",
+            TryFromHalfErrorKind::Infinite => {
+                "cannot convert an infinite value to an integral type"
+            },
+            TryFromHalfErrorKind::OutOfRange => "out of range integral type conversion attempted",
+            TryFromHalfErrorKind::Fractional => {
+                "cannot convert a value with a fractional part to an integral type"
+            },
+        };
+        fmt::Display::fmt(msg, f)
+    }
+}
+
+/// The reason a conversion of a [`struct@f16`] or [`struct@bf16`] value to
+/// an integer type failed, returned by [`TryFromHalfError::kind`].
+///
+/// A value that is out of range is reported as
+/// [`OutOfRange`][Self::OutOfRange] even if it also has a fractional part, so
+/// a value with a [`Fractional`][Self::Fractional] error always fits once it
+/// is rounded toward zero.
+///
+/// [`struct@f16`]: crate::f16
+/// [`struct@bf16`]: crate::bf16
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TryFromHalfErrorKind {
+    /// The value is NaN.
+    Nan,
+    /// The value is positive or negative infinity.
+    Infinite,
+    /// The integral part of the value is outside the range of the type.
+    OutOfRange,
+    /// The value is within the range of the type, but is not an integer.
+    Fractional,
+}
+
+/// The error type returned when parsing a hexadecimal floating point literal
+/// fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseHalfError(pub(crate) ());
+
+impl fmt::Display for ParseHalfError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = "invalid hexadecimal float literal";
+        fmt::Display::fmt(msg, f)
+    }
+}
+
+/// The error type returned when a byte slice does not have an even length, so
+/// it cannot be split into 16-bit values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OddLength(pub(crate) ());
+
+impl fmt::Display for OddLength {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = "byte slice length is not a multiple of 2";
+        fmt::Display::fmt(msg, f)
+    }
+}
+
+/// The error type returned when the shape and strides of a
+/// [`StridedView`] or [`StridedViewMut`] do not describe a valid layout of
+/// the data.
+///
+/// The [`kind`][Self::kind] is the reason the layout is invalid.
+///
+/// [`StridedView`]: crate::StridedView
+/// [`StridedViewMut`]: crate::StridedViewMut
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StridedLayoutError(pub(crate) StridedLayoutErrorKind);
+
+impl StridedLayoutError {
+    /// Returns the reason the layout is invalid.
+    #[inline]
+    #[must_use]
+    pub const fn kind(&self) -> StridedLayoutErrorKind {
+        self.0
+    }
+}
+
+impl fmt::Display for StridedLayoutError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self.0 {
+            StridedLayoutErrorKind::RankMismatch => "shape and strides have different lengths",
+            StridedLayoutErrorKind::Overflow => "strided layout size overflows usize",
+            StridedLayoutErrorKind::OutOfBounds => {
+                "strided layout indexes past the end of the data"
+            },
+            StridedLayoutErrorKind::Overlapping => {
+                "strided layout may map two indices to the same element"
+            },
+        };
+        fmt::Display::fmt(msg, f)
+    }
+}
+
+/// The reason the layout of a [`StridedView`] or [`StridedViewMut`] is
+/// invalid, returned by [`StridedLayoutError::kind`].
+///
+/// [`StridedView`]: crate::StridedView
+/// [`StridedViewMut`]: crate::StridedViewMut
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StridedLayoutErrorKind {
+    /// The shape and strides have different lengths.
+    RankMismatch,
+    /// The number of elements, or the offset of an element, does not fit in
+    /// a [`usize`].
+    Overflow,
+    /// The offset of the last element is past the end of the data.
+    OutOfBounds,
+    /// The strides of a mutable view are not nested, so two indices may refer
+    /// to the same element.
+    Overlapping,
+}
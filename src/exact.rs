@@ -0,0 +1,220 @@
+//! Exact sums of products of half-precision values, rounded once.
+//!
+//! Every product of two [`struct@f16`] or [`struct@bf16`] values is exactly
+//! representable as a small integer times a power of two, and so is any sum of
+//! them in a wide enough fixed-point accumulator. These only use integer
+//! arithmetic, so they can be `const`, and round the exact result once with
+//! [`round_to_half`].
+
+use crate::fixed::round_to_half;
+
+/// Number of 64-bit limbs in the accumulator.
+const LIMBS: usize = 10;
+
+/// Exponent of the least significant bit of the accumulator.
+///
+/// This is below the lowest bit of a product of two [`struct@bf16`]
+/// subnormals, `2^-266`, and the accumulator holds a product of two of the
+/// largest values, which is below `2^256`, with room for the sign and carries.
+const ACC_EXP: i64 = -320;
+
+/// A two's complement fixed-point accumulator with a least significant bit of
+/// `2^ACC_EXP`.
+type Acc = [u64; LIMBS];
+
+/// The components of a finite value as `(negative, man, exp)`, where the
+/// magnitude is `man * 2^exp`.
+type Parts = (bool, u64, i64);
+
+/// Returns if the bits are NaN or infinite.
+#[inline]
+const fn is_special(bits: u16, exp_bits: u32, man_bits: u32) -> bool {
+    let exp_mask = (((1u32 << exp_bits) - 1) << man_bits) as u16;
+    bits & exp_mask == exp_mask
+}
+
+/// Returns if the bits are NaN.
+#[inline]
+const fn is_nan(bits: u16, exp_bits: u32, man_bits: u32) -> bool {
+    let man_mask = ((1u32 << man_bits) - 1) as u16;
+    is_special(bits, exp_bits, man_bits) && bits & man_mask != 0
+}
+
+/// Decodes the bits of a finite value.
+#[inline]
+const fn decode(bits: u16, exp_bits: u32, man_bits: u32) -> Parts {
+    let bias = (1i64 << (exp_bits - 1)) - 1;
+    let man_mask = ((1u32 << man_bits) - 1) as u16;
+    let biased = ((bits & 0x7FFF) >> man_bits) as i64;
+    let negative = bits & 0x8000 != 0;
+    if biased == 0 {
+        (negative, (bits & man_mask) as u64, 1 - bias - man_bits as i64)
+    } else {
+        (negative, ((bits & man_mask) | (man_mask + 1)) as u64, biased - bias - man_bits as i64)
+    }
+}
+
+/// Returns the exact product of two finite values.
+#[inline]
+const fn mul(x: Parts, y: Parts) -> Parts {
+    (x.0 != y.0, x.1 * y.1, x.2 + y.2)
+}
+
+/// Adds the value to the accumulator, wrapping on overflow.
+const fn add(mut acc: Acc, value: Parts) -> Acc {
+    let (negative, man, exp) = value;
+    if man == 0 {
+        return acc;
+    }
+    let pos = (exp - ACC_EXP) as usize;
+    let (limb, offset) = (pos / 64, pos % 64);
+    let lo = man << offset;
+    let hi = if offset == 0 {
+        0
+    } else {
+        man >> (64 - offset)
+    };
+
+    let mut i = limb;
+    let mut carry = false;
+    while i < LIMBS {
+        let word = if i == limb {
+            lo
+        } else if i == limb + 1 {
+            hi
+        } else {
+            0
+        };
+        if !carry && word == 0 && i > limb + 1 {
+            break;
+        }
+        if negative {
+            let (diff, borrow1) = acc[i].overflowing_sub(word);
+            let (diff, borrow2) = diff.overflowing_sub(carry as u64);
+            acc[i] = diff;
+            carry = borrow1 || borrow2;
+        } else {
+            let (sum, carry1) = acc[i].overflowing_add(word);
+            let (sum, carry2) = sum.overflowing_add(carry as u64);
+            acc[i] = sum;
+            carry = carry1 || carry2;
+        }
+        i += 1;
+    }
+    acc
+}
+
+/// Rounds the accumulator to the nearest value with `exp_bits` exponent bits
+/// and `man_bits` mantissa bits, with ties to even. An exact zero is `+0`.
+const fn round(mut acc: Acc, exp_bits: u32, man_bits: u32) -> u16 {
+    // Take the magnitude of the two's complement value.
+    let negative = acc[LIMBS - 1] >> 63 != 0;
+    if negative {
+        let mut i = 0;
+        let mut carry = true;
+        while i < LIMBS {
+            let (value, overflow) = (!acc[i]).overflowing_add(carry as u64);
+            acc[i] = value;
+            carry = overflow;
+            i += 1;
+        }
+    }
+
+    // Find the most significant limb, then the 64 bits below its highest bit.
+    let mut top = LIMBS;
+    while top > 0 && acc[top - 1] == 0 {
+        top -= 1;
+    }
+    if top == 0 {
+        return 0;
+    }
+    let top = top - 1;
+    let msb = top as i64 * 64 + 63 - acc[top].leading_zeros() as i64;
+    let (man, exp, sticky) = if msb < 64 {
+        (acc[0], ACC_EXP, false)
+    } else {
+        let low = (msb - 63) as usize;
+        let (limb, offset) = (low / 64, low % 64);
+        let mut sticky = if offset == 0 {
+            false
+        } else {
+            acc[limb] & ((1u64 << offset) - 1) != 0
+        };
+        let mut i = 0;
+        while i < limb {
+            sticky |= acc[i] != 0;
+            i += 1;
+        }
+        let man = if offset == 0 {
+            acc[limb]
+        } else {
+            (acc[limb] >> offset) | (acc[limb + 1] << (64 - offset))
+        };
+        (man, low as i64 + ACC_EXP, sticky)
+    };
+
+    let sign = if negative {
+        0x8000
+    } else {
+        0
+    };
+    sign | round_to_half(man, exp, sticky, exp_bits, man_bits)
+}
+
+/// Linearly interpolates from `a` to `b` by `t`, computing `a + t * (b - a)`
+/// exactly and rounding once.
+///
+/// `t` of exactly `0` or `1` returns `a` or `b` unchanged. Otherwise, NaN or
+/// infinite arguments return NaN.
+pub(crate) const fn lerp(a: u16, b: u16, t: u16, exp_bits: u32, man_bits: u32) -> u16 {
+    let bias = (1u16 << (exp_bits - 1)) - 1;
+    let nan = ((((1u32 << exp_bits) - 1) << man_bits) | (1 << (man_bits - 1))) as u16;
+    let nan_arg = is_nan(a, exp_bits, man_bits) || is_nan(b, exp_bits, man_bits);
+    if nan_arg || is_special(t, exp_bits, man_bits) {
+        return nan;
+    }
+    if t & 0x7FFF == 0 {
+        return a;
+    }
+    if t == bias << man_bits {
+        return b;
+    }
+    if is_special(a, exp_bits, man_bits) || is_special(b, exp_bits, man_bits) {
+        return nan;
+    }
+
+    let a = decode(a, exp_bits, man_bits);
+    let b = decode(b, exp_bits, man_bits);
+    let t = decode(t, exp_bits, man_bits);
+    let ta = mul(t, a);
+    let mut acc = [0; LIMBS];
+    acc = add(acc, a);
+    acc = add(acc, mul(t, b));
+    acc = add(acc, (!ta.0, ta.1, ta.2));
+    round(acc, exp_bits, man_bits)
+}
+
+/// Returns `|a - b|`, computed exactly and rounded once.
+///
+/// NaN arguments, and infinities of the same sign, return NaN. Otherwise, an
+/// infinite argument returns infinity.
+pub(crate) const fn abs_diff(a: u16, b: u16, exp_bits: u32, man_bits: u32) -> u16 {
+    let inf = (((1u32 << exp_bits) - 1) << man_bits) as u16;
+    let nan = inf | (1 << (man_bits - 1));
+    if is_nan(a, exp_bits, man_bits) || is_nan(b, exp_bits, man_bits) {
+        return nan;
+    }
+    if is_special(a, exp_bits, man_bits) || is_special(b, exp_bits, man_bits) {
+        return if a == b {
+            nan
+        } else {
+            inf
+        };
+    }
+
+    let b = decode(b, exp_bits, man_bits);
+    let mut acc = [0; LIMBS];
+    acc = add(acc, decode(a, exp_bits, man_bits));
+    acc = add(acc, (!b.0, b.1, b.2));
+    round(acc, exp_bits, man_bits) & 0x7FFF
+}
@@ -0,0 +1,317 @@
+//! Conversions between binary fixed-point integers and half-precision bits.
+//!
+//! These are shared by [`struct@f16`] and [`struct@bf16`], parameterized by
+//! the number of exponent and mantissa bits of the format, and only use
+//! integer arithmetic so they can be `const` and round exactly once. The
+//! rounding is also shared by parsing, which produces the same exact binary
+//! values.
+
+use crate::error::TryFromHalfErrorKind;
+
+/// Rounds `value * 2^-fractional_bits` to the nearest value with `exp_bits`
+/// exponent bits and `man_bits` mantissa bits, with ties to even.
+///
+/// Values too large for the format overflow to ±∞, and values too small
+/// underflow to subnormals or ±0, keeping the sign of `value`. Zero itself
+/// is `+0`.
+#[inline]
+pub(crate) const fn fixed_to_half(
+    value: i64,
+    fractional_bits: u32,
+    exp_bits: u32,
+    man_bits: u32,
+) -> u16 {
+    let sign: u16 = if value < 0 {
+        0x8000
+    } else {
+        0
+    };
+    let mag = value.unsigned_abs();
+    if mag == 0 {
+        return 0;
+    }
+    sign | round_to_half(mag, -(fractional_bits as i64), false, exp_bits, man_bits)
+}
+
+/// Rounds the positive value `mag * 2^exp` to the bits of the nearest value
+/// with `exp_bits` exponent bits and `man_bits` mantissa bits, with ties to
+/// even.
+///
+/// This is shared by every format, including the
+/// [`minifloat`][crate::minifloat] formats, which can have up to 63 bits
+/// without the sign. `sticky` is set if there are non-zero bits below the least
+/// significant bit of `mag`, which must then have more significant bits than
+/// the format, so these bits are always below the rounding position. Values too
+/// large for the format overflow to +∞, and values too small underflow to
+/// subnormals or +0.
+#[inline]
+pub(crate) const fn round_to_float(
+    mag: u64,
+    exp: i64,
+    sticky: bool,
+    exp_bits: u32,
+    man_bits: u32,
+) -> u64 {
+    if mag == 0 {
+        return 0;
+    }
+
+    let bias = (1i64 << (exp_bits - 1)) - 1;
+    let min_exp = 1 - bias;
+    let inf = ((1u64 << exp_bits) - 1) << man_bits;
+
+    // Unbiased exponent of the most significant bit of the value.
+    let msb = 63 - mag.leading_zeros() as i64;
+    let value_exp = msb + exp;
+    if value_exp > bias {
+        return inf;
+    }
+
+    // Normals keep `man_bits + 1` significant bits, while subnormals have a
+    // fixed exponent, so find the exponent of the result and how many low
+    // bits of `mag` need to be shifted out for it.
+    let value_exp = if value_exp < min_exp {
+        min_exp
+    } else {
+        value_exp
+    };
+    let shift = value_exp - man_bits as i64 - exp;
+    let man = if shift <= 0 {
+        mag << -shift
+    } else if shift > 64 {
+        0
+    } else if shift == 64 {
+        // The value is at most the smallest subnormal, so is only rounded up
+        // above the tie with an even 0.
+        let half = 1u64 << 63;
+        (mag > half || (mag == half && sticky)) as u64
+    } else {
+        let man = mag >> shift;
+        let rem = mag & ((1u64 << shift) - 1);
+        let half = 1u64 << (shift - 1);
+        if rem > half || (rem == half && (sticky || man & 1 != 0)) {
+            man + 1
+        } else {
+            man
+        }
+    };
+
+    // `man` includes the hidden bit for normals, so this handles subnormals
+    // and carries from rounding into the exponent, including to infinity.
+    let bits = (((value_exp + bias - 1) as u64) << man_bits) + man;
+    if bits >= inf {
+        inf
+    } else {
+        bits
+    }
+}
+
+/// Rounds the positive value `mag * 2^exp` to the nearest 16-bit value, as
+/// [`round_to_float`].
+#[inline]
+pub(crate) const fn round_to_half(
+    mag: u64,
+    exp: i64,
+    sticky: bool,
+    exp_bits: u32,
+    man_bits: u32,
+) -> u16 {
+    round_to_float(mag, exp, sticky, exp_bits, man_bits) as u16
+}
+
+/// Converts the bits of a value with `exp_bits` exponent bits and `man_bits`
+/// mantissa bits to a fixed-point integer with `fractional_bits` fractional
+/// bits, rounding to the nearest integer with ties to even.
+///
+/// Returns `None` if the value is NaN, infinite or does not fit in an [`i64`].
+#[inline]
+pub(crate) const fn half_to_fixed(
+    bits: u16,
+    fractional_bits: u32,
+    exp_bits: u32,
+    man_bits: u32,
+) -> Option<i64> {
+    let bias = (1i64 << (exp_bits - 1)) - 1;
+    let exp_mask = (((1u32 << exp_bits) - 1) << man_bits) as u16;
+    let man_mask = ((1u32 << man_bits) - 1) as u16;
+
+    let biased = ((bits & exp_mask) >> man_bits) as i64;
+    if bits & exp_mask == exp_mask {
+        return None;
+    }
+
+    // Value is `man * 2^exp`, where subnormals have the minimum exponent.
+    let (man, exp) = if biased == 0 {
+        ((bits & man_mask) as u64, 1 - bias - man_bits as i64)
+    } else {
+        (((bits & man_mask) | (man_mask + 1)) as u64, biased - bias - man_bits as i64)
+    };
+    if man == 0 {
+        return Some(0);
+    }
+
+    let shift = exp + fractional_bits as i64;
+    let mag = if shift >= 0 {
+        if shift >= 64 || (man.leading_zeros() as i64) < shift {
+            return None;
+        }
+        man << shift
+    } else if shift <= -64 {
+        0
+    } else {
+        let shift = -shift;
+        let mag = man >> shift;
+        let rem = man & ((1u64 << shift) - 1);
+        let half = 1u64 << (shift - 1);
+        if rem > half || (rem == half && mag & 1 != 0) {
+            mag + 1
+        } else {
+            mag
+        }
+    };
+
+    if bits & 0x8000 != 0 {
+        if mag > i64::MIN.unsigned_abs() {
+            None
+        } else {
+            Some(0i64.wrapping_sub(mag as i64))
+        }
+    } else if mag > i64::MAX as u64 {
+        None
+    } else {
+        Some(mag as i64)
+    }
+}
+
+/// Converts the bits of a value with `exp_bits` exponent bits and `man_bits`
+/// mantissa bits to an integer as `(negative, magnitude)`, truncating toward
+/// zero.
+///
+/// Infinite values and magnitudes too large for a [`u128`] saturate to
+/// [`u128::MAX`], and NaN returns a magnitude of `0`.
+#[inline]
+pub(crate) const fn half_to_int_trunc(bits: u16, exp_bits: u32, man_bits: u32) -> (bool, u128) {
+    let bias = (1i64 << (exp_bits - 1)) - 1;
+    let exp_mask = (((1u32 << exp_bits) - 1) << man_bits) as u16;
+    let man_mask = ((1u32 << man_bits) - 1) as u16;
+    let negative = bits & 0x8000 != 0;
+
+    if bits & exp_mask == exp_mask {
+        if bits & man_mask != 0 {
+            return (false, 0);
+        }
+        return (negative, u128::MAX);
+    }
+
+    // Subnormals are always below one, so only normal values are non-zero.
+    let biased = ((bits & exp_mask) >> man_bits) as i64;
+    let man = ((bits & man_mask) | (man_mask + 1)) as u128;
+    let exp = biased - bias - man_bits as i64;
+    let mag = if biased == 0 || exp <= -(man_bits as i64 + 1) {
+        0
+    } else if exp < 0 {
+        man >> -exp
+    } else if exp >= 128 || (man.leading_zeros() as i64) < exp {
+        u128::MAX
+    } else {
+        man << exp
+    };
+    (negative, mag)
+}
+
+/// Converts the bits of a value with `exp_bits` exponent bits and `man_bits`
+/// mantissa bits to an integer as `(negative, magnitude, fractional)`,
+/// truncating toward zero, where `fractional` is set if the value is not an
+/// integer.
+///
+/// Magnitudes too large for a [`u128`] saturate to [`u128::MAX`], which is
+/// then out of range for every integer type. NaN and infinite values return
+/// an error.
+#[inline]
+pub(crate) const fn half_to_int_checked(
+    bits: u16,
+    exp_bits: u32,
+    man_bits: u32,
+) -> Result<(bool, u128, bool), TryFromHalfErrorKind> {
+    let bias = (1i64 << (exp_bits - 1)) - 1;
+    let exp_mask = (((1u32 << exp_bits) - 1) << man_bits) as u16;
+    let man_mask = ((1u32 << man_bits) - 1) as u16;
+
+    if bits & exp_mask == exp_mask {
+        if bits & man_mask != 0 {
+            return Err(TryFromHalfErrorKind::Nan);
+        }
+        return Err(TryFromHalfErrorKind::Infinite);
+    }
+
+    // Only the bits below the lowest integer bit can be fractional, and
+    // every non-zero subnormal is below one.
+    let biased = ((bits & exp_mask) >> man_bits) as i64;
+    let fractional = if biased == 0 {
+        bits & man_mask != 0
+    } else {
+        let shift = bias + man_bits as i64 - biased;
+        if shift > man_bits as i64 {
+            true
+        } else {
+            shift > 0 && bits & ((1u16 << shift) - 1) != 0
+        }
+    };
+    let (negative, mag) = half_to_int_trunc(bits, exp_bits, man_bits);
+    Ok((negative, mag, fractional))
+}
+
+/// Rounds an integer as `(negative, magnitude)` to the bits of the nearest
+/// value with `exp_bits` exponent bits and `man_bits` mantissa bits, with ties
+/// to even.
+///
+/// Integers too large for the format overflow to ±∞. Zero is always `+0`,
+/// since integers have no negative zero.
+#[cfg(feature = "num-traits")]
+#[inline]
+pub(crate) const fn int_to_half(negative: bool, mag: u128, exp_bits: u32, man_bits: u32) -> u16 {
+    if mag == 0 {
+        return 0;
+    }
+    // Keep the 64 most significant bits, which is more than any format, and
+    // fold the rest into the sticky bit.
+    let shift = 64u32.saturating_sub(mag.leading_zeros());
+    let sticky = mag & ((1u128 << shift) - 1) != 0;
+    let sign = if negative {
+        0x8000
+    } else {
+        0
+    };
+    sign | round_to_half((mag >> shift) as u64, shift as i64, sticky, exp_bits, man_bits)
+}
+
+/// Converts an integer as `(negative, magnitude)` to the bits of a value with
+/// `exp_bits` exponent bits and `man_bits` mantissa bits, if it is exactly
+/// representable.
+///
+/// Zero is always `+0`, since integers have no negative zero.
+#[inline]
+pub(crate) const fn int_to_half_exact(
+    negative: bool,
+    mag: u128,
+    exp_bits: u32,
+    man_bits: u32,
+) -> Option<u16> {
+    if mag == 0 {
+        return Some(0);
+    }
+    let bias = (1u32 << (exp_bits - 1)) - 1;
+    let leading = mag.leading_zeros();
+    let exp = 127 - leading;
+    if 128 - leading - mag.trailing_zeros() > man_bits + 1 || exp > bias {
+        return None;
+    }
+    // Shift the leading bit to the hidden bit and drop it.
+    let man = ((mag << leading) >> (127 - man_bits)) as u16 & ((1u16 << man_bits) - 1);
+    let sign = if negative {
+        0x8000
+    } else {
+        0
+    };
+    Some(sign | (((exp + bias) as u16) << man_bits) | man)
+}
@@ -0,0 +1,181 @@
+//! Hexadecimal floating point literals, such as `0x1.8p+1`.
+//!
+//! These are shared by [`struct@f16`] and [`struct@bf16`], parameterized by
+//! the number of exponent and mantissa bits of the format. Hexadecimal
+//! literals represent binary values exactly, so unlike decimal strings these
+//! round-trip every bit pattern other than NaN payloads.
+
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt::{self, Display, Formatter};
+
+use crate::error::ParseHalfError;
+use crate::fixed;
+
+/// Number of bits of the significand kept while parsing, before any further
+/// non-zero digits only set the sticky bit.
+const MAX_BITS: u32 = 60;
+
+/// Parses a hexadecimal floating point literal to the bits of a value with
+/// `exp_bits` exponent bits and `man_bits` mantissa bits, rounding to the
+/// nearest value with ties to even.
+///
+/// This accepts an optional sign, a `0x` or `0X` prefix, hexadecimal digits
+/// with an optional fractional part, and a mandatory binary exponent of a `p`
+/// or `P` followed by a signed decimal integer. Infinity and NaN are accepted
+/// as `inf`, `infinity` or `nan`, ignoring case.
+pub(crate) fn parse_hex(src: &str, exp_bits: u32, man_bits: u32) -> Result<u16, ParseHalfError> {
+    let bytes = src.as_bytes();
+    let (sign, bytes) = match bytes.first() {
+        Some(b'-') => (0x8000u16, &bytes[1..]),
+        Some(b'+') => (0, &bytes[1..]),
+        _ => (0, bytes),
+    };
+    let inf = (((1u32 << exp_bits) - 1) << man_bits) as u16;
+
+    if bytes.eq_ignore_ascii_case(b"inf") || bytes.eq_ignore_ascii_case(b"infinity") {
+        return Ok(sign | inf);
+    }
+    if bytes.eq_ignore_ascii_case(b"nan") {
+        return Ok(sign | inf | (1 << (man_bits - 1)));
+    }
+
+    let bytes = match bytes {
+        [b'0', b'x' | b'X', rest @ ..] => rest,
+        _ => return Err(ParseHalfError(())),
+    };
+
+    // Accumulate the significand, tracking the binary exponent of its least
+    // significant bit and whether any non-zero bits were dropped.
+    let mut mag = 0u64;
+    let mut exp = 0i64;
+    let mut sticky = false;
+    let mut digits = 0usize;
+    let mut fraction = false;
+    let mut index = 0;
+    while index < bytes.len() {
+        let byte = bytes[index];
+        let digit = match byte {
+            b'0'..=b'9' => byte - b'0',
+            b'a'..=b'f' => byte - b'a' + 10,
+            b'A'..=b'F' => byte - b'A' + 10,
+            b'.' if !fraction => {
+                fraction = true;
+                index += 1;
+                continue;
+            },
+            _ => break,
+        };
+        digits += 1;
+        if 64 - mag.leading_zeros() <= MAX_BITS {
+            mag = (mag << 4) | digit as u64;
+            if fraction {
+                exp -= 4;
+            }
+        } else {
+            sticky |= digit != 0;
+            if !fraction {
+                exp += 4;
+            }
+        }
+        index += 1;
+    }
+    if digits == 0 {
+        return Err(ParseHalfError(()));
+    }
+
+    let bytes = match &bytes[index..] {
+        [b'p' | b'P', rest @ ..] => rest,
+        _ => return Err(ParseHalfError(())),
+    };
+    let (negative, bytes) = match bytes.first() {
+        Some(b'-') => (true, &bytes[1..]),
+        Some(b'+') => (false, &bytes[1..]),
+        _ => (false, bytes),
+    };
+    if bytes.is_empty() {
+        return Err(ParseHalfError(()));
+    }
+    // Saturate large exponents, which overflow or underflow regardless.
+    let mut exponent = 0i64;
+    for &byte in bytes {
+        if !byte.is_ascii_digit() {
+            return Err(ParseHalfError(()));
+        }
+        exponent = (exponent * 10 + (byte - b'0') as i64).min(1 << 20);
+    }
+    if negative {
+        exponent = -exponent;
+    }
+
+    Ok(sign | fixed::round_to_half(mag, exp + exponent, sticky, exp_bits, man_bits))
+}
+
+/// Displays the bits of a value as a hexadecimal floating point literal.
+///
+/// This is returned by [`f16::to_hex`][crate::f16::to_hex] and
+/// [`bf16::to_hex`][crate::bf16::to_hex]. Normal values are written as
+/// `0x1.{fraction}p{exponent}`, subnormal values as
+/// `0x0.{fraction}p{minimum exponent}`, zeros as `0x0p+0`, and non-finite
+/// values as `inf` or `NaN`. Trailing zeros of the fraction are omitted.
+#[cfg(not(target_arch = "spirv"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HexFloat {
+    bits: u16,
+    exp_bits: u32,
+    man_bits: u32,
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl HexFloat {
+    #[inline]
+    pub(crate) const fn new(bits: u16, exp_bits: u32, man_bits: u32) -> HexFloat {
+        HexFloat {
+            bits,
+            exp_bits,
+            man_bits,
+        }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl Display for HexFloat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let man_mask = (1u16 << self.man_bits) - 1;
+        let max_exp = (1u16 << self.exp_bits) - 1;
+        let bias = (1i32 << (self.exp_bits - 1)) - 1;
+        let biased = (self.bits >> self.man_bits) & max_exp;
+        let man = self.bits & man_mask;
+
+        if biased == max_exp && man != 0 {
+            return f.write_str("NaN");
+        }
+        if self.bits & 0x8000 != 0 {
+            f.write_str("-")?;
+        }
+        if biased == max_exp {
+            return f.write_str("inf");
+        }
+        if biased == 0 && man == 0 {
+            return f.write_str("0x0p+0");
+        }
+
+        let (lead, exp) = if biased == 0 {
+            (0, 1 - bias)
+        } else {
+            (1, biased as i32 - bias)
+        };
+        write!(f, "0x{}", lead)?;
+        if man != 0 {
+            // Left-align the fraction to a whole number of hex digits.
+            let width = (self.man_bits + 3) / 4;
+            let mut fraction = (man as u32) << (width * 4 - self.man_bits);
+            let mut width = width as usize;
+            while fraction & 0xF == 0 {
+                fraction >>= 4;
+                width -= 1;
+            }
+            write!(f, ".{:01$x}", fraction, width)?;
+        }
+        write!(f, "p{:+}", exp)
+    }
+}
@@ -49,15 +49,91 @@
 //! | ------------ | ------------------ | ----- |
 //! | `x86`/`x86_64` | `f16c` | This supports conversion to/from [`struct@f16`] only (including vector SIMD) and does not support any [`struct@bf16`] or arithmetic operations. |
 //! | `aarch64` | `fp16` | This supports all operations on [`struct@f16`] only. |
+//! | `aarch64` | `bf16` | This supports slice conversion to/from [`struct@bf16`] only (including vector SIMD). |
 //! | `loongarch64` | `lsx` | This supports conversion to/from [`struct@f16`] only (including vector SIMD) and does not support any [`struct@bf16`] or arithmetic operations. |
 //!
 //! # Cargo Features
 //!
-//! To support numerous features, use the [float16-ext] package, which
+//! This crate supports the following cargo features:
+//!
+//! - `std` — Enabled by default. Uses the standard library for runtime CPU
+//!   feature detection and for the math functions of methods such as `exp` and
+//!   `sin`. Enables `alloc`.
+//! - `alloc` — Uses the `alloc` crate for methods that may need to allocate,
+//!   such as `f16::cow_from_le_bytes`, and adds `F16Fn`, a precomputed table of
+//!   a unary function on every `f16` value, and `f16_to_f32_table`, a table of
+//!   every `f16` value as an `f32`, built once on first use.
+//! - `approx` — Implements the [`approx`] traits for [`struct@f16`] and
+//!   [`struct@bf16`], so the `assert_relative_eq!` family of macros can be used
+//!   on half-precision values.
+//! - `num-traits` — Implements the [`num-traits`] traits for [`struct@f16`] and
+//!   [`struct@bf16`], so they can be used with generic numeric code such as
+//!   complex numbers from `num-complex`. The `Float` trait also requires the
+//!   `std` feature.
+//! - `rand` — Implements the [`rand`] `Standard` distribution, and the
+//!   [`rand_distr`] `StandardNormal` and `Exp1` distributions, for
+//!   [`struct@f16`] and [`struct@bf16`].
+//! - `canonical-nan` — Replaces every NaN result of the arithmetic operators,
+//!   [`Sum`] and [`Product`], and of the conversions to and from [`f32`],
+//!   [`f64`] and the other half type, including the slice conversions, with the
+//!   canonical NaN of the result type, such as `f16::NAN`, so NaN results are
+//!   bit-identical on every backend regardless of the NaN payloads of the
+//!   inputs. This is meant for golden-output tests. Methods that work on the
+//!   bits directly, such as `from_bits`, `to_bits`, negation, `abs`, `copysign`
+//!   and `quiet`, and the compact `serde` formats, bypass it and keep NaN
+//!   payloads.
+//! - `debug-vector-checks` — In debug builds, checks the results of the
+//!   vectorized slice conversions, such as `convert_from_f32_slice`, against
+//!   the scalar conversions for every chunk, and panics with the index and both
+//!   values at the first difference. NaN values only need to agree that they
+//!   are NaN. The rows of the matrix product kernels are checked the same way,
+//!   and the half-precision sums and products of slices on `aarch64` are
+//!   checked to be within the error bound of rounding after every element. This
+//!   is useful to validate a backend on new hardware, and has no effect in
+//!   release builds.
+//! - `schemars` — Implements the [`schemars`] `JsonSchema` trait for
+//!   [`struct@f16`] and [`struct@bf16`], describing them as numbers within the
+//!   finite range of each type. Enables `alloc`.
+//! - `serde` — Implements the [`serde`] `Serialize` and `Deserialize` traits
+//!   for [`struct@f16`] and [`struct@bf16`]. Human-readable formats, such as
+//!   JSON, use the value as an [`f32`] number, and compact formats use the bits
+//!   as a [`u16`].
+//! - `simd` — Adds fixed-width vectors of [`struct@f16`] and [`struct@bf16`]
+//!   values, such as `f16x8`, with element-wise arithmetic computed in [`f32`].
+//! - `test-support` — Adds the `testing` module, with generators of
+//!   [`struct@f16`] test inputs that cover every exponent and the special
+//!   values, for testing code that consumes half-precision values. Enables
+//!   `alloc`.
+//! - `total-order-impls` — Implements [`Eq`], [`Ord`] and [`Hash`] for
+//!   [`struct@f16`] and [`struct@bf16`] with the order of `total_cmp`, for use
+//!   as keys in sorted or hashed collections. **This changes the meaning of the
+//!   comparison operators**: [`PartialEq`] and [`PartialOrd`] are redefined to
+//!   agree with [`Ord`], so `-0 < +0`, every NaN equals itself, and NaNs are
+//!   ordered by sign and payload below or above every other value, unlike IEEE
+//!   754 and the primitive float types. Cargo features are unified, so enabling
+//!   this anywhere in the dependency graph changes the comparisons for every
+//!   crate using these types, and it should only be enabled by the final
+//!   binary. The inherent methods, such as `max`, `min` and `clamp`, keep the
+//!   IEEE 754 semantics.
+//! - `zeroize` — Implements the [`zeroize`] traits for [`struct@f16`] and
+//!   [`struct@bf16`], and with `alloc`, adds `SecretHalfBuffer`, a vector of
+//!   values that is zeroed when it is dropped.
+//!
+//! To support numerous other features, use the [float16-ext] package, which
 //! implements its own `f16` and `bf16` types that support features like
-//! `serde` serializing, zero-copy logic, and more.
+//! zero-copy logic, and more.
 //!
 //! [`std`]: https://doc.rust-lang.org/std/
+//! [`Hash`]: core::hash::Hash
+//! [`Sum`]: core::iter::Sum
+//! [`Product`]: core::iter::Product
+//! [`approx`]: https://docs.rs/approx/
+//! [`num-traits`]: https://docs.rs/num-traits/
+//! [`rand`]: https://docs.rs/rand/
+//! [`rand_distr`]: https://docs.rs/rand_distr/
+//! [`schemars`]: https://docs.rs/schemars/
+//! [`serde`]: https://docs.rs/serde/
+//! [`zeroize`]: https://docs.rs/zeroize/
 //! [`binary16`]: https://en.wikipedia.org/wiki/Half-precision_floating-point_format
 //! [`bfloat16`]: https://en.wikipedia.org/wiki/Bfloat16_floating-point_format
 #![allow(clippy::verbose_bit_mask, clippy::cast_lossless, unexpected_cfgs)]
@@ -68,19 +144,84 @@
 #![allow(unknown_lints, unnecessary_transmutes)]
 #![warn(unknown_lints)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "approx")]
+mod approx;
+#[cfg(target_has_atomic = "16")]
+mod atomic;
 mod bfloat;
 mod binary16;
+pub mod convert;
+#[cfg(not(target_arch = "spirv"))]
+mod decimal;
 mod error;
+mod exact;
+mod fixed;
+mod hex;
 mod leading_zeros;
+pub mod linalg;
+#[cfg(feature = "alloc")]
+mod lookup;
+pub mod minifloat;
+#[cfg(feature = "num-traits")]
+mod num_traits;
+mod option;
+pub mod packed;
+#[cfg(feature = "rand")]
+mod rand;
+mod rounding;
+#[cfg(feature = "schemars")]
+mod schemars;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(not(target_arch = "spirv"))]
+mod shortest;
+#[cfg(feature = "simd")]
+mod simd;
 mod slice;
+mod strided;
+#[cfg(target_has_atomic = "8")]
+pub mod sync;
+#[cfg(any(feature = "test-support", test))]
+pub mod testing;
+mod threshold;
 mod try_from;
+#[cfg(feature = "zeroize")]
+mod zeroize;
 
+#[cfg(target_has_atomic = "16")]
+pub use atomic::{AtomicBf16, AtomicF16};
 pub use bfloat::{bf16, bf16 as Bf16};
 pub use binary16::{f16, f16 as F16};
-pub use error::TryFromFloatError;
+pub use error::{
+    OddLength,
+    ParseHalfError,
+    StridedLayoutError,
+    StridedLayoutErrorKind,
+    TryFromFloatError,
+    TryFromHalfError,
+    TryFromHalfErrorKind,
+};
+#[cfg(all(feature = "alloc", target_has_atomic = "8"))]
+pub use lookup::f16_to_f32_table;
+#[cfg(feature = "alloc")]
+pub use lookup::F16Fn;
+pub use option::{OptionBf16, OptionF16, OptionHalfSliceExt};
+pub use rounding::RoundingMode;
+#[cfg(feature = "simd")]
+pub use simd::{bf16x4, bf16x8, f16x16, f16x4, f16x8};
+pub use strided::{StridedIter, StridedIterMut, StridedView, StridedViewMut};
 
 #[cfg(not(target_arch = "spirv"))]
-pub use crate::slice::{HalfBitsSliceExt, HalfFloatSliceExt};
+pub use crate::hex::HexFloat;
+#[cfg(not(target_arch = "spirv"))]
+pub use crate::slice::{Endianness, EndiannessGuess, HalfBitsSliceExt, HalfFloatSliceExt};
+#[cfg(not(target_arch = "spirv"))]
+pub use crate::threshold::CompiledThreshold;
+#[cfg(all(feature = "zeroize", feature = "alloc"))]
+pub use crate::zeroize::SecretHalfBuffer;
 
 // Keep this module private to crate
 mod private {
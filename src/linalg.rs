@@ -0,0 +1,322 @@
+//! Mixed-precision matrix multiplication, with [`struct@f16`] storage and
+//! [`f32`] accumulation.
+//!
+//! [`gemm_f16_f32`] computes `C += A × B` for modest sizes, such as a single
+//! layer of a small model, without depending on a full BLAS. It is blocked so
+//! that each block of `B` is widened to [`f32`] once, with the vectorized
+//! conversions of [`HalfFloatSliceExt`], and then accumulated with FMA on x86
+//! or `FMLAL` on aarch64 when the CPU supports them. It favors correctness
+//! over performance, and a tuned BLAS will be faster for large matrices.
+//!
+//! [`struct@f16`]: crate::f16
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use float16::*;
+//! use float16::linalg::gemm_f16_f32;
+//!
+//! let f = f16::from_f32;
+//! // A is 2×3, B is 3×2, and C is 2×2, all stored by rows.
+//! let a = [f(1.), f(2.), f(3.), f(4.), f(5.), f(6.)];
+//! let b = [f(1.), f(0.), f(0.), f(1.), f(1.), f(1.)];
+//! let mut c = [0.5f32; 4];
+//! gemm_f16_f32(2, 2, 3, &a, 3, &b, 2, &mut c, 2);
+//! assert_eq!(c, [4.5, 5.5, 10.5, 11.5]);
+//! ```
+
+use crate::binary16::arch::{self, MulAddKernel};
+use crate::f16;
+use crate::slice::HalfFloatSliceExt;
+
+/// The number of rows of `B` widened at once.
+const KC: usize = 64;
+
+/// The number of columns of `B` widened at once, so the widened block is
+/// 32 KiB.
+const NC: usize = 128;
+
+/// Computes `C += A × B`, where `A` is an `m × k` matrix and `B` is a `k × n`
+/// matrix of [`struct@f16`] values, and `C` is an `m × n` matrix of [`f32`]
+/// values.
+///
+/// The matrices are stored by rows, and `lda`, `ldb` and `ldc` are the
+/// distances between the starts of consecutive rows, so `A[i][p]` is
+/// `a[i * lda + p]`. These may be larger than the number of columns, to use a
+/// submatrix of a larger matrix, and elements outside the matrices are neither
+/// read nor written. If `k` is zero, `C` is unchanged.
+///
+/// Each element of `C` is accumulated in [`f32`], adding the products in
+/// order of `p`. The product of two [`struct@f16`] values is exact in
+/// [`f32`], so each addition rounds once, and the result is identical on every
+/// CPU, with or without FMA, and to the naive triple loop. The error of each
+/// element is therefore at most `γ(k) × (|C| + Σ |A[i][p]| |B[p][j]|)`, where
+/// `γ(k) = k u / (1 - k u)` and `u = 2^-24`, with `|C|` the initial value.
+///
+/// [`struct@f16`]: crate::f16
+///
+/// # Panics
+///
+/// This function will panic if a leading dimension is smaller than the
+/// number of columns of its matrix, or if a slice is too short for its
+/// matrix.
+///
+/// # Examples
+///
+/// ```rust
+/// # use float16::*;
+/// use float16::linalg::gemm_f16_f32;
+///
+/// // Multiply the 2×2 top-left block of a 3×3 matrix by itself.
+/// let a = [1., 2., 9., 3., 4., 9., 9., 9., 9.].map(f16::from_f32);
+/// let mut c = [0f32; 4];
+/// gemm_f16_f32(2, 2, 2, &a, 3, &a, 3, &mut c, 2);
+/// assert_eq!(c, [7., 10., 15., 22.]);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn gemm_f16_f32(
+    m: usize,
+    n: usize,
+    k: usize,
+    a: &[f16],
+    lda: usize,
+    b: &[f16],
+    ldb: usize,
+    c: &mut [f32],
+    ldc: usize,
+) {
+    check_matrix("A", m, k, a.len(), lda);
+    check_matrix("B", k, n, b.len(), ldb);
+    check_matrix("C", m, n, c.len(), ldc);
+    if m == 0 || n == 0 || k == 0 {
+        return;
+    }
+    gemm_with(arch::mul_add_kernel(), m, n, k, a, lda, b, ldb, c, ldc);
+}
+
+/// Checks that a slice holds a `rows × cols` matrix with rows `ld` apart.
+fn check_matrix(name: &str, rows: usize, cols: usize, len: usize, ld: usize) {
+    assert!(
+        ld >= cols,
+        "leading dimension of {} is {}, smaller than its {} columns",
+        name,
+        ld,
+        cols
+    );
+    if rows > 0 && cols > 0 {
+        let required = (rows - 1).checked_mul(ld).and_then(|x| x.checked_add(cols));
+        assert!(
+            required.map_or(false, |required| len >= required),
+            "slice of {} has {} elements, too few for {} rows of {} columns {} apart",
+            name,
+            len,
+            rows,
+            cols,
+            ld
+        );
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn gemm_with(
+    kernel: MulAddKernel,
+    m: usize,
+    n: usize,
+    k: usize,
+    a: &[f16],
+    lda: usize,
+    b: &[f16],
+    ldb: usize,
+    c: &mut [f32],
+    ldc: usize,
+) {
+    match kernel {
+        MulAddKernel::Widened(kernel) => {
+            let mut panel = [0f32; KC * NC];
+            let mut row = [0f32; KC];
+            for p0 in (0..k).step_by(KC) {
+                let kc = KC.min(k - p0);
+                for j0 in (0..n).step_by(NC) {
+                    let nc = NC.min(n - j0);
+                    for p in 0..kc {
+                        let src = &b[(p0 + p) * ldb + j0..][..nc];
+                        src.convert_to_f32_slice(&mut panel[p * nc..][..nc]);
+                    }
+                    for i in 0..m {
+                        a[i * lda + p0..][..kc].convert_to_f32_slice(&mut row[..kc]);
+                        let dst = &mut c[i * ldc + j0..][..nc];
+                        for (p, &x) in row[..kc].iter().enumerate() {
+                            // SAFETY: the kernel is only selected if the CPU
+                            // supports it.
+                            unsafe {
+                                arch::mul_add_row_widened(kernel, dst, x, &panel[p * nc..][..nc])
+                            };
+                        }
+                    }
+                }
+            }
+        },
+        MulAddKernel::Half(kernel) => {
+            // The instruction widens the values, so nothing is copied.
+            let a = a.reinterpret_cast();
+            let b = b.reinterpret_cast();
+            for i in 0..m {
+                let dst = &mut c[i * ldc..][..n];
+                for p in 0..k {
+                    // SAFETY: the kernel is only selected if the CPU
+                    // supports it.
+                    unsafe {
+                        arch::mul_add_row_half(kernel, dst, a[i * lda + p], &b[p * ldb..][..n])
+                    };
+                }
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Returns `count` values in `[-2, 2]` from a linear congruential
+    /// generator, with some exact zeros and small values.
+    fn values(seed: u32, count: usize) -> impl Iterator<Item = f16> {
+        let mut state = seed;
+        (0..count).map(move |_| {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            match state >> 28 {
+                0 => f16::ZERO,
+                1 => f16::from_f32(((state >> 8) & 0xFF) as f32 * 1e-6),
+                _ => f16::from_f32((state >> 8) as f32 / (1 << 22) as f32 - 2.),
+            }
+        })
+    }
+
+    /// Multiplies matrices stored with padding, which is filled with NaN, and
+    /// checks the result with `kernel` against the naive triple loop and an
+    /// [`f64`] reference.
+    fn check(kernel: MulAddKernel, m: usize, n: usize, k: usize, pad: usize) {
+        const MAX: usize = 160 * 160;
+        let (lda, ldb, ldc) = (k + pad, n + 2 * pad, n + pad);
+        let nan = f16::NAN;
+        let mut a = [nan; MAX];
+        let mut b = [nan; MAX];
+        let mut c = [f32::NAN; MAX];
+        for (i, x) in values(m as u32, m * k).enumerate() {
+            a[i / k * lda + i % k] = x;
+        }
+        for (i, x) in values(n as u32 + 7, k * n).enumerate() {
+            b[i / n * ldb + i % n] = x;
+        }
+        for i in 0..m {
+            for j in 0..n {
+                c[i * ldc + j] = (i as f32 - j as f32) / 4.;
+            }
+        }
+        let mut naive = c;
+        for i in 0..m {
+            for j in 0..n {
+                for p in 0..k {
+                    naive[i * ldc + j] += a[i * lda + p].to_f32() * b[p * ldb + j].to_f32();
+                }
+            }
+        }
+
+        let start = c;
+        if m > 0 && n > 0 && k > 0 {
+            gemm_with(kernel, m, n, k, &a, lda, &b, ldb, &mut c, ldc);
+        } else {
+            gemm_f16_f32(m, n, k, &a, lda, &b, ldb, &mut c, ldc);
+        }
+        let gamma = k as f64 * f64::powi(2., -24) / (1. - k as f64 * f64::powi(2., -24));
+        for (index, (&x, &y)) in c.iter().zip(&naive).enumerate() {
+            // Every element is padding if the rows are empty.
+            let (i, j) = (index / ldc.max(1), index % ldc.max(1));
+            if i >= m || j >= n {
+                // The padding is unchanged.
+                assert!(x.is_nan(), "{}, {}", i, j);
+                continue;
+            }
+            assert_eq!(x.to_bits(), y.to_bits(), "{}, {}", i, j);
+            let (mut exact, mut bound) = (start[index] as f64, (start[index] as f64).abs());
+            for p in 0..k {
+                let product = a[i * lda + p].to_f64() * b[p * ldb + j].to_f64();
+                exact += product;
+                bound += product.abs();
+            }
+            assert!((x as f64 - exact).abs() <= gamma * bound, "{}, {}", i, j);
+        }
+    }
+
+    #[test]
+    fn test_small() {
+        let kernel = arch::mul_add_kernel();
+        for &(m, n, k) in &[(1, 1, 1), (2, 3, 4), (5, 7, 3), (3, 1, 9), (1, 9, 1)] {
+            check(kernel, m, n, k, 0);
+            check(kernel, m, n, k, 3);
+        }
+    }
+
+    #[test]
+    fn test_empty() {
+        // No products leave `C` unchanged.
+        let kernel = arch::mul_add_kernel();
+        for &(m, n, k) in &[(0, 0, 0), (3, 4, 0), (0, 4, 5), (3, 0, 5)] {
+            check(kernel, m, n, k, 0);
+            check(kernel, m, n, k, 2);
+        }
+        gemm_f16_f32(0, 0, 0, &[], 0, &[], 0, &mut [], 0);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_blocks() {
+        // Shapes that cross the block sizes, with and without padding, for
+        // both the selected kernel and the software kernel.
+        let kernels = [arch::mul_add_kernel(), MulAddKernel::Widened(arch::mul_add_row_fallback)];
+        for kernel in kernels {
+            for &(m, n, k) in &[(17, 33, 65), (3, 130, 64), (9, 129, 129), (31, 8, 150)] {
+                check(kernel, m, n, k, 0);
+                check(kernel, m, n, k, 5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_special() {
+        // Infinite and NaN values propagate, as in the naive loop.
+        let f = f16::from_f32;
+        let a = [f16::INFINITY, f(1.), f(0.), f16::NAN];
+        let b = [f(0.), f(2.), f(-1.), f(3.)];
+        let mut c = [0f32; 4];
+        gemm_f16_f32(2, 2, 2, &a, 2, &b, 2, &mut c, 2);
+        assert!(c[0].is_nan());
+        assert_eq!(c[1], f32::INFINITY);
+        assert!(c[2].is_nan());
+        assert!(c[3].is_nan());
+
+        // Large values do not overflow the `f32` accumulator.
+        let a = [f16::MAX; 8];
+        let mut c = [0f32; 1];
+        gemm_f16_f32(1, 1, 8, &a, 8, &a, 1, &mut c, 1);
+        let mut sum = 0f32;
+        for _ in 0..8 {
+            sum += 65504. * 65504.;
+        }
+        assert_eq!(c[0], sum);
+    }
+
+    #[test]
+    #[should_panic(expected = "leading dimension of B is 2, smaller than its 3 columns")]
+    fn test_leading_dimension() {
+        let x = [f16::ZERO; 9];
+        gemm_f16_f32(3, 3, 3, &x, 3, &x, 2, &mut [0.; 9], 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "slice of C has 8 elements, too few for 3 rows of 3 columns 3 apart")]
+    fn test_short_slice() {
+        let x = [f16::ZERO; 9];
+        gemm_f16_f32(3, 3, 3, &x, 3, &x, 3, &mut [0.; 8], 3);
+    }
+}
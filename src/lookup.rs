@@ -0,0 +1,203 @@
+//! Precomputed tables of unary functions on [`struct@f16`] values.
+//!
+//! There are only 65536 [`struct@f16`] values, so any unary function on them
+//! can be evaluated once for every input and then applied with a table lookup.
+//! This is much faster than repeatedly converting to [`f32`], calling the
+//! function and rounding back, for example in an interpreter that applies a
+//! user-defined function to every element of a tensor.
+
+#[cfg(target_has_atomic = "8")]
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::f16;
+#[cfg(target_has_atomic = "8")]
+use crate::sync::OnceTable;
+
+/// Returns the [`f32`] value of every [`struct@f16`] value, indexed by its
+/// bits.
+///
+/// The 256 KiB table is built on the first call, which may be on any thread,
+/// and is then shared for the rest of the program, so converting with a
+/// lookup does not need hardware support. Each entry is the same as
+/// [`f16::to_f32`][crate::f16::to_f32].
+///
+/// # Examples
+///
+/// ```rust
+/// # use float16::*;
+/// let table = f16_to_f32_table();
+/// assert_eq!(table[f16::ONE.to_bits() as usize], 1.);
+/// assert_eq!(table[0xFBFF], -65504.);
+/// ```
+#[cfg(target_has_atomic = "8")]
+#[must_use]
+pub fn f16_to_f32_table() -> &'static [f32; 1 << 16] {
+    static TABLE: OnceTable<Box<[f32; 1 << 16]>> = OnceTable::new();
+    TABLE.get_or_init(|| {
+        // Built on the heap, since the table is too large for some stacks.
+        let table: Vec<f32> = (0..=u16::MAX).map(|bits| f16::from_bits(bits).to_f32()).collect();
+        match table.into_boxed_slice().try_into() {
+            Ok(table) => table,
+            Err(_) => unreachable!(),
+        }
+    })
+}
+
+/// A unary function on [`struct@f16`] values, precomputed for every input.
+///
+/// The table holds one output for each of the 65536 inputs, 128 KiB in total,
+/// and each output is rounded once from the [`f32`] result of the function.
+/// Applying the function is then a single lookup, and composing two functions
+/// with [`then`][Self::then] composes their tables, so the result is identical
+/// to applying each function in turn.
+///
+/// # Examples
+///
+/// ```rust
+/// # use float16::*;
+/// let square = F16Fn::new(|x| x * x);
+/// assert_eq!(square.apply(f16::from_f32(3.)), f16::from_f32(9.));
+///
+/// let mut values = [f16::from_f32(-2.), f16::from_f32(0.5)];
+/// square.apply_slice(&mut values);
+/// assert_eq!(values, [f16::from_f32(4.), f16::from_f32(0.25)]);
+///
+/// let plus_one = F16Fn::new(|x| x + 1.);
+/// assert_eq!(square.then(&plus_one).apply(f16::from_f32(3.)), f16::from_f32(10.));
+/// ```
+#[derive(Clone)]
+pub struct F16Fn {
+    table: Vec<f16>,
+}
+
+impl F16Fn {
+    /// Precomputes `f` for every [`struct@f16`] input.
+    ///
+    /// `f` is called once for each of the 65536 inputs, including NaN and
+    /// infinite values, and each result is rounded to the nearest
+    /// [`struct@f16`] value with [`f16::from_f32`][crate::f16::from_f32].
+    #[must_use]
+    pub fn new(f: impl Fn(f32) -> f32) -> F16Fn {
+        let table = (0..=u16::MAX).map(|bits| f16::from_f32(f(f16::from_bits(bits).to_f32())));
+        F16Fn {
+            table: table.collect(),
+        }
+    }
+
+    /// Returns the precomputed output for `x`.
+    #[inline]
+    #[must_use]
+    pub fn apply(&self, x: f16) -> f16 {
+        self.table[x.to_bits() as usize]
+    }
+
+    /// Replaces every value in the slice with the precomputed output for it.
+    #[inline]
+    pub fn apply_slice(&self, values: &mut [f16]) {
+        for value in values.iter_mut() {
+            *value = self.apply(*value);
+        }
+    }
+
+    /// Returns the function that applies `self` and then `other`.
+    ///
+    /// The composition is computed from the two tables, without calling
+    /// either function again, so it is identical to applying `self` and
+    /// then `other`, including the rounding of the intermediate result.
+    #[must_use]
+    pub fn then(&self, other: &F16Fn) -> F16Fn {
+        F16Fn {
+            table: self.table.iter().map(|&x| other.apply(x)).collect(),
+        }
+    }
+
+    /// Returns the table of outputs, indexed by the bits of each input.
+    #[inline]
+    #[must_use]
+    pub fn as_slice(&self) -> &[f16] {
+        &self.table
+    }
+}
+
+impl fmt::Debug for F16Fn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("F16Fn").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Returns if two values are the same, treating every NaN as the same.
+    fn same(a: f16, b: f16) -> bool {
+        a.to_bits() == b.to_bits() || (a.is_nan() && b.is_nan())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_matches_scalar() {
+        let sqrt = F16Fn::new(f32::sqrt);
+        assert_eq!(sqrt.as_slice().len(), 1 << 16);
+        for bits in 0..=u16::MAX {
+            let x = f16::from_bits(bits);
+            let expected = f16::from_f32(x.to_f32().sqrt());
+            assert!(same(sqrt.apply(x), expected), "{:#06x}", bits);
+        }
+
+        #[cfg(feature = "std")]
+        {
+            let exp = F16Fn::new(f32::exp);
+            for bits in 0..=u16::MAX {
+                let x = f16::from_bits(bits);
+                assert!(same(exp.apply(x), x.exp()), "{:#06x}", bits);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_then() {
+        let sqrt = F16Fn::new(f32::sqrt);
+        let half = F16Fn::new(|x| x * 0.5 - 1.);
+        let composed = sqrt.then(&half);
+        for bits in 0..=u16::MAX {
+            let x = f16::from_bits(bits);
+            assert!(same(composed.apply(x), half.apply(sqrt.apply(x))), "{:#06x}", bits);
+        }
+        // The intermediate result is rounded, unlike a single function.
+        let direct = F16Fn::new(|x| x.sqrt() * 0.5 - 1.);
+        assert!((0..=u16::MAX).any(|bits| {
+            let x = f16::from_bits(bits);
+            !same(composed.apply(x), direct.apply(x))
+        }));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_apply_slice() {
+        let neg = F16Fn::new(|x| -x);
+        let mut values = [f16::ONE, f16::NEG_ZERO, f16::INFINITY, f16::from_f32(-0.1)];
+        let expected = values.map(|x| -x);
+        neg.apply_slice(&mut values);
+        assert_eq!(values.map(f16::to_bits), expected.map(f16::to_bits));
+        let copy = neg.clone();
+        assert!(copy.as_slice().iter().zip(neg.as_slice()).all(|(&a, &b)| same(a, b)));
+    }
+
+    #[test]
+    #[cfg(target_has_atomic = "8")]
+    #[cfg_attr(miri, ignore)]
+    fn test_f16_to_f32_table() {
+        let table = f16_to_f32_table();
+        for bits in 0..=u16::MAX {
+            let expected = f16::from_bits(bits).to_f32();
+            let value = table[bits as usize];
+            assert!(value.to_bits() == expected.to_bits() || (value.is_nan() && expected.is_nan()));
+        }
+        // Later calls share the table.
+        assert!(core::ptr::eq(table, f16_to_f32_table()));
+    }
+}
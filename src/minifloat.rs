@@ -0,0 +1,360 @@
+//! Correctly rounded conversions between arbitrary binary floating point
+//! formats, for experimenting with custom formats such as 12-bit floats or
+//! E5M7.
+//!
+//! A format is described by its number of exponent and mantissa bits, and has
+//! a sign bit above them, an exponent bias of `2^(exp_bits - 1) - 1`,
+//! subnormals, infinities and NaNs, the same as the IEEE 754 binary formats.
+//! [`struct@f16`][crate::f16], [`struct@bf16`][crate::bf16], [`f32`] and
+//! [`f64`] are the formats `(5, 10)`, `(8, 7)`, `(8, 23)` and `(11, 52)`.
+//!
+//! Conversions round to the nearest value, with ties to even, exactly once.
+//! Values too large for the destination overflow to ±∞, values too small
+//! underflow to subnormals or ±0, and NaNs stay NaN, keeping the most
+//! significant bits of the payload and setting the most significant mantissa
+//! bit so they are quiet. This is the same rounding used by the
+//! [`struct@f16`][crate::f16] and [`struct@bf16`][crate::bf16] conversions,
+//! which are implemented with it when hardware support is not available.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use float16::minifloat::{narrow, MiniFloatSpec};
+//!
+//! // Round an f32 to E5M7, a 13-bit format.
+//! let e5m7 = MiniFloatSpec::new(5, 7);
+//! let bits = narrow(1.1f32.to_bits() as u64, 8, 23, 5, 7);
+//! assert_eq!(bits, 0x078D);
+//! assert_eq!(e5m7.to_f64(bits), 1.1015625);
+//! assert_eq!(e5m7.encode_f64(1.1), bits);
+//! ```
+
+use core::mem;
+
+use crate::fixed::round_to_float;
+
+/// Converts the bits of a value with `src_exp_bits` exponent bits and
+/// `src_man_bits` mantissa bits to a format with `dst_exp_bits` exponent bits
+/// and `dst_man_bits` mantissa bits, rounding to the nearest value with ties
+/// to even.
+///
+/// Both formats may have up to 63 bits without the sign, and bits above the
+/// sign bit of the source are ignored.
+#[inline]
+const fn convert(
+    bits: u64,
+    src_exp_bits: u32,
+    src_man_bits: u32,
+    dst_exp_bits: u32,
+    dst_man_bits: u32,
+) -> u64 {
+    let src_exp_mask = (1u64 << src_exp_bits) - 1;
+    let src_man_mask = (1u64 << src_man_bits) - 1;
+    let sign = (bits >> (src_exp_bits + src_man_bits)) & 1;
+    let exp = (bits >> src_man_bits) & src_exp_mask;
+    let man = bits & src_man_mask;
+
+    let dst_sign = sign << (dst_exp_bits + dst_man_bits);
+    let dst_inf = ((1u64 << dst_exp_bits) - 1) << dst_man_bits;
+
+    // Check for all exponent bits being set, which is Infinity or NaN
+    if exp == src_exp_mask {
+        if man == 0 {
+            return dst_sign | dst_inf;
+        }
+        // Keep the most significant bits of the payload, and set the most
+        // significant mantissa bit so the NaN is quiet
+        let payload = if src_man_bits >= dst_man_bits {
+            man >> (src_man_bits - dst_man_bits)
+        } else {
+            man << (dst_man_bits - src_man_bits)
+        };
+        return dst_sign | dst_inf | (1 << (dst_man_bits - 1)) | payload;
+    }
+
+    // Decode the magnitude as `mag * 2^exp`, with the hidden bit for normals
+    let bias = (1i64 << (src_exp_bits - 1)) - 1;
+    let (mag, exp) = if exp == 0 {
+        (man, 1 - bias - src_man_bits as i64)
+    } else {
+        (man | (1 << src_man_bits), exp as i64 - bias - src_man_bits as i64)
+    };
+    dst_sign | round_to_float(mag, exp, false, dst_exp_bits, dst_man_bits)
+}
+
+/// Converts the bits of a value with `src_exp_bits` exponent bits and
+/// `src_man_bits` mantissa bits to a format with `dst_exp_bits` exponent bits
+/// and `dst_man_bits` mantissa bits, rounding to the nearest value with ties
+/// to even.
+///
+/// The bits are stored in the low bits of the integers, with the sign bit
+/// above the exponent, and bits above the sign bit of the source are ignored.
+/// Subnormals, overflow and NaN are handled as described in the [module
+/// documentation](self). This also converts to wider formats, which is
+/// exact.
+///
+/// # Panics
+///
+/// This panics, or fails to compile in a `const` context, if either format has
+/// fewer than 2 exponent bits or 1 mantissa bit, if the source has more than
+/// 63 bits, or if the destination has more than 32 bits, including the sign.
+///
+/// # Examples
+///
+/// ```rust
+/// # use float16::*;
+/// use float16::minifloat::narrow;
+///
+/// // f32 to f16 is the same as `f16::from_f32`.
+/// let x = 3.14159f32;
+/// assert_eq!(narrow(x.to_bits() as u64, 8, 23, 5, 10), f16::from_f32(x).to_bits() as u32);
+///
+/// // f16 to a 12-bit format with 6 exponent bits and 5 mantissa bits.
+/// let bits = narrow(f16::ONE.to_bits() as u64, 5, 10, 6, 5);
+/// assert_eq!(bits, 0x3E0);
+/// ```
+#[inline]
+#[must_use]
+pub const fn narrow(
+    bits: u64,
+    src_exp_bits: u32,
+    src_man_bits: u32,
+    dst_exp_bits: u32,
+    dst_man_bits: u32,
+) -> u32 {
+    assert!(src_exp_bits >= 2 && src_man_bits >= 1, "invalid source format");
+    assert!(src_exp_bits + src_man_bits <= 63, "source format is too wide");
+    assert!(dst_exp_bits >= 2 && dst_man_bits >= 1, "invalid destination format");
+    assert!(dst_exp_bits + dst_man_bits <= 31, "destination format is too wide");
+    convert(bits, src_exp_bits, src_man_bits, dst_exp_bits, dst_man_bits) as u32
+}
+
+/// A binary floating point format with a sign bit, `exp_bits` exponent bits
+/// and `man_bits` mantissa bits, for converting to and from [`f64`].
+///
+/// See the [module documentation](self) for how the format is interpreted and
+/// how values are rounded.
+///
+/// # Examples
+///
+/// ```rust
+/// use float16::minifloat::MiniFloatSpec;
+///
+/// let e4m3 = MiniFloatSpec::new(4, 3);
+/// assert_eq!(e4m3.total_bits(), 8);
+/// assert_eq!(e4m3.max_finite(), 240.0);
+/// assert_eq!(e4m3.to_f64(e4m3.encode_f64(100.0)), 96.0);
+/// assert_eq!(e4m3.to_f64(e4m3.encode_f64(1000.0)), f64::INFINITY);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MiniFloatSpec {
+    exp_bits: u32,
+    man_bits: u32,
+}
+
+impl MiniFloatSpec {
+    /// Creates a format with `exp_bits` exponent bits and `man_bits` mantissa
+    /// bits.
+    ///
+    /// # Panics
+    ///
+    /// This panics, or fails to compile in a `const` context, if the format
+    /// has fewer than 2 exponent bits, fewer than 1 mantissa bit, or more than
+    /// 32 bits including the sign.
+    #[inline]
+    #[must_use]
+    pub const fn new(exp_bits: u32, man_bits: u32) -> MiniFloatSpec {
+        assert!(exp_bits >= 2 && man_bits >= 1, "invalid format");
+        assert!(exp_bits + man_bits <= 31, "format is too wide");
+        MiniFloatSpec {
+            exp_bits,
+            man_bits,
+        }
+    }
+
+    /// Returns the number of exponent bits.
+    #[inline]
+    #[must_use]
+    pub const fn exp_bits(self) -> u32 {
+        self.exp_bits
+    }
+
+    /// Returns the number of mantissa bits, without the hidden bit.
+    #[inline]
+    #[must_use]
+    pub const fn man_bits(self) -> u32 {
+        self.man_bits
+    }
+
+    /// Returns the total number of bits, including the sign.
+    #[inline]
+    #[must_use]
+    pub const fn total_bits(self) -> u32 {
+        1 + self.exp_bits + self.man_bits
+    }
+
+    /// Returns the exponent bias.
+    #[inline]
+    #[must_use]
+    pub const fn bias(self) -> i32 {
+        (1 << (self.exp_bits - 1)) - 1
+    }
+
+    /// Returns the largest finite value of the format.
+    #[inline]
+    #[must_use]
+    pub const fn max_finite(self) -> f64 {
+        self.to_f64((((1 << self.exp_bits) - 2) << self.man_bits) | ((1 << self.man_bits) - 1))
+    }
+
+    /// Rounds an [`f64`] value to the nearest value of the format, returning
+    /// its bits.
+    #[inline]
+    #[must_use]
+    pub const fn encode_f64(self, value: f64) -> u32 {
+        // TODO: Replace mem::transmute with to_bits() once to_bits is const-stabilized
+        let bits: u64 = unsafe { mem::transmute::<f64, u64>(value) };
+        narrow(bits, 11, 52, self.exp_bits, self.man_bits)
+    }
+
+    /// Rounds an [`f32`] value to the nearest value of the format, returning
+    /// its bits.
+    #[inline]
+    #[must_use]
+    pub const fn encode_f32(self, value: f32) -> u32 {
+        // TODO: Replace mem::transmute with to_bits() once to_bits is const-stabilized
+        let bits: u32 = unsafe { mem::transmute::<f32, u32>(value) };
+        narrow(bits as u64, 8, 23, self.exp_bits, self.man_bits)
+    }
+
+    /// Converts the bits of a value of the format to an [`f64`].
+    ///
+    /// This is exact for formats with at most 11 exponent bits and 52
+    /// mantissa bits, and is otherwise rounded to the nearest [`f64`]. Bits
+    /// above the sign bit are ignored.
+    #[inline]
+    #[must_use]
+    pub const fn to_f64(self, bits: u32) -> f64 {
+        let bits = convert(bits as u64, self.exp_bits, self.man_bits, 11, 52);
+        // TODO: Replace mem::transmute with from_bits() once from_bits is
+        // const-stabilized
+        unsafe { mem::transmute::<u64, f64>(bits) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{bf16, f16};
+
+    /// Formats that are not used by this crate.
+    const FORMATS: [(u32, u32); 5] = [(5, 7), (6, 5), (4, 3), (5, 2), (3, 4)];
+
+    #[test]
+    fn test_standard_formats() {
+        for &value in &[0.0f32, -0.0, 1.0, 0.1, -65504.0, 65520.0, 1e-7, 3e-8, 1e30] {
+            let bits = value.to_bits() as u64;
+            assert_eq!(narrow(bits, 8, 23, 5, 10), f16::from_f32(value).to_bits() as u32);
+            assert_eq!(narrow(bits, 8, 23, 8, 7), bf16::from_f32(value).to_bits() as u32);
+        }
+        let f16_spec = MiniFloatSpec::new(5, 10);
+        assert_eq!(f16_spec.encode_f32(0.1), f16::from_f32(0.1).to_bits() as u32);
+        assert_eq!(f16_spec.encode_f64(0.1), f16::from_f64(0.1).to_bits() as u32);
+        assert_eq!(f16_spec.max_finite(), 65504.0);
+        assert_eq!(f16_spec.bias(), 15);
+        assert_eq!(MiniFloatSpec::new(8, 7).max_finite(), bf16::MAX.to_f64());
+        assert_eq!(MiniFloatSpec::new(8, 23).max_finite(), f32::MAX as f64);
+
+        // Widening is exact.
+        for bits in 0..=u16::MAX {
+            let value = f16::from_bits(bits);
+            let wide = f16_spec.to_f64(bits as u32);
+            if value.is_nan() {
+                assert!(wide.is_nan());
+            } else {
+                assert_eq!(wide, value.to_f64());
+            }
+            let value = bf16::from_bits(bits);
+            if !value.is_nan() {
+                assert_eq!(narrow(bits as u64, 8, 7, 8, 23), value.to_f32().to_bits());
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_round_trip() {
+        // Every value of each format round trips through f64, and the values
+        // are ordered the same as their f64 values.
+        for &(exp_bits, man_bits) in &FORMATS {
+            let spec = MiniFloatSpec::new(exp_bits, man_bits);
+            let inf = ((1u32 << exp_bits) - 1) << man_bits;
+            let mut previous = f64::NEG_INFINITY;
+            for bits in 0..=inf {
+                let value = spec.to_f64(bits);
+                let negative = spec.to_f64(bits | (1 << (exp_bits + man_bits)));
+                assert_eq!(spec.encode_f64(value), bits);
+                assert_eq!(negative, -value);
+                assert!(previous < value || (bits == 0 && value == 0.0), "{:#x}", bits);
+                previous = value;
+            }
+            assert_eq!(previous, f64::INFINITY);
+            assert!(spec.to_f64(inf | 1).is_nan());
+            assert!(spec.to_f64(spec.encode_f64(f64::NAN)).is_nan());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_monotonic_narrowing() {
+        // Narrowing every f16 value is monotonic, and each result is the
+        // nearest value of the format, with ties to even.
+        for &(exp_bits, man_bits) in &FORMATS {
+            let spec = MiniFloatSpec::new(exp_bits, man_bits);
+            let mut previous = f64::NEG_INFINITY;
+            for bits in 0..0x7C00u16 {
+                let x = f16::from_bits(bits).to_f64();
+                let narrowed = narrow(bits as u64, 5, 10, exp_bits, man_bits);
+                let y = spec.to_f64(narrowed);
+                assert!(previous <= y, "{:#06x}", bits);
+                previous = y;
+
+                // The neighbors of the result are no closer.
+                if y.is_finite() {
+                    let below = spec.to_f64(narrowed.saturating_sub(1));
+                    let above = spec.to_f64(narrowed + 1);
+                    let error = (x - y).abs();
+                    assert!(error <= (x - below).abs() || narrowed == 0);
+                    assert!(error <= (above - x).abs(), "{:#06x}", bits);
+                    if error == (above - x).abs() || (narrowed != 0 && error == (x - below).abs()) {
+                        assert_eq!(narrowed & 1, 0, "{:#06x}", bits);
+                    }
+                }
+
+                // Narrowing the negated value negates the result.
+                let negative = narrow((bits | 0x8000) as u64, 5, 10, exp_bits, man_bits);
+                assert_eq!(negative, narrowed | (1 << (exp_bits + man_bits)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_nan_payloads() {
+        // The top payload bits are kept, and the NaN is quiet.
+        let bits = narrow(0x7F80_0001, 8, 23, 5, 7);
+        assert_eq!(bits, 0x0F80 | 0x40);
+        let bits = narrow(0xFFA0_0000, 8, 23, 5, 7);
+        assert_eq!(bits, 0x1000 | 0x0F80 | 0x40 | 0x20);
+        // Widening shifts the payload up.
+        assert_eq!(narrow(0x7C01, 5, 10, 8, 23), 0x7FC0_2000);
+        // Infinities stay infinite.
+        assert_eq!(narrow(0x7F80_0000, 8, 23, 3, 4), 0x70);
+        assert_eq!(narrow(0xFF80_0000, 8, 23, 3, 4), 0xF0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_too_wide_panics() {
+        let _ = narrow(0, 8, 23, 11, 52);
+    }
+}
@@ -0,0 +1,690 @@
+//! Implementations of the [`num-traits`](https://docs.rs/num-traits/) traits
+//! for [`struct@f16`] and [`struct@bf16`].
+//!
+//! These allow both types to be used by generic numeric code, such as the
+//! complex numbers of [`num-complex`](https://docs.rs/num-complex/). Integers
+//! and decimal strings are rounded once to the nearest half-precision value,
+//! the same as [`from_fixed_point`][crate::f16::from_fixed_point] and
+//! [`FromStr`][core::str::FromStr]. Strings in other radixes are parsed as an
+//! [`f64`] first, as [`num-traits`](https://docs.rs/num-traits/) does, so they
+//! may be rounded twice. Any other operation without a native implementation
+//! is computed in [`f32`] precision and then rounded to the nearest
+//! half-precision value. [`Float`] requires the `std` feature, since it needs
+//! the [`f32`] math functions.
+
+use core::num::FpCategory;
+
+#[cfg(feature = "std")]
+use num_traits::Float;
+use num_traits::{
+    float::FloatConst,
+    Bounded,
+    FloatErrorKind,
+    FromPrimitive,
+    Num,
+    NumCast,
+    One,
+    ParseFloatError,
+    Signed,
+    ToPrimitive,
+    Zero,
+};
+
+use crate::{bf16, f16, fixed};
+
+/// Implements a [`Float`] method by computing it in [`f32`] precision.
+#[cfg(feature = "std")]
+macro_rules! via_f32 {
+    ($($name:ident($($arg:ident),*);)*) => {
+        $(
+            #[inline]
+            fn $name(self, $($arg: Self),*) -> Self {
+                Self::from_f32(self.to_f32().$name($($arg.to_f32()),*))
+            }
+        )*
+    };
+}
+
+macro_rules! impl_num_traits {
+    ($half:ident, $exp_bits:literal, $man_bits:literal) => {
+        impl Zero for $half {
+            #[inline]
+            fn zero() -> Self {
+                Self::ZERO
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                $half::is_zero(*self)
+            }
+        }
+
+        impl One for $half {
+            #[inline]
+            fn one() -> Self {
+                Self::ONE
+            }
+        }
+
+        impl Num for $half {
+            type FromStrRadixErr = ParseFloatError;
+
+            #[inline]
+            fn from_str_radix(src: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                if radix == 10 {
+                    return src.parse().map_err(|_| ParseFloatError {
+                        kind: if src.is_empty() {
+                            FloatErrorKind::Empty
+                        } else {
+                            FloatErrorKind::Invalid
+                        },
+                    });
+                }
+                f64::from_str_radix(src, radix).map(Self::from_f64)
+            }
+        }
+
+        impl Bounded for $half {
+            #[inline]
+            fn min_value() -> Self {
+                Self::MIN
+            }
+
+            #[inline]
+            fn max_value() -> Self {
+                Self::MAX
+            }
+        }
+
+        impl Signed for $half {
+            #[inline]
+            fn abs(&self) -> Self {
+                $half::abs(*self)
+            }
+
+            #[inline]
+            fn abs_sub(&self, other: &Self) -> Self {
+                if self.to_f32() <= other.to_f32() {
+                    Self::ZERO
+                } else {
+                    *self - *other
+                }
+            }
+
+            #[inline]
+            fn signum(&self) -> Self {
+                $half::signum(*self)
+            }
+
+            #[inline]
+            fn is_positive(&self) -> bool {
+                self.is_sign_positive()
+            }
+
+            #[inline]
+            fn is_negative(&self) -> bool {
+                self.is_sign_negative()
+            }
+        }
+
+        impl ToPrimitive for $half {
+            #[inline]
+            fn to_i64(&self) -> Option<i64> {
+                $half::to_f32(*self).to_i64()
+            }
+
+            #[inline]
+            fn to_u64(&self) -> Option<u64> {
+                $half::to_f32(*self).to_u64()
+            }
+
+            #[inline]
+            fn to_i128(&self) -> Option<i128> {
+                $half::to_f32(*self).to_i128()
+            }
+
+            #[inline]
+            fn to_u128(&self) -> Option<u128> {
+                $half::to_f32(*self).to_u128()
+            }
+
+            #[inline]
+            fn to_f32(&self) -> Option<f32> {
+                Some($half::to_f32(*self))
+            }
+
+            #[inline]
+            fn to_f64(&self) -> Option<f64> {
+                Some($half::to_f64(*self))
+            }
+        }
+
+        impl FromPrimitive for $half {
+            #[inline]
+            fn from_i64(n: i64) -> Option<Self> {
+                <Self as FromPrimitive>::from_i128(n as i128)
+            }
+
+            #[inline]
+            fn from_u64(n: u64) -> Option<Self> {
+                <Self as FromPrimitive>::from_u128(n as u128)
+            }
+
+            #[inline]
+            fn from_i128(n: i128) -> Option<Self> {
+                let bits = fixed::int_to_half(n < 0, n.unsigned_abs(), $exp_bits, $man_bits);
+                Some(Self::from_bits(bits))
+            }
+
+            #[inline]
+            fn from_u128(n: u128) -> Option<Self> {
+                Some(Self::from_bits(fixed::int_to_half(false, n, $exp_bits, $man_bits)))
+            }
+
+            #[inline]
+            fn from_f32(n: f32) -> Option<Self> {
+                Some($half::from_f32(n))
+            }
+
+            #[inline]
+            fn from_f64(n: f64) -> Option<Self> {
+                Some($half::from_f64(n))
+            }
+        }
+
+        impl NumCast for $half {
+            #[inline]
+            fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+                // Integers may already be rounded as an `f64`, so round them
+                // from the exact integer, which converts to the same `f64`.
+                // Other floats and negative zero never match, so they are
+                // rounded from their value.
+                let float = n.to_f64()?;
+                match (n.to_i128(), n.to_u128()) {
+                    (Some(i), _) if (i as f64).to_bits() == float.to_bits() => {
+                        <Self as FromPrimitive>::from_i128(i)
+                    },
+                    (_, Some(u)) if (u as f64).to_bits() == float.to_bits() => {
+                        <Self as FromPrimitive>::from_u128(u)
+                    },
+                    _ => Some(Self::from_f64(float)),
+                }
+            }
+        }
+
+        impl FloatConst for $half {
+            #[inline]
+            fn E() -> Self {
+                Self::E
+            }
+
+            #[inline]
+            fn FRAC_1_PI() -> Self {
+                Self::FRAC_1_PI
+            }
+
+            #[inline]
+            fn FRAC_1_SQRT_2() -> Self {
+                Self::FRAC_1_SQRT_2
+            }
+
+            #[inline]
+            fn FRAC_2_PI() -> Self {
+                Self::FRAC_2_PI
+            }
+
+            #[inline]
+            fn FRAC_2_SQRT_PI() -> Self {
+                Self::FRAC_2_SQRT_PI
+            }
+
+            #[inline]
+            fn FRAC_PI_2() -> Self {
+                Self::FRAC_PI_2
+            }
+
+            #[inline]
+            fn FRAC_PI_3() -> Self {
+                Self::FRAC_PI_3
+            }
+
+            #[inline]
+            fn FRAC_PI_4() -> Self {
+                Self::FRAC_PI_4
+            }
+
+            #[inline]
+            fn FRAC_PI_6() -> Self {
+                Self::FRAC_PI_6
+            }
+
+            #[inline]
+            fn FRAC_PI_8() -> Self {
+                Self::FRAC_PI_8
+            }
+
+            #[inline]
+            fn LN_10() -> Self {
+                Self::LN_10
+            }
+
+            #[inline]
+            fn LN_2() -> Self {
+                Self::LN_2
+            }
+
+            #[inline]
+            fn LOG10_E() -> Self {
+                Self::LOG10_E
+            }
+
+            #[inline]
+            fn LOG2_E() -> Self {
+                Self::LOG2_E
+            }
+
+            #[inline]
+            fn PI() -> Self {
+                Self::PI
+            }
+
+            #[inline]
+            fn SQRT_2() -> Self {
+                Self::SQRT_2
+            }
+
+            #[inline]
+            fn TAU() -> Self {
+                Self::from_f32(core::f32::consts::TAU)
+            }
+
+            #[inline]
+            fn LOG10_2() -> Self {
+                Self::LOG10_2
+            }
+
+            #[inline]
+            fn LOG2_10() -> Self {
+                Self::LOG2_10
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl Float for $half {
+            #[inline]
+            fn nan() -> Self {
+                Self::NAN
+            }
+
+            #[inline]
+            fn infinity() -> Self {
+                Self::INFINITY
+            }
+
+            #[inline]
+            fn neg_infinity() -> Self {
+                Self::NEG_INFINITY
+            }
+
+            #[inline]
+            fn neg_zero() -> Self {
+                Self::NEG_ZERO
+            }
+
+            #[inline]
+            fn min_value() -> Self {
+                Self::MIN
+            }
+
+            #[inline]
+            fn min_positive_value() -> Self {
+                Self::MIN_POSITIVE
+            }
+
+            #[inline]
+            fn epsilon() -> Self {
+                Self::EPSILON
+            }
+
+            #[inline]
+            fn max_value() -> Self {
+                Self::MAX
+            }
+
+            #[inline]
+            fn is_nan(self) -> bool {
+                $half::is_nan(self)
+            }
+
+            #[inline]
+            fn is_infinite(self) -> bool {
+                $half::is_infinite(self)
+            }
+
+            #[inline]
+            fn is_finite(self) -> bool {
+                $half::is_finite(self)
+            }
+
+            #[inline]
+            fn is_normal(self) -> bool {
+                $half::is_normal(self)
+            }
+
+            #[inline]
+            fn is_subnormal(self) -> bool {
+                $half::is_subnormal(self)
+            }
+
+            #[inline]
+            fn classify(self) -> FpCategory {
+                $half::classify(self)
+            }
+
+            #[inline]
+            fn abs(self) -> Self {
+                $half::abs(self)
+            }
+
+            #[inline]
+            fn signum(self) -> Self {
+                $half::signum(self)
+            }
+
+            #[inline]
+            fn is_sign_positive(self) -> bool {
+                $half::is_sign_positive(self)
+            }
+
+            #[inline]
+            fn is_sign_negative(self) -> bool {
+                $half::is_sign_negative(self)
+            }
+
+            #[inline]
+            fn recip(self) -> Self {
+                $half::recip(self)
+            }
+
+            #[inline]
+            fn powi(self, n: i32) -> Self {
+                Self::from_f32(self.to_f32().powi(n))
+            }
+
+            #[inline]
+            fn to_degrees(self) -> Self {
+                $half::to_degrees(self)
+            }
+
+            #[inline]
+            fn to_radians(self) -> Self {
+                $half::to_radians(self)
+            }
+
+            #[inline]
+            fn max(self, other: Self) -> Self {
+                $half::max(self, other)
+            }
+
+            #[inline]
+            fn min(self, other: Self) -> Self {
+                $half::min(self, other)
+            }
+
+            #[inline]
+            fn clamp(self, min: Self, max: Self) -> Self {
+                $half::clamp(self, min, max)
+            }
+
+            #[inline]
+            fn copysign(self, sign: Self) -> Self {
+                $half::copysign(self, sign)
+            }
+
+            #[inline]
+            fn abs_sub(self, other: Self) -> Self {
+                Signed::abs_sub(&self, &other)
+            }
+
+            #[inline]
+            fn sin_cos(self) -> (Self, Self) {
+                $half::sin_cos(self)
+            }
+
+            #[inline]
+            fn integer_decode(self) -> (u64, i16, i8) {
+                let (sign, exp, man) = self.to_parts();
+                let exp = exp as i16;
+                let man = man as u64;
+                let mantissa_bits = Self::MANTISSA_DIGITS as i16 - 1;
+                // Subnormal values have the same scale as the smallest
+                // normal exponent, without the hidden bit.
+                let (mantissa, exponent) = if exp == 0 {
+                    (man << 1, exp)
+                } else {
+                    (man | (1 << mantissa_bits), exp)
+                };
+                let bias = Self::MAX_EXP as i16 - 1;
+                let sign = if sign {
+                    -1
+                } else {
+                    1
+                };
+                (mantissa, exponent - bias - mantissa_bits, sign)
+            }
+
+            via_f32! {
+                floor();
+                ceil();
+                round();
+                trunc();
+                fract();
+                mul_add(a, b);
+                powf(n);
+                sqrt();
+                exp();
+                exp2();
+                ln();
+                log(base);
+                log2();
+                log10();
+                cbrt();
+                hypot(other);
+                sin();
+                cos();
+                tan();
+                asin();
+                acos();
+                atan();
+                atan2(other);
+                exp_m1();
+                ln_1p();
+                sinh();
+                cosh();
+                tanh();
+                asinh();
+                acosh();
+                atanh();
+            }
+        }
+    };
+}
+
+impl_num_traits!(f16, 5, 10);
+impl_num_traits!(bf16, 8, 7);
+
+#[allow(clippy::float_cmp)]
+#[cfg(test)]
+mod test {
+    use num_complex::Complex;
+
+    use super::*;
+
+    /// Multiplies and adds complex values, checking against the same
+    /// operations in `f64` rounded to the half-precision type.
+    fn check_complex<T>(values: &[(f32, f32)])
+    where
+        T: Num + NumCast + ToPrimitive + Copy + core::fmt::Debug,
+    {
+        let half = |x: f32| -> T { NumCast::from(x).unwrap() };
+        // Every intermediate value is exact in `f32`, so this rounds once.
+        let round = |x: f64| -> f64 { <T as NumCast>::from(x as f32).unwrap().to_f64().unwrap() };
+        for &(ar, ai) in values {
+            for &(br, bi) in values {
+                let a = Complex::new(half(ar), half(ai));
+                let b = Complex::new(half(br), half(bi));
+                let (ar, ai) = (a.re.to_f64().unwrap(), a.im.to_f64().unwrap());
+                let (br, bi) = (b.re.to_f64().unwrap(), b.im.to_f64().unwrap());
+
+                // Each product and sum is rounded separately.
+                let product = a * b;
+                let re = round(round(ar * br) - round(ai * bi));
+                let im = round(round(ar * bi) + round(ai * br));
+                assert_eq!(product.re.to_f64().unwrap(), re, "{:?} * {:?}", a, b);
+                assert_eq!(product.im.to_f64().unwrap(), im, "{:?} * {:?}", a, b);
+
+                let sum = a + b;
+                assert_eq!(sum.re.to_f64().unwrap(), round(ar + br));
+                assert_eq!(sum.im.to_f64().unwrap(), round(ai + bi));
+            }
+        }
+    }
+
+    const COMPLEX_VALUES: [(f32, f32); 6] =
+        [(0., 0.), (1., 0.), (0., 1.), (1.5, -2.25), (-3.75, 0.5), (100., 7.)];
+
+    #[test]
+    fn test_complex_f16() {
+        check_complex::<f16>(&COMPLEX_VALUES);
+        let i = Complex::new(f16::ZERO, f16::ONE);
+        assert_eq!(i * i, Complex::new(f16::NEG_ONE, f16::ZERO));
+        assert_eq!(Complex::<f16>::i(), i);
+    }
+
+    #[test]
+    fn test_complex_bf16() {
+        check_complex::<bf16>(&COMPLEX_VALUES);
+        let i = Complex::new(bf16::ZERO, bf16::ONE);
+        assert_eq!(i * i, Complex::new(bf16::NEG_ONE, bf16::ZERO));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_complex_norm() {
+        let z = Complex::new(f16::from_f32(3.), f16::from_f32(4.));
+        assert_eq!(z.norm(), f16::from_f32(5.));
+        assert_eq!(z.arg(), f16::from_f32(4f32.atan2(3.)));
+
+        // The squared norm overflows half precision, but `hypot` computes the
+        // norm in `f32`, so it is still finite.
+        let z = Complex::new(f16::from_f32(300.), f16::from_f32(400.));
+        assert!(z.norm_sqr().is_infinite());
+        assert_eq!(z.norm(), f16::from_f32(500.));
+        let z = Complex::new(f16::MAX, f16::MAX);
+        assert!(z.norm().is_infinite());
+        let z = Complex::new(f16::MAX, f16::ZERO);
+        assert_eq!(z.norm(), f16::MAX);
+
+        let z = Complex::new(bf16::from_f32(3e38), bf16::from_f32(1e38));
+        assert!(z.norm().is_finite());
+        assert!(z.norm_sqr().is_infinite());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_float() {
+        fn generic<T: Float>(x: T) -> (T, T, T) {
+            (x.floor(), x.sqrt(), x.mul_add(x, T::one()))
+        }
+        let (floor, sqrt, mul_add) = generic(f16::from_f32(2.25));
+        assert_eq!(floor, f16::from_f32(2.));
+        assert_eq!(sqrt, f16::from_f32(1.5));
+        assert_eq!(mul_add, f16::from_f32(6.0625));
+
+        let decode = |(mantissa, exponent, sign): (u64, i16, i8)| {
+            sign as f64 * mantissa as f64 * 2f64.powi(exponent as i32)
+        };
+        for bits in 0..=u16::MAX {
+            let (x, y) = (f16::from_bits(bits), bf16::from_bits(bits));
+            if x.is_finite() {
+                assert_eq!(decode(Float::integer_decode(x)), x.to_f64(), "{:?}", x);
+            }
+            if y.is_finite() {
+                assert_eq!(decode(Float::integer_decode(y)), y.to_f64(), "{:?}", y);
+            }
+        }
+    }
+
+    #[test]
+    fn test_traits() {
+        assert!(f16::NEG_ZERO.is_zero());
+        assert!(!f16::MIN_POSITIVE_SUBNORMAL.is_zero());
+        assert_eq!(<bf16 as Num>::from_str_radix("-1.5", 10).ok(), Some(bf16::from_f32(-1.5)));
+        assert_eq!(<f16 as Num>::from_str_radix("-1.8", 16).ok(), Some(f16::from_f32(-1.5)));
+        assert!(<f16 as Num>::from_str_radix("1.5z", 10).is_err());
+        assert_eq!(<f16 as NumCast>::from(1e10f64), Some(f16::INFINITY));
+        assert_eq!(<f16 as NumCast>::from(3u8), Some(f16::from_f32(3.)));
+        assert_eq!(<bf16 as FromPrimitive>::from_u8(true as u8), Some(bf16::from_bool(true)));
+        assert_eq!(<f16 as FromPrimitive>::from_u8(false as u8), Some(f16::from_bool(false)));
+        assert_eq!(f16::from_f32(-2.5).to_i32(), Some(-2));
+        assert_eq!(f16::NAN.to_u8(), None);
+        assert_eq!(ToPrimitive::to_u32(&bf16::from_f32(1e10)), None);
+        assert_eq!(<f16 as Bounded>::max_value(), f16::MAX);
+        assert_eq!(Signed::abs_sub(&f16::ONE, &f16::from_f32(3.)), f16::ZERO);
+        assert_eq!(<f16 as FloatConst>::PI(), f16::PI);
+        assert_eq!(<bf16 as FloatConst>::TAU(), bf16::from_f32(core::f32::consts::TAU));
+    }
+
+    #[test]
+    fn test_round_once() {
+        // Rounding to `f64` first would drop the `1` and leave a tie, which
+        // rounds down to even, instead of up.
+        let n = (1i64 << 60) + (1 << 52) + 1;
+        let expected = bf16::from_f32(((1u64 << 60) + (1 << 53)) as f32);
+        assert_eq!(<bf16 as FromPrimitive>::from_i64(n), Some(expected));
+        assert_eq!(<bf16 as FromPrimitive>::from_i64(-n), Some(-expected));
+        assert_eq!(<bf16 as FromPrimitive>::from_u64(n as u64), Some(expected));
+        assert_eq!(<bf16 as NumCast>::from(n), Some(expected));
+        assert_eq!(<bf16 as NumCast>::from(n as u64), Some(expected));
+        assert_eq!(
+            <bf16 as FromPrimitive>::from_u128((n as u128) << 40),
+            Some(expected * bf16::from_f32(1099511627776.))
+        );
+        assert_eq!(<bf16 as NumCast>::from(u128::MAX), Some(bf16::INFINITY));
+        assert_eq!(<f16 as FromPrimitive>::from_i128(i128::MIN), Some(f16::NEG_INFINITY));
+
+        // Integers match the other conversions that round once.
+        for n in (-70_000..70_000).chain((0..i64::MAX).step_by(1 << 50)) {
+            let f = <f16 as FromPrimitive>::from_i64(n).unwrap();
+            let b = <bf16 as FromPrimitive>::from_i64(n).unwrap();
+            assert_eq!(f.to_bits(), f16::from_fixed_point(n, 0).to_bits(), "{}", n);
+            assert_eq!(b.to_bits(), bf16::from_fixed_point(n, 0).to_bits(), "{}", n);
+            assert_eq!(<f16 as NumCast>::from(n).map(f16::to_bits), Some(f.to_bits()));
+            assert_eq!(<bf16 as NumCast>::from(n).map(bf16::to_bits), Some(b.to_bits()));
+        }
+
+        // Floats are still rounded from their value, keeping negative zero.
+        assert_eq!(<f16 as NumCast>::from(-0f64).map(f16::to_bits), Some(0x8000));
+        assert_eq!(<f16 as NumCast>::from(2.5f32), Some(f16::from_f32(2.5)));
+        assert_eq!(<bf16 as NumCast>::from(1e30f64), Some(bf16::from_f64(1e30)));
+        assert!(<f16 as NumCast>::from(f64::NAN).unwrap().is_nan());
+
+        // Parsing `2049.0000001` as an `f32` would round it to `2049`, which
+        // is a tie that rounds down to `2048`.
+        let parse = |src| <f16 as Num>::from_str_radix(src, 10).ok();
+        assert_eq!(parse("2049.0000001"), Some(f16::from_f32(2050.)));
+        assert_eq!(parse("2049.0000001"), "2049.0000001".parse().ok());
+        assert!(matches!(
+            <f16 as Num>::from_str_radix("", 10),
+            Err(ParseFloatError {
+                kind: FloatErrorKind::Empty
+            })
+        ));
+        assert!(matches!(
+            <bf16 as Num>::from_str_radix("1.5.", 10),
+            Err(ParseFloatError {
+                kind: FloatErrorKind::Invalid
+            })
+        ));
+    }
+}
@@ -0,0 +1,345 @@
+//! Optional [`struct@f16`] and [`struct@bf16`] values in 16 bits.
+//!
+//! An [`Option<f16>`] needs a separate discriminant, which makes it twice the
+//! size of the value. These types instead reserve one quiet NaN bit pattern as
+//! the sentinel for `None`, so nullable columns of half-precision values take
+//! no extra memory. Every other bit pattern, including all other NaNs, is a
+//! present value.
+
+use core::fmt::{self, Debug, Formatter};
+
+use crate::{bf16, f16};
+
+macro_rules! option_half {
+    ($option:ident, $half:ident, $name:literal, $none:literal, $remap:literal) => {
+        #[doc = concat!("An optional [`struct@", $name, "`] value that is the same size as a [`struct@", $name, "`].")]
+        ///
+        #[doc = concat!(
+            "`None` is stored as the quiet NaN bit pattern [`NONE_BITS`][Self::NONE_BITS] (`",
+            stringify!($none), "`). Since that is a valid value, [`some`][Self::some] remaps it \
+            to the quiet NaN `", stringify!($remap), "` by clearing the lowest payload bit, so \
+            it stays present. All other values, including other NaNs, are stored unchanged."
+        )]
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// # use float16::*;
+        #[doc = concat!("let value = ", stringify!($option), "::some(", $name, "::ONE);")]
+        #[doc = concat!("assert_eq!(value.get(), Some(", $name, "::ONE));")]
+        #[doc = concat!("assert_eq!(", stringify!($option), "::NONE.get(), None);")]
+        #[doc = concat!("assert_eq!(core::mem::size_of::<", stringify!($option), ">(), 2);")]
+        /// ```
+        #[repr(transparent)]
+        #[derive(Clone, Copy)]
+        pub struct $option(u16);
+
+        impl $option {
+            /// The bit pattern used to store `None`.
+            pub const NONE_BITS: u16 = $none;
+
+            /// No value.
+            pub const NONE: $option = $option(Self::NONE_BITS);
+
+            /// Creates a present value.
+            ///
+            #[doc = concat!(
+                "A value with the bits of [`NONE_BITS`][Self::NONE_BITS] is stored as the NaN `",
+                stringify!($remap), "` instead, so it remains present."
+            )]
+            ///
+            /// # Examples
+            ///
+            /// ```rust
+            /// # use float16::*;
+            #[doc = concat!("let sentinel = ", $name, "::from_bits(", stringify!($option), "::NONE_BITS);")]
+            #[doc = concat!("let value = ", stringify!($option), "::some(sentinel);")]
+            /// assert!(value.is_some());
+            #[doc = concat!("assert_eq!(value.get().map(", $name, "::to_bits), Some(", stringify!($remap), "));")]
+            /// ```
+            #[inline]
+            #[must_use]
+            pub const fn some(value: $half) -> $option {
+                let bits = value.to_bits();
+                if bits == Self::NONE_BITS {
+                    $option($remap)
+                } else {
+                    $option(bits)
+                }
+            }
+
+            /// Returns the value, or `None` if there is no value.
+            #[inline]
+            #[must_use]
+            pub const fn get(self) -> Option<$half> {
+                if self.is_none() {
+                    None
+                } else {
+                    Some($half::from_bits(self.0))
+                }
+            }
+
+            /// Returns `true` if there is a value.
+            #[inline]
+            #[must_use]
+            pub const fn is_some(self) -> bool {
+                self.0 != Self::NONE_BITS
+            }
+
+            /// Returns `true` if there is no value.
+            #[inline]
+            #[must_use]
+            pub const fn is_none(self) -> bool {
+                self.0 == Self::NONE_BITS
+            }
+
+            /// Returns the value, or `default` if there is no value.
+            #[inline]
+            #[must_use]
+            pub const fn unwrap_or(self, default: $half) -> $half {
+                if self.is_none() {
+                    default
+                } else {
+                    $half::from_bits(self.0)
+                }
+            }
+
+            /// Returns the stored bits, which are
+            /// [`NONE_BITS`][Self::NONE_BITS] for `None`.
+            #[inline]
+            #[must_use]
+            pub const fn to_bits(self) -> u16 {
+                self.0
+            }
+
+            /// Creates an optional value from stored bits, where
+            /// [`NONE_BITS`][Self::NONE_BITS] is `None`.
+            #[inline]
+            #[must_use]
+            pub const fn from_bits(bits: u16) -> $option {
+                $option(bits)
+            }
+        }
+
+        impl Default for $option {
+            #[inline]
+            fn default() -> Self {
+                Self::NONE
+            }
+        }
+
+        impl PartialEq for $option {
+            /// Compares the same as [`Option`], so present NaN values are
+            /// never equal.
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.get() == other.get()
+            }
+        }
+
+        impl From<$half> for $option {
+            #[inline]
+            fn from(value: $half) -> Self {
+                Self::some(value)
+            }
+        }
+
+        impl From<Option<$half>> for $option {
+            #[inline]
+            fn from(value: Option<$half>) -> Self {
+                match value {
+                    Some(value) => Self::some(value),
+                    None => Self::NONE,
+                }
+            }
+        }
+
+        impl From<$option> for Option<$half> {
+            #[inline]
+            fn from(value: $option) -> Self {
+                value.get()
+            }
+        }
+
+        impl Debug for $option {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                Debug::fmt(&self.get(), f)
+            }
+        }
+    };
+}
+
+option_half!(OptionF16, f16, "f16", 0x7E01, 0x7E00);
+option_half!(OptionBf16, bf16, "bf16", 0x7FC1, 0x7FC0);
+
+/// Extensions to slices of [`OptionF16`] and [`OptionBf16`] values.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait OptionHalfSliceExt: private::SealedOptionHalfSlice {
+    /// The half-precision type of the present values.
+    type Half;
+
+    /// Counts the elements of `self` that have a value.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use float16::*;
+    /// let values = [OptionF16::some(f16::ONE), OptionF16::NONE, OptionF16::some(f16::NAN)];
+    /// assert_eq!(values.count_some(), 2);
+    /// ```
+    #[must_use]
+    fn count_some(&self) -> usize;
+
+    /// Replaces every element of `self` that has no value with `value`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use float16::*;
+    /// let mut values = [OptionBf16::some(bf16::ONE), OptionBf16::NONE];
+    /// values.fill_none_with(bf16::ZERO);
+    /// assert_eq!(values, [OptionBf16::some(bf16::ONE), OptionBf16::some(bf16::ZERO)]);
+    /// ```
+    fn fill_none_with(&mut self, value: Self::Half);
+}
+
+macro_rules! option_half_slice {
+    ($option:ident, $half:ident) => {
+        impl OptionHalfSliceExt for [$option] {
+            type Half = $half;
+
+            #[inline]
+            fn count_some(&self) -> usize {
+                self.iter().filter(|x| x.is_some()).count()
+            }
+
+            #[inline]
+            fn fill_none_with(&mut self, value: $half) {
+                let value = $option::some(value);
+                for x in self.iter_mut().filter(|x| x.is_none()) {
+                    *x = value;
+                }
+            }
+        }
+    };
+}
+
+option_half_slice!(OptionF16, f16);
+option_half_slice!(OptionBf16, bf16);
+
+mod private {
+    use super::{OptionBf16, OptionF16};
+
+    pub trait SealedOptionHalfSlice {}
+    impl SealedOptionHalfSlice for [OptionF16] {
+    }
+    impl SealedOptionHalfSlice for [OptionBf16] {
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::mem::{align_of, size_of};
+
+    use super::*;
+
+    #[test]
+    fn test_layout() {
+        assert_eq!(size_of::<OptionF16>(), 2);
+        assert_eq!(align_of::<OptionF16>(), align_of::<f16>());
+        assert_eq!(size_of::<OptionBf16>(), 2);
+        assert_eq!(align_of::<OptionBf16>(), align_of::<bf16>());
+        assert_eq!(size_of::<[OptionF16; 8]>(), 16);
+    }
+
+    #[test]
+    fn test_sentinel_is_nan() {
+        assert!(f16::from_bits(OptionF16::NONE_BITS).is_nan());
+        assert!(bf16::from_bits(OptionBf16::NONE_BITS).is_nan());
+        assert_eq!(OptionF16::default(), OptionF16::NONE);
+        assert_eq!(OptionBf16::default(), OptionBf16::NONE);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_round_trip_all() {
+        for bits in 0..=u16::MAX {
+            let value = OptionF16::some(f16::from_bits(bits));
+            assert!(value.is_some());
+            let out = value.get().unwrap();
+            if bits == OptionF16::NONE_BITS {
+                assert_eq!(out.to_bits(), 0x7E00);
+            } else {
+                assert_eq!(out.to_bits(), bits);
+            }
+
+            let value = OptionBf16::some(bf16::from_bits(bits));
+            assert!(value.is_some());
+            let out = value.get().unwrap();
+            if bits == OptionBf16::NONE_BITS {
+                assert_eq!(out.to_bits(), 0x7FC0);
+            } else {
+                assert_eq!(out.to_bits(), bits);
+            }
+        }
+    }
+
+    #[test]
+    fn test_nan_values() {
+        // Real NaNs stay present, and keep their bits.
+        for &bits in &[0x7E00u16, 0xFE00, 0x7C01, 0x7FFF, 0x7E02] {
+            let value = OptionF16::from(f16::from_bits(bits));
+            assert!(value.is_some());
+            assert_eq!(value.get().map(f16::to_bits), Some(bits));
+            // NaN only equals itself when `total-order-impls` compares bits.
+            if cfg!(feature = "total-order-impls") {
+                assert_eq!(value, value);
+            } else {
+                assert_ne!(value, value);
+            }
+        }
+        let value = OptionBf16::from(bf16::NAN);
+        assert!(value.get().unwrap().is_nan());
+
+        // The sentinel collides, and is remapped to another NaN.
+        let value = OptionF16::some(f16::from_bits(OptionF16::NONE_BITS));
+        assert_eq!(value.to_bits(), 0x7E00);
+        assert!(value.get().unwrap().is_nan());
+        let value = OptionBf16::some(bf16::from_bits(OptionBf16::NONE_BITS));
+        assert_eq!(value.to_bits(), 0x7FC0);
+        assert!(value.get().unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(OptionF16::from(Some(f16::ONE)).get(), Some(f16::ONE));
+        assert_eq!(OptionF16::from(None::<f16>), OptionF16::NONE);
+        assert_eq!(Option::<bf16>::from(OptionBf16::some(bf16::MAX)), Some(bf16::MAX));
+        assert_eq!(Option::<bf16>::from(OptionBf16::NONE), None);
+        assert_eq!(OptionF16::NONE.unwrap_or(f16::ONE), f16::ONE);
+        assert_eq!(OptionF16::some(f16::ZERO).unwrap_or(f16::ONE), f16::ZERO);
+        assert_eq!(OptionF16::from_bits(OptionF16::NONE_BITS), OptionF16::NONE);
+        assert!(OptionBf16::NONE.is_none());
+    }
+
+    #[test]
+    fn test_slices() {
+        let mut values = [
+            OptionF16::NONE,
+            OptionF16::some(f16::ONE),
+            OptionF16::some(f16::NAN),
+            OptionF16::NONE,
+        ];
+        assert_eq!(values.count_some(), 2);
+        values.fill_none_with(f16::ZERO);
+        assert_eq!(values.count_some(), 4);
+        assert_eq!(values[0].get(), Some(f16::ZERO));
+        assert_eq!(values[1].get(), Some(f16::ONE));
+        assert!(values[2].get().unwrap().is_nan());
+
+        // Filling with the sentinel still stores a present value.
+        let mut values = [OptionBf16::NONE; 3];
+        assert_eq!(values.count_some(), 0);
+        values.fill_none_with(bf16::from_bits(OptionBf16::NONE_BITS));
+        assert_eq!(values.count_some(), 3);
+    }
+}
@@ -0,0 +1,499 @@
+//! Conversions between [`struct@f16`] colors and the packed
+//! `R11G11B10_FLOAT` and `RGB9E5_SHAREDEXP` formats.
+//!
+//! These are the packed HDR color formats of Direct3D and Vulkan
+//! (`B10G11R11_UFLOAT_PACK32` and `E5B9G9R9_UFLOAT_PACK32`). Neither has a sign
+//! bit, so the packing functions define how every [`struct@f16`] value is
+//! clamped, following the Direct3D and Vulkan conversion rules:
+//!
+//! - `R11G11B10_FLOAT` stores the red and green channels as 11-bit floats, with
+//!   5 exponent bits and 6 mantissa bits, and the blue channel as a 10-bit
+//!   float, with 5 exponent bits and 5 mantissa bits. The exponent bias is the
+//!   same as [`struct@f16`], so values are rounded to the nearest representable
+//!   value, with ties to even. Negative values, including -∞, become `+0`,
+//!   finite values too large to fit become the largest finite value, +∞ is
+//!   preserved and NaN becomes a NaN.
+//! - `RGB9E5_SHAREDEXP` stores a 9-bit mantissa for each channel with a shared
+//!   5-bit exponent, and has no infinity or NaN. Negative values and NaN become
+//!   `+0`, and values above the largest representable value, `65408`, including
+//!   +∞, become it. The shared exponent is chosen from the largest channel, and
+//!   each channel is rounded to the nearest value with ties away from zero, as
+//!   specified by `EXT_texture_shared_exponent`.
+//!
+//! Unpacking is always exact, since every packed value is representable as a
+//! [`struct@f16`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use float16::*;
+//! use float16::packed::{pack_r11g11b10, pack_rgb9e5, unpack_r11g11b10, unpack_rgb9e5};
+//!
+//! let (r, g, b) = (f16::ONE, f16::from_f32(0.5), f16::from_f32(-2.0));
+//! assert_eq!(pack_r11g11b10(r, g, b), 0x001C_03C0);
+//! assert_eq!(unpack_r11g11b10(0x001C_03C0), (r, g, f16::ZERO));
+//! assert_eq!(unpack_rgb9e5(pack_rgb9e5(r, g, b)), (r, g, f16::ZERO));
+//! ```
+
+use crate::f16;
+use crate::fixed::round_to_half;
+
+/// The largest value of a `RGB9E5_SHAREDEXP` channel, `65408`, as a fixed-point
+/// value with 24 fractional bits.
+const RGB9E5_MAX: u64 = 511 << 31;
+
+/// Rounds the bits of a [`struct@f16`] value to an unsigned float with 5
+/// exponent bits and `man_bits` mantissa bits.
+#[inline]
+const fn to_small_float(bits: u16, man_bits: u32) -> u32 {
+    let shift = 10 - man_bits;
+    let inf = 0x1Fu32 << man_bits;
+    let bits = bits as u32;
+    if bits & 0x7FFF > 0x7C00 {
+        // NaN, keeping the top mantissa bit set so it is quiet
+        return inf | (1 << (man_bits - 1));
+    }
+    if bits & 0x8000 != 0 {
+        return 0;
+    }
+    if bits == 0x7C00 {
+        return inf;
+    }
+    // Round to nearest, ties to even, which may carry into the exponent, and
+    // clamp finite values to the largest finite value
+    let rounded = (bits + (1 << (shift - 1)) - 1 + ((bits >> shift) & 1)) >> shift;
+    if rounded >= inf {
+        inf - 1
+    } else {
+        rounded
+    }
+}
+
+/// Converts an unsigned float with 5 exponent bits and `man_bits` mantissa
+/// bits to a [`struct@f16`] value, which is exact.
+#[inline]
+const fn from_small_float(bits: u32, man_bits: u32) -> f16 {
+    let mask = (1u32 << (5 + man_bits)) - 1;
+    f16::from_bits(((bits & mask) << (10 - man_bits)) as u16)
+}
+
+/// Packs three [`struct@f16`] values into the `R11G11B10_FLOAT` format.
+///
+/// Red is stored in bits 0 to 10, green in bits 11 to 21 and blue in bits 22
+/// to 31. See the [module documentation](self) for how the values are rounded
+/// and clamped.
+///
+/// # Examples
+///
+/// ```rust
+/// # use float16::*;
+/// use float16::packed::pack_r11g11b10;
+///
+/// assert_eq!(pack_r11g11b10(f16::ONE, f16::ONE, f16::ONE), 0x781E_03C0);
+/// assert_eq!(pack_r11g11b10(f16::NEG_INFINITY, f16::ZERO, f16::ZERO), 0);
+/// ```
+#[inline]
+#[must_use]
+pub const fn pack_r11g11b10(r: f16, g: f16, b: f16) -> u32 {
+    to_small_float(r.to_bits(), 6)
+        | (to_small_float(g.to_bits(), 6) << 11)
+        | (to_small_float(b.to_bits(), 5) << 22)
+}
+
+/// Unpacks a `R11G11B10_FLOAT` value into its red, green and blue
+/// [`struct@f16`] values.
+///
+/// This is exact, and the inverse of [`pack_r11g11b10`] for every
+/// representable value.
+///
+/// # Examples
+///
+/// ```rust
+/// # use float16::*;
+/// use float16::packed::unpack_r11g11b10;
+///
+/// assert_eq!(unpack_r11g11b10(0x781E_03C0), (f16::ONE, f16::ONE, f16::ONE));
+/// ```
+#[inline]
+#[must_use]
+pub const fn unpack_r11g11b10(packed: u32) -> (f16, f16, f16) {
+    (
+        from_small_float(packed, 6),
+        from_small_float(packed >> 11, 6),
+        from_small_float(packed >> 22, 5),
+    )
+}
+
+/// Converts the bits of a [`struct@f16`] value to a clamped fixed-point value
+/// with 24 fractional bits, for the `RGB9E5_SHAREDEXP` format.
+#[inline]
+const fn to_rgb9e5_fixed(bits: u16) -> u64 {
+    // Negative values and NaN are clamped to zero
+    if bits & 0x8000 != 0 || bits & 0x7FFF > 0x7C00 {
+        return 0;
+    }
+    let exp = (bits >> 10) as u32;
+    let man = (bits & 0x03FF) as u64;
+    let fixed = if exp == 0 {
+        man
+    } else {
+        (man | 0x0400) << (exp - 1)
+    };
+    if fixed > RGB9E5_MAX {
+        RGB9E5_MAX
+    } else {
+        fixed
+    }
+}
+
+/// Rounds a fixed-point value with 24 fractional bits to a 9-bit mantissa for
+/// the shared exponent, with ties away from zero.
+#[inline]
+const fn rgb9e5_mantissa(fixed: u64, exp: u32) -> u32 {
+    if exp == 0 {
+        fixed as u32
+    } else {
+        ((fixed + (1 << (exp - 1))) >> exp) as u32
+    }
+}
+
+/// Packs three [`struct@f16`] values into the `RGB9E5_SHAREDEXP` format.
+///
+/// Red is stored in bits 0 to 8, green in bits 9 to 17, blue in bits 18 to 26
+/// and the shared exponent in bits 27 to 31. See the [module
+/// documentation](self) for how the values are rounded and clamped.
+///
+/// # Examples
+///
+/// ```rust
+/// # use float16::*;
+/// use float16::packed::pack_rgb9e5;
+///
+/// assert_eq!(pack_rgb9e5(f16::ONE, f16::ONE, f16::ONE), 0x8402_0100);
+/// assert_eq!(pack_rgb9e5(f16::NAN, f16::ZERO, f16::ZERO), 0);
+/// ```
+#[inline]
+#[must_use]
+pub const fn pack_rgb9e5(r: f16, g: f16, b: f16) -> u32 {
+    let r = to_rgb9e5_fixed(r.to_bits());
+    let g = to_rgb9e5_fixed(g.to_bits());
+    let b = to_rgb9e5_fixed(b.to_bits());
+    let mut max = r;
+    if g > max {
+        max = g;
+    }
+    if b > max {
+        max = b;
+    }
+
+    // The shared exponent is `max(-16, floor(log2(max))) + 16`, which is the
+    // number of fractional bits to shift out, since the mantissas have 9 bits
+    // and the exponent bias is 15.
+    let mut exp = if max < (1 << 8) {
+        0
+    } else {
+        63 - max.leading_zeros() - 8
+    };
+    // Rounding the largest channel may need another bit
+    if rgb9e5_mantissa(max, exp) == 1 << 9 {
+        exp += 1;
+    }
+    rgb9e5_mantissa(r, exp)
+        | (rgb9e5_mantissa(g, exp) << 9)
+        | (rgb9e5_mantissa(b, exp) << 18)
+        | (exp << 27)
+}
+
+/// Unpacks a `RGB9E5_SHAREDEXP` value into its red, green and blue
+/// [`struct@f16`] values.
+///
+/// This is exact, since every channel is a 9-bit integer times a power of two
+/// within the range of [`struct@f16`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use float16::*;
+/// use float16::packed::unpack_rgb9e5;
+///
+/// assert_eq!(unpack_rgb9e5(0x8402_0100), (f16::ONE, f16::ONE, f16::ONE));
+/// ```
+#[inline]
+#[must_use]
+pub const fn unpack_rgb9e5(packed: u32) -> (f16, f16, f16) {
+    let exp = (packed >> 27) as i64 - 24;
+    let r = (packed & 0x01FF) as u64;
+    let g = ((packed >> 9) & 0x01FF) as u64;
+    let b = ((packed >> 18) & 0x01FF) as u64;
+    (
+        f16::from_bits(round_to_half(r, exp, false, 5, 10)),
+        f16::from_bits(round_to_half(g, exp, false, 5, 10)),
+        f16::from_bits(round_to_half(b, exp, false, 5, 10)),
+    )
+}
+
+/// Packs a buffer of `[r, g, b]` [`struct@f16`] pixels into the
+/// `R11G11B10_FLOAT` format, as by [`pack_r11g11b10`].
+///
+/// # Panics
+///
+/// This function will panic if the two slices have different lengths.
+///
+/// # Examples
+///
+/// ```rust
+/// # use float16::*;
+/// use float16::packed::pack_r11g11b10_slice;
+///
+/// let pixels = [[f16::ONE; 3], [f16::ZERO; 3]];
+/// let mut packed = [0u32; 2];
+/// pack_r11g11b10_slice(&pixels, &mut packed);
+/// assert_eq!(packed, [0x781E_03C0, 0]);
+/// ```
+#[inline]
+pub fn pack_r11g11b10_slice(src: &[[f16; 3]], dst: &mut [u32]) {
+    assert_eq!(dst.len(), src.len(), "destination and source slices have different lengths");
+
+    for (d, &[r, g, b]) in dst.iter_mut().zip(src.iter()) {
+        *d = pack_r11g11b10(r, g, b);
+    }
+}
+
+/// Unpacks a buffer of `R11G11B10_FLOAT` values into `[r, g, b]`
+/// [`struct@f16`] pixels, as by [`unpack_r11g11b10`].
+///
+/// # Panics
+///
+/// This function will panic if the two slices have different lengths.
+#[inline]
+pub fn unpack_r11g11b10_slice(src: &[u32], dst: &mut [[f16; 3]]) {
+    assert_eq!(dst.len(), src.len(), "destination and source slices have different lengths");
+
+    for (d, &packed) in dst.iter_mut().zip(src.iter()) {
+        let (r, g, b) = unpack_r11g11b10(packed);
+        *d = [r, g, b];
+    }
+}
+
+/// Packs a buffer of `[r, g, b]` [`struct@f16`] pixels into the
+/// `RGB9E5_SHAREDEXP` format, as by [`pack_rgb9e5`].
+///
+/// # Panics
+///
+/// This function will panic if the two slices have different lengths.
+#[inline]
+pub fn pack_rgb9e5_slice(src: &[[f16; 3]], dst: &mut [u32]) {
+    assert_eq!(dst.len(), src.len(), "destination and source slices have different lengths");
+
+    for (d, &[r, g, b]) in dst.iter_mut().zip(src.iter()) {
+        *d = pack_rgb9e5(r, g, b);
+    }
+}
+
+/// Unpacks a buffer of `RGB9E5_SHAREDEXP` values into `[r, g, b]`
+/// [`struct@f16`] pixels, as by [`unpack_rgb9e5`].
+///
+/// # Panics
+///
+/// This function will panic if the two slices have different lengths.
+///
+/// # Examples
+///
+/// ```rust
+/// # use float16::*;
+/// use float16::packed::{pack_rgb9e5_slice, unpack_rgb9e5_slice};
+///
+/// let pixels = [[f16::ONE, f16::from_f32(0.25), f16::ZERO]; 4];
+/// let mut packed = [0u32; 4];
+/// pack_rgb9e5_slice(&pixels, &mut packed);
+/// let mut unpacked = [[f16::NAN; 3]; 4];
+/// unpack_rgb9e5_slice(&packed, &mut unpacked);
+/// assert_eq!(unpacked, pixels);
+/// ```
+#[inline]
+pub fn unpack_rgb9e5_slice(src: &[u32], dst: &mut [[f16; 3]]) {
+    assert_eq!(dst.len(), src.len(), "destination and source slices have different lengths");
+
+    for (d, &packed) in dst.iter_mut().zip(src.iter()) {
+        let (r, g, b) = unpack_rgb9e5(packed);
+        *d = [r, g, b];
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn f(value: f32) -> f16 {
+        f16::from_f32(value)
+    }
+
+    #[test]
+    fn test_r11g11b10_reference() {
+        // Spec-derived bit patterns: 1.0 has a biased exponent of 15 and no
+        // mantissa in every channel.
+        assert_eq!(pack_r11g11b10(f16::ONE, f16::ZERO, f16::ZERO), 0x3C0);
+        assert_eq!(pack_r11g11b10(f16::ZERO, f16::ONE, f16::ZERO), 0x3C0 << 11);
+        assert_eq!(pack_r11g11b10(f16::ZERO, f16::ZERO, f16::ONE), 0x1E0 << 22);
+        // The largest finite values are 65024 and 64512.
+        assert_eq!(pack_r11g11b10(f(65024.), f(65024.), f(64512.)), 0xF7FD_FFBF);
+        assert_eq!(unpack_r11g11b10(0xF7FD_FFBF), (f(65024.), f(65024.), f(64512.)));
+        // The smallest subnormals are 2^-20 and 2^-19.
+        let tiny = (f(2f32.powi(-20)), f(2f32.powi(-20)), f(2f32.powi(-19)));
+        assert_eq!(pack_r11g11b10(tiny.0, tiny.1, tiny.2), 0x0040_0801);
+        assert_eq!(unpack_r11g11b10(0x0040_0801), tiny);
+        // 1 + 2^-7 is a tie, which rounds to even, and 1 + 3 * 2^-7 rounds up.
+        assert_eq!(
+            pack_r11g11b10(f(1. + 1. / 128.), f(1. + 3. / 128.), f16::ZERO),
+            0x3C0 | (0x3C2 << 11)
+        );
+        assert_eq!(pack_r11g11b10(f16::ZERO, f16::ZERO, f(1. + 3. / 64.)), 0x1E2 << 22);
+    }
+
+    #[test]
+    fn test_r11g11b10_clamping() {
+        let special = |value: f16| {
+            let packed = pack_r11g11b10(value, value, value);
+            let (r, g, b) = unpack_r11g11b10(packed);
+            (packed, r, g, b)
+        };
+
+        // Negative values, including -0 and -∞, become +0.
+        for &value in &[f(-1.), f16::NEG_ZERO, f16::NEG_INFINITY, f16::MIN, f(-1e-7)] {
+            assert_eq!(special(value), (0, f16::ZERO, f16::ZERO, f16::ZERO));
+        }
+        // +∞ is preserved.
+        let (packed, r, g, b) = special(f16::INFINITY);
+        assert_eq!(packed, 0x7C0 | (0x7C0 << 11) | (0x3E0 << 22));
+        assert_eq!((r, g, b), (f16::INFINITY, f16::INFINITY, f16::INFINITY));
+        // NaN of either sign stays NaN.
+        for &value in &[f16::NAN, -f16::NAN, f16::from_bits(0x7C01)] {
+            let (_, r, g, b) = special(value);
+            assert!(r.is_nan() && g.is_nan() && b.is_nan());
+        }
+        // Finite values too large to fit become the largest finite value.
+        let (_, r, g, b) = special(f16::MAX);
+        assert_eq!((r, g, b), (f(65024.), f(65024.), f(64512.)));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_r11g11b10_round_trip() {
+        for bits in 0..0x7C00u16 {
+            let value = f16::from_bits(bits);
+            let x = value.to_f64();
+            let (r, g, b) = unpack_r11g11b10(pack_r11g11b10(value, value, value));
+            for &(channel, max, eps) in &[(r, 65024., 7), (g, 65024., 7), (b, 64512., 6)] {
+                let y = channel.to_f64();
+                if x >= max {
+                    assert_eq!(y, max);
+                } else {
+                    // Half an ulp, relative to normals or absolute for subnormals
+                    let bound = (x * 2f64.powi(-eps)).max(2f64.powi(-14 - eps));
+                    assert!((x - y).abs() <= bound, "{:#06x} {} {}", bits, x, y);
+                }
+            }
+        }
+
+        // Every packed value round trips exactly.
+        for bits in 0..0x7FFu32 {
+            let packed = bits | (bits << 11) | ((bits >> 1) << 22);
+            let (r, g, b) = unpack_r11g11b10(packed);
+            if r.is_nan() {
+                continue;
+            }
+            assert_eq!(pack_r11g11b10(r, g, b), packed);
+        }
+    }
+
+    #[test]
+    fn test_rgb9e5_reference() {
+        // 1.0 has the shared exponent 16 and a mantissa of 256.
+        assert_eq!(pack_rgb9e5(f16::ONE, f16::ZERO, f16::ZERO), (16 << 27) | 256);
+        assert_eq!(
+            pack_rgb9e5(f16::ONE, f(0.5), f(0.25)),
+            (16 << 27) | 256 | (128 << 9) | (64 << 18)
+        );
+        // The largest value, 511 * 2^7, and the smallest, 2^-24.
+        assert_eq!(pack_rgb9e5(f(65408.), f16::ZERO, f16::ZERO), 0xF800_01FF);
+        assert_eq!(unpack_rgb9e5(0xF800_01FF), (f(65408.), f16::ZERO, f16::ZERO));
+        let tiny = f16::MIN_POSITIVE_SUBNORMAL;
+        assert_eq!(pack_rgb9e5(tiny, tiny, tiny), 1 | (1 << 9) | (1 << 18));
+        assert_eq!(unpack_rgb9e5(1 | (1 << 9) | (1 << 18)), (tiny, tiny, tiny));
+        assert_eq!(pack_rgb9e5(f16::ZERO, f16::ZERO, f16::ZERO), 0);
+
+        // Rounding the largest channel up to 512 increments the exponent:
+        // 1.999 would be 511.75 with the exponent 16, so it is 256 with 17.
+        let x = f16::from_bits(0x3FFF);
+        assert_eq!(pack_rgb9e5(x, f16::ZERO, f16::ZERO), (17 << 27) | 256);
+        assert_eq!(unpack_rgb9e5(pack_rgb9e5(x, f16::ZERO, f16::ZERO)).0, f(2.));
+        // Smaller channels lose precision to the shared exponent, and round
+        // half-way cases away from zero.
+        let (_, g, b) = unpack_rgb9e5(pack_rgb9e5(f(256.), f(1.5), f(0.25)));
+        assert_eq!((g, b), (f(2.), f16::ZERO));
+        let (_, g, _) = unpack_rgb9e5(pack_rgb9e5(f(256.), f(0.5), f16::ZERO));
+        assert_eq!(g, f16::ONE);
+    }
+
+    #[test]
+    fn test_rgb9e5_clamping() {
+        // Negative values and NaN become +0, and too large values, including
+        // +∞, become the largest value.
+        for &value in &[f(-1.), f16::NEG_ZERO, f16::NEG_INFINITY, f16::NAN, -f16::NAN] {
+            assert_eq!(pack_rgb9e5(value, value, value), 0);
+        }
+        for &value in &[f16::INFINITY, f16::MAX, f(65420.)] {
+            let packed = pack_rgb9e5(value, f16::ONE, f16::NAN);
+            assert_eq!(unpack_rgb9e5(packed), (f(65408.), f16::ZERO, f16::ZERO));
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_rgb9e5_round_trip() {
+        for bits in 0..0x7C00u16 {
+            let value = f16::from_bits(bits);
+            let x = value.to_f64();
+            let packed = pack_rgb9e5(value, value, f16::ZERO);
+            let (r, g, b) = unpack_rgb9e5(packed);
+            assert_eq!(r, g);
+            assert_eq!(b, f16::ZERO);
+            // Half of the unit of the 9-bit mantissas
+            let exp = (packed >> 27) as i32;
+            let bound = if x >= 65408. {
+                0.
+            } else {
+                2f64.powi(exp - 25)
+            };
+            assert!((x.min(65408.) - r.to_f64()).abs() <= bound, "{:#06x} {} {}", bits, x, r);
+            assert_eq!(pack_rgb9e5(r, g, b), packed);
+        }
+    }
+
+    #[test]
+    fn test_slices() {
+        let pixels = [[f(1.), f(-1.), f(0.5)], [f16::INFINITY, f16::NAN, f(100.)], [f16::ZERO; 3]];
+        let mut packed = [0u32; 3];
+        pack_r11g11b10_slice(&pixels, &mut packed);
+        let mut unpacked = [[f16::ZERO; 3]; 3];
+        unpack_r11g11b10_slice(&packed, &mut unpacked);
+        for (pixel, &packed) in unpacked.iter().zip(packed.iter()) {
+            let (r, g, b) = unpack_r11g11b10(packed);
+            assert_eq!(pixel.map(f16::to_bits), [r, g, b].map(f16::to_bits));
+        }
+        assert_eq!(unpacked[0], [f(1.), f16::ZERO, f(0.5)]);
+
+        pack_rgb9e5_slice(&pixels, &mut packed);
+        unpack_rgb9e5_slice(&packed, &mut unpacked);
+        // The blue channel, 100, is rounded to a multiple of 128.
+        assert_eq!(unpacked[1], [f(65408.), f16::ZERO, f(128.)]);
+        assert_eq!(unpacked[2], [f16::ZERO; 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slice_len_mismatch_panics() {
+        let mut packed = [0u32; 2];
+        pack_rgb9e5_slice(&[[f16::ONE; 3]], &mut packed);
+    }
+}
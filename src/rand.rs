@@ -0,0 +1,156 @@
+//! Implementations of the [`rand`](https://docs.rs/rand/) and
+//! [`rand_distr`](https://docs.rs/rand_distr/) distributions for
+//! [`struct@f16`] and [`struct@bf16`].
+//!
+//! [`Standard`] samples uniformly from `[0, 1)`, using every representable
+//! value with the same spacing as the largest values below `1`. The
+//! [`StandardNormal`] and [`Exp1`] samples are drawn as [`f64`] values and
+//! rounded once to the nearest representable value, so there is no double
+//! rounding through [`f32`]. Their tails are truncated at the representable
+//! range: values too small to be represented become zero, and for
+//! [`struct@f16`], [`Exp1`] samples above [`f16::MAX`] saturate to it, which
+//! in practice never happens.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use float16::*;
+//! use rand::Rng;
+//! use rand_distr::StandardNormal;
+//!
+//! let mut rng = rand::thread_rng();
+//! let x: f16 = rng.gen();
+//! assert!(x >= f16::ZERO && x < f16::ONE);
+//! let weight: bf16 = rng.sample(StandardNormal);
+//! assert!(weight.is_finite());
+//! ```
+
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+use rand_distr::{Exp1, StandardNormal};
+
+use crate::{bf16, f16};
+
+macro_rules! impl_distributions {
+    ($half:ident, $precision:literal, $from_f64:ident) => {
+        impl Distribution<$half> for Standard {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $half {
+                // The top bits form a multiple of the smallest spacing below
+                // 1, which is exactly representable and never rounds up to 1.
+                let value = rng.gen::<u16>() >> (16 - $precision);
+                $half::from_f32(value as f32 / (1u32 << $precision) as f32)
+            }
+        }
+
+        impl Distribution<$half> for StandardNormal {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $half {
+                let value: f64 = rng.sample(StandardNormal);
+                $half::$from_f64(value)
+            }
+        }
+
+        impl Distribution<$half> for Exp1 {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $half {
+                let value: f64 = rng.sample(Exp1);
+                $half::$from_f64(value)
+            }
+        }
+    };
+}
+
+impl_distributions!(f16, 11, from_f64_saturating);
+impl_distributions!(bf16, 8, from_f64);
+
+#[cfg(test)]
+mod test {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    const SAMPLES: usize = 100_000;
+
+    /// Returns the mean and variance of samples of `H`, computed in [`f64`].
+    fn moments<H, D, F>(distribution: D, to_f64: F) -> (f64, f64)
+    where
+        D: Distribution<H>,
+        F: Fn(H) -> f64,
+    {
+        let mut rng = StdRng::seed_from_u64(0x5EED);
+        let (mut sum, mut sum_sq) = (0f64, 0f64);
+        for _ in 0..SAMPLES {
+            let x = to_f64(distribution.sample(&mut rng));
+            assert!(x.is_finite());
+            sum += x;
+            sum_sq += x * x;
+        }
+        let mean = sum / SAMPLES as f64;
+        (mean, sum_sq / SAMPLES as f64 - mean * mean)
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_moments() {
+        let (mean, variance) = moments(Standard, f16::to_f64);
+        assert!((mean - 0.5).abs() < 0.01, "{}", mean);
+        assert!((variance - 1. / 12.).abs() < 0.01, "{}", variance);
+        let (mean, variance) = moments(Standard, bf16::to_f64);
+        assert!((mean - 0.5).abs() < 0.01, "{}", mean);
+        assert!((variance - 1. / 12.).abs() < 0.01, "{}", variance);
+
+        let (mean, variance) = moments(StandardNormal, f16::to_f64);
+        assert!(mean.abs() < 0.02, "{}", mean);
+        assert!((variance - 1.).abs() < 0.02, "{}", variance);
+        let (mean, variance) = moments(StandardNormal, bf16::to_f64);
+        assert!(mean.abs() < 0.02, "{}", mean);
+        assert!((variance - 1.).abs() < 0.02, "{}", variance);
+
+        let (mean, variance) = moments(Exp1, f16::to_f64);
+        assert!((mean - 1.).abs() < 0.02, "{}", mean);
+        assert!((variance - 1.).abs() < 0.05, "{}", variance);
+        let (mean, variance) = moments(Exp1, bf16::to_f64);
+        assert!((mean - 1.).abs() < 0.02, "{}", mean);
+        assert!((variance - 1.).abs() < 0.05, "{}", variance);
+    }
+
+    #[test]
+    fn test_uniform_range() {
+        let mut rng = StdRng::seed_from_u64(1);
+        for _ in 0..1000 {
+            let x: f16 = rng.gen();
+            assert!(x >= f16::ZERO && x < f16::ONE);
+            let x: bf16 = rng.gen();
+            assert!(x >= bf16::ZERO && x < bf16::ONE);
+        }
+    }
+
+    #[test]
+    fn test_deterministic() {
+        // The samples are the rounded samples of the same distribution in
+        // f64, so they only depend on the generator.
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut reference = StdRng::seed_from_u64(42);
+        for _ in 0..100 {
+            let x: f16 = rng.sample(StandardNormal);
+            let y: f64 = reference.sample(StandardNormal);
+            assert_eq!(x, f16::from_f64(y));
+            let x: bf16 = rng.sample(Exp1);
+            let y: f64 = reference.sample(Exp1);
+            assert_eq!(x, bf16::from_f64(y));
+        }
+
+        // A snapshot of the first samples, which only changes if `StdRng` or
+        // the sampling in `rand_distr` changes.
+        let mut rng = StdRng::seed_from_u64(42);
+        let samples: [u16; 4] = [
+            rng.gen::<f16>().to_bits(),
+            rng.sample::<f16, _>(StandardNormal).to_bits(),
+            rng.sample::<bf16, _>(StandardNormal).to_bits(),
+            rng.sample::<f16, _>(Exp1).to_bits(),
+        ];
+        assert_eq!(samples, [0x3094, 0xBAFE, 0x3FF8, 0x3F64]);
+    }
+}
@@ -0,0 +1,71 @@
+//! Rounding modes for conversions to half-precision values.
+
+/// The rounding mode used when a value is not exactly representable.
+///
+/// These are the rounding-direction attributes of IEEE 754-2008. The
+/// conversions that do not take a mode, such as
+/// [`f16::from_f32`][crate::f16::from_f32], always use
+/// [`NearestEven`][RoundingMode::NearestEven].
+///
+/// # Examples
+///
+/// ```rust
+/// # use float16::*;
+/// // 1 + 2^-11 is half-way between 1 and the next value, 1 + 2^-10.
+/// let tie = 1.0 + f32::powi(2.0, -11);
+/// assert_eq!(f16::from_f32_round(tie, RoundingMode::NearestEven), f16::ONE);
+/// assert_eq!(f16::from_f32_round(tie, RoundingMode::NearestAway), f16::ONE + f16::EPSILON);
+/// assert_eq!(f16::from_f32_round(-1e6, RoundingMode::TowardZero), f16::MIN);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round to the nearest value, with half-way cases rounded to the value
+    /// with an even least significant digit. This is the default.
+    NearestEven,
+    /// Round to the nearest value, with half-way cases rounded away from
+    /// zero.
+    NearestAway,
+    /// Round toward zero, truncating the value.
+    TowardZero,
+    /// Round toward positive infinity.
+    TowardPositive,
+    /// Round toward negative infinity.
+    TowardNegative,
+}
+
+impl Default for RoundingMode {
+    #[inline]
+    fn default() -> Self {
+        RoundingMode::NearestEven
+    }
+}
+
+impl RoundingMode {
+    /// Returns if the truncated magnitude should be incremented.
+    ///
+    /// `lsb` is the least significant kept bit, `round` is the first
+    /// discarded bit and `sticky` is set if any later discarded bit is set.
+    #[inline]
+    pub(crate) const fn round_up(
+        self,
+        negative: bool,
+        lsb: bool,
+        round: bool,
+        sticky: bool,
+    ) -> bool {
+        match self {
+            RoundingMode::NearestEven => round && (sticky || lsb),
+            RoundingMode::NearestAway => round,
+            RoundingMode::TowardZero => false,
+            RoundingMode::TowardPositive => !negative && (round || sticky),
+            RoundingMode::TowardNegative => negative && (round || sticky),
+        }
+    }
+
+    /// Returns if a finite value too large for the type rounds to infinity,
+    /// instead of the largest finite value.
+    #[inline]
+    pub(crate) const fn overflows_to_infinity(self, negative: bool) -> bool {
+        self.round_up(negative, false, true, true)
+    }
+}
@@ -0,0 +1,103 @@
+//! Implementations of the [`schemars`](https://docs.rs/schemars/) `JsonSchema`
+//! trait for [`struct@f16`] and [`struct@bf16`].
+//!
+//! Both types are described as a JSON `number`, with the format `float16` or
+//! `bfloat16`, and with the finite range of the type as the minimum and
+//! maximum. The schemas are always inlined, the same as the primitive floats.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use float16::*;
+//! let schema = schemars::schema_for!(f16);
+//! let number = schema.schema.number.unwrap();
+//! assert_eq!(number.maximum, Some(f16::MAX.to_f64()));
+//! assert_eq!(schema.schema.format.as_deref(), Some("float16"));
+//! ```
+
+use alloc::{borrow::ToOwned, boxed::Box, string::String};
+
+use schemars::gen::SchemaGenerator;
+use schemars::schema::{InstanceType, Metadata, NumberValidation, Schema, SchemaObject};
+use schemars::JsonSchema;
+
+use crate::{bf16, f16};
+
+macro_rules! impl_json_schema {
+    ($half:ident, $format:literal, $description:literal) => {
+        impl JsonSchema for $half {
+            fn is_referenceable() -> bool {
+                false
+            }
+
+            fn schema_name() -> String {
+                $format.to_owned()
+            }
+
+            fn json_schema(_: &mut SchemaGenerator) -> Schema {
+                SchemaObject {
+                    metadata: Some(Box::new(Metadata {
+                        description: Some($description.to_owned()),
+                        ..Default::default()
+                    })),
+                    instance_type: Some(InstanceType::Number.into()),
+                    format: Some($format.to_owned()),
+                    number: Some(Box::new(NumberValidation {
+                        minimum: Some($half::MIN.to_f64_const()),
+                        maximum: Some($half::MAX.to_f64_const()),
+                        ..Default::default()
+                    })),
+                    ..Default::default()
+                }
+                .into()
+            }
+        }
+    };
+}
+
+impl_json_schema!(f16, "float16", "A 16-bit IEEE 754 binary16 floating point number.");
+impl_json_schema!(bf16, "bfloat16", "A 16-bit bfloat16 floating point number.");
+
+#[cfg(test)]
+mod test {
+    use schemars::schema::{Schema, SchemaObject};
+    use schemars::{schema_for, JsonSchema};
+
+    use super::*;
+
+    #[derive(JsonSchema)]
+    #[allow(dead_code)]
+    struct Weights {
+        scale: f16,
+        bias: bf16,
+    }
+
+    fn property(schema: &SchemaObject, name: &str) -> SchemaObject {
+        match &schema.object.as_ref().unwrap().properties[name] {
+            Schema::Object(schema) => schema.clone(),
+            Schema::Bool(_) => panic!("expected a schema object"),
+        }
+    }
+
+    #[test]
+    fn test_struct_schema() {
+        let root = schema_for!(Weights);
+        assert!(root.definitions.is_empty());
+
+        let scale = property(&root.schema, "scale");
+        assert_eq!(scale.instance_type, Some(InstanceType::Number.into()));
+        assert_eq!(scale.format.as_deref(), Some("float16"));
+        let number = scale.number.unwrap();
+        assert_eq!(number.minimum, Some(f16::MIN.to_f64()));
+        assert_eq!(number.maximum, Some(f16::MAX.to_f64()));
+        assert_eq!(number.maximum, Some(65504.0));
+        assert!(scale.metadata.unwrap().description.is_some());
+
+        let bias = property(&root.schema, "bias");
+        assert_eq!(bias.instance_type, Some(InstanceType::Number.into()));
+        assert_eq!(bias.format.as_deref(), Some("bfloat16"));
+        let number = bias.number.unwrap();
+        assert_eq!(number.minimum, Some(bf16::MIN.to_f64()));
+        assert_eq!(number.maximum, Some(bf16::MAX.to_f64()));
+    }
+}
@@ -0,0 +1,300 @@
+//! Implementations of the [`serde`](https://docs.rs/serde/) `Serialize` and
+//! `Deserialize` traits for [`struct@f16`] and [`struct@bf16`].
+//!
+//! Human-readable formats, such as JSON, serialize the value as an [`f32`],
+//! which holds every value exactly, so it appears as a number. Like the
+//! primitive floats, how NaN and infinite values are written depends on the
+//! format: JSON writes them as `null`, which cannot be read back. Reading
+//! accepts any number, which is rounded once to the nearest value, or a string
+//! in the format of [`FromStr`][core::str::FromStr].
+//!
+//! Compact formats, such as bincode, CBOR and MessagePack, serialize the bits
+//! of the value as a [`u16`], so every value round trips, including NaN
+//! payloads and the sign of zero.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use float16::*;
+//! let json = serde_json::to_string(&[f16::from_f32(1.5), f16::MAX]).unwrap();
+//! assert_eq!(json, "[1.5,65504.0]");
+//!
+//! let values: [bf16; 2] = serde_json::from_str("[0.5, 3]").unwrap();
+//! assert_eq!(values, [bf16::from_f32(0.5), bf16::from_f32(3.)]);
+//! ```
+
+use core::fmt;
+use core::str::FromStr;
+
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::{bf16, f16};
+
+macro_rules! impl_serde {
+    ($half:ident, $name:literal) => {
+        impl Serialize for $half {
+            #[inline]
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                if serializer.is_human_readable() {
+                    serializer.serialize_f32(self.to_f32())
+                } else {
+                    serializer.serialize_u16(self.to_bits())
+                }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $half {
+            #[inline]
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                if deserializer.is_human_readable() {
+                    deserializer.deserialize_f64(NumberVisitor::<$half>(core::marker::PhantomData))
+                } else {
+                    deserializer.deserialize_u16(BitsVisitor::<$half>(core::marker::PhantomData))
+                }
+            }
+        }
+
+        impl Half for $half {
+            const NAME: &'static str = $name;
+
+            #[inline]
+            fn from_bits(bits: u16) -> Self {
+                $half::from_bits(bits)
+            }
+
+            #[inline]
+            fn from_f64(value: f64) -> Self {
+                $half::from_f64(value)
+            }
+
+            #[inline]
+            fn from_str(value: &str) -> Option<Self> {
+                <$half as FromStr>::from_str(value).ok()
+            }
+        }
+    };
+}
+
+/// The conversions the visitors need from each type.
+trait Half: Sized {
+    const NAME: &'static str;
+
+    fn from_bits(bits: u16) -> Self;
+
+    fn from_f64(value: f64) -> Self;
+
+    fn from_str(value: &str) -> Option<Self>;
+}
+
+/// Converts an integer to an [`f64`], rounding to odd if it does not fit.
+///
+/// Any bits that do not fit in the mantissa set the lowest kept bit, so
+/// rounding the result again to fewer than 52 bits gives the same result as
+/// rounding the integer once, which `as f64` does not.
+#[inline]
+fn round_to_odd(value: u64) -> f64 {
+    let shift = (64 - value.leading_zeros()).saturating_sub(53);
+    if shift == 0 {
+        return value as f64;
+    }
+    let kept = (value >> shift) | (value & ((1 << shift) - 1) != 0) as u64;
+    kept as f64 * (1u64 << shift) as f64
+}
+
+/// Reads a number, or a string, from a human-readable format.
+struct NumberVisitor<H>(core::marker::PhantomData<H>);
+
+impl<'de, H: Half> Visitor<'de> for NumberVisitor<H> {
+    type Value = H;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "a number, which is rounded to the nearest {} value", H::NAME)
+    }
+
+    #[inline]
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<H, E> {
+        Ok(H::from_f64(value))
+    }
+
+    #[inline]
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<H, E> {
+        let mag = round_to_odd(value.unsigned_abs());
+        Ok(H::from_f64(if value < 0 {
+            -mag
+        } else {
+            mag
+        }))
+    }
+
+    #[inline]
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<H, E> {
+        Ok(H::from_f64(round_to_odd(value)))
+    }
+
+    #[inline]
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<H, E> {
+        H::from_str(value).ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+}
+
+/// Reads the bits of a value from a compact format.
+struct BitsVisitor<H>(core::marker::PhantomData<H>);
+
+impl<'de, H: Half> Visitor<'de> for BitsVisitor<H> {
+    type Value = H;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "the bits of an {} value as a u16", H::NAME)
+    }
+
+    #[inline]
+    fn visit_u16<E: de::Error>(self, value: u16) -> Result<H, E> {
+        Ok(H::from_bits(value))
+    }
+
+    #[inline]
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<H, E> {
+        // Self-describing formats, such as CBOR, may widen the integer.
+        match u16::try_from(value) {
+            Ok(bits) => Ok(H::from_bits(bits)),
+            Err(_) => Err(E::invalid_value(de::Unexpected::Unsigned(value), &self)),
+        }
+    }
+}
+
+impl_serde!(f16, "f16");
+impl_serde!(bf16, "bf16");
+
+#[cfg(test)]
+mod test {
+    use serde_test::{
+        assert_de_tokens,
+        assert_de_tokens_error,
+        assert_tokens,
+        Compact,
+        Configure,
+        Readable,
+        Token,
+    };
+
+    use super::*;
+
+    /// Compares the bits of the values, so NaN payloads and the sign of zero
+    /// are checked.
+    #[derive(Debug)]
+    struct Exact<H>(H);
+
+    impl<H: Copy + Into<f64>> PartialEq for Exact<H> {
+        fn eq(&self, other: &Self) -> bool {
+            let (a, b): (f64, f64) = (self.0.into(), other.0.into());
+            a.to_bits() == b.to_bits() || (a.is_nan() && b.is_nan())
+        }
+    }
+
+    impl<H: Serialize> Serialize for Exact<H> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.0.serialize(serializer)
+        }
+    }
+
+    impl<'de, H: Deserialize<'de>> Deserialize<'de> for Exact<H> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            H::deserialize(deserializer).map(Exact)
+        }
+    }
+
+    #[test]
+    fn test_compact() {
+        assert_tokens(&Exact(f16::from_f32(1.5)).compact(), &[Token::U16(0x3E00)]);
+        assert_tokens(&Exact(bf16::from_f32(1.5)).compact(), &[Token::U16(0x3FC0)]);
+        assert_tokens(&Exact(f16::NEG_ZERO).compact(), &[Token::U16(0x8000)]);
+        assert_tokens(&Exact(bf16::NEG_INFINITY).compact(), &[Token::U16(0xFF80)]);
+        for bits in [0x7C01u16, 0xFE00, 0x0001, 0x8001, 0x7BFF] {
+            let value = f16::from_bits(bits);
+            assert_tokens(&Exact(value).compact(), &[Token::U16(bits)]);
+            assert_eq!(value.to_bits(), bits);
+        }
+        for bits in [0x7F81u16, 0xFFC0, 0x0001, 0x7F7F] {
+            assert_tokens(&Exact(bf16::from_bits(bits)).compact(), &[Token::U16(bits)]);
+        }
+
+        // Formats may widen the integer, but it must still fit.
+        assert_de_tokens(&Exact(bf16::MAX).compact(), &[Token::U64(0x7F7F)]);
+        assert_de_tokens_error::<Compact<f16>>(
+            &[Token::U64(0x1_0000)],
+            "invalid value: integer `65536`, expected the bits of an f16 value as a u16",
+        );
+    }
+
+    #[test]
+    fn test_readable() {
+        assert_tokens(&Exact(f16::from_f32(1.5)).readable(), &[Token::F32(1.5)]);
+        assert_tokens(&Exact(bf16::from_f32(-2.)).readable(), &[Token::F32(-2.)]);
+        assert_tokens(&Exact(f16::NEG_ZERO).readable(), &[Token::F32(-0.)]);
+        assert_de_tokens(&Exact(f16::NAN).readable(), &[Token::F64(f64::NAN)]);
+
+        // Numbers are rounded once to the nearest value.
+        assert_de_tokens(&Exact(f16::from_f32(0.1)).readable(), &[Token::F64(0.1)]);
+        assert_de_tokens(&Exact(bf16::from_f64(1e10)).readable(), &[Token::U64(10_000_000_000)]);
+        assert_de_tokens(&Exact(f16::from_f32(-3.)).readable(), &[Token::I64(-3)]);
+        assert_de_tokens(&Exact(f16::INFINITY).readable(), &[Token::U64(u64::MAX)]);
+        assert_de_tokens(&Exact(bf16::from_f64(2f64.powi(64))).readable(), &[Token::U64(u64::MAX)]);
+        // Just above the midpoint between two values, which `as f64` rounds
+        // down to the midpoint.
+        let above = (0x101u64 << 54) + 1;
+        assert_de_tokens(&Exact(bf16::from_bits(0x5E81)).readable(), &[Token::U64(above)]);
+        assert_de_tokens(&Exact(bf16::from_bits(0xDE81)).readable(), &[Token::I64(
+            -(above as i64),
+        )]);
+        assert_de_tokens(&Exact(bf16::from_bits(0x5E80)).readable(), &[Token::U64(0x101 << 54)]);
+        assert_de_tokens(&Exact(f16::MAX).readable(), &[Token::F64(65519.)]);
+        assert_de_tokens(&Exact(bf16::NEG_INFINITY).readable(), &[Token::Str("-inf")]);
+        assert_de_tokens(&Exact(bf16::from_f32(0.25)).readable(), &[Token::Str("0.25")]);
+        assert_de_tokens_error::<Readable<bf16>>(
+            &[Token::Str("x")],
+            "invalid value: string \"x\", expected a number, which is rounded to the nearest bf16 value",
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        // Every finite value round trips through the shortest f32 string.
+        for bits in (0..=u16::MAX).step_by(13) {
+            let value = f16::from_bits(bits);
+            if value.is_finite() {
+                let json = serde_json::to_string(&value).unwrap();
+                let back: f16 = serde_json::from_str(&json).unwrap();
+                assert_eq!(back.to_bits(), bits, "{}", json);
+            }
+            let value = bf16::from_bits(bits);
+            if value.is_finite() {
+                let json = serde_json::to_string(&value).unwrap();
+                let back: bf16 = serde_json::from_str(&json).unwrap();
+                assert_eq!(back.to_bits(), bits, "{}", json);
+            }
+        }
+        assert_eq!(serde_json::to_string(&f16::NAN).unwrap(), "null");
+        assert!(serde_json::from_str::<f16>("null").is_err());
+    }
+
+    #[test]
+    fn test_json_exactness() {
+        // Numbers that are exactly a value once parsed, and numbers that are
+        // rounded, overflow or underflow.
+        let json = "[65504, 6.103515625e-5, 0.000000059604644775390625, -0.0, 1.00048828125, \
+                    0.1, 65520, 1e-8]";
+        let numbers: [f64; 8] = serde_json::from_str(json).unwrap();
+        let flags = numbers.map(|n| f16::from_json_number(n).1);
+        assert_eq!(flags, [true, true, true, true, false, false, false, false]);
+        for n in numbers {
+            let value: f16 = serde_json::from_str(&n.to_string()).unwrap();
+            assert_eq!(value.to_bits(), f16::from_json_number(n).0.to_bits());
+        }
+
+        let json = "[3.3895313892515355e38, -0.0078125, 1.0078125, 1.00390625, 3.4e38]";
+        let numbers: [f64; 5] = serde_json::from_str(json).unwrap();
+        let flags = numbers.map(|n| bf16::from_json_number(n).1);
+        assert_eq!(flags, [true, true, true, false, false]);
+    }
+}
@@ -0,0 +1,605 @@
+//! Shortest round-trip decimal representations for half-precision values.
+//!
+//! Every [`struct@f16`] and [`struct@bf16`] value is exactly representable as
+//! an [`f32`], which would otherwise print enough digits to round-trip an
+//! [`f32`] rather than the much narrower half-precision type.
+//!
+//! The digits are generated with the Ryu algorithm by Ulf Adams, from
+//! "Ryū: fast float-to-string conversion" (PLDI 2018), using the interval of
+//! decimals that round to the half-precision value rather than to the
+//! [`f32`]. The half-precision mantissas have at most 11 bits, so the 32-bit
+//! variant of the algorithm is used, with its tables extended to the larger
+//! binary exponents of [`struct@bf16`], whose mantissa is shorter than the
+//! [`f32`] mantissa for the same range.
+
+use core::fmt::{self, Formatter, Write};
+use core::str;
+
+/// The number of bits of the 64-bit multipliers for the inverse powers of 5,
+/// above the leading bit of the power.
+const POW5_INV_BITCOUNT: i32 = 59;
+/// The number of bits of the 64-bit multipliers for the powers of 5.
+const POW5_BITCOUNT: i32 = 61;
+
+/// The number of inverse powers of 5, up to `5^35` for the largest
+/// [`struct@bf16`] exponent of `2^118`, in units of a quarter of the last
+/// place.
+const POW5_INV_LEN: usize = 36;
+/// The number of powers of 5, up to `5^46`.
+const POW5_LEN: usize = 47;
+
+/// Returns `5^i`, which fits in a [`u128`] for `i <= 55`.
+const fn pow5(i: usize) -> u128 {
+    let mut power = 1u128;
+    let mut k = 0;
+    while k < i {
+        power *= 5;
+        k += 1;
+    }
+    power
+}
+
+/// Returns the number of bits of `value`.
+const fn bit_length(value: u128) -> i32 {
+    128 - value.leading_zeros() as i32
+}
+
+/// `floor(2^j / 5^i) + 1`, where `j` is the bit length of `5^i`, minus 1,
+/// plus [`POW5_INV_BITCOUNT`].
+const POW5_INV_SPLIT: [u64; POW5_INV_LEN] = {
+    let mut table = [0; POW5_INV_LEN];
+    let mut i = 0;
+    while i < POW5_INV_LEN {
+        let divisor = pow5(i);
+        let j = bit_length(divisor) - 1 + POW5_INV_BITCOUNT;
+        // Long division of `2^j`, one bit at a time, since `2^j` does not
+        // fit in a `u128`. The remainder is below `2^83`.
+        let mut remainder = 1u128;
+        let mut quotient = 0u128;
+        if remainder >= divisor {
+            remainder -= divisor;
+            quotient = 1;
+        }
+        let mut bit = 0;
+        while bit < j {
+            remainder <<= 1;
+            quotient <<= 1;
+            if remainder >= divisor {
+                remainder -= divisor;
+                quotient |= 1;
+            }
+            bit += 1;
+        }
+        table[i] = (quotient + 1) as u64;
+        i += 1;
+    }
+    table
+};
+
+/// The leading [`POW5_BITCOUNT`] bits of `5^i`.
+const POW5_SPLIT: [u64; POW5_LEN] = {
+    let mut table = [0; POW5_LEN];
+    let mut i = 0;
+    while i < POW5_LEN {
+        let power = pow5(i);
+        let shift = bit_length(power) - POW5_BITCOUNT;
+        table[i] = if shift >= 0 {
+            (power >> shift) as u64
+        } else {
+            (power << -shift) as u64
+        };
+        i += 1;
+    }
+    table
+};
+
+/// Returns `ceil(log2(5^e))`, or 1 if `e` is 0, for `e` up to 3528.
+#[inline]
+fn pow5_bits(e: i32) -> i32 {
+    (((e as u32) * 1_217_359) >> 19) as i32 + 1
+}
+
+/// Returns `floor(log10(2^e))`, for `e` up to 1650.
+#[inline]
+fn log10_pow2(e: i32) -> u32 {
+    ((e as u32) * 78_913) >> 18
+}
+
+/// Returns `floor(log10(5^e))`, for `e` up to 2620.
+#[inline]
+fn log10_pow5(e: i32) -> u32 {
+    ((e as u32) * 732_923) >> 20
+}
+
+/// Returns if `value` is divisible by `5^p`.
+#[inline]
+fn multiple_of_power_of_5(mut value: u32, p: u32) -> bool {
+    let mut count = 0;
+    while value % 5 == 0 && count < p {
+        value /= 5;
+        count += 1;
+    }
+    count >= p
+}
+
+/// Returns if `value` is divisible by `2^p`, for `p` below 32.
+#[inline]
+fn multiple_of_power_of_2(value: u32, p: u32) -> bool {
+    value & ((1 << p) - 1) == 0
+}
+
+/// Returns `(m * factor) >> shift`, for `shift` above 32.
+#[inline]
+fn mul_shift(m: u32, factor: u64, shift: i32) -> u32 {
+    let low = (m as u64) * (factor as u32 as u64);
+    let high = (m as u64) * (factor >> 32);
+    (((low >> 32) + high) >> (shift - 32)) as u32
+}
+
+/// A decimal `digits × 10^exponent`, without trailing zeros in `digits`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Decimal {
+    pub(crate) digits: u32,
+    pub(crate) exponent: i32,
+}
+
+impl Decimal {
+    /// Returns the decimal with the fewest significant digits that rounds to
+    /// the magnitude of a finite value, with the closest one to the value if
+    /// there are several, or `0` for zero.
+    ///
+    /// The value has the bits of a binary floating point type with
+    /// `exponent_bits` and `mantissa_bits`, which must be those of
+    /// [`struct@f16`] or [`struct@bf16`].
+    #[inline]
+    pub(crate) fn shortest(bits: u16, exponent_bits: u32, mantissa_bits: u32) -> Self {
+        Self::generate(bits, exponent_bits, mantissa_bits, true)
+    }
+
+    /// Returns the same as [`shortest`][Self::shortest], except that decimals
+    /// exactly halfway to a neighboring value are never used, even when they
+    /// round to the value. This is how NumPy's Dragon4 picks its digits.
+    #[inline]
+    #[cfg(feature = "std")]
+    pub(crate) fn shortest_exclusive(bits: u16, exponent_bits: u32, mantissa_bits: u32) -> Self {
+        Self::generate(bits, exponent_bits, mantissa_bits, false)
+    }
+
+    /// Generates the shortest digits with Ryu, including the bounds of the
+    /// interval for even mantissas if `ties_to_even` is set.
+    fn generate(bits: u16, exponent_bits: u32, mantissa_bits: u32, ties_to_even: bool) -> Self {
+        let mantissa = (bits & ((1 << mantissa_bits) - 1)) as u32;
+        let biased = ((bits >> mantissa_bits) & ((1 << exponent_bits) - 1)) as i32;
+        if mantissa == 0 && biased == 0 {
+            return Decimal {
+                digits: 0,
+                exponent: 0,
+            };
+        }
+        let bias = (1 << (exponent_bits - 1)) - 1;
+
+        // The value is `m2 × 2^e2`, in units of a quarter of the last place
+        // so the bounds of the interval are integers.
+        let (m2, e2) = if biased == 0 {
+            (mantissa, 1 - bias - mantissa_bits as i32 - 2)
+        } else {
+            (mantissa | (1 << mantissa_bits), biased - bias - mantissa_bits as i32 - 2)
+        };
+        // Ties round to even, so the bounds are in the interval for even
+        // mantissas.
+        let accept_bounds = ties_to_even && m2 & 1 == 0;
+        let mv = 4 * m2;
+        let mp = 4 * m2 + 2;
+        // The next smaller value is closer at powers of 2, except for the
+        // smallest normal value.
+        let mm_shift = (mantissa != 0 || biased <= 1) as u32;
+        let mm = 4 * m2 - 1 - mm_shift;
+
+        // Scale the value and the bounds to a power of 10.
+        let mut vr;
+        let mut vp;
+        let mut vm;
+        let e10;
+        let mut vm_is_trailing_zeros = false;
+        let mut vr_is_trailing_zeros = false;
+        let mut last_removed_digit = 0;
+        if e2 >= 0 {
+            let q = log10_pow2(e2);
+            e10 = q as i32;
+            let k = POW5_INV_BITCOUNT + pow5_bits(q as i32) - 1;
+            let i = -e2 + q as i32 + k;
+            vr = mul_shift(mv, POW5_INV_SPLIT[q as usize], i);
+            vp = mul_shift(mp, POW5_INV_SPLIT[q as usize], i);
+            vm = mul_shift(mm, POW5_INV_SPLIT[q as usize], i);
+            if q != 0 && (vp - 1) / 10 <= vm / 10 {
+                // The loop below removes no digits, but the last removed
+                // digit is needed to round.
+                let l = POW5_INV_BITCOUNT + pow5_bits(q as i32 - 1) - 1;
+                let shift = -e2 + q as i32 - 1 + l;
+                last_removed_digit = mul_shift(mv, POW5_INV_SPLIT[q as usize - 1], shift) % 10;
+            }
+            if q <= 9 {
+                // At most one of the bounds and the value is a multiple of 5.
+                if mv % 5 == 0 {
+                    vr_is_trailing_zeros = multiple_of_power_of_5(mv, q);
+                } else if accept_bounds {
+                    vm_is_trailing_zeros = multiple_of_power_of_5(mm, q);
+                } else {
+                    vp -= multiple_of_power_of_5(mp, q) as u32;
+                }
+            }
+        } else {
+            let q = log10_pow5(-e2);
+            e10 = q as i32 + e2;
+            let i = -e2 - q as i32;
+            let k = pow5_bits(i) - POW5_BITCOUNT;
+            let j = q as i32 - k;
+            vr = mul_shift(mv, POW5_SPLIT[i as usize], j);
+            vp = mul_shift(mp, POW5_SPLIT[i as usize], j);
+            vm = mul_shift(mm, POW5_SPLIT[i as usize], j);
+            if q != 0 && (vp - 1) / 10 <= vm / 10 {
+                let j = q as i32 - 1 - (pow5_bits(i + 1) - POW5_BITCOUNT);
+                last_removed_digit = mul_shift(mv, POW5_SPLIT[i as usize + 1], j) % 10;
+            }
+            if q <= 1 {
+                // `mv` has at least two trailing zero bits, `mp` has one, and
+                // `mm` has one only if `mm_shift` is 1.
+                vr_is_trailing_zeros = true;
+                if accept_bounds {
+                    vm_is_trailing_zeros = mm_shift == 1;
+                } else {
+                    vp -= 1;
+                }
+            } else if q < 31 {
+                vr_is_trailing_zeros = multiple_of_power_of_2(mv, q - 1);
+            }
+        }
+
+        // Remove digits while the bounds still differ, which gives the
+        // shortest decimal in the interval. Stopping at a single digit keeps
+        // the closest candidate when the interval spans a power of ten, as for
+        // the smallest `bf16` subnormal, which is nearer `9e-41` than `1e-40`.
+        let mut removed = 0;
+        let output = if vm_is_trailing_zeros || vr_is_trailing_zeros {
+            // The value or the lower bound may be exact, which needs the
+            // removed digits to decide ties and whether the bound is included.
+            while vp / 10 > vm / 10 && vr >= 10 {
+                vm_is_trailing_zeros &= vm % 10 == 0;
+                vr_is_trailing_zeros &= last_removed_digit == 0;
+                last_removed_digit = vr % 10;
+                vr /= 10;
+                vp /= 10;
+                vm /= 10;
+                removed += 1;
+            }
+            if vm_is_trailing_zeros {
+                while vm % 10 == 0 {
+                    vr_is_trailing_zeros &= last_removed_digit == 0;
+                    last_removed_digit = vr % 10;
+                    vr /= 10;
+                    vm /= 10;
+                    removed += 1;
+                }
+            }
+            if vr_is_trailing_zeros && last_removed_digit == 5 && vr % 2 == 0 {
+                // The value is exactly halfway, so round to even.
+                last_removed_digit = 4;
+            }
+            let round_up =
+                (vr == vm && (!accept_bounds || !vm_is_trailing_zeros)) || last_removed_digit >= 5;
+            vr + round_up as u32
+        } else {
+            while vp / 10 > vm / 10 && vr >= 10 {
+                last_removed_digit = vr % 10;
+                vr /= 10;
+                vp /= 10;
+                vm /= 10;
+                removed += 1;
+            }
+            vr + (vr == vm || last_removed_digit >= 5) as u32
+        };
+
+        let mut decimal = Decimal {
+            digits: output,
+            exponent: e10 + removed,
+        };
+        // Rounding up may carry into a trailing zero.
+        while decimal.digits % 10 == 0 {
+            decimal.digits /= 10;
+            decimal.exponent += 1;
+        }
+        decimal
+    }
+
+    /// Writes the significant digits to a buffer, returning their number.
+    #[inline]
+    fn write_digits(self, buffer: &mut [u8; 10]) -> usize {
+        let mut digits = self.digits;
+        let mut len = 0;
+        loop {
+            buffer[buffer.len() - 1 - len] = b'0' + (digits % 10) as u8;
+            digits /= 10;
+            len += 1;
+            if digits == 0 {
+                break;
+            }
+        }
+        let start = buffer.len() - len;
+        buffer.copy_within(start.., 0);
+        len
+    }
+
+    /// Writes the decimal positionally, without a sign, the same as
+    /// [`Display`][fmt::Display] for [`f64`] without a precision.
+    fn write_positional<W: Write>(self, out: &mut W) -> fmt::Result {
+        let mut buffer = [0; 10];
+        let len = self.write_digits(&mut buffer);
+        let digits = str::from_utf8(&buffer[..len]).map_err(|_| fmt::Error)?;
+        // The position of the decimal point, from the first digit.
+        let point = len as i32 + self.exponent;
+        if self.exponent >= 0 {
+            out.write_str(digits)?;
+            (0..self.exponent).try_for_each(|_| out.write_char('0'))
+        } else if point > 0 {
+            let (integer, fraction) = digits.split_at(point as usize);
+            write!(out, "{}.{}", integer, fraction)
+        } else {
+            out.write_str("0.")?;
+            (0..-point).try_for_each(|_| out.write_char('0'))?;
+            out.write_str(digits)
+        }
+    }
+
+    /// Writes the decimal in scientific notation, without a sign, the same
+    /// as [`LowerExp`][fmt::LowerExp] for [`f64`] without a precision.
+    fn write_scientific<W: Write>(self, out: &mut W, e: char) -> fmt::Result {
+        let mut buffer = [0; 10];
+        let len = self.write_digits(&mut buffer);
+        let digits = str::from_utf8(&buffer[..len]).map_err(|_| fmt::Error)?;
+        let (first, rest) = digits.split_at(1);
+        out.write_str(first)?;
+        if !rest.is_empty() {
+            write!(out, ".{}", rest)?;
+        }
+        write!(out, "{}{}", e, self.exponent + len as i32 - 1)
+    }
+}
+
+/// Fixed-size buffer for formatting a decimal, which fits the 40 leading
+/// zeros of the smallest [`struct@bf16`] subnormal.
+struct Buffer {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl Buffer {
+    #[inline]
+    fn new() -> Self {
+        Buffer {
+            bytes: [0; 64],
+            len: 0,
+        }
+    }
+
+    #[inline]
+    fn as_str(&self) -> &str {
+        // Only ASCII is written.
+        str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+}
+
+impl Write for Buffer {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.bytes.len() {
+            return Err(fmt::Error);
+        }
+        self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Formats a finite value positionally with its shortest decimal, with the
+/// sign, width and fill of the formatter, the same as for an [`f64`] with the
+/// same decimal.
+pub(crate) fn fmt_positional(
+    f: &mut Formatter<'_>,
+    bits: u16,
+    exponent_bits: u32,
+    mantissa_bits: u32,
+) -> fmt::Result {
+    let mut buffer = Buffer::new();
+    Decimal::shortest(bits, exponent_bits, mantissa_bits).write_positional(&mut buffer)?;
+    f.pad_integral(bits & 0x8000 == 0, "", buffer.as_str())
+}
+
+/// Formats a finite value in scientific notation with its shortest decimal,
+/// with the sign, width and fill of the formatter, the same as for an
+/// [`f64`] with the same decimal. `e` is the exponent marker, `e` or `E`.
+pub(crate) fn fmt_scientific(
+    f: &mut Formatter<'_>,
+    bits: u16,
+    exponent_bits: u32,
+    mantissa_bits: u32,
+    e: char,
+) -> fmt::Result {
+    let mut buffer = Buffer::new();
+    Decimal::shortest(bits, exponent_bits, mantissa_bits).write_scientific(&mut buffer, e)?;
+    f.pad_integral(bits & 0x8000 == 0, "", buffer.as_str())
+}
+
+/// Writes a value in the format of `str` of a NumPy scalar, with the
+/// shortest decimal of the bits from [`Decimal::shortest_exclusive`].
+///
+/// NumPy prints the same shortest digits, but always positionally with at
+/// least one fractional digit for zero and magnitudes from `1e-4` up to
+/// `1e16`, and otherwise in scientific notation with a signed exponent of at
+/// least two digits. NaN never has a sign.
+#[cfg(feature = "std")]
+pub(crate) fn write_numpy_repr<W: Write>(out: &mut W, value: f32, decimal: Decimal) -> fmt::Result {
+    if value.is_nan() {
+        return out.write_str("nan");
+    }
+    if value.is_sign_negative() {
+        out.write_char('-')?;
+    }
+    if value.is_infinite() {
+        return out.write_str("inf");
+    }
+
+    let abs = value.abs() as f64;
+    if abs != 0. && !(1e-4..1e16).contains(&abs) {
+        let mut buffer = Buffer::new();
+        decimal.write_scientific(&mut buffer, 'e')?;
+        let (mantissa, exp) = buffer.as_str().split_once('e').ok_or(fmt::Error)?;
+        let exp: i32 = exp.parse().map_err(|_| fmt::Error)?;
+        let sign = if exp < 0 {
+            '-'
+        } else {
+            '+'
+        };
+        return write!(out, "{}e{}{:02}", mantissa, sign, exp.unsigned_abs());
+    }
+
+    // The positional digits, with a single fractional zero for integers.
+    decimal.write_positional(out)?;
+    if decimal.exponent >= 0 {
+        out.write_str(".0")?;
+    }
+    Ok(())
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use std::format;
+    use std::string::String;
+
+    use super::*;
+    use crate::{bf16, f16};
+
+    /// Returns the decimal with the fewest significant digits that parses
+    /// back to `bits`, closest to the value, by trying the correctly rounded
+    /// decimal with each number of digits and its neighbors.
+    fn reference<H: core::str::FromStr + Copy>(
+        bits: u16,
+        value: f32,
+        to_bits: fn(H) -> u16,
+    ) -> Decimal {
+        for precision in 0..9 {
+            let nearest = format!("{:.*e}", precision, value.abs());
+            let (mantissa, exp) = nearest.split_once('e').unwrap();
+            let digits: i64 = mantissa.replace('.', "").parse().unwrap();
+            let exponent = exp.parse::<i32>().unwrap() - precision as i32;
+            // The nearest decimal is preferred, so it is tried first.
+            for candidate in [digits, digits - 1, digits + 1] {
+                let parsed = format!("{}e{}", candidate, exponent).parse::<H>();
+                if parsed.map_or(false, |x| to_bits(x) & 0x7FFF == bits & 0x7FFF) {
+                    let mut decimal = Decimal {
+                        digits: candidate as u32,
+                        exponent,
+                    };
+                    while decimal.digits % 10 == 0 && decimal.digits != 0 {
+                        decimal.digits /= 10;
+                        decimal.exponent += 1;
+                    }
+                    return decimal;
+                }
+            }
+        }
+        unreachable!("{:#06x}", bits)
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_shortest_f16() {
+        assert_eq!(Decimal::shortest(0x3C00, 5, 10), Decimal {
+            digits: 1,
+            exponent: 0
+        });
+        assert_eq!(Decimal::shortest(0x7BFF, 5, 10), Decimal {
+            digits: 655,
+            exponent: 2
+        });
+        assert_eq!(Decimal::shortest(0x0001, 5, 10), Decimal {
+            digits: 6,
+            exponent: -8
+        });
+        for bits in (0..=0x7BFF).chain(0x8000..=0xFBFF) {
+            let value = f16::from_bits(bits);
+            let expected = reference(bits, value.to_f32(), f16::to_bits);
+            assert_eq!(Decimal::shortest(bits, 5, 10), expected, "{:#06x}", bits);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_shortest_bf16() {
+        assert_eq!(Decimal::shortest(0x4049, 8, 7), Decimal {
+            digits: 314,
+            exponent: -2
+        });
+        assert_eq!(Decimal::shortest(0x7F7F, 8, 7), Decimal {
+            digits: 339,
+            exponent: 36
+        });
+        assert_eq!(Decimal::shortest(0x0001, 8, 7), Decimal {
+            digits: 9,
+            exponent: -41
+        });
+        for bits in (0..=0x7F7F).chain(0x8000..=0xFF7F) {
+            let value = bf16::from_bits(bits);
+            let expected = reference(bits, value.to_f32(), bf16::to_bits);
+            assert_eq!(Decimal::shortest(bits, 8, 7), expected, "{:#06x}", bits);
+        }
+    }
+
+    #[test]
+    fn test_write() {
+        let write = |digits, exponent| {
+            let decimal = Decimal {
+                digits,
+                exponent,
+            };
+            let mut positional = String::new();
+            decimal.write_positional(&mut positional).unwrap();
+            let mut scientific = String::new();
+            decimal.write_scientific(&mut scientific, 'e').unwrap();
+            (positional, scientific)
+        };
+        assert_eq!(write(0, 0), ("0".into(), "0e0".into()));
+        assert_eq!(write(655, 2), ("65500".into(), "6.55e4".into()));
+        assert_eq!(write(11, -1), ("1.1".into(), "1.1e0".into()));
+        assert_eq!(write(6, -8), ("0.00000006".into(), "6e-8".into()));
+        assert_eq!(write(9996, -8), ("0.00009996".into(), "9.996e-5".into()));
+        assert_eq!(write(12345, -2), ("123.45".into(), "1.2345e2".into()));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_flags_match_f64() {
+        // The shortest digits are exact in `f64`, which prints them unchanged,
+        // so padding, signs and alignment must agree with it.
+        for bits in 0..=u16::MAX {
+            let value = f16::from_bits(bits);
+            if !value.is_finite() {
+                continue;
+            }
+            let wide: f64 = format!("{}", value).parse().unwrap();
+            assert_eq!(format!("{:+010}", value), format!("{:+010}", wide));
+            assert_eq!(format!("{:^14}", value), format!("{:^14}", wide));
+            assert_eq!(format!("{:>12e}", value), format!("{:>12e}", wide));
+            assert_eq!(format!("{:<+9E}", value), format!("{:<+9E}", wide));
+        }
+        for bits in 0..=u16::MAX {
+            let value = bf16::from_bits(bits);
+            if !value.is_finite() {
+                continue;
+            }
+            let wide: f64 = format!("{:e}", value).parse().unwrap();
+            assert_eq!(format!("{:+010e}", value), format!("{:+010e}", wide));
+            assert_eq!(format!("{:^12E}", value), format!("{:^12E}", wide));
+        }
+    }
+}
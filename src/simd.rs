@@ -0,0 +1,312 @@
+//! Fixed-width vectors of [`struct@f16`] and [`struct@bf16`] values.
+//!
+//! These work on stable Rust, without the portable SIMD API. Arithmetic
+//! converts both operands to [`f32`] with the vectorized slice conversions,
+//! which use hardware instructions when available, computes each lane in
+//! [`f32`], and rounds the result back once, so every lane is identical to the
+//! scalar operation on the same values.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use float16::*;
+//! let a = f16x8::from_array([f16::ONE; 8]);
+//! let b = f16x8::splat(f16::from_f32(0.5));
+//!
+//! assert_eq!((a + b).to_array(), [f16::from_f32(1.5); 8]);
+//! assert_eq!((a * b).reduce_sum(), 4.);
+//! ```
+
+use core::ops::{
+    Add,
+    AddAssign,
+    Div,
+    DivAssign,
+    Mul,
+    MulAssign,
+    Neg,
+    Rem,
+    RemAssign,
+    Sub,
+    SubAssign,
+};
+
+use crate::binary16::arch;
+use crate::{bf16, f16};
+
+macro_rules! simd_binop {
+    ($vector:ident, $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:tt) => {
+        impl $trait for $vector {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: Self) -> Self::Output {
+                let lhs = self.to_f32_array();
+                let rhs = rhs.to_f32_array();
+                let mut result = [0f32; $vector::LANES];
+                for (r, (&a, &b)) in result.iter_mut().zip(lhs.iter().zip(rhs.iter())) {
+                    *r = a $op b;
+                }
+                Self::from_f32_array(result)
+            }
+        }
+
+        impl $assign_trait for $vector {
+            #[inline]
+            fn $assign_method(&mut self, rhs: Self) {
+                *self = (*self).$method(rhs);
+            }
+        }
+    };
+}
+
+macro_rules! simd_half {
+    (
+        $vector:ident,
+        $half:ident,
+        $lanes:literal,
+        $name:literal,
+        $to_f32:path,
+        $from_f32:path
+    ) => {
+        #[doc = concat!("A vector of ", stringify!($lanes), " [`struct@", $name, "`] values.")]
+        ///
+        /// This works on stable Rust, without the portable SIMD API.
+        /// Arithmetic converts both operands to [`f32`] with the vectorized
+        /// slice conversions, which use hardware instructions when available,
+        /// computes each lane in [`f32`], and rounds the result back once, so
+        /// every lane is identical to the scalar operation on the same values.
+        #[allow(non_camel_case_types)]
+        #[repr(transparent)]
+        #[derive(Clone, Copy, Debug, Default, PartialEq)]
+        pub struct $vector([$half; $lanes]);
+
+        impl $vector {
+            /// The number of lanes in the vector.
+            pub const LANES: usize = $lanes;
+
+            /// Constructs a vector from an array of values.
+            #[inline]
+            #[must_use]
+            pub const fn from_array(array: [$half; $lanes]) -> $vector {
+                $vector(array)
+            }
+
+            /// Returns the values of the vector as an array.
+            #[inline]
+            #[must_use]
+            pub const fn to_array(self) -> [$half; $lanes] {
+                self.0
+            }
+
+            /// Returns a reference to the values of the vector as an array.
+            #[inline]
+            #[must_use]
+            pub const fn as_array(&self) -> &[$half; $lanes] {
+                &self.0
+            }
+
+            /// Constructs a vector with every lane set to `value`.
+            #[inline]
+            #[must_use]
+            pub const fn splat(value: $half) -> $vector {
+                $vector([value; $lanes])
+            }
+
+            /// Constructs a vector by rounding each [`f32`] value to the
+            /// nearest representable value.
+            #[inline]
+            #[must_use]
+            pub fn from_f32_array(array: [f32; $lanes]) -> $vector {
+                let mut bits = [0u16; $lanes];
+                $from_f32(&array, &mut bits);
+                $vector(bits.map($half::from_bits))
+            }
+
+            /// Converts every lane of the vector to [`f32`], which is exact.
+            #[inline]
+            #[must_use]
+            pub fn to_f32_array(self) -> [f32; $lanes] {
+                let mut array = [0f32; $lanes];
+                $to_f32(&self.0.map($half::to_bits), &mut array);
+                array
+            }
+
+            /// Returns the sum of all lanes, computed in [`f32`].
+            ///
+            /// The lanes are added pairwise, in a fixed order, so the result
+            /// does not depend on the target.
+            #[inline]
+            #[must_use]
+            pub fn reduce_sum(self) -> f32 {
+                let mut array = self.to_f32_array();
+                let mut width = $lanes;
+                while width > 1 {
+                    width /= 2;
+                    for i in 0..width {
+                        array[i] += array[i + width];
+                    }
+                }
+                array[0]
+            }
+
+            /// Returns the product of all lanes, computed in [`f32`].
+            ///
+            /// The lanes are multiplied pairwise, in a fixed order, so the
+            /// result does not depend on the target.
+            #[inline]
+            #[must_use]
+            pub fn reduce_product(self) -> f32 {
+                let mut array = self.to_f32_array();
+                let mut width = $lanes;
+                while width > 1 {
+                    width /= 2;
+                    for i in 0..width {
+                        array[i] *= array[i + width];
+                    }
+                }
+                array[0]
+            }
+
+            #[doc = concat!("Returns the largest lane, with the same NaN handling as [`", $name, "::max`].")]
+            #[inline]
+            #[must_use]
+            pub fn reduce_max(self) -> $half {
+                self.0.iter().fold(self.0[0], |acc, &x| acc.max(x))
+            }
+
+            #[doc = concat!("Returns the smallest lane, with the same NaN handling as [`", $name, "::min`].")]
+            #[inline]
+            #[must_use]
+            pub fn reduce_min(self) -> $half {
+                self.0.iter().fold(self.0[0], |acc, &x| acc.min(x))
+            }
+        }
+
+        impl From<[$half; $lanes]> for $vector {
+            #[inline]
+            fn from(array: [$half; $lanes]) -> Self {
+                Self::from_array(array)
+            }
+        }
+
+        impl From<$vector> for [$half; $lanes] {
+            #[inline]
+            fn from(vector: $vector) -> Self {
+                vector.to_array()
+            }
+        }
+
+        impl Neg for $vector {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                $vector(self.0.map(|x| -x))
+            }
+        }
+
+        simd_binop!($vector, Add, add, AddAssign, add_assign, +);
+        simd_binop!($vector, Sub, sub, SubAssign, sub_assign, -);
+        simd_binop!($vector, Mul, mul, MulAssign, mul_assign, *);
+        simd_binop!($vector, Div, div, DivAssign, div_assign, /);
+        simd_binop!($vector, Rem, rem, RemAssign, rem_assign, %);
+    };
+}
+
+simd_half!(f16x4, f16, 4, "f16", arch::f16_to_f32_slice, arch::f32_to_f16_slice);
+simd_half!(f16x8, f16, 8, "f16", arch::f16_to_f32_slice, arch::f32_to_f16_slice);
+simd_half!(f16x16, f16, 16, "f16", arch::f16_to_f32_slice, arch::f32_to_f16_slice);
+simd_half!(bf16x4, bf16, 4, "bf16", arch::bf16_to_f32_slice, arch::f32_to_bf16_slice);
+simd_half!(bf16x8, bf16, 8, "bf16", arch::bf16_to_f32_slice, arch::f32_to_bf16_slice);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_matches_scalar() {
+        // Enough chunks to cover every stratum.
+        let mut values = [f16::ZERO; 5 * 16];
+        crate::testing::fill_stratified(&mut values, 0x51D);
+        for a in values.chunks_exact(16) {
+            let a: [f16; 16] = a.try_into().unwrap();
+            let mut b = a;
+            b.reverse();
+            matches_scalar(a, b);
+        }
+    }
+
+    fn matches_scalar(a: [f16; 16], b: [f16; 16]) {
+        let (x, y) = (f16x16::from_array(a), f16x16::from_array(b));
+        type Op = fn(f16, f16) -> f16;
+        let ops: [(f16x16, Op); 5] = [
+            (x + y, |a, b| a + b),
+            (x - y, |a, b| a - b),
+            (x * y, |a, b| a * b),
+            (x / y, |a, b| a / b),
+            (x % y, |a, b| a % b),
+        ];
+        for (result, op) in ops.iter() {
+            for i in 0..16 {
+                let expected = op(a[i], b[i]);
+                let actual = result.to_array()[i];
+                assert!(
+                    actual.to_bits() == expected.to_bits()
+                        || (actual.is_nan() && expected.is_nan()),
+                    "{:?} {:?}",
+                    actual,
+                    expected
+                );
+            }
+        }
+        // Compare the bits, since NaN is never equal to itself.
+        assert_eq!((-x).to_array().map(f16::to_bits), a.map(|v| (-v).to_bits()));
+
+        let mut z = x;
+        z += y;
+        z -= y;
+        z *= f16x16::splat(f16::ONE);
+        z /= f16x16::splat(f16::ONE);
+        assert_eq!(z.to_array().map(f16::to_bits), ((x + y) - y).to_array().map(f16::to_bits));
+
+        let a = [bf16::from_f32(1.5), bf16::from_f32(-3.), bf16::MAX, bf16::from_f32(1e-30)];
+        let x = bf16x4::from_array(a);
+        assert_eq!((x * x).to_array(), a.map(|v| v * v));
+        assert_eq!((x + x).to_array(), a.map(|v| v + v));
+    }
+
+    #[test]
+    fn test_reductions() {
+        let x = f16x8::from_f32_array([0., 1., 2., 3., 4., 5., 6., 7.]);
+        assert_eq!(x.reduce_sum(), 28.);
+        assert_eq!(x.reduce_product(), 0.);
+        assert_eq!(x.reduce_max(), f16::from_f32(7.));
+        assert_eq!(x.reduce_min(), f16::ZERO);
+        assert_eq!((x + f16x8::splat(f16::ONE)).reduce_product(), 40320.);
+
+        let mut a = [f16::ONE; 4];
+        a[1] = f16::NAN;
+        a[2] = f16::from_f32(-4.);
+        let x = f16x4::from(a);
+        assert!(x.reduce_sum().is_nan());
+        assert_eq!(x.reduce_max(), f16::ONE);
+        assert_eq!(x.reduce_min(), f16::from_f32(-4.));
+
+        let x = bf16x8::splat(bf16::from_f32(0.25));
+        assert_eq!(x.reduce_sum(), 2.);
+        assert_eq!(<[bf16; 8]>::from(x), [bf16::from_f32(0.25); 8]);
+        assert_eq!(bf16x8::LANES, 8);
+        assert_eq!(core::mem::size_of::<f16x16>(), 32);
+    }
+
+    #[test]
+    fn test_f32_arrays() {
+        let values = [0.1f32, -1e10, 1e-9, 65520., 3.140625, -0., 1., 0.5];
+        let x = f16x8::from_f32_array(values);
+        assert_eq!(x.to_array(), values.map(f16::from_f32));
+        assert_eq!(x.to_f32_array(), values.map(|v| f16::from_f32(v).to_f32()));
+        let x = bf16x8::from_f32_array(values);
+        assert_eq!(x.to_array(), values.map(bf16::from_f32));
+    }
+}
@@ -9,13 +9,22 @@
 
 use core::slice;
 
-use crate::{bf16, binary16::arch, f16};
+use crate::{
+    bf16,
+    binary16::arch::{self, ZipOp},
+    f16,
+    threshold::CompiledThreshold,
+    TryFromFloatError,
+};
 
 /// Extensions to `[f16]` and `[bf16]` slices to support conversion and
 /// reinterpret operations.
 ///
 /// This trait is sealed and cannot be implemented outside of this crate.
 pub trait HalfFloatSliceExt: private::SealedHalfFloatSlice {
+    /// The type of the elements, [`struct@f16`] or [`struct@bf16`].
+    type Element: Copy;
+
     /// Reinterprets a slice of [`struct@f16`] or [`struct@bf16`] numbers as a
     /// slice of [`u16`] bits.
     ///
@@ -124,6 +133,41 @@ pub trait HalfFloatSliceExt: private::SealedHalfFloatSlice {
     /// ```
     fn convert_from_f64_slice(&mut self, src: &[f64]);
 
+    /// Converts all of the elements of a `[f32]` slice into [`struct@f16`] or
+    /// [`struct@bf16`] values in `self`, skipping the handling of special
+    /// values.
+    ///
+    /// The length of `src` must be the same as `self`.
+    ///
+    /// This uses the same vectorized conversions as
+    /// [`convert_from_f32_slice`][HalfFloatSliceExt::convert_from_f32_slice]
+    /// when hardware support is available. Otherwise, it uses
+    /// [`f16::from_f32_unchecked_normal`][crate::f16::from_f32_unchecked_normal] or
+    /// [`bf16::from_f32_unchecked_normal`] for each element, and without the
+    /// branches for special values, the loop can be auto-vectorized.
+    ///
+    /// # Safety
+    ///
+    /// Every element of `src` must satisfy the safety requirements of
+    /// [`f16::from_f32_unchecked_normal`][crate::f16::from_f32_unchecked_normal] or
+    /// [`bf16::from_f32_unchecked_normal`], for the type of `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use float16::*;
+    /// let float_values = [1., 2., 3., 4.];
+    /// let mut buffer = [f16::ZERO; 4];
+    ///
+    /// unsafe { buffer.convert_from_f32_slice_unchecked_normal(&float_values) };
+    ///
+    /// assert_eq!(buffer, [f16::from_f32(1.), f16::from_f32(2.), f16::from_f32(3.), f16::from_f32(4.)]);
+    /// ```
+    unsafe fn convert_from_f32_slice_unchecked_normal(&mut self, src: &[f32]);
+
     /// Converts all of the [`struct@f16`] or [`struct@bf16`] elements of `self`
     /// into [`f32`] values in `dst`.
     ///
@@ -183,140 +227,1212 @@ pub trait HalfFloatSliceExt: private::SealedHalfFloatSlice {
     /// assert_eq!(buffer, [1., 2., 3., 4.]);
     /// ```
     fn convert_to_f64_slice(&self, dst: &mut [f64]);
-}
 
-/// Extensions to `[u16]` slices to support reinterpret operations.
-///
-/// This trait is sealed and cannot be implemented outside of this crate.
-pub trait HalfBitsSliceExt: private::SealedHalfBitsSlice {
-    /// Reinterprets a slice of [`u16`] bits as a slice of [`struct@f16`] or
-    /// [`struct@bf16`] numbers.
+    /// Converts all of the [`struct@f16`] or [`struct@bf16`] elements of `self`
+    /// into [`struct@f16`] values in `dst`.
     ///
-    /// `H` is the type to cast to, and must be either the [`struct@f16`] or
-    /// [`struct@bf16`] type.
+    /// Each [`struct@bf16`] element is rounded to the nearest [`struct@f16`]
+    /// value, as with [`bf16::to_f16`][crate::bf16::to_f16], so values too
+    /// large for [`struct@f16`] result in ±∞, and values too small round to
+    /// subnormals or ±0. [`struct@f16`] elements are copied.
     ///
-    /// This is a zero-copy operation. The reinterpreted slice has the same
-    /// lifetime and memory location as `self`.
+    /// The length of `dst` must be the same as `self`.
+    ///
+    /// The conversion operation is vectorized over the slice, meaning the
+    /// conversion may be more efficient than converting individual elements
+    /// on some hardware that supports SIMD conversions. See [crate
+    /// documentation](crate) for more information on hardware conversion
+    /// support.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
     ///
     /// # Examples
+    /// ```rust
+    /// # use float16::*;
+    /// let values = [bf16::from_f32(1.5), bf16::from_f32(-1e10), bf16::MIN_POSITIVE];
+    /// let mut buffer = [f16::ZERO; 3];
+    ///
+    /// values.convert_to_f16_slice(&mut buffer);
+    ///
+    /// assert_eq!(buffer, [f16::from_f32(1.5), f16::NEG_INFINITY, f16::ZERO]);
+    /// ```
+    fn convert_to_f16_slice(&self, dst: &mut [f16]);
+
+    /// Converts all of the [`struct@f16`] or [`struct@bf16`] elements of `self`
+    /// into [`struct@bf16`] values in `dst`.
+    ///
+    /// Each [`struct@f16`] element is rounded to the nearest [`struct@bf16`]
+    /// value, as with [`f16::to_bf16`][crate::f16::to_bf16], which only
+    /// loses the 3 lowest mantissa bits and never overflows or underflows.
+    /// [`struct@bf16`] elements are copied.
+    ///
+    /// The length of `dst` must be the same as `self`.
+    ///
+    /// The conversion operation is vectorized over the slice, meaning the
+    /// conversion may be more efficient than converting individual elements
+    /// on some hardware that supports SIMD conversions. See [crate
+    /// documentation](crate) for more information on hardware conversion
+    /// support.
+    ///
+    /// # Panics
     ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
     /// ```rust
     /// # use float16::*;
-    /// let int_buffer = [f16::from_f32(1.).to_bits(), f16::from_f32(2.).to_bits(), f16::from_f32(3.).to_bits()];
-    /// let float_buffer: &[f16] = int_buffer.reinterpret_cast();
+    /// let values = [f16::from_f32(1.5), f16::MAX, f16::MIN_POSITIVE_SUBNORMAL];
+    /// let mut buffer = [bf16::ZERO; 3];
     ///
-    /// assert_eq!(float_buffer, [f16::from_f32(1.), f16::from_f32(2.), f16::from_f32(3.)]);
+    /// values.convert_to_bf16_slice(&mut buffer);
     ///
-    /// // You may have to specify the cast type directly if the compiler can't infer the type.
-    /// // The following is also valid in Rust.
-    /// let typed_buffer = int_buffer.reinterpret_cast::<f16>();
+    /// assert_eq!(buffer.map(bf16::to_f32), [1.5, 65536., f16::MIN_POSITIVE_SUBNORMAL.to_f32()]);
     /// ```
-    #[must_use]
-    fn reinterpret_cast<H>(&self) -> &[H]
-    where
-        H: crate::private::SealedHalf;
+    fn convert_to_bf16_slice(&self, dst: &mut [bf16]);
 
-    /// Reinterprets a mutable slice of [`u16`] bits as a mutable slice of
-    /// [`struct@f16`] or [`struct@bf16`] numbers.
+    /// Converts all of the binary fixed-point integers in `src` into
+    /// [`struct@f16`] or [`struct@bf16`] values in `self`.
     ///
-    /// `H` is the type to cast to, and must be either the [`struct@f16`] or
-    /// [`struct@bf16`] type.
+    /// Each element is `value / 2^fractional_bits`, rounded once to the
+    /// nearest value with ties to even, as with
+    /// [`f16::from_fixed_point`][crate::f16::from_fixed_point].
     ///
-    /// This is a zero-copy operation. The transmuted slice has the same
-    /// lifetime as the original, which prevents mutating `self` as long as
-    /// the returned `&mut [f16]` is borrowed.
+    /// The length of `src` must be the same as `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
     ///
     /// # Examples
+    /// ```rust
+    /// # use float16::*;
+    /// // Q8.8 fixed-point values
+    /// let fixed = [256i64, -384, 64, 0];
+    /// let mut buffer = [f16::ZERO; 4];
+    ///
+    /// buffer.convert_from_fixed_point_slice(&fixed, 8);
+    ///
+    /// assert_eq!(buffer, [f16::from_f32(1.), f16::from_f32(-1.5), f16::from_f32(0.25), f16::ZERO]);
+    /// ```
+    fn convert_from_fixed_point_slice(&mut self, src: &[i64], fractional_bits: u32);
+
+    /// Converts all of the normalized [`u8`] elements of `src`, such as 8-bit
+    /// pixel channels, into [`struct@f16`] or [`struct@bf16`] values in
+    /// `self`.
+    ///
+    /// Each element is `src / 255` rounded to the nearest value, as with
+    /// [`f16::from_u8_norm`][crate::f16::from_u8_norm], so `0` maps to `0.0`
+    /// and `255` maps to `1.0`.
+    ///
+    /// The length of `src` must be the same as `self`.
+    ///
+    /// The conversion operation is vectorized over the slice, meaning the
+    /// conversion may be more efficient than converting individual elements
+    /// on some hardware that supports SIMD conversions. See [crate
+    /// documentation](crate) for more information on hardware conversion
+    /// support.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
     ///
+    /// # Examples
     /// ```rust
     /// # use float16::*;
-    /// let mut int_buffer = [f16::from_f32(1.).to_bits(), f16::from_f32(2.).to_bits(), f16::from_f32(3.).to_bits()];
+    /// let pixels = [0u8, 51, 255];
+    /// let mut buffer = [f16::ZERO; 3];
     ///
-    /// {
-    ///     let float_buffer: &mut [f16] = int_buffer.reinterpret_cast_mut();
+    /// buffer.convert_from_u8_norm_slice(&pixels);
     ///
-    ///     assert_eq!(float_buffer, [f16::from_f32(1.), f16::from_f32(2.), f16::from_f32(3.)]);
+    /// assert_eq!(buffer, [f16::ZERO, f16::from_f32(0.2), f16::ONE]);
+    /// ```
+    fn convert_from_u8_norm_slice(&mut self, src: &[u8]);
+
+    /// Converts all of the [`struct@f16`] or [`struct@bf16`] elements of `self`
+    /// into binary fixed-point integers in `dst`.
     ///
-    ///     // Mutating the f16 slice will mutating the original
-    ///     float_buffer[0] = f16::from_f32(0.);
-    /// }
+    /// Each element is `self * 2^fractional_bits`, rounded to the nearest
+    /// integer with ties to even, as with
+    /// [`f16::to_fixed_point`][crate::f16::to_fixed_point].
     ///
-    /// // Note that we need to drop float_buffer before using int_buffer again or we will get a borrow error.
-    /// assert_eq!(int_buffer, [f16::from_f32(0.).to_bits(), f16::from_f32(2.).to_bits(), f16::from_f32(3.).to_bits()]);
+    /// The length of `dst` must be the same as `self`.
     ///
-    /// // You may have to specify the cast type directly if the compiler can't infer the type.
-    /// // The following is also valid in Rust.
-    /// let typed_buffer = int_buffer.reinterpret_cast_mut::<f16>();
+    /// # Errors
+    ///
+    /// Returns [`TryFromFloatError`] if any element is NaN, infinite, or does
+    /// not fit in an [`i64`]. The contents of `dst` are unspecified if an
+    /// error is returned.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use float16::*;
+    /// let half_values = [f16::from_f32(1.), f16::from_f32(-1.5), f16::from_f32(0.25), f16::ZERO];
+    /// let mut buffer = [0i64; 4];
+    ///
+    /// // Convert to Q8.8 fixed-point values
+    /// assert!(half_values.convert_to_fixed_point_slice(&mut buffer, 8).is_ok());
+    ///
+    /// assert_eq!(buffer, [256, -384, 64, 0]);
+    /// assert!([f16::NAN].convert_to_fixed_point_slice(&mut [0], 8).is_err());
     /// ```
-    #[must_use]
-    fn reinterpret_cast_mut<H>(&mut self) -> &mut [H]
-    where
-        H: crate::private::SealedHalf;
-}
+    fn convert_to_fixed_point_slice(
+        &self,
+        dst: &mut [i64],
+        fractional_bits: u32,
+    ) -> Result<(), TryFromFloatError>;
 
-mod private {
-    use crate::{bf16, f16};
+    /// Counts the elements of `self` that are above a compiled threshold.
+    ///
+    /// This gives the same result as converting each element to [`f32`] and
+    /// comparing it against the threshold, but only compares the bits of
+    /// each element. NaN elements are never counted.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use float16::*;
+    /// let values = [f16::from_f32(-1.), f16::from_f32(0.1), f16::from_f32(2.), f16::NAN];
+    ///
+    /// // 0.1 is not representable, and rounds to a value just below it.
+    /// assert_eq!(values.count_above(&CompiledThreshold::new(0.1)), 1);
+    /// assert_eq!(values.count_above(&CompiledThreshold::new(0.09)), 2);
+    /// assert_eq!(values.count_above(&CompiledThreshold::new(-5.)), 3);
+    /// ```
+    #[must_use]
+    fn count_above(&self, threshold: &CompiledThreshold) -> usize;
 
-    pub trait SealedHalfFloatSlice {}
-    impl SealedHalfFloatSlice for [f16] {
-    }
-    impl SealedHalfFloatSlice for [bf16] {
-    }
+    /// Sets each element of `mask` to whether the corresponding element of
+    /// `self` is above a compiled threshold.
+    ///
+    /// The length of `mask` must be the same as `self`. See
+    /// [`count_above`][HalfFloatSliceExt::count_above] for the comparison.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use float16::*;
+    /// let values = [bf16::from_f32(-1.), bf16::from_f32(1.), bf16::from_f32(2.)];
+    /// let mut mask = [false; 3];
+    ///
+    /// values.mask_above(&CompiledThreshold::new_inclusive(1.), &mut mask);
+    ///
+    /// assert_eq!(mask, [false, true, true]);
+    /// ```
+    fn mask_above(&self, threshold: &CompiledThreshold, mask: &mut [bool]);
 
-    pub trait SealedHalfBitsSlice {}
-    impl SealedHalfBitsSlice for [u16] {
-    }
-}
+    /// Applies the softmax function to all of the [`struct@f16`] or
+    /// [`struct@bf16`] elements of `self` in-place.
+    ///
+    /// Each element `x` is replaced by `exp(x - max) / sum`, where `max` is
+    /// the largest element of the slice and `sum` is the sum of all of the
+    /// shifted exponentials. Subtracting the maximum first prevents `exp`
+    /// from overflowing. The exponentials, their sum and the quotients are
+    /// kept in [`f32`], where each operation rounds, and each output is
+    /// narrowed to half precision once at the end. The slice is converted
+    /// to [`f32`] in chunks, using the vectorized conversions when hardware
+    /// support is available.
+    ///
+    /// If any element is NaN or +∞, or if every element is −∞, every element
+    /// of the result will be NaN. This does nothing for an empty slice.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use float16::*;
+    /// let mut logits = [f16::from_f32(1.), f16::from_f32(1.), f16::from_f32(1.), f16::from_f32(1.)];
+    ///
+    /// logits.softmax_inplace();
+    ///
+    /// assert_eq!(logits, [f16::from_f32(0.25); 4]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn softmax_inplace(&mut self);
 
-impl HalfFloatSliceExt for [f16] {
-    #[inline]
-    fn reinterpret_cast(&self) -> &[u16] {
-        let pointer = self.as_ptr() as *const u16;
-        let length = self.len();
-        // SAFETY: We are reconstructing full length of original slice, using its same
-        // lifetime, and the size of elements are identical
-        unsafe { slice::from_raw_parts(pointer, length) }
-    }
+    /// Sums all of the elements of `self` as [`f32`] values with pairwise
+    /// summation.
+    ///
+    /// The shape of the summation tree only depends on the length of the
+    /// slice, so the result is identical on every target and backend. A
+    /// slice of at most 128 elements is a block, which is summed into 8
+    /// lanes, with element `i` added to lane `i % 8` in order, and the
+    /// lanes are then added as `((l0 + l4) + (l2 + l6)) + ((l1 + l5) + (l3 +
+    /// l7))`. A longer slice of `n` elements is split after the first
+    /// `ceil(ceil(n / 128) / 2) * 128` elements, and the sums of both halves
+    /// are added.
+    ///
+    /// Since every split is on a multiple of 128 elements, sums of separate
+    /// parts of a slice can be computed in parallel and merged with the same
+    /// rule to get the same result. The error grows with the logarithm of the
+    /// length, rather than linearly as with sequential summation. An empty
+    /// slice sums to `0`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use float16::*;
+    /// let mut values = [f16::from_f32(0.000_06); 1000];
+    /// values[0] = f16::ONE;
+    ///
+    /// // Adding each small value to 1 sequentially rounds it away.
+    /// let sequential = values.iter().fold(0f32, |acc, x| acc + x.to_f32());
+    /// let exact = 1. + 999. * f16::from_f32(0.000_06).to_f64();
+    /// let error = |sum: f32| (sum as f64 - exact).abs();
+    /// assert!(error(values.sum_pairwise_f32()) < error(sequential));
+    /// ```
+    #[must_use]
+    fn sum_pairwise_f32(&self) -> f32;
 
-    #[inline]
-    fn reinterpret_cast_mut(&mut self) -> &mut [u16] {
-        let pointer = self.as_mut_ptr().cast::<u16>();
-        let length = self.len();
-        // SAFETY: We are reconstructing full length of original slice, using its same
-        // lifetime, and the size of elements are identical
-        unsafe { slice::from_raw_parts_mut(pointer, length) }
-    }
+    /// Computes the dot product of `self` and `other` as [`f32`] values with
+    /// pairwise summation.
+    ///
+    /// Each product is computed in [`f32`], and the products are summed with
+    /// the same fixed tree as
+    /// [`sum_pairwise_f32`][HalfFloatSliceExt::sum_pairwise_f32], so the
+    /// result is identical on every target and backend.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use float16::*;
+    /// let a = [bf16::from_f32(1.), bf16::from_f32(2.), bf16::from_f32(3.)];
+    /// let b = [bf16::from_f32(4.), bf16::from_f32(-5.), bf16::from_f32(6.)];
+    ///
+    /// assert_eq!(a.dot_pairwise(&b), 12.);
+    /// ```
+    #[must_use]
+    fn dot_pairwise(&self, other: &Self) -> f32;
 
-    #[inline]
-    fn convert_from_f32_slice(&mut self, src: &[f32]) {
-        assert_eq!(self.len(), src.len(), "destination and source slices have different lengths");
+    /// Adds the products of the elements of `self` and `other`, as [`f32`]
+    /// values, to the elements of `accum`.
+    ///
+    /// Each element of `accum` is updated as with
+    /// [`f16::fma_f32_accum`][crate::f16::fma_f32_accum], so
+    /// `accum[i] = self[i] * other[i] + accum[i]`. The product of two
+    /// [`struct@f16`] values is exact in [`f32`], so each element is rounded
+    /// once, as with a fused multiply-add, and the result is identical on
+    /// every CPU, with or without FMA. The product of two [`struct@bf16`]
+    /// values is exact unless it is smaller than the smallest normal [`f32`].
+    /// The elements are widened in chunks with the vectorized conversions of
+    /// [`convert_to_f32_slice`][HalfFloatSliceExt::convert_to_f32_slice].
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the three slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use float16::*;
+    /// let a = [f16::from_f32(1.5), f16::from_f32(-2.), f16::MAX];
+    /// let b = [f16::from_f32(4.), f16::from_f32(0.25), f16::MAX];
+    /// let mut accum = [1f32, 1., 0.];
+    ///
+    /// a.fma_accumulate(&b, &mut accum);
+    ///
+    /// // `f16::MAX * f16::MAX` overflows `f16`, but not `f32`.
+    /// assert_eq!(accum, [7., 0.5, 65504. * 65504.]);
+    /// ```
+    fn fma_accumulate(&self, other: &Self, accum: &mut [f32]);
 
-        arch::f32_to_f16_slice(src, self.reinterpret_cast_mut())
-    }
+    /// Adds the elements of `self` and `other`, storing the sums in `dst`.
+    ///
+    /// Each element is the same as with the `+` operator, rounded once to
+    /// the nearest value. [`struct@f16`] slices are added 8 elements at a
+    /// time: with native half-precision instructions on `aarch64` CPUs with
+    /// the `fp16` feature, and in [`f32`] with AVX on `x86` and `x86_64` CPUs
+    /// with the `f16c` feature. Other slices are widened to [`f32`] in chunks.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the three slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use float16::*;
+    /// let a = [1., 2., 3.].map(f16::from_f32);
+    /// let b = [0.5, -2., f32::INFINITY].map(f16::from_f32);
+    /// let mut sums = [f16::ZERO; 3];
+    ///
+    /// a.zip_add(&b, &mut sums);
+    ///
+    /// assert_eq!(sums, [1.5, 0., f32::INFINITY].map(f16::from_f32));
+    /// ```
+    fn zip_add(&self, other: &Self, dst: &mut Self);
 
-    #[inline]
-    fn convert_from_f64_slice(&mut self, src: &[f64]) {
-        assert_eq!(self.len(), src.len(), "destination and source slices have different lengths");
+    /// Subtracts the elements of `other` from the elements of `self`,
+    /// storing the differences in `dst`.
+    ///
+    /// Each element is the same as with the `-` operator. See
+    /// [`zip_add`][HalfFloatSliceExt::zip_add] for how the slices are
+    /// vectorized.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the three slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use float16::*;
+    /// let a = [1., 2.].map(bf16::from_f32);
+    /// let b = [0.5, 4.].map(bf16::from_f32);
+    /// let mut differences = [bf16::ZERO; 2];
+    ///
+    /// a.zip_sub(&b, &mut differences);
+    ///
+    /// assert_eq!(differences, [0.5, -2.].map(bf16::from_f32));
+    /// ```
+    fn zip_sub(&self, other: &Self, dst: &mut Self);
 
-        arch::f64_to_f16_slice(src, self.reinterpret_cast_mut())
+    /// Multiplies the elements of `self` and `other`, storing the products
+    /// in `dst`.
+    ///
+    /// Each element is the same as with the `*` operator. See
+    /// [`zip_add`][HalfFloatSliceExt::zip_add] for how the slices are
+    /// vectorized.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the three slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use float16::*;
+    /// let a = [1.5, 300.].map(f16::from_f32);
+    /// let b = [-2., 300.].map(f16::from_f32);
+    /// let mut products = [f16::ZERO; 2];
+    ///
+    /// a.zip_mul(&b, &mut products);
+    ///
+    /// // `300 * 300` overflows `f16`.
+    /// assert_eq!(products, [f16::from_f32(-3.), f16::INFINITY]);
+    /// ```
+    fn zip_mul(&self, other: &Self, dst: &mut Self);
+
+    /// Divides the elements of `self` by the elements of `other`, storing
+    /// the quotients in `dst`.
+    ///
+    /// Each element is the same as with the `/` operator. See
+    /// [`zip_add`][HalfFloatSliceExt::zip_add] for how the slices are
+    /// vectorized.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the three slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use float16::*;
+    /// let a = [1., -1., 0.].map(f16::from_f32);
+    /// let b = [4., 0., 0.].map(f16::from_f32);
+    /// let mut quotients = [f16::ZERO; 3];
+    ///
+    /// a.zip_div(&b, &mut quotients);
+    ///
+    /// assert_eq!(quotients[..2], [f16::from_f32(0.25), f16::NEG_INFINITY]);
+    /// assert!(quotients[2].is_nan());
+    /// ```
+    fn zip_div(&self, other: &Self, dst: &mut Self);
+
+    /// Computes the remainders of dividing the elements of `self` by the
+    /// elements of `other`, storing them in `dst`.
+    ///
+    /// Each element is the same as with the `%` operator, which is exact.
+    /// There is no vector instruction for the remainder, so only the
+    /// conversions of [`struct@f16`] slices are vectorized.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the three slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use float16::*;
+    /// let a = [7., -7.].map(f16::from_f32);
+    /// let b = [2., 2.].map(f16::from_f32);
+    /// let mut remainders = [f16::ZERO; 2];
+    ///
+    /// a.zip_rem(&b, &mut remainders);
+    ///
+    /// assert_eq!(remainders, [1., -1.].map(f16::from_f32));
+    /// ```
+    fn zip_rem(&self, other: &Self, dst: &mut Self);
+
+    /// Multiplies the elements of `self` and `other`, and adds the products
+    /// to the elements of `accum`.
+    ///
+    /// Each element of `accum` is updated to `self[i] * other[i] + accum[i]`,
+    /// computed in [`f32`] and rounded to the nearest value. The product of
+    /// two [`struct@f16`] values is exact in [`f32`], so only the sum is
+    /// rounded, but it is rounded twice, which can differ from a fused
+    /// multiply-add in the last place when the [`f32`] sum is halfway between
+    /// two values. The result is identical on every CPU. See
+    /// [`fma_accumulate`][HalfFloatSliceExt::fma_accumulate] to keep the
+    /// sums in [`f32`].
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the three slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use float16::*;
+    /// let a = [2., 0.5].map(f16::from_f32);
+    /// let b = [3., -4.].map(f16::from_f32);
+    /// let mut accum = [1., 1.].map(f16::from_f32);
+    ///
+    /// a.zip_fma(&b, &mut accum);
+    ///
+    /// assert_eq!(accum, [7., -1.].map(f16::from_f32));
+    /// ```
+    fn zip_fma(&self, other: &Self, accum: &mut Self);
+
+    /// Sums all of the elements of `self` as [`f32`] values with recursive
+    /// pairwise summation.
+    ///
+    /// A slice of at most 16 elements is summed sequentially. A longer slice
+    /// is split in half, with the first half having `n / 2` elements, and
+    /// the sums of both halves are added. The error grows with the logarithm
+    /// of the length, rather than linearly as with sequential summation. See
+    /// [`sum_pairwise_f32`][HalfFloatSliceExt::sum_pairwise_f32] for a tree
+    /// that can be merged from parallel parts. An empty slice sums to `0`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use float16::*;
+    /// let values = [f16::from_f32(0.5); 100];
+    ///
+    /// assert_eq!(values.pairwise_sum_f32(), 50.);
+    /// ```
+    #[must_use]
+    fn pairwise_sum_f32(&self) -> f32;
+
+    /// Sums all of the elements of `self` as [`f32`] values with compensated
+    /// summation.
+    ///
+    /// This uses Neumaier's variant of Kahan summation, which keeps the
+    /// rounding error of each addition in a separate [`f32`] and adds it back
+    /// at the end, even when an element is larger than the running sum. The
+    /// error does not grow with the length of the slice. An empty slice sums
+    /// to `0`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use float16::*;
+    /// let mut values = [bf16::from_f32(1.); 3];
+    /// values[0] = bf16::from_f32(1e10);
+    /// values[2] = bf16::from_f32(-1e10);
+    ///
+    /// // Sequentially, the 1 is rounded away when added to 1e10.
+    /// assert_eq!(values.iter().fold(0f32, |acc, x| acc + x.to_f32()), 0.);
+    /// assert_eq!(values.kahan_sum_f32(), 1.);
+    /// ```
+    #[must_use]
+    fn kahan_sum_f32(&self) -> f32;
+
+    /// Computes the Euclidean norm of `self`, the square root of the sum of
+    /// the squares of the elements.
+    ///
+    /// The squares are exact and summed in types where neither they nor the
+    /// sum can overflow, so only the additions and the result round.
+    /// [`struct@f16`] squares are between `2^-48` and `2^32`, and are summed
+    /// in [`f32`] lanes, using FMA instructions when hardware support is
+    /// available, with the lanes added to [`f64`] sums every 64 elements so
+    /// the error does not grow with the length. [`struct@bf16`] squares are
+    /// between about `2^-266` and `2^256`, which overflows [`f32`], so they
+    /// are summed in [`f64`]. The norm is rounded once to [`f32`]. The result
+    /// is NaN if any element is NaN, and +∞ if any element is infinite and
+    /// none is NaN. An empty slice has a norm of `0`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use float16::*;
+    /// let values = [f16::from_f32(3.), f16::from_f32(-4.)];
+    /// assert_eq!(values.l2_norm(), 5.);
+    ///
+    /// // The squares overflow `f16`, but not the norm.
+    /// let values = [f16::MAX; 4];
+    /// assert_eq!(values.l2_norm(), 131008.);
+    /// ```
+    #[must_use]
+    #[cfg(feature = "std")]
+    fn l2_norm(&self) -> f32;
+
+    /// Computes the sum of the absolute values of the elements of `self`.
+    ///
+    /// The absolute values are summed in [`f64`], and the result is rounded
+    /// once to [`f32`]. The result is NaN if any element is NaN, and +∞ if
+    /// any element is infinite and none is NaN. An empty slice has a norm of
+    /// `0`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use float16::*;
+    /// let values = [bf16::from_f32(1.5), bf16::from_f32(-2.), bf16::NEG_ZERO];
+    /// assert_eq!(values.l1_norm(), 3.5);
+    /// ```
+    #[must_use]
+    fn l1_norm(&self) -> f32;
+
+    /// Returns the largest absolute value of the elements of `self`.
+    ///
+    /// This compares the bits of the elements, so it is exact and is a
+    /// branch-free loop the compiler vectorizes. The result is a positive
+    /// NaN if any element is NaN, and an empty slice has a norm of `+0`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use float16::*;
+    /// let values = [f16::from_f32(1.5), f16::from_f32(-8.), f16::from_f32(2.)];
+    /// assert_eq!(values.linf_norm(), f16::from_f32(8.));
+    /// assert!([f16::ONE, f16::NAN].linf_norm().is_nan());
+    /// ```
+    #[must_use]
+    fn linf_norm(&self) -> Self::Element;
+
+    /// Applies the rectified linear unit, `max(0, x)`, to every element of
+    /// `self` in place.
+    ///
+    /// Negative values, including −0 and −∞, become +0, and all other values,
+    /// including NaN, are unchanged. This only operates on the bits of the
+    /// elements, so it is exact, and is a branch-free loop the compiler
+    /// vectorizes without requiring hardware support for half-precision
+    /// values.
+    ///
+    /// There is intentionally no path using the hardware conversions, such as
+    /// F16C or NEON: the portable loop compiles to a few SSE2 or NEON integer
+    /// instructions per vector, and is several times faster than converting
+    /// to [`f32`] and back.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use float16::*;
+    /// let mut values = [f16::from_f32(-1.5), f16::NEG_ZERO, f16::from_f32(2.), f16::NAN];
+    ///
+    /// values.relu_inplace();
+    ///
+    /// assert_eq!(values[..3], [f16::ZERO, f16::ZERO, f16::from_f32(2.)]);
+    /// assert_eq!(values[1].to_bits(), f16::ZERO.to_bits());
+    /// assert!(values[3].is_nan());
+    /// ```
+    fn relu_inplace(&mut self);
+
+    /// Applies the rectified linear unit, `max(0, x)`, to every element of
+    /// `self`, storing the results in `dst`.
+    ///
+    /// The length of `dst` must be the same as `self`. See
+    /// [`relu_inplace`][HalfFloatSliceExt::relu_inplace] for how the values
+    /// are handled.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use float16::*;
+    /// let values = [bf16::from_f32(-3.), bf16::from_f32(0.5)];
+    /// let mut activations = [bf16::ZERO; 2];
+    ///
+    /// values.relu_to(&mut activations);
+    ///
+    /// assert_eq!(activations, [bf16::ZERO, bf16::from_f32(0.5)]);
+    /// ```
+    fn relu_to(&self, dst: &mut Self);
+
+    /// Replaces every `NaN` element of `self` with the canonical `NaN`, as
+    /// with [`f16::canonicalize`][crate::f16::canonicalize], leaving all
+    /// other elements unchanged.
+    ///
+    /// This only operates on the bits of the elements, so it is a loop the
+    /// compiler vectorizes without requiring hardware support for
+    /// half-precision values.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use float16::*;
+    /// let mut values = [f16::from_bits(0xFC01), f16::NEG_ZERO, f16::ONE];
+    ///
+    /// values.canonicalize_inplace();
+    ///
+    /// assert_eq!(values.reinterpret_cast(), [f16::NAN.to_bits(), 0x8000, 0x3C00]);
+    /// ```
+    fn canonicalize_inplace(&mut self);
+
+    /// Replaces every `NaN` element of `self` with the canonical `NaN`, and
+    /// every −0 with +0, as with
+    /// [`f16::canonicalize_zero`][crate::f16::canonicalize_zero].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use float16::*;
+    /// let mut values = [-bf16::NAN, bf16::NEG_ZERO, bf16::NEG_ONE];
+    ///
+    /// values.canonicalize_zero_inplace();
+    ///
+    /// assert_eq!(values.reinterpret_cast(), [bf16::NAN.to_bits(), 0, 0xBF80]);
+    /// ```
+    fn canonicalize_zero_inplace(&mut self);
+
+    /// Transposes `self`, a row-major matrix with `rows` rows and `cols`
+    /// columns, storing the row-major matrix with `cols` rows and `rows`
+    /// columns in `dst`.
+    ///
+    /// This copies the bits of the elements in square tiles that fit in the
+    /// L1 cache, so both the reads and the writes stay cache-friendly for
+    /// large matrices. Matrices with no rows or columns are empty, and are
+    /// left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the length of `self` is not `rows * cols`,
+    /// or if `dst` has a different length than `self`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use float16::*;
+    /// let matrix = [1., 2., 3., 4., 5., 6.].map(f16::from_f32);
+    /// let mut transposed = [f16::ZERO; 6];
+    ///
+    /// matrix.transpose_to(2, 3, &mut transposed);
+    ///
+    /// assert_eq!(transposed, [1., 4., 2., 5., 3., 6.].map(f16::from_f32));
+    /// ```
+    fn transpose_to(&self, rows: usize, cols: usize, dst: &mut Self);
+
+    /// Transposes `self`, a row-major square matrix with `n` rows and
+    /// columns, in place.
+    ///
+    /// The elements are swapped in square tiles, the same as
+    /// [`transpose_to`][HalfFloatSliceExt::transpose_to].
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the length of `self` is not `n * n`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use float16::*;
+    /// let mut matrix = [1., 2., 3., 4.].map(bf16::from_f32);
+    ///
+    /// matrix.transpose_square_inplace(2);
+    ///
+    /// assert_eq!(matrix, [1., 3., 2., 4.].map(bf16::from_f32));
+    /// ```
+    fn transpose_square_inplace(&mut self, n: usize);
+    /// Sorts the slice in the order of [`total_cmp`][crate::f16::total_cmp].
+    ///
+    /// Negative NaN values sort first, then −∞, the negative values, −0, +0,
+    /// the positive values, +∞ and the positive NaN values last. Values are
+    /// only equal in this order if they have the same bits, so the sort is
+    /// stable only in name, and gives the same result as
+    /// [`sort_total_order_unstable`][HalfFloatSliceExt::sort_total_order_unstable],
+    /// which does not allocate.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use float16::*;
+    /// let mut values = [f16::NAN, f16::ONE, f16::ZERO, f16::NEG_ZERO, f16::NEG_INFINITY];
+    ///
+    /// values.sort_total_order();
+    ///
+    /// assert_eq!(values[..4], [f16::NEG_INFINITY, f16::NEG_ZERO, f16::ZERO, f16::ONE]);
+    /// assert_eq!(values[1].to_bits(), f16::NEG_ZERO.to_bits());
+    /// assert!(values[4].is_nan());
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn sort_total_order(&mut self);
+
+    /// Sorts the slice in the order of [`total_cmp`][crate::f16::total_cmp],
+    /// without allocating.
+    ///
+    /// This sorts by the key of
+    /// [`to_ordered_bits`][crate::f16::to_ordered_bits], which is the same
+    /// order, and gives the same result as
+    /// `sort_total_order` when the `alloc` feature is enabled.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use float16::*;
+    /// let mut values = [bf16::from_f32(2.), -bf16::NAN, bf16::NEG_ZERO, bf16::MIN];
+    ///
+    /// values.sort_total_order_unstable();
+    ///
+    /// assert!(values[0].is_nan());
+    /// assert_eq!(values[1..], [bf16::MIN, bf16::NEG_ZERO, bf16::from_f32(2.)]);
+    /// ```
+    fn sort_total_order_unstable(&mut self);
+}
+
+/// Extensions to `[u16]` slices to support reinterpret operations.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait HalfBitsSliceExt: private::SealedHalfBitsSlice {
+    /// Reinterprets a slice of [`u16`] bits as a slice of [`struct@f16`] or
+    /// [`struct@bf16`] numbers.
+    ///
+    /// `H` is the type to cast to, and must be either the [`struct@f16`] or
+    /// [`struct@bf16`] type.
+    ///
+    /// This is a zero-copy operation. The reinterpreted slice has the same
+    /// lifetime and memory location as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let int_buffer = [f16::from_f32(1.).to_bits(), f16::from_f32(2.).to_bits(), f16::from_f32(3.).to_bits()];
+    /// let float_buffer: &[f16] = int_buffer.reinterpret_cast();
+    ///
+    /// assert_eq!(float_buffer, [f16::from_f32(1.), f16::from_f32(2.), f16::from_f32(3.)]);
+    ///
+    /// // You may have to specify the cast type directly if the compiler can't infer the type.
+    /// // The following is also valid in Rust.
+    /// let typed_buffer = int_buffer.reinterpret_cast::<f16>();
+    /// ```
+    #[must_use]
+    fn reinterpret_cast<H>(&self) -> &[H]
+    where
+        H: crate::private::SealedHalf;
+
+    /// Reinterprets a mutable slice of [`u16`] bits as a mutable slice of
+    /// [`struct@f16`] or [`struct@bf16`] numbers.
+    ///
+    /// `H` is the type to cast to, and must be either the [`struct@f16`] or
+    /// [`struct@bf16`] type.
+    ///
+    /// This is a zero-copy operation. The transmuted slice has the same
+    /// lifetime as the original, which prevents mutating `self` as long as
+    /// the returned `&mut [f16]` is borrowed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let mut int_buffer = [f16::from_f32(1.).to_bits(), f16::from_f32(2.).to_bits(), f16::from_f32(3.).to_bits()];
+    ///
+    /// {
+    ///     let float_buffer: &mut [f16] = int_buffer.reinterpret_cast_mut();
+    ///
+    ///     assert_eq!(float_buffer, [f16::from_f32(1.), f16::from_f32(2.), f16::from_f32(3.)]);
+    ///
+    ///     // Mutating the f16 slice will mutating the original
+    ///     float_buffer[0] = f16::from_f32(0.);
+    /// }
+    ///
+    /// // Note that we need to drop float_buffer before using int_buffer again or we will get a borrow error.
+    /// assert_eq!(int_buffer, [f16::from_f32(0.).to_bits(), f16::from_f32(2.).to_bits(), f16::from_f32(3.).to_bits()]);
+    ///
+    /// // You may have to specify the cast type directly if the compiler can't infer the type.
+    /// // The following is also valid in Rust.
+    /// let typed_buffer = int_buffer.reinterpret_cast_mut::<f16>();
+    /// ```
+    #[must_use]
+    fn reinterpret_cast_mut<H>(&mut self) -> &mut [H]
+    where
+        H: crate::private::SealedHalf;
+
+    /// Guesses the byte order that a buffer of [`struct@f16`] bits was
+    /// written in.
+    ///
+    /// This is intended for untagged binary data that was read directly into
+    /// `self`, so each element is interpreted from its bytes in memory as
+    /// both little- and big-endian. Typical data has few values that are NaN,
+    /// infinite, subnormal or within a factor of 2 of the limits of the
+    /// normal range, while swapping the bytes of a value moves the low
+    /// mantissa bits into the exponent, so these values are counted for both
+    /// interpretations and the byte order with the fewest is chosen. Elements
+    /// that are zero in either byte order are ignored.
+    ///
+    /// The confidence is the difference between the two counts, divided by
+    /// the larger count. This returns [`EndiannessGuess::Ambiguous`] if fewer
+    /// than 16 elements are considered, or if the counts differ by less than
+    /// 1/16th of the number of elements considered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let values: Vec<u16> = (1..100).map(|i| f16::from_f32(i as f32 * 0.37).to_bits()).collect();
+    /// let little: Vec<u16> = values.iter().map(|v| v.to_le()).collect();
+    /// let big: Vec<u16> = values.iter().map(|v| v.to_be()).collect();
+    ///
+    /// assert!(matches!(little.guess_endianness(), EndiannessGuess::Likely(Endianness::Little, _)));
+    /// assert!(matches!(big.guess_endianness(), EndiannessGuess::Likely(Endianness::Big, _)));
+    /// assert_eq!([0x3C3Cu16; 32].guess_endianness(), EndiannessGuess::Ambiguous);
+    /// ```
+    #[must_use]
+    fn guess_endianness(&self) -> EndiannessGuess;
+
+    /// Swaps the byte order of every element of `self` in place.
+    ///
+    /// This converts bits stored in a foreign byte order, such as big-endian
+    /// data loaded on a little-endian host, to the native byte order, or vice
+    /// versa. This is equivalent to calling [`u16::swap_bytes`] on each
+    /// element, but is vectorized on hardware that supports SIMD byte
+    /// shuffles, such as `x86`/`x86_64` with `avx2` or `aarch64` with `neon`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let mut buffer = [f16::from_f32(1.).to_bits().to_be(), f16::from_f32(2.).to_bits().to_be()];
+    /// if cfg!(target_endian = "little") {
+    ///     buffer.byteswap_inplace();
+    /// }
+    ///
+    /// assert_eq!(buffer, [f16::from_f32(1.).to_bits(), f16::from_f32(2.).to_bits()]);
+    /// ```
+    fn byteswap_inplace(&mut self);
+
+    /// Copies every element of `self` into `dst` with its byte order swapped.
+    ///
+    /// This is the copying equivalent of
+    /// [`byteswap_inplace`][HalfBitsSliceExt::byteswap_inplace].
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let buffer = [0x3C00u16, 0x4000];
+    /// let mut swapped = [0u16; 2];
+    /// buffer.byteswap_to(&mut swapped);
+    ///
+    /// assert_eq!(swapped, [0x003C, 0x0040]);
+    /// ```
+    fn byteswap_to(&self, dst: &mut [u16]);
+
+    /// Swaps the byte order of every element of `self` and converts the
+    /// resulting [`struct@f16`] bits into [`f32`] values in `dst`.
+    ///
+    /// This is a single pass over the data, equivalent to
+    /// [`byteswap_to`][HalfBitsSliceExt::byteswap_to] followed by
+    /// [`HalfFloatSliceExt::convert_to_f32_slice`] without an intermediate
+    /// buffer of the full length.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use float16::*;
+    /// let buffer = [f16::from_f32(1.).to_bits().swap_bytes(), f16::from_f32(2.).to_bits().swap_bytes()];
+    /// let mut values = [0f32; 2];
+    /// buffer.byteswap_and_convert_to_f32_slice(&mut values);
+    ///
+    /// assert_eq!(values, [1., 2.]);
+    /// ```
+    fn byteswap_and_convert_to_f32_slice(&self, dst: &mut [f32]);
+}
+
+/// The byte order of binary data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endianness {
+    /// Least significant byte first.
+    Little,
+    /// Most significant byte first.
+    Big,
+}
+
+/// The result of [`HalfBitsSliceExt::guess_endianness`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EndiannessGuess {
+    /// The data was likely written in this byte order, with a confidence in
+    /// `(0, 1]`.
+    Likely(Endianness, f32),
+    /// Both byte orders are equally plausible, or there is too little data.
+    Ambiguous,
+}
+
+mod private {
+    use crate::{bf16, f16};
+
+    pub trait SealedHalfFloatSlice {}
+    impl SealedHalfFloatSlice for [f16] {
+    }
+    impl SealedHalfFloatSlice for [bf16] {
+    }
+
+    pub trait SealedHalfBitsSlice {}
+    impl SealedHalfBitsSlice for [u16] {
+    }
+}
+
+impl HalfFloatSliceExt for [f16] {
+    type Element = f16;
+
+    #[inline]
+    fn reinterpret_cast(&self) -> &[u16] {
+        let pointer = self.as_ptr() as *const u16;
+        let length = self.len();
+        // SAFETY: We are reconstructing full length of original slice, using its same
+        // lifetime, and the size of elements are identical
+        unsafe { slice::from_raw_parts(pointer, length) }
+    }
+
+    #[inline]
+    fn reinterpret_cast_mut(&mut self) -> &mut [u16] {
+        let pointer = self.as_mut_ptr().cast::<u16>();
+        let length = self.len();
+        // SAFETY: We are reconstructing full length of original slice, using its same
+        // lifetime, and the size of elements are identical
+        unsafe { slice::from_raw_parts_mut(pointer, length) }
+    }
+
+    #[inline]
+    fn convert_from_f32_slice(&mut self, src: &[f32]) {
+        assert_eq!(self.len(), src.len(), "destination and source slices have different lengths");
+
+        arch::f32_to_f16_slice(src, self.reinterpret_cast_mut())
+    }
+
+    #[inline]
+    unsafe fn convert_from_f32_slice_unchecked_normal(&mut self, src: &[f32]) {
+        assert_eq!(self.len(), src.len(), "destination and source slices have different lengths");
+
+        arch::f32_to_f16_slice_normal(src, self.reinterpret_cast_mut())
+    }
+
+    #[inline]
+    fn convert_from_f64_slice(&mut self, src: &[f64]) {
+        assert_eq!(self.len(), src.len(), "destination and source slices have different lengths");
+
+        arch::f64_to_f16_slice(src, self.reinterpret_cast_mut())
+    }
+
+    #[inline]
+    fn convert_to_f32_slice(&self, dst: &mut [f32]) {
+        assert_eq!(self.len(), dst.len(), "destination and source slices have different lengths");
+
+        arch::f16_to_f32_slice(self.reinterpret_cast(), dst)
+    }
+
+    #[inline]
+    fn convert_to_f64_slice(&self, dst: &mut [f64]) {
+        assert_eq!(self.len(), dst.len(), "destination and source slices have different lengths");
+
+        arch::f16_to_f64_slice(self.reinterpret_cast(), dst)
+    }
+
+    #[inline]
+    fn convert_to_f16_slice(&self, dst: &mut [f16]) {
+        assert_eq!(self.len(), dst.len(), "destination and source slices have different lengths");
+
+        dst.copy_from_slice(self);
+    }
+
+    #[inline]
+    fn convert_to_bf16_slice(&self, dst: &mut [bf16]) {
+        assert_eq!(self.len(), dst.len(), "destination and source slices have different lengths");
+
+        arch::f16_to_bf16_slice(self.reinterpret_cast(), dst.reinterpret_cast_mut())
+    }
+
+    #[inline]
+    fn convert_from_fixed_point_slice(&mut self, src: &[i64], fractional_bits: u32) {
+        assert_eq!(self.len(), src.len(), "destination and source slices have different lengths");
+
+        for (h, &v) in self.iter_mut().zip(src.iter()) {
+            *h = f16::from_fixed_point(v, fractional_bits);
+        }
+    }
+
+    #[inline]
+    fn convert_from_u8_norm_slice(&mut self, src: &[u8]) {
+        assert_eq!(self.len(), src.len(), "destination and source slices have different lengths");
+
+        arch::u8_norm_to_f16_slice(src, self.reinterpret_cast_mut())
+    }
+
+    #[inline]
+    fn convert_to_fixed_point_slice(
+        &self,
+        dst: &mut [i64],
+        fractional_bits: u32,
+    ) -> Result<(), TryFromFloatError> {
+        assert_eq!(self.len(), dst.len(), "destination and source slices have different lengths");
+
+        for (v, h) in dst.iter_mut().zip(self.iter()) {
+            *v = h.to_fixed_point(fractional_bits).ok_or(TryFromFloatError(()))?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn count_above(&self, threshold: &CompiledThreshold) -> usize {
+        self.iter().filter(|&&x| threshold.is_f16_above(x)).count()
+    }
+
+    #[inline]
+    fn mask_above(&self, threshold: &CompiledThreshold, mask: &mut [bool]) {
+        assert_eq!(self.len(), mask.len(), "destination and source slices have different lengths");
+
+        for (m, &x) in mask.iter_mut().zip(self.iter()) {
+            *m = threshold.is_f16_above(x);
+        }
+    }
+
+    #[inline]
+    #[cfg(feature = "std")]
+    fn softmax_inplace(&mut self) {
+        softmax_inplace(self)
+    }
+
+    #[inline]
+    fn sum_pairwise_f32(&self) -> f32 {
+        sum_pairwise(self)
+    }
+
+    #[inline]
+    fn dot_pairwise(&self, other: &Self) -> f32 {
+        assert_eq!(self.len(), other.len(), "slices have different lengths");
+        dot_pairwise(self, other)
+    }
+
+    #[inline]
+    fn fma_accumulate(&self, other: &Self, accum: &mut [f32]) {
+        assert_eq!(self.len(), other.len(), "slices have different lengths");
+        assert_eq!(self.len(), accum.len(), "slices have different lengths");
+        fma_accumulate(self, other, accum)
+    }
+
+    #[inline]
+    fn zip_add(&self, other: &Self, dst: &mut Self) {
+        zip_f16(self, other, dst, ZipOp::Add)
+    }
+
+    #[inline]
+    fn zip_sub(&self, other: &Self, dst: &mut Self) {
+        zip_f16(self, other, dst, ZipOp::Subtract)
+    }
+
+    #[inline]
+    fn zip_mul(&self, other: &Self, dst: &mut Self) {
+        zip_f16(self, other, dst, ZipOp::Multiply)
+    }
+
+    #[inline]
+    fn zip_div(&self, other: &Self, dst: &mut Self) {
+        zip_f16(self, other, dst, ZipOp::Divide)
+    }
+
+    #[inline]
+    fn zip_rem(&self, other: &Self, dst: &mut Self) {
+        zip_f16(self, other, dst, ZipOp::Remainder)
+    }
+
+    #[inline]
+    fn zip_fma(&self, other: &Self, accum: &mut Self) {
+        zip_f16(self, other, accum, ZipOp::MulAdd)
+    }
+
+    #[inline]
+    fn pairwise_sum_f32(&self) -> f32 {
+        pairwise_sum(self)
     }
 
     #[inline]
-    fn convert_to_f32_slice(&self, dst: &mut [f32]) {
-        assert_eq!(self.len(), dst.len(), "destination and source slices have different lengths");
+    fn kahan_sum_f32(&self) -> f32 {
+        kahan_sum(self)
+    }
 
-        arch::f16_to_f32_slice(self.reinterpret_cast(), dst)
+    #[inline]
+    #[cfg(feature = "std")]
+    fn l2_norm(&self) -> f32 {
+        l2_norm_f32(self)
     }
 
     #[inline]
-    fn convert_to_f64_slice(&self, dst: &mut [f64]) {
-        assert_eq!(self.len(), dst.len(), "destination and source slices have different lengths");
+    fn l1_norm(&self) -> f32 {
+        l1_norm(self)
+    }
 
-        arch::f16_to_f64_slice(self.reinterpret_cast(), dst)
+    #[inline]
+    fn linf_norm(&self) -> Self::Element {
+        f16::from_bits(max_abs_bits(self.reinterpret_cast()))
+    }
+
+    #[inline]
+    fn relu_inplace(&mut self) {
+        for x in self.reinterpret_cast_mut() {
+            *x = relu_bits(*x, 0x7C00);
+        }
+    }
+
+    #[inline]
+    fn relu_to(&self, dst: &mut Self) {
+        assert_eq!(dst.len(), self.len(), "destination and source slices have different lengths");
+
+        for (d, &x) in dst.reinterpret_cast_mut().iter_mut().zip(self.reinterpret_cast()) {
+            *d = relu_bits(x, 0x7C00);
+        }
+    }
+
+    #[inline]
+    fn canonicalize_inplace(&mut self) {
+        for x in self.iter_mut() {
+            *x = x.canonicalize();
+        }
+    }
+
+    #[inline]
+    fn canonicalize_zero_inplace(&mut self) {
+        for x in self.iter_mut() {
+            *x = x.canonicalize_zero();
+        }
+    }
+
+    #[inline]
+    fn transpose_to(&self, rows: usize, cols: usize, dst: &mut Self) {
+        assert_eq!(
+            Some(self.len()),
+            rows.checked_mul(cols),
+            "matrix dimensions do not match the slice length"
+        );
+        assert_eq!(dst.len(), self.len(), "destination and source slices have different lengths");
+
+        transpose_tiles(self, rows, cols, dst)
+    }
+
+    #[inline]
+    fn transpose_square_inplace(&mut self, n: usize) {
+        assert_eq!(
+            Some(self.len()),
+            n.checked_mul(n),
+            "matrix dimensions do not match the slice length"
+        );
+
+        transpose_square_bits(self.reinterpret_cast_mut(), n)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn sort_total_order(&mut self) {
+        self.sort_by(f16::total_cmp)
+    }
+
+    #[inline]
+    fn sort_total_order_unstable(&mut self) {
+        self.sort_unstable_by_key(|x| x.to_ordered_bits())
     }
 }
 
 impl HalfFloatSliceExt for [bf16] {
+    type Element = bf16;
+
     #[inline]
     fn reinterpret_cast(&self) -> &[u16] {
         let pointer = self.as_ptr() as *const u16;
@@ -335,78 +1451,667 @@ impl HalfFloatSliceExt for [bf16] {
         unsafe { slice::from_raw_parts_mut(pointer, length) }
     }
 
-    #[inline]
-    fn convert_from_f32_slice(&mut self, src: &[f32]) {
-        assert_eq!(self.len(), src.len(), "destination and source slices have different lengths");
+    #[inline]
+    fn convert_from_f32_slice(&mut self, src: &[f32]) {
+        assert_eq!(self.len(), src.len(), "destination and source slices have different lengths");
+
+        arch::f32_to_bf16_slice(src, self.reinterpret_cast_mut())
+    }
+
+    #[inline]
+    unsafe fn convert_from_f32_slice_unchecked_normal(&mut self, src: &[f32]) {
+        assert_eq!(self.len(), src.len(), "destination and source slices have different lengths");
+
+        arch::f32_to_bf16_slice_non_nan(src, self.reinterpret_cast_mut())
+    }
+
+    #[inline]
+    fn convert_from_f64_slice(&mut self, src: &[f64]) {
+        assert_eq!(self.len(), src.len(), "destination and source slices have different lengths");
+
+        // Just use regular loop here until there's any bf16 SIMD support.
+        for (i, f) in src.iter().enumerate() {
+            self[i] = bf16::from_f64(*f);
+        }
+    }
+
+    #[inline]
+    fn convert_to_f32_slice(&self, dst: &mut [f32]) {
+        assert_eq!(self.len(), dst.len(), "destination and source slices have different lengths");
+
+        arch::bf16_to_f32_slice(self.reinterpret_cast(), dst)
+    }
+
+    #[inline]
+    fn convert_to_f64_slice(&self, dst: &mut [f64]) {
+        assert_eq!(self.len(), dst.len(), "destination and source slices have different lengths");
+
+        // Just use regular loop here until there's any bf16 SIMD support.
+        for (i, f) in self.iter().enumerate() {
+            dst[i] = f.to_f64();
+        }
+    }
+
+    #[inline]
+    fn convert_to_f16_slice(&self, dst: &mut [f16]) {
+        assert_eq!(self.len(), dst.len(), "destination and source slices have different lengths");
+
+        arch::bf16_to_f16_slice(self.reinterpret_cast(), dst.reinterpret_cast_mut())
+    }
+
+    #[inline]
+    fn convert_to_bf16_slice(&self, dst: &mut [bf16]) {
+        assert_eq!(self.len(), dst.len(), "destination and source slices have different lengths");
+
+        dst.copy_from_slice(self);
+    }
+
+    #[inline]
+    fn convert_from_fixed_point_slice(&mut self, src: &[i64], fractional_bits: u32) {
+        assert_eq!(self.len(), src.len(), "destination and source slices have different lengths");
+
+        for (h, &v) in self.iter_mut().zip(src.iter()) {
+            *h = bf16::from_fixed_point(v, fractional_bits);
+        }
+    }
+
+    #[inline]
+    fn convert_from_u8_norm_slice(&mut self, src: &[u8]) {
+        assert_eq!(self.len(), src.len(), "destination and source slices have different lengths");
+
+        // Just use regular loop here until there's any bf16 SIMD support.
+        for (h, &v) in self.iter_mut().zip(src.iter()) {
+            *h = bf16::from_u8_norm(v);
+        }
+    }
+
+    #[inline]
+    fn convert_to_fixed_point_slice(
+        &self,
+        dst: &mut [i64],
+        fractional_bits: u32,
+    ) -> Result<(), TryFromFloatError> {
+        assert_eq!(self.len(), dst.len(), "destination and source slices have different lengths");
+
+        for (v, h) in dst.iter_mut().zip(self.iter()) {
+            *v = h.to_fixed_point(fractional_bits).ok_or(TryFromFloatError(()))?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn count_above(&self, threshold: &CompiledThreshold) -> usize {
+        self.iter().filter(|&&x| threshold.is_bf16_above(x)).count()
+    }
+
+    #[inline]
+    fn mask_above(&self, threshold: &CompiledThreshold, mask: &mut [bool]) {
+        assert_eq!(self.len(), mask.len(), "destination and source slices have different lengths");
+
+        for (m, &x) in mask.iter_mut().zip(self.iter()) {
+            *m = threshold.is_bf16_above(x);
+        }
+    }
+
+    #[inline]
+    #[cfg(feature = "std")]
+    fn softmax_inplace(&mut self) {
+        softmax_inplace(self)
+    }
+
+    #[inline]
+    fn sum_pairwise_f32(&self) -> f32 {
+        sum_pairwise(self)
+    }
+
+    #[inline]
+    fn dot_pairwise(&self, other: &Self) -> f32 {
+        assert_eq!(self.len(), other.len(), "slices have different lengths");
+        dot_pairwise(self, other)
+    }
+
+    #[inline]
+    fn fma_accumulate(&self, other: &Self, accum: &mut [f32]) {
+        assert_eq!(self.len(), other.len(), "slices have different lengths");
+        assert_eq!(self.len(), accum.len(), "slices have different lengths");
+        fma_accumulate(self, other, accum)
+    }
+
+    #[inline]
+    fn zip_add(&self, other: &Self, dst: &mut Self) {
+        zip_widened(self, other, dst, ZipOp::Add)
+    }
+
+    #[inline]
+    fn zip_sub(&self, other: &Self, dst: &mut Self) {
+        zip_widened(self, other, dst, ZipOp::Subtract)
+    }
+
+    #[inline]
+    fn zip_mul(&self, other: &Self, dst: &mut Self) {
+        zip_widened(self, other, dst, ZipOp::Multiply)
+    }
+
+    #[inline]
+    fn zip_div(&self, other: &Self, dst: &mut Self) {
+        zip_widened(self, other, dst, ZipOp::Divide)
+    }
+
+    #[inline]
+    fn zip_rem(&self, other: &Self, dst: &mut Self) {
+        zip_widened(self, other, dst, ZipOp::Remainder)
+    }
+
+    #[inline]
+    fn zip_fma(&self, other: &Self, accum: &mut Self) {
+        zip_widened(self, other, accum, ZipOp::MulAdd)
+    }
+
+    #[inline]
+    fn pairwise_sum_f32(&self) -> f32 {
+        pairwise_sum(self)
+    }
+
+    #[inline]
+    fn kahan_sum_f32(&self) -> f32 {
+        kahan_sum(self)
+    }
+
+    #[inline]
+    #[cfg(feature = "std")]
+    fn l2_norm(&self) -> f32 {
+        l2_norm_f64(self)
+    }
+
+    #[inline]
+    fn l1_norm(&self) -> f32 {
+        l1_norm(self)
+    }
+
+    #[inline]
+    fn linf_norm(&self) -> Self::Element {
+        bf16::from_bits(max_abs_bits(self.reinterpret_cast()))
+    }
+
+    #[inline]
+    fn relu_inplace(&mut self) {
+        for x in self.reinterpret_cast_mut() {
+            *x = relu_bits(*x, 0x7F80);
+        }
+    }
+
+    #[inline]
+    fn relu_to(&self, dst: &mut Self) {
+        assert_eq!(dst.len(), self.len(), "destination and source slices have different lengths");
+
+        for (d, &x) in dst.reinterpret_cast_mut().iter_mut().zip(self.reinterpret_cast()) {
+            *d = relu_bits(x, 0x7F80);
+        }
+    }
+
+    #[inline]
+    fn canonicalize_inplace(&mut self) {
+        for x in self.iter_mut() {
+            *x = x.canonicalize();
+        }
+    }
+
+    #[inline]
+    fn canonicalize_zero_inplace(&mut self) {
+        for x in self.iter_mut() {
+            *x = x.canonicalize_zero();
+        }
+    }
+
+    #[inline]
+    fn transpose_to(&self, rows: usize, cols: usize, dst: &mut Self) {
+        assert_eq!(
+            Some(self.len()),
+            rows.checked_mul(cols),
+            "matrix dimensions do not match the slice length"
+        );
+        assert_eq!(dst.len(), self.len(), "destination and source slices have different lengths");
+
+        transpose_tiles(self, rows, cols, dst)
+    }
+
+    #[inline]
+    fn transpose_square_inplace(&mut self, n: usize) {
+        assert_eq!(
+            Some(self.len()),
+            n.checked_mul(n),
+            "matrix dimensions do not match the slice length"
+        );
+
+        transpose_square_bits(self.reinterpret_cast_mut(), n)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn sort_total_order(&mut self) {
+        self.sort_by(bf16::total_cmp)
+    }
+
+    #[inline]
+    fn sort_total_order_unstable(&mut self) {
+        self.sort_unstable_by_key(|x| x.to_ordered_bits())
+    }
+}
+
+impl HalfBitsSliceExt for [u16] {
+    // Since we sealed all the traits involved, these are safe.
+    #[inline]
+    fn reinterpret_cast<H>(&self) -> &[H]
+    where
+        H: crate::private::SealedHalf,
+    {
+        let pointer = self.as_ptr() as *const H;
+        let length = self.len();
+        // SAFETY: We are reconstructing full length of original slice, using its same
+        // lifetime, and the size of elements are identical
+        unsafe { slice::from_raw_parts(pointer, length) }
+    }
+
+    #[inline]
+    fn reinterpret_cast_mut<H>(&mut self) -> &mut [H]
+    where
+        H: crate::private::SealedHalf,
+    {
+        let pointer = self.as_mut_ptr() as *mut H;
+        let length = self.len();
+        // SAFETY: We are reconstructing full length of original slice, using its same
+        // lifetime, and the size of elements are identical
+        unsafe { slice::from_raw_parts_mut(pointer, length) }
+    }
+
+    fn guess_endianness(&self) -> EndiannessGuess {
+        const MIN_LENGTH: usize = 16;
+
+        let mut count = 0usize;
+        let mut little = 0usize;
+        let mut big = 0usize;
+        for &bits in self.iter() {
+            let (le, be) = (u16::from_le(bits), u16::from_be(bits));
+            if le & 0x7FFF != 0 && be & 0x7FFF != 0 {
+                count += 1;
+                little += is_unlikely_f16(le) as usize;
+                big += is_unlikely_f16(be) as usize;
+            }
+        }
+
+        let diff = little.abs_diff(big);
+        if count < MIN_LENGTH || diff * 16 < count {
+            return EndiannessGuess::Ambiguous;
+        }
+        let confidence = diff as f32 / little.max(big) as f32;
+        if little < big {
+            EndiannessGuess::Likely(Endianness::Little, confidence)
+        } else {
+            EndiannessGuess::Likely(Endianness::Big, confidence)
+        }
+    }
+
+    #[inline]
+    fn byteswap_inplace(&mut self) {
+        arch::swap_bytes_slice_inplace(self)
+    }
+
+    #[inline]
+    fn byteswap_to(&self, dst: &mut [u16]) {
+        assert_eq!(self.len(), dst.len(), "destination and source slices have different lengths");
+
+        arch::swap_bytes_slice(self, dst)
+    }
+
+    fn byteswap_and_convert_to_f32_slice(&self, dst: &mut [f32]) {
+        assert_eq!(self.len(), dst.len(), "destination and source slices have different lengths");
+
+        let mut buffer = [0u16; CHUNK_SIZE];
+        for (src, dst) in self.chunks(CHUNK_SIZE).zip(dst.chunks_mut(CHUNK_SIZE)) {
+            let buffer = &mut buffer[..src.len()];
+            arch::swap_bytes_slice(src, buffer);
+            arch::f16_to_f32_slice(buffer, dst);
+        }
+    }
+}
+
+/// Returns if the [`struct@f16`] bits are NaN, infinite, subnormal, or within
+/// a factor of 2 of the limits of the normal range.
+#[inline]
+const fn is_unlikely_f16(bits: u16) -> bool {
+    let exp = (bits & 0x7C00) >> 10;
+    match exp {
+        0 => bits & 0x7FFF != 0,
+        1 | 30 | 31 => true,
+        _ => false,
+    }
+}
+
+/// Number of elements converted at a time when a slice operation needs an
+/// intermediate buffer.
+const CHUNK_SIZE: usize = 64;
+
+#[cfg(feature = "std")]
+fn softmax_inplace<H>(slice: &mut [H])
+where
+    [H]: HalfFloatSliceExt,
+{
+    let mut buffer = [0f32; CHUNK_SIZE];
+
+    // Find the maximum first, so none of the exponentials can overflow.
+    let mut max = f32::NEG_INFINITY;
+    for chunk in slice.chunks(CHUNK_SIZE) {
+        let buffer = &mut buffer[..chunk.len()];
+        chunk.convert_to_f32_slice(buffer);
+        max = buffer.iter().fold(max, |acc, &x| acc.max(x));
+    }
+
+    // NaN elements are skipped by `f32::max`, but still propagate here.
+    let mut sum = 0f32;
+    for chunk in slice.chunks(CHUNK_SIZE) {
+        let buffer = &mut buffer[..chunk.len()];
+        chunk.convert_to_f32_slice(buffer);
+        sum += buffer.iter().map(|&x| (x - max).exp()).sum::<f32>();
+    }
+
+    // Recompute the exponentials rather than storing them in half precision,
+    // so each element is only narrowed once.
+    for chunk in slice.chunks_mut(CHUNK_SIZE) {
+        let buffer = &mut buffer[..chunk.len()];
+        chunk.convert_to_f32_slice(buffer);
+        for x in buffer.iter_mut() {
+            *x = (*x - max).exp() / sum;
+        }
+        chunk.convert_from_f32_slice(buffer);
+    }
+}
+
+/// Number of elements in a block of the pairwise summation tree.
+const PAIRWISE_BLOCK: usize = 128;
+
+/// Number of lanes each block is summed into.
+const PAIRWISE_LANES: usize = 8;
+
+/// Returns the length of the first half of a slice of `len` elements in the
+/// pairwise summation tree, which is always a multiple of the block size.
+#[inline]
+const fn pairwise_split(len: usize) -> usize {
+    let blocks = (len + PAIRWISE_BLOCK - 1) / PAIRWISE_BLOCK;
+    (blocks + 1) / 2 * PAIRWISE_BLOCK
+}
+
+/// Sums a block of at most [`PAIRWISE_BLOCK`] values into the lanes, then
+/// adds the lanes in a fixed order.
+#[inline]
+fn block_sum(values: &[f32]) -> f32 {
+    let mut lanes = [0f32; PAIRWISE_LANES];
+    let mut i = 0;
+    while i < values.len() {
+        lanes[i % PAIRWISE_LANES] += values[i];
+        i += 1;
+    }
+    ((lanes[0] + lanes[4]) + (lanes[2] + lanes[6]))
+        + ((lanes[1] + lanes[5]) + (lanes[3] + lanes[7]))
+}
+
+fn sum_pairwise<H>(slice: &[H]) -> f32
+where
+    [H]: HalfFloatSliceExt,
+{
+    if slice.len() <= PAIRWISE_BLOCK {
+        // The conversions are exact, so using the vectorized conversions does
+        // not change the result.
+        let mut buffer = [0f32; PAIRWISE_BLOCK];
+        let buffer = &mut buffer[..slice.len()];
+        slice.convert_to_f32_slice(buffer);
+        return block_sum(buffer);
+    }
+    let (left, right) = slice.split_at(pairwise_split(slice.len()));
+    sum_pairwise(left) + sum_pairwise(right)
+}
+
+fn dot_pairwise<H>(lhs: &[H], rhs: &[H]) -> f32
+where
+    [H]: HalfFloatSliceExt,
+{
+    if lhs.len() <= PAIRWISE_BLOCK {
+        let mut products = [0f32; PAIRWISE_BLOCK];
+        let mut buffer = [0f32; PAIRWISE_BLOCK];
+        let products = &mut products[..lhs.len()];
+        let buffer = &mut buffer[..lhs.len()];
+        lhs.convert_to_f32_slice(products);
+        rhs.convert_to_f32_slice(buffer);
+        for (p, &x) in products.iter_mut().zip(buffer.iter()) {
+            *p *= x;
+        }
+        return block_sum(products);
+    }
+    let split = pairwise_split(lhs.len());
+    let (lhs_left, lhs_right) = lhs.split_at(split);
+    let (rhs_left, rhs_right) = rhs.split_at(split);
+    dot_pairwise(lhs_left, rhs_left) + dot_pairwise(lhs_right, rhs_right)
+}
 
-        // Just use regular loop here until there's any bf16 SIMD support.
-        for (i, f) in src.iter().enumerate() {
-            self[i] = bf16::from_f32(*f);
+fn fma_accumulate<H>(lhs: &[H], rhs: &[H], accum: &mut [f32])
+where
+    [H]: HalfFloatSliceExt,
+{
+    let mut lhs_buffer = [0f32; CHUNK_SIZE];
+    let mut rhs_buffer = [0f32; CHUNK_SIZE];
+    let chunks = lhs.chunks(CHUNK_SIZE).zip(rhs.chunks(CHUNK_SIZE));
+    for ((lhs, rhs), accum) in chunks.zip(accum.chunks_mut(CHUNK_SIZE)) {
+        let lhs_buffer = &mut lhs_buffer[..lhs.len()];
+        let rhs_buffer = &mut rhs_buffer[..rhs.len()];
+        lhs.convert_to_f32_slice(lhs_buffer);
+        rhs.convert_to_f32_slice(rhs_buffer);
+        for ((acc, &x), &y) in accum.iter_mut().zip(lhs_buffer.iter()).zip(rhs_buffer.iter()) {
+            *acc += x * y;
         }
     }
+}
 
-    #[inline]
-    fn convert_from_f64_slice(&mut self, src: &[f64]) {
-        assert_eq!(self.len(), src.len(), "destination and source slices have different lengths");
+fn zip_f16(lhs: &[f16], rhs: &[f16], dst: &mut [f16], op: ZipOp) {
+    assert_eq!(lhs.len(), rhs.len(), "slices have different lengths");
+    assert_eq!(lhs.len(), dst.len(), "slices have different lengths");
+    arch::zip_f16_slice(
+        lhs.reinterpret_cast(),
+        rhs.reinterpret_cast(),
+        dst.reinterpret_cast_mut(),
+        op,
+    );
+}
 
-        // Just use regular loop here until there's any bf16 SIMD support.
-        for (i, f) in src.iter().enumerate() {
-            self[i] = bf16::from_f64(*f);
+fn zip_widened<H>(lhs: &[H], rhs: &[H], dst: &mut [H], op: ZipOp)
+where
+    [H]: HalfFloatSliceExt,
+{
+    assert_eq!(lhs.len(), rhs.len(), "slices have different lengths");
+    assert_eq!(lhs.len(), dst.len(), "slices have different lengths");
+    let mut lhs_buffer = [0f32; CHUNK_SIZE];
+    let mut rhs_buffer = [0f32; CHUNK_SIZE];
+    let mut dst_buffer = [0f32; CHUNK_SIZE];
+    let chunks = lhs.chunks(CHUNK_SIZE).zip(rhs.chunks(CHUNK_SIZE));
+    for ((lhs, rhs), dst) in chunks.zip(dst.chunks_mut(CHUNK_SIZE)) {
+        let lhs_buffer = &mut lhs_buffer[..lhs.len()];
+        let rhs_buffer = &mut rhs_buffer[..rhs.len()];
+        let dst_buffer = &mut dst_buffer[..dst.len()];
+        lhs.convert_to_f32_slice(lhs_buffer);
+        rhs.convert_to_f32_slice(rhs_buffer);
+        dst.convert_to_f32_slice(dst_buffer);
+        for ((z, &x), &y) in dst_buffer.iter_mut().zip(lhs_buffer.iter()).zip(rhs_buffer.iter()) {
+            *z = op.apply_f32(x, y, *z);
         }
+        dst.convert_from_f32_slice(dst_buffer);
     }
+}
 
-    #[inline]
-    fn convert_to_f32_slice(&self, dst: &mut [f32]) {
-        assert_eq!(self.len(), dst.len(), "destination and source slices have different lengths");
+/// Largest slice that [`pairwise_sum`] sums sequentially.
+const PAIRWISE_BASE: usize = 16;
+
+fn pairwise_sum<H>(slice: &[H]) -> f32
+where
+    [H]: HalfFloatSliceExt,
+{
+    if slice.len() <= PAIRWISE_BASE {
+        let mut buffer = [0f32; PAIRWISE_BASE];
+        let buffer = &mut buffer[..slice.len()];
+        slice.convert_to_f32_slice(buffer);
+        return buffer.iter().fold(0f32, |acc, &x| acc + x);
+    }
+    let (left, right) = slice.split_at(slice.len() / 2);
+    pairwise_sum(left) + pairwise_sum(right)
+}
 
-        // Just use regular loop here until there's any bf16 SIMD support.
-        for (i, f) in self.iter().enumerate() {
-            dst[i] = f.to_f32();
+fn kahan_sum<H>(slice: &[H]) -> f32
+where
+    [H]: HalfFloatSliceExt,
+{
+    let mut buffer = [0f32; CHUNK_SIZE];
+    let mut sum = 0f32;
+    let mut compensation = 0f32;
+    for chunk in slice.chunks(CHUNK_SIZE) {
+        let buffer = &mut buffer[..chunk.len()];
+        chunk.convert_to_f32_slice(buffer);
+        for &x in buffer.iter() {
+            let t = sum + x;
+            // Keep the low-order bits of whichever operand is smaller. This
+            // compares magnitudes by bits, since `f32::abs` requires std.
+            if sum.to_bits() & 0x7FFF_FFFF >= x.to_bits() & 0x7FFF_FFFF {
+                compensation += (sum - t) + x;
+            } else {
+                compensation += (x - t) + sum;
+            }
+            sum = t;
         }
     }
+    sum + compensation
+}
 
-    #[inline]
-    fn convert_to_f64_slice(&self, dst: &mut [f64]) {
-        assert_eq!(self.len(), dst.len(), "destination and source slices have different lengths");
-
-        // Just use regular loop here until there's any bf16 SIMD support.
-        for (i, f) in self.iter().enumerate() {
-            dst[i] = f.to_f64();
+/// Computes the Euclidean norm of [`struct@f16`] values, summing the squares
+/// of each chunk in eight [`f32`] lanes, and then in eight [`f64`] lanes.
+///
+/// Each [`f32`] lane only sums 8 squares, so the error does not grow with the
+/// length of the slice, as it would if the lanes summed the whole slice.
+#[cfg(feature = "std")]
+fn l2_norm_f32(slice: &[f16]) -> f32 {
+    let mut buffer = [0f32; CHUNK_SIZE];
+    let mut sums = [0f64; 8];
+    for chunk in slice.chunks(CHUNK_SIZE) {
+        let buffer = &mut buffer[..chunk.len()];
+        chunk.convert_to_f32_slice(buffer);
+        let mut acc = [0f32; 8];
+        arch::sum_squares_f32(buffer, &mut acc);
+        for (sum, &x) in sums.iter_mut().zip(acc.iter()) {
+            *sum += x as f64;
         }
     }
+    sums.iter().sum::<f64>().sqrt() as f32
 }
 
-impl HalfBitsSliceExt for [u16] {
-    // Since we sealed all the traits involved, these are safe.
-    #[inline]
-    fn reinterpret_cast<H>(&self) -> &[H]
-    where
-        H: crate::private::SealedHalf,
-    {
-        let pointer = self.as_ptr() as *const H;
-        let length = self.len();
-        // SAFETY: We are reconstructing full length of original slice, using its same
-        // lifetime, and the size of elements are identical
-        unsafe { slice::from_raw_parts(pointer, length) }
+/// Computes the Euclidean norm, accumulating the squares in [`f64`], which
+/// [`struct@bf16`] values need since their squares can overflow [`f32`].
+#[cfg(feature = "std")]
+fn l2_norm_f64<H>(slice: &[H]) -> f32
+where
+    [H]: HalfFloatSliceExt,
+{
+    let mut buffer = [0f32; CHUNK_SIZE];
+    let mut sum = 0f64;
+    for chunk in slice.chunks(CHUNK_SIZE) {
+        let buffer = &mut buffer[..chunk.len()];
+        chunk.convert_to_f32_slice(buffer);
+        sum += buffer.iter().map(|&x| x as f64 * x as f64).sum::<f64>();
     }
+    sum.sqrt() as f32
+}
 
-    #[inline]
-    fn reinterpret_cast_mut<H>(&mut self) -> &mut [H]
-    where
-        H: crate::private::SealedHalf,
-    {
-        let pointer = self.as_mut_ptr() as *mut H;
-        let length = self.len();
-        // SAFETY: We are reconstructing full length of original slice, using its same
-        // lifetime, and the size of elements are identical
-        unsafe { slice::from_raw_parts_mut(pointer, length) }
+fn l1_norm<H>(slice: &[H]) -> f32
+where
+    [H]: HalfFloatSliceExt,
+{
+    let mut buffer = [0f32; CHUNK_SIZE];
+    let mut sum = 0f64;
+    for chunk in slice.chunks(CHUNK_SIZE) {
+        let buffer = &mut buffer[..chunk.len()];
+        chunk.convert_to_f32_slice(buffer);
+        // Clear the sign bit, since `f32::abs` requires std.
+        sum +=
+            buffer.iter().map(|&x| f32::from_bits(x.to_bits() & 0x7FFF_FFFF) as f64).sum::<f64>();
+    }
+    sum as f32
+}
+
+/// Returns the largest bits of the absolute values, which is the largest
+/// absolute value, or a NaN if there are any.
+#[inline]
+fn max_abs_bits(bits: &[u16]) -> u16 {
+    bits.iter().fold(0, |max, &x| max.max(x & 0x7FFF))
+}
+
+/// Returns the bits of `max(0, x)`, where `inf` is the bits of +∞.
+///
+/// The comparison is branch-free, so loops over it can be vectorized.
+#[inline]
+const fn relu_bits(bits: u16, inf: u16) -> u16 {
+    // Negative values other than NaN become +0
+    let negative = (bits & 0x8000 != 0) & (bits & 0x7FFF <= inf);
+    bits & ((negative as u16).wrapping_sub(1))
+}
+
+/// The number of rows and columns of the tiles used to transpose matrices.
+///
+/// A pair of tiles of 16-bit elements is 4 KiB, which fits in the L1 cache.
+const TRANSPOSE_BLOCK: usize = 32;
+
+/// Transposes the row-major `rows` by `cols` matrix `src` into `dst`, one tile
+/// at a time.
+pub(crate) fn transpose_tiles<T: Copy>(src: &[T], rows: usize, cols: usize, dst: &mut [T]) {
+    for row_start in (0..rows).step_by(TRANSPOSE_BLOCK) {
+        let row_end = (row_start + TRANSPOSE_BLOCK).min(rows);
+        for col_start in (0..cols).step_by(TRANSPOSE_BLOCK) {
+            let col_end = (col_start + TRANSPOSE_BLOCK).min(cols);
+            for row in row_start..row_end {
+                let src_row = &src[row * cols + col_start..row * cols + col_end];
+                for (col, &x) in (col_start..col_end).zip(src_row) {
+                    dst[col * rows + row] = x;
+                }
+            }
+        }
+    }
+}
+
+/// Transposes the row-major `n` by `n` matrix in place, swapping each tile
+/// above the diagonal with the tile below it.
+fn transpose_square_bits(matrix: &mut [u16], n: usize) {
+    for row_start in (0..n).step_by(TRANSPOSE_BLOCK) {
+        let row_end = (row_start + TRANSPOSE_BLOCK).min(n);
+        for col_start in (row_start..n).step_by(TRANSPOSE_BLOCK) {
+            let col_end = (col_start + TRANSPOSE_BLOCK).min(n);
+            for row in row_start..row_end {
+                // Tiles on the diagonal only swap the elements above it
+                let first = if col_start == row_start {
+                    row + 1
+                } else {
+                    col_start
+                };
+                for col in first..col_end {
+                    matrix.swap(row * n + col, col * n + row);
+                }
+            }
+        }
     }
 }
 
 #[allow(clippy::float_cmp)]
 #[cfg(test)]
 mod test {
-    use super::{HalfBitsSliceExt, HalfFloatSliceExt};
+    use super::{
+        CompiledThreshold,
+        Endianness,
+        EndiannessGuess,
+        HalfBitsSliceExt,
+        HalfFloatSliceExt,
+    };
     use crate::{bf16, f16};
 
     #[test]
@@ -713,6 +2418,104 @@ mod test {
         assert_eq!(&vf16, &buf16);
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn slice_convert_f16_bf16() {
+        // Every value, including NaN payloads, subnormals and values that
+        // overflow `f16`, matches the scalar conversions. The odd length
+        // leaves a remainder in every call.
+        let mut f16s = [f16::ZERO; 1021];
+        let mut bf16s = [bf16::ZERO; 1021];
+        let mut f16_buf = [f16::ZERO; 1021];
+        let mut bf16_buf = [bf16::ZERO; 1021];
+        for start in (0..=u16::MAX as usize).step_by(f16s.len()) {
+            for i in 0..f16s.len() {
+                f16s[i] = f16::from_bits((start + i) as u16);
+                bf16s[i] = bf16::from_bits((start + i) as u16);
+            }
+            f16s.convert_to_bf16_slice(&mut bf16_buf);
+            bf16s.convert_to_f16_slice(&mut f16_buf);
+            for i in 0..f16s.len() {
+                assert_eq!(bf16_buf[i].to_bits(), f16s[i].to_bf16().to_bits(), "{:?}", f16s[i]);
+                assert_eq!(f16_buf[i].to_bits(), bf16s[i].to_f16().to_bits(), "{:?}", bf16s[i]);
+            }
+
+            // Converting to the same type copies the values.
+            f16s.convert_to_f16_slice(&mut f16_buf);
+            bf16s.convert_to_bf16_slice(&mut bf16_buf);
+            assert_eq!(f16_buf.map(f16::to_bits), f16s.map(f16::to_bits));
+            assert_eq!(bf16_buf.map(bf16::to_bits), bf16s.map(bf16::to_bits));
+        }
+
+        let values = [
+            f16::from_bits(0x7E01),
+            f16::from_bits(0xFC01),
+            f16::MIN_POSITIVE_SUBNORMAL,
+            f16::from_bits(0x03FF),
+            f16::MAX,
+            f16::from_f32(-1. - 3. / 1024.),
+            f16::NEG_ZERO,
+            f16::INFINITY,
+            f16::from_f32(0.1),
+        ];
+        // With the `canonical-nan` feature, NaN results lose their sign and
+        // payload.
+        let nan = |bits: u16, canonical: u16| {
+            if cfg!(feature = "canonical-nan") {
+                canonical
+            } else {
+                bits
+            }
+        };
+        let mut buf = [bf16::ZERO; 9];
+        values.convert_to_bf16_slice(&mut buf);
+        assert_eq!(buf.map(bf16::to_bits), [
+            0x7FC0,
+            nan(0xFFC0, 0x7FC0),
+            0x3380,
+            0x3880,
+            0x4780,
+            0xBF80,
+            0x8000,
+            0x7F80,
+            0x3DCD
+        ]);
+        let values = buf.map(|x| bf16::from_bits(x.to_bits() ^ 0x0001));
+        let mut back = [f16::ZERO; 9];
+        values.convert_to_f16_slice(&mut back);
+        assert_eq!(back.map(f16::to_bits), [
+            nan(0x7E08, 0x7E00),
+            nan(0xFE08, 0x7E00),
+            0x0001,
+            0x0408,
+            0x7C00,
+            0xBC08,
+            0x8000,
+            nan(0x7E08, 0x7E00),
+            0x2E60
+        ]);
+    }
+
+    #[test]
+    fn convert_from_u8_norm_slice() {
+        // Cover the vectorized chunks and both remainder sizes.
+        let mut pixels = [0u8; 256 + 7];
+        for (i, p) in pixels.iter_mut().enumerate() {
+            *p = (i * 31) as u8;
+        }
+        for len in [0, 3, 6, 8, 256, 256 + 7] {
+            let src = &pixels[..len];
+            let mut f16s = [f16::ZERO; 256 + 7];
+            let mut bf16s = [bf16::ZERO; 256 + 7];
+            f16s[..len].convert_from_u8_norm_slice(src);
+            bf16s[..len].convert_from_u8_norm_slice(src);
+            for (i, &v) in src.iter().enumerate() {
+                assert_eq!(f16s[i], f16::from_u8_norm(v));
+                assert_eq!(bf16s[i], bf16::from_u8_norm(v));
+            }
+        }
+    }
+
     #[test]
     #[should_panic]
     fn convert_from_f32_slice_len_mismatch_panics() {
@@ -744,4 +2547,877 @@ mod test {
         let mut slice2 = [0f64; 4];
         slice1.convert_to_f64_slice(&mut slice2);
     }
+
+    #[test]
+    #[should_panic]
+    fn convert_to_bf16_slice_len_mismatch_panics() {
+        let slice1 = [f16::ZERO; 3];
+        let mut slice2 = [bf16::ZERO; 4];
+        slice1.convert_to_bf16_slice(&mut slice2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn convert_to_f16_slice_len_mismatch_panics() {
+        let slice1 = [bf16::ZERO; 4];
+        let mut slice2 = [f16::ZERO; 3];
+        slice1.convert_to_f16_slice(&mut slice2);
+    }
+
+    #[test]
+    fn slice_convert_unchecked_normal() {
+        let mut values = [0f32; 200];
+        for (i, v) in values.iter_mut().enumerate() {
+            *v = (i as f32 - 99.5) * 3.7;
+        }
+
+        let mut expected = [f16::ZERO; 200];
+        let mut actual = [f16::ZERO; 200];
+        expected.convert_from_f32_slice(&values);
+        unsafe { actual.convert_from_f32_slice_unchecked_normal(&values) };
+        assert_eq!(actual, expected);
+
+        let mut expected = [bf16::ZERO; 200];
+        let mut actual = [bf16::ZERO; 200];
+        expected.convert_from_f32_slice(&values);
+        unsafe { actual.convert_from_f32_slice_unchecked_normal(&values) };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn slice_convert_fixed_point() {
+        let fixed = [0i64, 1, -1, 200, -384, 1 << 40];
+        let mut halves = [f16::ZERO; 6];
+        halves.convert_from_fixed_point_slice(&fixed, 4);
+        for (h, &v) in halves.iter().zip(fixed.iter()) {
+            assert_eq!(*h, f16::from_fixed_point(v, 4));
+        }
+        let mut result = [0i64; 6];
+        assert!(halves.convert_to_fixed_point_slice(&mut result, 4).is_err());
+        assert!(halves[..5].convert_to_fixed_point_slice(&mut result[..5], 4).is_ok());
+        assert_eq!(result[..5], fixed[..5]);
+
+        let mut bhalves = [bf16::ZERO; 6];
+        bhalves.convert_from_fixed_point_slice(&fixed, 4);
+        for (h, &v) in bhalves.iter().zip(fixed.iter()) {
+            assert_eq!(*h, bf16::from_fixed_point(v, 4));
+        }
+        let mut result = [0i64; 6];
+        assert!(bhalves.convert_to_fixed_point_slice(&mut result, 4).is_ok());
+        assert_eq!(result, fixed);
+        assert!([bf16::NAN].convert_to_fixed_point_slice(&mut result[..1], 4).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn convert_from_fixed_point_slice_len_mismatch_panics() {
+        let mut slice1 = [f16::ZERO; 3];
+        let slice2 = [0i64; 4];
+        slice1.convert_from_fixed_point_slice(&slice2, 0);
+    }
+
+    /// Approximately normally distributed values from a simple LCG.
+    fn normal_f16_bits(count: usize, scale: f32) -> [u16; 512] {
+        let mut state = 0x2545_F491_4F6C_DD1Du64;
+        let mut bits = [0u16; 512];
+        for b in bits[..count].iter_mut() {
+            let mut sum = 0f32;
+            for _ in 0..4 {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                sum += (state >> 40) as f32 / (1u64 << 24) as f32 - 0.5;
+            }
+            *b = f16::from_f32(sum * scale).to_bits();
+        }
+        bits
+    }
+
+    fn to_le(bits: &[u16]) -> [u16; 512] {
+        let mut result = [0u16; 512];
+        for (r, b) in result.iter_mut().zip(bits.iter()) {
+            *r = b.to_le();
+        }
+        result
+    }
+
+    fn to_be(bits: &[u16]) -> [u16; 512] {
+        let mut result = [0u16; 512];
+        for (r, b) in result.iter_mut().zip(bits.iter()) {
+            *r = b.to_be();
+        }
+        result
+    }
+
+    #[test]
+    fn guess_endianness() {
+        for &scale in &[0.05f32, 1., 100.] {
+            let bits = normal_f16_bits(512, scale);
+            match to_le(&bits).guess_endianness() {
+                EndiannessGuess::Likely(Endianness::Little, c) => assert!(c > 0.9, "{}", c),
+                guess => panic!("{:?} for scale {}", guess, scale),
+            }
+            match to_be(&bits).guess_endianness() {
+                EndiannessGuess::Likely(Endianness::Big, c) => assert!(c > 0.9, "{}", c),
+                guess => panic!("{:?} for scale {}", guess, scale),
+            }
+        }
+
+        // Mostly zeros with a few values still works.
+        let mut bits = [0u16; 512];
+        bits[..64].copy_from_slice(&normal_f16_bits(64, 1.)[..64]);
+        assert!(matches!(
+            to_be(&bits).guess_endianness(),
+            EndiannessGuess::Likely(Endianness::Big, _)
+        ));
+    }
+
+    #[test]
+    fn guess_endianness_ambiguous() {
+        // Both bytes are identical, so the interpretations are identical.
+        let bits = [0x3C3Cu16, 0x4141, 0xC5C5, 0x0000];
+        let mut buffer = [0u16; 64];
+        for (i, b) in buffer.iter_mut().enumerate() {
+            *b = bits[i % bits.len()];
+        }
+        assert_eq!(buffer.guess_endianness(), EndiannessGuess::Ambiguous);
+
+        // Values paired with their byte-swapped twins look the same either way.
+        let mut buffer = to_le(&normal_f16_bits(256, 1.));
+        for i in 0..256 {
+            buffer[256 + i] = buffer[i].swap_bytes();
+        }
+        assert_eq!(buffer.guess_endianness(), EndiannessGuess::Ambiguous);
+
+        // Tiny buffers are always ambiguous.
+        let bits = normal_f16_bits(15, 1.);
+        assert_eq!(to_be(&bits[..15])[..15].guess_endianness(), EndiannessGuess::Ambiguous);
+        assert_eq!([0u16; 0].guess_endianness(), EndiannessGuess::Ambiguous);
+        assert_eq!(
+            [f16::from_f32(1.).to_bits().swap_bytes()].guess_endianness(),
+            EndiannessGuess::Ambiguous
+        );
+    }
+
+    #[test]
+    fn byteswap() {
+        // Cover the vectorized chunks and every remainder length.
+        for len in 0..100usize {
+            let mut bits = [0u16; 100];
+            for (i, b) in bits.iter_mut().enumerate() {
+                *b = (i as u16).wrapping_mul(0x9E37) ^ 0x5A;
+            }
+            let bits = &bits[..len];
+            let mut expected = [0u16; 100];
+            for (e, b) in expected.iter_mut().zip(bits) {
+                *e = b.swap_bytes();
+            }
+            let expected = &expected[..len];
+
+            let mut swapped = [0u16; 100];
+            bits.byteswap_to(&mut swapped[..len]);
+            assert_eq!(&swapped[..len], expected);
+
+            let mut inplace = [0u16; 100];
+            inplace[..len].copy_from_slice(bits);
+            inplace[..len].byteswap_inplace();
+            assert_eq!(&inplace[..len], expected);
+            inplace[..len].byteswap_inplace();
+            assert_eq!(&inplace[..len], bits);
+
+            let mut values = [0f32; 100];
+            bits.byteswap_and_convert_to_f32_slice(&mut values[..len]);
+            for (v, e) in values.iter().zip(expected) {
+                assert_eq!(v.to_bits(), f16::from_bits(*e).to_f32().to_bits());
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn byteswap_to_length_mismatch() {
+        let mut dst = [0u16; 3];
+        [0u16; 4].byteswap_to(&mut dst);
+    }
+
+    /// Thresholds at, between and beyond the representable values of both
+    /// types, including the limits of each range and the zeros.
+    fn thresholds() -> impl Iterator<Item = f32> {
+        let special = [
+            0.,
+            -0.,
+            f32::MIN_POSITIVE,
+            1e-8,
+            -1e-8,
+            65504.,
+            65519.,
+            65520.,
+            1e5,
+            -1e5,
+            3.4e38,
+            f32::MAX,
+            f32::MIN,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            f32::NAN,
+        ];
+        let halves = (0..=u16::MAX).step_by(1021).flat_map(|bits| {
+            let x = f16::from_bits(bits).to_f32();
+            let y = bf16::from_bits(bits).to_f32();
+            let next = |v: f32| f32::from_bits(v.to_bits() + 1);
+            [x, next(x), y, next(y), (x + f16::from_bits(bits.wrapping_add(1)).to_f32()) / 2.]
+        });
+        special.into_iter().chain(halves)
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn compiled_threshold() {
+        let mut f16s = [f16::ZERO; 1 << 16];
+        let mut bf16s = [bf16::ZERO; 1 << 16];
+        for (bits, (x, y)) in f16s.iter_mut().zip(bf16s.iter_mut()).enumerate() {
+            *x = f16::from_bits(bits as u16);
+            *y = bf16::from_bits(bits as u16);
+        }
+        let mut mask = [false; 1 << 16];
+
+        for threshold in thresholds() {
+            for &inclusive in &[false, true] {
+                let compiled = if inclusive {
+                    CompiledThreshold::new_inclusive(threshold)
+                } else {
+                    CompiledThreshold::new(threshold)
+                };
+                let above = |x: f32| {
+                    if inclusive {
+                        x >= threshold
+                    } else {
+                        x > threshold
+                    }
+                };
+
+                f16s.mask_above(&compiled, &mut mask);
+                let mut count = 0;
+                for (&x, &m) in f16s.iter().zip(mask.iter()) {
+                    assert_eq!(m, above(x.to_f32()), "{:?} {} {}", x, threshold, inclusive);
+                    count += m as usize;
+                }
+                assert_eq!(f16s.count_above(&compiled), count);
+
+                bf16s.mask_above(&compiled, &mut mask);
+                let mut count = 0;
+                for (&x, &m) in bf16s.iter().zip(mask.iter()) {
+                    assert_eq!(m, above(x.to_f32()), "{:?} {} {}", x, threshold, inclusive);
+                    count += m as usize;
+                }
+                assert_eq!(bf16s.count_above(&compiled), count);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn convert_bf16_slice_all() {
+        // The vectorized paths must match the scalar conversions exactly,
+        // including for subnormals, NaNs and lengths that are not a multiple
+        // of the vector width.
+        let mut bits = [0u16; 1 << 16];
+        for (i, x) in bits.iter_mut().enumerate() {
+            *x = i as u16;
+        }
+        let halves: &[bf16] = bits.reinterpret_cast();
+        let mut floats = [0f32; 1 << 16];
+        halves.convert_to_f32_slice(&mut floats);
+        for (h, f) in halves.iter().zip(floats.iter()) {
+            assert_eq!(h.to_f32().to_bits(), f.to_bits(), "{:?}", h);
+        }
+
+        // Every value half-way between two bfloat16 values, and its neighbors.
+        let mut ties = [0f32; 3 << 16];
+        for (i, x) in ties.chunks_mut(3).enumerate() {
+            let tie = ((i as u32) << 16) | 0x8000;
+            x.copy_from_slice(&[tie - 1, tie, tie + 1].map(f32::from_bits));
+        }
+        let mut halves = [bf16::ZERO; 3 << 16];
+        for len in [ties.len(), ties.len() - 1] {
+            halves[..len].convert_from_f32_slice(&ties[..len]);
+            for (h, &f) in halves[..len].iter().zip(ties.iter()) {
+                assert_eq!(h.to_bits(), bf16::from_f32(f).to_bits(), "{:?}", f);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn softmax_inplace_f16() {
+        let values: [f32; 9] = [-4.5, -1., 0., 0.25, 1., 2., 3.5, 4., 6.];
+        let mut halves = [f16::ZERO; 9];
+        halves.convert_from_f32_slice(&values);
+
+        // Compute the reference from the rounded inputs.
+        let mut expected = [0f32; 9];
+        halves.convert_to_f32_slice(&mut expected);
+        let max = expected.iter().fold(f32::NEG_INFINITY, |acc, &x| acc.max(x));
+        let sum: f32 = expected.iter().map(|&x| (x - max).exp()).sum();
+        for x in expected.iter_mut() {
+            *x = (*x - max).exp() / sum;
+        }
+
+        halves.softmax_inplace();
+        for (h, e) in halves.iter().zip(expected.iter()) {
+            let diff = (h.to_f32() - e).abs();
+            assert!(diff <= 4. * f16::EPSILON.to_f32() * e.abs(), "{} != {}", h, e);
+        }
+
+        // Large inputs must not overflow, and spans multiple chunks.
+        let mut halves = [f16::MAX; 100];
+        halves.softmax_inplace();
+        assert!(halves.iter().all(|&h| h == f16::from_f32(0.01)));
+
+        let mut halves = [f16::ONE, f16::NAN];
+        halves.softmax_inplace();
+        assert!(halves.iter().all(|h| h.is_nan()));
+
+        let mut halves: [f16; 0] = [];
+        halves.softmax_inplace();
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn softmax_inplace_bf16() {
+        let mut halves = [bf16::from_f32(0.), bf16::from_f32(1e30), bf16::from_f32(1e30)];
+        halves.softmax_inplace();
+        assert_eq!(halves, [bf16::ZERO, bf16::from_f32(0.5), bf16::from_f32(0.5)]);
+    }
+
+    /// Sums with the documented tree using scalar conversions, as the
+    /// reference for the pairwise sums.
+    fn sum_pairwise_reference(values: &[f32]) -> f32 {
+        if values.len() <= 128 {
+            let mut lanes = [0f32; 8];
+            for (i, &x) in values.iter().enumerate() {
+                lanes[i % 8] += x;
+            }
+            return ((lanes[0] + lanes[4]) + (lanes[2] + lanes[6]))
+                + ((lanes[1] + lanes[5]) + (lanes[3] + lanes[7]));
+        }
+        let blocks = (values.len() + 127) / 128;
+        let (left, right) = values.split_at((blocks + 1) / 2 * 128);
+        sum_pairwise_reference(left) + sum_pairwise_reference(right)
+    }
+
+    #[test]
+    fn sum_pairwise_f32() {
+        let empty: [f16; 0] = [];
+        assert_eq!(empty.sum_pairwise_f32().to_bits(), 0);
+        assert_eq!(empty.dot_pairwise(&empty).to_bits(), 0);
+        assert_eq!([f16::from_f32(-2.5)].sum_pairwise_f32(), -2.5);
+        assert_eq!([bf16::from_f32(3.)].dot_pairwise(&[bf16::from_f32(-2.)]), -6.);
+        assert!([f16::ONE, f16::NAN].sum_pairwise_f32().is_nan());
+
+        // Pseudo-random values with a wide range, at lengths around the block
+        // boundaries, must match the scalar reference bit for bit.
+        let mut state = 0x2545_F491u32;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state
+        };
+        let mut f16s = [f16::ZERO; 1000];
+        let mut bf16s = [bf16::ZERO; 1000];
+        for (x, y) in f16s.iter_mut().zip(bf16s.iter_mut()) {
+            let bits = next();
+            *x = f16::from_bits(bits as u16 & 0xFBFF);
+            *y = bf16::from_bits((bits >> 16) as u16 & 0xBFFF);
+        }
+        for &len in &[2, 7, 8, 9, 127, 128, 129, 255, 256, 257, 383, 640, 1000] {
+            let mut a = [0f32; 1000];
+            let mut b = [0f32; 1000];
+            for (i, (x, y)) in f16s[..len].iter().zip(f16s[1000 - len..].iter()).enumerate() {
+                a[i] = x.to_f32();
+                b[i] = x.to_f32() * y.to_f32();
+            }
+            let expected = sum_pairwise_reference(&a[..len]);
+            assert_eq!(f16s[..len].sum_pairwise_f32().to_bits(), expected.to_bits(), "{}", len);
+            let expected = sum_pairwise_reference(&b[..len]);
+            let dot = f16s[..len].dot_pairwise(&f16s[1000 - len..]);
+            assert_eq!(dot.to_bits(), expected.to_bits(), "{}", len);
+
+            for (i, x) in bf16s[..len].iter().enumerate() {
+                a[i] = x.to_f32();
+            }
+            let expected = sum_pairwise_reference(&a[..len]);
+            assert_eq!(bf16s[..len].sum_pairwise_f32().to_bits(), expected.to_bits(), "{}", len);
+        }
+
+        // Adversarial data, where each small value is rounded away when added
+        // to the large one sequentially.
+        let mut values = [f16::MIN_POSITIVE_SUBNORMAL; 4096];
+        values[0] = f16::ONE;
+        let sequential = values.iter().fold(0f32, |acc, x| acc + x.to_f32());
+        let exact = 1. + 4095. * f16::MIN_POSITIVE_SUBNORMAL.to_f64();
+        assert_eq!(sequential, 1.);
+        // Only the values added to the lane holding 1 are rounded away.
+        let error = (values.sum_pairwise_f32() as f64 - exact).abs();
+        assert!(error <= 16. * f16::MIN_POSITIVE_SUBNORMAL.to_f64(), "{}", error);
+    }
+
+    #[test]
+    fn fma_accumulate() {
+        // Cover the chunks, a remainder and the empty slice.
+        let mut a = [f16::ZERO; 150];
+        let mut b = [bf16::ZERO; 150];
+        let mut initial = [0f32; 150];
+        for i in 0..a.len() {
+            a[i] = f16::from_bits((i as u16).wrapping_mul(0x1F3));
+            b[i] = bf16::from_bits((i as u16).wrapping_mul(0x2E9) ^ 0x3C00);
+            initial[i] = i as f32 * -0.375;
+        }
+        for len in [0, 3, 64, 150] {
+            let (mut accum, mut wide) = (initial, initial);
+            a[..len].fma_accumulate(&a[..len], &mut accum[..len]);
+            b[..len].fma_accumulate(&b[150 - len..], &mut wide[..len]);
+            for i in 0..len {
+                let scalar = a[i].fma_f32_accum(a[i], initial[i]);
+                assert_eq!(accum[i].to_bits(), scalar.to_bits(), "{:?}", a[i]);
+                let product = b[i].to_f32() * b[150 - len + i].to_f32();
+                assert_eq!(wide[i].to_bits(), (product + initial[i]).to_bits());
+            }
+            // Elements past the slices are untouched.
+            assert_eq!(accum[len..], initial[len..]);
+            assert_eq!(wide[len..], initial[len..]);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn fma_accumulate_len_mismatch_panics() {
+        let a = [f16::ONE; 3];
+        a.fma_accumulate(&a, &mut [0f32; 2]);
+    }
+
+    #[test]
+    fn zip_ops() {
+        // Cover the chunks, a remainder, including NaN, infinite and
+        // subnormal values, and the empty slice.
+        let mut a = [f16::ZERO; 64 * 3 + 4];
+        let mut b = [f16::ZERO; 64 * 3 + 4];
+        crate::testing::fill_stratified(&mut a, 0x2315);
+        crate::testing::fill_stratified(&mut b, 0x2316);
+        b.reverse();
+        let mut c = [bf16::ZERO; 150];
+        let mut d = [bf16::ZERO; 150];
+        for i in 0..c.len() {
+            c[i] = bf16::from_bits((i as u16).wrapping_mul(0x1F3));
+            d[i] = bf16::from_bits((i as u16).wrapping_mul(0x2E9) ^ 0x3F80);
+        }
+        type Ops<H> = [(fn(&[H], &[H], &mut [H]), fn(H, H, H) -> H); 6];
+        let f16_ops: Ops<f16> = [
+            (|a, b, dst| a.zip_add(b, dst), |x, y, _| x + y),
+            (|a, b, dst| a.zip_sub(b, dst), |x, y, _| x - y),
+            (|a, b, dst| a.zip_mul(b, dst), |x, y, _| x * y),
+            (|a, b, dst| a.zip_div(b, dst), |x, y, _| x / y),
+            (|a, b, dst| a.zip_rem(b, dst), |x, y, _| x % y),
+            (
+                |a, b, dst| a.zip_fma(b, dst),
+                |x, y, z| f16::from_f32(x.fma_f32_accum(y, z.to_f32())),
+            ),
+        ];
+        let bf16_ops: Ops<bf16> = [
+            (|a, b, dst| a.zip_add(b, dst), |x, y, _| x + y),
+            (|a, b, dst| a.zip_sub(b, dst), |x, y, _| x - y),
+            (|a, b, dst| a.zip_mul(b, dst), |x, y, _| x * y),
+            (|a, b, dst| a.zip_div(b, dst), |x, y, _| x / y),
+            (|a, b, dst| a.zip_rem(b, dst), |x, y, _| x % y),
+            (
+                |a, b, dst| a.zip_fma(b, dst),
+                |x, y, z| bf16::from_f32(x.to_f32() * y.to_f32() + z.to_f32()),
+            ),
+        ];
+        let same = |x: f32, y: f32| x.to_bits() == y.to_bits() || (x.is_nan() && y.is_nan());
+        for len in [0, 5, 8, 64, 150] {
+            for (zip, scalar) in f16_ops {
+                let n = a.len();
+                let mut dst = b;
+                zip(&a[n - len..], &b[n - len..], &mut dst[..len]);
+                for i in 0..len {
+                    let (x, y) = (a[n - len + i], b[n - len + i]);
+                    let expected = scalar(x, y, b[i]);
+                    assert!(same(dst[i].to_f32(), expected.to_f32()), "{:?} {:?}", x, y);
+                }
+                // Elements past the slices are untouched.
+                assert_eq!(dst[len..].reinterpret_cast(), b[len..].reinterpret_cast());
+            }
+            for (zip, scalar) in bf16_ops {
+                let mut dst = d;
+                zip(&c[..len], &d[150 - len..], &mut dst[..len]);
+                for i in 0..len {
+                    let expected = scalar(c[i], d[150 - len + i], d[i]);
+                    assert!(same(dst[i].to_f32(), expected.to_f32()), "{:?} {:?}", c[i], d[i]);
+                }
+                assert_eq!(dst[len..].reinterpret_cast(), d[len..].reinterpret_cast());
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn zip_len_mismatch_panics() {
+        let a = [f16::ONE; 3];
+        a.zip_add(&a, &mut [f16::ZERO; 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn dot_pairwise_len_mismatch_panics() {
+        let a = [f16::ONE; 3];
+        let _ = a.dot_pairwise(&a[..2]);
+    }
+
+    #[test]
+    fn pairwise_and_kahan_sum_f32() {
+        let empty: [bf16; 0] = [];
+        assert_eq!(empty.pairwise_sum_f32().to_bits(), 0);
+        assert_eq!(empty.kahan_sum_f32().to_bits(), 0);
+        assert_eq!([f16::from_f32(-2.5)].pairwise_sum_f32(), -2.5);
+        assert_eq!([f16::from_f32(-2.5)].kahan_sum_f32(), -2.5);
+        assert!([f16::ONE, f16::NAN].pairwise_sum_f32().is_nan());
+        assert!([bf16::ONE, bf16::NAN].kahan_sum_f32().is_nan());
+
+        // Each small value is rounded away when added to the large one
+        // sequentially.
+        let mut values = [f16::MIN_POSITIVE_SUBNORMAL; 4096];
+        values[0] = f16::ONE;
+        let exact = 1. + 4095. * f16::MIN_POSITIVE_SUBNORMAL.to_f64();
+        let error = |sum: f32| (sum as f64 - exact).abs();
+        let sequential = values.iter().fold(0f32, |acc, x| acc + x.to_f32());
+        assert_eq!(sequential, 1.);
+        assert!(error(values.pairwise_sum_f32()) <= 16. * f16::MIN_POSITIVE_SUBNORMAL.to_f64());
+        assert!(error(values.kahan_sum_f32()) <= f16::MIN_POSITIVE_SUBNORMAL.to_f64());
+
+        // Large cancelling values, which Kahan summation alone would lose.
+        let values =
+            [bf16::from_f32(1.), bf16::from_f32(1e30), bf16::from_f32(1.), bf16::from_f32(-1e30)];
+        assert_eq!(values.kahan_sum_f32(), 2.);
+
+        // The pairwise sum of exactly representable partial sums is exact.
+        let values = [f16::from_f32(0.25); 1000];
+        assert_eq!(values.pairwise_sum_f32(), 250.);
+        assert_eq!(values.kahan_sum_f32(), 250.);
+        assert_eq!(values[..17].pairwise_sum_f32(), 4.25);
+    }
+
+    #[test]
+    fn norms() {
+        let values = [f16::from_f32(3.), f16::from_f32(-4.), f16::NEG_ZERO];
+        #[cfg(feature = "std")]
+        assert_eq!(values.l2_norm(), 5.);
+        assert_eq!(values.l1_norm(), 7.);
+        assert_eq!(values.linf_norm(), f16::from_f32(4.));
+
+        let empty: [bf16; 0] = [];
+        #[cfg(feature = "std")]
+        assert_eq!(empty.l2_norm(), 0.);
+        assert_eq!(empty.l1_norm(), 0.);
+        assert_eq!(empty.linf_norm().to_bits(), 0);
+        assert_eq!([f16::NEG_ZERO].linf_norm().to_bits(), 0);
+
+        // The squares and sums overflow half precision, and for bf16, f32.
+        let values = [f16::MIN; 100];
+        #[cfg(feature = "std")]
+        assert_eq!(values.l2_norm(), 655040.);
+        assert_eq!(values.l1_norm(), 6550400.);
+        assert_eq!(values.linf_norm(), f16::MAX);
+        let values = [bf16::MAX, bf16::MIN];
+        #[cfg(feature = "std")]
+        assert_eq!(values.l2_norm() as f64, (2. * bf16::MAX.to_f64().powi(2)).sqrt() as f32 as f64);
+        assert!(values.l1_norm().is_infinite());
+        assert_eq!(values.linf_norm(), bf16::MAX);
+
+        // Special values.
+        let values = [f16::ONE, f16::NEG_INFINITY, f16::from_f32(-2.)];
+        #[cfg(feature = "std")]
+        assert_eq!(values.l2_norm(), f32::INFINITY);
+        assert_eq!(values.l1_norm(), f32::INFINITY);
+        assert_eq!(values.linf_norm(), f16::INFINITY);
+        let values = [bf16::INFINITY, -bf16::NAN, bf16::ONE];
+        #[cfg(feature = "std")]
+        assert!(values.l2_norm().is_nan());
+        assert!(values.l1_norm().is_nan());
+        assert!(values.linf_norm().is_nan());
+        assert!(values.linf_norm().is_sign_positive());
+
+        // Longer slices than a chunk, against a sequential reference.
+        let mut values = [bf16::ZERO; 300];
+        for (i, x) in values.iter_mut().enumerate() {
+            *x = bf16::from_f32((i as f32 - 150.) * 0.37);
+        }
+        let l1 = values.iter().fold(0f64, |acc, x| acc + x.to_f64().abs());
+        assert_eq!(values.l1_norm(), l1 as f32);
+        #[cfg(feature = "std")]
+        {
+            let l2 = values.iter().fold(0f64, |acc, x| acc + x.to_f64() * x.to_f64());
+            assert_eq!(values.l2_norm(), l2.sqrt() as f32);
+        }
+        assert_eq!(values.linf_norm(), bf16::from_f32(150. * 0.37));
+
+        // The f16 squares are summed in f32 in blocks, which only round
+        // slightly, however long the slice is.
+        #[cfg(feature = "std")]
+        for len in [7, 64, 300, 100_000] {
+            let values: Vec<f16> =
+                (0..len).map(|i| f16::from_f32(((i % 2000) as f32 - 999.5) * 0.37)).collect();
+            let l2 = values.iter().fold(0f64, |acc, x| acc + x.to_f64() * x.to_f64()).sqrt();
+            let error = (values.l2_norm() as f64 - l2).abs() / l2;
+            assert!(error < 1e-6, "{} {}", len, error);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn relu() {
+        let mut values = [
+            f16::from_f32(-1.),
+            f16::NEG_ZERO,
+            f16::ZERO,
+            f16::from_f32(3.5),
+            f16::NEG_INFINITY,
+            f16::INFINITY,
+            f16::MIN,
+            f16::MAX,
+            -f16::MIN_POSITIVE_SUBNORMAL,
+            f16::MIN_POSITIVE_SUBNORMAL,
+            -f16::MAX_SUBNORMAL,
+            f16::NAN,
+            -f16::NAN,
+            f16::from_bits(0xFC01),
+        ];
+        let src = values;
+        let mut dst = [f16::ONE; 14];
+        src.relu_to(&mut dst);
+        values.relu_inplace();
+        assert_eq!(values.reinterpret_cast(), dst.reinterpret_cast());
+        assert_eq!(values[..11].reinterpret_cast(), [
+            0x0000,
+            0x0000,
+            0x0000,
+            f16::from_f32(3.5).to_bits(),
+            0x0000,
+            0x7C00,
+            0x0000,
+            f16::MAX.to_bits(),
+            0x0000,
+            0x0001,
+            0x0000
+        ]);
+        // NaNs are unchanged, including their sign and payload.
+        assert_eq!(values[11..].reinterpret_cast(), src[11..].reinterpret_cast());
+
+        // Every bit pattern matches the scalar definition.
+        for high in 0..=0xFFu16 {
+            let mut bits = [0u16; 256];
+            for (low, b) in bits.iter_mut().enumerate() {
+                *b = (high << 8) | low as u16;
+            }
+            let mut f16_values = bits;
+            f16_values.reinterpret_cast_mut::<f16>().relu_inplace();
+            let mut bf16_values = [bf16::ZERO; 256];
+            bits.reinterpret_cast::<bf16>().relu_to(&mut bf16_values);
+            for (i, &b) in bits.iter().enumerate() {
+                let x = f16::from_bits(b);
+                let expected = if x.is_nan() || x > f16::ZERO {
+                    b
+                } else {
+                    0
+                };
+                assert_eq!(f16_values[i], expected);
+                let x = bf16::from_bits(b);
+                let expected = if x.is_nan() || x > bf16::ZERO {
+                    b
+                } else {
+                    0
+                };
+                assert_eq!(bf16_values[i].to_bits(), expected);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn canonicalize_inplace() {
+        let mut halves = [f16::ZERO; 1 << 16];
+        let mut bfloats = [bf16::ZERO; 1 << 16];
+        for (i, (h, b)) in halves.iter_mut().zip(bfloats.iter_mut()).enumerate() {
+            *h = f16::from_bits(i as u16);
+            *b = bf16::from_bits(i as u16);
+        }
+        let (mut zero_halves, mut zero_bfloats) = (halves, bfloats);
+        halves.canonicalize_inplace();
+        bfloats.canonicalize_inplace();
+        zero_halves.canonicalize_zero_inplace();
+        zero_bfloats.canonicalize_zero_inplace();
+        for i in 0..=u16::MAX {
+            let (h, b) = (f16::from_bits(i), bf16::from_bits(i));
+            let j = usize::from(i);
+            assert_eq!(halves[j].to_bits(), h.canonicalize().to_bits());
+            assert_eq!(bfloats[j].to_bits(), b.canonicalize().to_bits());
+            assert_eq!(zero_halves[j].to_bits(), h.canonicalize_zero().to_bits());
+            assert_eq!(zero_bfloats[j].to_bits(), b.canonicalize_zero().to_bits());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn relu_to_len_mismatch_panics() {
+        let src = [f16::ONE; 3];
+        let mut dst = [f16::ZERO; 2];
+        src.relu_to(&mut dst);
+    }
+
+    /// Transposes the matrix one element at a time.
+    fn naive_transpose(src: &[u16], rows: usize, cols: usize, dst: &mut [u16]) {
+        for row in 0..rows {
+            for col in 0..cols {
+                dst[col * rows + row] = src[row * cols + col];
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn transpose() {
+        const MAX: usize = 97 * 97;
+        let mut src = [0u16; MAX];
+        for (i, x) in src.iter_mut().enumerate() {
+            *x = i as u16;
+        }
+        let shapes = [
+            (0, 0),
+            (0, 5),
+            (5, 0),
+            (1, 1),
+            (1, 7),
+            (7, 1),
+            (2, 3),
+            (31, 37),
+            (32, 32),
+            (33, 65),
+            (64, 31),
+            (97, 13),
+            (97, 67),
+        ];
+        for &(rows, cols) in &shapes {
+            let len = rows * cols;
+            let src = &src[..len];
+            let mut expected = [0u16; MAX];
+            naive_transpose(src, rows, cols, &mut expected[..len]);
+
+            let mut dst = [f16::NAN; MAX];
+            src.reinterpret_cast::<f16>().transpose_to(rows, cols, &mut dst[..len]);
+            assert_eq!(dst[..len].reinterpret_cast(), &expected[..len], "{}x{}", rows, cols);
+            let mut dst = [bf16::NAN; MAX];
+            src.reinterpret_cast::<bf16>().transpose_to(rows, cols, &mut dst[..len]);
+            assert_eq!(dst[..len].reinterpret_cast(), &expected[..len], "{}x{}", rows, cols);
+
+            // Transposing twice is the original matrix.
+            let mut back = [0u16; MAX];
+            expected[..len].reinterpret_cast::<f16>().transpose_to(
+                cols,
+                rows,
+                back[..len].reinterpret_cast_mut(),
+            );
+            assert_eq!(&back[..len], src);
+        }
+
+        for &n in &[0, 1, 2, 3, 31, 32, 33, 64, 67, 97] {
+            let len = n * n;
+            let mut expected = [0u16; MAX];
+            naive_transpose(&src[..len], n, n, &mut expected[..len]);
+            let mut matrix = [0u16; MAX];
+            matrix[..len].copy_from_slice(&src[..len]);
+            matrix[..len].reinterpret_cast_mut::<f16>().transpose_square_inplace(n);
+            assert_eq!(&matrix[..len], &expected[..len], "{}", n);
+            matrix[..len].reinterpret_cast_mut::<bf16>().transpose_square_inplace(n);
+            assert_eq!(&matrix[..len], &src[..len], "{}", n);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn transpose_dimension_mismatch_panics() {
+        let src = [f16::ONE; 6];
+        let mut dst = [f16::ZERO; 6];
+        src.transpose_to(2, 4, &mut dst);
+    }
+
+    #[test]
+    #[should_panic]
+    fn transpose_overflow_panics() {
+        let src = [f16::ONE; 4];
+        let mut dst = [f16::ZERO; 4];
+        src.transpose_to(usize::MAX, 2, &mut dst);
+    }
+
+    #[test]
+    #[should_panic]
+    fn transpose_square_dimension_mismatch_panics() {
+        let mut matrix = [bf16::ONE; 6];
+        matrix.transpose_square_inplace(2);
+    }
+
+    #[test]
+    fn sort_total_order() {
+        let mut values = [f16::NAN, f16::ZERO, f16::ONE, f16::NEG_ZERO, -f16::NAN, f16::MIN];
+        values.sort_total_order_unstable();
+        let expected = [-f16::NAN, f16::MIN, f16::NEG_ZERO, f16::ZERO, f16::ONE, f16::NAN];
+        assert_eq!(values.reinterpret_cast(), expected.reinterpret_cast());
+        #[cfg(feature = "alloc")]
+        {
+            let mut values = [bf16::ZERO, bf16::NAN, bf16::NEG_ZERO, bf16::NEG_ONE, bf16::ZERO];
+            values.sort_total_order();
+            let expected = [bf16::NEG_ONE, bf16::NEG_ZERO, bf16::ZERO, bf16::ZERO, bf16::NAN];
+            assert_eq!(values.reinterpret_cast(), expected.reinterpret_cast());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn sort_total_order_all_values() {
+        // Every value, shuffled by an odd multiplier, against a reference.
+        let mut bits = [0u16; 1 << 16];
+        for (i, b) in bits.iter_mut().enumerate() {
+            *b = (i as u16).wrapping_mul(0x9E37);
+        }
+
+        let mut values = bits;
+        let mut expected = bits;
+        expected.reinterpret_cast_mut::<f16>().sort_unstable_by(f16::total_cmp);
+        values.reinterpret_cast_mut::<f16>().sort_total_order_unstable();
+        assert_eq!(values, expected);
+        #[cfg(feature = "alloc")]
+        {
+            values = bits;
+            values.reinterpret_cast_mut::<f16>().sort_total_order();
+            assert_eq!(values, expected);
+        }
+        // Positive NaN values are last, and the signs of zero are adjacent.
+        let sorted = values.reinterpret_cast::<f16>();
+        assert!(sorted[..0x3FF].iter().all(|x| x.is_nan() && x.is_sign_negative()));
+        assert!(sorted[0xFC01..].iter().all(|x| x.is_nan() && x.is_sign_positive()));
+        assert_eq!(sorted[0x7FFF..0x8001].reinterpret_cast(), [0x8000, 0x0000]);
+
+        let mut values = bits;
+        expected = bits;
+        expected.reinterpret_cast_mut::<bf16>().sort_unstable_by(bf16::total_cmp);
+        values.reinterpret_cast_mut::<bf16>().sort_total_order_unstable();
+        assert_eq!(values, expected);
+        #[cfg(feature = "alloc")]
+        {
+            values = bits;
+            values.reinterpret_cast_mut::<bf16>().sort_total_order();
+            assert_eq!(values, expected);
+        }
+        let sorted = values.reinterpret_cast::<bf16>();
+        assert!(sorted[0xFF81..].iter().all(|x| x.is_nan() && x.is_sign_positive()));
+        assert_eq!(sorted[0xFF80], bf16::INFINITY);
+    }
 }
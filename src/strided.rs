@@ -0,0 +1,800 @@
+//! Strided views of [`struct@f16`] and [`struct@bf16`] slices as
+//! N-dimensional arrays.
+//!
+//! Tensor libraries store an array as a flat buffer with a shape and, for each
+//! dimension, a stride: the distance in elements between consecutive indices
+//! of that dimension. Transposes and slices of an array then share its
+//! buffer, and only change the shape and strides. [`StridedView`] and
+//! [`StridedViewMut`] check the layout once, when they are constructed, so
+//! that every index within the shape is within the buffer, and indexing them
+//! needs no further checks of the layout.
+//!
+//! Strides are in elements and unsigned, and there is no broadcasting.
+
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+use crate::error::{StridedLayoutError, StridedLayoutErrorKind};
+use crate::private::SealedHalf;
+use crate::slice::transpose_tiles;
+
+/// Checks that every index of the layout is within `len` elements, and returns
+/// the number of elements.
+fn validate(len: usize, shape: &[usize], strides: &[usize]) -> Result<usize, StridedLayoutError> {
+    if shape.len() != strides.len() {
+        return Err(StridedLayoutError(StridedLayoutErrorKind::RankMismatch));
+    }
+    if shape.contains(&0) {
+        return Ok(0);
+    }
+
+    let overflow = StridedLayoutError(StridedLayoutErrorKind::Overflow);
+    let mut count = 1usize;
+    let mut last = 0usize;
+    for (&n, &stride) in shape.iter().zip(strides) {
+        count = count.checked_mul(n).ok_or(overflow)?;
+        let extent = (n - 1).checked_mul(stride).ok_or(overflow)?;
+        last = last.checked_add(extent).ok_or(overflow)?;
+    }
+    if last >= len {
+        return Err(StridedLayoutError(StridedLayoutErrorKind::OutOfBounds));
+    }
+    Ok(count)
+}
+
+/// Returns if the strides of a valid, non-empty layout are nested, so every
+/// index has a different offset.
+///
+/// Visiting the dimensions in increasing order of stride, each stride must be
+/// larger than the largest offset of the dimensions visited before it. This
+/// holds for any layout made by transposing or slicing a contiguous array, but
+/// rejects some interleaved layouts that do not overlap, which would take far
+/// longer to check exactly.
+fn is_nested(shape: &[usize], strides: &[usize]) -> bool {
+    // The largest offset of the dimensions visited so far, which cannot
+    // overflow since the layout is valid.
+    let mut reach = 0usize;
+    let mut prev: Option<(usize, usize)> = None;
+    loop {
+        // The next dimension in order of stride, and then of position, ignoring
+        // dimensions with a single index.
+        let mut next: Option<(usize, usize)> = None;
+        for (i, (&n, &stride)) in shape.iter().zip(strides).enumerate() {
+            let key = (stride, i);
+            if n > 1 && prev.map_or(true, |p| key > p) && next.map_or(true, |q| key < q) {
+                next = Some(key);
+            }
+        }
+        let (stride, i) = match next {
+            Some(next) => next,
+            None => return true,
+        };
+        if stride <= reach {
+            return false;
+        }
+        reach += (shape[i] - 1) * stride;
+        prev = next;
+    }
+}
+
+/// Returns the offset of the element at the position `flat` in row-major
+/// order, for a non-empty layout.
+#[inline]
+fn flat_offset(shape: &[usize], strides: &[usize], mut flat: usize) -> usize {
+    let mut offset = 0;
+    for (&n, &stride) in shape.iter().zip(strides).rev() {
+        offset += flat % n * stride;
+        flat /= n;
+    }
+    offset
+}
+
+/// Returns the offset of the element at `index`, or `None` if the index is
+/// outside the shape.
+#[inline]
+fn index_offset(shape: &[usize], strides: &[usize], index: &[usize]) -> Option<usize> {
+    if index.len() != shape.len() {
+        return None;
+    }
+    let mut offset = 0;
+    for ((&i, &n), &stride) in index.iter().zip(shape).zip(strides) {
+        if i >= n {
+            return None;
+        }
+        offset += i * stride;
+    }
+    Some(offset)
+}
+
+/// Copies the elements of a valid, non-empty layout to `dst` in row-major
+/// order.
+fn copy_strided<T: Copy>(data: &[T], shape: &[usize], strides: &[usize], dst: &mut [T]) {
+    let rank = shape.len();
+    if rank == 0 {
+        dst[0] = data[0];
+        return;
+    }
+
+    let (outer, cols) = (rank - 1, shape[rank - 1]);
+    if strides[outer] == 1 {
+        // Each row is contiguous, so it is copied at once.
+        for (row, out) in dst.chunks_exact_mut(cols).enumerate() {
+            let start = flat_offset(&shape[..outer], &strides[..outer], row);
+            out.copy_from_slice(&data[start..start + cols]);
+        }
+    } else if rank >= 2 && strides[rank - 2] == 1 && strides[outer] == shape[rank - 2] {
+        // Each matrix of the last two dimensions is contiguous in column-major
+        // order, so it is transposed one tile at a time.
+        let (outer, rows) = (rank - 2, shape[rank - 2]);
+        for (i, out) in dst.chunks_exact_mut(rows * cols).enumerate() {
+            let start = flat_offset(&shape[..outer], &strides[..outer], i);
+            transpose_tiles(&data[start..start + rows * cols], cols, rows, out);
+        }
+    } else {
+        for (flat, out) in dst.iter_mut().enumerate() {
+            *out = data[flat_offset(shape, strides, flat)];
+        }
+    }
+}
+
+/// A read-only N-dimensional view of a slice of [`struct@f16`] or
+/// [`struct@bf16`] values, with a shape and a stride for each dimension.
+///
+/// The element at the index `[i, j, ...]` is at the offset
+/// `i * strides[0] + j * strides[1] + ...` of the data. The layout is checked
+/// when the view is constructed, so every index within the shape is within
+/// the data, and the elements may be shared between indices, as they are
+/// with a stride of zero.
+///
+/// [`struct@f16`]: crate::f16
+/// [`struct@bf16`]: crate::bf16
+///
+/// # Examples
+///
+/// ```rust
+/// # use float16::*;
+/// let data = [1., 2., 3., 4., 5., 6.].map(f16::from_f32);
+///
+/// // The transpose of the row-major 2 by 3 matrix.
+/// let view = StridedView::new(&data, &[3, 2], &[1, 3]).unwrap();
+/// assert_eq!(view.get(&[2, 0]), Some(f16::from_f32(3.)));
+/// assert_eq!(view.get(&[2, 2]), None);
+///
+/// let mut transposed = [f16::ZERO; 6];
+/// view.copy_to_contiguous(&mut transposed);
+/// assert_eq!(transposed, [1., 4., 2., 5., 3., 6.].map(f16::from_f32));
+/// assert!(view.iter().eq(transposed));
+///
+/// let error = StridedView::new(&data, &[3, 3], &[1, 3]).unwrap_err();
+/// assert_eq!(error.kind(), StridedLayoutErrorKind::OutOfBounds);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct StridedView<'a, T> {
+    data: &'a [T],
+    shape: &'a [usize],
+    strides: &'a [usize],
+    len: usize,
+}
+
+impl<'a, T: SealedHalf + Copy> StridedView<'a, T> {
+    /// Constructs a view of `data` with the given shape and strides.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the shape and strides have different lengths, if
+    /// the number of elements or the offset of an element overflows, or if
+    /// the offset of the last element is past the end of `data`. A view with
+    /// a dimension of length zero has no elements, so any strides are valid.
+    #[inline]
+    pub fn new(
+        data: &'a [T],
+        shape: &'a [usize],
+        strides: &'a [usize],
+    ) -> Result<Self, StridedLayoutError> {
+        let len = validate(data.len(), shape, strides)?;
+        Ok(Self {
+            data,
+            shape,
+            strides,
+            len,
+        })
+    }
+
+    /// Returns the length of each dimension.
+    #[inline]
+    #[must_use]
+    pub fn shape(&self) -> &'a [usize] {
+        self.shape
+    }
+
+    /// Returns the stride of each dimension, in elements.
+    #[inline]
+    #[must_use]
+    pub fn strides(&self) -> &'a [usize] {
+        self.strides
+    }
+
+    /// Returns the number of elements, the product of the shape.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the view has no elements.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the element at `index`, or `None` if the index does not have
+    /// one coordinate for each dimension or is outside the shape.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, index: &[usize]) -> Option<T> {
+        index_offset(self.shape, self.strides, index).map(|offset| self.data[offset])
+    }
+
+    /// Returns an iterator over the elements in row-major order, with the
+    /// last dimension changing fastest.
+    #[inline]
+    pub fn iter(&self) -> StridedIter<'a, T> {
+        StridedIter {
+            view: *self,
+            front: 0,
+            back: self.len,
+        }
+    }
+
+    /// Copies the elements to `dst` in row-major order, as a contiguous
+    /// array of the same shape.
+    ///
+    /// If the last dimension is contiguous, each row is copied at once, and
+    /// if the last two dimensions are a contiguous column-major matrix, as
+    /// for a transpose, the matrix is copied in tiles that fit in the L1
+    /// cache, like [`transpose_to`][crate::HalfFloatSliceExt::transpose_to].
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the length of `dst` is not the number of
+    /// elements.
+    #[inline]
+    pub fn copy_to_contiguous(&self, dst: &mut [T]) {
+        assert_eq!(dst.len(), self.len, "destination length does not match the number of elements");
+        if self.len != 0 {
+            copy_strided(self.data, self.shape, self.strides, dst);
+        }
+    }
+}
+
+impl<'a, T: SealedHalf + Copy> IntoIterator for StridedView<'a, T> {
+    type Item = T;
+    type IntoIter = StridedIter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> StridedIter<'a, T> {
+        self.iter()
+    }
+}
+
+/// An iterator over the elements of a [`StridedView`] in row-major order.
+///
+/// This is returned by [`StridedView::iter`].
+#[derive(Clone, Debug)]
+pub struct StridedIter<'a, T> {
+    view: StridedView<'a, T>,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T: SealedHalf + Copy> Iterator for StridedIter<'a, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+        let offset = flat_offset(self.view.shape, self.view.strides, self.front);
+        self.front += 1;
+        Some(self.view.data[offset])
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: SealedHalf + Copy> DoubleEndedIterator for StridedIter<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.view.data[flat_offset(self.view.shape, self.view.strides, self.back)])
+    }
+}
+
+impl<'a, T: SealedHalf + Copy> ExactSizeIterator for StridedIter<'a, T> {
+}
+
+impl<'a, T: SealedHalf + Copy> FusedIterator for StridedIter<'a, T> {
+}
+
+/// A mutable N-dimensional view of a slice of [`struct@f16`] or
+/// [`struct@bf16`] values, with a shape and a stride for each dimension.
+///
+/// This is the same as a [`StridedView`], except that the layout must also
+/// map every index to a different element, so the elements can be mutated
+/// independently. This is checked when the view is constructed: visiting the
+/// dimensions in increasing order of stride, each stride must be larger than
+/// the largest offset of the dimensions before it. Every transpose or slice
+/// of a contiguous array passes this check.
+///
+/// [`struct@f16`]: crate::f16
+/// [`struct@bf16`]: crate::bf16
+///
+/// # Examples
+///
+/// ```rust
+/// # use float16::*;
+/// let mut data = [bf16::ZERO; 8];
+///
+/// // Every other element of the rows of a 2 by 4 matrix.
+/// let mut view = StridedViewMut::new(&mut data, &[2, 2], &[4, 2]).unwrap();
+/// view.copy_from_contiguous(&[1., 2., 3., 4.].map(bf16::from_f32));
+/// *view.get_mut(&[1, 1]).unwrap() = bf16::from_f32(-4.);
+/// assert_eq!(data, [1., 0., 2., 0., 3., 0., -4., 0.].map(bf16::from_f32));
+///
+/// let error = StridedViewMut::new(&mut data, &[2, 2], &[1, 1]).unwrap_err();
+/// assert_eq!(error.kind(), StridedLayoutErrorKind::Overlapping);
+/// ```
+#[derive(Debug)]
+pub struct StridedViewMut<'a, T> {
+    data: &'a mut [T],
+    shape: &'a [usize],
+    strides: &'a [usize],
+    len: usize,
+}
+
+impl<'a, T: SealedHalf + Copy> StridedViewMut<'a, T> {
+    /// Constructs a mutable view of `data` with the given shape and strides.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for the same layouts as [`StridedView::new`], or if
+    /// the strides are not nested, so two indices may refer to the same
+    /// element.
+    #[inline]
+    pub fn new(
+        data: &'a mut [T],
+        shape: &'a [usize],
+        strides: &'a [usize],
+    ) -> Result<Self, StridedLayoutError> {
+        let len = validate(data.len(), shape, strides)?;
+        if len != 0 && !is_nested(shape, strides) {
+            return Err(StridedLayoutError(StridedLayoutErrorKind::Overlapping));
+        }
+        Ok(Self {
+            data,
+            shape,
+            strides,
+            len,
+        })
+    }
+
+    /// Returns the length of each dimension.
+    #[inline]
+    #[must_use]
+    pub fn shape(&self) -> &'a [usize] {
+        self.shape
+    }
+
+    /// Returns the stride of each dimension, in elements.
+    #[inline]
+    #[must_use]
+    pub fn strides(&self) -> &'a [usize] {
+        self.strides
+    }
+
+    /// Returns the number of elements, the product of the shape.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the view has no elements.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a read-only view of the same elements.
+    #[inline]
+    #[must_use]
+    pub fn as_view(&self) -> StridedView<'_, T> {
+        StridedView {
+            data: self.data,
+            shape: self.shape,
+            strides: self.strides,
+            len: self.len,
+        }
+    }
+
+    /// Returns the element at `index`, or `None` if the index does not have
+    /// one coordinate for each dimension or is outside the shape.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, index: &[usize]) -> Option<T> {
+        self.as_view().get(index)
+    }
+
+    /// Returns a mutable reference to the element at `index`, or `None` if
+    /// the index does not have one coordinate for each dimension or is
+    /// outside the shape.
+    #[inline]
+    #[must_use]
+    pub fn get_mut(&mut self, index: &[usize]) -> Option<&mut T> {
+        index_offset(self.shape, self.strides, index).map(move |offset| &mut self.data[offset])
+    }
+
+    /// Returns an iterator over mutable references to the elements in
+    /// row-major order, with the last dimension changing fastest.
+    #[inline]
+    pub fn iter_mut(&mut self) -> StridedIterMut<'_, T> {
+        StridedIterMut {
+            data: self.data.as_mut_ptr(),
+            shape: self.shape,
+            strides: self.strides,
+            front: 0,
+            back: self.len,
+            marker: PhantomData,
+        }
+    }
+
+    /// Copies the elements from `src`, a contiguous array of the same shape
+    /// in row-major order.
+    ///
+    /// If the last dimension is contiguous, each row is copied at once.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the length of `src` is not the number of
+    /// elements.
+    #[inline]
+    pub fn copy_from_contiguous(&mut self, src: &[T]) {
+        assert_eq!(src.len(), self.len, "source length does not match the number of elements");
+        let rank = self.shape.len();
+        if self.len != 0 && rank != 0 && self.strides[rank - 1] == 1 {
+            let (outer, cols) = (rank - 1, self.shape[rank - 1]);
+            for (row, values) in src.chunks_exact(cols).enumerate() {
+                let start = flat_offset(&self.shape[..outer], &self.strides[..outer], row);
+                self.data[start..start + cols].copy_from_slice(values);
+            }
+        } else {
+            for (dst, &value) in self.iter_mut().zip(src) {
+                *dst = value;
+            }
+        }
+    }
+}
+
+/// An iterator over mutable references to the elements of a
+/// [`StridedViewMut`] in row-major order.
+///
+/// This is returned by [`StridedViewMut::iter_mut`].
+#[derive(Debug)]
+pub struct StridedIterMut<'a, T> {
+    data: *mut T,
+    shape: &'a [usize],
+    strides: &'a [usize],
+    front: usize,
+    back: usize,
+    marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> StridedIterMut<'a, T> {
+    /// Returns a reference to the element at the position `flat`.
+    ///
+    /// # Safety
+    ///
+    /// `flat` must be less than the number of elements, and must not be
+    /// returned more than once.
+    #[inline]
+    unsafe fn element(&mut self, flat: usize) -> &'a mut T {
+        let offset = flat_offset(self.shape, self.strides, flat);
+        // SAFETY: the layout was checked to be within the data, and to map
+        // every index to a different element, so this is the only reference
+        // to the element.
+        &mut *self.data.add(offset)
+    }
+}
+
+impl<'a, T> Iterator for StridedIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        // SAFETY: each position is returned once, from the front or back.
+        Some(unsafe { self.element(self.front - 1) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for StridedIterMut<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        // SAFETY: each position is returned once, from the front or back.
+        Some(unsafe { self.element(self.back) })
+    }
+}
+
+impl<'a, T> ExactSizeIterator for StridedIterMut<'a, T> {
+}
+
+impl<'a, T> FusedIterator for StridedIterMut<'a, T> {
+}
+
+// SAFETY: the iterator is a unique borrow of the elements, like `&mut [T]`.
+unsafe impl<'a, T: Send> Send for StridedIterMut<'a, T> {
+}
+
+// SAFETY: the iterator is a unique borrow of the elements, like `&mut [T]`.
+unsafe impl<'a, T: Sync> Sync for StridedIterMut<'a, T> {
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{bf16, f16};
+
+    /// The elements `0, 1, 2, ...` as bits, so values identify their offsets.
+    fn iota<const N: usize>() -> [f16; N] {
+        let mut data = [f16::ZERO; N];
+        for (i, x) in data.iter_mut().enumerate() {
+            *x = f16::from_bits(i as u16);
+        }
+        data
+    }
+
+    fn kind<T>(result: Result<T, StridedLayoutError>) -> Option<StridedLayoutErrorKind> {
+        result.err().map(|error| error.kind())
+    }
+
+    #[test]
+    fn test_view() {
+        let data = iota::<12>();
+        let view = StridedView::new(&data, &[3, 4], &[4, 1]).unwrap();
+        assert_eq!((view.len(), view.shape(), view.strides()), (12, &[3, 4][..], &[4, 1][..]));
+        assert!(view.iter().eq(data));
+        assert_eq!(view.get(&[2, 1]), Some(data[9]));
+        assert_eq!(view.get(&[3, 0]), None);
+        assert_eq!(view.get(&[0, 4]), None);
+        assert_eq!(view.get(&[1]), None);
+        assert_eq!(view.get(&[1, 1, 0]), None);
+
+        // A transposed slice of every other column, and its reverse.
+        let view = StridedView::new(&data, &[2, 3], &[2, 4]).unwrap();
+        let expected = [0, 4, 8, 2, 6, 10].map(f16::from_bits);
+        assert!(view.iter().eq(expected));
+        assert!(view.iter().rev().eq(expected.iter().rev().copied()));
+        assert_eq!(view.iter().len(), 6);
+        let mut out = [f16::ZERO; 6];
+        view.copy_to_contiguous(&mut out);
+        assert_eq!(out, expected);
+
+        // Strides of zero repeat elements, and rank 0 has one element.
+        let view = StridedView::new(&data[5..], &[2, 3], &[0, 1]).unwrap();
+        assert!(view.into_iter().eq([5, 6, 7, 5, 6, 7].map(f16::from_bits)));
+        let view = StridedView::new(&data[3..], &[], &[]).unwrap();
+        assert_eq!((view.len(), view.get(&[])), (1, Some(data[3])));
+        let mut out = [f16::ZERO];
+        view.copy_to_contiguous(&mut out);
+        assert_eq!(out, [data[3]]);
+
+        // Empty views accept any strides, even of empty data.
+        let view = StridedView::<bf16>::new(&[], &[3, 0, 2], &[usize::MAX, 7, 1]).unwrap();
+        assert!(view.is_empty());
+        assert_eq!(view.iter().next(), None);
+        view.copy_to_contiguous(&mut []);
+        assert_eq!(
+            kind(StridedView::<bf16>::new(&[], &[], &[])),
+            Some(StridedLayoutErrorKind::OutOfBounds)
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let data = iota::<12>();
+        let errors = [
+            (&[3, 4][..], &[4][..], StridedLayoutErrorKind::RankMismatch),
+            (&[3, 4], &[4, 1, 1], StridedLayoutErrorKind::RankMismatch),
+            (&[3, 5], &[4, 1], StridedLayoutErrorKind::OutOfBounds),
+            (&[13], &[1], StridedLayoutErrorKind::OutOfBounds),
+            (&[2], &[usize::MAX], StridedLayoutErrorKind::OutOfBounds),
+            (&[3], &[usize::MAX], StridedLayoutErrorKind::Overflow),
+            (&[2, 2], &[usize::MAX, 1], StridedLayoutErrorKind::Overflow),
+            (&[usize::MAX, 2], &[0, 0], StridedLayoutErrorKind::Overflow),
+        ];
+        for (shape, strides, expected) in errors {
+            assert_eq!(kind(StridedView::new(&data, shape, strides)), Some(expected));
+        }
+        let error = StridedView::new(&data, &[3], &[6]).unwrap_err();
+        assert_eq!(error.kind(), StridedLayoutErrorKind::OutOfBounds);
+
+        // Mutable views must not overlap.
+        let mut data = iota::<12>();
+        let overlapping = [
+            (&[2][..], &[0][..]),
+            (&[3, 4], &[1, 1]),
+            (&[2, 2, 2], &[4, 2, 3]),
+            // This does not overlap, but is interleaved.
+            (&[3, 2], &[2, 3]),
+        ];
+        for (shape, strides) in overlapping {
+            let view = StridedViewMut::new(&mut data, shape, strides);
+            assert_eq!(kind(view), Some(StridedLayoutErrorKind::Overlapping));
+            assert!(StridedView::new(&data, shape, strides).is_ok());
+        }
+        let nested = [(&[1, 12][..], &[0, 1][..]), (&[4, 3], &[1, 4]), (&[2, 3, 2], &[1, 4, 2])];
+        for (shape, strides) in nested {
+            assert!(StridedViewMut::new(&mut data, shape, strides).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_view_mut() {
+        let mut data = [bf16::ZERO; 12];
+        let mut view = StridedViewMut::new(&mut data, &[2, 3], &[1, 4]).unwrap();
+        assert_eq!((view.len(), view.shape(), view.strides()), (6, &[2, 3][..], &[1, 4][..]));
+        view.copy_from_contiguous(&[1., 2., 3., 4., 5., 6.].map(bf16::from_f32));
+        assert_eq!(view.get(&[1, 2]), Some(bf16::from_f32(6.)));
+        *view.get_mut(&[0, 1]).unwrap() = bf16::NEG_ONE;
+        assert!(view.get_mut(&[2, 0]).is_none());
+        for (i, x) in view.iter_mut().rev().enumerate() {
+            *x += bf16::from_f32(i as f32 * 0.5);
+        }
+        let mut out = [bf16::ZERO; 6];
+        view.as_view().copy_to_contiguous(&mut out);
+        assert_eq!(out, [3.5, 1., 4.5, 5., 5.5, 6.].map(bf16::from_f32));
+        let expected = [3.5, 5., 0., 0., 1., 5.5, 0., 0., 4.5, 6., 0., 0.];
+        assert_eq!(data, expected.map(bf16::from_f32));
+
+        // Contiguous rows are copied at once.
+        let mut data = iota::<12>();
+        let mut view = StridedViewMut::new(&mut data, &[2, 2], &[6, 1]).unwrap();
+        view.copy_from_contiguous(&[f16::ONE; 4]);
+        assert_eq!(data.iter().filter(|&&x| x == f16::ONE).count(), 4);
+        assert_eq!(data[6], f16::ONE);
+        assert_eq!(data[8], f16::from_bits(8));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_copy_paths() {
+        // Both fast paths, with tiles larger than the transpose tiles, against
+        // copying one element at a time.
+        let data = iota::<{ 3 * 40 * 50 }>();
+        let layouts = [
+            (&[3, 40, 50][..], &[2000, 50, 1][..]),
+            (&[3, 40, 50], &[2000, 1, 40]),
+            (&[40, 3, 50], &[1, 2000, 40]),
+            (&[50, 40], &[1, 50]),
+            (&[3, 20, 25], &[2000, 2, 80]),
+        ];
+        for (shape, strides) in layouts {
+            let view = StridedView::new(&data, shape, strides).unwrap();
+            let mut out = [f16::ZERO; 3 * 40 * 50];
+            let out = &mut out[..view.len()];
+            view.copy_to_contiguous(out);
+            assert!(view.iter().eq(out.iter().copied()), "{:?} {:?}", shape, strides);
+        }
+    }
+
+    #[test]
+    fn test_fuzz_layouts() {
+        // A linear congruential generator, so the layouts are reproducible.
+        let mut state = 0x2545_F491_4F6C_DD1Du64;
+        let mut next = |bound: usize| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 33) as usize % bound
+        };
+
+        let data = iota::<64>();
+        let iterations = if cfg!(miri) {
+            200
+        } else {
+            5000
+        };
+        for _ in 0..iterations {
+            let rank = next(5);
+            let mut shape = [0; 4];
+            let mut strides = [0; 4];
+            for i in 0..rank {
+                shape[i] = next(5);
+                strides[i] = next(13);
+            }
+            let (shape, strides) = (&shape[..rank], &strides[..rank]);
+            let len = next(65);
+
+            // The reference visits every index, counting how often each
+            // offset is reached, and panics past 64 elements.
+            let mut count = 1;
+            for &n in shape {
+                count *= n;
+            }
+            let mut reached = [0u32; 64];
+            let mut offsets = [0usize; 256];
+            let mut in_bounds = true;
+            for (flat, offset) in offsets.iter_mut().enumerate().take(count) {
+                let mut rest = flat;
+                *offset = 0;
+                for (&n, &stride) in shape.iter().zip(strides).rev() {
+                    *offset += rest % n * stride;
+                    rest /= n;
+                }
+                if *offset < len {
+                    reached[*offset] += 1;
+                } else {
+                    in_bounds = false;
+                }
+            }
+            let disjoint = reached.iter().all(|&r| r <= 1);
+
+            let data = &data[..len];
+            let view = match StridedView::new(data, shape, strides) {
+                Ok(view) => view,
+                Err(error) => {
+                    assert!(!in_bounds && count != 0, "{:?} {:?} {}", shape, strides, len);
+                    assert_eq!(error.kind(), StridedLayoutErrorKind::OutOfBounds);
+                    continue;
+                },
+            };
+            assert!(in_bounds || count == 0);
+            assert_eq!(view.len(), count);
+            assert!(view.iter().eq(offsets[..count].iter().map(|&o| data[o])));
+            let mut out = [f16::ZERO; 256];
+            view.copy_to_contiguous(&mut out[..count]);
+            assert!(view.iter().eq(out[..count].iter().copied()));
+
+            let mut copy = iota::<64>();
+            if let Ok(mut view) = StridedViewMut::new(&mut copy[..len], shape, strides) {
+                assert!(disjoint, "{:?} {:?}", shape, strides);
+                for x in view.iter_mut() {
+                    *x = -*x;
+                }
+                let mut negated = [f16::ZERO; 256];
+                for (dst, &x) in negated.iter_mut().zip(&out[..count]) {
+                    *dst = -x;
+                }
+                view.copy_from_contiguous(&negated[..count]);
+                assert!(view.as_view().iter().eq(negated[..count].iter().copied()));
+                // Elements outside the view are unchanged.
+                for (offset, &r) in reached.iter().enumerate().take(len) {
+                    if r == 0 {
+                        assert_eq!(copy[offset], data[offset]);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,304 @@
+//! One-time initialization of lookup tables, without the standard library.
+//!
+//! [`OnceTable`] is a minimal replacement for `std::sync::OnceLock` that only
+//! needs 8-bit atomics, so tables such as `f16_to_f32_table` can be built on
+//! first use and then shared as `&'static` references in `no_std`
+//! environments.
+
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::hint;
+use core::mem::MaybeUninit;
+use core::sync::atomic::{AtomicU8, Ordering};
+
+/// No thread has started to initialize the value.
+const UNINIT: u8 = 0;
+/// A thread is running the initializer.
+const RUNNING: u8 = 1;
+/// The value is initialized, and is never written again.
+const READY: u8 = 2;
+
+/// A cell which is initialized once, for values such as lookup tables that
+/// are shared between threads.
+///
+/// The first call to [`get_or_init`][Self::get_or_init] runs the initializer,
+/// and concurrent calls spin until it finishes, so the initializer is run
+/// once and every caller gets a reference to the same value. If the
+/// initializer panics, the cell is left uninitialized and the next call runs
+/// it again.
+///
+/// Spinning is only appropriate for initializers that are short compared to
+/// the time slice of a thread, such as building a table. Calling
+/// [`get_or_init`][Self::get_or_init] on the same cell from the initializer
+/// never returns.
+///
+/// # Examples
+///
+/// ```rust
+/// use float16::f16;
+/// use float16::sync::OnceTable;
+///
+/// static SQUARES: OnceTable<[f16; 256]> = OnceTable::new();
+///
+/// fn squares() -> &'static [f16; 256] {
+///     SQUARES.get_or_init(|| {
+///         let mut table = [f16::ZERO; 256];
+///         for (i, x) in table.iter_mut().enumerate() {
+///             *x = f16::from_f32((i * i) as f32);
+///         }
+///         table
+///     })
+/// }
+///
+/// assert!(SQUARES.get().is_none());
+/// assert_eq!(squares()[12], f16::from_f32(144.));
+/// assert!(SQUARES.get().is_some());
+/// ```
+pub struct OnceTable<T> {
+    state: AtomicU8,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+// SAFETY: The value is only written by the thread that moved the state from
+// `UNINIT` to `RUNNING`, and only read after the state is `READY`, which is
+// never changed again, so sharing the cell shares `&T` between threads, and
+// the value may be initialized on any of them.
+unsafe impl<T: Send + Sync> Sync for OnceTable<T> {
+}
+
+unsafe impl<T: Send> Send for OnceTable<T> {
+}
+
+impl<T> OnceTable<T> {
+    /// Creates a new, uninitialized cell.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            state: AtomicU8::new(UNINIT),
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+
+    /// Returns the value, or [`None`] if it is not initialized yet.
+    ///
+    /// This does not wait for an initializer that is running on another
+    /// thread.
+    #[inline]
+    #[must_use]
+    pub fn get(&self) -> Option<&T> {
+        if self.state.load(Ordering::Acquire) == READY {
+            // SAFETY: The value is initialized once the state is `READY`.
+            Some(unsafe { (*self.value.get()).assume_init_ref() })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value, initializing it with `f` if it is not initialized
+    /// yet.
+    ///
+    /// If another thread is running its initializer, this spins until it
+    /// finishes, and `f` is not called.
+    #[inline]
+    pub fn get_or_init<F: FnOnce() -> T>(&self, f: F) -> &T {
+        match self.get() {
+            Some(value) => value,
+            None => self.initialize(f),
+        }
+    }
+
+    #[cold]
+    fn initialize<F: FnOnce() -> T>(&self, f: F) -> &T {
+        loop {
+            match self.state.compare_exchange_weak(
+                UNINIT,
+                RUNNING,
+                Ordering::Acquire,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => {
+                    // Resets the state if `f` panics, so another caller can
+                    // run its initializer.
+                    let guard = ResetOnDrop(&self.state);
+                    let value = f();
+                    // SAFETY: Only this thread moved the state to `RUNNING`,
+                    // and no reference to the value exists until it is
+                    // `READY`.
+                    unsafe { (*self.value.get()).write(value) };
+                    core::mem::forget(guard);
+                    self.state.store(READY, Ordering::Release);
+                    // SAFETY: The value was just initialized.
+                    return unsafe { (*self.value.get()).assume_init_ref() };
+                },
+                Err(RUNNING) => {
+                    while self.state.load(Ordering::Acquire) == RUNNING {
+                        hint::spin_loop();
+                    }
+                },
+                // A spurious failure, or the initializer of another thread
+                // finished or panicked.
+                Err(_) => {},
+            }
+            if let Some(value) = self.get() {
+                return value;
+            }
+        }
+    }
+}
+
+impl<T> Default for OnceTable<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for OnceTable<T> {
+    fn drop(&mut self) {
+        if *self.state.get_mut() == READY {
+            // SAFETY: The value is initialized once the state is `READY`.
+            unsafe { self.value.get_mut().assume_init_drop() };
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for OnceTable<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.get() {
+            Some(value) => f.debug_tuple("OnceTable").field(value).finish(),
+            None => f.write_str("OnceTable(<uninit>)"),
+        }
+    }
+}
+
+/// Moves the state back to `UNINIT` when dropped, unless forgotten.
+struct ResetOnDrop<'a>(&'a AtomicU8);
+
+impl Drop for ResetOnDrop<'_> {
+    fn drop(&mut self) {
+        self.0.store(UNINIT, Ordering::Release);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_state_machine() {
+        let cell = OnceTable::new();
+        assert!(cell.get().is_none());
+        assert_eq!(cell.state.load(Ordering::Relaxed), UNINIT);
+
+        // The value is not visible while the initializer runs.
+        let value = cell.get_or_init(|| {
+            assert_eq!(cell.state.load(Ordering::Relaxed), RUNNING);
+            assert!(cell.get().is_none());
+            7u32
+        });
+        assert_eq!(*value, 7);
+        assert_eq!(cell.state.load(Ordering::Relaxed), READY);
+
+        // Later initializers are not called.
+        assert_eq!(*cell.get_or_init(|| unreachable!()), 7);
+        assert_eq!(cell.get(), Some(&7));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_panicking_initializer() {
+        use std::panic::{self, AssertUnwindSafe};
+
+        let cell = OnceTable::new();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| cell.get_or_init(|| panic!("init"))));
+        assert!(result.is_err());
+        assert_eq!(cell.state.load(Ordering::Relaxed), UNINIT);
+        assert!(cell.get().is_none());
+        assert_eq!(*cell.get_or_init(|| 3u8), 3);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_drop() {
+        use std::rc::Rc;
+
+        let counter = Rc::new(());
+        let cell = OnceTable::new();
+        cell.get_or_init(|| Rc::clone(&counter));
+        assert_eq!(Rc::strong_count(&counter), 2);
+        drop(cell);
+        assert_eq!(Rc::strong_count(&counter), 1);
+
+        // An uninitialized cell has nothing to drop.
+        drop(OnceTable::<Rc<()>>::new());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(miri, ignore)]
+    fn test_concurrent_first_access() {
+        use std::sync::atomic::AtomicUsize;
+        use std::sync::{Arc, Barrier};
+        use std::thread;
+
+        const THREADS: usize = 16;
+
+        let cell = Arc::new(OnceTable::new());
+        let calls = Arc::new(AtomicUsize::new(0));
+        let barrier = Arc::new(Barrier::new(THREADS));
+        let handles: Vec<_> = (0..THREADS)
+            .map(|t| {
+                let (cell, calls, barrier) =
+                    (Arc::clone(&cell), Arc::clone(&calls), Arc::clone(&barrier));
+                thread::spawn(move || {
+                    barrier.wait();
+                    let value = cell.get_or_init(|| {
+                        calls.fetch_add(1, Ordering::Relaxed);
+                        t
+                    });
+                    (*value, value as *const usize as usize)
+                })
+            })
+            .collect();
+        let results: Vec<_> = handles.into_iter().map(|handle| handle.join().unwrap()).collect();
+
+        // Every thread gets the value of the only initializer that ran.
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+        assert!(results.iter().all(|&result| result == results[0]));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[cfg_attr(miri, ignore)]
+    fn test_waits_for_running_initializer() {
+        use std::sync::{mpsc, Arc};
+        use std::thread;
+
+        // Interleaves the threads by hand: the first initializer blocks until
+        // the cell is seen running, and the second thread has been started.
+        let cell = Arc::new(OnceTable::new());
+        let (started, wait_started) = mpsc::channel();
+        let (release, wait_release) = mpsc::channel::<()>();
+        let first = {
+            let cell = Arc::clone(&cell);
+            thread::spawn(move || {
+                *cell.get_or_init(|| {
+                    started.send(()).unwrap();
+                    wait_release.recv().unwrap();
+                    1u32
+                })
+            })
+        };
+        wait_started.recv().unwrap();
+        assert_eq!(cell.state.load(Ordering::Acquire), RUNNING);
+        assert!(cell.get().is_none());
+        let second = {
+            let cell = Arc::clone(&cell);
+            thread::spawn(move || *cell.get_or_init(|| 2u32))
+        };
+        release.send(()).unwrap();
+        assert_eq!(first.join().unwrap(), 1);
+        assert_eq!(second.join().unwrap(), 1);
+    }
+}
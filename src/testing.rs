@@ -0,0 +1,300 @@
+//! Generators of [`struct@f16`] test inputs, for testing code that consumes
+//! half-precision values.
+//!
+//! Sampling bit patterns uniformly, or stepping through them, spends most of
+//! the inputs on the larger exponents and can miss the values where bugs
+//! hide. [`stratified_values`] instead draws the same number of values from
+//! every sign and exponent, including zeros, subnormals, infinities and NaN
+//! payloads, [`fill_stratified`] does the same for a slice without
+//! allocating, and [`boundary_pairs`] returns the neighbors at every binade
+//! edge.
+//!
+//! This module requires the `test-support` feature.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use float16::f16;
+//! use float16::testing::{boundary_pairs, fill_stratified, stratified_values};
+//!
+//! for x in stratified_values(4, 0x5EED) {
+//!     assert_eq!(x.to_f32().is_nan(), x.is_nan());
+//! }
+//! let mut values = [f16::ZERO; 68];
+//! fill_stratified(&mut values, 0x5EED);
+//! assert!(values.iter().any(|x| x.is_subnormal()));
+//! for (x, next) in boundary_pairs() {
+//!     assert!(x < next);
+//! }
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::f16;
+
+/// A deterministic generator of pseudo-random [`u64`] values, with the
+/// SplitMix64 algorithm.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns random bits for the 10-bit mantissa.
+    #[inline]
+    fn next_mantissa(&mut self) -> u16 {
+        (self.next_u64() >> 54) as u16
+    }
+}
+
+/// The number of strata for each sign: zero, the subnormal values, the 30
+/// normal exponents, infinity and NaN.
+const STRATA: usize = 34;
+
+/// Returns a value from `stratum` of the values with the sign bit `sign`,
+/// drawing the mantissa from `rng` for the strata which have several values.
+/// NaN values are quiet if `quiet` is set, and signaling otherwise.
+#[inline]
+fn stratum_value(rng: &mut SplitMix64, sign: u16, stratum: usize, quiet: bool) -> f16 {
+    let bits = match stratum {
+        0 => 0,
+        1 => rng.next_mantissa().max(1),
+        32 => 0x7C00,
+        33 => {
+            let payload = rng.next_mantissa() & 0x1FF;
+            0x7C00
+                | if quiet {
+                    0x200 | payload
+                } else {
+                    payload.max(1)
+                }
+        },
+        exponent => ((exponent as u16 - 1) << 10) | rng.next_mantissa(),
+    };
+    f16::from_bits(sign | bits)
+}
+
+/// Returns a deterministic mix of [`struct@f16`] values, with the same number
+/// of values from every sign and exponent.
+///
+/// For each sign, in turn, there is one stratum for zero, the subnormal
+/// values, each of the 30 normal exponents, infinity and NaN. The zero and
+/// infinity strata hold their single value, and each other stratum holds
+/// `per_stratum` values with pseudo-random mantissas, so there are `64 *
+/// per_stratum + 4` values in total, or none if `per_stratum` is 0. The NaN
+/// strata alternate between quiet and signaling NaNs, starting with quiet,
+/// with pseudo-random payloads.
+///
+/// The values only depend on `per_stratum` and `seed`, so a failing input can
+/// be reproduced on any target.
+///
+/// # Examples
+///
+/// ```rust
+/// # use float16::*;
+/// use float16::testing::stratified_values;
+///
+/// let values = stratified_values(2, 42);
+/// assert_eq!(values.len(), 64 * 2 + 4);
+/// assert!(values.iter().any(|x| x.is_subnormal()));
+/// assert!(values.iter().any(|x| x.is_nan_signaling()));
+/// // NaNs are not equal to themselves, so compare the bits.
+/// let bits = |values: Vec<f16>| values.iter().map(|x| x.to_bits()).collect::<Vec<_>>();
+/// assert_eq!(bits(values), bits(stratified_values(2, 42)));
+/// ```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn stratified_values(per_stratum: usize, seed: u64) -> Vec<f16> {
+    if per_stratum == 0 {
+        return Vec::new();
+    }
+    let mut rng = SplitMix64(seed);
+    let mut values = Vec::with_capacity(64 * per_stratum + 4);
+    for sign in [0, 0x8000] {
+        for stratum in 0..STRATA {
+            let count = match stratum {
+                0 | 32 => 1,
+                _ => per_stratum,
+            };
+            for i in 0..count {
+                values.push(stratum_value(&mut rng, sign, stratum, i % 2 == 0));
+            }
+        }
+    }
+    values
+}
+
+/// Fills a slice with a deterministic mix of [`struct@f16`] values, from
+/// every sign and exponent in turn, without allocating.
+///
+/// The strata are the same as those of [`stratified_values`], for the
+/// positive values and then the negative values, and each element is drawn
+/// from the next stratum, so any 68 consecutive elements hold one value from
+/// each. The NaN strata alternate between quiet and signaling NaNs, starting
+/// with quiet, on each pass through the strata.
+///
+/// The values only depend on the length of `values` and `seed`, so a failing
+/// input can be reproduced on any target, including those without an
+/// allocator.
+///
+/// # Examples
+///
+/// ```rust
+/// # use float16::*;
+/// use float16::testing::fill_stratified;
+///
+/// let mut values = [f16::ZERO; 136];
+/// fill_stratified(&mut values, 42);
+/// assert_eq!(values[0].to_bits(), 0);
+/// assert!(values[1].is_subnormal());
+/// assert_eq!(values[32], f16::INFINITY);
+/// assert!(values[33].is_nan_quiet() && values[67].is_nan_quiet());
+/// assert!(values[101].is_nan_signaling() && values[135].is_nan_signaling());
+/// ```
+pub fn fill_stratified(values: &mut [f16], seed: u64) {
+    let mut rng = SplitMix64(seed);
+    for (i, x) in values.iter_mut().enumerate() {
+        let stratum = i % (2 * STRATA);
+        let sign = if stratum < STRATA {
+            0
+        } else {
+            0x8000
+        };
+        let quiet = i / (2 * STRATA) % 2 == 0;
+        *x = stratum_value(&mut rng, sign, stratum % STRATA, quiet);
+    }
+}
+
+/// Returns every pair of adjacent [`struct@f16`] values on either side of a
+/// binade edge, in increasing order.
+///
+/// Each pair is a value and the next larger value, where the exponent of the
+/// magnitude changes: between zero and the smallest subnormal, between the
+/// subnormal and normal values, between each pair of normal exponents, and
+/// between the largest finite value and infinity, for both signs. There are
+/// 64 pairs, from `(f16::NEG_INFINITY, f16::MIN)` to
+/// `(f16::MAX, f16::INFINITY)`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use float16::*;
+/// use float16::testing::boundary_pairs;
+///
+/// let pairs: Vec<_> = boundary_pairs().collect();
+/// assert_eq!(pairs.len(), 64);
+/// assert!(pairs.contains(&(f16::MAX_SUBNORMAL, f16::MIN_POSITIVE)));
+/// assert_eq!(pairs[63], (f16::MAX, f16::INFINITY));
+/// ```
+pub fn boundary_pairs() -> impl Iterator<Item = (f16, f16)> {
+    // The smallest magnitude of each binade, from infinity down to the
+    // smallest subnormal.
+    let negative = (0..32u16).rev().map(|exponent| {
+        let edge = 0x8000 | (exponent << 10).max(1);
+        (f16::from_bits(edge), f16::from_bits(edge - 1))
+    });
+    let positive = (0..32u16).map(|exponent| {
+        let edge = (exponent << 10).max(1);
+        (f16::from_bits(edge - 1), f16::from_bits(edge))
+    });
+    negative.chain(positive)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_stratified_values_coverage() {
+        for per_stratum in [1, 2, 5] {
+            let values = stratified_values(per_stratum, 7);
+            assert_eq!(values.len(), 64 * per_stratum + 4);
+            for sign in [false, true] {
+                let signed = || values.iter().filter(move |x| x.is_sign_negative() == sign);
+                // Every exponent has `per_stratum` values, and the exponents
+                // of zero and infinity also hold them.
+                for exponent in 0..32 {
+                    let count = signed().filter(|x| (x.to_bits() >> 10) & 0x1F == exponent).count();
+                    let extra = if exponent == 0 || exponent == 31 {
+                        1
+                    } else {
+                        0
+                    };
+                    assert_eq!(count, per_stratum + extra, "{} {}", sign, exponent);
+                }
+                assert_eq!(signed().filter(|x| x.to_bits() & 0x7FFF == 0).count(), 1);
+                assert_eq!(signed().filter(|x| x.is_infinite()).count(), 1);
+                assert_eq!(signed().filter(|x| x.is_subnormal()).count(), per_stratum);
+                assert_eq!(signed().filter(|x| x.is_nan_quiet()).count(), (per_stratum + 1) / 2);
+                assert_eq!(signed().filter(|x| x.is_nan_signaling()).count(), per_stratum / 2);
+            }
+        }
+        assert!(stratified_values(0, 7).is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_stratified_values_deterministic() {
+        let bits =
+            |seed| stratified_values(8, seed).iter().map(|x| x.to_bits()).collect::<Vec<_>>();
+        assert_eq!(bits(1), bits(1));
+        assert_ne!(bits(1), bits(2));
+        // The strata are in order, starting with +0.
+        assert_eq!(stratified_values(1, 1)[0].to_bits(), 0);
+        assert_eq!(stratified_values(1, 1)[34].to_bits(), 0x8000);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_boundary_pairs() {
+        let pairs: Vec<_> = boundary_pairs().collect();
+        assert_eq!(pairs.len(), 64);
+        assert_eq!(pairs[0], (f16::NEG_INFINITY, f16::MIN));
+        assert_eq!(pairs[31].0, -f16::MIN_POSITIVE_SUBNORMAL);
+        assert_eq!(pairs[31].1.to_bits(), f16::NEG_ZERO.to_bits());
+        assert_eq!(pairs[32].0.to_bits(), f16::ZERO.to_bits());
+        assert_eq!(pairs[32].1, f16::MIN_POSITIVE_SUBNORMAL);
+        for (i, &(x, next)) in pairs.iter().enumerate() {
+            assert_eq!(x.ulp_distance(next), Some(1), "{:?} {:?}", x, next);
+            assert!(x.total_cmp(&next).is_lt());
+            if i > 0 {
+                assert!(pairs[i - 1].1.total_cmp(&x).is_lt());
+            }
+        }
+    }
+
+    #[test]
+    fn test_fill_stratified() {
+        let mut values = [f16::ZERO; 3 * 68 + 5];
+        fill_stratified(&mut values, 7);
+        for (pass, chunk) in values.chunks(68).enumerate() {
+            for (i, x) in chunk.iter().enumerate() {
+                let (sign, stratum) = (i >= 34, i % 34);
+                let exponent = (x.to_bits() >> 10) & 0x1F;
+                assert_eq!(x.is_sign_negative(), sign, "{}", i);
+                match stratum {
+                    0 => assert_eq!(x.to_bits() & 0x7FFF, 0),
+                    1 => assert!(x.is_subnormal()),
+                    32 => assert!(x.is_infinite()),
+                    33 => assert_eq!(x.is_nan_quiet(), pass % 2 == 0),
+                    _ => assert_eq!(exponent as usize, stratum - 1),
+                }
+            }
+        }
+
+        // The values only depend on the length and the seed.
+        let mut again = [f16::ZERO; 3 * 68 + 5];
+        fill_stratified(&mut again, 7);
+        assert!(values.iter().zip(&again).all(|(x, y)| x.to_bits() == y.to_bits()));
+        fill_stratified(&mut again, 8);
+        assert!(values.iter().zip(&again).any(|(x, y)| x.to_bits() != y.to_bits()));
+    }
+}
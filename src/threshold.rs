@@ -0,0 +1,155 @@
+//! Comparisons of half-precision slices against an [`f32`] threshold without
+//! converting each element.
+//!
+//! Every [`struct@f16`] and [`struct@bf16`] value maps to an integer that
+//! follows the [`total_cmp`][crate::f16::total_cmp] order, so comparing
+//! against a threshold is a range check on that integer once the threshold
+//! has been converted to the first value of each type that passes it.
+
+use crate::{bf16, f16};
+
+/// An [`f32`] threshold compiled for fast comparisons against slices of
+/// [`struct@f16`] and [`struct@bf16`] values.
+///
+/// The threshold does not need to be representable in either type. It
+/// stores, for each type, the smallest value that is above the threshold, so
+/// each element is compared by its bits with the same result as widening it
+/// to [`f32`] and comparing against the threshold. NaN elements are never
+/// above a threshold, and nothing is above a NaN threshold.
+///
+/// This is used by [`HalfFloatSliceExt::count_above`] and
+/// [`HalfFloatSliceExt::mask_above`].
+///
+/// [`HalfFloatSliceExt::count_above`]: crate::HalfFloatSliceExt::count_above
+/// [`HalfFloatSliceExt::mask_above`]: crate::HalfFloatSliceExt::mask_above
+///
+/// # Examples
+///
+/// ```rust
+/// # use float16::*;
+/// let values = [f16::from_f32(0.1), f16::from_f32(0.5), f16::from_f32(0.9)];
+/// assert_eq!(values.count_above(&CompiledThreshold::new(0.5)), 1);
+/// assert_eq!(values.count_above(&CompiledThreshold::new_inclusive(0.5)), 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CompiledThreshold {
+    threshold: f32,
+    inclusive: bool,
+    f16_min: u16,
+    bf16_min: u16,
+}
+
+/// Ordered bits of [`struct@f16`] positive infinity.
+const F16_INFINITY: u16 = f16::INFINITY.to_ordered_bits();
+/// Ordered bits of [`struct@bf16`] positive infinity.
+const BF16_INFINITY: u16 = bf16::INFINITY.to_ordered_bits();
+
+impl CompiledThreshold {
+    /// Compiles a threshold for values strictly greater than `threshold`.
+    #[must_use]
+    pub fn new(threshold: f32) -> CompiledThreshold {
+        Self::compile(threshold, false)
+    }
+
+    /// Compiles a threshold for values greater than or equal to `threshold`.
+    #[must_use]
+    pub fn new_inclusive(threshold: f32) -> CompiledThreshold {
+        Self::compile(threshold, true)
+    }
+
+    /// Returns the [`f32`] threshold.
+    #[inline]
+    #[must_use]
+    pub const fn threshold(&self) -> f32 {
+        self.threshold
+    }
+
+    /// Returns if values equal to the threshold are above it.
+    #[inline]
+    #[must_use]
+    pub const fn is_inclusive(&self) -> bool {
+        self.inclusive
+    }
+
+    /// Returns if a [`struct@f16`] value is above the threshold.
+    #[inline]
+    #[must_use]
+    pub const fn is_f16_above(&self, value: f16) -> bool {
+        let key = value.to_ordered_bits();
+        key >= self.f16_min && key <= F16_INFINITY
+    }
+
+    /// Returns if a [`struct@bf16`] value is above the threshold.
+    #[inline]
+    #[must_use]
+    pub const fn is_bf16_above(&self, value: bf16) -> bool {
+        let key = value.to_ordered_bits();
+        key >= self.bf16_min && key <= BF16_INFINITY
+    }
+
+    fn compile(threshold: f32, inclusive: bool) -> CompiledThreshold {
+        if threshold.is_nan() {
+            return CompiledThreshold {
+                threshold,
+                inclusive,
+                f16_min: F16_INFINITY + 1,
+                bf16_min: BF16_INFINITY + 1,
+            };
+        }
+        let passes = |x: f32| {
+            if inclusive {
+                x >= threshold
+            } else {
+                x > threshold
+            }
+        };
+        CompiledThreshold {
+            threshold,
+            inclusive,
+            f16_min: first_above(f16::from_f32(threshold).to_ordered_bits(), F16_INFINITY, |key| {
+                passes(f16_from_ordered_bits(key).to_f32())
+            }),
+            bf16_min: first_above(
+                bf16::from_f32(threshold).to_ordered_bits(),
+                BF16_INFINITY,
+                |key| passes(bf16_from_ordered_bits(key).to_f32()),
+            ),
+        }
+    }
+}
+
+/// Finds the smallest ordered key in `..=infinity` that passes, starting
+/// from the key of the threshold rounded to the nearest value.
+///
+/// Rounding is monotonic, so the answer is within a couple of steps of the
+/// rounded threshold: the rounded value itself may or may not pass, and
+/// `-0` and `+0` are separate keys for the same value. Returns `infinity + 1`
+/// if nothing passes, which is never reached by finite keys.
+fn first_above<F: Fn(u16) -> bool>(start: u16, infinity: u16, passes: F) -> u16 {
+    let mut key = start.clamp(!infinity, infinity);
+    while key <= infinity && !passes(key) {
+        key += 1;
+    }
+    while key > !infinity && key <= infinity && passes(key - 1) {
+        key -= 1;
+    }
+    key
+}
+
+#[inline]
+const fn f16_from_ordered_bits(key: u16) -> f16 {
+    if key & 0x8000 != 0 {
+        f16::from_bits(key & 0x7FFF)
+    } else {
+        f16::from_bits(!key)
+    }
+}
+
+#[inline]
+const fn bf16_from_ordered_bits(key: u16) -> bf16 {
+    if key & 0x8000 != 0 {
+        bf16::from_bits(key & 0x7FFF)
+    } else {
+        bf16::from_bits(!key)
+    }
+}
@@ -0,0 +1,199 @@
+//! Implementations of the [`zeroize`](https://docs.rs/zeroize/) traits for
+//! [`struct@f16`] and [`struct@bf16`].
+//!
+//! Both types are [`DefaultIsZeroes`], since their default value, `+0.0`, has
+//! all bits zero, so [`Zeroize`] is implemented for the values and for slices,
+//! arrays and vectors of them. The wipe uses the volatile writes of
+//! [`zeroize`](https://docs.rs/zeroize/), which the compiler cannot elide.
+//!
+//! With the `alloc` feature, [`SecretHalfBuffer`] owns a vector of values and
+//! zeroes it when it is dropped.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use float16::*;
+//! use zeroize::Zeroize;
+//!
+//! let mut embedding = [f16::from_f32(0.25), f16::from_f32(-1.5)];
+//! embedding.zeroize();
+//! assert_eq!(embedding.map(f16::to_bits), [0, 0]);
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::{fmt, ops};
+
+use zeroize::DefaultIsZeroes;
+#[cfg(feature = "alloc")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{bf16, f16};
+
+impl DefaultIsZeroes for f16 {
+}
+
+impl DefaultIsZeroes for bf16 {
+}
+
+/// A vector of [`struct@f16`] or [`struct@bf16`] values that is zeroed when
+/// it is dropped.
+///
+/// The buffer dereferences to a slice of the values, and its [`Debug`]
+/// output does not show them. The whole capacity of the vector is zeroed, so
+/// values left behind by earlier, longer contents are also wiped. Values are
+/// only wiped if they stay in the buffer: growing it past its capacity moves
+/// them to a new allocation and leaves the old one untouched, so reserve the
+/// capacity up front.
+///
+/// [`Debug`]: core::fmt::Debug
+///
+/// # Examples
+///
+/// ```rust
+/// # use float16::*;
+/// use zeroize::Zeroize;
+///
+/// let mut buffer = SecretHalfBuffer::new(vec![bf16::ONE, bf16::from_f32(0.5)]);
+/// buffer[1] = bf16::from_f32(2.0);
+/// assert_eq!(buffer.iter().map(|x| x.to_f32()).sum::<f32>(), 3.0);
+///
+/// buffer.zeroize();
+/// assert!(buffer.is_empty());
+/// ```
+#[cfg(feature = "alloc")]
+pub struct SecretHalfBuffer<T: DefaultIsZeroes> {
+    values: Vec<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T: DefaultIsZeroes> SecretHalfBuffer<T> {
+    /// Takes ownership of the values, which are zeroed when the buffer is
+    /// dropped.
+    #[inline]
+    #[must_use]
+    pub fn new(values: Vec<T>) -> Self {
+        Self {
+            values,
+        }
+    }
+
+    /// Returns the values as a slice.
+    #[inline]
+    #[must_use]
+    pub fn as_slice(&self) -> &[T] {
+        &self.values
+    }
+
+    /// Returns the values as a mutable slice.
+    #[inline]
+    #[must_use]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.values
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: DefaultIsZeroes> From<Vec<T>> for SecretHalfBuffer<T> {
+    #[inline]
+    fn from(values: Vec<T>) -> Self {
+        Self::new(values)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: DefaultIsZeroes> ops::Deref for SecretHalfBuffer<T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        &self.values
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: DefaultIsZeroes> ops::DerefMut for SecretHalfBuffer<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.values
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: DefaultIsZeroes> Zeroize for SecretHalfBuffer<T> {
+    /// Zeroes the whole capacity of the vector and then clears it.
+    #[inline]
+    fn zeroize(&mut self) {
+        self.values.zeroize();
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: DefaultIsZeroes> Drop for SecretHalfBuffer<T> {
+    #[inline]
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: DefaultIsZeroes> ZeroizeOnDrop for SecretHalfBuffer<T> {
+}
+
+#[cfg(feature = "alloc")]
+impl<T: DefaultIsZeroes> fmt::Debug for SecretHalfBuffer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecretHalfBuffer").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use zeroize::Zeroize;
+
+    use super::*;
+
+    #[test]
+    fn test_zeroize_values() {
+        let mut x = f16::from_f32(-1.5);
+        x.zeroize();
+        assert_eq!(x.to_bits(), 0);
+        let mut x = bf16::NAN;
+        x.zeroize();
+        assert_eq!(x.to_bits(), 0);
+
+        let mut values = [f16::MAX, f16::NEG_INFINITY, f16::NEG_ZERO, f16::NAN];
+        values.zeroize();
+        assert_eq!(values.map(f16::to_bits), [0; 4]);
+        let mut values = [bf16::MIN, bf16::INFINITY, bf16::NEG_ZERO, bf16::EPSILON];
+        values[1..].zeroize();
+        assert_eq!(values.map(bf16::to_bits), [bf16::MIN.to_bits(), 0, 0, 0]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_secret_buffer() {
+        let mut buffer = SecretHalfBuffer::new(alloc::vec![f16::ONE; 16]);
+        assert_eq!(buffer.len(), 16);
+        buffer.as_mut_slice()[3] = f16::from_f32(0.1);
+        assert_eq!(buffer[3], f16::from_f32(0.1));
+        buffer.zeroize();
+        assert!(buffer.is_empty());
+        drop(buffer);
+
+        // The values of a vector are zeroed before it is cleared.
+        let mut values = alloc::vec![bf16::from_f32(0.75); 8];
+        let ptr = values.as_ptr();
+        values.zeroize();
+        assert!(values.is_empty());
+        // SAFETY: the allocation is still owned by `values` and was fully
+        // initialized, so reading it as bits is valid.
+        let bits = unsafe { core::slice::from_raw_parts(ptr as *const u16, 8) };
+        assert!(bits.iter().all(|&b| b == 0));
+
+        let buffer: SecretHalfBuffer<bf16> = alloc::vec![bf16::MAX, bf16::MIN].into();
+        assert_eq!(buffer.as_slice(), [bf16::MAX, bf16::MIN]);
+        drop(buffer);
+    }
+}
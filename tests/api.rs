@@ -0,0 +1,561 @@
+//! Checks that every item in `tests/api.txt` exists with the listed type.
+//!
+//! This file is generated by `devel/generate_api_test.py`. Do not edit it:
+//! change `tests/api.txt` and run the script instead.
+
+#![allow(unused_imports, unexpected_cfgs, clippy::type_complexity)]
+
+use float16::*;
+
+/// The FNV-1a hash of `tests/api.txt` when this file was generated, ignoring
+/// carriage returns.
+const SNAPSHOT_HASH: u64 = 0x3af3a12d810af1a4;
+
+#[test]
+fn snapshot_is_current() {
+    let mut hash = 0xcbf2_9ce4_8422_2325u64;
+    for &byte in include_bytes!("api.txt").iter().filter(|&&b| b != b'\r') {
+        hash = (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3);
+    }
+    assert_eq!(
+        hash, SNAPSHOT_HASH,
+        "`tests/api.txt` changed, run `python3 devel/generate_api_test.py`"
+    );
+}
+
+#[test]
+#[rustfmt::skip]
+fn items_exist() {
+    let _: fn(u16) -> f16 = f16::from_bits;
+    let _: fn(f32) -> f16 = f16::from_f32;
+    let _: fn(f32) -> f16 = f16::from_f32_const;
+    let _: fn(f32, RoundingMode) -> f16 = f16::from_f32_round;
+    let _: fn(f32, u16) -> f16 = f16::from_f32_stochastic;
+    let _: fn(f32) -> f16 = f16::from_f32_instrinsic;
+    let _: unsafe fn(f32) -> f16 = f16::from_f32_unchecked_normal;
+    let _: fn(f32) -> Option<f16> = f16::from_f32_lossless;
+    let _: fn(f32) -> f16 = f16::from_f32_saturating;
+    let _: fn(f32) -> f16 = f16::saturating_from_f32;
+    let _: fn(f64) -> f16 = f16::saturating_from_f64;
+    let _: fn(f64) -> (f16, bool) = f16::from_json_number;
+    let _: fn(f32) -> f16 = f16::from_f32_ftz;
+    let _: fn(f64) -> f16 = f16::from_f64;
+    let _: fn(f64) -> f16 = f16::from_f64_const;
+    let _: fn(f64, RoundingMode) -> f16 = f16::from_f64_round;
+    let _: fn(f64) -> f16 = f16::from_f64_instrinsic;
+    let _: fn() -> bool = f16::hardware_f16_available;
+    let _: fn(f64) -> Option<f16> = f16::from_f64_lossless;
+    let _: fn(bf16) -> f16 = f16::from_bf16_lossy;
+    let _: fn(bf16) -> Option<f16> = f16::from_bf16_lossless;
+    let _: fn(f64) -> f16 = f16::from_f64_saturating;
+    let _: fn(i64, u32) -> f16 = f16::from_fixed_point;
+    let _: fn(f16, u32) -> Option<i64> = f16::to_fixed_point;
+    let _: fn(i32) -> f16 = f16::from_i32;
+    let _: fn(i32, RoundingMode) -> f16 = f16::from_i32_with_mode;
+    let _: fn(i32) -> Option<f16> = f16::from_i32_exact;
+    let _: fn(bool) -> f16 = f16::from_bool;
+    let _: fn(f16) -> Option<i32> = f16::to_i32_trunc;
+    let _: fn(u8) -> f16 = f16::from_u8_norm;
+    let _: fn(f16) -> u8 = f16::to_u8_norm;
+    let _: fn(f16) -> u16 = f16::to_bits;
+    let _: fn(bool, u8, u16) -> f16 = f16::from_parts;
+    let _: fn(bool, u8, u16) -> Option<f16> = f16::from_parts_checked;
+    let _: fn(f16) -> (bool, u8, u16) = f16::to_parts;
+    let _: fn(f16) -> u32 = f16::count_ones;
+    let _: fn(f16) -> u32 = f16::count_zeros;
+    let _: fn(f16) -> u32 = f16::leading_zeros_mantissa;
+    let _: fn(f16) -> u32 = f16::trailing_zeros_mantissa;
+    let _: fn() -> core::ops::RangeInclusive<i32> = f16::exponent_range;
+    let _: fn() -> i32 = f16::subnormal_exponent;
+    let _: fn(f16) -> Option<i32> = f16::decode_exponent;
+    let _: fn(&str) -> Result<f16, ParseHalfError> = f16::from_hex_str;
+    #[cfg(not(target_arch = "spirv"))]
+    let _: fn(f16) -> HexFloat = f16::to_hex;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(f16) -> std::string::String = f16::to_hex_string;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(f16) -> std::string::String = f16::to_numpy_repr_string;
+    let _: fn(f16) -> [u8; 2] = f16::to_le_bytes;
+    let _: fn(f16) -> [u8; 2] = f16::to_be_bytes;
+    let _: fn(f16) -> [u8; 2] = f16::to_ne_bytes;
+    let _: fn([u8; 2]) -> f16 = f16::from_le_bytes;
+    let _: fn([u8; 2]) -> f16 = f16::from_be_bytes;
+    let _: fn([u8; 2]) -> f16 = f16::from_ne_bytes;
+    #[cfg(feature = "alloc")]
+    let _: fn(&[u8]) -> Result<std::borrow::Cow<'_, [f16]>, OddLength> = f16::cow_from_le_bytes;
+    #[cfg(feature = "alloc")]
+    let _: fn(&[u8]) -> Result<std::borrow::Cow<'_, [f16]>, OddLength> = f16::cow_from_be_bytes;
+    let _: fn(f16) -> f32 = f16::to_f32;
+    let _: fn(f16) -> f32 = f16::to_f32_const;
+    let _: fn(f16) -> f32 = f16::to_f32_intrinsic;
+    let _: fn(f16) -> f32 = f16::as_f32;
+    let _: fn(f16) -> f32 = f16::as_f32_const;
+    let _: fn(f16) -> f64 = f16::to_f64;
+    let _: fn(f16) -> f64 = f16::to_f64_const;
+    let _: fn(f16) -> f64 = f16::to_f64_intrinsic;
+    let _: fn(f16) -> f64 = f16::as_f64;
+    let _: fn(f16) -> f64 = f16::as_f64_const;
+    let _: fn(f16) -> bf16 = f16::to_bf16;
+    let _: fn(f16) -> bool = f16::is_nan;
+    let _: fn(f16) -> bool = f16::is_nan_signaling;
+    let _: fn(f16) -> bool = f16::is_nan_quiet;
+    let _: fn(f16) -> f16 = f16::quiet;
+    let _: fn(u16) -> Option<f16> = f16::nan_with_payload;
+    let _: fn(u16) -> Option<f16> = f16::signaling_nan_with_payload;
+    let _: fn(f16) -> Option<u16> = f16::nan_payload;
+    let _: fn(f16) -> f16 = f16::canonicalize;
+    let _: fn(f16) -> f16 = f16::canonicalize_zero;
+    let _: fn(f16) -> f16 = f16::abs;
+    let _: fn(f16) -> bool = f16::is_infinite;
+    let _: fn(f16) -> bool = f16::is_finite;
+    let _: fn(f16) -> bool = f16::is_subnormal;
+    let _: fn(f16) -> bool = f16::is_normal;
+    let _: fn(f16) -> bool = f16::is_zero;
+    let _: fn(f16) -> bool = f16::is_integer;
+    let _: fn(f16) -> bool = f16::is_odd_integer;
+    let _: fn(f16) -> bool = f16::is_even_integer;
+    let _: fn(f16) -> u16 = f16::biased_exponent;
+    let _: fn(f16) -> i16 = f16::unbiased_exponent;
+    let _: fn(f16) -> u16 = f16::mantissa;
+    let _: fn(f16, i32) -> f16 = f16::scale;
+    let _: fn(f16) -> f16 = f16::ftz;
+    let _: fn(f16) -> core::num::FpCategory = f16::classify;
+    let _: fn(f16) -> f16 = f16::signum;
+    let _: fn(f16) -> bool = f16::is_sign_positive;
+    let _: fn(f16) -> bool = f16::is_sign_negative;
+    let _: fn(f16, f16) -> f16 = f16::copysign;
+    let _: fn(f16) -> f16 = f16::recip;
+    let _: fn(&[f16]) -> f16 = f16::sum_slice;
+    let _: fn(&[f16]) -> f16 = f16::product_slice;
+    let _: fn(f16) -> f16 = f16::recip_estimate;
+    let _: fn(f16) -> f16 = f16::recip_refined;
+    let _: fn(f16, f16) -> f16 = f16::ieee_rem;
+    let _: fn(f16, f16) -> Option<f16> = f16::checked_add;
+    let _: fn(f16, f16) -> Option<f16> = f16::checked_sub;
+    let _: fn(f16, f16) -> Option<f16> = f16::checked_mul;
+    let _: fn(f16, f16) -> Option<f16> = f16::checked_div;
+    let _: fn(f16, f16) -> Option<f16> = f16::checked_rem;
+    let _: fn(f16) -> Option<f16> = f16::checked_neg;
+    let _: fn(f16) -> f16 = f16::to_degrees;
+    let _: fn(f16) -> f16 = f16::to_radians;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(f16) -> f16 = f16::rsqrt;
+    let _: fn(f16, f16, f16) -> f16 = f16::lerp;
+    let _: fn(f16, f16, f16) -> f16 = f16::lerp_const;
+    let _: fn(i64) -> bool = f16::can_represent_integer;
+    let _: fn(f16) -> Option<f16> = f16::increment_integer;
+    let _: fn(f16) -> f16 = f16::round_ties_away;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(f16) -> f16 = f16::exp;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(f16) -> f16 = f16::exp2;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(f16) -> f16 = f16::ln;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(f16) -> f16 = f16::log2;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(f16) -> f16 = f16::log10;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(f16) -> f16 = f16::exp_m1;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(f16) -> f16 = f16::ln_1p;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(f16) -> f16 = f16::sin;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(f16) -> f16 = f16::cos;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(f16) -> f16 = f16::tan;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(f16) -> f16 = f16::asin;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(f16) -> f16 = f16::acos;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(f16) -> f16 = f16::atan;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(f16, f16) -> f16 = f16::atan2;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(f16, f16) -> f16 = f16::hypot;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(f16) -> (f16, f16) = f16::sin_cos;
+    let _: fn(f16, f16) -> (f16, f16) = f16::two_sum;
+    let _: fn(f16, f16) -> (f16, f16) = f16::two_prod;
+    let _: fn(f16, f16, f32) -> f32 = f16::fma_f32_accum;
+    let _: fn(f16, f16) -> f16 = f16::max;
+    let _: fn(f16, f16) -> f16 = f16::min;
+    let _: fn(f16, f16, f16) -> f16 = f16::max3;
+    let _: fn(f16, f16, f16) -> f16 = f16::min3;
+    let _: fn(f16, f16, f16) -> f16 = f16::clamp;
+    let _: fn(&f16, &f16) -> core::cmp::Ordering = f16::total_cmp;
+    let _: fn(f16) -> u16 = f16::to_ordered_bits;
+    let _: fn(f16, f16) -> Option<u16> = f16::ulp_distance;
+    let _: fn(f16, f16) -> f16 = f16::bisect;
+    let _: fn(fn(f16) -> f16, f16, f16) -> Option<f16> = f16::find_root;
+    let _: fn(f16, f16) -> f16 = f16::abs_diff;
+    let _: fn(f16, f16) -> u16 = f16::abs_diff_ulp;
+    let _: fn(f16, f16, u16) -> bool = f16::ulps_eq;
+    let _: fn(f16, f16, f16, u16) -> bool = f16::approx_eq;
+    let _: fn() -> f16 = f16::min_normal;
+    let _: fn() -> f16 = f16::max_normal;
+    let _: fn() -> f16 = f16::min_positive_subnormal;
+    let _: u32 = f16::DIGITS;
+    let _: f16 = f16::EPSILON;
+    let _: f16 = f16::INFINITY;
+    let _: u32 = f16::MANTISSA_DIGITS;
+    let _: f16 = f16::MAX;
+    let _: i32 = f16::MAX_10_EXP;
+    let _: i32 = f16::MAX_EXP;
+    let _: f16 = f16::MIN;
+    let _: i32 = f16::MIN_10_EXP;
+    let _: i32 = f16::MIN_EXP;
+    let _: f16 = f16::MIN_POSITIVE;
+    let _: f16 = f16::NAN;
+    let _: f16 = f16::SIGNALING_NAN;
+    let _: f16 = f16::NEG_INFINITY;
+    let _: u32 = f16::RADIX;
+    let _: f16 = f16::MIN_POSITIVE_SUBNORMAL;
+    let _: f16 = f16::MAX_SUBNORMAL;
+    let _: f16 = f16::MAX_CONSECUTIVE_INTEGER;
+    let _: f16 = f16::ONE;
+    let _: f16 = f16::ZERO;
+    let _: f16 = f16::NEG_ZERO;
+    let _: f16 = f16::NEG_ONE;
+    let _: f16 = f16::E;
+    let _: f16 = f16::PI;
+    let _: f16 = f16::FRAC_1_PI;
+    let _: f16 = f16::FRAC_1_SQRT_2;
+    let _: f16 = f16::FRAC_2_PI;
+    let _: f16 = f16::FRAC_2_SQRT_PI;
+    let _: f16 = f16::FRAC_PI_2;
+    let _: f16 = f16::FRAC_PI_3;
+    let _: f16 = f16::FRAC_PI_4;
+    let _: f16 = f16::FRAC_PI_6;
+    let _: f16 = f16::FRAC_PI_8;
+    let _: f16 = f16::LN_10;
+    let _: f16 = f16::LN_2;
+    let _: f16 = f16::LOG10_E;
+    let _: f16 = f16::LOG10_2;
+    let _: f16 = f16::LOG2_E;
+    let _: f16 = f16::LOG2_10;
+    let _: f16 = f16::SQRT_2;
+    let _: u16 = f16::SIGN_MASK;
+    let _: u16 = f16::EXP_MASK;
+    let _: u16 = f16::HIDDEN_BIT_MASK;
+    let _: u16 = f16::MAN_MASK;
+    let _: u16 = f16::TINY_BITS;
+    let _: u16 = f16::NEG_TINY_BITS;
+    let _: fn(u16) -> bf16 = bf16::from_bits;
+    let _: fn(f32) -> bf16 = bf16::from_f32;
+    let _: fn(f32) -> bf16 = bf16::from_f32_const;
+    let _: fn(f32, RoundingMode) -> bf16 = bf16::from_f32_round;
+    let _: fn(f32, u16) -> bf16 = bf16::from_f32_stochastic;
+    let _: fn(f32) -> bf16 = bf16::from_f32_truncate;
+    let _: unsafe fn(f32) -> bf16 = bf16::from_f32_unchecked_normal;
+    let _: fn(f32) -> Option<bf16> = bf16::from_f32_lossless;
+    let _: fn(f32) -> bf16 = bf16::from_f32_saturating;
+    let _: fn(f32) -> bf16 = bf16::saturating_from_f32;
+    let _: fn(f64) -> bf16 = bf16::saturating_from_f64;
+    let _: fn(f64) -> (bf16, bool) = bf16::from_json_number;
+    let _: fn(f64) -> bf16 = bf16::from_f64;
+    let _: fn() -> bool = bf16::hardware_bf16_available;
+    let _: fn(f64) -> bf16 = bf16::from_f64_const;
+    let _: fn(f64) -> Option<bf16> = bf16::from_f64_lossless;
+    let _: fn(f16) -> bf16 = bf16::from_f16_lossy;
+    let _: fn(f16) -> Option<bf16> = bf16::from_f16_lossless;
+    let _: fn(i64, u32) -> bf16 = bf16::from_fixed_point;
+    let _: fn(bf16, u32) -> Option<i64> = bf16::to_fixed_point;
+    let _: fn(u8) -> bf16 = bf16::from_u8_norm;
+    let _: fn(bf16) -> u8 = bf16::to_u8_norm;
+    let _: fn(bf16) -> u16 = bf16::to_bits;
+    let _: fn(bool, u8, u8) -> bf16 = bf16::from_parts;
+    let _: fn(bool, u8, u8) -> Option<bf16> = bf16::from_parts_checked;
+    let _: fn(bf16) -> (bool, u8, u8) = bf16::to_parts;
+    let _: fn(bf16) -> u32 = bf16::count_ones;
+    let _: fn(bf16) -> u32 = bf16::count_zeros;
+    let _: fn(bf16) -> u32 = bf16::leading_zeros_mantissa;
+    let _: fn(bf16) -> u32 = bf16::trailing_zeros_mantissa;
+    let _: fn() -> core::ops::RangeInclusive<i32> = bf16::exponent_range;
+    let _: fn() -> i32 = bf16::subnormal_exponent;
+    let _: fn(bf16) -> Option<i32> = bf16::decode_exponent;
+    let _: fn(&str) -> Result<bf16, ParseHalfError> = bf16::from_hex_str;
+    #[cfg(not(target_arch = "spirv"))]
+    let _: fn(bf16) -> HexFloat = bf16::to_hex;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(bf16) -> std::string::String = bf16::to_hex_string;
+    let _: fn(bf16) -> [u8; 2] = bf16::to_le_bytes;
+    let _: fn(bf16) -> [u8; 2] = bf16::to_be_bytes;
+    let _: fn(bf16) -> [u8; 2] = bf16::to_ne_bytes;
+    let _: fn([u8; 2]) -> bf16 = bf16::from_le_bytes;
+    let _: fn([u8; 2]) -> bf16 = bf16::from_be_bytes;
+    let _: fn([u8; 2]) -> bf16 = bf16::from_ne_bytes;
+    #[cfg(feature = "alloc")]
+    let _: fn(&[u8]) -> Result<std::borrow::Cow<'_, [bf16]>, OddLength> = bf16::cow_from_le_bytes;
+    #[cfg(feature = "alloc")]
+    let _: fn(&[u8]) -> Result<std::borrow::Cow<'_, [bf16]>, OddLength> = bf16::cow_from_be_bytes;
+    let _: fn(bf16) -> f32 = bf16::to_f32;
+    let _: fn(bf16) -> f32 = bf16::to_f32_const;
+    let _: fn(bf16) -> f32 = bf16::as_f32;
+    let _: fn(bf16) -> f32 = bf16::as_f32_const;
+    let _: fn(bf16) -> f64 = bf16::to_f64;
+    let _: fn(bf16) -> f64 = bf16::to_f64_const;
+    let _: fn(bf16) -> f64 = bf16::as_f64;
+    let _: fn(bf16) -> f64 = bf16::as_f64_const;
+    let _: fn(bf16) -> f16 = bf16::to_f16;
+    let _: fn(bf16) -> bool = bf16::is_nan;
+    let _: fn(bf16) -> bool = bf16::is_nan_signaling;
+    let _: fn(bf16) -> bool = bf16::is_nan_quiet;
+    let _: fn(bf16) -> bf16 = bf16::quiet;
+    let _: fn(bf16) -> bf16 = bf16::canonicalize;
+    let _: fn(bf16) -> bf16 = bf16::canonicalize_zero;
+    let _: fn(bf16) -> bf16 = bf16::abs;
+    let _: fn(bf16) -> bool = bf16::is_infinite;
+    let _: fn(bf16) -> bool = bf16::is_finite;
+    let _: fn(bf16) -> bool = bf16::is_subnormal;
+    let _: fn(bf16) -> bool = bf16::is_normal;
+    let _: fn(bf16) -> bool = bf16::is_zero;
+    let _: fn(bf16) -> bool = bf16::is_integer;
+    let _: fn(bf16) -> bool = bf16::is_odd_integer;
+    let _: fn(bf16) -> bool = bf16::is_even_integer;
+    let _: fn(bf16) -> u16 = bf16::biased_exponent;
+    let _: fn(bf16) -> i16 = bf16::unbiased_exponent;
+    let _: fn(bf16) -> u16 = bf16::mantissa;
+    let _: fn(bf16, i32) -> bf16 = bf16::scale;
+    let _: fn(bf16) -> core::num::FpCategory = bf16::classify;
+    let _: fn(bf16) -> bf16 = bf16::signum;
+    let _: fn(bf16) -> bool = bf16::is_sign_positive;
+    let _: fn(bf16) -> bool = bf16::is_sign_negative;
+    let _: fn(bf16, bf16) -> bf16 = bf16::copysign;
+    let _: fn(bf16) -> bf16 = bf16::recip;
+    let _: fn(&[bf16]) -> bf16 = bf16::sum_slice;
+    let _: fn(&[bf16]) -> bf16 = bf16::product_slice;
+    let _: fn(bf16, bf16) -> Option<bf16> = bf16::checked_add;
+    let _: fn(bf16, bf16) -> Option<bf16> = bf16::checked_sub;
+    let _: fn(bf16, bf16) -> Option<bf16> = bf16::checked_mul;
+    let _: fn(bf16, bf16) -> Option<bf16> = bf16::checked_div;
+    let _: fn(bf16, bf16) -> Option<bf16> = bf16::checked_rem;
+    let _: fn(bf16) -> Option<bf16> = bf16::checked_neg;
+    let _: fn(bf16) -> bf16 = bf16::to_degrees;
+    let _: fn(bf16) -> bf16 = bf16::to_radians;
+    let _: fn(bf16, bf16, bf16) -> bf16 = bf16::lerp;
+    let _: fn(bf16, bf16, bf16) -> bf16 = bf16::lerp_const;
+    let _: fn(i64) -> bool = bf16::can_represent_integer;
+    let _: fn(bf16) -> Option<bf16> = bf16::increment_integer;
+    let _: fn(bf16) -> bf16 = bf16::round_ties_away;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(bf16) -> bf16 = bf16::exp;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(bf16) -> bf16 = bf16::exp2;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(bf16) -> bf16 = bf16::ln;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(bf16) -> bf16 = bf16::log2;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(bf16) -> bf16 = bf16::log10;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(bf16) -> bf16 = bf16::exp_m1;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(bf16) -> bf16 = bf16::ln_1p;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(bf16) -> bf16 = bf16::sin;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(bf16) -> bf16 = bf16::cos;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(bf16) -> bf16 = bf16::tan;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(bf16) -> bf16 = bf16::asin;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(bf16) -> bf16 = bf16::acos;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(bf16) -> bf16 = bf16::atan;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(bf16, bf16) -> bf16 = bf16::atan2;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(bf16, bf16) -> bf16 = bf16::hypot;
+    #[cfg(all(feature = "std", not(target_arch = "spirv")))]
+    let _: fn(bf16) -> (bf16, bf16) = bf16::sin_cos;
+    let _: fn(bf16, bf16) -> bf16 = bf16::max;
+    let _: fn(bf16, bf16) -> bf16 = bf16::min;
+    let _: fn(bf16, bf16, bf16) -> bf16 = bf16::max3;
+    let _: fn(bf16, bf16, bf16) -> bf16 = bf16::min3;
+    let _: fn(bf16, bf16, bf16) -> bf16 = bf16::clamp;
+    let _: fn(&bf16, &bf16) -> core::cmp::Ordering = bf16::total_cmp;
+    let _: fn(bf16) -> u16 = bf16::to_ordered_bits;
+    let _: fn(bf16, bf16) -> Option<u16> = bf16::ulp_distance;
+    let _: fn(bf16, bf16) -> bf16 = bf16::bisect;
+    let _: fn(fn(bf16) -> bf16, bf16, bf16) -> Option<bf16> = bf16::find_root;
+    let _: fn(bf16, bf16) -> bf16 = bf16::abs_diff;
+    let _: fn(bf16, bf16) -> u16 = bf16::abs_diff_ulp;
+    let _: fn(bf16, bf16, u16) -> bool = bf16::ulps_eq;
+    let _: fn(bf16, bf16, bf16, u16) -> bool = bf16::approx_eq;
+    let _: fn() -> bf16 = bf16::min_normal;
+    let _: fn() -> bf16 = bf16::max_normal;
+    let _: fn() -> bf16 = bf16::min_positive_subnormal;
+    let _: u32 = bf16::DIGITS;
+    let _: bf16 = bf16::EPSILON;
+    let _: bf16 = bf16::INFINITY;
+    let _: u32 = bf16::MANTISSA_DIGITS;
+    let _: bf16 = bf16::MAX;
+    let _: i32 = bf16::MAX_10_EXP;
+    let _: i32 = bf16::MAX_EXP;
+    let _: bf16 = bf16::MIN;
+    let _: i32 = bf16::MIN_10_EXP;
+    let _: i32 = bf16::MIN_EXP;
+    let _: bf16 = bf16::MIN_POSITIVE;
+    let _: bf16 = bf16::NAN;
+    let _: bf16 = bf16::SIGNALING_NAN;
+    let _: bf16 = bf16::NEG_INFINITY;
+    let _: u32 = bf16::RADIX;
+    let _: bf16 = bf16::MIN_POSITIVE_SUBNORMAL;
+    let _: bf16 = bf16::MAX_SUBNORMAL;
+    let _: bf16 = bf16::MAX_CONSECUTIVE_INTEGER;
+    let _: bf16 = bf16::ONE;
+    let _: bf16 = bf16::ZERO;
+    let _: bf16 = bf16::NEG_ZERO;
+    let _: bf16 = bf16::NEG_ONE;
+    let _: bf16 = bf16::E;
+    let _: bf16 = bf16::PI;
+    let _: bf16 = bf16::FRAC_1_PI;
+    let _: bf16 = bf16::FRAC_1_SQRT_2;
+    let _: bf16 = bf16::FRAC_2_PI;
+    let _: bf16 = bf16::FRAC_2_SQRT_PI;
+    let _: bf16 = bf16::FRAC_PI_2;
+    let _: bf16 = bf16::FRAC_PI_3;
+    let _: bf16 = bf16::FRAC_PI_4;
+    let _: bf16 = bf16::FRAC_PI_6;
+    let _: bf16 = bf16::FRAC_PI_8;
+    let _: bf16 = bf16::LN_10;
+    let _: bf16 = bf16::LN_2;
+    let _: bf16 = bf16::LOG10_E;
+    let _: bf16 = bf16::LOG10_2;
+    let _: bf16 = bf16::LOG2_E;
+    let _: bf16 = bf16::LOG2_10;
+    let _: bf16 = bf16::SQRT_2;
+    let _: u16 = bf16::SIGN_MASK;
+    let _: u16 = bf16::EXP_MASK;
+    let _: u16 = bf16::HIDDEN_BIT_MASK;
+    let _: u16 = bf16::MAN_MASK;
+    let _: u16 = bf16::TINY_BITS;
+    let _: u16 = bf16::NEG_TINY_BITS;
+    let _: fn(i64) -> Option<bf16> = bf16::from_i64_exact;
+    let _: fn(bool) -> bf16 = bf16::from_bool;
+    let _: fn(&[f16]) -> &[u16] = <[f16] as HalfFloatSliceExt>::reinterpret_cast;
+    let _: fn(&mut [f16]) -> &mut [u16] = <[f16] as HalfFloatSliceExt>::reinterpret_cast_mut;
+    let _: fn(&mut [f16], &[f32]) = <[f16] as HalfFloatSliceExt>::convert_from_f32_slice;
+    let _: fn(&mut [f16], &[f64]) = <[f16] as HalfFloatSliceExt>::convert_from_f64_slice;
+    let _: unsafe fn(&mut [f16], &[f32]) = <[f16] as HalfFloatSliceExt>::convert_from_f32_slice_unchecked_normal;
+    let _: fn(&[f16], &mut [f32]) = <[f16] as HalfFloatSliceExt>::convert_to_f32_slice;
+    let _: fn(&[f16], &mut [f64]) = <[f16] as HalfFloatSliceExt>::convert_to_f64_slice;
+    let _: fn(&[f16], &mut [f16]) = <[f16] as HalfFloatSliceExt>::convert_to_f16_slice;
+    let _: fn(&[f16], &mut [bf16]) = <[f16] as HalfFloatSliceExt>::convert_to_bf16_slice;
+    let _: fn(&mut [f16], &[i64], u32) = <[f16] as HalfFloatSliceExt>::convert_from_fixed_point_slice;
+    let _: fn(&mut [f16], &[u8]) = <[f16] as HalfFloatSliceExt>::convert_from_u8_norm_slice;
+    let _: fn(&[f16], &mut [i64], u32) -> Result<(), TryFromFloatError> = <[f16] as HalfFloatSliceExt>::convert_to_fixed_point_slice;
+    let _: fn(&[f16], &CompiledThreshold) -> usize = <[f16] as HalfFloatSliceExt>::count_above;
+    let _: fn(&[f16], &CompiledThreshold, &mut [bool]) = <[f16] as HalfFloatSliceExt>::mask_above;
+    #[cfg(feature = "std")]
+    let _: fn(&mut [f16]) = <[f16] as HalfFloatSliceExt>::softmax_inplace;
+    let _: fn(&[f16]) -> f32 = <[f16] as HalfFloatSliceExt>::sum_pairwise_f32;
+    let _: fn(&[f16], &[f16]) -> f32 = <[f16] as HalfFloatSliceExt>::dot_pairwise;
+    let _: fn(&[f16], &[f16], &mut [f32]) = <[f16] as HalfFloatSliceExt>::fma_accumulate;
+    let _: fn(&[f16], &[f16], &mut [f16]) = <[f16] as HalfFloatSliceExt>::zip_add;
+    let _: fn(&[f16], &[f16], &mut [f16]) = <[f16] as HalfFloatSliceExt>::zip_sub;
+    let _: fn(&[f16], &[f16], &mut [f16]) = <[f16] as HalfFloatSliceExt>::zip_mul;
+    let _: fn(&[f16], &[f16], &mut [f16]) = <[f16] as HalfFloatSliceExt>::zip_div;
+    let _: fn(&[f16], &[f16], &mut [f16]) = <[f16] as HalfFloatSliceExt>::zip_rem;
+    let _: fn(&[f16], &[f16], &mut [f16]) = <[f16] as HalfFloatSliceExt>::zip_fma;
+    let _: fn(&[f16]) -> f32 = <[f16] as HalfFloatSliceExt>::pairwise_sum_f32;
+    let _: fn(&[f16]) -> f32 = <[f16] as HalfFloatSliceExt>::kahan_sum_f32;
+    #[cfg(feature = "std")]
+    let _: fn(&[f16]) -> f32 = <[f16] as HalfFloatSliceExt>::l2_norm;
+    let _: fn(&[f16]) -> f32 = <[f16] as HalfFloatSliceExt>::l1_norm;
+    let _: fn(&[f16]) -> f16 = <[f16] as HalfFloatSliceExt>::linf_norm;
+    let _: fn(&mut [f16]) = <[f16] as HalfFloatSliceExt>::relu_inplace;
+    let _: fn(&[f16], &mut [f16]) = <[f16] as HalfFloatSliceExt>::relu_to;
+    let _: fn(&mut [f16]) = <[f16] as HalfFloatSliceExt>::canonicalize_inplace;
+    let _: fn(&mut [f16]) = <[f16] as HalfFloatSliceExt>::canonicalize_zero_inplace;
+    let _: fn(&[f16], usize, usize, &mut [f16]) = <[f16] as HalfFloatSliceExt>::transpose_to;
+    let _: fn(&mut [f16], usize) = <[f16] as HalfFloatSliceExt>::transpose_square_inplace;
+    #[cfg(feature = "alloc")]
+    let _: fn(&mut [f16]) = <[f16] as HalfFloatSliceExt>::sort_total_order;
+    let _: fn(&mut [f16]) = <[f16] as HalfFloatSliceExt>::sort_total_order_unstable;
+    let _: fn(&[bf16]) -> &[u16] = <[bf16] as HalfFloatSliceExt>::reinterpret_cast;
+    let _: fn(&mut [bf16]) -> &mut [u16] = <[bf16] as HalfFloatSliceExt>::reinterpret_cast_mut;
+    let _: fn(&mut [bf16], &[f32]) = <[bf16] as HalfFloatSliceExt>::convert_from_f32_slice;
+    let _: fn(&mut [bf16], &[f64]) = <[bf16] as HalfFloatSliceExt>::convert_from_f64_slice;
+    let _: unsafe fn(&mut [bf16], &[f32]) = <[bf16] as HalfFloatSliceExt>::convert_from_f32_slice_unchecked_normal;
+    let _: fn(&[bf16], &mut [f32]) = <[bf16] as HalfFloatSliceExt>::convert_to_f32_slice;
+    let _: fn(&[bf16], &mut [f64]) = <[bf16] as HalfFloatSliceExt>::convert_to_f64_slice;
+    let _: fn(&[bf16], &mut [f16]) = <[bf16] as HalfFloatSliceExt>::convert_to_f16_slice;
+    let _: fn(&[bf16], &mut [bf16]) = <[bf16] as HalfFloatSliceExt>::convert_to_bf16_slice;
+    let _: fn(&mut [bf16], &[i64], u32) = <[bf16] as HalfFloatSliceExt>::convert_from_fixed_point_slice;
+    let _: fn(&mut [bf16], &[u8]) = <[bf16] as HalfFloatSliceExt>::convert_from_u8_norm_slice;
+    let _: fn(&[bf16], &mut [i64], u32) -> Result<(), TryFromFloatError> = <[bf16] as HalfFloatSliceExt>::convert_to_fixed_point_slice;
+    let _: fn(&[bf16], &CompiledThreshold) -> usize = <[bf16] as HalfFloatSliceExt>::count_above;
+    let _: fn(&[bf16], &CompiledThreshold, &mut [bool]) = <[bf16] as HalfFloatSliceExt>::mask_above;
+    #[cfg(feature = "std")]
+    let _: fn(&mut [bf16]) = <[bf16] as HalfFloatSliceExt>::softmax_inplace;
+    let _: fn(&[bf16]) -> f32 = <[bf16] as HalfFloatSliceExt>::sum_pairwise_f32;
+    let _: fn(&[bf16], &[bf16]) -> f32 = <[bf16] as HalfFloatSliceExt>::dot_pairwise;
+    let _: fn(&[bf16], &[bf16], &mut [f32]) = <[bf16] as HalfFloatSliceExt>::fma_accumulate;
+    let _: fn(&[bf16], &[bf16], &mut [bf16]) = <[bf16] as HalfFloatSliceExt>::zip_add;
+    let _: fn(&[bf16], &[bf16], &mut [bf16]) = <[bf16] as HalfFloatSliceExt>::zip_sub;
+    let _: fn(&[bf16], &[bf16], &mut [bf16]) = <[bf16] as HalfFloatSliceExt>::zip_mul;
+    let _: fn(&[bf16], &[bf16], &mut [bf16]) = <[bf16] as HalfFloatSliceExt>::zip_div;
+    let _: fn(&[bf16], &[bf16], &mut [bf16]) = <[bf16] as HalfFloatSliceExt>::zip_rem;
+    let _: fn(&[bf16], &[bf16], &mut [bf16]) = <[bf16] as HalfFloatSliceExt>::zip_fma;
+    let _: fn(&[bf16]) -> f32 = <[bf16] as HalfFloatSliceExt>::pairwise_sum_f32;
+    let _: fn(&[bf16]) -> f32 = <[bf16] as HalfFloatSliceExt>::kahan_sum_f32;
+    #[cfg(feature = "std")]
+    let _: fn(&[bf16]) -> f32 = <[bf16] as HalfFloatSliceExt>::l2_norm;
+    let _: fn(&[bf16]) -> f32 = <[bf16] as HalfFloatSliceExt>::l1_norm;
+    let _: fn(&[bf16]) -> bf16 = <[bf16] as HalfFloatSliceExt>::linf_norm;
+    let _: fn(&mut [bf16]) = <[bf16] as HalfFloatSliceExt>::relu_inplace;
+    let _: fn(&[bf16], &mut [bf16]) = <[bf16] as HalfFloatSliceExt>::relu_to;
+    let _: fn(&mut [bf16]) = <[bf16] as HalfFloatSliceExt>::canonicalize_inplace;
+    let _: fn(&mut [bf16]) = <[bf16] as HalfFloatSliceExt>::canonicalize_zero_inplace;
+    let _: fn(&[bf16], usize, usize, &mut [bf16]) = <[bf16] as HalfFloatSliceExt>::transpose_to;
+    let _: fn(&mut [bf16], usize) = <[bf16] as HalfFloatSliceExt>::transpose_square_inplace;
+    #[cfg(feature = "alloc")]
+    let _: fn(&mut [bf16]) = <[bf16] as HalfFloatSliceExt>::sort_total_order;
+    let _: fn(&mut [bf16]) = <[bf16] as HalfFloatSliceExt>::sort_total_order_unstable;
+    let _: Endianness = Endianness::Little;
+    let _: Endianness = Endianness::Big;
+    let _: fn(Endianness, f32) -> EndiannessGuess = EndiannessGuess::Likely;
+    let _: EndiannessGuess = EndiannessGuess::Ambiguous;
+    let _: fn(&[u16]) -> &[f16] = <[u16] as HalfBitsSliceExt>::reinterpret_cast::<f16>;
+    let _: fn(&[u16]) -> &[bf16] = <[u16] as HalfBitsSliceExt>::reinterpret_cast::<bf16>;
+    let _: fn(&mut [u16]) -> &mut [f16] = <[u16] as HalfBitsSliceExt>::reinterpret_cast_mut::<f16>;
+    let _: fn(&mut [u16]) -> &mut [bf16] = <[u16] as HalfBitsSliceExt>::reinterpret_cast_mut::<bf16>;
+    let _: fn(&[u16]) -> EndiannessGuess = <[u16] as HalfBitsSliceExt>::guess_endianness;
+    let _: fn(&mut [u16]) = <[u16] as HalfBitsSliceExt>::byteswap_inplace;
+    let _: fn(&[u16], &mut [u16]) = <[u16] as HalfBitsSliceExt>::byteswap_to;
+    let _: fn(&[u16], &mut [f32]) = <[u16] as HalfBitsSliceExt>::byteswap_and_convert_to_f32_slice;
+    let _: fn(&TryFromFloatError, &mut core::fmt::Formatter<'_>) -> core::fmt::Result = <TryFromFloatError as core::fmt::Display>::fmt;
+    let _: fn(&TryFromHalfError, &mut core::fmt::Formatter<'_>) -> core::fmt::Result = <TryFromHalfError as core::fmt::Display>::fmt;
+    let _: fn(&TryFromHalfError) -> TryFromHalfErrorKind = TryFromHalfError::kind;
+    let _: TryFromHalfErrorKind = TryFromHalfErrorKind::Nan;
+    let _: TryFromHalfErrorKind = TryFromHalfErrorKind::Infinite;
+    let _: TryFromHalfErrorKind = TryFromHalfErrorKind::OutOfRange;
+    let _: TryFromHalfErrorKind = TryFromHalfErrorKind::Fractional;
+    let _: fn(&ParseHalfError, &mut core::fmt::Formatter<'_>) -> core::fmt::Result = <ParseHalfError as core::fmt::Display>::fmt;
+    let _: fn(&OddLength, &mut core::fmt::Formatter<'_>) -> core::fmt::Result = <OddLength as core::fmt::Display>::fmt;
+    let _: fn(&StridedLayoutError, &mut core::fmt::Formatter<'_>) -> core::fmt::Result = <StridedLayoutError as core::fmt::Display>::fmt;
+    let _: fn(&StridedLayoutError) -> StridedLayoutErrorKind = StridedLayoutError::kind;
+    let _: StridedLayoutErrorKind = StridedLayoutErrorKind::RankMismatch;
+    let _: StridedLayoutErrorKind = StridedLayoutErrorKind::Overflow;
+    let _: StridedLayoutErrorKind = StridedLayoutErrorKind::OutOfBounds;
+    let _: StridedLayoutErrorKind = StridedLayoutErrorKind::Overlapping;
+    #[cfg(target_has_atomic = "8")]
+    let _: fn() -> sync::OnceTable<u8> = sync::OnceTable::<u8>::new;
+    #[cfg(target_has_atomic = "8")]
+    let _: fn(&sync::OnceTable<u8>) -> Option<&u8> = sync::OnceTable::<u8>::get;
+    #[cfg(target_has_atomic = "8")]
+    let _: fn(&sync::OnceTable<u8>, fn() -> u8) -> &u8 = sync::OnceTable::<u8>::get_or_init::<fn() -> u8>;
+    #[cfg(all(feature = "alloc", target_has_atomic = "8"))]
+    let _: fn() -> &'static [f32; 1 << 16] = f16_to_f32_table;
+    #[cfg(feature = "test-support")]
+    let _: fn(usize, u64) -> std::vec::Vec<f16> = testing::stratified_values;
+    #[cfg(feature = "test-support")]
+    let _: fn(&mut [f16], u64) = testing::fill_stratified;
+    #[cfg(feature = "test-support")]
+    let _: fn() -> _ = testing::boundary_pairs;
+}
@@ -0,0 +1,38 @@
+//! Compares `f16::to_numpy_repr_string` against NumPy's formatting of every
+//! value, from the corpus in `tests/data/numpy_f16_repr.txt`.
+//!
+//! The corpus is generated by `devel/numpy_f16_repr.py`. Rerun it and commit
+//! the result if NumPy changes how it formats `float16` scalars.
+
+#![cfg(feature = "std")]
+
+use float16::f16;
+
+const CORPUS: &str = include_str!("data/numpy_f16_repr.txt");
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn numpy_repr_corpus() {
+    let mut count = 0;
+    for line in CORPUS.lines() {
+        let (bits, repr) = line.split_once(' ').unwrap();
+        let bits = u16::from_str_radix(bits, 16).unwrap();
+        assert_eq!(count, bits as usize, "the corpus must list every value in order");
+        let value = f16::from_bits(bits);
+        assert_eq!(value.to_numpy_repr_string(), repr, "{:#06x}", bits);
+
+        // Where `Display` is written differently, it is still the same value.
+        let display = value.to_string();
+        if display != repr {
+            let display: f16 = display.parse().unwrap();
+            let repr: f16 = repr.parse().unwrap();
+            if value.is_nan() {
+                assert!(display.is_nan() && repr.is_nan(), "{:#06x}", bits);
+            } else {
+                assert_eq!(display.to_bits(), repr.to_bits(), "{:#06x}", bits);
+            }
+        }
+        count += 1;
+    }
+    assert_eq!(count, 1 << 16);
+}